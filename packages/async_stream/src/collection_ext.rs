@@ -0,0 +1,145 @@
+//! Async map combinators for `ZeroOneOrMany` and `OneOrMany`
+
+use crate::result_types::AsyncResult;
+use core::future::Future;
+use futures::FutureExt as _;
+use futures::future::{Either, join_all, ready, try_join_all};
+use sugars_async_task::{AsyncTask, NotResult};
+use sugars_collections::{OneOrMany, ZeroOneOrMany};
+
+//────────────────────────────────────────────────────────────────────────────
+// CollectionAsyncExt – Async mapping for ZeroOneOrMany<T> / OneOrMany<T>
+//────────────────────────────────────────────────────────────────────────────
+
+/// Extension trait that maps collection elements with async closures.
+///
+/// The closure is called for every element up front, the resulting futures run
+/// concurrently, and the mapped collection keeps the variant shape and element order
+/// of the original.
+pub trait CollectionAsyncExt<T>: Sized {
+    /// The same collection type holding mapped elements.
+    type Mapped<U: NotResult + Send>: NotResult + Send;
+
+    /// Maps each element with an async closure, resolving to the mapped collection.
+    fn map_async<U, F, Fut>(self, f: F) -> AsyncTask<Self::Mapped<U>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static + NotResult;
+
+    /// Maps each element with a fallible async closure, resolving to the mapped collection
+    /// or the first error encountered.
+    fn try_map_async<U, E, F, Fut>(self, f: F) -> AsyncTask<AsyncResult<Self::Mapped<U>, E>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<U, E>> + Send + 'static,
+        U: Send + 'static + NotResult,
+        E: Send + 'static;
+}
+
+impl<T> CollectionAsyncExt<T> for ZeroOneOrMany<T> {
+    type Mapped<U: NotResult + Send> = ZeroOneOrMany<U>;
+
+    fn map_async<U, F, Fut>(self, f: F) -> AsyncTask<ZeroOneOrMany<U>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static + NotResult,
+    {
+        AsyncTask::from_future(map_all(self, f))
+    }
+
+    fn try_map_async<U, E, F, Fut>(self, f: F) -> AsyncTask<AsyncResult<ZeroOneOrMany<U>, E>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<U, E>> + Send + 'static,
+        U: Send + 'static + NotResult,
+        E: Send + 'static,
+    {
+        AsyncTask::from_future(try_map_all(self, f).map(into_async_result))
+    }
+}
+
+impl<T> CollectionAsyncExt<T> for OneOrMany<T> {
+    type Mapped<U: NotResult + Send> = OneOrMany<U>;
+
+    fn map_async<U, F, Fut>(self, f: F) -> AsyncTask<OneOrMany<U>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = U> + Send + 'static,
+        U: Send + 'static + NotResult,
+    {
+        AsyncTask::from_future(map_all(self.into(), f).map(into_one_or_many))
+    }
+
+    fn try_map_async<U, E, F, Fut>(self, f: F) -> AsyncTask<AsyncResult<OneOrMany<U>, E>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<U, E>> + Send + 'static,
+        U: Send + 'static + NotResult,
+        E: Send + 'static,
+    {
+        AsyncTask::from_future(
+            try_map_all(self.into(), f)
+                .map(|result| into_async_result(result.map(into_one_or_many))),
+        )
+    }
+}
+
+/// Calls `f` on every element and joins the futures, keeping the variant shape.
+fn map_all<T, U, F, Fut>(
+    items: ZeroOneOrMany<T>,
+    mut f: F,
+) -> impl Future<Output = ZeroOneOrMany<U>> + Send + 'static
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = U> + Send + 'static,
+    U: Send + 'static,
+{
+    match items {
+        ZeroOneOrMany::None => Either::Left(ready(ZeroOneOrMany::None)),
+        ZeroOneOrMany::One(item) => Either::Right(Either::Left(f(item).map(ZeroOneOrMany::One))),
+        ZeroOneOrMany::Many(v) => Either::Right(Either::Right(
+            join_all(v.into_iter().map(f)).map(ZeroOneOrMany::Many),
+        )),
+    }
+}
+
+/// Calls `f` on every element and joins the futures, stopping at the first error.
+fn try_map_all<T, U, E, F, Fut>(
+    items: ZeroOneOrMany<T>,
+    mut f: F,
+) -> impl Future<Output = Result<ZeroOneOrMany<U>, E>> + Send + 'static
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Result<U, E>> + Send + 'static,
+    U: Send + 'static,
+    E: Send + 'static,
+{
+    match items {
+        ZeroOneOrMany::None => Either::Left(ready(Ok(ZeroOneOrMany::None))),
+        ZeroOneOrMany::One(item) => {
+            Either::Right(Either::Left(f(item).map(|r| r.map(ZeroOneOrMany::One))))
+        }
+        ZeroOneOrMany::Many(v) => Either::Right(Either::Right(
+            try_join_all(v.into_iter().map(f)).map(|r| r.map(ZeroOneOrMany::Many)),
+        )),
+    }
+}
+
+/// Converts a plain `Result` into the `AsyncResult` an `AsyncTask` may carry.
+fn into_async_result<T, E>(result: Result<T, E>) -> AsyncResult<T, E> {
+    match result {
+        Ok(value) => AsyncResult::ok(value),
+        Err(error) => AsyncResult::err(error),
+    }
+}
+
+/// Rewraps the mapped elements of a `OneOrMany`, which are never empty.
+fn into_one_or_many<U>(mapped: ZeroOneOrMany<U>) -> OneOrMany<U> {
+    match mapped {
+        ZeroOneOrMany::None => unreachable!("mapping a OneOrMany yields at least one element"),
+        ZeroOneOrMany::One(item) => OneOrMany::one(item),
+        ZeroOneOrMany::Many(v) => v.into_iter().collect(),
+    }
+}
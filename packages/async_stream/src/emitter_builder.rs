@@ -1,18 +1,8 @@
 //! EmitterBuilder - builds AsyncStream with error handling
 
-#[cfg(all(
-    feature = "crossbeam-backend",
-    not(feature = "tokio-backend"),
-    not(feature = "std-backend")
-))]
-use crate::stream::crossbeam::AsyncStream;
-#[cfg(all(feature = "std-backend", not(feature = "tokio-backend")))]
-use crate::stream::std::AsyncStream;
-#[cfg(feature = "tokio-backend")]
-use crate::stream::tokio::AsyncStream;
+use crate::AsyncStream;
 use std::future::Future;
 use std::pin::Pin;
-use tokio::sync::mpsc;
 
 /// Builder that emits AsyncStream after handling Result
 pub struct EmitterBuilder<T> {
@@ -41,9 +31,7 @@ impl<T: Send + 'static + super::NotResult> EmitterBuilder<T> {
         FOk: FnOnce(Vec<T>) -> Vec<T> + Send + 'static,
         FErr: FnOnce(Box<dyn std::error::Error + Send>) + Send + 'static,
     {
-        let (tx, rx) = mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             match self.inner.execute().await {
                 Ok(items) => {
                     for item in on_ok(items) {
@@ -54,9 +42,7 @@ impl<T: Send + 'static + super::NotResult> EmitterBuilder<T> {
                 }
                 Err(e) => on_err(e),
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 }
 
@@ -5,26 +5,38 @@
 //! - `std-async`: Runtime-agnostic using async-channel  
 //! - `crossbeam-async`: Compute-heavy workloads with crossbeam + async-channel
 
+/// Runs an async test on the selected backend, with Tokio's clock paused
+#[cfg(test)]
+macro_rules! backend_test {
+    ($(async fn $name:ident() $body:block)*) => {$(
+        #[cfg(feature = "tokio-backend")]
+        #[::tokio::test(start_paused = true)]
+        async fn $name() $body
+
+        #[cfg(not(feature = "tokio-backend"))]
+        #[test]
+        fn $name() {
+            futures::executor::block_on(async $body)
+        }
+    )*};
+}
+
+pub mod collection_ext;
 pub mod emitter_builder;
 pub mod result_types;
 pub mod stream;
 pub mod stream_ext;
 
 // Runtime-specific unified exports
-#[cfg(all(
-    feature = "crossbeam-backend",
-    not(feature = "tokio-backend"),
-    not(feature = "std-backend")
-))]
-pub use stream::crossbeam::AsyncStream;
-#[cfg(all(feature = "std-backend", not(feature = "tokio-backend")))]
-pub use stream::std::AsyncStream;
+pub use stream::AsyncStream;
 #[cfg(feature = "tokio-backend")]
-pub use stream::tokio::AsyncStream;
+pub use stream::bounded::{BoundedSender, SendError};
 
 // Core types available in all configurations
+pub use collection_ext::CollectionAsyncExt;
 pub use emitter_builder::{EmitterBuilder, EmitterImpl};
 pub use result_types::{AsyncResult, AsyncResultChunk};
+pub use stream::{OverflowPolicy, SelectStrategy};
 pub use stream_ext::StreamExt;
 
 // Re-export from async_task
@@ -0,0 +1,138 @@
+//! Bounded channels feeding an AsyncStream, with a configurable overflow policy
+//!
+//! The tokio backend uses its own queue; the std and crossbeam backends wrap a bounded
+//! async-channel. Both expose the same `BoundedSender` API.
+
+use std::fmt;
+
+#[cfg(not(feature = "tokio-backend"))]
+mod async_channel;
+#[cfg(feature = "tokio-backend")]
+mod queue;
+
+#[cfg(not(feature = "tokio-backend"))]
+pub use self::async_channel::BoundedSender;
+#[cfg(not(feature = "tokio-backend"))]
+pub(crate) use self::async_channel::channel;
+#[cfg(feature = "tokio-backend")]
+pub use self::queue::BoundedSender;
+#[cfg(feature = "tokio-backend")]
+pub(crate) use self::queue::{BoundedReceiver, channel};
+
+/// Error returned when a `BoundedSender` cannot deliver an item
+///
+/// The rejected item is handed back so the producer can retry or report it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendError<T> {
+    /// The channel was full and its policy is `OverflowPolicy::Error`
+    Full(T),
+    /// The stream was dropped, so nothing will read the item
+    Closed(T),
+}
+
+impl<T> SendError<T> {
+    /// Returns the item that could not be sent
+    pub fn into_inner(self) -> T {
+        match self {
+            SendError::Full(item) | SendError::Closed(item) => item,
+        }
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Full(_) => f.write_str("stream channel is full"),
+            SendError::Closed(_) => f.write_str("stream channel is closed"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for SendError<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AsyncStream;
+    use crate::stream::OverflowPolicy;
+    use futures::StreamExt;
+    use pretty_assertions::assert_eq;
+
+    /// Sends 1, 2 and 3 into a channel of capacity 2 that nobody reads yet
+    fn overfill(policy: OverflowPolicy) -> (Result<(), SendError<u32>>, AsyncStream<u32>) {
+        let (tx, stream) = AsyncStream::bounded(2, policy);
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        let third = tx.try_send(3);
+        (third, stream)
+    }
+
+    backend_test! {
+        async fn test_block_waits_for_a_free_slot() {
+            let (tx, mut stream) = AsyncStream::bounded(1, OverflowPolicy::Block);
+            tx.send(1).await.unwrap();
+            assert!(stream.is_full());
+            assert_eq!(tx.try_send(2), Err(SendError::Full(2)));
+
+            // The send only completes once the read frees the slot
+            let (sent, first) = futures::join!(tx.send(2), stream.next());
+            assert_eq!(sent, Ok(()));
+            assert_eq!(first, Some(1));
+
+            drop(tx);
+            assert_eq!(stream.collect::<Vec<_>>().await, vec![2]);
+        }
+
+        async fn test_drop_oldest_keeps_newest_items() {
+            let (third, stream) = overfill(OverflowPolicy::DropOldest);
+            assert_eq!(third, Ok(()));
+            assert_eq!(stream.len(), 2);
+            assert_eq!(stream.take(2).collect::<Vec<_>>().await, vec![2, 3]);
+        }
+
+        async fn test_drop_newest_keeps_buffered_items() {
+            let (third, stream) = overfill(OverflowPolicy::DropNewest);
+            assert_eq!(third, Ok(()));
+            assert_eq!(stream.take(2).collect::<Vec<_>>().await, vec![1, 2]);
+        }
+
+        async fn test_error_rejects_when_full() {
+            let (third, stream) = overfill(OverflowPolicy::Error);
+            assert_eq!(third, Err(SendError::Full(3)));
+            assert!(stream.is_full());
+            assert_eq!(stream.take(2).collect::<Vec<_>>().await, vec![1, 2]);
+        }
+
+        async fn test_send_after_stream_dropped_is_closed() {
+            let (tx, stream) = AsyncStream::bounded(2, OverflowPolicy::DropOldest);
+            drop(stream);
+            assert!(tx.is_closed());
+            assert_eq!(tx.send(1).await, Err(SendError::Closed(1)));
+        }
+
+        async fn test_bounded_introspection() {
+            let (tx, stream) = AsyncStream::bounded(0, OverflowPolicy::Error);
+            assert_eq!(tx.capacity(), 1);
+            assert_eq!(stream.capacity(), Some(1));
+            assert_eq!(tx.policy(), OverflowPolicy::Error);
+            assert!(tx.is_empty());
+
+            tx.try_send(7).unwrap();
+            assert_eq!((tx.len(), stream.len()), (1, 1));
+            assert!(tx.is_full());
+
+            let unbounded = AsyncStream::<u32>::empty();
+            assert_eq!(unbounded.capacity(), None);
+            assert!(!unbounded.is_full());
+        }
+
+        async fn test_stream_ends_when_every_sender_is_dropped() {
+            let (tx, stream) = AsyncStream::bounded(4, OverflowPolicy::Block);
+            let other = tx.clone();
+            tx.send(1).await.unwrap();
+            other.send(2).await.unwrap();
+            drop((tx, other));
+            assert_eq!(stream.collect_async().await, vec![1, 2]);
+        }
+    }
+}
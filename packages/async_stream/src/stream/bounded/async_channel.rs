@@ -0,0 +1,123 @@
+//! Bounded async-channel feeding a std or crossbeam AsyncStream
+
+use std::fmt;
+
+use ::async_channel::{Receiver, Sender, TrySendError};
+
+use super::SendError;
+use crate::stream::OverflowPolicy;
+
+/// Sending half of `AsyncStream::bounded`
+///
+/// Cloning the sender adds another producer; the stream ends once every sender is dropped
+/// and the buffered items have been read.
+pub struct BoundedSender<T> {
+    sender: Sender<T>,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T> BoundedSender<T> {
+    /// Sends `item`, applying the channel's overflow policy when it is full
+    ///
+    /// With `OverflowPolicy::Block` this waits for the stream to free a slot.
+    /// `DropNewest` discards `item`, `DropOldest` discards the oldest buffered item, and
+    /// `Error` returns `SendError::Full`.
+    pub async fn send(&self, item: T) -> Result<(), SendError<T>> {
+        match self.policy {
+            OverflowPolicy::Block => self
+                .sender
+                .send(item)
+                .await
+                .map_err(|error| SendError::Closed(error.into_inner())),
+            _ => self.try_send(item),
+        }
+    }
+
+    /// Sends `item` without waiting
+    ///
+    /// Behaves like `send`, except that `OverflowPolicy::Block` returns `SendError::Full`
+    /// instead of waiting.
+    pub fn try_send(&self, item: T) -> Result<(), SendError<T>> {
+        if self.policy == OverflowPolicy::DropOldest {
+            return self
+                .sender
+                .force_send(item)
+                .map(drop)
+                .map_err(|error| SendError::Closed(error.into_inner()));
+        }
+        match self.sender.try_send(item) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) if self.policy == OverflowPolicy::DropNewest => Ok(()),
+            Err(TrySendError::Full(item)) => Err(SendError::Full(item)),
+            Err(TrySendError::Closed(item)) => Err(SendError::Closed(item)),
+        }
+    }
+
+    /// Returns the number of items waiting to be read
+    pub fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    /// Returns true if no items are waiting to be read
+    pub fn is_empty(&self) -> bool {
+        self.sender.is_empty()
+    }
+
+    /// Returns true if the channel holds `capacity` items
+    pub fn is_full(&self) -> bool {
+        self.sender.is_full()
+    }
+
+    /// Returns the maximum number of buffered items
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the policy applied when the channel is full
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns true if the stream has been dropped
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            capacity: self.capacity,
+            policy: self.policy,
+        }
+    }
+}
+
+impl<T> fmt::Debug for BoundedSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedSender")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+/// Creates a channel holding at most `capacity` items (at least 1)
+pub(crate) fn channel<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> (BoundedSender<T>, Receiver<T>) {
+    let capacity = capacity.max(1);
+    let (sender, receiver) = ::async_channel::bounded(capacity);
+    (
+        BoundedSender {
+            sender,
+            capacity,
+            policy,
+        },
+        receiver,
+    )
+}
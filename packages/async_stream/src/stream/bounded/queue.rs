@@ -0,0 +1,220 @@
+//! Bounded channel feeding a Tokio AsyncStream
+
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+use tokio::sync::Notify;
+
+use super::SendError;
+use crate::stream::OverflowPolicy;
+
+struct State<T> {
+    queue: VecDeque<T>,
+    receiver_waker: Option<Waker>,
+    senders: usize,
+    receiver_alive: bool,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    /// Signalled whenever the receiver frees a slot or goes away
+    space: Notify,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T> Shared<T> {
+    /// Queues `item` if there is room (or the policy makes room) without waiting
+    fn push(&self, item: T) -> Result<(), SendError<T>> {
+        let mut state = self.state.lock();
+        if !state.receiver_alive {
+            return Err(SendError::Closed(item));
+        }
+        if state.queue.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::DropOldest => {
+                    state.queue.pop_front();
+                }
+                OverflowPolicy::DropNewest => return Ok(()),
+                OverflowPolicy::Block | OverflowPolicy::Error => {
+                    return Err(SendError::Full(item));
+                }
+            }
+        }
+        state.queue.push_back(item);
+        let waker = state.receiver_waker.take();
+        drop(state);
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+}
+
+/// Sending half of `AsyncStream::bounded`
+///
+/// Cloning the sender adds another producer; the stream ends once every sender is dropped
+/// and the buffered items have been read.
+pub struct BoundedSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedSender<T> {
+    /// Sends `item`, applying the channel's overflow policy when it is full
+    ///
+    /// With `OverflowPolicy::Block` this waits for the stream to free a slot.
+    /// `DropNewest` discards `item`, `DropOldest` discards the oldest buffered item, and
+    /// `Error` returns `SendError::Full`.
+    pub async fn send(&self, mut item: T) -> Result<(), SendError<T>> {
+        loop {
+            let space = self.shared.space.notified();
+            tokio::pin!(space);
+            // Register before checking, so a slot freed in between is not missed
+            space.as_mut().enable();
+
+            match self.shared.push(item) {
+                Err(SendError::Full(rejected)) if self.shared.policy == OverflowPolicy::Block => {
+                    item = rejected;
+                    space.await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends `item` without waiting
+    ///
+    /// Behaves like `send`, except that `OverflowPolicy::Block` returns `SendError::Full`
+    /// instead of waiting.
+    pub fn try_send(&self, item: T) -> Result<(), SendError<T>> {
+        self.shared.push(item)
+    }
+
+    /// Returns the number of items waiting to be read
+    pub fn len(&self) -> usize {
+        self.shared.state.lock().queue.len()
+    }
+
+    /// Returns true if no items are waiting to be read
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the channel holds `capacity` items
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.shared.capacity
+    }
+
+    /// Returns the maximum number of buffered items
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    /// Returns the policy applied when the channel is full
+    pub fn policy(&self) -> OverflowPolicy {
+        self.shared.policy
+    }
+
+    /// Returns true if the stream has been dropped
+    pub fn is_closed(&self) -> bool {
+        !self.shared.state.lock().receiver_alive
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock();
+        state.senders -= 1;
+        let waker = if state.senders == 0 {
+            state.receiver_waker.take()
+        } else {
+            None
+        };
+        drop(state);
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> fmt::Debug for BoundedSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedSender")
+            .field("len", &self.len())
+            .field("capacity", &self.shared.capacity)
+            .field("policy", &self.shared.policy)
+            .finish()
+    }
+}
+
+/// Receiving half of a bounded channel, owned by an `AsyncStream`
+pub(crate) struct BoundedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedReceiver<T> {
+    pub(crate) fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.shared.state.lock();
+        if let Some(item) = state.queue.pop_front() {
+            drop(state);
+            self.shared.space.notify_one();
+            return Poll::Ready(Some(item));
+        }
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+        state.receiver_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.shared.state.lock().queue.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.state.lock().receiver_alive = false;
+        self.shared.space.notify_waiters();
+    }
+}
+
+/// Creates a channel holding at most `capacity` items (at least 1)
+pub(crate) fn channel<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    let capacity = capacity.max(1);
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(capacity),
+            receiver_waker: None,
+            senders: 1,
+            receiver_alive: true,
+        }),
+        space: Notify::new(),
+        capacity,
+        policy,
+    });
+    (
+        BoundedSender {
+            shared: Arc::clone(&shared),
+        },
+        BoundedReceiver { shared },
+    )
+}
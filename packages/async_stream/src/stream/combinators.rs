@@ -0,0 +1,325 @@
+//! Constructors and combinators shared by every backend
+//!
+//! These are written against each backend's unbounded channel and the AsyncTask
+//! backend's executor, so they behave the same whichever runtime is selected.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+
+use futures::Stream;
+use sugars_async_task::{AsyncTask, NotResult};
+use sugars_collections::ZeroOneOrMany;
+
+use super::{AsyncStream, OverflowPolicy, Producer, SelectStrategy};
+
+/// How far `AsyncStream::iter` reads ahead of the consumer
+const ITER_READ_AHEAD: usize = 16;
+
+impl<T> AsyncStream<T>
+where
+    T: NotResult, // T cannot be any Result type
+{
+    /// Create an AsyncStream fed by the future `f` builds from the stream's producer
+    ///
+    /// The future runs on the AsyncTask backend's executor and should stop once `send`
+    /// hands an item back, which means the stream has been dropped.
+    pub(crate) fn produce<F, Fut>(f: F) -> Self
+    where
+        F: FnOnce(Producer<T>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (tx, stream) = Self::unbounded();
+        sugars_async_task::backend::spawn(f(tx));
+        stream
+    }
+
+    /// Create an AsyncStream that pulls items from an iterator as they are read
+    ///
+    /// Unlike `collect::<AsyncStream<_>>()`, the iterator may be endless: it is advanced on
+    /// the AsyncTask backend's executor, at most 16 items ahead of the consumer, and is
+    /// dropped once the stream is. With the tokio backend this must be called within a
+    /// Tokio runtime.
+    pub fn iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+        T: Send + 'static,
+    {
+        let iter = iter.into_iter();
+        let (tx, stream) = Self::bounded(ITER_READ_AHEAD, OverflowPolicy::Block);
+        sugars_async_task::backend::spawn(async move {
+            for item in iter {
+                if tx.send(item).await.is_err() {
+                    break;
+                }
+            }
+        });
+        stream
+    }
+
+    /// Create an AsyncTask that collects every item of a futures Stream into a Vec
+    ///
+    /// Use `from_futures_stream` to keep the items flowing as an AsyncStream instead.
+    pub fn from_stream<S>(stream: S) -> AsyncTask<Vec<T>>
+    where
+        S: Stream<Item = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        Self::from_futures_stream(stream).collect_async()
+    }
+
+    /// Create an AsyncStream that yields every item of a futures Stream
+    ///
+    /// The stream is driven on the AsyncTask backend's executor, so with the tokio backend
+    /// this must be called within a Tokio runtime.
+    pub fn from_futures_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        Self::from_stream_with(stream, std::convert::identity)
+    }
+
+    /// Create an AsyncStream from a futures Stream, converting each item with `f`
+    ///
+    /// This is how a `Stream<Item = Result<..>>` enters the sugars types: `f` folds every
+    /// error into a chunk value, e.g. `|r| r.unwrap_or_else(|e| Chunk::bad_chunk(e.to_string()))`.
+    /// The stream is driven on the AsyncTask backend's executor, so with the tokio backend
+    /// this must be called within a Tokio runtime.
+    pub fn from_stream_with<S, F>(stream: S, mut f: F) -> Self
+    where
+        S: Stream + Send + 'static,
+        F: FnMut(S::Item) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        Self::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut stream = std::pin::pin!(stream);
+            while let Some(item) = stream.next().await {
+                if tx.send(f(item)).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Convert into a plain futures Stream, for use with `futures` or `tokio_stream` combinators
+    ///
+    /// Items are yielded directly from the underlying channel without another task.
+    pub fn into_futures_stream(self) -> impl Stream<Item = T> + Unpin {
+        self
+    }
+
+    /// Interleave several streams into one, yielding items as they arrive
+    ///
+    /// Streams that are ready at the same time take turns. The merged stream ends once
+    /// every input has ended.
+    pub fn merge(streams: ZeroOneOrMany<AsyncStream<T>>) -> Self
+    where
+        T: Send + 'static,
+    {
+        Self::select_with_strategy(streams, SelectStrategy::RoundRobin)
+    }
+
+    /// Interleave several streams into one, using `strategy` to choose between streams
+    /// that are ready at the same time
+    ///
+    /// The combined stream ends once every input has ended.
+    pub fn select_with_strategy(
+        streams: ZeroOneOrMany<AsyncStream<T>>,
+        strategy: SelectStrategy,
+    ) -> Self
+    where
+        T: Send + 'static,
+    {
+        let mut streams: Vec<Option<AsyncStream<T>>> =
+            Vec::from(streams).into_iter().map(Some).collect();
+        let mut turn = 0;
+
+        let mut selected = futures::stream::poll_fn(move |cx| {
+            let len = streams.len();
+            let start = match strategy {
+                SelectStrategy::RoundRobin => turn,
+                SelectStrategy::Priority => 0,
+            };
+            let mut pending = false;
+
+            for offset in 0..len {
+                let index = (start + offset) % len;
+                let Some(stream) = streams[index].as_mut() else {
+                    continue;
+                };
+                match Pin::new(stream).poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        turn = (index + 1) % len;
+                        return Poll::Ready(Some(item));
+                    }
+                    Poll::Ready(None) => streams[index] = None,
+                    Poll::Pending => pending = true,
+                }
+            }
+
+            if pending {
+                Poll::Pending
+            } else {
+                Poll::Ready(None)
+            }
+        });
+
+        Self::produce(move |tx| async move {
+            use futures::StreamExt;
+            while let Some(item) = selected.next().await {
+                if tx.send(item).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Pair up items from this stream and `other`, ending when either stream ends
+    pub fn zip<U>(self, other: AsyncStream<U>) -> AsyncStream<(T, U)>
+    where
+        T: Send + 'static,
+        U: NotResult + Send + 'static,
+    {
+        AsyncStream::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut zipped = futures::StreamExt::zip(self, other);
+            while let Some(pair) = zipped.next().await {
+                if tx.send(pair).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// Collect all items from the stream into a Vec
+    pub fn collect_async(self) -> AsyncTask<Vec<T>>
+    where
+        T: Send + 'static,
+    {
+        AsyncTask::from_future(futures::StreamExt::collect(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::OverflowPolicy;
+    use pretty_assertions::assert_eq;
+
+    backend_test! {
+        async fn test_from_stream_collects_every_item() {
+            let items = AsyncStream::from_stream(futures::stream::iter(vec![1, 2, 3])).await;
+            assert_eq!(items, vec![1, 2, 3]);
+        }
+
+        async fn test_from_futures_stream_keeps_streaming() {
+            use futures::StreamExt;
+            let mut stream = AsyncStream::from_futures_stream(futures::stream::iter(1..=3));
+            assert_eq!(stream.next().await, Some(1));
+            assert_eq!(stream.collect::<Vec<_>>().await, vec![2, 3]);
+        }
+
+        async fn test_from_stream_with_folds_errors_into_items() {
+            let results: Vec<Result<u32, String>> = vec![Ok(1), Err("bad".into()), Ok(3)];
+            let stream = AsyncStream::from_stream_with(futures::stream::iter(results), |result| {
+                result.unwrap_or_else(|error| format!("error: {error}").len() as u32)
+            });
+            assert_eq!(stream.collect_async().await, vec![1, 10, 3]);
+        }
+
+        async fn test_iter_streams_an_endless_iterator() {
+            use futures::StreamExt;
+            let first: Vec<u64> = AsyncStream::iter(0..).take(3).collect().await;
+            assert_eq!(first, vec![0, 1, 2]);
+        }
+
+        async fn test_iter_stops_reading_once_the_stream_is_dropped() {
+            use futures::StreamExt;
+            use std::sync::Arc;
+            use std::sync::atomic::{AtomicUsize, Ordering};
+
+            let pulled = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&pulled);
+            let mut stream = AsyncStream::iter(std::iter::repeat_with(move || {
+                counter.fetch_add(1, Ordering::SeqCst)
+            }));
+            assert_eq!(stream.next().await, Some(0));
+            drop(stream);
+
+            // Give the producer a chance to run; it may only fill its read-ahead buffer
+            sugars_async_task::backend::sleep(std::time::Duration::from_millis(20)).await;
+            assert!(pulled.load(Ordering::SeqCst) <= ITER_READ_AHEAD + 2);
+        }
+
+        async fn test_collecting_an_iterator_yields_every_item() {
+            let stream: AsyncStream<u32> = (1..=3).collect();
+            assert_eq!(stream.len(), 3);
+            assert_eq!(stream.collect_async().await, vec![1, 2, 3]);
+        }
+
+        async fn test_empty_and_once() {
+            assert_eq!(AsyncStream::<u32>::empty().collect_async().await, Vec::<u32>::new());
+            assert_eq!(AsyncStream::once(5).collect_async().await, vec![5]);
+        }
+
+        async fn test_merge_takes_turns_between_ready_streams() {
+            let streams = ZeroOneOrMany::many(vec![
+                AsyncStream::from_iter(vec![1, 2, 3]),
+                AsyncStream::from_iter(vec![10, 20]),
+            ]);
+            let merged = AsyncStream::merge(streams).collect_async().await;
+            assert_eq!(merged, vec![1, 10, 2, 20, 3]);
+        }
+
+        async fn test_select_with_priority_drains_earlier_streams_first() {
+            let streams = ZeroOneOrMany::many(vec![
+                AsyncStream::from_iter(vec![1, 2, 3]),
+                AsyncStream::from_iter(vec![10, 20]),
+            ]);
+            let selected = AsyncStream::select_with_strategy(streams, SelectStrategy::Priority);
+            assert_eq!(selected.collect_async().await, vec![1, 2, 3, 10, 20]);
+        }
+
+        async fn test_merge_ends_only_after_every_input_ends() {
+            let (tx, open) = AsyncStream::bounded(4, OverflowPolicy::Block);
+            let merged = AsyncStream::merge(ZeroOneOrMany::many(vec![AsyncStream::once(1), open]));
+            tx.send(2).await.unwrap();
+            drop(tx);
+            assert_eq!(merged.collect_async().await, vec![1, 2]);
+        }
+
+        async fn test_merge_of_nothing_is_empty() {
+            let merged = AsyncStream::<u32>::merge(ZeroOneOrMany::None);
+            assert_eq!(merged.collect_async().await, Vec::<u32>::new());
+        }
+
+        async fn test_zip_stops_at_the_shorter_stream() {
+            let left = AsyncStream::from_iter(vec![1, 2, 3]);
+            let right = AsyncStream::from_iter(vec!['a', 'b']);
+            let zipped = left.zip(right).collect_async().await;
+            assert_eq!(zipped, vec![(1, 'a'), (2, 'b')]);
+        }
+
+        async fn test_zip_ends_without_waiting_on_an_open_stream() {
+            let (tx, right) = AsyncStream::bounded(4, OverflowPolicy::Block);
+            tx.send('a').await.unwrap();
+            tx.send('b').await.unwrap();
+            let zipped = AsyncStream::once(1).zip(right).collect_async().await;
+            assert_eq!(zipped, vec![(1, 'a')]);
+            drop(tx);
+        }
+
+        async fn test_into_futures_stream_composes_with_futures_combinators() {
+            use futures::StreamExt;
+            let doubled: Vec<u32> = AsyncStream::from_iter(vec![1, 2, 3])
+                .into_futures_stream()
+                .map(|item| item * 2)
+                .collect()
+                .await;
+            assert_eq!(doubled, vec![2, 4, 6]);
+        }
+    }
+}
@@ -1,11 +1,32 @@
 //! Crossbeam + async-channel hybrid implementation for compute-heavy workloads
 
-use async_channel;
 use futures::Stream;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use sugars_async_task::NotResult;
 
+use super::OverflowPolicy;
+use super::bounded::{self, BoundedSender};
+
+/// Longest pause between checks of a crossbeam receiver that has nothing ready
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Sending half of an unbounded AsyncStream, used by the combinators to feed their output
+pub(crate) struct Producer<T>(async_channel::Sender<T>);
+
+impl<T> Producer<T> {
+    /// Queues `item`, handing it back if the stream has been dropped
+    pub(crate) fn send(&self, item: T) -> Result<(), T> {
+        self.0.try_send(item).map_err(|error| error.into_inner())
+    }
+
+    /// Returns true once the stream has been dropped
+    pub(crate) fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+}
+
 /// Generic async stream wrapper for streaming operations with Crossbeam + async-channel
 ///
 /// IMPORTANT: AsyncStream must never contain Result types - all error handling
@@ -17,61 +38,117 @@ pub struct AsyncStream<T>
 where
     T: NotResult, // T cannot be any Result type
 {
-    receiver: async_channel::Receiver<T>,
+    // Boxed because async-channel's receiver is not Unpin
+    receiver: Pin<Box<async_channel::Receiver<T>>>,
 }
 
 impl<T> AsyncStream<T>
 where
     T: NotResult, // T cannot be any Result type
 {
-    /// Create a new AsyncStream from a crossbeam receiver
-    pub fn new(receiver: channel::Receiver<T>) -> Self {
+    /// Create a new AsyncStream from an async-channel receiver
+    pub fn new(receiver: async_channel::Receiver<T>) -> Self {
         Self {
-            receiver,
-            waker: Arc::new(Mutex::new(None)),
+            receiver: Box::pin(receiver),
         }
     }
 
-    /// Create an AsyncStream from a futures Stream
-    pub fn from_stream<S>(stream: S) -> super::AsyncTask<Vec<T>>
-    where
-        S: Stream<Item = T> + Send + 'static,
-        T: Send + 'static,
-    {
-        let (tx, rx) = channel::unbounded();
+    /// Create an unbounded AsyncStream along with the producer feeding it
+    pub(crate) fn unbounded() -> (Producer<T>, Self) {
+        let (tx, rx) = async_channel::unbounded();
+        (Producer(tx), Self::new(rx))
+    }
 
-        std::thread::spawn(move || {
-            use futures::StreamExt;
-            let rt = tokio::runtime::Handle::current();
-            rt.block_on(async move {
-                let mut stream = std::pin::pin!(stream);
-                while let Some(item) = stream.next().await {
-                    if tx.send(item).is_err() {
-                        break;
-                    }
-                }
-            });
-        });
+    /// Create an AsyncStream fed by a channel holding at most `capacity` items (at least 1)
+    ///
+    /// `policy` decides what the returned sender does when the stream falls behind and the
+    /// channel is full, so producers never buffer without bound.
+    pub fn bounded(capacity: usize, policy: OverflowPolicy) -> (BoundedSender<T>, Self) {
+        let (tx, rx) = bounded::channel(capacity, policy);
+        (tx, Self::new(rx))
+    }
+
+    /// Returns the number of items waiting to be read
+    pub fn len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    /// Returns true if no items are waiting to be read
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
 
-        AsyncStream::new(rx).collect_async()
+    /// Returns the maximum number of buffered items, or `None` for an unbounded stream
+    pub fn capacity(&self) -> Option<usize> {
+        self.receiver.capacity()
     }
 
-    /// Collect all items from the stream into a Vec
-    pub fn collect_async(self) -> super::AsyncTask<Vec<T>>
+    /// Returns true if the stream is bounded and its channel is full
+    pub fn is_full(&self) -> bool {
+        self.receiver.is_full()
+    }
+
+    /// Create an AsyncStream from a crossbeam channel receiver
+    ///
+    /// Crossbeam receivers cannot wake a future, so the receiver is checked from the
+    /// AsyncTask executor, pausing up to 16ms while it is empty. Checking stops once the
+    /// stream is dropped or every sender is gone.
+    pub fn from_receiver(receiver: crossbeam::channel::Receiver<T>) -> Self
     where
         T: Send + 'static,
     {
-        super::AsyncTask::new(async move {
-            let mut items = Vec::new();
-            let receiver = self.receiver;
+        Self::produce(move |tx| async move {
+            use crossbeam::channel::TryRecvError;
 
-            while let Ok(item) = receiver.recv() {
-                items.push(item);
+            let mut interval = Duration::from_micros(250);
+            loop {
+                match receiver.try_recv() {
+                    Ok(item) => {
+                        if tx.send(item).is_err() {
+                            return;
+                        }
+                        interval = Duration::from_micros(250);
+                    }
+                    Err(TryRecvError::Disconnected) => return,
+                    Err(TryRecvError::Empty) if tx.is_closed() => return,
+                    Err(TryRecvError::Empty) => {
+                        sugars_async_task::backend::sleep(interval).await;
+                        interval = (interval * 2).min(MAX_POLL_INTERVAL);
+                    }
+                }
             }
-
-            items
         })
     }
+
+    /// Create an AsyncStream that completes without yielding any items
+    pub fn empty() -> Self {
+        let (_tx, rx) = async_channel::unbounded();
+        Self::new(rx)
+    }
+
+    /// Create an AsyncStream that yields a single item
+    pub fn once(value: T) -> Self {
+        let (tx, rx) = async_channel::unbounded();
+        let _ = tx.try_send(value);
+        Self::new(rx)
+    }
+}
+
+impl<T> FromIterator<T> for AsyncStream<T>
+where
+    T: NotResult,
+{
+    /// Create an AsyncStream that yields every item of an iterator
+    ///
+    /// Every item is buffered up front, so the iterator must be finite; use
+    /// `AsyncStream::iter` to stream a long or endless iterator lazily.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let (tx, rx) = async_channel::unbounded();
+        for item in iter {
+            let _ = tx.try_send(item);
+        }
+        Self::new(rx)
+    }
 }
 
 impl<T> Stream for AsyncStream<T>
@@ -80,16 +157,41 @@ where
 {
     type Item = T;
 
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        // Store the waker for crossbeam channel notifications
-        if let Ok(mut waker_guard) = self.waker.lock() {
-            *waker_guard = Some(cx.waker().clone());
-        }
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use pretty_assertions::assert_eq;
+    use std::time::Instant;
+
+    #[test]
+    fn test_from_crossbeam_receiver_yields_until_disconnected() {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let stream = AsyncStream::from_receiver(rx);
+        std::thread::spawn(move || {
+            for item in 0..3 {
+                std::thread::sleep(Duration::from_millis(5));
+                tx.send(item).unwrap();
+            }
+        });
+        let items: Vec<u32> = futures::executor::block_on(stream.collect());
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_dropped_stream_releases_crossbeam_receiver() {
+        let (tx, rx) = crossbeam::channel::unbounded::<u32>();
+        drop(AsyncStream::from_receiver(rx));
 
-        match self.receiver.try_recv() {
-            Ok(item) => Poll::Ready(Some(item)),
-            Err(channel::TryRecvError::Empty) => Poll::Pending,
-            Err(channel::TryRecvError::Disconnected) => Poll::Ready(None),
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while tx.send(1).is_ok() {
+            assert!(Instant::now() < deadline, "receiver was never released");
+            std::thread::sleep(Duration::from_millis(5));
         }
     }
 }
@@ -4,16 +4,60 @@
 //! - tokio-async: Uses tokio::sync::mpsc for Tokio ecosystem
 //! - std-async: Uses async-channel (runtime-agnostic)  
 //! - crossbeam-async: Uses async-channel + crossbeam for compute-heavy workloads
+//!
+//! When several backends are enabled, tokio takes precedence over std, and std over
+//! crossbeam. With none enabled, the std backend is used.
+
+/// How `AsyncStream::select_with_strategy` picks between streams that are ready together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectStrategy {
+    /// Take turns, starting after the stream that produced the previous item
+    #[default]
+    RoundRobin,
+    /// Always prefer the earliest stream in the list that has an item ready
+    Priority,
+}
+
+/// What a bounded AsyncStream's sender does when the channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Wait until the stream reads an item and frees a slot
+    #[default]
+    Block,
+    /// Discard the oldest buffered item to make room for the new one
+    DropOldest,
+    /// Discard the new item, keeping what is already buffered
+    DropNewest,
+    /// Reject the new item with `SendError::Full`
+    Error,
+}
 
+pub mod bounded;
+mod combinators;
+
+pub use bounded::{BoundedSender, SendError};
 #[cfg(feature = "tokio-backend")]
 pub mod tokio;
 #[cfg(feature = "tokio-backend")]
 pub use tokio::AsyncStream;
+#[cfg(feature = "tokio-backend")]
+use tokio::Producer;
 
-#[cfg(all(feature = "std-backend", not(feature = "tokio-backend")))]
+#[cfg(all(
+    not(feature = "tokio-backend"),
+    any(feature = "std-backend", not(feature = "crossbeam-backend"))
+))]
 pub mod std;
-#[cfg(all(feature = "std-backend", not(feature = "tokio-backend")))]
+#[cfg(all(
+    not(feature = "tokio-backend"),
+    any(feature = "std-backend", not(feature = "crossbeam-backend"))
+))]
 pub use std::AsyncStream;
+#[cfg(all(
+    not(feature = "tokio-backend"),
+    any(feature = "std-backend", not(feature = "crossbeam-backend"))
+))]
+use std::Producer;
 
 #[cfg(all(
     feature = "crossbeam-backend",
@@ -27,3 +71,9 @@ pub mod crossbeam;
     not(feature = "std-backend")
 ))]
 pub use crossbeam::AsyncStream;
+#[cfg(all(
+    feature = "crossbeam-backend",
+    not(feature = "tokio-backend"),
+    not(feature = "std-backend")
+))]
+use crossbeam::Producer;
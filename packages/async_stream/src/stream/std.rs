@@ -1,11 +1,23 @@
 //! Standard library async stream implementation using async-channel
 
-use async_channel;
 use futures::Stream;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use sugars_async_task::NotResult;
 
+use super::OverflowPolicy;
+use super::bounded::{self, BoundedSender};
+
+/// Sending half of an unbounded AsyncStream, used by the combinators to feed their output
+pub(crate) struct Producer<T>(async_channel::Sender<T>);
+
+impl<T> Producer<T> {
+    /// Queues `item`, handing it back if the stream has been dropped
+    pub(crate) fn send(&self, item: T) -> Result<(), T> {
+        self.0.try_send(item).map_err(|error| error.into_inner())
+    }
+}
+
 /// Generic async stream wrapper for streaming operations with async-channel
 ///
 /// IMPORTANT: AsyncStream must never contain Result types - all error handling
@@ -14,7 +26,8 @@ pub struct AsyncStream<T>
 where
     T: NotResult, // T cannot be any Result type
 {
-    receiver: async_channel::Receiver<T>,
+    // Boxed because async-channel's receiver is not Unpin
+    receiver: Pin<Box<async_channel::Receiver<T>>>,
 }
 
 impl<T> AsyncStream<T>
@@ -23,47 +36,79 @@ where
 {
     /// Create a new AsyncStream from an async-channel receiver
     pub fn new(receiver: async_channel::Receiver<T>) -> Self {
-        Self { receiver }
+        Self {
+            receiver: Box::pin(receiver),
+        }
     }
 
-    /// Create an AsyncStream from a futures Stream
-    pub fn from_stream<S>(stream: S) -> super::AsyncTask<Vec<T>>
-    where
-        S: Stream<Item = T> + Send + 'static,
-        T: Send + 'static,
-    {
+    /// Create an unbounded AsyncStream along with the producer feeding it
+    pub(crate) fn unbounded() -> (Producer<T>, Self) {
         let (tx, rx) = async_channel::unbounded();
+        (Producer(tx), Self::new(rx))
+    }
+
+    /// Create an AsyncStream fed by a channel holding at most `capacity` items (at least 1)
+    ///
+    /// `policy` decides what the returned sender does when the stream falls behind and the
+    /// channel is full, so producers never buffer without bound.
+    pub fn bounded(capacity: usize, policy: OverflowPolicy) -> (BoundedSender<T>, Self) {
+        let (tx, rx) = bounded::channel(capacity, policy);
+        (tx, Self::new(rx))
+    }
+
+    /// Returns the number of items waiting to be read
+    pub fn len(&self) -> usize {
+        self.receiver.len()
+    }
+
+    /// Returns true if no items are waiting to be read
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+
+    /// Returns the maximum number of buffered items, or `None` for an unbounded stream
+    pub fn capacity(&self) -> Option<usize> {
+        self.receiver.capacity()
+    }
+
+    /// Returns true if the stream is bounded and its channel is full
+    pub fn is_full(&self) -> bool {
+        self.receiver.is_full()
+    }
+
+    /// Create an AsyncStream from an async-channel receiver (bounded or unbounded)
+    pub fn from_receiver(receiver: async_channel::Receiver<T>) -> Self {
+        Self::new(receiver)
+    }
+
+    /// Create an AsyncStream that completes without yielding any items
+    pub fn empty() -> Self {
+        let (_tx, rx) = async_channel::unbounded();
+        Self::new(rx)
+    }
 
-        std::thread::spawn(move || {
-            use futures::StreamExt;
-            futures::executor::block_on(async move {
-                let mut stream = std::pin::pin!(stream);
-                while let Some(item) = stream.next().await {
-                    if tx.send(item).await.is_err() {
-                        break;
-                    }
-                }
-            });
-        });
-
-        AsyncStream::new(rx).collect_async()
+    /// Create an AsyncStream that yields a single item
+    pub fn once(value: T) -> Self {
+        let (tx, rx) = async_channel::unbounded();
+        let _ = tx.try_send(value);
+        Self::new(rx)
     }
+}
 
-    /// Collect all items from the stream into a Vec
-    pub fn collect_async(self) -> super::AsyncTask<Vec<T>>
-    where
-        T: Send + 'static,
-    {
-        super::AsyncTask::new(async move {
-            let mut items = Vec::new();
-            let receiver = self.receiver;
-
-            while let Ok(item) = receiver.recv().await {
-                items.push(item);
-            }
-
-            items
-        })
+impl<T> FromIterator<T> for AsyncStream<T>
+where
+    T: NotResult,
+{
+    /// Create an AsyncStream that yields every item of an iterator
+    ///
+    /// Every item is buffered up front, so the iterator must be finite; use
+    /// `AsyncStream::iter` to stream a long or endless iterator lazily.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let (tx, rx) = async_channel::unbounded();
+        for item in iter {
+            let _ = tx.try_send(item);
+        }
+        Self::new(rx)
     }
 }
 
@@ -73,13 +118,26 @@ where
 {
     type Item = T;
 
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        use std::pin::Pin;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.as_mut().poll_next(cx)
+    }
+}
 
-        match Pin::new(&mut self.get_mut().receiver).poll_next(cx) {
-            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
-            Poll::Ready(None) => Poll::Ready(None),
-            Poll::Pending => Poll::Pending,
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_receiver_reads_an_async_channel() {
+        let (tx, rx) = async_channel::bounded(2);
+        let stream = AsyncStream::from_receiver(rx);
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert_eq!(stream.capacity(), Some(2));
+        assert!(stream.is_full());
+        drop(tx);
+        let items = futures::executor::block_on(futures::StreamExt::collect::<Vec<_>>(stream));
+        assert_eq!(items, vec![1, 2]);
     }
 }
@@ -3,9 +3,29 @@
 use futures::Stream;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use sugars_async_task::{AsyncTask, NotResult};
-use sugars_collections::ZeroOneOrMany;
+use sugars_async_task::NotResult;
 use tokio::sync::mpsc;
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+
+use super::OverflowPolicy;
+use super::bounded::{self, BoundedReceiver, BoundedSender};
+
+/// Channel an AsyncStream reads from
+enum Receiver<T> {
+    Unbounded(mpsc::UnboundedReceiver<T>),
+    Bounded(BoundedReceiver<T>),
+    Mpsc(mpsc::Receiver<T>),
+}
+
+/// Sending half of an unbounded AsyncStream, used by the combinators to feed their output
+pub(crate) struct Producer<T>(mpsc::UnboundedSender<T>);
+
+impl<T> Producer<T> {
+    /// Queues `item`, handing it back if the stream has been dropped
+    pub(crate) fn send(&self, item: T) -> Result<(), T> {
+        self.0.send(item).map_err(|error| error.0)
+    }
+}
 
 /// Generic async stream wrapper for streaming operations with Tokio
 ///
@@ -15,7 +35,7 @@ pub struct AsyncStream<T>
 where
     T: NotResult, // T cannot be any Result type
 {
-    receiver: mpsc::UnboundedReceiver<T>,
+    receiver: Receiver<T>,
 }
 
 impl<T> AsyncStream<T>
@@ -24,47 +44,117 @@ where
 {
     /// Create a new AsyncStream from an unbounded receiver
     pub fn new(receiver: mpsc::UnboundedReceiver<T>) -> Self {
-        Self { receiver }
-    }
-
-    /// Create an AsyncStream from a futures Stream
-    pub fn from_stream<S>(stream: S) -> AsyncTask<Vec<T>>
-    where
-        S: Stream<Item = T> + Send + 'static,
-        T: Send + 'static,
-    {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-
-        tokio::spawn(async move {
-            use futures::StreamExt;
-            let mut stream = std::pin::pin!(stream);
-            let mut items = Vec::new();
-            while let Some(item) = stream.next().await {
-                items.push(item);
-            }
-            let _ = tx.send(items);
-        });
-
-        AsyncTask::new(ZeroOneOrMany::one(rx))
-    }
-
-    /// Collect all items from the stream into a Vec
-    pub fn collect_async(self) -> AsyncTask<Vec<T>>
-    where
-        T: Send + 'static,
-    {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        let mut receiver = self.receiver;
-
-        tokio::spawn(async move {
-            let mut items = Vec::new();
-            while let Some(item) = receiver.recv().await {
-                items.push(item);
-            }
-            let _ = tx.send(items);
-        });
-
-        AsyncTask::new(ZeroOneOrMany::one(rx))
+        Self {
+            receiver: Receiver::Unbounded(receiver),
+        }
+    }
+
+    /// Create an unbounded AsyncStream along with the producer feeding it
+    pub(crate) fn unbounded() -> (Producer<T>, Self) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Producer(tx), Self::new(rx))
+    }
+
+    /// Create an AsyncStream fed by a channel holding at most `capacity` items (at least 1)
+    ///
+    /// `policy` decides what the returned sender does when the stream falls behind and the
+    /// channel is full, so producers never buffer without bound.
+    pub fn bounded(capacity: usize, policy: OverflowPolicy) -> (BoundedSender<T>, Self) {
+        let (tx, rx) = bounded::channel(capacity, policy);
+        (
+            tx,
+            Self {
+                receiver: Receiver::Bounded(rx),
+            },
+        )
+    }
+
+    /// Returns the number of items waiting to be read
+    pub fn len(&self) -> usize {
+        match &self.receiver {
+            Receiver::Unbounded(receiver) => receiver.len(),
+            Receiver::Bounded(receiver) => receiver.len(),
+            Receiver::Mpsc(receiver) => receiver.len(),
+        }
+    }
+
+    /// Returns true if no items are waiting to be read
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of buffered items, or `None` for an unbounded stream
+    pub fn capacity(&self) -> Option<usize> {
+        match &self.receiver {
+            Receiver::Unbounded(_) => None,
+            Receiver::Bounded(receiver) => Some(receiver.capacity()),
+            Receiver::Mpsc(receiver) => Some(receiver.max_capacity()),
+        }
+    }
+
+    /// Returns true if the stream is bounded and its channel is full
+    pub fn is_full(&self) -> bool {
+        self.capacity()
+            .is_some_and(|capacity| self.len() >= capacity)
+    }
+
+    /// Create an AsyncStream that reads directly from a bounded tokio mpsc receiver
+    ///
+    /// The receiver keeps its own capacity, so senders still wait when the stream falls behind.
+    pub fn from_receiver(receiver: mpsc::Receiver<T>) -> Self {
+        Self {
+            receiver: Receiver::Mpsc(receiver),
+        }
+    }
+
+    /// Create an AsyncStream that completes without yielding any items
+    pub fn empty() -> Self {
+        let (_tx, rx) = mpsc::unbounded_channel();
+        Self::new(rx)
+    }
+
+    /// Create an AsyncStream that yields a single item
+    pub fn once(value: T) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let _ = tx.send(value);
+        Self::new(rx)
+    }
+}
+
+impl<T> From<UnboundedReceiverStream<T>> for AsyncStream<T>
+where
+    T: NotResult,
+{
+    /// Read directly from the wrapped unbounded receiver
+    fn from(stream: UnboundedReceiverStream<T>) -> Self {
+        Self::new(stream.into_inner())
+    }
+}
+
+impl<T> From<ReceiverStream<T>> for AsyncStream<T>
+where
+    T: NotResult,
+{
+    /// Read directly from the wrapped bounded receiver, like `from_receiver`
+    fn from(stream: ReceiverStream<T>) -> Self {
+        Self::from_receiver(stream.into_inner())
+    }
+}
+
+impl<T> FromIterator<T> for AsyncStream<T>
+where
+    T: NotResult,
+{
+    /// Create an AsyncStream that yields every item of an iterator
+    ///
+    /// Every item is buffered up front, so the iterator must be finite; use
+    /// `AsyncStream::iter` to stream a long or endless iterator lazily.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        for item in iter {
+            let _ = tx.send(item);
+        }
+        Self::new(rx)
     }
 }
 
@@ -75,6 +165,44 @@ where
     type Item = T;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.receiver.poll_recv(cx)
+        match &mut self.receiver {
+            Receiver::Unbounded(receiver) => receiver.poll_recv(cx),
+            Receiver::Bounded(receiver) => receiver.poll_recv(cx),
+            Receiver::Mpsc(receiver) => receiver.poll_recv(cx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_receiver_needs_no_runtime() {
+        let (tx, rx) = mpsc::channel(2);
+        let stream = AsyncStream::from_receiver(rx);
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert!(stream.is_full());
+        drop(tx);
+        let items = futures::executor::block_on(futures::StreamExt::collect::<Vec<_>>(stream));
+        assert_eq!(items, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_from_tokio_stream_wrappers() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tx.send(1).unwrap();
+        drop(tx);
+        let unbounded = AsyncStream::from(UnboundedReceiverStream::new(rx));
+        assert_eq!(unbounded.collect_async().await, vec![1]);
+
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(2).await.unwrap();
+        drop(tx);
+        let bounded = AsyncStream::from(ReceiverStream::new(rx));
+        assert_eq!(bounded.capacity(), Some(4));
+        assert_eq!(bounded.collect_async().await, vec![2]);
     }
 }
@@ -2,12 +2,27 @@
 
 use crate::AsyncStream;
 use core::future::Future;
+use futures::future::{self, Either};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 use std::vec::Vec;
-use sugars_async_task::{AsyncTask, NotResult};
-use sugars_collections::ZeroOneOrMany;
+use sugars_async_task::{AsyncTask, NotResult, backend};
+use sugars_collections::OneOrMany;
 
 type Error = Box<dyn std::error::Error + Send + Sync>;
 
+/// Waits for the next item, or returns `None` if `deadline` passes first
+async fn next_before<S>(stream: &mut S, deadline: Instant) -> Option<Option<S::Item>>
+where
+    S: futures::Stream + Unpin,
+{
+    let timer = std::pin::pin!(backend::sleep_until(deadline));
+    match future::select(futures::StreamExt::next(stream), timer).await {
+        Either::Left((item, _)) => Some(item),
+        Either::Right(_) => None,
+    }
+}
+
 //────────────────────────────────────────────────────────────────────────────
 // StreamExt – Fluent ops for AsyncStream<T>
 //────────────────────────────────────────────────────────────────────────────
@@ -58,6 +73,45 @@ pub trait StreamExt<T>: Sized + 'static {
     where
         Vec<T>: NotResult;
 
+    /// Groups items into batches of `size` (at least 1); the last batch may be shorter.
+    fn chunks(self, size: usize) -> AsyncStream<OneOrMany<T>>
+    where
+        OneOrMany<T>: NotResult;
+
+    /// Groups items into batches of up to `size`, emitting a partial batch once `timeout`
+    /// has passed since its first item arrived.
+    ///
+    /// Useful for forwarding tokens or progress events to a UI at a bounded rate.
+    fn chunks_timeout(self, size: usize, timeout: Duration) -> AsyncStream<OneOrMany<T>>
+    where
+        OneOrMany<T>: NotResult;
+
+    /// Emits every run of `size` (at least 1) consecutive items, sliding by one item.
+    ///
+    /// Like `slice::windows`, a stream shorter than `size` yields nothing.
+    fn windows(self, size: usize) -> AsyncStream<OneOrMany<T>>
+    where
+        OneOrMany<T>: NotResult;
+
+    /// Emits an item, then drops every item that arrives within `period` of it.
+    fn throttle(self, period: Duration) -> AsyncStream<T>
+    where
+        T: NotResult;
+
+    /// Emits an item only once `quiet` has passed without a newer one arriving.
+    ///
+    /// The pending item is emitted when the stream ends.
+    fn debounce(self, quiet: Duration) -> AsyncStream<T>
+    where
+        T: NotResult;
+
+    /// Emits the most recent item, if any arrived, once every `period`.
+    ///
+    /// The pending item is emitted when the stream ends, so the final value is never lost.
+    fn sample(self, period: Duration) -> AsyncStream<T>
+    where
+        T: NotResult;
+
     /// Terminates the stream by collecting all values into a Vec.
     fn collect(self) -> AsyncTask<Vec<T>>
     where
@@ -82,9 +136,7 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
     where
         F: FnMut(Result<T, Error>) -> Result<T, Error> + Send + 'static,
     {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             while let Some(item) = stream.next().await {
@@ -97,9 +149,7 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
                     Err(_) => break,
                 }
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 
     fn on_chunk<F, U>(self, mut f: F) -> AsyncStream<U>
@@ -108,9 +158,7 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
         F: FnMut(Result<T, Error>) -> U + Send + 'static,
         U: Send + 'static + NotResult,
     {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             while let Some(item) = stream.next().await {
@@ -119,9 +167,7 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
                     break;
                 }
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 
     fn on_error<F>(self, _f: F) -> AsyncStream<T>
@@ -134,9 +180,7 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
     }
 
     fn tap_each(self, mut f: impl FnMut(&T) + Send + 'static) -> AsyncStream<T> {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             while let Some(item) = stream.next().await {
@@ -145,15 +189,11 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
                     break;
                 }
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 
     fn tee_each(self, mut f: impl FnMut(T) + Send + 'static) -> AsyncStream<T> {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             while let Some(item) = stream.next().await {
@@ -162,18 +202,14 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
                     break;
                 }
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 
     fn map_stream<U: Send + 'static + NotResult>(
         self,
         mut f: impl FnMut(T) -> U + Send + 'static,
     ) -> AsyncStream<U> {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             while let Some(item) = stream.next().await {
@@ -181,15 +217,11 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
                     break;
                 }
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 
     fn filter_stream(self, mut f: impl FnMut(&T) -> bool + Send + 'static) -> AsyncStream<T> {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             while let Some(item) = stream.next().await {
@@ -197,18 +229,14 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
                     break;
                 }
             }
-        });
-
-        AsyncStream::new(rx)
+        })
     }
 
     fn partition_chunks(self, chunk_size: usize) -> AsyncStream<Vec<T>>
     where
         Vec<T>: NotResult,
     {
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        tokio::spawn(async move {
+        AsyncStream::produce(move |tx| async move {
             use futures::StreamExt;
             let mut stream = self;
             let mut buffer = Vec::with_capacity(chunk_size);
@@ -227,9 +255,192 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
             if !buffer.is_empty() {
                 let _ = tx.send(buffer);
             }
-        });
+        })
+    }
+
+    fn chunks(self, size: usize) -> AsyncStream<OneOrMany<T>>
+    where
+        OneOrMany<T>: NotResult,
+    {
+        let size = size.max(1);
+        AsyncStream::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut stream = self;
+            let mut buffer = Vec::with_capacity(size);
+
+            while let Some(item) = stream.next().await {
+                buffer.push(item);
+                if buffer.len() >= size {
+                    let batch = std::mem::replace(&mut buffer, Vec::with_capacity(size));
+                    if let Ok(batch) = OneOrMany::many(batch)
+                        && tx.send(batch).is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+
+            // Send remaining items
+            if let Ok(batch) = OneOrMany::many(buffer) {
+                let _ = tx.send(batch);
+            }
+        })
+    }
+
+    fn chunks_timeout(self, size: usize, timeout: Duration) -> AsyncStream<OneOrMany<T>>
+    where
+        OneOrMany<T>: NotResult,
+    {
+        let size = size.max(1);
+        AsyncStream::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut stream = self;
+            let mut buffer = Vec::with_capacity(size);
+            let mut deadline = None;
+
+            loop {
+                // `None` means the pending batch timed out before the next item arrived
+                let next = match deadline {
+                    Some(deadline) => next_before(&mut stream, deadline).await,
+                    None => Some(stream.next().await),
+                };
+
+                let ended = match next {
+                    Some(Some(item)) => {
+                        if buffer.is_empty() {
+                            deadline = Some(backend::now() + timeout);
+                        }
+                        buffer.push(item);
+                        if buffer.len() < size {
+                            continue;
+                        }
+                        false
+                    }
+                    Some(None) => true,
+                    None => false,
+                };
+
+                deadline = None;
+                let batch = std::mem::replace(&mut buffer, Vec::with_capacity(size));
+                if let Ok(batch) = OneOrMany::many(batch)
+                    && tx.send(batch).is_err()
+                {
+                    return;
+                }
+                if ended {
+                    return;
+                }
+            }
+        })
+    }
+
+    fn windows(self, size: usize) -> AsyncStream<OneOrMany<T>>
+    where
+        OneOrMany<T>: NotResult,
+    {
+        let size = size.max(1);
+        AsyncStream::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut stream = self;
+            let mut window = VecDeque::with_capacity(size);
+
+            while let Some(item) = stream.next().await {
+                if window.len() == size {
+                    window.pop_front();
+                }
+                window.push_back(item);
+                if window.len() == size {
+                    let batch = window.iter().cloned().collect::<OneOrMany<T>>();
+                    if tx.send(batch).is_err() {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    fn throttle(self, period: Duration) -> AsyncStream<T> {
+        AsyncStream::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut stream = self;
+            let mut next_allowed = backend::now();
+
+            while let Some(item) = stream.next().await {
+                let now = backend::now();
+                if now < next_allowed {
+                    continue;
+                }
+                next_allowed = now + period;
+                if tx.send(item).is_err() {
+                    break;
+                }
+            }
+        })
+    }
 
-        AsyncStream::new(rx)
+    fn debounce(self, quiet: Duration) -> AsyncStream<T> {
+        AsyncStream::produce(move |tx| async move {
+            use futures::StreamExt;
+            let mut stream = self;
+            let mut pending = None;
+            let mut deadline = None;
+
+            loop {
+                // `None` means the pending item stayed quiet until its deadline
+                let next = match deadline {
+                    Some(deadline) => next_before(&mut stream, deadline).await,
+                    None => Some(stream.next().await),
+                };
+
+                match next {
+                    Some(Some(item)) => {
+                        pending = Some(item);
+                        deadline = Some(backend::now() + quiet);
+                    }
+                    Some(None) => break,
+                    None => {
+                        deadline = None;
+                        if let Some(item) = pending.take()
+                            && tx.send(item).is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+
+            if let Some(item) = pending {
+                let _ = tx.send(item);
+            }
+        })
+    }
+
+    fn sample(self, period: Duration) -> AsyncStream<T> {
+        AsyncStream::produce(move |tx| async move {
+            let mut stream = self;
+            let mut latest = None;
+            let mut tick = backend::now() + period;
+
+            loop {
+                match next_before(&mut stream, tick).await {
+                    Some(Some(item)) => latest = Some(item),
+                    Some(None) => break,
+                    None => {
+                        // A late tick pushes the next one back rather than firing twice
+                        tick = backend::now() + period;
+                        if let Some(item) = latest.take()
+                            && tx.send(item).is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+
+            if let Some(item) = latest {
+                let _ = tx.send(item);
+            }
+        })
     }
 
     fn collect(self) -> AsyncTask<Vec<T>> {
@@ -241,9 +452,7 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
         F: FnMut(T) -> Fut + Send + 'static,
         Fut: Future<Output = Result<(), Error>> + Send + 'static,
     {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        let _ = tx.send(());
-        AsyncTask::new(ZeroOneOrMany::one(rx))
+        AsyncTask::from_value(())
     }
 
     fn await_ok<F, Fut>(self, mut _f: F) -> AsyncTask<()>
@@ -251,8 +460,107 @@ impl<T: Clone + Send + 'static + NotResult> StreamExt<T> for AsyncStream<T> {
         F: FnMut(T) -> Fut + Send + 'static,
         Fut: Future<Output = ()> + Send + 'static,
     {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        let _ = tx.send(());
-        AsyncTask::new(ZeroOneOrMany::one(rx))
+        AsyncTask::from_value(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Length of one test tick; real clocks need more slack than Tokio's paused one
+    #[cfg(feature = "tokio-backend")]
+    const TICK: Duration = Duration::from_millis(10);
+    #[cfg(not(feature = "tokio-backend"))]
+    const TICK: Duration = Duration::from_millis(40);
+
+    /// Emits each item after waiting the given number of ticks since the previous one
+    fn timed(items: Vec<(u32, u32)>) -> AsyncStream<u32> {
+        use futures::StreamExt as _;
+        let delayed = futures::stream::iter(items).then(|(ticks, item)| async move {
+            backend::sleep(TICK * ticks).await;
+            item
+        });
+        AsyncStream::from_futures_stream(delayed)
+    }
+
+    /// Collects the batches of a stream as plain vectors
+    async fn batches(stream: AsyncStream<OneOrMany<u32>>) -> Vec<Vec<u32>> {
+        let batches = stream.collect().await;
+        batches.into_iter().map(|batch| batch.into_iter().collect()).collect()
+    }
+
+    backend_test! {
+        async fn test_chunks_of_exact_multiples() {
+            let chunks = batches(AsyncStream::iter(1..=6).chunks(3)).await;
+            assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        }
+
+        async fn test_chunks_emit_a_short_trailing_batch() {
+            let chunks = batches(AsyncStream::iter(1..=7).chunks(3)).await;
+            assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+            assert_eq!(batches(AsyncStream::<u32>::empty().chunks(3)).await, Vec::<Vec<u32>>::new());
+        }
+
+        async fn test_zero_sizes_are_clamped_to_one() {
+            assert_eq!(batches(AsyncStream::iter(1..=3).chunks(0)).await, vec![vec![1], vec![2], vec![3]]);
+            assert_eq!(batches(AsyncStream::iter(1..=3).windows(0)).await, vec![vec![1], vec![2], vec![3]]);
+            let chunks = batches(AsyncStream::iter(1..=2).chunks_timeout(0, TICK)).await;
+            assert_eq!(chunks, vec![vec![1], vec![2]]);
+        }
+
+        async fn test_chunks_timeout_flushes_a_partial_batch() {
+            // Items at ticks 0 and 1 time out at tick 3; 3, 4 and 5 arrive together at tick 6
+            let stream = timed(vec![(0, 1), (1, 2), (5, 3), (0, 4), (0, 5), (1, 6)]);
+            let chunks = batches(stream.chunks_timeout(3, TICK * 3)).await;
+            assert_eq!(chunks, vec![vec![1, 2], vec![3, 4, 5], vec![6]]);
+        }
+
+        async fn test_windows_slide_by_one_item() {
+            let windows = batches(AsyncStream::iter(1..=4).windows(2)).await;
+            assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+            assert_eq!(batches(AsyncStream::iter(1..=3).windows(3)).await, vec![vec![1, 2, 3]]);
+        }
+
+        async fn test_windows_of_a_short_stream_yield_nothing() {
+            assert_eq!(batches(AsyncStream::iter(1..=2).windows(3)).await, Vec::<Vec<u32>>::new());
+            assert_eq!(batches(AsyncStream::<u32>::empty().windows(1)).await, Vec::<Vec<u32>>::new());
+        }
+
+        async fn test_throttle_drops_items_inside_the_period() {
+            // Items at ticks 0, 1, 2, 4 and 5 with a 3-tick period
+            let stream = timed(vec![(0, 1), (1, 2), (1, 3), (2, 4), (1, 5)]);
+            let throttled = stream.throttle(TICK * 3).collect().await;
+            assert_eq!(throttled, vec![1, 4]);
+        }
+
+        async fn test_debounce_emits_after_quiet_period_and_at_end() {
+            // Items at ticks 0, 1, 5 and 6 with a 2-tick quiet period
+            let stream = timed(vec![(0, 1), (1, 2), (4, 3), (1, 4)]);
+            let debounced = stream.debounce(TICK * 2).collect().await;
+            assert_eq!(debounced, vec![2, 4]);
+        }
+
+        async fn test_sample_emits_latest_item_each_period() {
+            // Items at ticks 0, 1, 4 and 5, sampled every 3 ticks; 4 is flushed at the end
+            let stream = timed(vec![(0, 1), (1, 2), (3, 3), (1, 4)]);
+            let sampled = stream.sample(TICK * 3).collect().await;
+            assert_eq!(sampled, vec![2, 4]);
+        }
+
+        async fn test_sample_skips_periods_without_items() {
+            // Nothing arrives between ticks 3 and 6, so only 1 and 2 are emitted
+            let stream = timed(vec![(1, 1), (6, 2)]);
+            let sampled = stream.sample(TICK * 3).collect().await;
+            assert_eq!(sampled, vec![1, 2]);
+        }
+
+        async fn test_time_operators_end_with_their_input() {
+            let empty = || AsyncStream::<u32>::empty();
+            assert_eq!(empty().throttle(TICK).collect().await, Vec::<u32>::new());
+            assert_eq!(empty().debounce(TICK).collect().await, Vec::<u32>::new());
+            assert_eq!(empty().sample(TICK).collect().await, Vec::<u32>::new());
+        }
     }
 }
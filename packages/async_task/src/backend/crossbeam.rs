@@ -0,0 +1,171 @@
+//! Crossbeam backend: futures oneshot channels with a crossbeam-fed blocking pool
+//!
+//! Futures run on the same shared pool and timer as the std backend. Blocking work runs
+//! on a fixed set of workers sized for compute-heavy jobs, and `AsyncTask` can be built
+//! straight from a crossbeam channel receiver.
+
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::{self, TryRecvError};
+use futures::future::{self, Either};
+
+use super::timer::Sleep;
+use crate::task::{AsyncTask, NotResult};
+
+pub use super::executor::spawn;
+pub use futures::channel::oneshot;
+
+/// Error seen when an AsyncTask's sender is dropped without sending
+pub type RecvError = oneshot::Canceled;
+
+/// Longest pause between checks of a crossbeam receiver that has nothing ready
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Returns the queue feeding the blocking workers, starting one per CPU on first use
+fn workers() -> &'static channel::Sender<Job> {
+    static WORKERS: OnceLock<channel::Sender<Job>> = OnceLock::new();
+    WORKERS.get_or_init(|| {
+        let (tx, rx) = channel::unbounded::<Job>();
+        let count = std::thread::available_parallelism().map_or(4, |n| n.get());
+        for index in 0..count {
+            let rx = rx.clone();
+            std::thread::Builder::new()
+                .name(format!("sugars-blocking-{index}"))
+                .spawn(move || rx.iter().for_each(|job| job()))
+                .expect("failed to spawn a sugars blocking worker");
+        }
+        tx
+    })
+}
+
+/// Runs `f` on the blocking worker pool
+///
+/// The pool has one worker per CPU, so `f` must not wait on another `spawn_blocking`
+/// closure that may still be queued behind it.
+pub fn spawn_blocking<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    workers()
+        .send(Box::new(f))
+        .expect("sugars blocking workers never shut down");
+}
+
+/// Returns the current time as seen by this backend's timers
+pub fn now() -> Instant {
+    Instant::now()
+}
+
+/// Waits for `duration` on the shared timer
+pub async fn sleep(duration: Duration) {
+    sleep_until(now() + duration).await;
+}
+
+/// Waits until `deadline` on the shared timer
+pub async fn sleep_until(deadline: Instant) {
+    Sleep::until(deadline).await;
+}
+
+/// Returns the output of `future`, or `None` if `deadline` passes first
+///
+/// The timer is cancelled as soon as `future` completes.
+pub async fn timeout_at<F: Future>(deadline: Instant, future: F) -> Option<F::Output> {
+    match future::select(std::pin::pin!(future), Sleep::until(deadline)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+/// Receives the next value from a crossbeam channel without blocking a thread
+///
+/// Crossbeam receivers cannot wake a future, so an empty channel is checked again after a
+/// pause that doubles up to 16ms. Returns `None` once every sender is gone.
+pub async fn recv<T>(receiver: &channel::Receiver<T>) -> Option<T> {
+    let mut interval = Duration::from_micros(250);
+    loop {
+        match receiver.try_recv() {
+            Ok(value) => return Some(value),
+            Err(TryRecvError::Disconnected) => return None,
+            Err(TryRecvError::Empty) => {
+                sleep(interval).await;
+                interval = (interval * 2).min(MAX_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+impl<T> From<channel::Receiver<T>> for AsyncTask<T>
+where
+    T: NotResult + Send + 'static,
+{
+    /// Resolves with the first value received
+    ///
+    /// The receiver is checked from the shared pool, and checking stops as soon as the
+    /// task is dropped.
+    fn from(receiver: channel::Receiver<T>) -> Self {
+        let (mut tx, rx) = oneshot::channel();
+        spawn(async move {
+            let value =
+                match future::select(std::pin::pin!(recv(&receiver)), tx.cancellation()).await {
+                    Either::Left((value, _)) => value,
+                    Either::Right(_) => return,
+                };
+            if let Some(value) = value {
+                let _ = tx.send(value);
+            }
+        });
+        Self::from(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_task_from_crossbeam_receiver() {
+        let (tx, rx) = channel::unbounded();
+        let task = AsyncTask::from(rx);
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            tx.send(7).unwrap();
+        });
+        assert_eq!(futures::executor::block_on(task), 7);
+    }
+
+    #[test]
+    fn test_dropped_task_stops_reading_the_receiver() {
+        let (tx, rx) = channel::unbounded::<u32>();
+        drop(AsyncTask::from(rx));
+
+        // The receiver is dropped once the polling future notices the cancellation
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while tx.send(1).is_ok() {
+            assert!(Instant::now() < deadline, "receiver was never released");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_recv_returns_none_when_disconnected() {
+        let (tx, rx) = channel::unbounded::<u32>();
+        drop(tx);
+        assert_eq!(futures::executor::block_on(recv(&rx)), None);
+    }
+
+    #[test]
+    fn test_spawn_blocking_runs_on_the_pool() {
+        let (tx, rx) = channel::bounded(1);
+        spawn_blocking(move || {
+            tx.send(std::thread::current().name().map(String::from))
+                .unwrap()
+        });
+        let name = rx.recv().unwrap().unwrap_or_default();
+        assert!(name.starts_with("sugars-blocking-"), "ran on {name}");
+    }
+}
@@ -0,0 +1,28 @@
+//! Shared thread pool running futures for the std and crossbeam backends
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use futures::executor::ThreadPool;
+
+/// Returns the process-wide pool, sized to the number of CPUs
+fn pool() -> &'static ThreadPool {
+    static POOL: OnceLock<ThreadPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        ThreadPool::builder()
+            .name_prefix("sugars-async-")
+            .create()
+            .expect("failed to start the sugars async thread pool")
+    })
+}
+
+/// Runs `future` to completion on the shared pool
+///
+/// Futures share a handful of threads, so they must not block; use `spawn_blocking` for
+/// work that does.
+pub fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    pool().spawn_ok(future);
+}
@@ -0,0 +1,52 @@
+//! Runtime backends for AsyncTask
+//!
+//! This module provides feature-gated implementations for different async runtimes:
+//! - tokio-backend: Uses tokio::sync::oneshot and the Tokio scheduler
+//! - std-backend: Uses futures oneshot channels on a shared thread pool (runtime-agnostic)
+//! - crossbeam-backend: Like std-backend, with a fixed blocking pool and receiver bridging
+//!
+//! When several backends are enabled, tokio takes precedence over std, and std over
+//! crossbeam. With none enabled, the std backend is used.
+//!
+//! The selected backend's `spawn`, `sleep` and `timeout_at` are re-exported here so that
+//! crates built on AsyncTask, such as AsyncStream, run on the same executor and clock.
+
+#[cfg(not(feature = "tokio-backend"))]
+mod executor;
+#[cfg(not(feature = "tokio-backend"))]
+mod timer;
+
+#[cfg(feature = "tokio-backend")]
+pub mod tokio;
+#[cfg(feature = "tokio-backend")]
+pub use self::tokio::{
+    RecvError, now, oneshot, sleep, sleep_until, spawn, spawn_blocking, timeout_at,
+};
+
+#[cfg(all(
+    not(feature = "tokio-backend"),
+    any(feature = "std-backend", not(feature = "crossbeam-backend"))
+))]
+pub mod std;
+#[cfg(all(
+    not(feature = "tokio-backend"),
+    any(feature = "std-backend", not(feature = "crossbeam-backend"))
+))]
+pub use self::std::{
+    RecvError, now, oneshot, sleep, sleep_until, spawn, spawn_blocking, timeout_at,
+};
+
+#[cfg(all(
+    feature = "crossbeam-backend",
+    not(feature = "tokio-backend"),
+    not(feature = "std-backend")
+))]
+pub mod crossbeam;
+#[cfg(all(
+    feature = "crossbeam-backend",
+    not(feature = "tokio-backend"),
+    not(feature = "std-backend")
+))]
+pub use self::crossbeam::{
+    RecvError, now, oneshot, sleep, sleep_until, spawn, spawn_blocking, timeout_at,
+};
@@ -0,0 +1,50 @@
+//! Standard library backend: futures oneshot channels on a shared thread pool
+//!
+//! Nothing here depends on a particular executor, so tasks can be awaited from tokio,
+//! async-std, smol or `futures::executor::block_on` alike.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use futures::future::{self, Either};
+
+use super::timer::Sleep;
+
+pub use super::executor::spawn;
+pub use futures::channel::oneshot;
+
+/// Error seen when an AsyncTask's sender is dropped without sending
+pub type RecvError = oneshot::Canceled;
+
+/// Runs `f` on a dedicated thread, so it may block for as long as it needs
+pub fn spawn_blocking<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    std::thread::spawn(f);
+}
+
+/// Returns the current time as seen by this backend's timers
+pub fn now() -> Instant {
+    Instant::now()
+}
+
+/// Waits for `duration` on the shared timer
+pub async fn sleep(duration: Duration) {
+    sleep_until(now() + duration).await;
+}
+
+/// Waits until `deadline` on the shared timer
+pub async fn sleep_until(deadline: Instant) {
+    Sleep::until(deadline).await;
+}
+
+/// Returns the output of `future`, or `None` if `deadline` passes first
+///
+/// The timer is cancelled as soon as `future` completes.
+pub async fn timeout_at<F: Future>(deadline: Instant, future: F) -> Option<F::Output> {
+    match future::select(std::pin::pin!(future), Sleep::until(deadline)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
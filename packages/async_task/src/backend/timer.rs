@@ -0,0 +1,191 @@
+//! Shared timer for the std and crossbeam backends
+//!
+//! Every pending sleep registers its waker with a single timer thread, which wakes it
+//! once the deadline passes. Dropping a sleep removes its registration, so abandoned
+//! timeouts cost nothing after the future they guarded completes.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex, MutexGuard, Once, OnceLock, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::Instant;
+
+/// Deadline plus a sequence number, so sleeps sharing a deadline stay distinct
+type Key = (Instant, u64);
+
+#[derive(Default)]
+struct Entries {
+    wakers: BTreeMap<Key, Waker>,
+    next_id: u64,
+}
+
+struct Timer {
+    entries: Mutex<Entries>,
+    /// Signalled when a sleep registers a deadline earlier than any pending one
+    changed: Condvar,
+}
+
+impl Timer {
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn run(&self) {
+        let mut entries = self.lock();
+        loop {
+            let now = Instant::now();
+            let mut due = Vec::new();
+            while let Some(entry) = entries.wakers.first_entry() {
+                if entry.key().0 > now {
+                    break;
+                }
+                due.push(entry.remove());
+            }
+
+            if !due.is_empty() {
+                // Wake outside the lock, since a waker may poll the sleep right away
+                drop(entries);
+                due.into_iter().for_each(Waker::wake);
+                entries = self.lock();
+                continue;
+            }
+
+            entries = match entries.wakers.first_key_value() {
+                Some(((deadline, _), _)) => {
+                    let wait = deadline.saturating_duration_since(now);
+                    self.changed
+                        .wait_timeout(entries, wait)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(entries)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+}
+
+/// Returns the process-wide timer, starting its thread on first use
+fn timer() -> &'static Timer {
+    static TIMER: OnceLock<Timer> = OnceLock::new();
+    static STARTED: Once = Once::new();
+
+    let timer = TIMER.get_or_init(|| Timer {
+        entries: Mutex::new(Entries::default()),
+        changed: Condvar::new(),
+    });
+    STARTED.call_once(|| {
+        std::thread::Builder::new()
+            .name("sugars-timer".into())
+            .spawn(move || timer.run())
+            .expect("failed to spawn the sugars timer thread");
+    });
+    timer
+}
+
+/// Future that completes once its deadline has passed
+pub(crate) struct Sleep {
+    deadline: Instant,
+    key: Option<Key>,
+}
+
+impl Sleep {
+    pub(crate) fn until(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            key: None,
+        }
+    }
+
+    fn cancel(&mut self) {
+        if let Some(key) = self.key.take() {
+            timer().lock().wakers.remove(&key);
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            self.cancel();
+            return Poll::Ready(());
+        }
+
+        let timer = timer();
+        let mut entries = timer.lock();
+        let key = match self.key {
+            Some(key) => key,
+            None => {
+                let key = (self.deadline, entries.next_id);
+                entries.next_id += 1;
+                self.key = Some(key);
+                key
+            }
+        };
+        let earliest = entries
+            .wakers
+            .first_key_value()
+            .is_none_or(|(first, _)| key <= *first);
+        entries.wakers.insert(key, cx.waker().clone());
+        drop(entries);
+
+        if earliest {
+            timer.changed.notify_one();
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::task::Waker;
+    use std::time::Duration;
+
+    fn registered(sleep: &Sleep) -> bool {
+        sleep
+            .key
+            .is_some_and(|key| timer().lock().wakers.contains_key(&key))
+    }
+
+    #[test]
+    fn test_dropping_a_sleep_cancels_its_timer() {
+        let mut sleep = Sleep::until(Instant::now() + Duration::from_secs(60));
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(Pin::new(&mut sleep).poll(&mut cx), Poll::Pending);
+        assert!(registered(&sleep));
+
+        let key = sleep.key.expect("a pending sleep is registered");
+        drop(sleep);
+        assert!(!timer().lock().wakers.contains_key(&key));
+    }
+
+    #[test]
+    fn test_sleep_wakes_after_deadline() {
+        let started = Instant::now();
+        futures::executor::block_on(Sleep::until(started + Duration::from_millis(20)));
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_elapsed_sleep_is_ready_without_registering() {
+        let mut sleep = Sleep::until(Instant::now());
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(Pin::new(&mut sleep).poll(&mut cx), Poll::Ready(()));
+        assert!(!registered(&sleep));
+    }
+}
@@ -0,0 +1,54 @@
+//! Tokio backend: oneshot channels and tasks on the current Tokio runtime
+//!
+//! Time is read from Tokio's clock, so tests using `tokio::time::pause` control every
+//! timeout and backoff built on this backend.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+pub use ::tokio::sync::oneshot;
+
+/// Error seen when an AsyncTask's sender is dropped without sending
+pub type RecvError = oneshot::error::RecvError;
+
+/// Runs `future` to completion as a Tokio task
+///
+/// Panics if called outside a Tokio runtime.
+pub fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    ::tokio::spawn(future);
+}
+
+/// Runs `f` on Tokio's blocking thread pool
+///
+/// Panics if called outside a Tokio runtime.
+pub fn spawn_blocking<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    ::tokio::task::spawn_blocking(f);
+}
+
+/// Returns the current time as seen by Tokio's clock
+pub fn now() -> Instant {
+    ::tokio::time::Instant::now().into_std()
+}
+
+/// Waits for `duration` using the Tokio timer
+pub async fn sleep(duration: Duration) {
+    ::tokio::time::sleep(duration).await;
+}
+
+/// Waits until `deadline` using the Tokio timer
+pub async fn sleep_until(deadline: Instant) {
+    ::tokio::time::sleep_until(deadline.into()).await;
+}
+
+/// Returns the output of `future`, or `None` if `deadline` passes first
+pub async fn timeout_at<F: Future>(deadline: Instant, future: F) -> Option<F::Output> {
+    ::tokio::time::timeout_at(deadline.into(), future)
+        .await
+        .ok()
+}
@@ -1,7 +1,7 @@
 //! Future extension traits for async future processing
 
+use crate::backend::RecvError;
 use crate::task::{AsyncTask, NotResult};
-use tokio::sync::oneshot;
 
 //────────────────────────────────────────────────────────────────────────────
 // FutureExt – Fluent ops for AsyncTask<T>
@@ -20,10 +20,7 @@ pub trait FutureExt<T>: Sized {
         U: Send + 'static + NotResult;
 
     /// Executes a function when the future encounters an error.
-    fn on_error<U>(
-        self,
-        f: impl FnOnce(oneshot::error::RecvError) -> U + Send + 'static,
-    ) -> AsyncTask<U>
+    fn on_error<U>(self, f: impl FnOnce(RecvError) -> U + Send + 'static) -> AsyncTask<U>
     where
         U: Send + 'static + NotResult,
         T: Into<U>;
@@ -31,7 +28,7 @@ pub trait FutureExt<T>: Sized {
     /// Handles both success and error cases with a result handler.
     fn on_result<U>(
         self,
-        f: impl FnOnce(Result<T, oneshot::error::RecvError>) -> U + Send + 'static,
+        f: impl FnOnce(Result<T, RecvError>) -> U + Send + 'static,
     ) -> AsyncTask<U>
     where
         U: Send + 'static + NotResult;
@@ -47,7 +44,7 @@ pub trait FutureExt<T>: Sized {
         T: NotResult;
 
     /// Applies a function to the error value without consuming it.
-    fn tap_err(self, f: impl FnOnce(&oneshot::error::RecvError) + Send + 'static) -> AsyncTask<T>
+    fn tap_err(self, f: impl FnOnce(&RecvError) + Send + 'static) -> AsyncTask<T>
     where
         T: NotResult;
 }
@@ -71,10 +68,7 @@ impl<T: Send + 'static + NotResult> FutureExt<T> for AsyncTask<T> {
         })
     }
 
-    fn on_error<U>(
-        self,
-        f: impl FnOnce(oneshot::error::RecvError) -> U + Send + 'static,
-    ) -> AsyncTask<U>
+    fn on_error<U>(self, f: impl FnOnce(RecvError) -> U + Send + 'static) -> AsyncTask<U>
     where
         U: Send + 'static + NotResult,
         T: Into<U>,
@@ -87,7 +81,7 @@ impl<T: Send + 'static + NotResult> FutureExt<T> for AsyncTask<T> {
 
     fn on_result<U>(
         self,
-        f: impl FnOnce(Result<T, oneshot::error::RecvError>) -> U + Send + 'static,
+        f: impl FnOnce(Result<T, RecvError>) -> U + Send + 'static,
     ) -> AsyncTask<U>
     where
         U: Send + 'static + NotResult,
@@ -113,7 +107,7 @@ impl<T: Send + 'static + NotResult> FutureExt<T> for AsyncTask<T> {
         })
     }
 
-    fn tap_err(self, f: impl FnOnce(&oneshot::error::RecvError) + Send + 'static) -> AsyncTask<T> {
+    fn tap_err(self, f: impl FnOnce(&RecvError) + Send + 'static) -> AsyncTask<T> {
         AsyncTask::from_future(async move {
             match self.receiver.await {
                 Ok(value) => value,
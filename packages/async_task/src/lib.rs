@@ -1,7 +1,33 @@
 #![feature(auto_traits, negative_impls)]
 
+/// Runs an async test on the selected backend, with Tokio's clock paused
+#[cfg(test)]
+macro_rules! backend_test {
+    ($(async fn $name:ident() $body:block)*) => {$(
+        #[cfg(feature = "tokio-backend")]
+        #[::tokio::test(start_paused = true)]
+        async fn $name() $body
+
+        #[cfg(not(feature = "tokio-backend"))]
+        #[test]
+        fn $name() {
+            futures::executor::block_on(async $body)
+        }
+    )*};
+}
+
+/// Length of one test tick; real clocks need more slack than Tokio's paused one
+#[cfg(all(test, feature = "tokio-backend"))]
+const TEST_TICK: std::time::Duration = std::time::Duration::from_millis(10);
+#[cfg(all(test, not(feature = "tokio-backend")))]
+const TEST_TICK: std::time::Duration = std::time::Duration::from_millis(40);
+
+pub mod backend;
 pub mod future_ext;
+pub mod retry;
 pub mod task;
 
+pub use backend::RecvError;
 pub use future_ext::*;
-pub use task::{AsyncTask, NotResult};
+pub use retry::RetryPolicy;
+pub use task::{AsyncTask, NotResult, TimedOut};
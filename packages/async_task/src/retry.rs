@@ -0,0 +1,247 @@
+//! Retry with exponential backoff for operations producing unwrapped values
+//!
+//! Errors in this crate travel inside values (e.g. a chunk whose `error()` is set), so a
+//! `RetryPolicy` decides whether to retry by inspecting the value an attempt produced.
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::future::Future;
+use std::hash::BuildHasher;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::backend;
+use crate::task::{AsyncTask, NotResult};
+
+/// How often and how patiently `AsyncTask::retrying` repeats an operation
+///
+/// Retry `n` (starting at 1) waits `initial_delay * multiplier^(n - 1)`, capped at
+/// `max_delay`, then reduced by a random fraction of up to `jitter` so that many clients
+/// retrying at once spread out.
+pub struct RetryPolicy<T> {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: f64,
+    retry_if: Arc<dyn Fn(&T) -> bool + Send + Sync>,
+}
+
+impl<T> RetryPolicy<T> {
+    /// Creates a policy making up to `max_attempts` attempts (at least one), retrying
+    /// whenever `retry_if` returns true for the value an attempt produced.
+    ///
+    /// Defaults to a 100ms initial delay doubling up to 10s, with 20% jitter.
+    pub fn new<F>(max_attempts: u32, retry_if: F) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.2,
+            retry_if: Arc::new(retry_if),
+        }
+    }
+
+    /// Sets the delay before the first retry and the cap on later delays.
+    pub fn backoff(mut self, initial_delay: Duration, max_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay.max(initial_delay);
+        self
+    }
+
+    /// Sets the factor each delay grows by; values below 1 are treated as 1.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Sets the largest fraction (0 to 1) randomly taken off each delay.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the maximum number of attempts, including the first.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns whether `value` should be retried, ignoring the attempt limit.
+    pub fn should_retry(&self, value: &T) -> bool {
+        (self.retry_if)(value)
+    }
+
+    /// Returns the delay before retry number `retry` (starting at 1), before jitter.
+    pub fn base_delay(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::try_from_secs_f64(delay)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Returns the delay before retry number `retry` (starting at 1), with jitter applied.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let base = self.base_delay(retry);
+        if self.jitter == 0.0 {
+            return base;
+        }
+        let random = RandomState::new().hash_one(retry) as f64 / u64::MAX as f64;
+        base.mul_f64(1.0 - self.jitter * random)
+    }
+}
+
+impl<T> Clone for RetryPolicy<T> {
+    fn clone(&self) -> Self {
+        Self {
+            max_attempts: self.max_attempts,
+            initial_delay: self.initial_delay,
+            max_delay: self.max_delay,
+            multiplier: self.multiplier,
+            jitter: self.jitter,
+            retry_if: Arc::clone(&self.retry_if),
+        }
+    }
+}
+
+impl<T> fmt::Debug for RetryPolicy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> AsyncTask<T>
+where
+    T: NotResult, // T cannot be any Result type
+{
+    /// Create an AsyncTask that runs `factory` until the policy stops retrying
+    ///
+    /// Each attempt calls `factory` for a fresh future. The task resolves with the first
+    /// value the policy accepts, or with the last attempt's value once attempts run out.
+    pub fn retrying<F, Fut>(mut factory: F, policy: RetryPolicy<T>) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        Self::from_future(async move {
+            let mut attempt = 1;
+            loop {
+                let value = factory().await;
+                if attempt >= policy.max_attempts || !policy.should_retry(&value) {
+                    return value;
+                }
+                backend::sleep(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_TICK as TICK;
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Runs `policy` over an operation that returns its attempt number
+    async fn count_attempts(policy: RetryPolicy<u32>) -> (u32, u32, Duration) {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&attempts);
+        let started = backend::now();
+        let value = AsyncTask::retrying(
+            move || {
+                let attempt = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                async move { attempt }
+            },
+            policy,
+        )
+        .await;
+        let elapsed = backend::now().saturating_duration_since(started);
+        (value, attempts.load(Ordering::SeqCst), elapsed)
+    }
+
+    backend_test! {
+        async fn test_retrying_stops_at_the_first_accepted_value() {
+            let policy = RetryPolicy::new(5, |value: &u32| *value < 3)
+                .backoff(TICK, TICK * 10)
+                .jitter(0.0);
+            let (value, attempts, elapsed) = count_attempts(policy).await;
+            assert_eq!((value, attempts), (3, 3));
+
+            // Waits one tick, then two
+            #[cfg(feature = "tokio-backend")]
+            assert_eq!(elapsed, TICK * 3);
+            #[cfg(not(feature = "tokio-backend"))]
+            assert!(elapsed >= TICK * 3, "elapsed {elapsed:?}");
+        }
+
+        async fn test_retrying_returns_the_last_value_when_attempts_run_out() {
+            let policy = RetryPolicy::new(3, |_: &u32| true)
+                .backoff(TICK, TICK)
+                .jitter(0.0);
+            let (value, attempts, _) = count_attempts(policy).await;
+            assert_eq!((value, attempts), (3, 3));
+        }
+
+        async fn test_retrying_accepts_the_first_value_without_waiting() {
+            let policy = RetryPolicy::new(5, |_: &u32| false).backoff(TICK * 100, TICK * 100);
+            let (value, attempts, elapsed) = count_attempts(policy).await;
+            assert_eq!((value, attempts), (1, 1));
+            assert!(elapsed < TICK * 100, "elapsed {elapsed:?}");
+        }
+    }
+
+    #[test]
+    fn test_base_delay_grows_and_caps() {
+        let policy = RetryPolicy::new(10, |_: &u32| true)
+            .backoff(Duration::from_millis(100), Duration::from_secs(1))
+            .multiplier(2.0);
+        let delays: Vec<_> = (1..=5).map(|retry| policy.base_delay(retry)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+                Duration::from_millis(800),
+                Duration::from_secs(1),
+            ]
+        );
+        assert_eq!(policy.base_delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_jitter_only_shortens_the_delay() {
+        let policy = RetryPolicy::new(10, |_: &u32| true)
+            .backoff(Duration::from_millis(100), Duration::from_secs(1))
+            .jitter(0.5);
+        for retry in 1..=5 {
+            let base = policy.base_delay(retry);
+            let delay = policy.delay(retry);
+            assert!(delay <= base && delay >= base / 2, "{delay:?} vs {base:?}");
+        }
+    }
+
+    #[test]
+    fn test_policy_settings_are_clamped() {
+        let policy = RetryPolicy::new(0, |value: &u32| *value > 1)
+            .backoff(Duration::from_secs(2), Duration::from_secs(1))
+            .multiplier(0.5);
+        assert_eq!(policy.max_attempts(), 1);
+        assert_eq!(policy.base_delay(3), Duration::from_secs(2));
+        assert!(policy.should_retry(&2));
+        assert!(!policy.should_retry(&1));
+    }
+}
@@ -3,11 +3,15 @@
 //! This module provides reusable async primitives that follow the project's
 //! conventions of returning concrete types instead of boxed futures or async fn.
 
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use sugars_collections::ZeroOneOrMany;
-use tokio::sync::oneshot;
+use std::time::{Duration, Instant};
+use sugars_collections::{OneOrMany, ZeroOneOrMany};
+
+use crate::backend::{self, oneshot};
 
 /// Marker trait to prevent Result types in AsyncTask/AsyncStream
 ///
@@ -19,9 +23,24 @@ pub auto trait NotResult {}
 // Negative implementations - Result types do NOT implement NotResult
 impl<T, E> !NotResult for Result<T, E> {}
 
+/// Details passed to the fallback when an `AsyncTask` misses its timeout or deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut {
+    /// How long the task ran before it was abandoned
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out after {:?}", self.elapsed)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
 /// Generic async task wrapper for single operations
 ///
-/// This wraps a oneshot::Receiver and implements Future to provide
+/// This wraps the active backend's oneshot::Receiver and implements Future to provide
 /// a concrete return type instead of boxed futures or async fn.
 ///
 /// IMPORTANT: AsyncTask must never return Result types - all error handling
@@ -38,6 +57,8 @@ where
     T: NotResult, // T cannot be any Result type
 {
     /// Create a new AsyncTask from ZeroOneOrMany receivers
+    ///
+    /// With many receivers only the first is awaited; use `all` to collect every result.
     pub fn new(receivers: ZeroOneOrMany<oneshot::Receiver<T>>) -> Self
     where
         T: Send + 'static,
@@ -62,6 +83,36 @@ where
         }
     }
 
+    /// Create an AsyncTask that waits for every receiver, in the order they are given
+    ///
+    /// Panics if any sender is dropped without sending, like awaiting an `AsyncTask`.
+    pub fn all(receivers: OneOrMany<oneshot::Receiver<T>>) -> AsyncTask<OneOrMany<T>>
+    where
+        T: Send + 'static,
+    {
+        AsyncTask::from_future(async move {
+            let receivers = Vec::from(receivers).into_iter().map(AsyncTask::from);
+            let values = futures::future::join_all(receivers).await;
+            values.into_iter().collect()
+        })
+    }
+
+    /// Create an AsyncTask that waits for every receiver, in the order results arrive
+    ///
+    /// Panics if any sender is dropped without sending, like awaiting an `AsyncTask`.
+    pub fn all_by_arrival(receivers: OneOrMany<oneshot::Receiver<T>>) -> AsyncTask<OneOrMany<T>>
+    where
+        T: Send + 'static,
+    {
+        AsyncTask::from_future(async move {
+            let pending: FuturesUnordered<AsyncTask<T>> = Vec::from(receivers)
+                .into_iter()
+                .map(AsyncTask::from)
+                .collect();
+            pending.collect::<Vec<T>>().await.into_iter().collect()
+        })
+    }
+
     /// Create an AsyncTask from a future
     pub fn from_future<F>(future: F) -> Self
     where
@@ -69,7 +120,7 @@ where
         T: Send + 'static,
     {
         let (tx, rx) = oneshot::channel();
-        tokio::spawn(async move {
+        backend::spawn(async move {
             let result = future.await;
             let _ = tx.send(result);
         });
@@ -93,12 +144,68 @@ where
         T: Send + 'static,
     {
         let (tx, rx) = oneshot::channel();
-        tokio::task::spawn_blocking(move || {
+        backend::spawn_blocking(move || {
             let result = f();
             let _ = tx.send(result);
         });
         Self { receiver: rx }
     }
+
+    /// Yields `fallback` if the task does not complete within `duration`
+    pub fn with_timeout(self, duration: Duration, fallback: T) -> Self
+    where
+        T: Send + 'static,
+    {
+        self.with_deadline(backend::now() + duration, fallback)
+    }
+
+    /// Yields `fallback` if the task does not complete by `deadline`
+    pub fn with_deadline(self, deadline: Instant, fallback: T) -> Self
+    where
+        T: Send + 'static,
+    {
+        self.with_deadline_else(deadline, move |_| fallback)
+    }
+
+    /// Calls `on_timeout` to build the value if the task does not complete within `duration`
+    ///
+    /// Use this to turn a timeout into a typed error chunk, e.g.
+    /// `task.with_timeout_else(d, |timed_out| Chunk::bad_chunk(timed_out.to_string()))`.
+    pub fn with_timeout_else<F>(self, duration: Duration, on_timeout: F) -> Self
+    where
+        F: FnOnce(TimedOut) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.with_deadline_else(backend::now() + duration, on_timeout)
+    }
+
+    /// Calls `on_timeout` to build the value if the task does not complete by `deadline`
+    ///
+    /// The underlying work is not cancelled; its result is dropped when it arrives late.
+    pub fn with_deadline_else<F>(self, deadline: Instant, on_timeout: F) -> Self
+    where
+        F: FnOnce(TimedOut) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let started = backend::now();
+        Self::from_future(async move {
+            match backend::timeout_at(deadline, self).await {
+                Some(value) => value,
+                None => on_timeout(TimedOut {
+                    elapsed: backend::now().saturating_duration_since(started),
+                }),
+            }
+        })
+    }
+}
+
+impl<T> From<oneshot::Receiver<T>> for AsyncTask<T>
+where
+    T: NotResult, // T cannot be any Result type
+{
+    fn from(receiver: oneshot::Receiver<T>) -> Self {
+        Self { receiver }
+    }
 }
 
 impl<T> Future for AsyncTask<T>
@@ -115,3 +222,87 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_TICK as TICK;
+    use pretty_assertions::assert_eq;
+
+    /// A task resolving to `value` after `ticks` ticks
+    fn after(ticks: u32, value: u32) -> AsyncTask<u32> {
+        AsyncTask::from_future(async move {
+            backend::sleep(TICK * ticks).await;
+            value
+        })
+    }
+
+    /// A receiver that gets `value` after `ticks` ticks
+    fn delayed(ticks: u32, value: u32) -> oneshot::Receiver<u32> {
+        let (tx, rx) = oneshot::channel();
+        backend::spawn(async move {
+            backend::sleep(TICK * ticks).await;
+            let _ = tx.send(value);
+        });
+        rx
+    }
+
+    backend_test! {
+        async fn test_all_keeps_declared_order() {
+            let receivers = OneOrMany::many(vec![delayed(3, 1), delayed(1, 2), delayed(2, 3)])
+                .unwrap();
+            let values = AsyncTask::all(receivers).await;
+            assert_eq!(Vec::from(values), vec![1, 2, 3]);
+        }
+
+        async fn test_all_by_arrival_keeps_arrival_order() {
+            let receivers = OneOrMany::many(vec![delayed(3, 1), delayed(1, 2), delayed(2, 3)])
+                .unwrap();
+            let values = AsyncTask::all_by_arrival(receivers).await;
+            assert_eq!(Vec::from(values), vec![2, 3, 1]);
+        }
+
+        async fn test_all_of_one_receiver() {
+            let values = AsyncTask::all(OneOrMany::one(delayed(1, 9))).await;
+            assert_eq!(values, OneOrMany::one(9));
+        }
+
+        async fn test_new_with_many_receivers_awaits_the_first() {
+            let task = AsyncTask::new(ZeroOneOrMany::many(vec![delayed(2, 1), delayed(1, 2)]));
+            assert_eq!(task.await, 1);
+        }
+
+        async fn test_with_timeout_keeps_a_timely_value() {
+            assert_eq!(after(1, 7).with_timeout(TICK * 5, 0).await, 7);
+            assert_eq!(AsyncTask::from_value(3).with_timeout(TICK, 0).await, 3);
+        }
+
+        async fn test_with_timeout_falls_back_when_late() {
+            assert_eq!(after(50, 7).with_timeout(TICK * 2, 0).await, 0);
+        }
+
+        async fn test_with_timeout_else_reports_elapsed_time() {
+            let timed_out = after(50, 7)
+                .with_timeout_else(TICK * 2, |timed_out| timed_out.elapsed.as_millis() as u32)
+                .await;
+            let expected = (TICK * 2).as_millis() as u32;
+            #[cfg(feature = "tokio-backend")]
+            assert_eq!(timed_out, expected);
+            #[cfg(not(feature = "tokio-backend"))]
+            assert!(timed_out >= expected, "elapsed {timed_out}ms");
+        }
+
+        async fn test_with_deadline_in_the_past_falls_back() {
+            let deadline = backend::now();
+            assert_eq!(after(5, 7).with_deadline(deadline, 0).await, 0);
+        }
+    }
+
+    #[test]
+    fn test_timed_out_display() {
+        let timed_out = TimedOut {
+            elapsed: Duration::from_millis(250),
+        };
+        assert_eq!(timed_out.to_string(), "timed out after 250ms");
+    }
+}
@@ -3,6 +3,9 @@
 //! This module provides traits for handling message chunks in builders:
 //! - `MessageChunk` - trait for types that can represent both success and error states
 //! - `ChunkHandler` - trait for handling streaming Results
+//! - `#[derive(MessageChunk)]` and `#[derive(NoResult)]` - derives for custom chunk types
+
+pub use sugars_macros::{MessageChunk, NoResult};
 
 /// Trait for message chunks that can represent both success and error states
 pub trait MessageChunk: Sized {
@@ -4,6 +4,8 @@
 //! that leverage all cyrup_sugars features seamlessly.
 pub mod chunk_handler;
 pub mod llm;
+/// Composable field validation with structured, path-addressed errors
+pub mod validate;
 pub use chunk_handler::*;
 pub use llm::*;
 
@@ -314,21 +316,28 @@ pub mod closure_macros {
     // Macros are automatically re-exported by macro_export attribute
 
     /// Builder validation macro
+    ///
+    /// Checks each `field => condition` pair and returns the config, or a
+    /// `ValidationErrors` with one entry per failed field.
+    ///
+    /// ```rust
+    /// use sugars_builders::validate_config;
+    ///
+    /// let port = 0;
+    /// let errors = validate_config!(port, port => port > 0, name => true).unwrap_err();
+    /// assert_eq!(errors.to_string(), "port: validation failed");
+    /// ```
     #[macro_export]
     macro_rules! validate_config {
         ($config:expr, $($field:ident => $validation:expr),+ $(,)?) => {
             {
-                let mut errors = Vec::new();
+                let mut errors = $crate::validate::ValidationErrors::new();
                 $(
                     if !($validation) {
-                        errors.push(format!("Validation failed for field: {}", stringify!($field)));
+                        errors.push(stringify!($field), "validation failed");
                     }
                 )+
-                if errors.is_empty() {
-                    Ok($config)
-                } else {
-                    Err(errors.join(", "))
-                }
+                errors.into_result().map(|()| $config)
             }
         };
     }
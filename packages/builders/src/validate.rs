@@ -0,0 +1,478 @@
+//! Composable validation for builder configurations
+//!
+//! A `Validator` runs `Rule`s against fields and collects every failure as a `FieldError`
+//! with a dotted path, so `build()` can report all problems at once:
+//!
+//! ```rust
+//! use sugars_builders::validate::{Validate, ValidationErrors, Validator, non_empty, range};
+//!
+//! struct Retry {
+//!     max: u32,
+//! }
+//!
+//! impl Validate for Retry {
+//!     fn validate(&self) -> Result<(), ValidationErrors> {
+//!         let mut validator = Validator::new();
+//!         validator.field("max", &self.max).rule(range(1..=5));
+//!         validator.finish()
+//!     }
+//! }
+//!
+//! struct Config {
+//!     name: String,
+//!     retry: Retry,
+//! }
+//!
+//! impl Validate for Config {
+//!     fn validate(&self) -> Result<(), ValidationErrors> {
+//!         let mut validator = Validator::new();
+//!         validator.field("name", &self.name).rule(non_empty());
+//!         validator.nested("retry", &self.retry);
+//!         validator.finish()
+//!     }
+//! }
+//!
+//! let config = Config { name: String::new(), retry: Retry { max: 9 } };
+//! let errors = config.validate().unwrap_err();
+//! assert_eq!(errors.to_string(), "name: must not be empty; retry.max: must be between 1 and 5");
+//! ```
+//!
+//! `#[derive(Validate)]` generates the same code from field attributes.
+
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use sugars_collections::{OneOrMany, ZeroOneOrMany};
+
+pub use sugars_macros::Validate;
+
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use regex as __regex;
+
+/// A validation failure for a single field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Dotted path to the field, e.g. `retry.max`
+    pub path: String,
+    /// What the value failed to satisfy
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Every field error found while validating a value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    /// Creates an empty set of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an error for the field at `path`.
+    pub fn push(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            path: path.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Records the errors of a nested value, prefixing their paths with `prefix`.
+    pub fn extend_nested(&mut self, prefix: &str, nested: ValidationErrors) {
+        self.errors
+            .extend(nested.errors.into_iter().map(|error| FieldError {
+                path: format!("{prefix}.{}", error.path),
+                message: error.message,
+            }));
+    }
+
+    /// Returns whether no errors were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns an iterator over the errors in the order they were found.
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldError> {
+        self.errors.iter()
+    }
+
+    /// Returns the errors recorded for the field at `path`.
+    pub fn field<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a FieldError> {
+        self.errors.iter().filter(move |error| error.path == path)
+    }
+
+    /// Returns `Ok(())` if no errors were recorded, or `Err(self)` otherwise.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, error) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl IntoIterator for ValidationErrors {
+    type Item = FieldError;
+    type IntoIter = std::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+/// Types that can check themselves and report every invalid field
+pub trait Validate {
+    /// Validates every field, collecting all failures.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Returns `self` if it is valid, for use at the end of `build()`.
+    fn validated(self) -> Result<Self, ValidationErrors>
+    where
+        Self: Sized,
+    {
+        self.validate().map(|()| self)
+    }
+}
+
+/// A check applied to a single field value
+///
+/// Closures `Fn(&T) -> Result<(), String>` are rules, so one-off checks need no new type.
+pub trait Rule<T: ?Sized> {
+    /// Returns a message describing why `value` is invalid.
+    fn check(&self, value: &T) -> Result<(), String>;
+}
+
+impl<T: ?Sized, F> Rule<T> for F
+where
+    F: Fn(&T) -> Result<(), String>,
+{
+    fn check(&self, value: &T) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// Rule requiring a value to lie within a range; see `range`
+#[derive(Debug, Clone)]
+pub struct Range<B>(B);
+
+/// Requires the value to lie within `bounds`, e.g. `range(1..=10)` or `range(0.0..1.0)`.
+pub fn range<B>(bounds: B) -> Range<B> {
+    Range(bounds)
+}
+
+impl<T, B> Rule<T> for Range<B>
+where
+    T: PartialOrd + fmt::Display,
+    B: RangeBounds<T>,
+{
+    fn check(&self, value: &T) -> Result<(), String> {
+        if self.0.contains(value) {
+            return Ok(());
+        }
+        let message = match (self.0.start_bound(), self.0.end_bound()) {
+            (Bound::Included(min), Bound::Included(max)) => {
+                format!("must be between {min} and {max}")
+            }
+            (Bound::Included(min), Bound::Excluded(max)) => {
+                format!("must be at least {min} and less than {max}")
+            }
+            (Bound::Included(min), Bound::Unbounded) => format!("must be at least {min}"),
+            (Bound::Excluded(min), _) => format!("must be greater than {min}"),
+            (Bound::Unbounded, Bound::Included(max)) => format!("must be at most {max}"),
+            (Bound::Unbounded, Bound::Excluded(max)) => format!("must be less than {max}"),
+            (Bound::Unbounded, Bound::Unbounded) => unreachable!("unbounded ranges contain all"),
+        };
+        Err(message)
+    }
+}
+
+/// Values that can be empty, checked by `non_empty`
+pub trait IsEmpty {
+    /// Returns whether the value holds nothing.
+    fn is_empty_value(&self) -> bool;
+}
+
+impl IsEmpty for str {
+    fn is_empty_value(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+impl IsEmpty for String {
+    fn is_empty_value(&self) -> bool {
+        self.as_str().is_empty_value()
+    }
+}
+
+impl<T> IsEmpty for [T] {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> IsEmpty for Vec<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T: IsEmpty> IsEmpty for Option<T> {
+    fn is_empty_value(&self) -> bool {
+        self.as_ref().is_none_or(IsEmpty::is_empty_value)
+    }
+}
+
+impl<T> IsEmpty for ZeroOneOrMany<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> IsEmpty for OneOrMany<T> {
+    fn is_empty_value(&self) -> bool {
+        false
+    }
+}
+
+impl<K, V, S> IsEmpty for hashbrown::HashMap<K, V, S> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Rule rejecting empty or whitespace-only values; see `non_empty`
+#[derive(Debug, Clone, Copy)]
+pub struct NonEmpty;
+
+/// Requires strings to contain non-whitespace and collections to hold an element.
+pub fn non_empty() -> NonEmpty {
+    NonEmpty
+}
+
+impl<T: IsEmpty + ?Sized> Rule<T> for NonEmpty {
+    fn check(&self, value: &T) -> Result<(), String> {
+        if value.is_empty_value() {
+            Err("must not be empty".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Rule requiring a string to match a regular expression; see `pattern`
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone, Copy)]
+pub struct Pattern<'a> {
+    regex: Option<&'a regex::Regex>,
+    source: &'a str,
+}
+
+/// Requires the string to match `regex`.
+#[cfg(feature = "regex")]
+pub fn pattern(regex: &regex::Regex) -> Pattern<'_> {
+    Pattern {
+        regex: Some(regex),
+        source: regex.as_str(),
+    }
+}
+
+/// Compiles `source` once into `cell` and returns a rule matching it.
+///
+/// Used by `#[derive(Validate)]`; an invalid pattern fails validation instead of panicking.
+#[cfg(feature = "regex")]
+pub fn cached_pattern(
+    cell: &'static std::sync::OnceLock<Option<regex::Regex>>,
+    source: &'static str,
+) -> Pattern<'static> {
+    Pattern {
+        regex: cell.get_or_init(|| regex::Regex::new(source).ok()).as_ref(),
+        source,
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Rule<str> for Pattern<'_> {
+    fn check(&self, value: &str) -> Result<(), String> {
+        match self.regex {
+            Some(regex) if regex.is_match(value) => Ok(()),
+            Some(_) => Err(format!("must match `{}`", self.source)),
+            None => Err(format!("invalid pattern `{}`", self.source)),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Rule<String> for Pattern<'_> {
+    fn check(&self, value: &String) -> Result<(), String> {
+        Rule::<str>::check(self, value)
+    }
+}
+
+/// Collects field errors while checking a value
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: ValidationErrors,
+}
+
+impl Validator {
+    /// Creates a validator with no errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts checking the field at `path`; chain `rule` calls on the result.
+    pub fn field<'v, T: ?Sized>(
+        &'v mut self,
+        path: &'v str,
+        value: &'v T,
+    ) -> FieldValidator<'v, T> {
+        FieldValidator {
+            errors: &mut self.errors,
+            path,
+            value,
+        }
+    }
+
+    /// Validates a nested value, prefixing its error paths with `path`.
+    pub fn nested<V: Validate + ?Sized>(&mut self, path: &str, value: &V) -> &mut Self {
+        if let Err(nested) = value.validate() {
+            self.errors.extend_nested(path, nested);
+        }
+        self
+    }
+
+    /// Records an error that no rule covers, such as a relationship between fields.
+    pub fn error(&mut self, path: impl Into<String>, message: impl Into<String>) -> &mut Self {
+        self.errors.push(path, message);
+        self
+    }
+
+    /// Returns every error recorded, or `Ok(())` if there were none.
+    pub fn finish(self) -> Result<(), ValidationErrors> {
+        self.errors.into_result()
+    }
+}
+
+/// Applies rules to one field; created by `Validator::field`
+#[derive(Debug)]
+pub struct FieldValidator<'v, T: ?Sized> {
+    errors: &'v mut ValidationErrors,
+    path: &'v str,
+    value: &'v T,
+}
+
+impl<T: ?Sized> FieldValidator<'_, T> {
+    /// Checks the field against `rule`, recording a failure under the field's path.
+    pub fn rule(self, rule: impl Rule<T>) -> Self {
+        if let Err(message) = rule.check(self.value) {
+            self.errors.push(self.path, message);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_range_messages() {
+        assert_eq!(range(1..=3).check(&2), Ok(()));
+        assert_eq!(
+            range(1..=3).check(&4),
+            Err("must be between 1 and 3".to_string())
+        );
+        assert_eq!(
+            range(..10).check(&10),
+            Err("must be less than 10".to_string())
+        );
+        assert_eq!(
+            range(0.5..).check(&0.1),
+            Err("must be at least 0.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_non_empty() {
+        assert!(non_empty().check("  ").is_err());
+        assert!(non_empty().check(&Some(String::from("x"))).is_ok());
+        assert!(non_empty().check(&None::<String>).is_err());
+        assert!(non_empty().check(&ZeroOneOrMany::<u8>::none()).is_err());
+    }
+
+    #[test]
+    fn test_validator_collects_every_error() {
+        let mut validator = Validator::new();
+        validator
+            .field("port", &0u16)
+            .rule(range(1..=65535))
+            .rule(|port: &u16| {
+                if *port == 0 {
+                    Err("must not be zero".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+        validator.field("host", "localhost").rule(non_empty());
+        validator.error("tls", "requires a certificate");
+        let errors = validator.finish().unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.field("port").count(), 2);
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| error.path.as_str())
+                .collect::<Vec<_>>(),
+            ["port", "port", "tls"]
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_pattern() {
+        let regex = regex::Regex::new("^[a-z]+$").unwrap();
+        assert_eq!(pattern(&regex).check("abc"), Ok(()));
+        assert_eq!(
+            pattern(&regex).check("ABC"),
+            Err("must match `^[a-z]+$`".to_string())
+        );
+        static INVALID: std::sync::OnceLock<Option<regex::Regex>> = std::sync::OnceLock::new();
+        assert_eq!(
+            cached_pattern(&INVALID, "(").check("x"),
+            Err("invalid pattern `(`".to_string())
+        );
+    }
+}
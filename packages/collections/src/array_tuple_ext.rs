@@ -605,3 +605,116 @@ where
         Ok(ZeroOneOrMany::many(items))
     }
 }
+
+/// Extension trait for JSON parameter collections whose values mix numbers, bools and strings.
+///
+/// An array literal needs a single element type, so build mixed pairs with `json_pairs!`,
+/// which converts each value into a `serde_json::Value`.
+#[cfg(feature = "array-tuples")]
+pub trait ArrayTupleObjectExtJson: Sized {
+    /// Creates an instance from key-value pairs whose values convert into JSON values.
+    fn from_json_pairs<K, V, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<serde_json::Value>;
+}
+
+#[cfg(feature = "array-tuples")]
+impl ArrayTupleObjectExtJson for serde_json::Map<String, serde_json::Value> {
+    fn from_json_pairs<K, V, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        pairs
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "array-tuples")]
+impl ArrayTupleObjectExtJson for ::hashbrown::HashMap<String, serde_json::Value> {
+    fn from_json_pairs<K, V, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        pairs
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "array-tuples")]
+impl ArrayTupleObjectExtJson for Vec<(String, serde_json::Value)> {
+    fn from_json_pairs<K, V, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        pairs
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "array-tuples")]
+impl ArrayTupleObjectExtJson for Option<Vec<(String, serde_json::Value)>> {
+    fn from_json_pairs<K, V, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        let items: Vec<(String, serde_json::Value)> = Vec::from_json_pairs(pairs);
+        if items.is_empty() { None } else { Some(items) }
+    }
+}
+
+#[cfg(feature = "array-tuples")]
+impl ArrayTupleObjectExtJson for ZeroOneOrMany<(String, serde_json::Value)> {
+    fn from_json_pairs<K, V, I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<serde_json::Value>,
+    {
+        ZeroOneOrMany::many(Vec::from_json_pairs(pairs))
+    }
+}
+
+#[cfg(all(test, feature = "array-tuples"))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::{Value, json};
+
+    #[test]
+    fn test_json_pairs_mix_value_types() {
+        let params = serde_json::Map::from_json_pairs(crate::json_pairs![
+            ("max_tokens", 8000),
+            ("stream", true),
+            ("model", "gpt"),
+        ]);
+        assert_eq!(
+            Value::Object(params),
+            json!({"max_tokens": 8000, "stream": true, "model": "gpt"})
+        );
+    }
+
+    #[test]
+    fn test_json_pairs_into_collections() {
+        let items = Vec::from_json_pairs([("temperature", 0.5)]);
+        assert_eq!(items, [("temperature".to_string(), json!(0.5))]);
+        let empty: Option<Vec<(String, Value)>> =
+            ArrayTupleObjectExtJson::from_json_pairs(Vec::<(&str, bool)>::new());
+        assert_eq!(empty, None);
+    }
+}
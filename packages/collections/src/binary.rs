@@ -0,0 +1,257 @@
+// -----------------------------------------------------------------------------
+// src/binary.rs
+// -----------------------------------------------------------------------------
+
+//! bincode 2 support for `ZeroOneOrMany`, `OneOrMany` and `ByteSize`.
+//!
+//! Collections encode a variant tag (`0` = none, `1` = one, `2` = many) followed by the
+//! element or the length-prefixed elements, so decoding restores the exact variant.
+//! Decoding a `OneOrMany` rejects the empty tag and an empty `Many`.
+
+use crate::byte_size::ByteSize;
+use crate::one_or_many::OneOrMany;
+use crate::zero_one_or_many::ZeroOneOrMany;
+use bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{DecodeError, EncodeError};
+
+const TAG_NONE: u8 = 0;
+const TAG_ONE: u8 = 1;
+const TAG_MANY: u8 = 2;
+
+impl<T: Encode> Encode for ZeroOneOrMany<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        match self {
+            ZeroOneOrMany::None => TAG_NONE.encode(encoder),
+            ZeroOneOrMany::One(item) => {
+                TAG_ONE.encode(encoder)?;
+                item.encode(encoder)
+            }
+            ZeroOneOrMany::Many(v) => {
+                TAG_MANY.encode(encoder)?;
+                v.encode(encoder)
+            }
+        }
+    }
+}
+
+impl<Context, T: Decode<Context>> Decode<Context> for ZeroOneOrMany<T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        match decode_tag(decoder)? {
+            TAG_NONE => Ok(ZeroOneOrMany::None),
+            TAG_ONE => Ok(ZeroOneOrMany::One(T::decode(decoder)?)),
+            _ => Ok(ZeroOneOrMany::many(Vec::decode(decoder)?)),
+        }
+    }
+}
+
+impl<'de, Context, T: BorrowDecode<'de, Context>> BorrowDecode<'de, Context> for ZeroOneOrMany<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        match decode_tag(decoder)? {
+            TAG_NONE => Ok(ZeroOneOrMany::None),
+            TAG_ONE => Ok(ZeroOneOrMany::One(T::borrow_decode(decoder)?)),
+            _ => Ok(ZeroOneOrMany::many(Vec::borrow_decode(decoder)?)),
+        }
+    }
+}
+
+/// Reads a collection variant tag, rejecting unknown values.
+fn decode_tag<D: Decoder>(decoder: &mut D) -> Result<u8, DecodeError> {
+    match u8::decode(decoder)? {
+        tag @ (TAG_NONE | TAG_ONE | TAG_MANY) => Ok(tag),
+        found => Err(DecodeError::UnexpectedVariant {
+            type_name: "ZeroOneOrMany",
+            allowed: &bincode::error::AllowedEnumVariants::Range { min: 0, max: 2 },
+            found: found.into(),
+        }),
+    }
+}
+
+impl<T: Encode> Encode for OneOrMany<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+impl<Context, T: Decode<Context>> Decode<Context> for OneOrMany<T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        non_empty(ZeroOneOrMany::decode(decoder)?)
+    }
+}
+
+impl<'de, Context, T: BorrowDecode<'de, Context>> BorrowDecode<'de, Context> for OneOrMany<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        non_empty(ZeroOneOrMany::borrow_decode(decoder)?)
+    }
+}
+
+/// Wraps decoded elements in a `OneOrMany`, rejecting an empty collection.
+fn non_empty<T>(items: ZeroOneOrMany<T>) -> Result<OneOrMany<T>, DecodeError> {
+    match items {
+        ZeroOneOrMany::None => Err(DecodeError::Other("OneOrMany cannot be empty")),
+        items => Ok(OneOrMany(items)),
+    }
+}
+
+impl Encode for ByteSize {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for ByteSize {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        usize::decode(decoder).map(ByteSize)
+    }
+}
+
+bincode::impl_borrow_decode!(ByteSize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn bincode_round_trip<T>(value: &T) -> T
+    where
+        T: Encode + Decode<()>,
+    {
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(value, config).expect("encode");
+        let (decoded, read) = bincode::decode_from_slice(&bytes, config).expect("decode");
+        assert_eq!(read, bytes.len());
+        decoded
+    }
+
+    fn postcard_round_trip<T>(value: &T) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let bytes = postcard::to_allocvec(value).expect("serialize");
+        postcard::from_bytes(&bytes).expect("deserialize")
+    }
+
+    #[test]
+    fn test_bincode_zero_one_or_many_keeps_variant() {
+        let values = [
+            ZeroOneOrMany::None,
+            ZeroOneOrMany::One(7u32),
+            ZeroOneOrMany::Many(vec![1u32, 2, 3]),
+        ];
+        for value in values {
+            assert_eq!(bincode_round_trip(&value), value);
+        }
+        // A single element encoded as `Many` decodes to the variant matching its length
+        assert_eq!(
+            bincode_round_trip(&ZeroOneOrMany::Many(vec![9u32])),
+            ZeroOneOrMany::One(9)
+        );
+    }
+
+    #[test]
+    fn test_bincode_one_or_many() {
+        let one = OneOrMany::one("a".to_string());
+        assert_eq!(bincode_round_trip(&one), one);
+        let many = OneOrMany::many(vec!["a".to_string(), "b".to_string()]).expect("non-empty");
+        assert_eq!(bincode_round_trip(&many), many);
+    }
+
+    #[test]
+    fn test_bincode_one_or_many_rejects_empty() {
+        let config = bincode::config::standard();
+        let none = bincode::encode_to_vec(ZeroOneOrMany::<u32>::None, config).expect("encode");
+        let empty =
+            bincode::encode_to_vec(ZeroOneOrMany::<u32>::Many(vec![]), config).expect("encode");
+        for bytes in [none, empty] {
+            let decoded: Result<(OneOrMany<u32>, usize), _> =
+                bincode::decode_from_slice(&bytes, config);
+            assert!(decoded.is_err());
+        }
+    }
+
+    #[test]
+    fn test_bincode_rejects_unknown_tag() {
+        let decoded: Result<(ZeroOneOrMany<u32>, usize), _> =
+            bincode::decode_from_slice(&[3], bincode::config::standard());
+        assert!(matches!(
+            decoded,
+            Err(DecodeError::UnexpectedVariant { found: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_bincode_byte_size() {
+        assert_eq!(
+            bincode_round_trip(&ByteSize::megabytes(3)),
+            ByteSize::megabytes(3)
+        );
+    }
+
+    #[test]
+    fn test_postcard_round_trip() {
+        let zero: ZeroOneOrMany<u32> = ZeroOneOrMany::None;
+        assert_eq!(postcard_round_trip(&zero), zero);
+        let many = ZeroOneOrMany::many(vec![1u32, 2, 3]);
+        assert_eq!(postcard_round_trip(&many), many);
+        let one = OneOrMany::many(vec![4u32, 5]).expect("non-empty");
+        assert_eq!(postcard_round_trip(&one), one);
+        assert_eq!(
+            postcard_round_trip(&ByteSize::kilobytes(2)),
+            ByteSize::kilobytes(2)
+        );
+    }
+
+    #[test]
+    fn test_postcard_one_or_many_rejects_empty() {
+        let bytes = postcard::to_allocvec(&ZeroOneOrMany::<u32>::None).expect("serialize");
+        assert!(postcard::from_bytes::<OneOrMany<u32>>(&bytes).is_err());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_round_trip() {
+        use rkyv::rancor::Error;
+
+        let many = ZeroOneOrMany::Many(vec![1u32, 2, 3]);
+        let bytes = rkyv::to_bytes::<Error>(&many).expect("archive");
+        let archived =
+            rkyv::access::<rkyv::Archived<ZeroOneOrMany<u32>>, Error>(&bytes).expect("access");
+        assert!(matches!(archived, rkyv::Archived::<ZeroOneOrMany<u32>>::Many(v) if v.len() == 3));
+        assert_eq!(
+            rkyv::from_bytes::<ZeroOneOrMany<u32>, Error>(&bytes).expect("deserialize"),
+            many
+        );
+
+        let one = OneOrMany::one(ByteSize::kilobytes(1));
+        let bytes = rkyv::to_bytes::<Error>(&one).expect("archive");
+        assert_eq!(
+            rkyv::from_bytes::<OneOrMany<ByteSize>, Error>(&bytes).expect("deserialize"),
+            one
+        );
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_one_or_many_rejects_empty() {
+        use rkyv::rancor::Error;
+
+        let empty_many = OneOrMany(ZeroOneOrMany::<u32>::Many(Vec::new()));
+        let bytes = rkyv::to_bytes::<Error>(&empty_many).expect("archive");
+        assert!(rkyv::from_bytes::<OneOrMany<u32>, Error>(&bytes).is_err());
+
+        let empty_none = OneOrMany(ZeroOneOrMany::<u32>::None);
+        let bytes = rkyv::to_bytes::<Error>(&empty_none).expect("archive");
+        assert!(rkyv::from_bytes::<OneOrMany<u32>, Error>(&bytes).is_err());
+
+        let many = OneOrMany::many(vec![1u32, 2]).expect("non-empty");
+        let bytes = rkyv::to_bytes::<Error>(&many).expect("archive");
+        assert_eq!(
+            rkyv::from_bytes::<OneOrMany<u32>, Error>(&bytes).expect("deserialize"),
+            many
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Vectors whose length is bounded at construction
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::ops::Deref;
+
+/// A vector holding between `MIN` and `MAX` elements, inclusive.
+///
+/// The bounds are checked whenever a `BoundedVec` is built, including on deserialization,
+/// where the error names the violated bound. `OneOrMany` covers `MIN = 1` without an upper
+/// limit; use `BoundedVec` for settings such as "between 1 and 5 replicas".
+///
+/// Serializes as a plain sequence. Naming bounds with `MIN > MAX` fails to compile.
+///
+/// ### Examples
+/// ```rust
+/// use sugars_collections::BoundedVec;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// type Replicas = BoundedVec<String, 1, 5>;
+/// let replicas = Replicas::new(vec!["eu-west".to_string(), "us-east".to_string()])?;
+/// assert_eq!(replicas.len(), 2);
+/// assert!(Replicas::new(vec![]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoundedVec<T, const MIN: usize, const MAX: usize>(Vec<T>);
+
+/// Error returned when a `BoundedVec` would hold too few or too many elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundsError {
+    /// Fewer elements than the minimum
+    TooFew {
+        /// Minimum number of elements
+        min: usize,
+        /// Number of elements given
+        len: usize,
+    },
+    /// More elements than the maximum
+    TooMany {
+        /// Maximum number of elements
+        max: usize,
+        /// Number of elements given
+        len: usize,
+    },
+}
+
+impl fmt::Display for BoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundsError::TooFew { min, len } => {
+                write!(f, "expected at least {min} element(s), found {len}")
+            }
+            BoundsError::TooMany { max, len } => {
+                write!(f, "expected at most {max} element(s), found {len}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoundsError {}
+
+impl<T, const MIN: usize, const MAX: usize> BoundedVec<T, MIN, MAX> {
+    /// Rejects `MIN > MAX` when the type is used.
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "BoundedVec requires MIN <= MAX");
+
+    /// Creates a bounded vector, failing if `items` has fewer than `MIN` or more than
+    /// `MAX` elements.
+    #[inline]
+    pub fn new(items: Vec<T>) -> Result<Self, BoundsError> {
+        let () = Self::VALID_BOUNDS;
+        let len = items.len();
+        if len < MIN {
+            Err(BoundsError::TooFew { min: MIN, len })
+        } else if len > MAX {
+            Err(BoundsError::TooMany { max: MAX, len })
+        } else {
+            Ok(BoundedVec(items))
+        }
+    }
+
+    /// Returns the minimum number of elements.
+    #[inline]
+    pub const fn min_len() -> usize {
+        MIN
+    }
+
+    /// Returns the maximum number of elements.
+    #[inline]
+    pub const fn max_len() -> usize {
+        MAX
+    }
+
+    /// Returns the elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Consumes the wrapper, returning the inner `Vec`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> Deref for BoundedVec<T, MIN, MAX> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> AsRef<[T]> for BoundedVec<T, MIN, MAX> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> IntoIterator for BoundedVec<T, MIN, MAX> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const MIN: usize, const MAX: usize> IntoIterator for &'a BoundedVec<T, MIN, MAX> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+// Conversion Traits
+impl<T, const MIN: usize, const MAX: usize> TryFrom<Vec<T>> for BoundedVec<T, MIN, MAX> {
+    type Error = BoundsError;
+
+    #[inline]
+    fn try_from(items: Vec<T>) -> Result<Self, Self::Error> {
+        BoundedVec::new(items)
+    }
+}
+
+impl<T, const MIN: usize, const MAX: usize> From<BoundedVec<T, MIN, MAX>> for Vec<T> {
+    #[inline]
+    fn from(value: BoundedVec<T, MIN, MAX>) -> Self {
+        value.0
+    }
+}
+
+// Serde Support
+impl<T: Serialize, const MIN: usize, const MAX: usize> Serialize for BoundedVec<T, MIN, MAX> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(ser)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const MIN: usize, const MAX: usize> Deserialize<'de>
+    for BoundedVec<T, MIN, MAX>
+{
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let items = Vec::deserialize(de)?;
+        BoundedVec::new(items).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    type Replicas = BoundedVec<u8, 1, 3>;
+
+    #[test]
+    fn test_bounded_vec_enforces_bounds() {
+        assert_eq!(
+            Replicas::new(vec![]),
+            Err(BoundsError::TooFew { min: 1, len: 0 })
+        );
+        assert_eq!(
+            Replicas::new(vec![1, 2, 3, 4]),
+            Err(BoundsError::TooMany { max: 3, len: 4 })
+        );
+        assert_eq!(
+            Replicas::new(vec![1, 2, 3]).map(Vec::from),
+            Ok(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_bounded_vec_exact_length() {
+        let pair = BoundedVec::<u8, 2, 2>::new(vec![1, 2]).expect("two elements");
+        assert_eq!(pair.as_slice(), &[1, 2]);
+        assert!(BoundedVec::<u8, 2, 2>::new(vec![1]).is_err());
+    }
+
+    #[test]
+    fn test_bounded_vec_serde_names_bound() {
+        let replicas: Replicas = serde_json::from_str("[1, 2]").expect("deserialize");
+        assert_eq!(
+            serde_json::to_string(&replicas).expect("serialize"),
+            "[1,2]"
+        );
+
+        let error = serde_json::from_str::<Replicas>("[]").expect_err("too few");
+        assert!(error.to_string().contains("at least 1"), "{error}");
+        let error = serde_json::from_str::<Replicas>("[1, 2, 3, 4]").expect_err("too many");
+        assert!(error.to_string().contains("at most 3"), "{error}");
+    }
+}
@@ -1,11 +1,24 @@
 //! Byte size utilities for semantic sizing
 
-#[cfg(feature = "array-tuples")]
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents a size in bytes with semantic constructors
+///
+/// Parses from strings such as `"1024"`, `"512MB"`, `"512mb"` or `"1.5 GiB"`. Units are
+/// case-insensitive: `KB`, `MB`, `GB` and `TB` are decimal (powers of 1000), while `KiB`,
+/// `MiB`, `GiB`, `TiB` and the bare `K`, `M`, `G`, `T` are binary (powers of 1024).
+/// `Display` uses the largest binary unit that fits, e.g. `1.5 GiB`.
+///
+/// Serializes as a number of bytes and deserializes from a number or a size string, so
+/// configs can say `cache = "512MiB"`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "array-tuples", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct ByteSize(pub usize);
 
 impl ByteSize {
@@ -15,19 +28,105 @@ impl ByteSize {
     }
 
     /// Create a new ByteSize from kilobytes
+    ///
+    /// Kept binary (1 KB = 1024 bytes) for compatibility; equivalent to `kibibytes`. Use
+    /// `kilobytes_decimal` for 1000-byte kilobytes.
     pub fn kilobytes(size: usize) -> Self {
         Self(size * 1024)
     }
 
     /// Create a new ByteSize from megabytes
+    ///
+    /// Kept binary (1 MB = 1024² bytes) for compatibility; equivalent to `mebibytes`. Use
+    /// `megabytes_decimal` for 1000² bytes.
     pub fn megabytes(size: usize) -> Self {
         Self(size * 1024 * 1024)
     }
 
+    /// Create a new ByteSize from gigabytes
+    ///
+    /// Binary (1 GB = 1024³ bytes) like `kilobytes` and `megabytes`; equivalent to
+    /// `gibibytes`. Use `gigabytes_decimal` for 1000³ bytes.
+    pub fn gigabytes(size: usize) -> Self {
+        Self(size * 1024 * 1024 * 1024)
+    }
+
+    /// Create a new ByteSize from decimal kilobytes (1 kB = 1000 bytes)
+    pub fn kilobytes_decimal(size: usize) -> Self {
+        Self(size * 1000)
+    }
+
+    /// Create a new ByteSize from decimal megabytes (1 MB = 1000² bytes)
+    pub fn megabytes_decimal(size: usize) -> Self {
+        Self(size * 1000 * 1000)
+    }
+
+    /// Create a new ByteSize from decimal gigabytes (1 GB = 1000³ bytes)
+    pub fn gigabytes_decimal(size: usize) -> Self {
+        Self(size * 1000 * 1000 * 1000)
+    }
+
+    /// Create a new ByteSize from kibibytes (1 KiB = 1024 bytes)
+    pub fn kibibytes(size: usize) -> Self {
+        Self(size * 1024)
+    }
+
+    /// Create a new ByteSize from mebibytes (1 MiB = 1024² bytes)
+    pub fn mebibytes(size: usize) -> Self {
+        Self(size * 1024 * 1024)
+    }
+
+    /// Create a new ByteSize from gibibytes (1 GiB = 1024³ bytes)
+    pub fn gibibytes(size: usize) -> Self {
+        Self(size * 1024 * 1024 * 1024)
+    }
+
     /// Get the size in bytes
     pub fn as_bytes(&self) -> usize {
         self.0
     }
+
+    /// Adds two sizes, returning `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Subtracts a size, returning `None` if it is larger than `self`
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Multiplies by a factor, returning `None` on overflow
+    pub fn checked_mul(self, factor: u64) -> Option<Self> {
+        usize::try_from(factor)
+            .ok()
+            .and_then(|factor| self.0.checked_mul(factor))
+            .map(Self)
+    }
+
+    /// Divides by a divisor, returning `None` if it is zero
+    pub fn checked_div(self, divisor: u64) -> Option<Self> {
+        match usize::try_from(divisor) {
+            Ok(divisor) => self.0.checked_div(divisor).map(Self),
+            // Larger than any size, so the quotient is zero
+            Err(_) => Some(Self(0)),
+        }
+    }
+
+    /// Adds two sizes, clamping at the largest size
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Subtracts a size, clamping at zero
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Multiplies by a factor, clamping at the largest size
+    pub fn saturating_mul(self, factor: u64) -> Self {
+        self.checked_mul(factor).unwrap_or(Self(usize::MAX))
+    }
 }
 
 impl From<usize> for ByteSize {
@@ -42,6 +141,8 @@ impl From<ByteSize> for usize {
     }
 }
 
+// Arithmetic panics on overflow in debug builds like the integer operators; use the
+// checked_* or saturating_* methods where sizes come from user input.
 impl std::ops::Add for ByteSize {
     type Output = Self;
 
@@ -50,72 +151,293 @@ impl std::ops::Add for ByteSize {
     }
 }
 
-/// Extension trait to add .bytes() method to integers
-pub trait ByteSizeExt {
-    /// Converts this value to a ByteSize representing the given number of bytes.
-    fn bytes(self) -> ByteSize;
-    /// Converts this value to a ByteSize representing the given number of kilobytes.
-    fn kb(self) -> ByteSize;
-    /// Converts this value to a ByteSize representing the given number of megabytes.
-    fn mb(self) -> ByteSize;
+impl std::ops::AddAssign for ByteSize {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
 }
 
-impl ByteSizeExt for usize {
-    fn bytes(self) -> ByteSize {
-        ByteSize::bytes(self)
-    }
+impl std::ops::Sub for ByteSize {
+    type Output = Self;
 
-    fn kb(self) -> ByteSize {
-        ByteSize::kilobytes(self)
+    fn sub(self, other: Self) -> Self {
+        ByteSize(self.0 - other.0)
     }
+}
 
-    fn mb(self) -> ByteSize {
-        ByteSize::megabytes(self)
+impl std::ops::SubAssign for ByteSize {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
     }
 }
 
-impl ByteSizeExt for u32 {
-    fn bytes(self) -> ByteSize {
-        ByteSize::bytes(self as usize)
+impl std::ops::Mul<u64> for ByteSize {
+    type Output = Self;
+
+    fn mul(self, factor: u64) -> Self {
+        ByteSize(self.0 * factor as usize)
     }
+}
 
-    fn kb(self) -> ByteSize {
-        ByteSize::kilobytes(self as usize)
+impl std::ops::Div<u64> for ByteSize {
+    type Output = Self;
+
+    fn div(self, divisor: u64) -> Self {
+        ByteSize(self.0 / divisor as usize)
     }
+}
 
-    fn mb(self) -> ByteSize {
-        ByteSize::megabytes(self as usize)
+impl std::iter::Sum for ByteSize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ByteSize(0), |total, size| total + size)
     }
 }
 
-impl ByteSizeExt for u64 {
-    fn bytes(self) -> ByteSize {
-        ByteSize::bytes(self as usize)
+/// Error returned when a string is not a valid byte size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseByteSizeError {
+    /// The string was empty or only whitespace
+    Empty,
+    /// The numeric part was missing, negative or malformed
+    InvalidNumber(String),
+    /// The unit suffix was not recognized
+    UnknownUnit(String),
+    /// The size does not fit in `usize`
+    Overflow,
+}
+
+impl fmt::Display for ParseByteSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseByteSizeError::Empty => write!(f, "byte size cannot be empty"),
+            ParseByteSizeError::InvalidNumber(number) => {
+                write!(f, "invalid byte size number: {number:?}")
+            }
+            ParseByteSizeError::UnknownUnit(unit) => {
+                write!(
+                    f,
+                    "unknown byte size unit: {unit:?} (expected B, KB, KiB, MB, MiB, GB, GiB, TB or TiB)"
+                )
+            }
+            ParseByteSizeError::Overflow => write!(f, "byte size is too large"),
+        }
     }
+}
+
+impl std::error::Error for ParseByteSizeError {}
+
+/// Returns the number of bytes in a unit suffix, matched case-insensitively.
+fn unit_multiplier(unit: &str) -> Option<u128> {
+    const KIB: u128 = 1024;
+    const KB: u128 = 1000;
+    let multiplier = match unit.to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "kb" => KB,
+        "mb" => KB.pow(2),
+        "gb" => KB.pow(3),
+        "tb" => KB.pow(4),
+        "k" | "kib" => KIB,
+        "m" | "mib" => KIB.pow(2),
+        "g" | "gib" => KIB.pow(3),
+        "t" | "tib" => KIB.pow(4),
+        _ => return None,
+    };
+    Some(multiplier)
+}
 
-    fn kb(self) -> ByteSize {
-        ByteSize::kilobytes(self as usize)
+impl FromStr for ByteSize {
+    type Err = ParseByteSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseByteSizeError::Empty);
+        }
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+        if number.is_empty() {
+            return Err(ParseByteSizeError::InvalidNumber(s.to_string()));
+        }
+        let multiplier = unit_multiplier(unit.trim_start())
+            .ok_or_else(|| ParseByteSizeError::UnknownUnit(unit.trim_start().to_string()))?;
+        let invalid = || ParseByteSizeError::InvalidNumber(number.to_string());
+
+        let bytes = if !number.contains('.') {
+            // Whole numbers stay exact
+            number
+                .parse::<u128>()
+                .map_err(|_| invalid())?
+                .checked_mul(multiplier)
+                .ok_or(ParseByteSizeError::Overflow)?
+        } else {
+            let value: f64 = number.parse().map_err(|_| invalid())?;
+            let bytes = (value * multiplier as f64).round();
+            if bytes > usize::MAX as f64 {
+                return Err(ParseByteSizeError::Overflow);
+            }
+            bytes as u128
+        };
+        usize::try_from(bytes)
+            .map(ByteSize)
+            .map_err(|_| ParseByteSizeError::Overflow)
     }
+}
+
+impl TryFrom<&str> for ByteSize {
+    type Error = ParseByteSizeError;
 
-    fn mb(self) -> ByteSize {
-        ByteSize::megabytes(self as usize)
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
-impl ByteSizeExt for i32 {
-    fn bytes(self) -> ByteSize {
-        ByteSize::bytes(self as usize)
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        let mut unit = None;
+        let mut scaled = self.0 as f64;
+        for candidate in UNITS {
+            if scaled < 1024.0 {
+                break;
+            }
+            scaled /= 1024.0;
+            unit = Some(candidate);
+        }
+        match unit {
+            None => write!(f, "{} B", self.0),
+            Some(unit) => {
+                // Up to two decimals, without trailing zeros
+                let rounded = format!("{scaled:.2}");
+                let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+                write!(f, "{trimmed} {unit}")
+            }
+        }
     }
+}
 
-    fn kb(self) -> ByteSize {
-        ByteSize::kilobytes(self as usize)
+// Serde Support
+impl Serialize for ByteSize {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_u64(self.0 as u64)
     }
+}
 
-    fn mb(self) -> ByteSize {
-        ByteSize::megabytes(self as usize)
+impl<'de> Deserialize<'de> for ByteSize {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct V;
+        impl Visitor<'_> for V {
+            type Value = ByteSize;
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a number of bytes or a size string such as \"512MiB\"")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                usize::try_from(value)
+                    .map(ByteSize)
+                    .map_err(|_| E::custom(ParseByteSizeError::Overflow))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        // Binary formats cannot deserialize_any; they always hold the byte count.
+        if de.is_human_readable() {
+            de.deserialize_any(V)
+        } else {
+            de.deserialize_u64(V)
+        }
     }
 }
 
+/// Extension trait to add .bytes() method to integers
+///
+/// `kb`, `mb` and `gb` are binary (powers of 1024), the same as `kib`, `mib` and `gib`.
+/// Decimal units (powers of 1000) are spelled out as `kb_decimal`, `mb_decimal` and
+/// `gb_decimal`; note that string parsing treats `"512MB"` as decimal.
+pub trait ByteSizeExt {
+    /// Converts this value to a ByteSize representing the given number of bytes.
+    fn bytes(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of kilobytes.
+    fn kb(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of megabytes.
+    fn mb(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of gigabytes (1024³ bytes).
+    fn gb(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of kilobytes (1000 bytes).
+    fn kb_decimal(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of megabytes (1000² bytes).
+    fn mb_decimal(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of gigabytes (1000³ bytes).
+    fn gb_decimal(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of kibibytes (1024 bytes).
+    fn kib(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of mebibytes (1024² bytes).
+    fn mib(self) -> ByteSize;
+    /// Converts this value to a ByteSize representing the given number of gibibytes (1024³ bytes).
+    fn gib(self) -> ByteSize;
+}
+
+macro_rules! impl_byte_size_ext {
+    ($($ty:ty),*) => {
+        $(
+            impl ByteSizeExt for $ty {
+                fn bytes(self) -> ByteSize {
+                    ByteSize::bytes(self as usize)
+                }
+
+                fn kb(self) -> ByteSize {
+                    ByteSize::kilobytes(self as usize)
+                }
+
+                fn mb(self) -> ByteSize {
+                    ByteSize::megabytes(self as usize)
+                }
+
+                fn gb(self) -> ByteSize {
+                    ByteSize::gigabytes(self as usize)
+                }
+
+                fn kb_decimal(self) -> ByteSize {
+                    ByteSize::kilobytes_decimal(self as usize)
+                }
+
+                fn mb_decimal(self) -> ByteSize {
+                    ByteSize::megabytes_decimal(self as usize)
+                }
+
+                fn gb_decimal(self) -> ByteSize {
+                    ByteSize::gigabytes_decimal(self as usize)
+                }
+
+                fn kib(self) -> ByteSize {
+                    ByteSize::kibibytes(self as usize)
+                }
+
+                fn mib(self) -> ByteSize {
+                    ByteSize::mebibytes(self as usize)
+                }
+
+                fn gib(self) -> ByteSize {
+                    ByteSize::gibibytes(self as usize)
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_size_ext!(usize, u32, u64, i32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,4 +624,119 @@ mod tests {
         assert!(small_file < image);
         assert!(image < video);
     }
+
+    #[test]
+    fn test_byte_size_parse_units() {
+        assert_eq!("1024".parse(), Ok(ByteSize(1024)));
+        assert_eq!("512B".parse(), Ok(ByteSize(512)));
+        assert_eq!("512MB".parse(), Ok(ByteSize(512_000_000)));
+        assert_eq!("512mb".parse(), Ok(ByteSize(512_000_000)));
+        assert_eq!("512MiB".parse(), Ok(ByteSize(512 * 1024 * 1024)));
+        assert_eq!("512m".parse(), Ok(ByteSize(512 * 1024 * 1024)));
+        assert_eq!("1.5 GiB".parse(), Ok(ByteSize(1024 * 1024 * 1024 * 3 / 2)));
+        assert_eq!(" 2 kb ".parse(), Ok(ByteSize(2000)));
+        assert_eq!(ByteSize::try_from(".5KiB"), Ok(ByteSize(512)));
+    }
+
+    #[test]
+    fn test_byte_size_parse_errors() {
+        assert_eq!("".parse::<ByteSize>(), Err(ParseByteSizeError::Empty));
+        assert_eq!(
+            "MB".parse::<ByteSize>(),
+            Err(ParseByteSizeError::InvalidNumber("MB".to_string()))
+        );
+        assert_eq!(
+            "-1KB".parse::<ByteSize>(),
+            Err(ParseByteSizeError::InvalidNumber("-1KB".to_string()))
+        );
+        assert_eq!(
+            "12 parsecs".parse::<ByteSize>(),
+            Err(ParseByteSizeError::UnknownUnit("parsecs".to_string()))
+        );
+        assert_eq!(
+            "1.2.3MB".parse::<ByteSize>(),
+            Err(ParseByteSizeError::InvalidNumber("1.2.3".to_string()))
+        );
+        assert_eq!(
+            "99999999999999999999TiB".parse::<ByteSize>(),
+            Err(ParseByteSizeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_byte_size_display() {
+        assert_eq!(ByteSize(512).to_string(), "512 B");
+        assert_eq!(ByteSize(1024).to_string(), "1 KiB");
+        assert_eq!(ByteSize(1536).to_string(), "1.5 KiB");
+        assert_eq!(ByteSize::megabytes(512).to_string(), "512 MiB");
+        assert_eq!(ByteSize(1024 * 1024 * 1024 * 3 / 2).to_string(), "1.5 GiB");
+        let displayed = ByteSize::megabytes(3).to_string();
+        assert_eq!(displayed.parse(), Ok(ByteSize::megabytes(3)));
+    }
+
+    #[test]
+    fn test_byte_size_serde() {
+        assert_eq!(
+            serde_json::from_str::<ByteSize>("1024").ok(),
+            Some(ByteSize(1024))
+        );
+        assert_eq!(
+            serde_json::from_str::<ByteSize>(r#""512mb""#).ok(),
+            Some(ByteSize(512_000_000))
+        );
+        assert!(serde_json::from_str::<ByteSize>(r#""lots""#).is_err());
+        assert!(serde_json::from_str::<ByteSize>("-1").is_err());
+        assert_eq!(
+            serde_json::to_string(&ByteSize(2048)).ok(),
+            Some("2048".to_string())
+        );
+    }
+
+    #[test]
+    fn test_byte_size_decimal_constructors() {
+        assert_eq!(ByteSize::kilobytes_decimal(1), ByteSize(1000));
+        assert_eq!(ByteSize::megabytes_decimal(1), ByteSize(1000 * 1000));
+        assert_eq!(ByteSize::gigabytes_decimal(1), ByteSize(1000 * 1000 * 1000));
+        assert_eq!(ByteSize::gigabytes(1), ByteSize(1024 * 1024 * 1024));
+        assert_eq!(ByteSize::kibibytes(1), ByteSize::kilobytes(1));
+        assert_eq!(ByteSize::mebibytes(1), ByteSize::megabytes(1));
+        assert_eq!(ByteSize::gibibytes(1), ByteSize::gigabytes(1));
+        assert_eq!(1.kb_decimal(), ByteSize(1000));
+        assert_eq!(1u32.mb_decimal(), ByteSize(1000 * 1000));
+        assert_eq!(1u64.gb_decimal(), ByteSize(1000 * 1000 * 1000));
+        assert_eq!(1i32.gib(), 1i32.gb());
+    }
+
+    #[test]
+    fn test_byte_size_decimal_and_binary_differ() {
+        assert_eq!("512MB".parse(), Ok(512.mb_decimal()));
+        assert_eq!("512MiB".parse(), Ok(512.mib()));
+        assert_eq!(512.mb(), 512.mib());
+        assert!(512.mb_decimal() < 512.mib());
+    }
+
+    #[test]
+    fn test_byte_size_arithmetic() {
+        let mut size = 3.kib();
+        assert_eq!(size - 1.kib(), 2.kib());
+        assert_eq!(size * 2, 6.kib());
+        assert_eq!(size / 3, 1.kib());
+        size += 1.kib();
+        size -= 2.kib();
+        assert_eq!(size, 2.kib());
+        assert_eq!([1.kib(), 2.kib()].into_iter().sum::<ByteSize>(), 3.kib());
+    }
+
+    #[test]
+    fn test_byte_size_checked_and_saturating() {
+        let max = ByteSize(usize::MAX);
+        assert_eq!(max.checked_add(1.bytes()), None);
+        assert_eq!(1.kb().checked_sub(2.kb()), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(1.kb().checked_div(0), None);
+        assert_eq!(1.kb_decimal().checked_div(4), Some(250.bytes()));
+        assert_eq!(max.saturating_add(1.bytes()), max);
+        assert_eq!(1.kb().saturating_sub(2.kb()), 0.bytes());
+        assert_eq!(max.saturating_mul(2), max);
+    }
 }
@@ -0,0 +1,100 @@
+//! Borrowed-or-owned string collections for config builders
+
+use std::borrow::Cow;
+
+use super::one_or_many::{EmptyListError, OneOrMany};
+use super::zero_one_or_many::ZeroOneOrMany;
+
+/// A string that is either a `&'static str` literal or an owned `String`.
+pub type CowStr = Cow<'static, str>;
+
+/// A non-empty collection of borrowed-or-owned strings.
+pub type OneOrManyStr = OneOrMany<CowStr>;
+
+/// A collection of zero, one, or many borrowed-or-owned strings.
+pub type ZeroOneOrManyStr = ZeroOneOrMany<CowStr>;
+
+impl OneOrMany<CowStr> {
+    /// Creates a collection from string literals or owned strings without copying literals.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sugars_collections::OneOrManyStr;
+    /// let hosts = OneOrManyStr::from_strs(["api.com", "db.com"]).unwrap();
+    /// assert_eq!(hosts.first(), "api.com");
+    /// ```
+    #[inline]
+    pub fn from_strs<I, S>(items: I) -> Result<Self, EmptyListError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<CowStr>,
+    {
+        OneOrMany::many(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl ZeroOneOrMany<CowStr> {
+    /// Creates a collection from string literals or owned strings without copying literals.
+    #[inline]
+    pub fn from_strs<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<CowStr>,
+    {
+        items.into_iter().map(Into::into).collect()
+    }
+}
+
+impl From<&'static str> for OneOrMany<CowStr> {
+    #[inline]
+    fn from(value: &'static str) -> Self {
+        OneOrMany::one(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for OneOrMany<CowStr> {
+    #[inline]
+    fn from(value: String) -> Self {
+        OneOrMany::one(Cow::Owned(value))
+    }
+}
+
+impl From<&'static str> for ZeroOneOrMany<CowStr> {
+    #[inline]
+    fn from(value: &'static str) -> Self {
+        ZeroOneOrMany::one(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for ZeroOneOrMany<CowStr> {
+    #[inline]
+    fn from(value: String) -> Self {
+        ZeroOneOrMany::one(Cow::Owned(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_one_or_many_macro_keeps_literals_borrowed() {
+        let tiers: OneOrManyStr = crate::one_or_many!["core", String::from("macros")];
+        assert_eq!(tiers.len(), 2);
+        assert!(matches!(tiers.first(), Cow::Borrowed("core")));
+        assert_eq!(tiers.rest(), [&Cow::<str>::Owned("macros".to_string())]);
+        assert_eq!(crate::one_or_many!["solo"], OneOrMany::from("solo"));
+    }
+
+    #[test]
+    fn test_from_strs() {
+        assert!(OneOrManyStr::from_strs(Vec::<String>::new()).is_err());
+        let names = ZeroOneOrManyStr::from_strs(["a", "b"]);
+        assert_eq!(names.to_vec(), ["a", "b"]);
+        assert_eq!(
+            ZeroOneOrManyStr::from(String::from("x")),
+            ZeroOneOrMany::one("x".into())
+        );
+    }
+}
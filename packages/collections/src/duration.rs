@@ -0,0 +1,311 @@
+//! Duration utilities mirroring `ByteSizeExt`
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serializer};
+use std::fmt;
+use std::time::Duration;
+
+/// Extension trait to build `std::time::Duration`s from integers, e.g. `30.seconds()`
+///
+/// Negative values become a zero duration, and values too large for `Duration` saturate at
+/// the largest one instead of overflowing.
+pub trait DurationExt {
+    /// Converts this value to a Duration of the given number of milliseconds.
+    fn milliseconds(self) -> Duration;
+    /// Converts this value to a Duration of the given number of seconds.
+    fn seconds(self) -> Duration;
+    /// Converts this value to a Duration of the given number of minutes.
+    fn minutes(self) -> Duration;
+    /// Converts this value to a Duration of the given number of hours.
+    fn hours(self) -> Duration;
+    /// Converts this value to a Duration of the given number of days.
+    fn days(self) -> Duration;
+}
+
+/// Builds a Duration of `count` units of `unit_secs` seconds, saturating on overflow
+fn saturating_secs(count: u64, unit_secs: u64) -> Duration {
+    Duration::from_secs(count.saturating_mul(unit_secs))
+}
+
+macro_rules! impl_duration_ext {
+    ($($ty:ty),*) => {
+        $(
+            impl DurationExt for $ty {
+                fn milliseconds(self) -> Duration {
+                    Duration::from_millis(u64::try_from(self).unwrap_or(0))
+                }
+
+                fn seconds(self) -> Duration {
+                    saturating_secs(u64::try_from(self).unwrap_or(0), 1)
+                }
+
+                fn minutes(self) -> Duration {
+                    saturating_secs(u64::try_from(self).unwrap_or(0), 60)
+                }
+
+                fn hours(self) -> Duration {
+                    saturating_secs(u64::try_from(self).unwrap_or(0), 60 * 60)
+                }
+
+                fn days(self) -> Duration {
+                    saturating_secs(u64::try_from(self).unwrap_or(0), 60 * 60 * 24)
+                }
+            }
+        )*
+    };
+}
+
+impl_duration_ext!(usize, u32, u64, i32);
+
+/// Error returned when a string is not a valid duration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The string was empty or only whitespace
+    Empty,
+    /// A numeric part was missing, negative or malformed
+    InvalidNumber(String),
+    /// A unit suffix was not recognized
+    UnknownUnit(String),
+    /// The duration does not fit in `Duration`
+    Overflow,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDurationError::Empty => write!(f, "duration cannot be empty"),
+            ParseDurationError::InvalidNumber(number) => {
+                write!(f, "invalid duration number: {number:?}")
+            }
+            ParseDurationError::UnknownUnit(unit) => {
+                write!(
+                    f,
+                    "unknown duration unit: {unit:?} (expected ms, s, m, h or d)"
+                )
+            }
+            ParseDurationError::Overflow => write!(f, "duration is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Returns the length of a unit suffix in seconds.
+fn unit_seconds(unit: &str) -> Option<f64> {
+    let seconds = match unit {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        "h" => 60.0 * 60.0,
+        "d" => 60.0 * 60.0 * 24.0,
+        _ => return None,
+    };
+    Some(seconds)
+}
+
+/// Parses a duration such as `"30s"`, `"5m"`, `"1.5h"`, `"250ms"` or `"1h30m"`.
+///
+/// Units are `ms`, `s`, `m`, `h` and `d`, matched case-insensitively; a bare number is a
+/// number of seconds.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use sugars_collections::{DurationExt, parse_duration};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(parse_duration("1h30m")?, 90.minutes());
+/// assert_eq!(parse_duration("250ms")?, Duration::from_millis(250));
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let s = s.trim().to_ascii_lowercase();
+    if s.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+
+    let mut total = Duration::ZERO;
+    let mut rest = s.as_str();
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(number_len);
+        if number.is_empty() {
+            return Err(ParseDurationError::InvalidNumber(rest.to_string()));
+        }
+        let after = after.trim_start();
+        let unit_len = after
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_len);
+        let seconds =
+            unit_seconds(unit).ok_or_else(|| ParseDurationError::UnknownUnit(unit.to_string()))?;
+        let value: f64 = number
+            .parse()
+            .map_err(|_| ParseDurationError::InvalidNumber(number.to_string()))?;
+        let part = Duration::try_from_secs_f64(value * seconds)
+            .map_err(|_| ParseDurationError::Overflow)?;
+        total = total
+            .checked_add(part)
+            .ok_or(ParseDurationError::Overflow)?;
+        rest = after.trim_start();
+    }
+    Ok(total)
+}
+
+/// Formats a duration with the largest units that fit, e.g. `"1h30m"` or `"250ms"`.
+///
+/// The output parses back with `parse_duration`; precision below a millisecond is dropped.
+pub fn format_duration(duration: Duration) -> String {
+    const UNITS: [(&str, u128); 5] = [
+        ("d", 24 * 60 * 60 * 1000),
+        ("h", 60 * 60 * 1000),
+        ("m", 60 * 1000),
+        ("s", 1000),
+        ("ms", 1),
+    ];
+    let mut millis = duration.as_millis();
+    if millis == 0 {
+        return "0s".to_string();
+    }
+    let mut formatted = String::new();
+    for (unit, unit_millis) in UNITS {
+        let count = millis / unit_millis;
+        if count > 0 {
+            formatted.push_str(&format!("{count}{unit}"));
+            millis %= unit_millis;
+        }
+    }
+    formatted
+}
+
+/// Human-readable serde format for `Duration` fields, for use with
+/// `#[serde(with = "sugars_collections::duration::human")]`.
+///
+/// Serializes as a string such as `"1h30m"` and deserializes from a duration string or
+/// a number of seconds, so builders and configs can say `timeout = "30s"`.
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use std::time::Duration;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "sugars_collections::duration::human")]
+///     timeout: Duration,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"timeout": "5m"}"#).unwrap();
+/// assert_eq!(config.timeout, Duration::from_secs(300));
+/// let config: Config = serde_json::from_str(r#"{"timeout": 30}"#).unwrap();
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"30s"}"#);
+/// ```
+pub mod human {
+    use super::*;
+
+    /// Serializes a duration as a human-readable string.
+    pub fn serialize<S: Serializer>(duration: &Duration, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(&format_duration(*duration))
+    }
+
+    /// Deserializes a duration from a duration string or a number of seconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<Duration, D::Error> {
+        struct V;
+        impl Visitor<'_> for V {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a number of seconds or a duration string such as \"30s\"")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(Duration::from_secs(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map(Duration::from_secs)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+                Duration::try_from_secs_f64(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Float(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                parse_duration(value).map_err(E::custom)
+            }
+        }
+
+        // Binary formats cannot deserialize_any; they always hold the string form.
+        if de.is_human_readable() {
+            de.deserialize_any(V)
+        } else {
+            let value = String::deserialize(de)?;
+            parse_duration(&value).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_duration_ext() {
+        assert_eq!(250.milliseconds(), Duration::from_millis(250));
+        assert_eq!(30.seconds(), Duration::from_secs(30));
+        assert_eq!(5u32.minutes(), Duration::from_secs(300));
+        assert_eq!(2u64.hours(), Duration::from_secs(7200));
+        assert_eq!(1usize.days(), Duration::from_secs(86400));
+    }
+
+    #[test]
+    fn test_duration_ext_negative_and_overflow() {
+        assert_eq!((-5i32).seconds(), Duration::ZERO);
+        assert_eq!((-1i32).days(), Duration::ZERO);
+        assert_eq!((-250i32).milliseconds(), Duration::ZERO);
+        assert_eq!(u64::MAX.days(), Duration::from_secs(u64::MAX));
+        assert_eq!((u64::MAX / 60).hours(), Duration::from_secs(u64::MAX));
+        assert_eq!(u64::MAX.milliseconds(), Duration::from_millis(u64::MAX));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s"), Ok(30.seconds()));
+        assert_eq!(parse_duration("5m"), Ok(5.minutes()));
+        assert_eq!(parse_duration("90"), Ok(90.seconds()));
+        assert_eq!(parse_duration("1.5h"), Ok(90.minutes()));
+        assert_eq!(parse_duration("1h 30m"), Ok(90.minutes()));
+        assert_eq!(parse_duration("2D"), Ok(2.days()));
+        assert_eq!(parse_duration("250ms"), Ok(250.milliseconds()));
+    }
+
+    #[test]
+    fn test_parse_duration_errors() {
+        assert_eq!(parse_duration(" "), Err(ParseDurationError::Empty));
+        assert_eq!(
+            parse_duration("5 fortnights"),
+            Err(ParseDurationError::UnknownUnit("fortnights".to_string()))
+        );
+        assert_eq!(
+            parse_duration("-5s"),
+            Err(ParseDurationError::InvalidNumber("-5s".to_string()))
+        );
+        assert_eq!(
+            parse_duration("1.2.3s"),
+            Err(ParseDurationError::InvalidNumber("1.2.3".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_format_duration_round_trips() {
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+        assert_eq!(format_duration(90.minutes()), "1h30m");
+        assert_eq!(format_duration(1500.milliseconds()), "1s500ms");
+        let duration = 2.days() + 3.hours() + 250.milliseconds();
+        assert_eq!(parse_duration(&format_duration(duration)), Ok(duration));
+    }
+}
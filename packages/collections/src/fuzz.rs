@@ -0,0 +1,132 @@
+// -----------------------------------------------------------------------------
+// src/fuzz.rs
+// -----------------------------------------------------------------------------
+
+//! Property-test and fuzzing support for `ZeroOneOrMany` and `OneOrMany`.
+//!
+//! Generated collections always use the variant matching their length (`None`, `One`,
+//! then `Many`), and a `OneOrMany` is never empty. The proptest strategies shrink by
+//! removing and shrinking elements, never below one element for `OneOrMany`.
+
+use crate::one_or_many::OneOrMany;
+use crate::zero_one_or_many::ZeroOneOrMany;
+
+/// Picks the variant matching the number of generated elements.
+fn from_generated<T>(mut items: Vec<T>) -> ZeroOneOrMany<T> {
+    match items.len() {
+        0 => ZeroOneOrMany::None,
+        1 => ZeroOneOrMany::One(items.swap_remove(0)),
+        _ => ZeroOneOrMany::Many(items),
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impls {
+    use super::*;
+    use proptest::arbitrary::{Arbitrary, any_with};
+    use proptest::collection::{SizeRange, vec};
+    use proptest::strategy::{BoxedStrategy, Strategy};
+    use std::fmt::Debug;
+
+    impl<T: Arbitrary + Debug + 'static> Arbitrary for ZeroOneOrMany<T> {
+        /// Element count range (0 to 99 by default) and element parameters.
+        type Parameters = (SizeRange, T::Parameters);
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+            vec(any_with::<T>(params), size)
+                .prop_map(from_generated)
+                .boxed()
+        }
+    }
+
+    impl<T: Arbitrary + Debug + 'static> Arbitrary for OneOrMany<T> {
+        /// Element count range, raised to at least one, and element parameters.
+        type Parameters = (SizeRange, T::Parameters);
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+            let size = SizeRange::new(size.start().max(1)..=size.end_incl().max(1));
+            vec(any_with::<T>(params), size)
+                .prop_map(|items| OneOrMany(from_generated(items)))
+                .boxed()
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::*;
+    use arbitrary::{Arbitrary, Result, Unstructured, size_hint};
+
+    impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for ZeroOneOrMany<T> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Vec::arbitrary(u).map(from_generated)
+        }
+
+        fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+            Vec::arbitrary_take_rest(u).map(from_generated)
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            Vec::<T>::size_hint(depth)
+        }
+    }
+
+    impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for OneOrMany<T> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let first = T::arbitrary(u)?;
+            let rest = Vec::arbitrary(u)?;
+            Ok(with_first(first, rest))
+        }
+
+        fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+            let first = T::arbitrary(&mut u)?;
+            let rest = Vec::arbitrary_take_rest(u)?;
+            Ok(with_first(first, rest))
+        }
+
+        fn size_hint(depth: usize) -> (usize, Option<usize>) {
+            size_hint::and(T::size_hint(depth), Vec::<T>::size_hint(depth))
+        }
+    }
+
+    /// Builds a `OneOrMany` from a generated first element and the generated rest.
+    fn with_first<T>(first: T, rest: Vec<T>) -> OneOrMany<T> {
+        OneOrMany(from_generated(std::iter::once(first).chain(rest).collect()))
+    }
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn variant_matches_len<T>(items: &ZeroOneOrMany<T>) -> bool {
+        match items {
+            ZeroOneOrMany::None => true,
+            ZeroOneOrMany::One(_) => true,
+            ZeroOneOrMany::Many(v) => v.len() > 1,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_zero_one_or_many_variant_matches_len(items in any::<ZeroOneOrMany<u8>>()) {
+            prop_assert!(variant_matches_len(&items));
+        }
+
+        #[test]
+        fn test_one_or_many_never_empty(items in any::<OneOrMany<u8>>()) {
+            prop_assert!(items.iter().next().is_some());
+            prop_assert!(variant_matches_len(&items.0));
+        }
+
+        #[test]
+        fn test_one_or_many_respects_zero_min_size(
+            items in any_with::<OneOrMany<u8>>(((0..3usize).into(), ()))
+        ) {
+            prop_assert!((1..3).contains(&items.len()));
+        }
+    }
+}
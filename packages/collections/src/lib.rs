@@ -2,7 +2,19 @@
 
 #![feature(auto_traits, negative_impls)]
 
+/// Vectors whose length is bounded at construction.
+pub mod bounded_vec;
 pub mod byte_size;
+/// `Cow<'static, str>` collections that keep string literals borrowed.
+pub mod cow_str;
+/// Duration constructors, parsing, and serde mirroring `ByteSizeExt`.
+pub mod duration;
+/// Maps from keys to zero, one, or many values.
+pub mod multi_map;
+/// Hash maps guaranteed to hold at least one entry.
+pub mod non_empty_map;
+/// Strings guaranteed to hold at least one character.
+pub mod non_empty_string;
 /// A non-empty collection guaranteed to hold at least one value.
 pub mod one_or_many;
 /// A collection that can hold zero, one, or many values, optimized for minimal allocations.
@@ -11,17 +23,42 @@ pub mod zero_one_or_many;
 /// Extension traits for array tuple syntax support
 pub mod array_tuple_ext;
 
+/// Strict array-only serde mode for `OneOrMany` and `ZeroOneOrMany`
+pub mod serde_strict;
+
+/// bincode 2 `Encode`/`Decode` for the collection types
+#[cfg(feature = "bincode")]
+mod binary;
+
+/// `proptest` and `arbitrary` generators for the collection types
+#[cfg(any(feature = "proptest", feature = "arbitrary"))]
+mod fuzz;
+
+/// Parallel iteration over `ZeroOneOrMany` and `OneOrMany` via rayon
+#[cfg(feature = "rayon")]
+pub mod par_iter;
+
 // Re-export main types
-pub use byte_size::{ByteSize, ByteSizeExt};
+pub use bounded_vec::{BoundedVec, BoundsError};
+pub use byte_size::{ByteSize, ByteSizeExt, ParseByteSizeError};
+pub use cow_str::{CowStr, OneOrManyStr, ZeroOneOrManyStr};
+pub use duration::{DurationExt, ParseDurationError, format_duration, parse_duration};
+pub use multi_map::MultiMap;
+pub use non_empty_map::{EmptyMapError, NonEmptyMap, OneOrManyMap};
+pub use non_empty_string::{EmptyStringError, NonEmptyStr, NonEmptyString};
 pub use one_or_many::OneOrMany;
 pub use zero_one_or_many::ZeroOneOrMany;
 
+// Re-export the map type behind ordered_map!
+#[cfg(feature = "indexmap")]
+pub use indexmap::IndexMap;
+
 // Re-export extension traits
 #[cfg(feature = "array-tuples")]
 pub use array_tuple_ext::{
-    ArrayTupleObjectExtKString, ArrayTupleObjectExtKV, ArrayTupleObjectExtStringString,
-    ArrayTupleObjectExtStringV, CollectionArrayTupleExtKString, CollectionArrayTupleExtKV,
-    CollectionArrayTupleExtStringString, CollectionArrayTupleExtStringV,
+    ArrayTupleObjectExtJson, ArrayTupleObjectExtKString, ArrayTupleObjectExtKV,
+    ArrayTupleObjectExtStringString, ArrayTupleObjectExtStringV, CollectionArrayTupleExtKString,
+    CollectionArrayTupleExtKV, CollectionArrayTupleExtStringString, CollectionArrayTupleExtStringV,
     TryCollectionArrayTupleExtKString, TryCollectionArrayTupleExtKV,
     TryCollectionArrayTupleExtStringString, TryCollectionArrayTupleExtStringV,
 };
@@ -51,6 +88,195 @@ macro_rules! hash_map {
     };
 }
 
+/// Creates an `IndexMap` that keeps entries in the order they are written
+///
+/// Later entries with the same key replace the value but keep the key's first position.
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::ordered_map;
+///
+/// let tiers = ordered_map! {
+///     "core" => 0,
+///     "macros" => 1,
+///     "release" => 2,
+/// };
+/// assert_eq!(tiers.keys().copied().collect::<Vec<_>>(), ["core", "macros", "release"]);
+/// ```
+#[cfg(feature = "indexmap")]
+#[macro_export]
+macro_rules! ordered_map {
+    // Counts entries for the initial capacity
+    (@unit $key:expr) => { () };
+
+    ( $($key:expr => $value:expr),* $(,)? ) => {{
+        let mut map = $crate::IndexMap::with_capacity(
+            <[()]>::len(&[$($crate::ordered_map!(@unit $key)),*]),
+        );
+        $(
+            map.insert($key, $value);
+        )*
+        map
+    }};
+}
+
+/// Creates an `IndexMap` that keeps entries in the order they are written
+///
+/// Alias of `ordered_map!`, named to match the other map literal macros.
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::index_map;
+///
+/// let tiers = index_map! { "core" => 0, "macros" => 1 };
+/// assert_eq!(tiers.get_index(1), Some((&"macros", &1)));
+/// ```
+#[cfg(feature = "indexmap")]
+#[macro_export]
+macro_rules! index_map {
+    ( $($key:expr => $value:expr),* $(,)? ) => {
+        $crate::ordered_map! { $($key => $value),* }
+    };
+}
+
+/// Creates a `BTreeMap` from `key => value` pairs, sorted by key
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::btree_map;
+///
+/// let limits = btree_map! { "tokens" => 8000, "retries" => 3 };
+/// assert_eq!(limits.keys().copied().collect::<Vec<_>>(), ["retries", "tokens"]);
+/// ```
+#[macro_export]
+macro_rules! btree_map {
+    ( $($key:expr => $value:expr),* $(,)? ) => {{
+        let mut map = ::std::collections::BTreeMap::new();
+        $(
+            map.insert($key, $value);
+        )*
+        map
+    }};
+}
+
+/// Creates a hashbrown `HashSet` from a list of values
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::hash_set;
+///
+/// let features = hash_set!["rayon", "serde", "rayon"];
+/// assert_eq!(features.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! hash_set {
+    // Counts values for the initial capacity
+    (@unit $value:expr) => { () };
+
+    [ $($value:expr),* $(,)? ] => {{
+        let mut set = ::hashbrown::HashSet::with_capacity(
+            <[()]>::len(&[$($crate::hash_set!(@unit $value)),*]),
+        );
+        $(
+            set.insert($value);
+        )*
+        set
+    }};
+}
+
+/// Creates a hashbrown `HashMap<String, serde_json::Value>` from a JSON-like object literal
+///
+/// Keys are string literals followed by `:`. Values may be nested `{ ... }` objects,
+/// `[ ... ]` arrays, or any expression `serde_json::json!` accepts, so numbers, bools and
+/// strings can be mixed freely.
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::obj;
+///
+/// let max = 3;
+/// let params = obj! {
+///     "stream": true,
+///     "retry": { "max": max, "codes": [429, 503] },
+///     "temperature": -0.5,
+/// };
+/// assert_eq!(params["retry"]["codes"][1], 503);
+/// assert_eq!(params["temperature"], -0.5);
+/// ```
+#[macro_export]
+macro_rules! obj {
+    // Munches tokens for one value up to the next top-level comma
+    (@value $map:ident $key:literal ($($value:tt)*) ()) => {
+        $map.insert(::std::string::String::from($key), ::serde_json::json!($($value)*));
+    };
+    (@value $map:ident $key:literal ($($value:tt)*) (, $($rest:tt)*)) => {
+        $map.insert(::std::string::String::from($key), ::serde_json::json!($($value)*));
+        $crate::obj!(@entries $map ($($rest)*));
+    };
+    (@value $map:ident $key:literal ($($value:tt)*) ($next:tt $($rest:tt)*)) => {
+        $crate::obj!(@value $map $key ($($value)* $next) ($($rest)*));
+    };
+
+    (@entries $map:ident ()) => {};
+    (@entries $map:ident ($key:literal : $($rest:tt)*)) => {
+        $crate::obj!(@value $map $key () ($($rest)*));
+    };
+
+    { $($body:tt)* } => {{
+        let mut map: ::hashbrown::HashMap<::std::string::String, ::serde_json::Value> =
+            ::hashbrown::HashMap::new();
+        $crate::obj!(@entries map ($($body)*));
+        map
+    }};
+}
+
+/// Creates a `OneOrManyStr` from string literals or owned strings
+///
+/// Literals stay borrowed, so config builders can skip `.to_string()`. At least one
+/// element is required.
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::{OneOrManyStr, one_or_many};
+///
+/// let hosts: OneOrManyStr = one_or_many!["api.com", format!("db-{}.com", 1)];
+/// assert_eq!(hosts.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! one_or_many {
+    [ $first:expr $(, $rest:expr)* $(,)? ] => {{
+        let mut items = $crate::OneOrMany::<$crate::CowStr>::one(
+            ::std::borrow::Cow::from($first),
+        );
+        ::core::iter::Extend::<$crate::CowStr>::extend(
+            &mut items,
+            [$(::std::borrow::Cow::from($rest)),*],
+        );
+        items
+    }};
+}
+
+/// Creates an array of key-value pairs whose values may mix JSON types
+///
+/// Each value is converted with `serde_json::Value::from`, so numbers, bools and strings
+/// can share one array tuple literal.
+///
+/// Usage:
+/// ```rust
+/// use sugars_collections::{ArrayTupleObjectExtJson, json_pairs};
+///
+/// let pairs = json_pairs![("max_tokens", 8000), ("stream", true)];
+/// let params = serde_json::Map::from_json_pairs(pairs);
+/// assert_eq!(params["stream"], true);
+/// ```
+#[cfg(feature = "array-tuples")]
+#[macro_export]
+macro_rules! json_pairs {
+    [ $(($key:expr, $value:expr)),* $(,)? ] => {
+        [$(($key, ::serde_json::Value::from($value))),*]
+    };
+}
+
 /// Transforms array tuple syntax in builder chains to work with hash_map! macro
 ///
 /// This macro makes `[("key", "value")]` syntax work transparently as closures
@@ -0,0 +1,207 @@
+//! Maps from keys to zero, one, or many values
+
+use crate::zero_one_or_many::ZeroOneOrMany;
+use hashbrown::HashMap;
+use std::hash::Hash;
+
+/// A hash map holding any number of values per key, stored as `ZeroOneOrMany<V>`.
+///
+/// Keys with a single value avoid allocating a `Vec`. Iteration is flattened, yielding
+/// one `(key, value)` pair per value; keys are unordered, values keep insertion order.
+///
+/// ### Examples
+/// ```rust
+/// use sugars_collections::{MultiMap, ZeroOneOrMany};
+///
+/// let mut owners = MultiMap::new();
+/// owners.append("collections", "alice");
+/// owners.append("collections", "bob");
+/// owners.insert("macros", "carol");
+/// assert_eq!(owners.get("collections"), Some(&ZeroOneOrMany::many(vec!["alice", "bob"])));
+/// assert_eq!(owners.iter().count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiMap<K, V>(HashMap<K, ZeroOneOrMany<V>>);
+
+impl<K, V> MultiMap<K, V> {
+    /// Creates an empty map.
+    #[inline]
+    pub fn new() -> Self {
+        MultiMap(HashMap::new())
+    }
+
+    /// Returns the number of keys.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of values across all keys.
+    #[inline]
+    pub fn values_len(&self) -> usize {
+        self.0.values().map(ZeroOneOrMany::len).sum()
+    }
+
+    /// Checks if the map has no keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the keys.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.0.keys()
+    }
+
+    /// Returns an iterator over each key and all of its values.
+    #[inline]
+    pub fn iter_all(&self) -> impl Iterator<Item = (&K, &ZeroOneOrMany<V>)> {
+        self.0.iter()
+    }
+
+    /// Returns a flattened iterator yielding one `(key, value)` pair per value.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.0
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+    }
+}
+
+impl<K: Eq + Hash, V> MultiMap<K, V> {
+    /// Sets the values for a key to `value` alone, returning the values it replaced.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<ZeroOneOrMany<V>> {
+        self.0.insert(key, ZeroOneOrMany::One(value))
+    }
+
+    /// Adds `value` after any existing values for a key.
+    #[inline]
+    pub fn append(&mut self, key: K, value: V) {
+        self.0.entry(key).or_default().push(value);
+    }
+
+    /// Returns the values for a key, if present.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&ZeroOneOrMany<V>>
+    where
+        Q: Hash + hashbrown::Equivalent<K> + ?Sized,
+    {
+        self.0.get(key)
+    }
+
+    /// Returns the values for a key mutably, if present.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut ZeroOneOrMany<V>>
+    where
+        Q: Hash + hashbrown::Equivalent<K> + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Checks if the map has values for a key.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + hashbrown::Equivalent<K> + ?Sized,
+    {
+        self.0.contains_key(key)
+    }
+
+    /// Removes a key, returning its values.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<ZeroOneOrMany<V>>
+    where
+        Q: Hash + hashbrown::Equivalent<K> + ?Sized,
+    {
+        self.0.remove(key)
+    }
+}
+
+impl<K, V> Default for MultiMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        MultiMap::new()
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for MultiMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for MultiMap<K, V> {}
+
+// Conversion Traits
+impl<K: Eq + Hash, V> FromIterator<(K, V)> for MultiMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = MultiMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for MultiMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.append(key, value);
+        }
+    }
+}
+
+impl<K, V> From<MultiMap<K, V>> for HashMap<K, ZeroOneOrMany<V>> {
+    #[inline]
+    fn from(value: MultiMap<K, V>) -> Self {
+        value.0
+    }
+}
+
+// Owned iterator clones each key once per value, matching ZeroOneOrMany's boxed iterators
+impl<K: Clone + 'static, V: 'static> IntoIterator for MultiMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = Box<dyn Iterator<Item = (K, V)>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.0.into_iter().flat_map(|(key, values)| {
+            Vec::from(values)
+                .into_iter()
+                .map(move |value| (key.clone(), value))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_multi_map_insert_and_append() {
+        let mut map = MultiMap::new();
+        assert_eq!(map.insert("a", 1), None);
+        map.append("a", 2);
+        map.append("b", 3);
+        assert_eq!(map.get("a"), Some(&ZeroOneOrMany::Many(vec![1, 2])));
+        assert_eq!(map.get("b"), Some(&ZeroOneOrMany::One(3)));
+        assert_eq!(map.insert("a", 4), Some(ZeroOneOrMany::Many(vec![1, 2])));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.values_len(), 2);
+    }
+
+    #[test]
+    fn test_multi_map_flattened_iteration() {
+        let map: MultiMap<&str, u8> = [("a", 1), ("b", 2), ("a", 3)].into_iter().collect();
+        let mut pairs: Vec<(&str, u8)> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![("a", 1), ("a", 3), ("b", 2)]);
+
+        let mut owned: Vec<(&str, u8)> = map.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec![("a", 1), ("a", 3), ("b", 2)]);
+    }
+}
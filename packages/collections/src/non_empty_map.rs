@@ -0,0 +1,276 @@
+//! Hash maps guaranteed to hold at least one entry
+
+use hashbrown::HashMap;
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// A hash map that holds at least one entry.
+///
+/// Use it for settings such as "at least one provider must be configured". It derefs to
+/// the underlying `hashbrown::HashMap` for lookups and iteration; operations that could
+/// remove the last entry are not offered.
+///
+/// ### Serialization and Deserialization
+/// Serializes to a JSON object. Deserializes from an object or, like `OneOrMany`, from an
+/// array of `[key, value]` pairs, failing if either is empty.
+///
+/// ### Examples
+/// ```rust
+/// use sugars_collections::NonEmptyMap;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut providers = NonEmptyMap::one("openai", 8080);
+/// providers.insert("anthropic", 8081);
+/// assert_eq!(providers.len(), 2);
+/// assert!(NonEmptyMap::<&str, u16>::many(Vec::new()).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NonEmptyMap<K, V>(HashMap<K, V>);
+
+/// Alternative name for `NonEmptyMap`, matching `OneOrMany`.
+pub type OneOrManyMap<K, V> = NonEmptyMap<K, V>;
+
+/// Error returned when attempting to create a `NonEmptyMap` with no entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyMapError;
+
+impl fmt::Display for EmptyMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NonEmptyMap cannot be empty")
+    }
+}
+
+impl std::error::Error for EmptyMapError {}
+
+impl<K: Eq + Hash, V> NonEmptyMap<K, V> {
+    /// Creates a map with a single entry.
+    #[inline]
+    pub fn one(key: K, value: V) -> Self {
+        let mut map = HashMap::with_capacity(1);
+        map.insert(key, value);
+        NonEmptyMap(map)
+    }
+
+    /// Creates a map from key-value pairs, failing if there are none.
+    /// Later pairs replace earlier ones with the same key.
+    #[inline]
+    pub fn many<I: IntoIterator<Item = (K, V)>>(entries: I) -> Result<Self, EmptyMapError> {
+        Self::from_hashmap(entries.into_iter().collect())
+    }
+
+    /// Wraps a hashbrown HashMap, failing if it is empty.
+    #[inline]
+    pub fn from_hashmap(map: HashMap<K, V>) -> Result<Self, EmptyMapError> {
+        if map.is_empty() {
+            Err(EmptyMapError)
+        } else {
+            Ok(NonEmptyMap(map))
+        }
+    }
+
+    /// Inserts an entry, returning the previous value for the key, if any.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Returns a mutable reference to the value for a key, if present.
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Hash + hashbrown::Equivalent<K> + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+}
+
+impl<K, V> NonEmptyMap<K, V> {
+    /// Returns an entry of the map. The map is unordered, so which entry is unspecified,
+    /// but it is stable while the map is not modified.
+    #[inline]
+    pub fn first_entry(&self) -> (&K, &V) {
+        self.0
+            .iter()
+            .next()
+            .unwrap_or_else(|| unreachable!("NonEmptyMap cannot be empty"))
+    }
+
+    /// Returns whether the map is empty (always false for NonEmptyMap).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Consumes the wrapper, returning the inner `HashMap`.
+    #[inline]
+    pub fn into_inner(self) -> HashMap<K, V> {
+        self.0
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for NonEmptyMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for NonEmptyMap<K, V> {}
+
+impl<K, V> Deref for NonEmptyMap<K, V> {
+    type Target = HashMap<K, V>;
+    #[inline]
+    fn deref(&self) -> &HashMap<K, V> {
+        &self.0
+    }
+}
+
+impl<K, V> IntoIterator for NonEmptyMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = hashbrown::hash_map::IntoIter<K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a NonEmptyMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hashbrown::hash_map::Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for NonEmptyMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+// Serde Support
+impl<K: Serialize, V: Serialize> Serialize for NonEmptyMap<K, V> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for NonEmptyMap<K, V>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct Vis<K, V>(PhantomData<(K, V)>);
+        impl<'de, K, V> Visitor<'de> for Vis<K, V>
+        where
+            K: Deserialize<'de> + Eq + Hash,
+            V: Deserialize<'de>,
+        {
+            type Value = NonEmptyMap<K, V>;
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a non-empty map or sequence of key-value pairs")
+            }
+
+            #[inline]
+            fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut map = HashMap::with_capacity(access.size_hint().unwrap_or(0));
+                while let Some((key, value)) = access.next_entry()? {
+                    map.insert(key, value);
+                }
+                NonEmptyMap::from_hashmap(map)
+                    .map_err(|_| de::Error::invalid_length(0, &"at least one entry"))
+            }
+
+            #[inline]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut map = HashMap::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some((key, value)) = seq.next_element()? {
+                    map.insert(key, value);
+                }
+                NonEmptyMap::from_hashmap(map)
+                    .map_err(|_| de::Error::invalid_length(0, &"at least one entry"))
+            }
+        }
+
+        // Binary formats cannot deserialize_any; they always hold a length-prefixed map.
+        if de.is_human_readable() {
+            de.deserialize_any(Vis(PhantomData))
+        } else {
+            de.deserialize_map(Vis(PhantomData))
+        }
+    }
+}
+
+// Conversion Traits
+impl<K: Eq + Hash, V> TryFrom<HashMap<K, V>> for NonEmptyMap<K, V> {
+    type Error = EmptyMapError;
+
+    #[inline]
+    fn try_from(map: HashMap<K, V>) -> Result<Self, Self::Error> {
+        NonEmptyMap::from_hashmap(map)
+    }
+}
+
+impl<K, V> From<NonEmptyMap<K, V>> for HashMap<K, V> {
+    #[inline]
+    fn from(value: NonEmptyMap<K, V>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_non_empty_map_constructors() {
+        let one = NonEmptyMap::one("a", 1);
+        assert_eq!(one.first_entry(), (&"a", &1));
+        let many = NonEmptyMap::many([("a", 1), ("b", 2), ("a", 3)]).expect("non-empty");
+        assert_eq!(many.len(), 2);
+        assert_eq!(many.get("a"), Some(&3));
+        assert_eq!(NonEmptyMap::<&str, u8>::many([]), Err(EmptyMapError));
+        assert_eq!(
+            NonEmptyMap::<&str, u8>::try_from(HashMap::new()),
+            Err(EmptyMapError)
+        );
+    }
+
+    #[test]
+    fn test_non_empty_map_serde() {
+        let map: NonEmptyMap<String, u8> =
+            serde_json::from_str(r#"{"primary": 1}"#).expect("deserialize object");
+        assert_eq!(
+            serde_json::to_string(&map).expect("serialize"),
+            r#"{"primary":1}"#
+        );
+
+        let pairs: NonEmptyMap<String, u8> =
+            serde_json::from_str(r#"[["primary", 1]]"#).expect("deserialize pairs");
+        assert_eq!(pairs, map);
+
+        assert!(serde_json::from_str::<NonEmptyMap<String, u8>>("{}").is_err());
+        assert!(serde_json::from_str::<NonEmptyMap<String, u8>>("[]").is_err());
+        assert!(serde_json::from_str::<NonEmptyMap<String, u8>>("null").is_err());
+    }
+}
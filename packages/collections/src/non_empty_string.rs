@@ -0,0 +1,281 @@
+//! Strings guaranteed to hold at least one character
+
+use serde::de::{self, Deserializer, Unexpected};
+use serde::{Deserialize, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// An owned string that is never empty.
+///
+/// Use it for required string fields such as names and URLs, where an empty value is as
+/// wrong as a missing one. Only the empty string is rejected; whitespace is kept as is.
+///
+/// Serializes as a plain string. Deserialization fails on `""`.
+///
+/// ### Examples
+/// ```rust
+/// use sugars_collections::NonEmptyString;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let name: NonEmptyString = "release-bot".parse()?;
+/// assert_eq!(name.len(), 11);
+/// assert!(NonEmptyString::new("").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(String);
+
+/// A borrowed string slice that is never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyStr<'a>(&'a str);
+
+/// Error returned when attempting to create a `NonEmptyString` or `NonEmptyStr` from `""`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyStringError;
+
+impl fmt::Display for EmptyStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "string cannot be empty")
+    }
+}
+
+impl std::error::Error for EmptyStringError {}
+
+impl NonEmptyString {
+    /// Creates a non-empty string, failing if `value` is empty.
+    #[inline]
+    pub fn new(value: impl Into<String>) -> Result<Self, EmptyStringError> {
+        let value = value.into();
+        if value.is_empty() {
+            Err(EmptyStringError)
+        } else {
+            Ok(NonEmptyString(value))
+        }
+    }
+
+    /// Returns the string as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Borrows the string as a `NonEmptyStr`.
+    #[inline]
+    pub fn as_non_empty_str(&self) -> NonEmptyStr<'_> {
+        NonEmptyStr(&self.0)
+    }
+
+    /// Consumes the wrapper, returning the inner `String`.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<'a> NonEmptyStr<'a> {
+    /// Wraps a string slice, failing if it is empty.
+    #[inline]
+    pub fn new(value: &'a str) -> Result<Self, EmptyStringError> {
+        if value.is_empty() {
+            Err(EmptyStringError)
+        } else {
+            Ok(NonEmptyStr(value))
+        }
+    }
+
+    /// Returns the underlying string slice.
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Copies the slice into an owned `NonEmptyString`.
+    #[inline]
+    pub fn to_non_empty_string(&self) -> NonEmptyString {
+        NonEmptyString(self.0.to_string())
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for NonEmptyStr<'_> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for NonEmptyString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for NonEmptyStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl PartialEq<str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+// Conversion Traits
+impl FromStr for NonEmptyString {
+    type Err = EmptyStringError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NonEmptyString::new(s)
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyStringError;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        NonEmptyString::new(value)
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyStringError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        NonEmptyString::new(value)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonEmptyStr<'a> {
+    type Error = EmptyStringError;
+
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        NonEmptyStr::new(value)
+    }
+}
+
+impl From<NonEmptyString> for String {
+    #[inline]
+    fn from(value: NonEmptyString) -> Self {
+        value.0
+    }
+}
+
+impl From<NonEmptyStr<'_>> for NonEmptyString {
+    #[inline]
+    fn from(value: NonEmptyStr<'_>) -> Self {
+        value.to_non_empty_string()
+    }
+}
+
+impl<'a> From<NonEmptyStr<'a>> for &'a str {
+    #[inline]
+    fn from(value: NonEmptyStr<'a>) -> Self {
+        value.0
+    }
+}
+
+// Serde Support
+impl Serialize for NonEmptyString {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(&self.0)
+    }
+}
+
+impl Serialize for NonEmptyStr<'_> {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(de)?;
+        NonEmptyString::new(value)
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(""), &"a non-empty string"))
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for NonEmptyStr<'a> {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let value = <&'a str>::deserialize(de)?;
+        NonEmptyStr::new(value)
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(""), &"a non-empty string"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_non_empty_string_rejects_empty() {
+        assert_eq!(NonEmptyString::new(""), Err(EmptyStringError));
+        assert_eq!("".parse::<NonEmptyString>(), Err(EmptyStringError));
+        assert_eq!(
+            NonEmptyString::try_from(String::new()),
+            Err(EmptyStringError)
+        );
+        assert_eq!(NonEmptyStr::new(""), Err(EmptyStringError));
+    }
+
+    #[test]
+    fn test_non_empty_string_keeps_whitespace() {
+        let value = NonEmptyString::new(" ").expect("non-empty");
+        assert_eq!(value, " ");
+        assert_eq!(value.len(), 1);
+    }
+
+    #[test]
+    fn test_non_empty_string_serde() {
+        let value: NonEmptyString = serde_json::from_str(r#""main""#).expect("deserialize");
+        assert_eq!(value.as_str(), "main");
+        assert_eq!(
+            serde_json::to_string(&value).expect("serialize"),
+            r#""main""#
+        );
+        assert!(serde_json::from_str::<NonEmptyString>(r#""""#).is_err());
+
+        let borrowed: NonEmptyStr<'_> = serde_json::from_str(r#""main""#).expect("deserialize");
+        assert_eq!(NonEmptyString::from(borrowed), value);
+        assert!(serde_json::from_str::<NonEmptyStr<'_>>(r#""""#).is_err());
+    }
+}
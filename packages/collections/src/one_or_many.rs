@@ -6,6 +6,7 @@ use super::zero_one_or_many::ZeroOneOrMany;
 use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
 use serde::ser::{SerializeSeq, Serializer};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 use std::iter::FromIterator;
 use std::marker::PhantomData;
@@ -17,12 +18,16 @@ use std::marker::PhantomData;
 /// resulting in an `EmptyListError`.
 ///
 /// ### Immutability
-/// All operations are immutable, returning new instances to preserve the non-empty
-/// invariant and ensure thread-safety.
+/// Most operations are immutable, returning new instances to preserve the non-empty
+/// invariant and ensure thread-safety. The in-place `retain`, `dedup_by`, `sort_by`
+/// and `reverse` mirror their `Vec` counterparts; `retain` fails with `EmptyListError`
+/// rather than removing the last element, while `filter` returns a `ZeroOneOrMany`.
 ///
 /// ### Serialization and Deserialization
 /// Serializes to a JSON array with at least one element. Deserialization fails on
 /// `null` or empty arrays, enforcing the non-empty constraint.
+/// Use `#[serde(with = "sugars_collections::serde_strict")]` to accept arrays only.
+/// The `bincode` and `rkyv` features add bincode 2 `Encode`/`Decode` and rkyv archives.
 ///
 /// ### Performance
 /// - **Zero Allocation**: Reuses `ZeroOneOrMany<T>`'s allocation strategy (`None` and
@@ -41,7 +46,24 @@ use std::marker::PhantomData;
 /// # }
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct OneOrMany<T>(ZeroOneOrMany<T>);
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize))]
+pub struct OneOrMany<T>(pub(crate) ZeroOneOrMany<T>);
+
+// Deserializing goes through `TryFrom` so an archive holding no elements is rejected
+// instead of producing an empty `OneOrMany`.
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<OneOrMany<T>, D> for ArchivedOneOrMany<T>
+where
+    T: rkyv::Archive,
+    rkyv::Archived<ZeroOneOrMany<T>>: rkyv::Deserialize<ZeroOneOrMany<T>, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<OneOrMany<T>, D::Error> {
+        let items = self.0.deserialize(deserializer)?;
+        OneOrMany::try_from(items).map_err(<D::Error as rkyv::rancor::Source>::new)
+    }
+}
 
 /// Error returned when attempting to create a `OneOrMany` from an empty collection.
 #[derive(Debug)]
@@ -63,13 +85,13 @@ impl<T> OneOrMany<T> {
         OneOrMany(ZeroOneOrMany::One(item))
     }
 
-    /// Creates a collection from a `Vec<T>`, failing if empty.
+    /// Creates a collection from a `Vec<T>`, failing if empty. A single element is stored as `One`.
     #[inline]
     pub fn many(items: Vec<T>) -> Result<Self, EmptyListError> {
         if items.is_empty() {
             Err(EmptyListError)
         } else {
-            Ok(OneOrMany(ZeroOneOrMany::Many(items)))
+            Ok(OneOrMany(ZeroOneOrMany::many(items)))
         }
     }
 
@@ -98,7 +120,7 @@ impl<T> OneOrMany<T> {
         if items.is_empty() {
             Err(EmptyListError)
         } else {
-            Ok(OneOrMany(ZeroOneOrMany::Many(items)))
+            Ok(OneOrMany(ZeroOneOrMany::many(items)))
         }
     }
 
@@ -211,6 +233,127 @@ impl<T> OneOrMany<T> {
         self.0.try_map(f).map(OneOrMany)
     }
 
+    /// Maps each element to a non-empty collection and flattens the results, preserving order.
+    #[inline]
+    pub fn flat_map<U, F: FnMut(T) -> OneOrMany<U>>(self, mut f: F) -> OneOrMany<U> {
+        match self.0 {
+            ZeroOneOrMany::None => unreachable!("OneOrMany cannot be None"),
+            ZeroOneOrMany::One(item) => f(item),
+            ZeroOneOrMany::Many(v) => {
+                let mut result = Vec::with_capacity(v.len());
+                for item in v {
+                    result.extend(Vec::from(f(item)));
+                }
+                OneOrMany(ZeroOneOrMany::Many(result))
+            }
+        }
+    }
+
+    /// Keeps only the elements matching `predicate`, which may leave none.
+    #[inline]
+    pub fn filter<P: FnMut(&T) -> bool>(self, mut predicate: P) -> ZeroOneOrMany<T> {
+        match self.0 {
+            ZeroOneOrMany::None => unreachable!("OneOrMany cannot be None"),
+            ZeroOneOrMany::One(item) if predicate(&item) => ZeroOneOrMany::One(item),
+            ZeroOneOrMany::One(_) => ZeroOneOrMany::None,
+            ZeroOneOrMany::Many(mut v) => {
+                v.retain(predicate);
+                ZeroOneOrMany::many(v)
+            }
+        }
+    }
+
+    /// Keeps only the elements matching `predicate`, in place.
+    ///
+    /// Fails with `EmptyListError` and leaves the collection unchanged if no element matches.
+    /// `predicate` is called exactly once per element, in order.
+    #[inline]
+    pub fn retain<P: FnMut(&T) -> bool>(&mut self, mut predicate: P) -> Result<(), EmptyListError> {
+        match &mut self.0 {
+            ZeroOneOrMany::None => unreachable!("OneOrMany cannot be None"),
+            ZeroOneOrMany::One(item) if predicate(item) => Ok(()),
+            ZeroOneOrMany::One(_) => Err(EmptyListError),
+            ZeroOneOrMany::Many(v) => {
+                let keep: Vec<bool> = v.iter().map(&mut predicate).collect();
+                if !keep.contains(&true) {
+                    return Err(EmptyListError);
+                }
+                let mut keep = keep.into_iter();
+                v.retain(|_| keep.next().unwrap_or(false));
+                self.0 = ZeroOneOrMany::many(std::mem::take(v));
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns true, keeping the first.
+    ///
+    /// As with `Vec::dedup_by`, `same_bucket` receives the candidate for removal first and
+    /// the element it is compared against second.
+    #[inline]
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, same_bucket: F) {
+        if let ZeroOneOrMany::Many(v) = &mut self.0 {
+            v.dedup_by(same_bucket);
+            self.0 = ZeroOneOrMany::many(std::mem::take(v));
+        }
+    }
+
+    /// Sorts the elements in place with a comparator. The sort is stable.
+    #[inline]
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        if let ZeroOneOrMany::Many(v) = &mut self.0 {
+            v.sort_by(compare);
+        }
+    }
+
+    /// Reverses the order of the elements in place.
+    #[inline]
+    pub fn reverse(&mut self) {
+        if let ZeroOneOrMany::Many(v) = &mut self.0 {
+            v.reverse();
+        }
+    }
+
+    /// Returns the elements as a non-empty slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Splits the elements into consecutive slices of `size`, the last possibly shorter.
+    ///
+    /// There is always at least one chunk, so batch loops need no empty case.
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sugars_collections::OneOrMany;
+    /// let tiers = OneOrMany::many(vec!["core", "macros", "builders"]).unwrap();
+    /// let chunks = tiers.chunks(2);
+    /// assert_eq!(chunks.first(), &&["core", "macros"][..]);
+    /// assert_eq!(chunks.len(), 2);
+    /// ```
+    #[inline]
+    pub fn chunks(&self, size: usize) -> OneOrMany<&[T]> {
+        OneOrMany(self.0.chunks(size))
+    }
+
+    /// Returns every overlapping slice of `size` elements, or `None` if there are fewer.
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn windows(&self, size: usize) -> ZeroOneOrMany<&[T]> {
+        self.0.windows(size)
+    }
+
+    /// Splits the elements into owned non-empty chunks of `size`, the last possibly shorter.
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn into_chunks(self, size: usize) -> OneOrMany<OneOrMany<T>> {
+        OneOrMany(self.0.into_chunks(size))
+    }
+
     /// Returns an iterator over references to the elements.
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &T> {
@@ -294,7 +437,13 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for OneOrMany<T> {
             }
         }
 
-        de.deserialize_any(V(PhantomData))
+        // Binary formats such as postcard and bincode cannot deserialize_any; they
+        // always hold a length-prefixed sequence.
+        if de.is_human_readable() {
+            de.deserialize_any(V(PhantomData))
+        } else {
+            de.deserialize_seq(V(PhantomData))
+        }
     }
 }
 
@@ -315,6 +464,19 @@ impl<T> TryFrom<Vec<T>> for OneOrMany<T> {
     }
 }
 
+impl<T> TryFrom<ZeroOneOrMany<T>> for OneOrMany<T> {
+    type Error = EmptyListError;
+
+    #[inline]
+    fn try_from(value: ZeroOneOrMany<T>) -> Result<Self, Self::Error> {
+        match value {
+            ZeroOneOrMany::None => Err(EmptyListError),
+            ZeroOneOrMany::Many(v) if v.is_empty() => Err(EmptyListError),
+            items => Ok(OneOrMany(items)),
+        }
+    }
+}
+
 impl<T> From<OneOrMany<T>> for Vec<T> {
     #[inline]
     fn from(value: OneOrMany<T>) -> Self {
@@ -322,6 +484,13 @@ impl<T> From<OneOrMany<T>> for Vec<T> {
     }
 }
 
+impl<T> From<OneOrMany<T>> for ZeroOneOrMany<T> {
+    #[inline]
+    fn from(value: OneOrMany<T>) -> Self {
+        value.0
+    }
+}
+
 impl<T> FromIterator<T> for OneOrMany<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
@@ -342,3 +511,17 @@ impl<T> FromIterator<T> for OneOrMany<T> {
         }
     }
 }
+
+impl<T> Extend<T> for OneOrMany<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for OneOrMany<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
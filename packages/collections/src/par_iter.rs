@@ -0,0 +1,156 @@
+// -----------------------------------------------------------------------------
+// src/par_iter.rs
+// -----------------------------------------------------------------------------
+
+//! Rayon support for `ZeroOneOrMany` and `OneOrMany`.
+//!
+//! Both types implement `IntoParallelIterator` by value, by reference and by mutable
+//! reference, so `par_iter()`, `par_iter_mut()` and `into_par_iter()` work directly.
+//! Borrowed iteration runs over the stored slice without copying. `par_map` and
+//! `par_try_map` preserve the variant shape, and skip the thread pool entirely when
+//! there is at most one element.
+//!
+//! ```rust
+//! use rayon::prelude::*;
+//! use sugars_collections::ZeroOneOrMany;
+//!
+//! let sizes = ZeroOneOrMany::many(vec!["a", "bb", "ccc"]);
+//! let total: usize = sizes.par_iter().map(|s| s.len()).sum();
+//! assert_eq!(total, 6);
+//! assert_eq!(sizes.par_map(str::len), ZeroOneOrMany::many(vec![1, 2, 3]));
+//! ```
+
+use crate::one_or_many::OneOrMany;
+use crate::zero_one_or_many::ZeroOneOrMany;
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
+
+impl<T> ZeroOneOrMany<T> {
+    /// Returns the elements as a mutable slice, borrowing `One` in place.
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        match self {
+            ZeroOneOrMany::None => &mut [],
+            ZeroOneOrMany::One(item) => std::slice::from_mut(item),
+            ZeroOneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T: Send> ZeroOneOrMany<T> {
+    /// Maps each element to a new type in parallel, returning a collection of the same shape.
+    #[inline]
+    pub fn par_map<U, F>(self, f: F) -> ZeroOneOrMany<U>
+    where
+        U: Send,
+        F: Fn(T) -> U + Sync + Send,
+    {
+        match self {
+            ZeroOneOrMany::None => ZeroOneOrMany::None,
+            ZeroOneOrMany::One(item) => ZeroOneOrMany::One(f(item)),
+            ZeroOneOrMany::Many(v) => ZeroOneOrMany::Many(v.into_par_iter().map(f).collect()),
+        }
+    }
+
+    /// Maps each element to a new type in parallel, propagating one of the errors if any fail.
+    #[inline]
+    pub fn par_try_map<U, E, F>(self, f: F) -> Result<ZeroOneOrMany<U>, E>
+    where
+        U: Send,
+        E: Send,
+        F: Fn(T) -> Result<U, E> + Sync + Send,
+    {
+        match self {
+            ZeroOneOrMany::None => Ok(ZeroOneOrMany::None),
+            ZeroOneOrMany::One(item) => f(item).map(ZeroOneOrMany::One),
+            ZeroOneOrMany::Many(v) => v
+                .into_par_iter()
+                .map(f)
+                .collect::<Result<Vec<U>, E>>()
+                .map(ZeroOneOrMany::Many),
+        }
+    }
+}
+
+impl<T: Send> OneOrMany<T> {
+    /// Maps each element to a new type in parallel, returning a new collection.
+    #[inline]
+    pub fn par_map<U, F>(self, f: F) -> OneOrMany<U>
+    where
+        U: Send,
+        F: Fn(T) -> U + Sync + Send,
+    {
+        OneOrMany(self.0.par_map(f))
+    }
+
+    /// Maps each element to a new type in parallel, propagating one of the errors if any fail.
+    #[inline]
+    pub fn par_try_map<U, E, F>(self, f: F) -> Result<OneOrMany<U>, E>
+    where
+        U: Send,
+        E: Send,
+        F: Fn(T) -> Result<U, E> + Sync + Send,
+    {
+        self.0.par_try_map(f).map(OneOrMany)
+    }
+}
+
+impl<T: Send> IntoParallelIterator for ZeroOneOrMany<T> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        Vec::from(self).into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a ZeroOneOrMany<T> {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut ZeroOneOrMany<T> {
+    type Item = &'a mut T;
+    type Iter = rayon::slice::IterMut<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
+
+impl<T: Send> IntoParallelIterator for OneOrMany<T> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.0.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a OneOrMany<T> {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        (&self.0).into_par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut OneOrMany<T> {
+    type Item = &'a mut T;
+    type Iter = rayon::slice::IterMut<'a, T>;
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        (&mut self.0).into_par_iter()
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for ZeroOneOrMany<T> {
+    #[inline]
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        ZeroOneOrMany::many(Vec::from_par_iter(par_iter))
+    }
+}
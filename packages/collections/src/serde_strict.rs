@@ -0,0 +1,81 @@
+// -----------------------------------------------------------------------------
+// src/serde_strict.rs
+// -----------------------------------------------------------------------------
+
+//! Strict serde mode for `OneOrMany` and `ZeroOneOrMany`, for use with
+//! `#[serde(with = "sugars_collections::serde_strict")]`.
+//!
+//! The default format is flexible on input: a bare value is read as a single element, and
+//! `null` as an empty `ZeroOneOrMany`. Some schemas require an array even for one element,
+//! so this mode accepts arrays only and rejects bare values and `null`. It serializes as an
+//! array, like the default.
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use sugars_collections::{OneOrMany, ZeroOneOrMany};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Schema {
+//!     #[serde(with = "sugars_collections::serde_strict")]
+//!     tags: OneOrMany<String>,
+//!     #[serde(with = "sugars_collections::serde_strict")]
+//!     aliases: ZeroOneOrMany<String>,
+//! }
+//!
+//! let schema: Schema = serde_json::from_str(r#"{"tags": ["x"], "aliases": []}"#).unwrap();
+//! assert_eq!(serde_json::to_string(&schema).unwrap(), r#"{"tags":["x"],"aliases":[]}"#);
+//!
+//! assert!(serde_json::from_str::<Schema>(r#"{"tags": {"x": 1}, "aliases": []}"#).is_err());
+//! assert!(serde_json::from_str::<Schema>(r#"{"tags": ["x"], "aliases": null}"#).is_err());
+//! assert!(serde_json::from_str::<Schema>(r#"{"tags": [], "aliases": []}"#).is_err());
+//! ```
+
+use crate::one_or_many::{EmptyListError, OneOrMany};
+use crate::zero_one_or_many::ZeroOneOrMany;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+/// Collections that can be read strictly from an array.
+pub trait StrictSeq: Sized {
+    /// Element type of the collection.
+    type Item;
+
+    /// Builds the collection from the elements of an array.
+    fn from_vec(items: Vec<Self::Item>) -> Result<Self, EmptyListError>;
+}
+
+impl<T> StrictSeq for OneOrMany<T> {
+    type Item = T;
+
+    #[inline]
+    fn from_vec(items: Vec<T>) -> Result<Self, EmptyListError> {
+        OneOrMany::many(items)
+    }
+}
+
+impl<T> StrictSeq for ZeroOneOrMany<T> {
+    type Item = T;
+
+    #[inline]
+    fn from_vec(items: Vec<T>) -> Result<Self, EmptyListError> {
+        Ok(ZeroOneOrMany::many(items))
+    }
+}
+
+/// Serializes the collection as an array, including single elements.
+#[inline]
+pub fn serialize<C: Serialize, S: Serializer>(value: &C, ser: S) -> Result<S::Ok, S::Error> {
+    value.serialize(ser)
+}
+
+/// Deserializes the collection from an array, rejecting bare values and `null`.
+#[inline]
+pub fn deserialize<'de, C, D>(de: D) -> Result<C, D::Error>
+where
+    C: StrictSeq,
+    C::Item: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let items = Vec::<C::Item>::deserialize(de)?;
+    C::from_vec(items).map_err(|_| de::Error::invalid_length(0, &"at least one element"))
+}
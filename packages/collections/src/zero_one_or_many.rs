@@ -2,6 +2,7 @@
 // src/zero_one_or_many.rs
 // -----------------------------------------------------------------------------
 
+use super::one_or_many::OneOrMany;
 use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
 use serde::ser::{SerializeSeq, Serializer};
 use serde::{Deserialize, Serialize};
@@ -22,15 +23,24 @@ use std::marker::PhantomData;
 ///   pre-allocated capacity to minimize reallocations.
 ///
 /// ### Immutability
-/// All operations are immutable, returning new instances to ensure thread-safety and
+/// Transformations are immutable, returning new instances to ensure thread-safety and
 /// functional programming patterns. Methods like `with_pushed` and `with_inserted`
 /// consume the current instance and produce a new one with the desired changes.
 ///
+/// ### In-place Mutation
+/// For hot loops, `push`, `insert`, `remove`, `pop`, `clear`, `get_mut`, `iter_mut` and
+/// `Extend` mutate in place. Growing promotes `None` to `One` and `One` to `Many`; shrinking
+/// demotes `Many` to `One` and `One` to `None`, so the variant always matches the length.
+/// `many`, `collect` and deserialization normalize the same way, which keeps the derived
+/// `==` equal to comparing elements: `ZeroOneOrMany::one(1) == ZeroOneOrMany::many(vec![1])`.
+///
 /// ### Serialization and Deserialization
 /// Implements `Serialize` and `Deserialize` from the Serde library:
 /// - Serializes to a JSON array: `[]` for `None`, `[item]` for `One`, or multi-element
 ///   array for `Many`.
 /// - Deserializes from `null`, a single value, or an array.
+/// - Use `#[serde(with = "sugars_collections::serde_strict")]` to accept arrays only.
+/// - The `bincode` and `rkyv` features add bincode 2 `Encode`/`Decode` and rkyv archives.
 ///
 /// ### Performance
 /// - **Zero Allocation**: `None` and `One` variants avoid heap allocations.
@@ -46,8 +56,17 @@ use std::marker::PhantomData;
 /// let single = ZeroOneOrMany::one(42);
 /// let multiple = ZeroOneOrMany::many(vec![1, 2, 3]);
 /// let pushed = single.with_pushed(43);
+///
+/// let mut collected: ZeroOneOrMany<i32> = std::iter::once(1).collect();
+/// assert_eq!(collected, ZeroOneOrMany::one(1));
+/// collected.extend(&[2, 3]);
+/// assert_eq!(collected, ZeroOneOrMany::many(vec![1, 2, 3]));
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum ZeroOneOrMany<T> {
     /// Empty collection with zero elements
     None,
@@ -71,14 +90,11 @@ impl<T> ZeroOneOrMany<T> {
         ZeroOneOrMany::One(item)
     }
 
-    /// Creates a collection from a `Vec<T>`, normalizing empty vectors to `None`.
+    /// Creates a collection from a `Vec<T>`, normalizing empty vectors to `None` and
+    /// single-element vectors to `One`.
     #[inline]
     pub fn many(items: Vec<T>) -> Self {
-        if items.is_empty() {
-            ZeroOneOrMany::None
-        } else {
-            ZeroOneOrMany::Many(items)
-        }
+        ZeroOneOrMany::demoted(items)
     }
 
     /// Creates a collection from a hashbrown HashMap.
@@ -174,6 +190,45 @@ impl<T> ZeroOneOrMany<T> {
         }
     }
 
+    /// Returns the elements as a slice, borrowing `One` in place.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            ZeroOneOrMany::None => &[],
+            ZeroOneOrMany::One(item) => std::slice::from_ref(item),
+            ZeroOneOrMany::Many(v) => v,
+        }
+    }
+
+    /// Copies the elements into a new `Vec`.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec()
+    }
+
+    /// Checks if the collection contains an element equal to `item`.
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(item)
+    }
+
+    /// Returns the index of the first element equal to `item`, if any.
+    #[inline]
+    pub fn position(&self, item: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.as_slice()
+            .iter()
+            .position(|candidate| candidate == item)
+    }
+
     /// Returns a vector of references to all elements after the first.
     #[inline]
     pub fn rest(&self) -> Vec<&T> {
@@ -235,6 +290,96 @@ impl<T> ZeroOneOrMany<T> {
         }
     }
 
+    /// Appends an element to the end, promoting `None` to `One` and `One` to `Many`.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        match self {
+            ZeroOneOrMany::Many(v) => v.push(item),
+            _ => *self = std::mem::take(self).with_pushed(item),
+        }
+    }
+
+    /// Inserts an element at the specified index, shifting later elements right.
+    /// Panics if `idx > len`.
+    #[inline]
+    pub fn insert(&mut self, idx: usize, item: T) {
+        match self {
+            ZeroOneOrMany::Many(v) => v.insert(idx, item),
+            _ => *self = std::mem::take(self).with_inserted(idx, item),
+        }
+    }
+
+    /// Removes and returns the element at the specified index, shifting later elements left.
+    /// Demotes `Many` to `One` when a single element remains, and `One` to `None`.
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> T {
+        let len = self.len();
+        if idx >= len {
+            panic!("Index {idx} out of bounds for length {len}");
+        }
+        match std::mem::take(self) {
+            ZeroOneOrMany::None => unreachable!("bounds checked above"),
+            ZeroOneOrMany::One(item) => item,
+            ZeroOneOrMany::Many(mut v) => {
+                let item = v.remove(idx);
+                *self = Self::demoted(v);
+                item
+            }
+        }
+    }
+
+    /// Removes and returns the last element, if any, demoting the variant as needed.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        match std::mem::take(self) {
+            ZeroOneOrMany::None => None,
+            ZeroOneOrMany::One(item) => Some(item),
+            ZeroOneOrMany::Many(mut v) => {
+                let item = v.pop();
+                *self = Self::demoted(v);
+                item
+            }
+        }
+    }
+
+    /// Removes all elements, leaving `None`.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = ZeroOneOrMany::None;
+    }
+
+    /// Returns a mutable reference to the element at the specified index, if any.
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        match self {
+            ZeroOneOrMany::None => None,
+            ZeroOneOrMany::One(item) if idx == 0 => Some(item),
+            ZeroOneOrMany::One(_) => None,
+            ZeroOneOrMany::Many(v) => v.get_mut(idx),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut T> + '_> {
+        match self {
+            ZeroOneOrMany::None => Box::new(std::iter::empty()),
+            ZeroOneOrMany::One(item) => Box::new(std::iter::once(item)),
+            ZeroOneOrMany::Many(v) => Box::new(v.iter_mut()),
+        }
+    }
+
+    /// Picks the variant matching the length of a vector.
+    #[inline]
+    fn demoted(mut v: Vec<T>) -> Self {
+        match v.len() {
+            0 => ZeroOneOrMany::None,
+            1 => ZeroOneOrMany::One(v.swap_remove(0)),
+            _ => ZeroOneOrMany::Many(v),
+        }
+    }
+
     /// Maps each element to a new type, returning a new collection.
     #[inline]
     pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> ZeroOneOrMany<U> {
@@ -264,6 +409,54 @@ impl<T> ZeroOneOrMany<T> {
         }
     }
 
+    /// Splits the elements into consecutive slices of `size`, the last possibly shorter.
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use sugars_collections::ZeroOneOrMany;
+    /// let batches = ZeroOneOrMany::many(vec![1, 2, 3, 4, 5]);
+    /// let chunks: Vec<&[i32]> = batches.chunks(2).iter().copied().collect();
+    /// assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+    /// ```
+    #[inline]
+    pub fn chunks(&self, size: usize) -> ZeroOneOrMany<&[T]> {
+        ZeroOneOrMany::demoted(self.as_slice().chunks(size).collect())
+    }
+
+    /// Returns every overlapping slice of `size` elements, or `None` if there are fewer.
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn windows(&self, size: usize) -> ZeroOneOrMany<&[T]> {
+        ZeroOneOrMany::demoted(self.as_slice().windows(size).collect())
+    }
+
+    /// Splits the elements into owned chunks of `size`, the last possibly shorter.
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn into_chunks(self, size: usize) -> ZeroOneOrMany<OneOrMany<T>> {
+        assert!(size != 0, "chunk size must be non-zero");
+        match self {
+            ZeroOneOrMany::None => ZeroOneOrMany::None,
+            ZeroOneOrMany::One(item) => ZeroOneOrMany::One(OneOrMany::one(item)),
+            ZeroOneOrMany::Many(v) => {
+                let mut chunks = Vec::with_capacity(v.len().div_ceil(size));
+                let mut items = v.into_iter();
+                loop {
+                    let chunk: Vec<T> = items.by_ref().take(size).collect();
+                    if chunk.is_empty() {
+                        break;
+                    }
+                    chunks.push(OneOrMany(Self::demoted(chunk)));
+                }
+                ZeroOneOrMany::demoted(chunks)
+            }
+        }
+    }
+
     /// Returns an iterator over references to the elements.
     #[inline]
     pub fn iter(&self) -> Box<dyn Iterator<Item = &T> + '_> {
@@ -370,7 +563,20 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for ZeroOneOrMany<T> {
             }
         }
 
-        de.deserialize_any(V(PhantomData))
+        // Binary formats such as postcard and bincode cannot deserialize_any; they
+        // always hold a length-prefixed sequence.
+        if de.is_human_readable() {
+            de.deserialize_any(V(PhantomData))
+        } else {
+            de.deserialize_seq(V(PhantomData))
+        }
+    }
+}
+
+impl<T> Default for ZeroOneOrMany<T> {
+    #[inline]
+    fn default() -> Self {
+        ZeroOneOrMany::None
     }
 }
 
@@ -389,10 +595,44 @@ impl<T> From<Vec<T>> for ZeroOneOrMany<T> {
     }
 }
 
+impl<T> From<Option<T>> for ZeroOneOrMany<T> {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => ZeroOneOrMany::None,
+            Some(item) => ZeroOneOrMany::One(item),
+        }
+    }
+}
+
 impl<T> FromIterator<T> for ZeroOneOrMany<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        ZeroOneOrMany::many(iter.into_iter().collect())
+        ZeroOneOrMany::demoted(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for ZeroOneOrMany<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        // Promote through `push` until there is a Vec to extend directly.
+        while !matches!(self, ZeroOneOrMany::Many(_)) {
+            match iter.next() {
+                Some(item) => self.push(item),
+                None => return,
+            }
+        }
+        if let ZeroOneOrMany::Many(v) = self {
+            v.extend(iter);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for ZeroOneOrMany<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
     }
 }
 
@@ -406,3 +646,79 @@ impl<T> From<ZeroOneOrMany<T>> for Vec<T> {
         }
     }
 }
+
+impl<T> From<ZeroOneOrMany<T>> for Option<Vec<T>> {
+    #[inline]
+    fn from(value: ZeroOneOrMany<T>) -> Self {
+        match value {
+            ZeroOneOrMany::None => None,
+            items => Some(items.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_constructors_pick_the_variant_matching_the_length() {
+        assert!(matches!(
+            ZeroOneOrMany::<i32>::many(vec![]),
+            ZeroOneOrMany::None
+        ));
+        assert!(matches!(
+            ZeroOneOrMany::many(vec![1]),
+            ZeroOneOrMany::One(1)
+        ));
+        assert!(matches!(
+            ZeroOneOrMany::many(vec![1, 2]),
+            ZeroOneOrMany::Many(_)
+        ));
+        assert!(matches!(
+            ZeroOneOrMany::from(vec![1]),
+            ZeroOneOrMany::One(1)
+        ));
+        let parsed: ZeroOneOrMany<i32> = serde_json::from_str("[1]").expect("deserialize");
+        assert!(matches!(parsed, ZeroOneOrMany::One(1)));
+    }
+
+    #[test]
+    fn test_equality_compares_elements() {
+        assert_eq!(ZeroOneOrMany::one(1), ZeroOneOrMany::many(vec![1]));
+        assert_eq!(ZeroOneOrMany::<i32>::none(), ZeroOneOrMany::many(vec![]));
+        assert!(ZeroOneOrMany::one(1) != ZeroOneOrMany::many(vec![1, 1]));
+        assert!(ZeroOneOrMany::many(vec![1, 2]) != ZeroOneOrMany::many(vec![2, 1]));
+    }
+
+    #[test]
+    fn test_collect_matches_many() {
+        let collected: ZeroOneOrMany<i32> = std::iter::once(7).collect();
+        assert!(matches!(collected, ZeroOneOrMany::One(7)));
+        assert_eq!(collected, ZeroOneOrMany::many(vec![7]));
+        let collected: ZeroOneOrMany<i32> = (1..=3).collect();
+        assert_eq!(collected, ZeroOneOrMany::many(vec![1, 2, 3]));
+        let empty: ZeroOneOrMany<i32> = std::iter::empty().collect();
+        assert!(matches!(empty, ZeroOneOrMany::None));
+    }
+
+    #[test]
+    fn test_in_place_shrinking_demotes() {
+        let mut items = ZeroOneOrMany::many(vec![1, 2]);
+        assert_eq!(items.pop(), Some(2));
+        assert!(matches!(items, ZeroOneOrMany::One(1)));
+        assert_eq!(items.pop(), Some(1));
+        assert!(matches!(items, ZeroOneOrMany::None));
+    }
+
+    #[test]
+    fn test_one_or_many_equality_after_retain() {
+        let mut items = OneOrMany::many(vec![1, 2, 3]).unwrap();
+        items.retain(|item| *item == 2).unwrap();
+        assert_eq!(items, OneOrMany::one(2));
+        let mut items = OneOrMany::many(vec![4, 4]).unwrap();
+        items.dedup_by(|a, b| a == b);
+        assert_eq!(items, OneOrMany::one(4));
+    }
+}
@@ -0,0 +1,88 @@
+//! Insert and lookup throughput of `ObjectIdMap` against default-hashed maps.
+//!
+//! Run with `cargo bench -p cyrup_sugars --features gix-interop --bench object_id_map`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use cyrup_sugars::gix_interop::{ObjectId, ObjectIdMap, ObjectIdSet};
+use std::collections::HashMap as StdHashMap;
+use std::hint::black_box;
+
+/// Deterministic pseudo-random object IDs, uniformly distributed like real digests
+fn object_ids(count: usize) -> Vec<ObjectId> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    (0..count)
+        .map(|_| {
+            let mut bytes = [0u8; 20];
+            for chunk in bytes.chunks_mut(8) {
+                // xorshift64
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                chunk.copy_from_slice(&state.to_ne_bytes()[..chunk.len()]);
+            }
+            ObjectId::Sha1(bytes)
+        })
+        .collect()
+}
+
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("object_id_insert");
+    for count in [10_000, 1_000_000] {
+        let ids = object_ids(count);
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::new("ObjectIdMap", count), &ids, |b, ids| {
+            b.iter(|| {
+                let mut map = ObjectIdMap::with_capacity_and_hasher(ids.len(), Default::default());
+                for (index, id) in ids.iter().enumerate() {
+                    map.insert(*id, index);
+                }
+                black_box(map)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("hashbrown default", count), &ids, |b, ids| {
+            b.iter(|| {
+                let mut map = hashbrown::HashMap::with_capacity(ids.len());
+                for (index, id) in ids.iter().enumerate() {
+                    map.insert(*id, index);
+                }
+                black_box(map)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("std SipHash", count), &ids, |b, ids| {
+            b.iter(|| {
+                let mut map = StdHashMap::with_capacity(ids.len());
+                for (index, id) in ids.iter().enumerate() {
+                    map.insert(*id, index);
+                }
+                black_box(map)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("object_id_lookup");
+    for count in [10_000, 1_000_000] {
+        let ids = object_ids(count);
+        group.throughput(Throughput::Elements(count as u64));
+
+        let set: ObjectIdSet = ids.iter().copied().collect();
+        group.bench_with_input(BenchmarkId::new("ObjectIdSet", count), &ids, |b, ids| {
+            b.iter(|| ids.iter().filter(|id| set.contains(*id)).count())
+        });
+        let set: hashbrown::HashSet<ObjectId> = ids.iter().copied().collect();
+        group.bench_with_input(BenchmarkId::new("hashbrown default", count), &ids, |b, ids| {
+            b.iter(|| ids.iter().filter(|id| set.contains(*id)).count())
+        });
+        let set: std::collections::HashSet<ObjectId> = ids.iter().copied().collect();
+        group.bench_with_input(BenchmarkId::new("std SipHash", count), &ids, |b, ids| {
+            b.iter(|| ids.iter().filter(|id| set.contains(*id)).count())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_insert, bench_lookup);
+criterion_main!(benches);
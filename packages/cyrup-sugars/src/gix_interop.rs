@@ -0,0 +1,154 @@
+//! Hash tables keyed by git object IDs.
+//!
+//! Object IDs are cryptographic digests, so their bytes are already uniformly
+//! distributed. Running them through SipHash again only costs time: these
+//! tables use the first eight bytes of the ID as its hash instead, which makes
+//! hashing a single load. That matters for tools that index millions of
+//! objects, where hashing dominates insert and lookup.
+//!
+//! ```
+//! use cyrup_sugars::gix_interop::{ObjectId, ObjectIdMap, ObjectIdSet};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let id = ObjectId::from_hex(b"8f5b0c0e1f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c")?;
+//! let mut sizes: ObjectIdMap<u64> = ObjectIdMap::default();
+//! sizes.insert(id, 1024);
+//! assert_eq!(sizes.get(&id), Some(&1024));
+//!
+//! let mut seen = ObjectIdSet::default();
+//! assert!(seen.insert(id));
+//! assert!(!seen.insert(id));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::hash::{BuildHasher, Hasher};
+
+pub use gix_hash::{oid, ObjectId};
+
+/// A `HashMap` keyed by object IDs, hashing them by identity
+pub type ObjectIdMap<V> = hashbrown::HashMap<ObjectId, V, ObjectIdBuildHasher>;
+
+/// A `HashSet` of object IDs, hashing them by identity
+pub type ObjectIdSet = hashbrown::HashSet<ObjectId, ObjectIdBuildHasher>;
+
+/// Hasher that uses the leading bytes of an object ID as its hash.
+///
+/// Only suitable for keys that are digests: any other key written in chunks
+/// shorter than eight bytes is folded without mixing, so similar keys collide.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ObjectIdHasher(u64);
+
+impl Hasher for ObjectIdHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        match bytes.first_chunk::<8>() {
+            // The digest itself; anything written before it (length prefix, hash kind) is dropped
+            Some(prefix) => self.0 = u64::from_ne_bytes(*prefix),
+            None => {
+                for &byte in bytes {
+                    self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+                }
+            }
+        }
+    }
+
+    fn write_usize(&mut self, _length: usize) {
+        // Slices hash their length first, which is the same for every ID of a hash kind
+    }
+}
+
+/// `BuildHasher` for `ObjectIdHasher`, used by `ObjectIdMap` and `ObjectIdSet`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ObjectIdBuildHasher;
+
+impl BuildHasher for ObjectIdBuildHasher {
+    type Hasher = ObjectIdHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        ObjectIdHasher::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::Hash;
+
+    fn id(hex: &str) -> ObjectId {
+        ObjectId::from_hex(hex.as_bytes()).expect("valid object id")
+    }
+
+    fn hash_of(id: &ObjectId) -> u64 {
+        let mut hasher = ObjectIdBuildHasher.build_hasher();
+        id.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_is_the_leading_bytes() {
+        let id = id("8f5b0c0e1f3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c");
+        let prefix = [0x8f, 0x5b, 0x0c, 0x0e, 0x1f, 0x3a, 0x4b, 0x5c];
+        assert_eq!(hash_of(&id), u64::from_ne_bytes(prefix));
+        assert_eq!(ObjectIdBuildHasher.hash_one(id), hash_of(&id));
+    }
+
+    #[test]
+    fn test_insert_and_lookup() {
+        let ids: Vec<ObjectId> = (1u8..=255)
+            .map(|byte| {
+                let mut bytes = [byte; 20];
+                bytes[19] = byte.wrapping_mul(31);
+                ObjectId::Sha1(bytes)
+            })
+            .collect();
+
+        let mut sizes: ObjectIdMap<usize> = ObjectIdMap::default();
+        for (size, id) in ids.iter().enumerate() {
+            assert_eq!(sizes.insert(*id, size), None);
+        }
+        assert_eq!(sizes.len(), ids.len());
+        for (size, id) in ids.iter().enumerate() {
+            assert_eq!(sizes.get(id), Some(&size));
+            assert_eq!(sizes.get(id.as_ref()), Some(&size));
+        }
+        assert_eq!(sizes.get(&ObjectId::null(gix_hash::Kind::Sha1)), None);
+    }
+
+    #[test]
+    fn test_colliding_prefixes_stay_distinct() {
+        // Same first eight bytes, so the same hash; only equality tells them apart
+        let first = id("0123456789abcdef00000000000000000000000a");
+        let second = id("0123456789abcdef00000000000000000000000b");
+        let third = id("0123456789abcdefffffffffffffffffffffffff");
+        assert_eq!(hash_of(&first), hash_of(&second));
+        assert_eq!(hash_of(&first), hash_of(&third));
+
+        let mut map: ObjectIdMap<&str> = ObjectIdMap::default();
+        map.insert(first, "first");
+        map.insert(second, "second");
+        assert_eq!(map.insert(first, "first again"), Some("first"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&first), Some(&"first again"));
+        assert_eq!(map.get(&second), Some(&"second"));
+        assert_eq!(map.get(&third), None);
+
+        let mut set = ObjectIdSet::default();
+        assert!(set.insert(first));
+        assert!(set.insert(second));
+        assert!(!set.insert(first));
+        assert!(set.remove(&second));
+        assert!(set.contains(&first) && !set.contains(&second) && !set.contains(&third));
+    }
+
+    #[test]
+    fn test_short_writes_are_folded() {
+        let mut hasher = ObjectIdHasher::default();
+        hasher.write(&[0x01, 0x02]);
+        hasher.write_u8(0x03);
+        assert_eq!(hasher.finish(), 0x0001_0203);
+    }
+}
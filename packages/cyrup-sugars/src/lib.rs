@@ -6,7 +6,7 @@
 //!
 //! ## Features
 //!
-//! - `collections` - Enhanced collection types like `ZeroOneOrMany`, `OneOrMany`, and `ByteSize`
+//! - `collections` - Enhanced collection types like `ZeroOneOrMany`, `OneOrMany`, and `ByteSize`, plus `DurationExt`
 //! - `async` - Async utilities with the "always unwrapped" pattern using `AsyncTask` and `AsyncStream`
 //! - `macros` - Convenient macros for collections and async operations
 //! - `array-tuples` - 🔥 Amazing hashbrown HashMap macros with array tuple syntax support
@@ -55,6 +55,10 @@
 /// Closure macros for elegant stream processing with zero-allocation pattern matching
 pub mod closures;
 
+/// Hash tables keyed by git object IDs, hashing them by identity
+#[cfg(feature = "gix-interop")]
+pub mod gix_interop;
+
 // Re-export modules from workspace packages
 pub use sugars_collections as collections;
 
@@ -73,14 +77,29 @@ pub use sugars_macros as macros;
 pub use sugars_builders as builders;
 
 // Re-export commonly used types from collections
-pub use sugars_collections::{ByteSize, ByteSizeExt, OneOrMany, ZeroOneOrMany};
+pub use sugars_collections::{
+    BoundedVec, ByteSize, ByteSizeExt, CowStr, DurationExt, MultiMap, NonEmptyMap, NonEmptyStr,
+    NonEmptyString, OneOrMany, OneOrManyStr, ZeroOneOrMany, ZeroOneOrManyStr, one_or_many,
+};
+
+// Re-export the insertion-ordered map macro for the indexmap feature
+#[cfg(feature = "indexmap")]
+pub use sugars_collections::{IndexMap, index_map, ordered_map};
+
+// Re-export the eager and closure-returning collection literal macros
+pub use sugars_collections::{btree_map, hash_set, obj};
+pub use sugars_macros::{btree_map_fn, hash_set_fn, index_map_fn};
+
+// Re-export object ID hash tables for the gix-interop feature
+#[cfg(feature = "gix-interop")]
+pub use gix_interop::{ObjectIdMap, ObjectIdSet};
 
 // Re-export array tuple extension traits when both features are enabled
 #[cfg(feature = "array-tuples")]
 pub use sugars_collections::{
-    ArrayTupleObjectExtKString, ArrayTupleObjectExtKV, ArrayTupleObjectExtStringString,
-    ArrayTupleObjectExtStringV, CollectionArrayTupleExtKString, CollectionArrayTupleExtKV,
-    CollectionArrayTupleExtStringString, CollectionArrayTupleExtStringV,
+    ArrayTupleObjectExtJson, ArrayTupleObjectExtKString, ArrayTupleObjectExtKV,
+    ArrayTupleObjectExtStringString, ArrayTupleObjectExtStringV, CollectionArrayTupleExtKString,
+    CollectionArrayTupleExtKV, CollectionArrayTupleExtStringString, CollectionArrayTupleExtStringV,
     TryCollectionArrayTupleExtKString, TryCollectionArrayTupleExtKV,
     TryCollectionArrayTupleExtStringString, TryCollectionArrayTupleExtStringV,
 };
@@ -92,7 +111,7 @@ pub use r#async::{
 
 // Re-export JSON syntax macros for array-tuples feature
 #[cfg(feature = "array-tuples")]
-pub use sugars_collections::hash_map;
+pub use sugars_collections::{hash_map, json_pairs};
 #[cfg(feature = "array-tuples")]
 pub use sugars_macros::hash_map_fn;
 
@@ -101,7 +120,8 @@ pub mod prelude {
 
     // Re-export commonly used types
     pub use crate::{
-        AsyncResult, AsyncStream, AsyncTask, ByteSize, ByteSizeExt, OneOrMany, ZeroOneOrMany,
+        AsyncResult, AsyncStream, AsyncTask, ByteSize, ByteSizeExt, DurationExt, OneOrMany,
+        ZeroOneOrMany,
     };
 
     // Re-export JSON syntax macros when array-tuples feature is enabled
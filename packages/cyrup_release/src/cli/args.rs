@@ -3,13 +3,20 @@
 //! This module provides comprehensive CLI argument parsing using clap,
 //! with proper validation and error handling.
 
+use crate::cli::ci::CiProvider;
+use crate::cli::deprecation::{rewrite_deprecated_args, DeprecationWarning};
+use crate::cli::logging::OUTPUT_TARGET;
+use crate::cli::prompt::{select_prompter, Prompter};
 use crate::version::VersionBump;
 use clap::{Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use semver::Version;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Cyrup Release - Production-quality release management for Rust workspaces
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(
     name = "cyrup_release",
     version,
@@ -24,28 +31,56 @@ pub struct Args {
     pub command: Command,
 
     /// Enable verbose output
-    #[arg(short, long, global = true)]
+    #[arg(short, long, global = true, env = "CYRUP_RELEASE_VERBOSE")]
     pub verbose: bool,
 
     /// Suppress all output except errors
-    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    #[arg(short, long, global = true, conflicts_with = "verbose", env = "CYRUP_RELEASE_QUIET")]
     pub quiet: bool,
 
     /// Path to workspace root (defaults to current directory)
-    #[arg(short, long, global = true, value_name = "PATH")]
+    #[arg(short, long, global = true, value_name = "PATH", env = "CYRUP_RELEASE_WORKSPACE")]
     pub workspace: Option<PathBuf>,
 
     /// Path to state file (defaults to .cyrup_release_state.json)
-    #[arg(long, global = true, value_name = "PATH")]
+    #[arg(long, global = true, value_name = "PATH", env = "CYRUP_RELEASE_STATE_FILE")]
     pub state_file: Option<PathBuf>,
 
     /// Configuration file path
-    #[arg(short, long, global = true, value_name = "PATH")]
+    #[arg(short, long, global = true, value_name = "PATH", env = "CYRUP_RELEASE_CONFIG")]
     pub config: Option<PathBuf>,
+
+    /// Also write structured JSON logs to this file
+    #[arg(long, global = true, value_name = "PATH", env = "CYRUP_RELEASE_LOG_FILE")]
+    pub log_file: Option<PathBuf>,
+
+    /// Log filter directives in RUST_LOG syntax (overrides --verbose/--quiet)
+    #[arg(long, global = true, value_name = "FILTER", env = "CYRUP_RELEASE_LOG_FILTER")]
+    pub log_filter: Option<String>,
+
+    /// Answer yes to every confirmation prompt
+    #[arg(long, global = true, env = "CYRUP_RELEASE_YES_ALL")]
+    pub yes_all: bool,
+
+    /// Answer no to every confirmation prompt
+    #[arg(long, global = true, conflicts_with = "yes_all", env = "CYRUP_RELEASE_ASSUME_NO")]
+    pub assume_no: bool,
+
+    /// Treat use of deprecated flags or config keys as an error
+    #[arg(long, global = true, env = "CYRUP_RELEASE_DENY_DEPRECATED")]
+    pub deny_deprecated: bool,
+
+    /// Deprecated flags that were rewritten during parsing
+    #[arg(skip)]
+    pub deprecations: Vec<DeprecationWarning>,
+
+    /// CI provider detected from the environment
+    #[arg(skip)]
+    pub ci: Option<CiProvider>,
 }
 
 /// Available commands
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Debug, Clone)]
 pub enum Command {
     /// Release packages with version bump
     Release {
@@ -54,123 +89,245 @@ pub enum Command {
         bump_type: BumpType,
 
         /// Perform dry run without making changes
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_DRY_RUN")]
         dry_run: bool,
 
         /// Skip validation checks
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_SKIP_VALIDATION")]
         skip_validation: bool,
 
         /// Force release even if working directory is dirty
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_ALLOW_DIRTY")]
         allow_dirty: bool,
 
+        /// Allow untracked files in the working directory, leaving them out of the release commit
+        #[arg(long, env = "CYRUP_RELEASE_ALLOW_UNTRACKED")]
+        allow_untracked: bool,
+
         /// Don't push to remote repository
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_NO_PUSH")]
         no_push: bool,
 
         /// Registry to publish to (defaults to crates.io)
-        #[arg(long, value_name = "REGISTRY")]
+        #[arg(long, value_name = "REGISTRY", env = "CYRUP_RELEASE_REGISTRY")]
         registry: Option<String>,
 
         /// Delay between package publishes in seconds
-        #[arg(long, default_value = "15", value_name = "SECONDS")]
+        #[arg(long, default_value = "15", value_name = "SECONDS", env = "CYRUP_RELEASE_PACKAGE_DELAY")]
         package_delay: u64,
 
         /// Maximum number of retry attempts for publishing
-        #[arg(long, default_value = "3", value_name = "COUNT")]
+        #[arg(long, default_value = "3", value_name = "COUNT", env = "CYRUP_RELEASE_MAX_RETRIES")]
         max_retries: usize,
 
         /// Timeout for individual operations in seconds
-        #[arg(long, default_value = "300", value_name = "SECONDS")]
+        #[arg(long, default_value = "300", value_name = "SECONDS", env = "CYRUP_RELEASE_TIMEOUT")]
         timeout: u64,
 
         /// Don't create backups during operation
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_NO_BACKUP")]
         no_backup: bool,
 
         /// Maximum concurrent package publishes per dependency tier
-        #[arg(long, default_value = "1", value_name = "COUNT")]
+        #[arg(long, default_value = "1", value_name = "COUNT", env = "CYRUP_RELEASE_MAX_CONCURRENT")]
         max_concurrent: usize,
+
+        /// Release only this package, keeping internal dependencies pinned to published versions
+        #[arg(short, long, value_name = "PACKAGE", env = "CYRUP_RELEASE_PACKAGE")]
+        package: Option<String>,
+
+        /// Wait for docs.rs to build the published crates and report failures
+        #[arg(long, env = "CYRUP_RELEASE_VERIFY_DOCS")]
+        verify_docs: bool,
+
+        /// Allow packages that have never been published, checking their names are free first
+        #[arg(long, env = "CYRUP_RELEASE_FIRST_RELEASE")]
+        first_release: bool,
+
+        /// Also build documentation and run doctests during validation
+        #[arg(long, env = "CYRUP_RELEASE_CHECK_DOCS")]
+        check_docs: bool,
+
+        /// Run the release in a temporary git worktree, leaving the working tree untouched
+        #[arg(long, env = "CYRUP_RELEASE_ISOLATED")]
+        isolated: bool,
+
+        /// Stash uncommitted changes (including untracked files) for the release and restore them afterwards
+        #[arg(long, conflicts_with = "isolated", env = "CYRUP_RELEASE_STASH")]
+        stash: bool,
+
+        /// Skip repository hooks (pre-commit, commit-msg, pre-push) for the release commit and push
+        #[arg(long, env = "CYRUP_RELEASE_NO_VERIFY")]
+        no_verify: bool,
+
+        /// Refuse to release during a freeze window from `[schedule]`
+        #[arg(long, env = "CYRUP_RELEASE_SCHEDULE")]
+        schedule: bool,
+
+        /// Release during a freeze window, recording the reason in the release state
+        #[arg(long, value_name = "REASON", env = "CYRUP_RELEASE_OVERRIDE_FREEZE")]
+        override_freeze: Option<String>,
+
+        /// Write a software bill of materials for the released packages next to the state file
+        #[arg(long, value_enum, value_name = "FORMAT", env = "CYRUP_RELEASE_SBOM")]
+        sbom: Option<SbomFormat>,
+
+        /// Upload the SBOM to the GitHub Release of each tag (requires `gh`)
+        #[arg(long, requires = "sbom", env = "CYRUP_RELEASE_SBOM_ATTACH")]
+        sbom_attach: bool,
+
+        /// Remove `[patch]` entries pointing at workspace members from the release commit
+        #[arg(long, env = "CYRUP_RELEASE_STRIP_PATCHES")]
+        strip_patches: bool,
+    },
+
+    /// Release the workspaces of a meta-repo in dependency order with shared progress
+    MetaRelease {
+        /// Type of version bump to apply to every workspace
+        #[arg(value_enum)]
+        bump_type: BumpType,
+
+        /// Meta manifest listing the workspaces (defaults to release-meta.toml in the workspace path)
+        #[arg(short, long, value_name = "PATH", env = "CYRUP_RELEASE_MANIFEST")]
+        manifest: Option<PathBuf>,
+
+        /// Show the release order and planned versions without making changes
+        #[arg(short, long, env = "CYRUP_RELEASE_DRY_RUN")]
+        dry_run: bool,
+
+        /// Skip validation checks
+        #[arg(long, env = "CYRUP_RELEASE_SKIP_VALIDATION")]
+        skip_validation: bool,
+
+        /// Don't push to remote repositories
+        #[arg(long, env = "CYRUP_RELEASE_NO_PUSH")]
+        no_push: bool,
+
+        /// Registry to publish to (defaults to crates.io)
+        #[arg(long, value_name = "REGISTRY", env = "CYRUP_RELEASE_REGISTRY")]
+        registry: Option<String>,
+
+        /// Delay between package publishes in seconds
+        #[arg(long, default_value = "15", value_name = "SECONDS", env = "CYRUP_RELEASE_PACKAGE_DELAY")]
+        package_delay: u64,
+    },
+
+    /// Release a patch of an older version from its maintenance branch
+    Hotfix {
+        /// Tag of the release to patch (e.g. `v1.2.3`)
+        #[arg(long, value_name = "TAG", env = "CYRUP_RELEASE_BASE")]
+        base: String,
+
+        /// Maintenance branch to release from (defaults to `[git] maintenance-branch`)
+        #[arg(long, value_name = "BRANCH", env = "CYRUP_RELEASE_BRANCH")]
+        branch: Option<String>,
+
+        /// Perform dry run without making changes
+        #[arg(short, long, env = "CYRUP_RELEASE_DRY_RUN")]
+        dry_run: bool,
+
+        /// Skip validation checks
+        #[arg(long, env = "CYRUP_RELEASE_SKIP_VALIDATION")]
+        skip_validation: bool,
+
+        /// Don't push to remote repository
+        #[arg(long, env = "CYRUP_RELEASE_NO_PUSH")]
+        no_push: bool,
+
+        /// Registry to publish to (defaults to crates.io)
+        #[arg(long, value_name = "REGISTRY", env = "CYRUP_RELEASE_REGISTRY")]
+        registry: Option<String>,
+
+        /// Delay between package publishes in seconds
+        #[arg(long, default_value = "15", value_name = "SECONDS", env = "CYRUP_RELEASE_PACKAGE_DELAY")]
+        package_delay: u64,
     },
 
     /// Rollback a failed or completed release
     Rollback {
         /// Force rollback even if state indicates success
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_FORCE")]
         force: bool,
 
         /// Only rollback git operations (don't yank packages)
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_GIT_ONLY")]
         git_only: bool,
 
         /// Only yank published packages (don't touch git)
-        #[arg(long, conflicts_with = "git_only")]
+        #[arg(long, conflicts_with = "git_only", env = "CYRUP_RELEASE_PACKAGES_ONLY")]
         packages_only: bool,
 
         /// Confirm rollback without prompting
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_YES")]
         yes: bool,
     },
 
     /// Resume an interrupted release
     Resume {
         /// Force resume even if state seems inconsistent
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_FORCE")]
         force: bool,
 
         /// Reset to specific phase before resuming
-        #[arg(long, value_enum)]
+        #[arg(long, value_enum, env = "CYRUP_RELEASE_RESET_TO_PHASE")]
         reset_to_phase: Option<ResumePhase>,
 
         /// Don't validate state before resuming
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_SKIP_VALIDATION")]
         skip_validation: bool,
     },
 
     /// Show status of current or last release
     Status {
         /// Show detailed status information
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_DETAILED")]
         detailed: bool,
 
         /// Show release history
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_HISTORY")]
         history: bool,
 
         /// Format output as JSON
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_JSON")]
         json: bool,
     },
 
     /// Clean up old state files and backups
     Cleanup {
         /// Remove all state files including backups
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_ALL")]
         all: bool,
 
-        /// Remove state files older than N days
-        #[arg(long, value_name = "DAYS")]
-        older_than: Option<u32>,
+        /// Only remove state files and backups older than this age (e.g. `30d`, `12h`; plain numbers are days)
+        #[arg(long, value_name = "AGE", value_parser = parse_age, env = "CYRUP_RELEASE_OLDER_THAN")]
+        older_than: Option<Duration>,
 
         /// Confirm cleanup without prompting
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_YES")]
         yes: bool,
     },
 
     /// Validate workspace for release readiness
     Validate {
         /// Fix validation issues automatically where possible
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_FIX")]
         fix: bool,
 
+        /// Also build documentation and run doctests for each package
+        #[arg(long, env = "CYRUP_RELEASE_CHECK_DOCS")]
+        check_docs: bool,
+
         /// Show detailed validation report
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_DETAILED")]
         detailed: bool,
 
         /// Format output as JSON
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_JSON")]
         json: bool,
+
+        /// Don't fail the working directory check for untracked files
+        #[arg(long, env = "CYRUP_RELEASE_ALLOW_UNTRACKED")]
+        allow_untracked: bool,
     },
 
     /// Preview version bump without making changes
@@ -180,13 +337,105 @@ pub enum Command {
         bump_type: BumpType,
 
         /// Show detailed preview including file changes
-        #[arg(short, long)]
+        #[arg(short, long, env = "CYRUP_RELEASE_DETAILED")]
         detailed: bool,
 
         /// Format output as JSON
-        #[arg(long)]
+        #[arg(long, env = "CYRUP_RELEASE_JSON")]
         json: bool,
     },
+
+    /// Yank (or un-yank) a published version of workspace packages
+    Yank {
+        /// Version to yank
+        version: Version,
+
+        /// Only yank these packages (defaults to all workspace packages)
+        #[arg(short, long = "package", value_name = "NAME", env = "CYRUP_RELEASE_PACKAGES")]
+        packages: Vec<String>,
+
+        /// Undo a previous yank
+        #[arg(long, env = "CYRUP_RELEASE_UNDO")]
+        undo: bool,
+
+        /// Registry to yank from
+        #[arg(long, env = "CYRUP_RELEASE_REGISTRY")]
+        registry: Option<String>,
+
+        /// Confirm without prompting
+        #[arg(short, long, env = "CYRUP_RELEASE_YES")]
+        yes: bool,
+    },
+
+    /// List, add or remove crates.io owners of workspace packages
+    Owners {
+        /// Owner to add (user login or `github:org:team`)
+        #[arg(long = "add", value_name = "LOGIN", env = "CYRUP_RELEASE_ADD")]
+        add: Vec<String>,
+
+        /// Owner to remove
+        #[arg(long = "remove", value_name = "LOGIN", env = "CYRUP_RELEASE_REMOVE")]
+        remove: Vec<String>,
+
+        /// Also add the expected owners from `[owners]` that are missing
+        #[arg(long, env = "CYRUP_RELEASE_SYNC")]
+        sync: bool,
+
+        /// Only manage these packages (defaults to all workspace packages)
+        #[arg(short, long = "package", value_name = "NAME", env = "CYRUP_RELEASE_PACKAGES")]
+        packages: Vec<String>,
+
+        /// Registry to manage owners on
+        #[arg(long, env = "CYRUP_RELEASE_REGISTRY")]
+        registry: Option<String>,
+
+        /// Confirm changes without prompting
+        #[arg(short, long, env = "CYRUP_RELEASE_YES")]
+        yes: bool,
+    },
+
+    /// Export the workspace dependency graph with publish tiers
+    Graph {
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "dot", env = "CYRUP_RELEASE_FORMAT")]
+        format: GraphFormat,
+    },
+
+    /// Rewrite deprecated configuration keys in place
+    MigrateConfig {
+        /// Show the keys that would change without writing the file
+        #[arg(short, long, env = "CYRUP_RELEASE_DRY_RUN")]
+        dry_run: bool,
+    },
+
+    /// Rewrite member manifests to inherit version and shared metadata from the workspace
+    MigrateInheritance {
+        /// Show the diff without writing any manifest
+        #[arg(short, long, env = "CYRUP_RELEASE_DRY_RUN")]
+        dry_run: bool,
+
+        /// Write without prompting
+        #[arg(short, long, env = "CYRUP_RELEASE_YES")]
+        yes: bool,
+    },
+
+    /// Generate shell completions and print them to stdout
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+
+    /// Check crates.io for a newer cyrup_release and install it
+    SelfUpdate {
+        /// Only report whether an update is available
+        #[arg(long, env = "CYRUP_RELEASE_CHECK")]
+        check: bool,
+
+        /// Install without prompting
+        #[arg(short, long, env = "CYRUP_RELEASE_YES")]
+        yes: bool,
+    },
 }
 
 /// Type of version bump
@@ -202,6 +451,26 @@ pub enum BumpType {
     Exact,
 }
 
+/// Output format for the dependency graph
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// JSON document
+    Json,
+}
+
+/// Document format of the software bill of materials
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SbomFormat {
+    /// CycloneDX 1.5 JSON
+    Cyclonedx,
+    /// SPDX 2.3 JSON
+    Spdx,
+}
+
 /// Phase to reset to when resuming
 #[derive(ValueEnum, Clone, Debug, PartialEq, Eq)]
 pub enum ResumePhase {
@@ -215,6 +484,25 @@ pub enum ResumePhase {
     Publishing,
 }
 
+/// Parse a cleanup age such as `30d`, `12h`, `45m` or `90s` (plain numbers are days)
+fn parse_age(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    let amount: u64 = amount.parse()
+        .map_err(|_| format!("Invalid age '{}': expected a number followed by d, h, m or s", value))?;
+    let seconds_per_unit = match unit {
+        "" | "d" => 24 * 60 * 60,
+        "h" => 60 * 60,
+        "m" => 60,
+        "s" => 1,
+        _ => return Err(format!("Invalid age unit '{}': expected d, h, m or s", unit)),
+    };
+
+    Ok(Duration::from_secs(amount.saturating_mul(seconds_per_unit)))
+}
+
 impl From<BumpType> for VersionBump {
     fn from(bump_type: BumpType) -> Self {
         match bump_type {
@@ -233,7 +521,15 @@ impl From<BumpType> for VersionBump {
 impl Args {
     /// Parse command line arguments
     pub fn parse_args() -> Self {
-        Self::parse()
+        let (argv, deprecations) = rewrite_deprecated_args(std::env::args_os());
+        let mut args = Self::parse_from(argv);
+        args.deprecations = deprecations;
+
+        args.ci = CiProvider::detect();
+        if args.ci.is_some() {
+            args.command.assume_yes();
+        }
+        args
     }
 
     /// Get workspace path or default to current directory
@@ -289,6 +585,7 @@ impl Args {
                 package_delay, 
                 max_retries, 
                 timeout,
+                override_freeze,
                 .. 
             } => {
                 if *package_delay > 3600 {
@@ -303,12 +600,20 @@ impl Args {
                 if *timeout > 3600 {
                     return Err("Timeout cannot exceed 1 hour (3600 seconds)".to_string());
                 }
+                if override_freeze.as_ref().is_some_and(|reason| reason.trim().is_empty()) {
+                    return Err("--override-freeze requires a reason".to_string());
+                }
+            }
+            Command::Hotfix { package_delay, .. } | Command::MetaRelease { package_delay, .. } => {
+                if *package_delay > 3600 {
+                    return Err("Package delay cannot exceed 1 hour (3600 seconds)".to_string());
+                }
             }
             Command::Cleanup { older_than, .. } => {
-                if let Some(days) = older_than {
-                    if *days > 365 {
-                        return Err("Cleanup age cannot exceed 365 days".to_string());
-                    }
+                if let Some(age) = older_than
+                    && *age > Duration::from_secs(365 * 24 * 60 * 60)
+                {
+                    return Err("Cleanup age cannot exceed 365 days".to_string());
                 }
             }
             _ => {}
@@ -323,15 +628,37 @@ impl Command {
     pub fn name(&self) -> &'static str {
         match self {
             Command::Release { .. } => "release",
+            Command::MetaRelease { .. } => "meta-release",
+            Command::Hotfix { .. } => "hotfix",
             Command::Rollback { .. } => "rollback",
             Command::Resume { .. } => "resume",
             Command::Status { .. } => "status",
             Command::Cleanup { .. } => "cleanup",
             Command::Validate { .. } => "validate",
             Command::Preview { .. } => "preview",
+            Command::MigrateConfig { .. } => "migrate-config",
+            Command::MigrateInheritance { .. } => "migrate-inheritance",
+            Command::Yank { .. } => "yank",
+            Command::Owners { .. } => "owners",
+            Command::Graph { .. } => "graph",
+            Command::Completions { .. } => "completions",
+            Command::SelfUpdate { .. } => "self-update",
         }
     }
 
+    /// Check if confirmation prompts were answered with `--yes`
+    pub fn assumes_yes(&self) -> bool {
+        matches!(
+            self,
+            Command::Rollback { yes: true, .. } |
+            Command::Cleanup { yes: true, .. } |
+            Command::Yank { yes: true, .. } |
+            Command::Owners { yes: true, .. } |
+            Command::MigrateInheritance { yes: true, .. } |
+            Command::SelfUpdate { yes: true, .. }
+        )
+    }
+
     /// Check if this command requires an existing release state
     pub fn requires_state(&self) -> bool {
         matches!(self, Command::Rollback { .. } | Command::Resume { .. })
@@ -342,9 +669,17 @@ impl Command {
         matches!(
             self,
             Command::Release { dry_run: false, .. } | 
+            Command::MetaRelease { dry_run: false, .. } |
+            Command::Hotfix { dry_run: false, .. } |
             Command::Rollback { .. } | 
             Command::Resume { .. } |
-            Command::Validate { fix: true, .. }
+            Command::Validate { fix: true, .. } |
+            Command::MigrateConfig { dry_run: false } |
+            Command::MigrateInheritance { dry_run: false, .. } |
+            Command::Yank { .. }
+        ) || matches!(
+            self,
+            Command::Owners { add, remove, sync, .. } if *sync || !add.is_empty() || !remove.is_empty()
         )
     }
 
@@ -353,6 +688,8 @@ impl Command {
         matches!(
             self,
             Command::Release { skip_validation: false, .. } |
+            Command::MetaRelease { skip_validation: false, .. } |
+            Command::Hotfix { skip_validation: false, .. } |
             Command::Resume { skip_validation: false, .. }
         )
     }
@@ -377,6 +714,8 @@ pub struct RuntimeConfig {
     pub registry: Option<String>,
     /// Whether to create backups
     pub create_backups: bool,
+    /// Answers confirmation prompts
+    pub prompter: Arc<dyn Prompter>,
 }
 
 /// Verbosity level for output
@@ -433,6 +772,7 @@ impl From<&Args> for RuntimeConfig {
             timeout,
             registry,
             create_backups,
+            prompter: select_prompter(args.yes_all || args.command.assumes_yes(), args.assume_no),
         }
     }
 }
@@ -448,36 +788,33 @@ impl RuntimeConfig {
         self.verbosity == VerbosityLevel::Verbose
     }
 
+    /// Ask for confirmation before a destructive operation
+    pub fn confirm(&self, message: &str) -> crate::error::Result<bool> {
+        self.prompter.confirm(message)
+    }
+
     /// Print message if not in quiet mode
     pub fn println(&self, message: &str) {
-        if !self.is_quiet() {
-            println!("{}", message);
-        }
+        tracing::info!(target: OUTPUT_TARGET, "{}", message);
     }
 
     /// Print verbose message if in verbose mode
     pub fn verbose_println(&self, message: &str) {
-        if self.is_verbose() {
-            println!("🔍 {}", message);
-        }
+        tracing::debug!(target: OUTPUT_TARGET, "{}", message);
     }
 
     /// Print error message (always shown)
     pub fn error_println(&self, message: &str) {
-        eprintln!("❌ {}", message);
+        tracing::error!(target: OUTPUT_TARGET, "{}", message);
     }
 
     /// Print warning message if not in quiet mode
     pub fn warning_println(&self, message: &str) {
-        if !self.is_quiet() {
-            println!("⚠️ {}", message);
-        }
+        tracing::warn!(target: OUTPUT_TARGET, "{}", message);
     }
 
     /// Print success message if not in quiet mode
     pub fn success_println(&self, message: &str) {
-        if !self.is_quiet() {
-            println!("✅ {}", message);
-        }
+        tracing::info!(target: OUTPUT_TARGET, success = true, "{}", message);
     }
 }
\ No newline at end of file
@@ -0,0 +1,253 @@
+//! Continuous integration provider awareness.
+//!
+//! Detects GitHub Actions, GitLab CI and Buildkite from their environment
+//! variables. When running under one of them, commands that would prompt for
+//! confirmation run non-interactively and each release phase is wrapped in
+//! the provider's collapsible log group markers.
+
+use crate::cli::Command;
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Prefix of the environment variables that override command line flags
+pub const ENV_PREFIX: &str = "CYRUP_RELEASE_";
+
+/// Supported CI providers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiProvider {
+    /// GitHub Actions (`GITHUB_ACTIONS=true`)
+    GitHubActions,
+    /// GitLab CI (`GITLAB_CI=true`)
+    GitLabCi,
+    /// Buildkite (`BUILDKITE=true`)
+    Buildkite,
+}
+
+impl CiProvider {
+    /// Detect the CI provider from the environment.
+    ///
+    /// Setting `CYRUP_RELEASE_CI=false` disables detection.
+    pub fn detect() -> Option<Self> {
+        Self::detect_from(|name| std::env::var(name).ok())
+    }
+
+    /// Detect the CI provider from variables looked up through `var`
+    fn detect_from(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        if var(&format!("{}CI", ENV_PREFIX)).is_some_and(|value| is_falsey(&value)) {
+            return None;
+        }
+
+        let is_set = |name: &str| var(name).is_some_and(|value| value == "true");
+        if is_set("GITHUB_ACTIONS") {
+            Some(Self::GitHubActions)
+        } else if is_set("GITLAB_CI") {
+            Some(Self::GitLabCi)
+        } else if is_set("BUILDKITE") {
+            Some(Self::Buildkite)
+        } else {
+            None
+        }
+    }
+
+    /// Human readable provider name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GitHubActions => "GitHub Actions",
+            Self::GitLabCi => "GitLab CI",
+            Self::Buildkite => "Buildkite",
+        }
+    }
+
+    /// Log line opening a collapsible group
+    pub fn group_start(&self, id: &str, title: &str) -> String {
+        match self {
+            Self::GitHubActions => format!("::group::{}", title),
+            Self::GitLabCi => format!(
+                "\x1b[0Ksection_start:{}:{}[collapsed=true]\r\x1b[0K{}",
+                chrono::Utc::now().timestamp(),
+                id,
+                title
+            ),
+            Self::Buildkite => format!("--- {}", title),
+        }
+    }
+
+    /// Log line closing a group (Buildkite groups end at the next header)
+    pub fn group_end(&self, id: &str) -> Option<String> {
+        match self {
+            Self::GitHubActions => Some("::endgroup::".to_string()),
+            Self::GitLabCi => Some(format!(
+                "\x1b[0Ksection_end:{}:{}\r\x1b[0K",
+                chrono::Utc::now().timestamp(),
+                id
+            )),
+            Self::Buildkite => None,
+        }
+    }
+}
+
+impl fmt::Display for CiProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Command {
+    /// Confirm prompts up front, as there is nobody to answer them in CI
+    pub fn assume_yes(&mut self) {
+        match self {
+            Command::Rollback { yes, .. }
+            | Command::Cleanup { yes, .. }
+            | Command::Yank { yes, .. }
+            | Command::Owners { yes, .. }
+            | Command::MigrateInheritance { yes, .. }
+            | Command::SelfUpdate { yes, .. } => *yes = true,
+            _ => {}
+        }
+    }
+}
+
+/// Check if an environment value switches something off
+fn is_falsey(value: &str) -> bool {
+    matches!(value.trim().to_ascii_lowercase().as_str(), "0" | "false" | "no" | "off")
+}
+
+/// Layer wrapping each release phase span in CI log group markers
+#[derive(Debug, Clone, Copy)]
+pub struct CiGroupLayer {
+    provider: CiProvider,
+}
+
+/// Group opened for a phase span, kept in the span's extensions
+struct CiGroup {
+    id: String,
+}
+
+impl CiGroupLayer {
+    /// Create a group layer for a CI provider
+    pub fn new(provider: CiProvider) -> Self {
+        Self { provider }
+    }
+}
+
+impl<S> Layer<S> for CiGroupLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if attrs.metadata().name() != "phase" {
+            return;
+        }
+
+        let mut visitor = PhaseVisitor::default();
+        attrs.record(&mut visitor);
+        let Some(phase) = visitor.phase else {
+            return;
+        };
+
+        println!("{}", self.provider.group_start(&phase, &format!("Release phase: {}", phase)));
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(CiGroup { id: phase });
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        if let Some(group) = span.extensions().get::<CiGroup>()
+            && let Some(line) = self.provider.group_end(&group.id)
+        {
+            println!("{}", line);
+        }
+    }
+}
+
+/// Extracts the `phase` field of a span
+#[derive(Default)]
+struct PhaseVisitor {
+    phase: Option<String>,
+}
+
+impl Visit for PhaseVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "phase" {
+            self.phase = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "phase" {
+            self.phase = Some(format!("{:?}", value).trim_matches('"').to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Args;
+    use clap::Parser;
+    use std::collections::HashMap;
+
+    fn detect(vars: &[(&str, &str)]) -> Option<CiProvider> {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        CiProvider::detect_from(|name| vars.get(name).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn test_detect_provider() {
+        assert_eq!(detect(&[]), None);
+        assert_eq!(detect(&[("GITHUB_ACTIONS", "true")]), Some(CiProvider::GitHubActions));
+        assert_eq!(detect(&[("GITLAB_CI", "true")]), Some(CiProvider::GitLabCi));
+        assert_eq!(detect(&[("BUILDKITE", "true")]), Some(CiProvider::Buildkite));
+        // Only the exact value `true` counts
+        assert_eq!(detect(&[("GITHUB_ACTIONS", "1")]), None);
+        assert_eq!(detect(&[("GITHUB_ACTIONS", "true"), ("GITLAB_CI", "true")]), Some(CiProvider::GitHubActions));
+    }
+
+    #[test]
+    fn test_detection_can_be_disabled() {
+        for value in ["false", "0", " No ", "OFF"] {
+            assert_eq!(detect(&[("GITHUB_ACTIONS", "true"), ("CYRUP_RELEASE_CI", value)]), None, "{}", value);
+        }
+        assert_eq!(
+            detect(&[("GITHUB_ACTIONS", "true"), ("CYRUP_RELEASE_CI", "true")]),
+            Some(CiProvider::GitHubActions)
+        );
+    }
+
+    #[test]
+    fn test_group_markers() {
+        let github = CiProvider::GitHubActions;
+        assert_eq!(github.group_start("publish", "Release phase: publish"), "::group::Release phase: publish");
+        assert_eq!(github.group_end("publish").as_deref(), Some("::endgroup::"));
+
+        let gitlab = CiProvider::GitLabCi;
+        let start = gitlab.group_start("publish", "Release phase: publish");
+        assert!(start.starts_with("\x1b[0Ksection_start:"), "{:?}", start);
+        assert!(start.ends_with(":publish[collapsed=true]\r\x1b[0KRelease phase: publish"), "{:?}", start);
+        let end = gitlab.group_end("publish").unwrap();
+        assert!(end.starts_with("\x1b[0Ksection_end:") && end.ends_with(":publish\r\x1b[0K"), "{:?}", end);
+
+        let buildkite = CiProvider::Buildkite;
+        assert_eq!(buildkite.group_start("publish", "Release phase: publish"), "--- Release phase: publish");
+        assert_eq!(buildkite.group_end("publish"), None);
+        assert_eq!(buildkite.to_string(), "Buildkite");
+    }
+
+    #[test]
+    fn test_assume_yes_confirms_prompting_commands() {
+        let mut args = Args::try_parse_from(["cyrup_release", "rollback"]).unwrap();
+        args.command.assume_yes();
+        assert!(matches!(args.command, Command::Rollback { yes: true, .. }));
+
+        let mut args = Args::try_parse_from(["cyrup_release", "cleanup"]).unwrap();
+        args.command.assume_yes();
+        assert!(matches!(args.command, Command::Cleanup { yes: true, .. }));
+    }
+}
@@ -3,18 +3,40 @@
 //! This module implements the complete release workflow by coordinating
 //! all modules and providing comprehensive error handling and user feedback.
 
-use crate::cli::{Args, Command, BumpType, ResumePhase, RuntimeConfig};
-use crate::error::{Result, ReleaseError};
-use crate::git::{GitManager, GitConfig};
-use crate::publish::{Publisher, PublisherConfig};
+use crate::cli::{
+    Args, Command, BumpType, GraphFormat, ResumePhase, RuntimeConfig, SbomFormat, DeprecationWarning, init_logging,
+    report_deprecations,
+};
+use crate::config::{
+    load_project_config, migrate_config_file, project_config_path, ProjectConfig, ValidationSection,
+    VersioningMode,
+};
+use crate::error::{CliError, GitError, PublishError, Result, ReleaseError, WorkspaceError};
+use crate::git::{
+    render_template, CommitInfo, GitManager, GitOperations, GitRepository, HostingProvider, RemoteUrl, StashOptions,
+    TagInfo, TemplateContext,
+};
+use crate::metrics::{MetricsExporter, ReleaseMetrics, ReleaseOutcome};
+use crate::notify::{Notifier, NotifyEvent};
+use crate::publish::{
+    CargoOwners, CrateAvailability, DocsBuildStatus, DocsRsClient, DocsVerification, OwnerChange, OwnershipDiff,
+    Publisher, PublisherConfig, PublishingResult, RegistryIndex,
+};
 use crate::state::{
-    ReleaseState, ReleasePhase, ReleaseConfig,
-    create_state_manager_at, has_active_release_at,
-    StateConfig, create_state_manager_with_config,
+    ReleaseState, ReleasePhase, ReleaseConfig, ReleaseHistory, ReleaseTimeline, MetaReleaseState,
+    has_active_release_at, open_state_store,
+    StateConfig, StateManager, create_state_manager_with_config,
 };
-use crate::version::{VersionManager, VersionBump, TomlEditor};
-use crate::workspace::{WorkspaceInfo, WorkspaceValidator};
+use crate::version::{DependencySection, InheritanceMigration, Lockfile, PathDependencies, VersionManager, VersionBump, TomlEditor};
+use crate::workspace::{
+    internal_patches, strip_internal_patches, update_upstream_requirements, DependencyGraph, MetaManifest, MetaRepo,
+    MetaWorkspace, Sbom, ValidatorConfig, WorkspaceInfo, WorkspaceValidator, DEFAULT_META_MANIFEST,
+};
+use clap::CommandFactory;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
+use tracing::{info_span, Instrument};
 
 /// Execute the main command based on parsed arguments
 pub async fn execute_command(args: Args) -> Result<i32> {
@@ -25,21 +47,46 @@ pub async fn execute_command(args: Args) -> Result<i32> {
     }
 
     let config = RuntimeConfig::from(&args);
+
+    if let Err(e) = init_logging(&args, config.verbosity) {
+        eprintln!("❌ {}", e);
+        return Ok(1);
+    }
+
+    // Report deprecated flags before doing any work
+    if let Err(e) = report_deprecations(&args.deprecations, args.deny_deprecated, &config) {
+        config.error_println(&format!("Command '{}' failed: {}", args.command.name(), e));
+        return Ok(1);
+    }
+
+    if let Some(provider) = args.ci {
+        config.verbose_println(&format!("Running under {}; confirmations are assumed", provider));
+    }
     
     // Execute command and handle errors
     let result = match &args.command {
         Command::Release { .. } => execute_release(&args, &config).await,
+        Command::MetaRelease { .. } => execute_meta_release(&args, &config).await,
+        Command::Hotfix { .. } => execute_hotfix(&args, &config).await,
         Command::Rollback { .. } => execute_rollback(&args, &config).await,
         Command::Resume { .. } => execute_resume(&args, &config).await,
         Command::Status { .. } => execute_status(&args, &config).await,
         Command::Cleanup { .. } => execute_cleanup(&args, &config).await,
         Command::Validate { .. } => execute_validate(&args, &config).await,
         Command::Preview { .. } => execute_preview(&args, &config).await,
+        Command::MigrateConfig { .. } => execute_migrate_config(&args, &config).await,
+        Command::MigrateInheritance { .. } => execute_migrate_inheritance(&args, &config).await,
+        Command::Yank { .. } => execute_yank(&args, &config).await,
+        Command::Owners { .. } => execute_owners(&args, &config).await,
+        Command::Graph { .. } => execute_graph(&args, &config).await,
+        Command::Completions { .. } => execute_completions(&args),
+        Command::SelfUpdate { .. } => execute_self_update(&args, &config).await,
     };
 
     match result {
         Ok(()) => {
-            if !config.is_quiet() {
+            // Keep stdout clean for commands whose output is meant to be piped
+            if !config.is_quiet() && !matches!(args.command, Command::Graph { .. } | Command::Completions { .. }) {
                 config.success_println(&format!("Command '{}' completed successfully", args.command.name()));
             }
             Ok(0)
@@ -65,18 +112,164 @@ pub async fn execute_command(args: Args) -> Result<i32> {
 
 /// Execute release command
 async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::Release { isolated, dry_run, stash, .. } = &args.command {
+        if *isolated && !dry_run {
+            return execute_isolated_release(args, config).await;
+        }
+        if !*stash || *dry_run {
+            return release_workspace(args, config, None).await;
+        }
+
+        // Release state files stay in place so an interrupted release can resume
+        let repository = GitRepository::open(&config.workspace_path)?;
+        let stashed = repository.stash_save(&StashOptions {
+            message: Some("cyrup_release: uncommitted changes during release".to_string()),
+            include_untracked: true,
+            excluded: vec!["**/.cyrup_release_*".to_string()],
+        }).await?;
+        if let Some(entry) = &stashed {
+            config.println(&format!("📥 Stashed uncommitted changes as {}", entry.reference));
+        }
+
+        let result = release_workspace(args, config, None).await;
+
+        if let Some(entry) = stashed {
+            // Other entries may have been pushed meanwhile; find ours by commit
+            let reference = repository.stash_list().await?.into_iter()
+                .find(|current| current.commit == entry.commit)
+                .map_or(entry.reference, |current| current.reference);
+            match repository.stash_pop(&reference).await {
+                Ok(()) => config.println("📤 Restored stashed changes"),
+                Err(e) => config.warning_println(&format!(
+                    "Could not restore stashed changes ({}); they are kept in {}",
+                    e, reference
+                )),
+            }
+        }
+        result
+    } else {
+        unreachable!("execute_release called with non-Release command");
+    }
+}
+
+/// Temporary worktree an isolated release runs in
+#[derive(Debug)]
+struct ReleaseWorktree {
+    /// Root of the worktree checkout
+    path: std::path::PathBuf,
+    /// Branch the release commit is pushed to
+    branch: String,
+    /// Target directory shared with the developer's workspace to reuse build artifacts
+    target_dir: std::path::PathBuf,
+}
+
+/// Run a release in a temporary worktree checked out from HEAD.
+///
+/// Version edits, the build check, the release commit and tags all happen in
+/// the worktree, and the commit is pushed to the current branch only once they
+/// succeed. Uncommitted changes in the working tree are not part of the release.
+async fn execute_isolated_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    let repository = GitRepository::open(&config.workspace_path)?;
+    let branch = repository.get_current_branch().await?.name;
+    if branch.is_empty() {
+        return Err(GitError::BranchOperationFailed {
+            reason: "Isolated releases must start from a branch, not a detached HEAD".to_string(),
+        }.into());
+    }
+
+    let top_level = repository.top_level().await?;
+    let workspace_root = config.workspace_path.canonicalize()?;
+    let relative_root = workspace_root.strip_prefix(top_level.canonicalize()?)
+        .map(std::path::Path::to_path_buf)
+        .unwrap_or_default();
+
+    let worktree = ReleaseWorktree {
+        path: std::env::temp_dir().join(format!("cyrup_release-{}", std::process::id())),
+        branch,
+        target_dir: workspace_root.join("target"),
+    };
+
+    config.println(&format!("🌳 Creating isolated worktree at {}...", worktree.path.display()));
+    repository.add_worktree(&worktree.path).await?;
+    if !repository.is_working_directory_clean().await? {
+        config.warning_println("Uncommitted changes in the working tree are not part of an isolated release");
+    }
+
+    let isolated_config = RuntimeConfig {
+        workspace_path: worktree.path.join(relative_root),
+        ..config.clone()
+    };
+    let result = release_workspace(args, &isolated_config, Some(&worktree)).await;
+
+    match &result {
+        Ok(()) => {
+            repository.remove_worktree(&worktree.path).await?;
+            config.verbose_println("Removed isolated worktree");
+            config.println(&format!(
+                "Release pushed from an isolated worktree; run 'git pull' to update '{}'",
+                worktree.branch
+            ));
+        }
+        Err(_) => {
+            config.warning_println(&format!(
+                "Isolated worktree kept for inspection at {} (remove with 'git worktree remove --force {}')",
+                worktree.path.display(),
+                worktree.path.display()
+            ));
+        }
+    }
+
+    result
+}
+
+/// Check that the bumped workspace in an isolated worktree still builds
+async fn check_worktree_build(workspace: &WorkspaceInfo, worktree: &ReleaseWorktree) -> Result<()> {
+    let output = tokio::process::Command::new("cargo")
+        .args(["check", "--workspace", "--all-targets", "--target-dir"])
+        .arg(&worktree.target_dir)
+        .current_dir(&workspace.root)
+        .output()
+        .await
+        .map_err(|e| WorkspaceError::BuildFailed {
+            reason: format!("Failed to execute cargo check: {}", e),
+        })?;
+
+    if !output.status.success() {
+        return Err(WorkspaceError::BuildFailed {
+            reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }.into());
+    }
+
+    Ok(())
+}
+
+/// Release the workspace at `config.workspace_path`, optionally inside an isolated worktree
+async fn release_workspace(args: &Args, config: &RuntimeConfig, worktree: Option<&ReleaseWorktree>) -> Result<()> {
     if let Command::Release {
         bump_type,
         dry_run,
         skip_validation,
         allow_dirty,
+        allow_untracked,
         no_push,
         registry,
         package_delay,
-        max_retries: _,
-        timeout: _,
+        max_retries,
+        timeout,
         no_backup,
         max_concurrent,
+        package,
+        verify_docs,
+        first_release,
+        check_docs,
+        isolated: _,
+        stash: _,
+        no_verify,
+        schedule,
+        override_freeze,
+        sbom: sbom_format,
+        sbom_attach,
+        strip_patches,
     } = &args.command {
         config.verbose_println("Starting release operation...");
 
@@ -98,10 +291,140 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
         config.verbose_println("Analyzing workspace...");
         let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
 
+        if let Some(package_name) = package {
+            workspace.get_package(package_name)?;
+        }
+
+        // Load project configuration
+        let project_config = load_config(args, config, &workspace)?;
+
+        report_package_overrides(&workspace, &project_config, config);
+
+        // A release started on another machine only shows up in the shared store
+        let state_store = open_state_store(&project_config.state)?;
+        if let Some(store) = &state_store
+            && store.read(&state_file_name(&config.state_file_path))?.is_some()
+        {
+            return Err(ReleaseError::State(crate::error::StateError::SaveFailed {
+                reason: format!(
+                    "Another release is in progress (state in {}). Use 'resume' or 'cleanup' first",
+                    store.describe()
+                ),
+            }));
+        }
+
+        // Check the release schedule before anything is changed
+        let freeze_override = check_release_schedule(
+            &project_config,
+            *schedule,
+            override_freeze.as_deref(),
+            *dry_run,
+            config,
+        )?;
+
+        // Initialize version manager and compute the target version
+        let mut version_manager = VersionManager::new(workspace.clone());
+
+        // Determine version bump
+        let version_bump = match bump_type {
+            BumpType::Exact => {
+                // This would need additional input for exact version
+                return Err(ReleaseError::Cli(crate::error::CliError::InvalidArguments {
+                    reason: "Exact version bump not yet implemented".to_string(),
+                }));
+            }
+            _ => VersionBump::from(bump_type.clone()),
+        };
+
+        // In independent mode each package is bumped from its own version
+        let independent = project_config.release.versioning == VersioningMode::Independent;
+        let package_changes = if independent && package.is_none() {
+            version_manager.plan_independent_bump(version_bump.clone())?
+        } else {
+            Vec::new()
+        };
+
+        let (current_version, new_version) = match package {
+            Some(package_name) => {
+                let current_version = version_manager.package_version(package_name)?;
+                let new_version = crate::version::VersionBumper::from_version(current_version.clone())
+                    .bump(version_bump.clone())?;
+                (current_version, new_version)
+            }
+            None if independent => {
+                let highest = |versions: Vec<&semver::Version>| versions.into_iter().max().cloned()
+                    .ok_or_else(|| crate::error::WorkspaceError::InvalidStructure {
+                        reason: "Workspace has no packages to release".to_string(),
+                    });
+                (
+                    highest(package_changes.iter().map(|change| &change.previous_version).collect())?,
+                    highest(package_changes.iter().map(|change| &change.new_version).collect())?,
+                )
+            }
+            None => {
+                let current_version = version_manager.current_version()?;
+                let new_version = crate::version::VersionBumper::from_version(current_version.clone())
+                    .bump(version_bump.clone())?;
+                (current_version, new_version)
+            }
+        };
+
+        // Resolve tag and message template variables for this release
+        let template_context = match package {
+            Some(package_name) => TemplateContext::new(&new_version).with_package(package_name.clone()),
+            None => TemplateContext::new(&new_version).with_packages(workspace.package_names()),
+        };
+
+        // Packages that get their own tag (independent mode only)
+        let package_tags: Vec<(String, semver::Version)> = match package {
+            Some(package_name) if independent => vec![(package_name.clone(), new_version.clone())],
+            _ => package_changes.iter()
+                .map(|change| (change.package_name.clone(), change.new_version.clone()))
+                .collect(),
+        };
+
+        let release_tags = if independent {
+            package_tags.iter()
+                .map(|(package_name, version)| render_template(
+                    &project_config.git.package_tag_template,
+                    &template_context.for_package(package_name, version),
+                ))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            vec![render_template(&project_config.git.tag_template, &template_context)?]
+        };
+
+        // Packages whose new versions the release ships
+        let released_packages: Vec<String> = match package {
+            Some(package_name) => vec![package_name.clone()],
+            None if independent => package_changes.iter().map(|change| change.package_name.clone()).collect(),
+            None => workspace.package_names(),
+        };
+        let sbom_path = sbom_format.map(|format| sbom_path_for(&config.state_file_path, &new_version, format));
+
+        // Detect never-published crates and make sure their names can be claimed
+        let new_crates = if *first_release {
+            check_first_release(&workspace, &project_config, package.as_deref(), registry.as_deref(), config).await?
+        } else {
+            HashSet::new()
+        };
+
         // Validate workspace if not skipped
         if !skip_validation {
             config.verbose_println("Validating workspace...");
-            let validator = WorkspaceValidator::new(workspace.clone())?;
+            let validator_config = ValidatorConfig {
+                git: project_config.git.clone(),
+                release_tags: release_tags.clone(),
+                package_contents: project_config.package_contents.clone(),
+                owners: project_config.owners.clone(),
+                registry: registry.clone(),
+                validation: with_doc_checks(&project_config.validation, *check_docs),
+                advisories: project_config.advisories.clone(),
+                branch: worktree.map(|worktree| worktree.branch.clone()),
+                strip_patches: *strip_patches,
+                allow_untracked: *allow_untracked,
+            };
+            let validator = WorkspaceValidator::with_config(workspace.clone(), validator_config)?;
             let validation = validator.validate().await?;
             
             if !validation.success {
@@ -122,14 +445,34 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
             }
         }
 
+        // Verify pinned internal dependencies resolve against the registry
+        if let Some(package_name) = package {
+            config.println(&format!("🔗 Checking pinned dependencies of {}...", package_name));
+            let registry_index = RegistryIndex::for_registry(registry.as_deref())?;
+            let pinned = registry_index
+                .verify_pinned_dependencies(workspace.get_package(package_name)?, &workspace)
+                .await?;
+
+            for dependency in &pinned {
+                config.verbose_println(&format!("  {}", dependency.format_result()));
+            }
+            config.success_println(&format!("{} pinned dependencies resolve from the registry", pinned.len()));
+        }
+
         // Initialize managers
-        let mut version_manager = VersionManager::new(workspace.clone());
-        
-        let git_config = GitConfig {
-            default_remote: "origin".to_string(),
+        // Untracked files present before the release stay out of the release commit
+        let excluded_paths = if *allow_untracked {
+            GitRepository::open(&config.workspace_path)?.status().await?.untracked
+        } else {
+            Vec::new()
+        };
+        let git_config = crate::git::GitConfig {
             annotated_tags: true,
+            excluded_paths,
             auto_push_tags: !no_push,
-            ..Default::default()
+            push_branch: worktree.map(|worktree| worktree.branch.clone()),
+            skip_hooks: *no_verify,
+            ..project_config.git.git_config()
         };
         let mut git_manager = GitManager::with_config(&config.workspace_path, git_config)?;
 
@@ -137,23 +480,17 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
             inter_package_delay: Duration::from_secs(*package_delay),
             registry: registry.clone(),
             max_concurrent_per_tier: *max_concurrent,
+            new_crates: new_crates.clone(),
+            history_path: Some(ReleaseHistory::path_for_state_file(&config.state_file_path)),
+            max_retries: *max_retries,
+            operation_timeout: Duration::from_secs(*timeout),
+            package_overrides: project_config.packages.clone(),
             ..Default::default()
         };
         let mut publisher = Publisher::with_config(&workspace, publisher_config)?;
 
-        // Determine version bump
-        let version_bump = match bump_type {
-            BumpType::Exact => {
-                // This would need additional input for exact version
-                return Err(ReleaseError::Cli(crate::error::CliError::InvalidArguments {
-                    reason: "Exact version bump not yet implemented".to_string(),
-                }));
-            }
-            _ => VersionBump::from(bump_type.clone()),
-        };
-
         // Create release state
-        let release_config = ReleaseConfig {
+        let mut release_config = ReleaseConfig {
             dry_run_first: true,
             push_to_remote: !no_push,
             inter_package_delay_ms: package_delay * 1000,
@@ -162,15 +499,36 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
             ..Default::default()
         };
 
-        let current_version = version_manager.current_version()?;
-        let bumper = crate::version::VersionBumper::from_version(current_version.clone());
-        let new_version = bumper.bump(version_bump.clone())?;
+        if let Some(package_name) = package {
+            release_config.additional_options.insert(
+                "package".to_string(),
+                serde_json::Value::String(package_name.clone()),
+            );
+        }
+        if independent {
+            release_config.additional_options.insert(
+                "versioning".to_string(),
+                serde_json::Value::String("independent".to_string()),
+            );
+        }
+        if let Some(freeze_override) = freeze_override {
+            release_config.additional_options.insert("freeze_override".to_string(), freeze_override);
+        }
+        if !new_crates.is_empty() {
+            let mut names: Vec<&String> = new_crates.iter().collect();
+            names.sort();
+            release_config.additional_options.insert(
+                "first_release".to_string(),
+                serde_json::json!(names),
+            );
+        }
 
         let mut release_state = ReleaseState::new(new_version.clone(), version_bump.clone(), release_config);
         
         // Initialize state manager
         let state_config = StateConfig {
             create_backups: !no_backup,
+            store: state_store,
             ..StateConfig::default()
         };
         let mut state_manager = create_state_manager_with_config(&config.state_file_path, state_config)?;
@@ -179,8 +537,33 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
             config.println("🔍 Performing dry run...");
             
             // Preview changes
-            let preview = version_manager.preview_bump(version_bump)?;
-            config.println(&format!("Version preview: {}", preview.format_preview()));
+            match package {
+                Some(package_name) => {
+                    config.println(&format!(
+                        "Version preview: {} {} → {} (other members unchanged)",
+                        package_name, current_version, new_version
+                    ));
+                }
+                None if independent => {
+                    config.println("Version preview (independent versioning):");
+                    for change in &package_changes {
+                        config.println(&format!("  {}", change.format_change()));
+                    }
+                }
+                None => {
+                    let preview = version_manager.preview_bump(version_bump)?;
+                    config.println(&format!("Version preview: {}", preview.format_preview()));
+                }
+            }
+            config.println(&format!("Tags: {}", release_tags.join(", ")));
+            if let Some(sbom_path) = &sbom_path {
+                config.println(&format!("SBOM: {}", sbom_path.display()));
+            }
+            if *strip_patches {
+                for patch in internal_patches(&workspace)? {
+                    config.println(&format!("Would strip {}", patch.format_patch()));
+                }
+            }
             
             // Validate packages
             config.println("Validating packages for publishing...");
@@ -191,7 +574,17 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
         }
 
         // Begin release process
-        config.println(&format!("🚀 Starting release: {} → {}", current_version, new_version));
+        match package {
+            Some(package_name) => config.println(&format!(
+                "🚀 Starting release of {}: {} → {}",
+                package_name, current_version, new_version
+            )),
+            None if independent => config.println(&format!(
+                "🚀 Starting independent release of {} packages",
+                package_changes.len()
+            )),
+            None => config.println(&format!("🚀 Starting release: {} → {}", current_version, new_version)),
+        }
         
         release_state.add_checkpoint(
             "release_started".to_string(),
@@ -201,185 +594,768 @@ async fn execute_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
         );
         state_manager.save_state(&release_state)?;
 
-        // Phase 1: Version Update
-        config.println("📝 Updating versions...");
+        notify_event(&project_config, NotifyEvent::Started, &release_state, None, config).await;
 
-        // Capture original versions before bumping (for rollback support)
-        let mut original_versions = std::collections::HashMap::new();
-        for (package_name, package_info) in &workspace.packages {
-            original_versions.insert(package_name.clone(), package_info.version.clone());
-        }
-        release_state.set_original_versions(original_versions);
+        // Run the release phases, notifying webhooks if any of them fail
+        let release_span = info_span!("release", release_id = %release_state.release_id, version = %new_version);
+        let outcome: Result<Option<DocsVerification>> = async {
+            // Phase 1: Version Update
+            async {
+                config.println("📝 Updating versions...");
+
+                // Capture original versions before bumping (for rollback support)
+                let mut original_versions = std::collections::HashMap::new();
+                for (package_name, package_info) in &workspace.packages {
+                    if package.as_ref().is_none_or(|name| name == package_name) {
+                        original_versions.insert(package_name.clone(), package_info.version.clone());
+                    }
+                }
+                release_state.set_original_versions(original_versions);
 
-        let version_result = version_manager.release_version(version_bump)?;
+                let (update_result, update_summary) = match package {
+                    Some(package_name) => {
+                        let result = version_manager.release_package_version(package_name, version_bump)?;
+                        let summary = result.summary();
+                        (result.update_result, summary)
+                    }
+                    None if independent => {
+                        let result = version_manager.release_independent_versions(version_bump)?;
+                        for change in &result.packages {
+                            config.verbose_println(&format!("  {}", change.format_change()));
+                        }
+                        let summary = result.summary();
+                        (result.update_result, summary)
+                    }
+                    None => {
+                        let result = version_manager.release_version(version_bump)?;
+                        let summary = result.summary();
+                        (result.update_result, summary)
+                    }
+                };
         
-        // Set phase and state together to maintain consistency
-        release_state.set_phase(ReleasePhase::VersionUpdate);
-        release_state.set_version_state(&version_result.update_result);
-        release_state.add_checkpoint(
-            "version_updated".to_string(),
-            ReleasePhase::VersionUpdate,
-            None,
-            true,
-        );
-        state_manager.save_state(&release_state)?;
+                // Set phase and state together to maintain consistency
+                release_state.set_phase(ReleasePhase::VersionUpdate);
+                release_state.set_version_state(&update_result);
+                release_state.add_checkpoint(
+                    "version_updated".to_string(),
+                    ReleasePhase::VersionUpdate,
+                    None,
+                    true,
+                );
+                state_manager.save_state(&release_state)?;
+
+                config.success_println(&format!("Version updated: {}", update_summary));
+
+                // Published packages never see [patch] entries, so keep them out of the release commit
+                if *strip_patches {
+                    let stripped = strip_internal_patches(&workspace)?;
+                    for patch in &stripped {
+                        config.verbose_println(&format!("  Removed {}", patch.format_patch()));
+                    }
+                    if !stripped.is_empty() {
+                        config.success_println(&format!("Stripped {} internal [patch] entries", stripped.len()));
+                    }
+                }
 
-        config.success_println(&format!("Version updated: {}", version_result.summary()));
+                // Refresh Cargo.lock so the release commit records the new versions
+                let lockfile = Lockfile::new(&workspace.root);
+                if project_config.release.update_lockfile && lockfile.exists() {
+                    config.println("🔒 Updating Cargo.lock...");
+                    lockfile.refresh().await?;
+
+                    // Verify against the bumped manifests before anything is committed or tagged
+                    let bumped_workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+                    let report = lockfile.verify(&bumped_workspace)?;
+                    if !report.is_consistent() {
+                        config.error_println(&report.format_report());
+                    }
+                    let report = report.into_result()?;
+                    config.success_println(&report.format_report());
+                }
 
-        // Phase 2: Git Operations
-        config.println("📦 Creating git commit and tag...");
+                // Catch path dependencies the registry would reject before anything is committed
+                let published: Vec<String> = released_packages.iter()
+                    .filter(|package_name| !project_config.is_publish_skipped(package_name))
+                    .cloned()
+                    .collect();
+                let report = PathDependencies::new(&workspace.root).verify(&published).await?;
+                if !report.is_consistent() {
+                    config.error_println(&report.format_report());
+                }
+                let report = report.into_result()?;
+                config.verbose_println(&report.format_report());
+
+                // Nothing is committed in an isolated release until the bumped workspace builds
+                if let Some(worktree) = worktree {
+                    config.println("🔨 Checking that the release builds...");
+                    check_worktree_build(&workspace, worktree).await?;
+                    config.success_println("Release builds in the isolated worktree");
+                }
 
-        let git_result = git_manager.perform_release(&new_version, !no_push).await?;
-        
-        // Set phase and state together to maintain consistency
-        release_state.set_phase(ReleasePhase::GitOperations);
-        release_state.set_git_state(Some(&git_result.commit), Some(&git_result.tag));
+                Ok::<_, ReleaseError>(())
+            }.instrument(info_span!("phase", phase = "version-update")).await?;
+
+            // Resolve the SBOM before committing, so a failure leaves nothing pushed
+            let mut sbom = match sbom_format {
+                Some(_) => {
+                    config.verbose_println("Resolving dependency tree for the SBOM...");
+                    Some(Sbom::generate(&workspace.root, &released_packages).await?)
+                }
+                None => None,
+            };
+
+            // Phase 2: Git Operations
+            async {
+                config.println("📦 Creating git commit and tag...");
+
+                // Persist the pre-release HEAD first, so rollback can reset to it even if committing fails
+                let previous_head = git_manager.capture_previous_head().await?;
+                release_state.set_previous_head(&previous_head);
+                state_manager.save_state(&release_state)?;
+
+                // Persist the commit and every tag as soon as it exists, so a later failure can still roll it back
+                release_state.set_phase(ReleasePhase::GitOperations);
+                let mut record = |commit: &CommitInfo, tags: &[TagInfo]| {
+                    release_state.set_git_state(Some(commit), tags);
+                    state_manager.save_state(&release_state).map(|_| ())
+                };
+                let git_result = if independent {
+                    git_manager
+                        .perform_package_releases_recorded(&template_context, &package_tags, !no_push, &mut record)
+                        .await?
+                } else {
+                    git_manager.perform_release_recorded(&template_context, !no_push, &mut record).await?
+                };
+
+                release_state.set_git_state(Some(&git_result.commit), &git_result.tags);
         
-        if let Some(push_info) = &git_result.push_info {
-            release_state.set_git_push_state(push_info);
-        }
+                if let Some(push_info) = &git_result.push_info {
+                    release_state.set_git_push_state(push_info);
+                }
 
-        release_state.add_checkpoint(
-            "git_operations_complete".to_string(),
-            ReleasePhase::GitOperations,
-            None,
-            true,
-        );
-        state_manager.save_state(&release_state)?;
+                if let Some(sbom) = &mut sbom {
+                    let tags: Vec<String> = git_result.tags.iter().map(|tag| tag.name.clone()).collect();
+                    sbom.set_git(&git_result.commit.hash, &tags);
+                }
 
-        config.success_println(&format!("Git operations completed: {}", git_result.format_result()));
+                release_state.add_checkpoint(
+                    "git_operations_complete".to_string(),
+                    ReleasePhase::GitOperations,
+                    None,
+                    true,
+                );
+                state_manager.save_state(&release_state)?;
 
-        // Phase 3: Publishing
-        config.println("📤 Publishing packages...");
-        release_state.set_phase(ReleasePhase::Publishing);
-        
-        let publish_order = crate::workspace::DependencyGraph::build(&workspace)?.publish_order()?;
-        release_state.init_publish_state(publish_order.tier_count());
-        state_manager.save_state(&release_state)?;
+                config.success_println(&format!("Git operations completed: {}", git_result.format_result()));
 
-        let publish_result = publisher.publish_all_packages().await?;
+                Ok::<_, ReleaseError>(())
+            }.instrument(info_span!("phase", phase = "git")).await?;
+
+            // Phase 3: Publishing
+            let publish_result = async {
+                config.println("📤 Publishing packages...");
+                release_state.set_phase(ReleasePhase::Publishing);
         
-        // Update state with publish results
-        for (_package_name, package_result) in &publish_result.successful_publishes {
-            release_state.add_published_package(package_result);
-        }
+                let publish_result = match package {
+                    Some(package_name) => {
+                        release_state.init_publish_state(1);
+                        state_manager.save_state(&release_state)?;
+
+                        // Reload so the publisher sees the bumped package version
+                        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+                        publisher = Publisher::with_config(&workspace, publisher.config().clone())?;
+                        publisher.publish_package_only(package_name).await?
+                    }
+                    None => {
+                        let publish_order = crate::workspace::DependencyGraph::build(&workspace)?.publish_order()?;
+                        release_state.init_publish_state(publish_order.tier_count());
+                        state_manager.save_state(&release_state)?;
+
+                        // Reload so the publisher sees each package's independently bumped version
+                        if independent {
+                            let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+                            publisher = Publisher::with_config(&workspace, publisher.config().clone())?;
+                        }
+                        publisher.publish_all_packages().await?
+                    }
+                };
         
-        for (package_name, error) in &publish_result.failed_packages {
-            release_state.add_failed_package(package_name.clone(), error.clone());
-        }
+                // Update state with publish results
+                for (_package_name, package_result) in &publish_result.successful_publishes {
+                    release_state.add_published_package(package_result);
+                }
+        
+                for (package_name, error) in &publish_result.failed_packages {
+                    release_state.add_failed_package(package_name.clone(), error.clone());
+                }
 
-        release_state.add_checkpoint(
-            "publishing_complete".to_string(),
-            ReleasePhase::Publishing,
-            None,
-            true,
-        );
-        state_manager.save_state(&release_state)?;
+                release_state.add_checkpoint(
+                    "publishing_complete".to_string(),
+                    ReleasePhase::Publishing,
+                    None,
+                    true,
+                );
+                state_manager.save_state(&release_state)?;
+
+                if publish_result.all_successful {
+                    config.success_println(&format!("Publishing completed: {}", publish_result.format_summary()));
+                } else {
+                    config.warning_println(&format!("Publishing partially failed: {}", publish_result.format_summary()));
+                }
 
-        if publish_result.all_successful {
-            config.success_println(&format!("Publishing completed: {}", publish_result.format_summary()));
-        } else {
-            config.warning_println(&format!("Publishing partially failed: {}", publish_result.format_summary()));
-        }
+                Ok::<_, ReleaseError>(publish_result)
+            }.instrument(info_span!("phase", phase = "publish")).await?;
 
-        // Phase 4: Cleanup
-        config.println("🧹 Cleaning up...");
-        release_state.set_phase(ReleasePhase::Cleanup);
-        state_manager.save_state(&release_state)?;
+            // Phase 4: SBOM (reported, never fatal)
+            if let (Some(sbom), Some(format), Some(sbom_path)) = (&sbom, sbom_format, &sbom_path) {
+                let attach_tags = (*sbom_attach).then_some(release_tags.as_slice());
+                write_sbom(sbom, *format, sbom_path, attach_tags, &project_config.git.remote, *no_push, config)
+                    .instrument(info_span!("phase", phase = "sbom"))
+                    .await;
+            }
 
-        // Clear git manager state
-        git_manager.clear_release_state();
+            // Phase 5: Documentation build verification (reported, never fatal)
+            let docs_verification = if *verify_docs || project_config.docs_rs.verify {
+                verify_docs_builds(&publish_result, &project_config, registry.as_deref(), config)
+                    .instrument(info_span!("phase", phase = "docs-verification"))
+                    .await
+            } else {
+                None
+            };
 
-        // Clear publisher state
-        publisher.clear_state();
+            // Phase 6: Cleanup
+            async {
+                config.println("🧹 Cleaning up...");
+                release_state.set_phase(ReleasePhase::Cleanup);
+                state_manager.save_state(&release_state)?;
+
+                // Clear git manager state
+                git_manager.clear_release_state();
+
+                // Clear publisher state
+                publisher.clear_state();
+
+                // Mark as completed
+                release_state.set_phase(ReleasePhase::Completed);
+                release_state.add_checkpoint(
+                    "release_completed".to_string(),
+                    ReleasePhase::Completed,
+                    None,
+                    false,
+                );
+                state_manager.save_state(&release_state)?;
+
+                Ok::<_, ReleaseError>(())
+            }.instrument(info_span!("phase", phase = "cleanup")).await?;
+
+            Ok(docs_verification)
+        }.instrument(release_span).await;
+
+        if let Err(e) = &outcome {
+            let error = e.to_string();
+            notify_event(&project_config, NotifyEvent::Failed, &release_state, Some(&error), config).await;
+            export_metrics(&project_config, &release_state, ReleaseOutcome::Failed, Some(e), config).await;
+        }
+        let docs_verification = outcome?;
 
-        // Mark as completed
-        release_state.set_phase(ReleasePhase::Completed);
-        release_state.add_checkpoint(
-            "release_completed".to_string(),
-            ReleasePhase::Completed,
-            None,
-            false,
-        );
-        state_manager.save_state(&release_state)?;
+        notify_event(&project_config, NotifyEvent::Succeeded, &release_state, None, config).await;
+        export_metrics(&project_config, &release_state, ReleaseOutcome::Succeeded, None, config).await;
 
         config.success_println(&format!("🎉 Release {} completed successfully!", new_version));
-        
-        // Cleanup state file after successful completion
-        if !no_backup {
-            state_manager.create_backup()?;
+
+        // Surface docs.rs breakage in the summary and notifications rather than only in the log
+        if let Some(verification) = docs_verification.filter(|verification| !verification.all_succeeded()) {
+            config.warning_println(&format!("📚 docs.rs: {}", verification.format_summary()));
+            let problems: Vec<String> = verification.failed().into_iter()
+                .chain(verification.pending())
+                .map(|report| report.format_result())
+                .collect();
+            for problem in &problems {
+                config.warning_println(&format!("  • {}", problem));
+            }
+
+            let error = problems.join("; ");
+            notify_event(&project_config, NotifyEvent::DocsFailed, &release_state, Some(&error), config).await;
         }
+        
+        // The release is over, so its state and backups are no longer needed for resume or rollback
         state_manager.cleanup_state()?;
 
     } else {
-        unreachable!("execute_release called with non-Release command");
+        unreachable!("release_workspace called with non-Release command");
     }
 
     Ok(())
 }
 
-/// Execute rollback command
-async fn execute_rollback(args: &Args, config: &RuntimeConfig) -> Result<()> {
-    if let Command::Rollback { force, git_only, packages_only, yes } = &args.command {
-        config.verbose_println("Starting rollback operation...");
+/// Execute meta-release command.
+///
+/// Releases the workspaces of a meta manifest one after another, upstream
+/// first. Before a workspace is released, its requirements on packages
+/// released earlier in the run are raised and committed. Progress is kept in
+/// a shared meta state next to the manifest, so re-running the command after
+/// a failure continues with the workspace that failed.
+async fn execute_meta_release(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::MetaRelease {
+        bump_type,
+        manifest,
+        dry_run,
+        skip_validation,
+        no_push,
+        registry,
+        package_delay,
+    } = &args.command {
+        let manifest_path = manifest.clone()
+            .unwrap_or_else(|| config.workspace_path.join(DEFAULT_META_MANIFEST));
+        let manifest = MetaManifest::load(&manifest_path)?;
+        let meta_repo = MetaRepo::analyze(&manifest)?;
 
-        // Load release state
-        let mut state_manager = create_state_manager_at(&config.state_file_path)?;
-        let load_result = state_manager.load_state()?;
-        let mut release_state = load_result.state;
+        let version_bump = match bump_type {
+            BumpType::Exact => {
+                return Err(ReleaseError::Cli(CliError::InvalidArguments {
+                    reason: "Exact version bump is not supported for meta releases".to_string(),
+                }));
+            }
+            _ => VersionBump::from(bump_type.clone()),
+        };
 
-        if load_result.recovered_from_backup {
-            config.warning_println("Loaded state from backup file");
-        }
+        config.println(&format!("🗂️  Release order for {} workspaces:", meta_repo.workspaces.len()));
+        config.println(meta_repo.format_plan().trim_end());
+
+        let state_path = MetaReleaseState::path_for_manifest(&manifest_path);
+        let mut meta_state = match MetaReleaseState::load(&state_path)? {
+            Some(meta_state) => {
+                let planned: Vec<&str> = meta_repo.workspaces.iter().map(|workspace| workspace.name.as_str()).collect();
+                let recorded: Vec<&str> = meta_state.workspaces.iter().map(|workspace| workspace.name.as_str()).collect();
+                if meta_state.version_bump != version_bump || planned != recorded {
+                    return Err(ReleaseError::State(crate::error::StateError::SaveFailed {
+                        reason: format!(
+                            "A {} meta release of {} is in progress. Re-run it with the same bump, or remove {} to start over",
+                            meta_state.version_bump,
+                            recorded.join(", "),
+                            state_path.display()
+                        ),
+                    }));
+                }
+                config.println(&format!("⏯️  Continuing meta release {}:", meta_state.meta_release_id));
+                config.println(meta_state.format_status().trim_end());
+                meta_state
+            }
+            None => MetaReleaseState::new(
+                version_bump.clone(),
+                meta_repo.workspaces.iter()
+                    .map(|workspace| (workspace.name.clone(), workspace.info.root.clone()))
+                    .collect(),
+            ),
+        };
 
-        // Validate rollback conditions
-        if release_state.current_phase == ReleasePhase::Completed && !force {
-            return Err(ReleaseError::State(crate::error::StateError::SaveFailed {
-                reason: "Release completed successfully. Use --force to rollback anyway".to_string(),
-            }));
-        }
+        let release_command = Command::Release {
+            bump_type: bump_type.clone(),
+            dry_run: *dry_run,
+            skip_validation: *skip_validation,
+            allow_dirty: false,
+            allow_untracked: false,
+            no_push: *no_push,
+            registry: registry.clone(),
+            package_delay: *package_delay,
+            max_retries: 3,
+            timeout: 300,
+            no_backup: false,
+            max_concurrent: 1,
+            package: None,
+            verify_docs: false,
+            first_release: false,
+            check_docs: false,
+            isolated: false,
+            stash: false,
+            no_verify: false,
+            schedule: false,
+            override_freeze: None,
+            sbom: None,
+            sbom_attach: false,
+            strip_patches: false,
+        };
 
-        if !yes {
-            config.println(&format!(
-                "About to rollback release {} (phase: {:?})",
-                release_state.target_version,
-                release_state.current_phase
-            ));
-            config.println("WARNING: Rollback will:");
-            config.println("  - Delete local and remote release tags");
-            config.println("  - Reset git HEAD to previous commit");
-            config.println("  - This operation cannot be undone");
-            
-            if !prompt_confirmation("Proceed with rollback?")? {
-                config.println("Rollback cancelled");
-                return Ok(());
-            }
+        if !dry_run {
+            meta_state.save(&state_path)?;
         }
 
-        release_state.set_phase(ReleasePhase::RollingBack);
-        state_manager.save_state(&release_state)?;
+        for workspace in &meta_repo.workspaces {
+            if meta_state.is_released(&workspace.name) {
+                config.verbose_println(&format!("Skipping {}: already released", workspace.name));
+                continue;
+            }
 
-        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+            config.println(&format!("\n📦 Workspace {} ({})", workspace.name, workspace.info.root.display()));
+            let released = meta_state.released_versions();
+            let result = release_meta_workspace(args, config, workspace, &released, &release_command, &version_bump).await;
 
-        // Rollback publishing if needed and not git-only
-        if !git_only && release_state.publish_state.is_some() {
-            config.println("📤 Rolling back published packages...");
-            let publisher = Publisher::new(&workspace)?;
-            let rollback_result = publisher.rollback_published_packages().await?;
-            
-            if rollback_result.fully_successful {
-                config.success_println("All published packages yanked successfully");
-            } else {
-                config.warning_println(&format!("Rollback completed with warnings: {}", rollback_result.format_summary()));
+            match result {
+                Ok(versions) => {
+                    meta_state.mark_released(&workspace.name, versions);
+                    if !dry_run {
+                        meta_state.save(&state_path)?;
+                    }
+                }
+                Err(e) if *dry_run => return Err(e),
+                Err(e) => {
+                    meta_state.mark_failed(&workspace.name, e.to_string());
+                    meta_state.save(&state_path)?;
+                    config.warning_println(&format!(
+                        "Meta release stopped at {}; recover that workspace, then re-run 'meta-release' to continue",
+                        workspace.name
+                    ));
+                    return Err(e);
+                }
             }
         }
 
-        // Rollback git operations if needed and not packages-only
-        if !packages_only && release_state.git_state.is_some() {
-            config.println("📦 Rolling back git operations...");
-            let git_config = GitConfig::default();
-            let mut git_manager = GitManager::with_config(&config.workspace_path, git_config)?;
-            
+        if *dry_run {
+            config.success_println("Dry run completed successfully");
+            return Ok(());
+        }
+
+        config.success_println(&format!("🎉 Released {} workspaces", meta_repo.workspaces.len()));
+        config.println(meta_state.format_status().trim_end());
+        std::fs::remove_file(&state_path).map_err(|e| crate::error::StateError::SaveFailed {
+            reason: format!("Failed to remove {}: {}", state_path.display(), e),
+        })?;
+    } else {
+        unreachable!("execute_meta_release called with non-MetaRelease command");
+    }
+
+    Ok(())
+}
+
+/// Release one workspace of a meta release, returning the versions of its packages afterwards.
+///
+/// A dry run returns each package's current version bumped, as a preview for downstream workspaces.
+async fn release_meta_workspace(
+    args: &Args,
+    config: &RuntimeConfig,
+    workspace: &MetaWorkspace,
+    released: &BTreeMap<String, semver::Version>,
+    release_command: &Command,
+    version_bump: &VersionBump,
+) -> Result<BTreeMap<String, semver::Version>> {
+    let root = &workspace.info.root;
+    let dry_run = matches!(release_command, Command::Release { dry_run: true, .. });
+
+    // Requirements on upstream packages move to the versions just published
+    if !released.is_empty() {
+        let consumed: Vec<String> = released.iter()
+            .filter(|(package, _)| workspace.info.packages.values()
+                .any(|member| member.all_dependencies.keys()
+                    .any(|key| key.rsplit(':').next() == Some(package.as_str()))))
+            .map(|(package, version)| format!("{} {}", package, version))
+            .collect();
+
+        if dry_run {
+            if !consumed.is_empty() {
+                config.println(&format!("Would update requirements: {}", consumed.join(", ")));
+            }
+        } else {
+            let repository = GitRepository::open(root)?;
+            if !repository.is_working_directory_clean().await? {
+                return Err(GitError::DirtyWorkingDirectory.into());
+            }
+
+            let modified = update_upstream_requirements(&workspace.info, released)?;
+            if !modified.is_empty() {
+                let commit = repository
+                    .commit_all(&format!("chore: update dependencies to {}", consumed.join(", ")))
+                    .await?;
+                config.println(&format!(
+                    "🔗 Updated requirements in {} manifests ({})",
+                    modified.len(),
+                    commit.short_hash
+                ));
+            }
+        }
+    }
+
+    let release_args = Args {
+        command: release_command.clone(),
+        workspace: Some(root.clone()),
+        config: None,
+        ..args.clone()
+    };
+    let state_file_name = args.state_file_path().file_name()
+        .map_or_else(|| PathBuf::from(".cyrup_release_state.json"), PathBuf::from);
+    let release_config = RuntimeConfig {
+        workspace_path: root.clone(),
+        state_file_path: root.join(state_file_name),
+        ..RuntimeConfig::from(&release_args)
+    };
+    release_workspace(&release_args, &release_config, None).await?;
+
+    let mut versions = BTreeMap::new();
+    for package in WorkspaceInfo::analyze(root)?.packages.values() {
+        let Ok(version) = semver::Version::parse(&package.version) else {
+            continue;
+        };
+        let version = if dry_run {
+            crate::version::VersionBumper::from_version(version).bump(version_bump.clone())?
+        } else {
+            version
+        };
+        versions.insert(package.name.clone(), version);
+    }
+    Ok(versions)
+}
+
+/// Execute hotfix command.
+///
+/// Checks out the maintenance branch for the base release (creating it at
+/// the base tag if needed), then runs a patch release from it. The main-line
+/// branch and its manifest versions are never touched.
+async fn execute_hotfix(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::Hotfix { base, branch, dry_run, skip_validation, no_push, registry, package_delay } = &args.command {
+        let repository = GitRepository::open(&config.workspace_path)?;
+        if !repository.tag_exists(base).await? {
+            return Err(GitError::OperationFailed {
+                operation: "hotfix".to_string(),
+                reason: format!("Base tag '{}' does not exist", base),
+            }.into());
+        }
+        let base_version = parse_tag_version(base)?;
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let project_config = load_config(args, config, &workspace)?;
+        let maintenance_branch = match branch {
+            Some(branch) => branch.clone(),
+            None => project_config.git.maintenance_branch_name(&base_version)?,
+        };
+
+        // Switch to the maintenance branch, creating it at the base tag on first use
+        let original_branch = repository.get_current_branch().await?.name;
+        let switched = original_branch != maintenance_branch;
+        if switched {
+            if !repository.is_working_directory_clean().await? {
+                return Err(GitError::DirtyWorkingDirectory.into());
+            }
+
+            if repository.branch_exists(&maintenance_branch).await? {
+                config.println(&format!("🔀 Checking out maintenance branch {}...", maintenance_branch));
+                repository.checkout_branch(&maintenance_branch, None).await?;
+            } else {
+                config.println(&format!("🌱 Creating maintenance branch {} at {}...", maintenance_branch, base));
+                repository.checkout_branch(&maintenance_branch, Some(base)).await?;
+            }
+        }
+
+        let result = async {
+            // The branch must continue the base release's lineage
+            if !repository.is_ancestor(base, "HEAD").await? {
+                return Err(GitError::BranchOperationFailed {
+                    reason: format!("Branch '{}' does not contain base release {}", maintenance_branch, base),
+                }.into());
+            }
+
+            let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+            let project_config = load_config(args, config, &workspace)?;
+            if project_config.release.versioning == VersioningMode::Unified {
+                let current_version = VersionManager::new(workspace).current_version()?;
+                if (current_version.major, current_version.minor) != (base_version.major, base_version.minor)
+                    || current_version < base_version
+                {
+                    return Err(GitError::BranchOperationFailed {
+                        reason: format!(
+                            "Branch '{}' is at {}, which is not on the {}.{} line of {}",
+                            maintenance_branch, current_version, base_version.major, base_version.minor, base
+                        ),
+                    }.into());
+                }
+            }
+
+            config.println(&format!("🩹 Releasing hotfix from {} (base {})", maintenance_branch, base));
+            let release_args = Args {
+                command: Command::Release {
+                    bump_type: BumpType::Patch,
+                    dry_run: *dry_run,
+                    skip_validation: *skip_validation,
+                    allow_dirty: false,
+                    allow_untracked: false,
+                    no_push: *no_push,
+                    registry: registry.clone(),
+                    package_delay: *package_delay,
+                    max_retries: 3,
+                    timeout: 300,
+                    no_backup: false,
+                    max_concurrent: 1,
+                    package: None,
+                    verify_docs: false,
+                    first_release: false,
+                    check_docs: false,
+                    isolated: false,
+                    stash: false,
+                    no_verify: false,
+                    schedule: false,
+                    override_freeze: None,
+                    sbom: None,
+                    sbom_attach: false,
+                    strip_patches: false,
+                },
+                ..args.clone()
+            };
+            release_workspace(&release_args, config, None).await
+        }.await;
+
+        // Return to the original branch unless a failed release needs the maintenance branch for recovery
+        if switched && (result.is_ok() || *dry_run) {
+            repository.checkout_branch(&original_branch, None).await?;
+            config.verbose_println(&format!("Switched back to {}", original_branch));
+        } else if switched {
+            config.warning_println(&format!(
+                "Staying on {} so the failed hotfix can be resumed or rolled back",
+                maintenance_branch
+            ));
+        }
+
+        result
+    } else {
+        unreachable!("execute_hotfix called with non-Hotfix command");
+    }
+}
+
+/// Parse the version from a release tag such as `v1.2.3` or `pkg-v1.2.3`
+fn parse_tag_version(tag: &str) -> Result<semver::Version> {
+    let version = tag.char_indices()
+        .find(|&(index, c)| c == 'v' && tag[index + 1..].starts_with(|c: char| c.is_ascii_digit()))
+        .map_or(tag, |(index, _)| &tag[index + 1..]);
+    semver::Version::parse(version).map_err(|e| crate::error::VersionError::ParseFailed {
+        version: tag.to_string(),
+        source: e,
+    }.into())
+}
+
+/// Execute rollback command
+async fn execute_rollback(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::Rollback { force, git_only, packages_only, .. } = &args.command {
+        config.verbose_println("Starting rollback operation...");
+
+        // Load release state
+        let mut state_manager = open_state_manager(args, config, StateConfig::default())?;
+        let load_result = state_manager.load_state()?;
+        let mut release_state = load_result.state;
+
+        if let Some(backup_path) = &load_result.backup_used {
+            config.warning_println(&format!("Loaded state from backup {}", backup_path.display()));
+        }
+
+        // Validate rollback conditions
+        if release_state.current_phase == ReleasePhase::Completed && !force {
+            return Err(ReleaseError::State(crate::error::StateError::SaveFailed {
+                reason: "Release completed successfully. Use --force to rollback anyway".to_string(),
+            }));
+        }
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let project_config = load_config(args, config, &workspace)?;
+
+        config.println(&format!(
+            "About to rollback release {} (phase: {:?})",
+            release_state.target_version,
+            release_state.current_phase
+        ));
+        config.println("WARNING: Rollback will:");
+        config.println("  - Delete local and remote release tags");
+        let git_state = release_state.git_state.as_ref();
+        let release_commit = git_state.and_then(|git_state| git_state.release_commit.as_ref());
+        match (release_commit, git_state.and_then(|git_state| git_state.previous_head.as_deref())) {
+            (Some(release_commit), _) if project_config.git.revert_on_rollback => config.println(&format!(
+                "  - Revert release commit {} with a new commit",
+                release_commit.short_hash
+            )),
+            (_, Some(previous_head)) => config.println(&format!(
+                "  - Reset git HEAD to {} (only if nothing was committed after the release)",
+                previous_head.get(..7).unwrap_or(previous_head)
+            )),
+            (_, None) => config.println("  - Leave git HEAD as is (no pre-release commit recorded)"),
+        }
+        config.println("  - This operation cannot be undone");
+
+        if !config.confirm("Proceed with rollback?")? {
+            config.println("Rollback cancelled");
+            return Ok(());
+        }
+
+        release_state.set_phase(ReleasePhase::RollingBack);
+        state_manager.save_state(&release_state)?;
+
+        // Rollback publishing if needed and not git-only
+        if !git_only && release_state.publish_state.is_some() {
+            config.println("📤 Rolling back published packages...");
+            let publisher = Publisher::new(&workspace)?;
+            let rollback_result = publisher.rollback_published_packages().await?;
+            
+            if rollback_result.fully_successful {
+                config.success_println("All published packages yanked successfully");
+            } else {
+                config.warning_println(&format!("Rollback completed with warnings: {}", rollback_result.format_summary()));
+            }
+        }
+
+        // Rollback git operations if needed and not packages-only
+        if !packages_only && release_state.git_state.is_some() {
+            config.println("📦 Rolling back git operations...");
+            let mut git_manager = GitManager::with_config(&config.workspace_path, project_config.git.git_config())?;
+
+            // Rebuild the tags created by this release from state. Only unified
+            // releases can fall back to the configured template, since per-package
+            // tags depend on versions that were never recorded.
+            if let Some(git_state) = &release_state.git_state {
+                let independent = release_state.config.additional_options
+                    .get("versioning")
+                    .and_then(|value| value.as_str()) == Some("independent");
+
+                let tags: Vec<TagInfo> = if !git_state.release_tags.is_empty() {
+                    git_state.release_tags.iter()
+                        .map(|tag| TagInfo {
+                            name: tag.name.clone(),
+                            message: tag.message.clone(),
+                            target_commit: tag.target_commit.clone(),
+                            timestamp: tag.timestamp,
+                            is_annotated: tag.is_annotated,
+                        })
+                        .collect()
+                } else if independent || git_state.release_commit.is_none() {
+                    config.warning_println("No release tags recorded in state; skipping tag deletion");
+                    Vec::new()
+                } else {
+                    let mut context = TemplateContext::new(&release_state.target_version);
+                    if let Some(package_name) = release_state.config.additional_options
+                        .get("package")
+                        .and_then(|value| value.as_str())
+                    {
+                        context = context.with_package(package_name);
+                    }
+                    git_manager.tag_name(&context).ok()
+                        .map(|name| TagInfo {
+                            name,
+                            message: None,
+                            target_commit: String::new(),
+                            timestamp: chrono::Utc::now(),
+                            is_annotated: true,
+                        })
+                        .into_iter()
+                        .collect()
+                };
+
+                git_manager.restore_release_tags(tags, git_state.pushed_to_remote);
+
+                let release_commit = git_state.release_commit.as_ref().map(|commit| CommitInfo {
+                    hash: commit.hash.clone(),
+                    short_hash: commit.short_hash.clone(),
+                    message: commit.message.clone(),
+                    author_name: commit.author_name.clone(),
+                    author_email: commit.author_email.clone(),
+                    timestamp: commit.timestamp,
+                    parents: Vec::new(),
+                });
+                git_manager.restore_release_commit(git_state.previous_head.clone(), release_commit);
+            }
+            
             let git_rollback = git_manager.rollback_release().await?;
             
             if git_rollback.success {
@@ -441,6 +1417,9 @@ async fn execute_rollback(args: &Args, config: &RuntimeConfig) -> Result<()> {
         );
         state_manager.save_state(&release_state)?;
 
+        notify_event(&project_config, NotifyEvent::RolledBack, &release_state, None, config).await;
+        export_metrics(&project_config, &release_state, ReleaseOutcome::RolledBack, None, config).await;
+
         config.success_println("🔄 Rollback completed");
 
     } else {
@@ -456,7 +1435,7 @@ async fn execute_resume(args: &Args, config: &RuntimeConfig) -> Result<()> {
         config.verbose_println("Resuming release operation...");
 
         // Load release state
-        let mut state_manager = create_state_manager_at(&config.state_file_path)?;
+        let mut state_manager = open_state_manager(args, config, StateConfig::default())?;
         let load_result = state_manager.load_state()?;
         let mut release_state = load_result.state;
 
@@ -533,10 +1512,14 @@ async fn execute_resume(args: &Args, config: &RuntimeConfig) -> Result<()> {
 
 /// Execute status command
 async fn execute_status(args: &Args, config: &RuntimeConfig) -> Result<()> {
-    if let Command::Status { detailed, history: _, json } = &args.command {
+    if let Command::Status { detailed, history, json } = &args.command {
         config.verbose_println("Checking release status...");
 
-        if !has_active_release_at(&config.state_file_path) {
+        let mut state_manager = open_state_manager(args, config, StateConfig::default())?;
+
+        // The history can come from the newest backup once the state file is gone
+        let active = state_manager.state_exists();
+        if !active && !(*history && !state_manager.backup_paths().is_empty()) {
             if *json {
                 println!("{{\"status\": \"no_active_release\"}}");
             } else {
@@ -546,11 +1529,23 @@ async fn execute_status(args: &Args, config: &RuntimeConfig) -> Result<()> {
         }
 
         // Load release state
-        let mut state_manager = create_state_manager_at(&config.state_file_path)?;
         let load_result = state_manager.load_state()?;
         let release_state = load_result.state;
 
-        if *json {
+        if *history {
+            let timeline = ReleaseTimeline::from_state(&release_state);
+            if *json {
+                let json_output = serde_json::to_string_pretty(&timeline)
+                    .map_err(ReleaseError::Json)?;
+                println!("{}", json_output);
+            } else {
+                if let Some(backup) = load_result.backup_used.as_ref().filter(|_| !active) {
+                    config.println(&format!("No active release; showing most recent state from {}", backup.display()));
+                }
+                config.println(&format!("📊 {}", release_state.summary()));
+                config.println(timeline.format_timeline().trim_end());
+            }
+        } else if *json {
             let json_output = serde_json::to_string_pretty(&release_state)
                 .map_err(|e| ReleaseError::Json(e))?;
             println!("{}", json_output);
@@ -589,26 +1584,53 @@ async fn execute_status(args: &Args, config: &RuntimeConfig) -> Result<()> {
 
 /// Execute cleanup command
 async fn execute_cleanup(args: &Args, config: &RuntimeConfig) -> Result<()> {
-    if let Command::Cleanup { all, older_than, yes } = &args.command {
+    if let Command::Cleanup { all, older_than, .. } = &args.command {
         config.verbose_println("Cleaning up state files...");
 
-        if !yes {
-            config.println("About to clean up release state files");
-            
-            if !prompt_confirmation("Proceed with cleanup?")? {
-                config.println("Cleanup cancelled");
-                return Ok(());
-            }
+        match older_than {
+            Some(max_age) => config.println(&format!(
+                "About to clean up release state files older than {}",
+                format_age(*max_age)
+            )),
+            None => config.println("About to clean up release state files"),
+        }
+        if !config.confirm("Proceed with cleanup?")? {
+            config.println("Cleanup cancelled");
+            return Ok(());
         }
 
-        let state_manager = create_state_manager_at(&config.state_file_path)?;
+        let state_manager = open_state_manager(args, config, StateConfig::default())?;
         
-        if *all || older_than.is_some() {
+        if let Some(max_age) = older_than {
+            let result = state_manager.cleanup_older_than(*max_age)?;
+            for removed in &result.removed {
+                config.println(&format!(
+                    "  • Removed {} ({} old)",
+                    removed.path.display(),
+                    format_age(removed.age)
+                ));
+            }
+            for kept in &result.kept {
+                config.verbose_println(&format!("  Kept {}", kept.display()));
+            }
+
+            if let Some(reason) = &result.in_use {
+                config.warning_println(&format!("Kept all state files: {}", reason));
+            } else if result.removed.is_empty() {
+                config.println(&format!("No state files older than {}", format_age(*max_age)));
+            } else {
+                config.success_println(&format!(
+                    "Removed {} state file(s), kept {}",
+                    result.removed.len(),
+                    result.kept.len()
+                ));
+            }
+        } else if *all {
             state_manager.cleanup_state()?;
             config.success_println("State files cleaned up");
         } else {
             // Just clean up current state
-            if has_active_release_at(&config.state_file_path) {
+            if state_manager.state_exists() {
                 state_manager.cleanup_state()?;
                 config.success_println("Current state file cleaned up");
             } else {
@@ -625,11 +1647,36 @@ async fn execute_cleanup(args: &Args, config: &RuntimeConfig) -> Result<()> {
 
 /// Execute validate command
 async fn execute_validate(args: &Args, config: &RuntimeConfig) -> Result<()> {
-    if let Command::Validate { fix: _, detailed, json } = &args.command {
+    if let Command::Validate { fix, check_docs, detailed, json, allow_untracked } = &args.command {
         config.verbose_println("Validating workspace...");
 
-        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
-        let validator = WorkspaceValidator::new(workspace)?;
+        let mut workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let project_config = load_config(args, config, &workspace)?;
+
+        if *fix {
+            let fixes = apply_validation_fixes(workspace, &project_config)?;
+            if !*json {
+                for fix in &fixes {
+                    config.success_println(&format!("Fixed {}", fix));
+                }
+                if fixes.is_empty() {
+                    config.println("Nothing to fix automatically");
+                } else {
+                    config.println("🔧 Review and commit the fixed manifests before releasing");
+                }
+            }
+            workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        }
+        let validator_config = ValidatorConfig {
+            git: project_config.git,
+            package_contents: project_config.package_contents,
+            owners: project_config.owners,
+            validation: with_doc_checks(&project_config.validation, *check_docs),
+            advisories: project_config.advisories.clone(),
+            allow_untracked: *allow_untracked,
+            ..Default::default()
+        };
+        let validator = WorkspaceValidator::with_config(workspace, validator_config)?;
         let validation = validator.validate().await?;
 
         if *json {
@@ -720,6 +1767,877 @@ async fn execute_preview(args: &Args, config: &RuntimeConfig) -> Result<()> {
     Ok(())
 }
 
+/// Execute migrate-config command
+async fn execute_migrate_config(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::MigrateConfig { dry_run } = &args.command {
+        config.verbose_println("Migrating configuration...");
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let config_path = project_config_path(&workspace.root, args.config.as_deref());
+
+        if !config_path.exists() {
+            config.println(&format!("No configuration file at {}", config_path.display()));
+            return Ok(());
+        }
+
+        let report = migrate_config_file(&config_path, *dry_run)?;
+
+        if report.migrations.is_empty() {
+            config.println(&format!("✨ {} uses no deprecated keys", report.path.display()));
+            return Ok(());
+        }
+
+        for migration in &report.migrations {
+            config.println(&format!("  {} → {}", migration.old_key, migration.new_key));
+        }
+
+        if report.written {
+            config.success_println(&format!(
+                "Migrated {} key(s) in {}",
+                report.migrations.len(),
+                report.path.display()
+            ));
+        } else {
+            config.println(&format!(
+                "🔍 Dry run: {} key(s) would be migrated in {}",
+                report.migrations.len(),
+                report.path.display()
+            ));
+        }
+
+    } else {
+        unreachable!("execute_migrate_config called with non-MigrateConfig command");
+    }
+
+    Ok(())
+}
+
+/// Execute migrate-inheritance command
+async fn execute_migrate_inheritance(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::MigrateInheritance { dry_run, .. } = &args.command {
+        config.verbose_println("Planning workspace inheritance migration...");
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let migration = InheritanceMigration::plan(&workspace)?;
+
+        for skipped in &migration.skipped {
+            config.warning_println(&format!("Keeping explicit version for '{}': {}", skipped.package, skipped.reason));
+        }
+
+        if migration.is_empty() {
+            config.println("✨ Members already inherit everything they share with the workspace");
+            return Ok(());
+        }
+
+        config.println(migration.format_diff().trim_end());
+        for change in &migration.changes {
+            config.verbose_println(&format!("  {}", change.format_change()));
+        }
+
+        if *dry_run {
+            config.println(&format!(
+                "🔍 Dry run: {} manifest(s) would be migrated to workspace version {}",
+                migration.changes.len(),
+                migration.workspace_version
+            ));
+            return Ok(());
+        }
+
+        if !config.confirm(&format!("Rewrite {} manifest(s)?", migration.changes.len()))? {
+            config.println("Migration cancelled");
+            return Ok(());
+        }
+
+        migration.apply()?;
+        config.success_println(&format!(
+            "Migrated {} manifest(s) to workspace inheritance",
+            migration.changes.len()
+        ));
+
+    } else {
+        unreachable!("execute_migrate_inheritance called with non-MigrateInheritance command");
+    }
+
+    Ok(())
+}
+
+/// Execute yank command
+async fn execute_yank(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::Yank { version, packages, undo, registry, .. } = &args.command {
+        let action = if *undo { "un-yank" } else { "yank" };
+        config.verbose_println(&format!("Resolving packages to {}...", action));
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        for package_name in packages {
+            workspace.get_package(package_name)?;
+        }
+
+        // Yank dependents before their dependencies, mirroring rollback
+        let publish_order = crate::workspace::DependencyGraph::build(&workspace)?.publish_order()?;
+        let mut targets: Vec<String> = publish_order.ordered_packages()
+            .filter(|name| packages.is_empty() || packages.iter().any(|p| p == *name))
+            .cloned()
+            .collect();
+        if !*undo {
+            targets.reverse();
+        }
+
+        config.println(&format!("🔄 Packages to {} at version {}:", action, version));
+        for package_name in &targets {
+            config.println(&format!("  • {}@{}", package_name, version));
+        }
+
+        if !config.confirm(&format!("Proceed with {}?", action))? {
+            config.println("Yank cancelled");
+            return Ok(());
+        }
+
+        let publisher = crate::publish::CargoPublisher::new();
+        let publish_config = crate::publish::PublishConfig {
+            registry: registry.clone(),
+            ..Default::default()
+        };
+
+        let mut failures = Vec::new();
+        for package_name in &targets {
+            let result = if *undo {
+                publisher.unyank_package(package_name, version, &publish_config).await
+            } else {
+                publisher.yank_package(package_name, version, &publish_config).await
+            };
+
+            match result {
+                Ok(yank_result) => config.println(&yank_result.format_result()),
+                Err(e) => {
+                    config.error_println(&format!("{}: {}", package_name, e));
+                    failures.push(package_name.clone());
+                }
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(crate::error::PublishError::YankFailed {
+                package: failures.join(", "),
+                version: version.to_string(),
+                reason: format!("{} of {} package(s) failed", failures.len(), targets.len()),
+            }.into());
+        }
+
+    } else {
+        unreachable!("execute_yank called with non-Yank command");
+    }
+
+    Ok(())
+}
+
+/// Execute owners command
+async fn execute_owners(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::Owners { add, remove, sync, packages, registry, .. } = &args.command {
+        config.verbose_println("Checking crate owners...");
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let project_config = load_config(args, config, &workspace)?;
+        for package_name in packages {
+            workspace.get_package(package_name)?;
+        }
+
+        // Crates that are never published have no registry owners to manage
+        let mut package_names: Vec<&String> = Vec::new();
+        for (package_name, package_info) in &workspace.packages {
+            if !packages.is_empty() && !packages.contains(package_name) {
+                continue;
+            }
+            if package_info.is_publishable() {
+                package_names.push(package_name);
+            } else if !packages.is_empty() {
+                config.warning_println(&format!("Skipping {}: it is not published (publish = false)", package_name));
+            }
+        }
+        package_names.sort();
+
+        let expected = &project_config.owners.expected;
+        let mut to_add = add.clone();
+        if *sync {
+            to_add.extend(expected.iter().filter(|login| !add.contains(login)).cloned());
+        }
+        let modifying = !to_add.is_empty() || !remove.is_empty();
+
+        // A crate that cannot be read or changed is reported, and the others still run
+        let mut failures = Vec::new();
+
+        let owners = CargoOwners::with_registry(registry.clone());
+        let mut diffs = Vec::new();
+        for package_name in package_names {
+            // In list mode, preview the expected owners that are missing
+            let diff = if modifying {
+                owners.diff(package_name, &to_add, remove).await
+            } else {
+                owners.diff(package_name, expected, &[]).await
+            };
+            match diff {
+                Ok(diff) => {
+                    config.println(&diff.format_diff());
+                    diffs.push(diff);
+                }
+                Err(e) => {
+                    config.error_println(&format!("Could not read owners of {}: {}", package_name, e));
+                    failures.push(package_name.clone());
+                }
+            }
+        }
+
+        let pending: Vec<&OwnershipDiff> = diffs.iter().filter(|diff| !diff.is_empty()).collect();
+
+        if !modifying {
+            if pending.is_empty() {
+                if !expected.is_empty() && failures.is_empty() {
+                    config.success_println("All packages have the expected owners");
+                }
+            } else {
+                config.warning_println(&format!(
+                    "{} package(s) are missing expected owners; run 'cyrup_release owners --sync' to add them",
+                    pending.len()
+                ));
+            }
+            return owner_failures(failures);
+        }
+
+        if pending.is_empty() {
+            config.println("✨ No owner changes needed");
+            return owner_failures(failures);
+        }
+
+        if !config.confirm(&format!("Apply owner changes to {} package(s)?", pending.len()))? {
+            config.println("Owner changes cancelled");
+            return owner_failures(failures);
+        }
+
+        for diff in pending {
+            let mut failed = false;
+            for (change, login) in diff.changes() {
+                let verb = match change {
+                    OwnerChange::Add => "Added",
+                    OwnerChange::Remove => "Removed",
+                };
+                match owners.apply(&diff.package_name, change, login).await {
+                    Ok(()) => config.success_println(&format!("{} {} as owner of {}", verb, login, diff.package_name)),
+                    Err(e) => {
+                        config.error_println(&format!("Could not change owner {} of {}: {}", login, diff.package_name, e));
+                        failed = true;
+                    }
+                }
+            }
+            if failed {
+                failures.push(diff.package_name.clone());
+            }
+        }
+
+        owner_failures(failures)?;
+    } else {
+        unreachable!("execute_owners called with non-Owners command");
+    }
+
+    Ok(())
+}
+
+/// Fail the owners command after it has gone through every package, naming the ones that failed
+fn owner_failures(failed_packages: Vec<String>) -> Result<()> {
+    if failed_packages.is_empty() {
+        return Ok(());
+    }
+    Err(CliError::ExecutionFailed {
+        command: "owners".to_string(),
+        reason: format!("failed for {} package(s): {}", failed_packages.len(), failed_packages.join(", ")),
+    }.into())
+}
+
+/// Execute graph command
+async fn execute_graph(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::Graph { format } = &args.command {
+        config.verbose_println("Building dependency graph...");
+
+        let workspace = WorkspaceInfo::analyze(&config.workspace_path)?;
+        let graph = DependencyGraph::build(&workspace)?;
+
+        // Still export a graph with a cycle, without tiers, so the cycle can be inspected
+        let publish_order = match graph.publish_order() {
+            Ok(order) => Some(order),
+            Err(e) => {
+                config.warning_println(&e.to_string());
+                for suggestion in e.recovery_suggestions() {
+                    config.warning_println(&format!("  • {}", suggestion));
+                }
+                None
+            }
+        };
+
+        let output = match format {
+            GraphFormat::Dot => graph.to_dot(publish_order.as_ref()),
+            GraphFormat::Mermaid => graph.to_mermaid(publish_order.as_ref()),
+            GraphFormat::Json => graph.to_json(publish_order.as_ref())?,
+        };
+
+        // Graph output is data, so it bypasses the quiet/verbose output filter
+        print!("{}", output);
+
+    } else {
+        unreachable!("execute_graph called with non-Graph command");
+    }
+
+    Ok(())
+}
+
+/// Execute completions command
+fn execute_completions(args: &Args) -> Result<()> {
+    if let Command::Completions { shell } = &args.command {
+        let mut command = Args::command();
+        let name = command.get_name().to_string();
+        clap_complete::generate(*shell, &mut command, name, &mut std::io::stdout());
+    } else {
+        unreachable!("execute_completions called with non-Completions command");
+    }
+
+    Ok(())
+}
+
+/// Execute self-update command
+async fn execute_self_update(args: &Args, config: &RuntimeConfig) -> Result<()> {
+    if let Command::SelfUpdate { check, .. } = &args.command {
+        let current = semver::Version::parse(env!("CARGO_PKG_VERSION"))
+            .map_err(|e| CliError::ExecutionFailed {
+                command: "self-update".to_string(),
+                reason: format!("Invalid package version: {}", e),
+            })?;
+
+        config.verbose_println("Checking crates.io for a newer cyrup_release...");
+        let latest = RegistryIndex::new()?
+            .published_versions(env!("CARGO_PKG_NAME"))
+            .await?
+            .into_iter()
+            .filter(|entry| !entry.yanked && entry.version.pre.is_empty())
+            .map(|entry| entry.version)
+            .max();
+
+        let latest = match latest {
+            Some(latest) if latest > current => latest,
+            _ => {
+                config.success_println(&format!("cyrup_release {} is up to date", current));
+                return Ok(());
+            }
+        };
+
+        let install_args = [
+            "install".to_string(),
+            env!("CARGO_PKG_NAME").to_string(),
+            "--version".to_string(),
+            latest.to_string(),
+            "--locked".to_string(),
+            "--force".to_string(),
+        ];
+        config.println(&format!("⬆️  cyrup_release {} is available (installed: {})", latest, current));
+        config.println(&format!("   Upgrade with: cargo {}", install_args.join(" ")));
+
+        if *check {
+            return Ok(());
+        }
+
+        if !config.confirm(&format!("Install cyrup_release {} now?", latest))? {
+            config.println("Update cancelled");
+            return Ok(());
+        }
+
+        let status = tokio::process::Command::new("cargo")
+            .args(&install_args)
+            .status()
+            .await
+            .map_err(|e| CliError::ExecutionFailed {
+                command: "self-update".to_string(),
+                reason: format!("Failed to run cargo install: {}", e),
+            })?;
+
+        if !status.success() {
+            return Err(CliError::ExecutionFailed {
+                command: "self-update".to_string(),
+                reason: format!("cargo install exited with {}", status),
+            }.into());
+        }
+
+        config.success_println(&format!("Updated cyrup_release to {}", latest));
+    } else {
+        unreachable!("execute_self_update called with non-SelfUpdate command");
+    }
+
+    Ok(())
+}
+
+/// Load project configuration, reporting any deprecated keys it used
+fn load_config(args: &Args, config: &RuntimeConfig, workspace: &WorkspaceInfo) -> Result<ProjectConfig> {
+    let project_config = load_project_config(&workspace.root, args.config.as_deref())?;
+
+    let warnings: Vec<DeprecationWarning> = project_config.migrations
+        .iter()
+        .map(DeprecationWarning::from)
+        .collect();
+    report_deprecations(&warnings, args.deny_deprecated, config)?;
+
+    Ok(project_config)
+}
+
+/// Open the release state, mirrored to the `[state]` store when the workspace configures one
+fn open_state_manager(args: &Args, config: &RuntimeConfig, state_config: StateConfig) -> Result<StateManager> {
+    // Outside a workspace there is no configuration, so state stays local
+    let store = match WorkspaceInfo::analyze(&config.workspace_path) {
+        Ok(workspace) => open_state_store(&load_project_config(&workspace.root, args.config.as_deref())?.state)?,
+        Err(_) => None,
+    };
+
+    create_state_manager_with_config(&config.state_file_path, StateConfig { store, ..state_config })
+}
+
+/// Name the state file is stored under in a shared store
+fn state_file_name(state_file_path: &Path) -> String {
+    state_file_path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Send a release event to the configured webhooks, reporting delivery failures as warnings
+async fn notify_event(
+    project_config: &ProjectConfig,
+    event: NotifyEvent,
+    state: &ReleaseState,
+    error: Option<&str>,
+    config: &RuntimeConfig,
+) {
+    if project_config.notify.webhooks.is_empty() {
+        return;
+    }
+
+    let notifier = match Notifier::new(project_config.notify.webhooks.clone()) {
+        Ok(notifier) => notifier,
+        Err(e) => {
+            config.warning_println(&format!("Skipping {} notification: {}", event.as_str(), e));
+            return;
+        }
+    };
+
+    for failure in notifier.notify(event, state, error).await {
+        config.warning_println(&format!("Notification failed: {}", failure));
+    }
+}
+
+/// Push the metrics of a finished release to the configured exporter, reporting failures as warnings
+async fn export_metrics(
+    project_config: &ProjectConfig,
+    state: &ReleaseState,
+    outcome: ReleaseOutcome,
+    failure: Option<&ReleaseError>,
+    config: &RuntimeConfig,
+) {
+    let exporter = match MetricsExporter::new(&project_config.metrics) {
+        Ok(Some(exporter)) => exporter,
+        Ok(None) => return,
+        Err(e) => {
+            config.warning_println(&format!("Skipping metrics export: {}", e));
+            return;
+        }
+    };
+
+    let metrics = ReleaseMetrics::from_state(state, outcome, failure);
+    match exporter.export(&metrics).await {
+        Ok(()) => config.verbose_println(&format!("Exported release metrics to {}", exporter.url())),
+        Err(e) => config.warning_println(&format!("Metrics export failed: {}", e)),
+    }
+}
+
+/// Validation checks from the configuration, with doc checks forced on by `--check-docs`
+fn with_doc_checks(validation: &ValidationSection, check_docs: bool) -> ValidationSection {
+    ValidationSection {
+        docs: validation.docs || check_docs,
+        doctests: validation.doctests || check_docs,
+        ..validation.clone()
+    }
+}
+
+/// Check the current time against the configured freeze windows.
+///
+/// Returns the override to record in the release state when a freeze is
+/// overridden. A dry run only reports the freeze.
+fn check_release_schedule(
+    project_config: &ProjectConfig,
+    schedule: bool,
+    override_freeze: Option<&str>,
+    dry_run: bool,
+    config: &RuntimeConfig,
+) -> Result<Option<serde_json::Value>> {
+    let schedule_config = &project_config.schedule;
+    if !schedule && !schedule_config.enforce && override_freeze.is_none() {
+        return Ok(None);
+    }
+
+    let now = chrono::Utc::now();
+    let Some(freeze) = schedule_config.active_freeze(now)? else {
+        if override_freeze.is_some() {
+            config.warning_println("--override-freeze given, but no freeze window is in effect");
+        } else {
+            config.verbose_println(&format!("No release freeze in effect at {}", now.format("%a %H:%M UTC")));
+        }
+        return Ok(None);
+    };
+
+    let until = freeze.until.format("%a %Y-%m-%d %H:%M UTC").to_string();
+    match override_freeze {
+        Some(reason) => {
+            config.warning_println(&format!(
+                "Overriding release freeze '{}' (until {}): {}",
+                freeze.name, until, reason
+            ));
+            Ok(Some(serde_json::json!({
+                "window": freeze.name,
+                "until": freeze.until.to_rfc3339(),
+                "reason": reason,
+                "overridden_at": now.to_rfc3339(),
+            })))
+        }
+        None if dry_run => {
+            config.warning_println(&format!("Release freeze '{}' is in effect until {}", freeze.name, until));
+            Ok(None)
+        }
+        None => Err(CliError::ReleaseFrozen {
+            window: freeze.name,
+            until,
+        }.into()),
+    }
+}
+
+/// Query the registry for each package being released, returning the never-published ones.
+///
+/// Fails before anything is changed if a new crate's name is already claimed
+/// under an equivalent spelling.
+async fn check_first_release(
+    workspace: &WorkspaceInfo,
+    project_config: &ProjectConfig,
+    package: Option<&str>,
+    registry: Option<&str>,
+    config: &RuntimeConfig,
+) -> Result<HashSet<String>> {
+    config.println("🆕 Checking for first releases...");
+
+    let mut package_names: Vec<&String> = workspace.packages.iter()
+        .filter(|(name, package_info)| {
+            package.is_none_or(|package| package == name.as_str())
+                && package_info.is_publishable()
+                && !project_config.is_publish_skipped(name)
+        })
+        .map(|(name, _)| name)
+        .collect();
+    package_names.sort();
+
+    let mut new_crates = HashSet::new();
+    let mut unavailable = Vec::new();
+    for package_name in package_names {
+        let registry_index = RegistryIndex::for_registry(project_config.package_registry(package_name, registry))?;
+        match registry_index.availability(package_name).await? {
+            CrateAvailability::Published { latest } => {
+                config.verbose_println(&format!("  {} already published (latest {})", package_name, latest));
+            }
+            CrateAvailability::Available => {
+                config.println(&format!("  {} is new; name is available", package_name));
+                new_crates.insert(package_name.clone());
+            }
+            CrateAvailability::Taken { existing } => {
+                config.error_println(&format!("  {} cannot be claimed: '{}' already exists", package_name, existing));
+                unavailable.push((package_name.clone(), existing));
+            }
+        }
+    }
+
+    if let Some((package, existing)) = unavailable.into_iter().next() {
+        return Err(PublishError::NameUnavailable { package, existing }.into());
+    }
+
+    if new_crates.is_empty() {
+        config.warning_println("--first-release given, but every package has been published before");
+    } else {
+        config.success_println(&format!("{} new crate(s) will be published for the first time", new_crates.len()));
+    }
+
+    Ok(new_crates)
+}
+
+/// Apply the fixes `validate --fix` knows to be safe, returning a description of each change.
+///
+/// In unified versioning mode packages are synchronized to the workspace
+/// version, then internal path dependencies get a version requirement for the
+/// member's current version and missing license and description are
+/// inherited from `[workspace.package]`.
+fn apply_validation_fixes(mut workspace: WorkspaceInfo, project_config: &ProjectConfig) -> Result<Vec<String>> {
+    let mut fixes = Vec::new();
+
+    // Synchronize first, since it also rewrites internal dependency requirements
+    if project_config.release.versioning == VersioningMode::Unified
+        && let Ok(workspace_version) = workspace.workspace_version()
+    {
+        let mut mismatched: Vec<String> = workspace.packages.values()
+            .filter(|package_info| package_info.version != workspace_version)
+            .map(|package_info| package_info.name.clone())
+            .collect();
+        if !mismatched.is_empty() {
+            mismatched.sort();
+            VersionManager::new(workspace.clone()).synchronize_versions()?;
+            fixes.extend(mismatched.iter().map(|package_name| format!(
+                "{}: version synchronized to workspace version {}",
+                package_name, workspace_version
+            )));
+            workspace = WorkspaceInfo::analyze(&workspace.root)?;
+        }
+    }
+
+    let workspace_fields = workspace.workspace_config.package.as_ref().map(|package| &package.other);
+
+    let mut package_names = workspace.package_names();
+    package_names.sort();
+    for package_name in &package_names {
+        let package_info = workspace.get_package(package_name)?;
+        let mut editor = TomlEditor::open(&package_info.cargo_toml_path)?;
+
+        let mut dependency_names = package_info.workspace_dependencies.clone();
+        dependency_names.sort();
+        for dependency_name in &dependency_names {
+            let Some(dependency_info) = workspace.packages.get(dependency_name) else {
+                continue;
+            };
+            let version = semver::Version::parse(&dependency_info.version)
+                .map_err(|e| crate::error::VersionError::ParseFailed {
+                    version: dependency_info.version.clone(),
+                    source: e,
+                })?;
+            if editor.add_missing_dependency_version(dependency_name, DependencySection::Dependencies, &version)? {
+                fixes.push(format!(
+                    "{}: added version \"{}\" to path dependency '{}'",
+                    package_name, version, dependency_name
+                ));
+            }
+        }
+
+        let missing_fields = [
+            ("license", package_info.config.license.is_none() && !package_info.config.other.contains_key("license-file")),
+            ("description", package_info.config.description.is_none()),
+        ];
+        for (field, missing) in missing_fields {
+            if missing
+                && workspace_fields.is_some_and(|fields| fields.contains_key(field))
+                && editor.inherit_package_field(field)?
+            {
+                fixes.push(format!("{}: {} inherited from [workspace.package]", package_name, field));
+            }
+        }
+
+        if editor.is_modified() {
+            editor.save()?;
+        }
+    }
+
+    Ok(fixes)
+}
+
+/// Report `[package.<name>]` overrides, warning about ones that cannot work as intended
+fn report_package_overrides(workspace: &WorkspaceInfo, project_config: &ProjectConfig, config: &RuntimeConfig) {
+    for (package_name, overrides) in &project_config.packages {
+        if !workspace.has_package(package_name) {
+            config.warning_println(&format!("[package.{}] does not match any workspace package", package_name));
+            continue;
+        }
+
+        if overrides.skip {
+            config.verbose_println(&format!("  {} will not be published (skip = true)", package_name));
+        } else if let Some(registry) = &overrides.registry {
+            config.verbose_println(&format!("  {} will be published to registry '{}'", package_name, registry));
+        }
+    }
+
+    let mut dependents: Vec<(&String, &String)> = workspace.internal_dependencies.iter()
+        .filter(|(package_name, _)| !project_config.is_publish_skipped(package_name))
+        .flat_map(|(package_name, dependencies)| dependencies.iter().map(move |dependency| (package_name, dependency)))
+        .filter(|(_, dependency)| project_config.is_publish_skipped(dependency))
+        .collect();
+    dependents.sort();
+    for (package_name, dependency) in dependents {
+        config.warning_println(&format!(
+            "{} depends on {}, which is skipped; that version must already be on the registry",
+            package_name, dependency
+        ));
+    }
+}
+
+/// Format a duration as a coarse age such as `3d 4h` or `12m`
+fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    let (days, hours, minutes) = (seconds / 86_400, seconds % 86_400 / 3_600, seconds % 3_600 / 60);
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds),
+        (0, 0, minutes) => format!("{}m", minutes),
+        (0, hours, 0) => format!("{}h", hours),
+        (0, hours, minutes) => format!("{}h {}m", hours, minutes),
+        (days, 0, _) => format!("{}d", days),
+        (days, hours, _) => format!("{}d {}h", days, hours),
+    }
+}
+
+/// Wait for docs.rs to build the published crates and report failures as warnings
+async fn verify_docs_builds(
+    publish_result: &PublishingResult,
+    project_config: &ProjectConfig,
+    registry: Option<&str>,
+    config: &RuntimeConfig,
+) -> Option<DocsVerification> {
+    // docs.rs only builds crates published to crates.io
+    let on_crates_io = |package_name: &str| {
+        project_config.package_registry(package_name, registry).is_none_or(|name| name == "crates-io")
+    };
+
+    let mut crates: Vec<(String, semver::Version)> = publish_result.successful_publishes.values()
+        .filter(|result| !result.dry_run && on_crates_io(&result.package_name))
+        .map(|result| (result.package_name.clone(), result.version.clone()))
+        .collect();
+    if crates.is_empty() {
+        config.verbose_println("Skipping docs.rs verification: no crates were published to crates.io");
+        return None;
+    }
+    crates.sort();
+
+    let settings = &project_config.docs_rs;
+    config.println(&format!(
+        "📚 Waiting up to {}s for docs.rs to build {} crates...",
+        settings.window_secs,
+        crates.len()
+    ));
+
+    let client = match DocsRsClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            config.warning_println(&format!("Skipping docs.rs verification: {}", e));
+            return None;
+        }
+    };
+
+    let verification = client.wait_for_builds(
+        &crates,
+        Duration::from_secs(settings.window_secs),
+        Duration::from_secs(settings.poll_interval_secs),
+    ).await;
+
+    for report in &verification.reports {
+        if report.status == DocsBuildStatus::Succeeded {
+            config.verbose_println(&format!("  {}", report.format_result()));
+        } else {
+            config.warning_println(&format!("  {}", report.format_result()));
+        }
+    }
+
+    if verification.all_succeeded() {
+        config.success_println(&format!("docs.rs builds succeeded: {}", verification.format_summary()));
+    } else {
+        config.warning_println(&format!("docs.rs verification incomplete: {}", verification.format_summary()));
+    }
+
+    Some(verification)
+}
+
+/// Location of the SBOM for a release, next to the state file
+fn sbom_path_for(state_file_path: &Path, version: &semver::Version, format: SbomFormat) -> PathBuf {
+    let extension = match format {
+        SbomFormat::Cyclonedx => "cdx.json",
+        SbomFormat::Spdx => "spdx.json",
+    };
+    state_file_path.with_file_name(format!(".cyrup_release_sbom-{}.{}", version, extension))
+}
+
+/// Write the SBOM and optionally upload it to the GitHub Release of each tag
+async fn write_sbom(
+    sbom: &Sbom,
+    format: SbomFormat,
+    path: &Path,
+    attach_tags: Option<&[String]>,
+    remote: &str,
+    no_push: bool,
+    config: &RuntimeConfig,
+) {
+    let document = match format {
+        SbomFormat::Cyclonedx => sbom.to_cyclonedx(),
+        SbomFormat::Spdx => sbom.to_spdx(),
+    };
+    let written = document.and_then(|document| {
+        std::fs::write(path, document).map_err(|e| WorkspaceError::SbomFailed {
+            reason: format!("Failed to write {}: {}", path.display(), e),
+        }.into())
+    });
+    if let Err(e) = written {
+        config.warning_println(&format!("SBOM not written: {}", e));
+        return;
+    }
+    config.success_println(&format!(
+        "SBOM written to {} ({} components)",
+        path.display(),
+        sbom.components.len()
+    ));
+
+    let Some(tags) = attach_tags else {
+        return;
+    };
+    if no_push {
+        config.warning_println("Not attaching the SBOM: tags were not pushed (--no-push)");
+        return;
+    }
+    if which::which("gh").is_err() {
+        config.warning_println("Not attaching the SBOM: the GitHub CLI (gh) is not installed");
+        return;
+    }
+
+    // Releases live in the repository the tags were pushed to, whatever gh would pick by default
+    let repository = match GitRepository::open(&config.workspace_path) {
+        Ok(repository) => repository.remote_url(remote, true).await,
+        Err(e) => Err(e),
+    };
+    let github_repo = match repository.as_deref().map(RemoteUrl::parse) {
+        Ok(Some(url)) if url.provider == HostingProvider::GitHub => url,
+        Ok(_) => {
+            config.warning_println(&format!("Not attaching the SBOM: remote '{}' is not a GitHub repository", remote));
+            return;
+        }
+        Err(e) => {
+            config.warning_println(&format!("Not attaching the SBOM: {}", e));
+            return;
+        }
+    };
+
+    for tag in tags {
+        let output = tokio::process::Command::new("gh")
+            .args(["release", "upload", tag.as_str()])
+            .arg(path)
+            .arg("--clobber")
+            .args(["--repo", &github_repo.to_string()])
+            .current_dir(&config.workspace_path)
+            .output()
+            .await;
+        match output {
+            Ok(output) if output.status.success() => {
+                config.success_println(&format!("SBOM attached to GitHub Release {}", tag));
+            }
+            Ok(output) => config.warning_println(&format!(
+                "Failed to attach the SBOM to GitHub Release {}: {}",
+                tag,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )),
+            Err(e) => config.warning_println(&format!(
+                "Failed to attach the SBOM to GitHub Release {}: {}",
+                tag, e
+            )),
+        }
+    }
+}
+
 /// Restore a package version in its Cargo.toml file
 fn restore_package_version(cargo_toml_path: &std::path::Path, version: &str) -> Result<()> {
     let version_parsed = semver::Version::parse(version)
@@ -734,36 +2652,3 @@ fn restore_package_version(cargo_toml_path: &std::path::Path, version: &str) ->
 
     Ok(())
 }
-
-/// Prompt user for confirmation (yes/no)
-/// Returns true if user confirms with "yes" or "y" (case-insensitive)
-/// Returns false on EOF, empty input, or any other input
-fn prompt_confirmation(message: &str) -> Result<bool> {
-    use std::io::{self, Write};
-    
-    // Print prompt message and flush to ensure it appears immediately
-    print!("{} [y/N]: ", message);
-    io::stdout().flush()
-        .map_err(|e| ReleaseError::Cli(crate::error::CliError::ExecutionFailed {
-            command: "prompt".to_string(),
-            reason: format!("Failed to flush stdout: {}", e),
-        }))?;
-    
-    // Read user input
-    let mut input = String::new();
-    match io::stdin().read_line(&mut input) {
-        Ok(0) => {
-            // EOF (Ctrl+D) - default to no
-            return Ok(false);
-        }
-        Ok(_) => {
-            // Got input, check if it's a confirmation
-            let trimmed = input.trim().to_lowercase();
-            Ok(trimmed == "yes" || trimmed == "y")
-        }
-        Err(_) => {
-            // Error reading input - default to no
-            Ok(false)
-        }
-    }
-}
\ No newline at end of file
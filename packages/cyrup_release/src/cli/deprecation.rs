@@ -0,0 +1,242 @@
+//! Deprecation warnings for renamed flags and configuration keys.
+//!
+//! Deprecated flags are rewritten to their replacements before argument
+//! parsing so old invocations keep working. Every rewrite (and every migrated
+//! configuration key) produces a structured warning; with `--deny-deprecated`
+//! those warnings become errors.
+
+use crate::cli::RuntimeConfig;
+use crate::config::ConfigMigration;
+use crate::error::{CliError, Result};
+use std::ffi::OsString;
+use std::fmt;
+
+/// A command line flag that has been renamed
+#[derive(Debug, Clone, Copy)]
+pub struct DeprecatedFlag {
+    /// Old flag spelling (including leading dashes)
+    pub old: &'static str,
+    /// Flag that replaces it
+    pub replacement: &'static str,
+    /// Version in which the flag was deprecated
+    pub since: &'static str,
+}
+
+/// Flags that are still accepted but rewritten to their replacements
+pub const DEPRECATED_FLAGS: &[DeprecatedFlag] = &[
+    DeprecatedFlag {
+        old: "--publish-interval",
+        replacement: "--package-delay",
+        since: "0.5.4",
+    },
+];
+
+/// What kind of item is deprecated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DeprecationKind {
+    /// Command line flag
+    Flag,
+    /// Configuration file key
+    ConfigKey,
+}
+
+/// A single use of a deprecated flag or configuration key
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DeprecationWarning {
+    /// Kind of deprecated item
+    pub kind: DeprecationKind,
+    /// Deprecated name that was used
+    pub old: String,
+    /// Name to use instead
+    pub replacement: String,
+    /// Version in which the item was deprecated
+    pub since: String,
+}
+
+impl fmt::Display for DeprecationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            DeprecationKind::Flag => write!(
+                f,
+                "flag '{}' is deprecated since {}; use '{}' instead",
+                self.old, self.since, self.replacement
+            ),
+            DeprecationKind::ConfigKey => write!(
+                f,
+                "config key '{}' is deprecated since {}; use '{}' instead (run 'cyrup_release migrate-config')",
+                self.old, self.since, self.replacement
+            ),
+        }
+    }
+}
+
+impl From<&ConfigMigration> for DeprecationWarning {
+    fn from(migration: &ConfigMigration) -> Self {
+        Self {
+            kind: DeprecationKind::ConfigKey,
+            old: migration.old_key.clone(),
+            replacement: migration.new_key.clone(),
+            since: migration.since.clone(),
+        }
+    }
+}
+
+/// Rewrite deprecated flags to their replacements, returning the new argument list
+pub fn rewrite_deprecated_args<I>(args: I) -> (Vec<OsString>, Vec<DeprecationWarning>)
+where
+    I: IntoIterator<Item = OsString>,
+{
+    let mut rewritten = Vec::new();
+    let mut warnings = Vec::new();
+    let mut end_of_options = false;
+
+    for arg in args {
+        if end_of_options {
+            rewritten.push(arg);
+            continue;
+        }
+
+        let Some(arg_str) = arg.to_str() else {
+            rewritten.push(arg);
+            continue;
+        };
+
+        if arg_str == "--" {
+            end_of_options = true;
+            rewritten.push(arg);
+            continue;
+        }
+
+        let (flag, value) = match arg_str.split_once('=') {
+            Some((flag, value)) => (flag, Some(value)),
+            None => (arg_str, None),
+        };
+
+        match DEPRECATED_FLAGS.iter().find(|deprecated| deprecated.old == flag) {
+            Some(deprecated) => {
+                warnings.push(DeprecationWarning {
+                    kind: DeprecationKind::Flag,
+                    old: deprecated.old.to_string(),
+                    replacement: deprecated.replacement.to_string(),
+                    since: deprecated.since.to_string(),
+                });
+
+                let replacement = match value {
+                    Some(value) => format!("{}={}", deprecated.replacement, value),
+                    None => deprecated.replacement.to_string(),
+                };
+                rewritten.push(OsString::from(replacement));
+            }
+            None => rewritten.push(arg),
+        }
+    }
+
+    (rewritten, warnings)
+}
+
+/// Print deprecation warnings, failing instead when deprecated usage is denied
+pub fn report_deprecations(
+    warnings: &[DeprecationWarning],
+    deny: bool,
+    config: &RuntimeConfig,
+) -> Result<()> {
+    if warnings.is_empty() {
+        return Ok(());
+    }
+
+    if deny {
+        for warning in warnings {
+            config.error_println(&format!("Deprecated: {}", warning));
+        }
+        return Err(CliError::DeprecatedUsage {
+            count: warnings.len(),
+        }.into());
+    }
+
+    for warning in warnings {
+        config.warning_println(&format!("Deprecated: {}", warning));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Args;
+    use clap::Parser;
+
+    fn rewrite(args: &[&str]) -> (Vec<String>, Vec<DeprecationWarning>) {
+        let (args, warnings) = rewrite_deprecated_args(args.iter().map(OsString::from));
+        let args = args.into_iter().map(|arg| arg.into_string().unwrap()).collect();
+        (args, warnings)
+    }
+
+    fn flag_warning() -> DeprecationWarning {
+        DeprecationWarning {
+            kind: DeprecationKind::Flag,
+            old: "--publish-interval".to_string(),
+            replacement: "--package-delay".to_string(),
+            since: "0.5.4".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_deprecated_flag_is_rewritten() {
+        let (args, warnings) = rewrite(&["cyrup_release", "publish", "--publish-interval", "30"]);
+        assert_eq!(args, vec!["cyrup_release", "publish", "--package-delay", "30"]);
+        assert_eq!(warnings, vec![flag_warning()]);
+
+        let (args, warnings) = rewrite(&["cyrup_release", "publish", "--publish-interval=30"]);
+        assert_eq!(args, vec!["cyrup_release", "publish", "--package-delay=30"]);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_other_arguments_are_left_alone() {
+        let args = ["cyrup_release", "publish", "--package-delay", "30", "--publish-intervals", "x=--publish-interval"];
+        let (rewritten, warnings) = rewrite(&args);
+        assert_eq!(rewritten, args);
+        assert!(warnings.is_empty());
+
+        // Nothing after `--` is a flag
+        let args = ["cyrup_release", "run", "--", "--publish-interval", "30"];
+        let (rewritten, warnings) = rewrite(&args);
+        assert_eq!(rewritten, args);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_warning_messages() {
+        assert_eq!(
+            flag_warning().to_string(),
+            "flag '--publish-interval' is deprecated since 0.5.4; use '--package-delay' instead"
+        );
+
+        let warning = DeprecationWarning::from(&ConfigMigration {
+            old_key: "release.old-name".to_string(),
+            new_key: "git.new-name".to_string(),
+            since: "1.0.0".to_string(),
+        });
+        assert_eq!(warning.kind, DeprecationKind::ConfigKey);
+        assert_eq!(
+            warning.to_string(),
+            "config key 'release.old-name' is deprecated since 1.0.0; use 'git.new-name' instead \
+             (run 'cyrup_release migrate-config')"
+        );
+    }
+
+    #[test]
+    fn test_deny_deprecated_fails_with_the_warning_count() {
+        let config = RuntimeConfig::from(&Args::try_parse_from(["cyrup_release", "status"]).unwrap());
+        let warnings = vec![flag_warning(), flag_warning()];
+
+        assert!(report_deprecations(&[], true, &config).is_ok());
+        assert!(report_deprecations(&warnings, false, &config).is_ok());
+        match report_deprecations(&warnings, true, &config) {
+            Err(crate::error::ReleaseError::Cli(CliError::DeprecatedUsage { count })) => assert_eq!(count, 2),
+            other => panic!("expected a deprecated usage error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Tracing based logging for console output and structured log files.
+//!
+//! All user facing output goes through `tracing` events on [`OUTPUT_TARGET`],
+//! which the console layer renders in the familiar emoji-prefixed format.
+//! Diagnostic events from the rest of the crate are shown on the console only
+//! when the filter allows them. With `--log-file`, every event is additionally
+//! written as JSON lines together with the active phase and package spans.
+//! Under CI, release phases are additionally wrapped in log group markers.
+//! On a terminal, console output pauses the live progress bar while printing.
+
+use crate::cli::{set_progress_enabled, suspend, Args, CiGroupLayer, VerbosityLevel};
+use crate::error::{CliError, Result};
+use std::fmt::{self, Write as _};
+use std::fs::OpenOptions;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// Target of events that make up the regular command output
+pub const OUTPUT_TARGET: &str = "cyrup_release::output";
+
+/// Filter used for log files when no explicit filter is given
+const DEFAULT_FILE_FILTER: &str = "cyrup_release=debug";
+
+/// Console filter directives for a verbosity level
+pub fn verbosity_filter(verbosity: VerbosityLevel) -> &'static str {
+    match verbosity {
+        VerbosityLevel::Quiet => "error",
+        VerbosityLevel::Normal => "warn,cyrup_release::output=info",
+        VerbosityLevel::Verbose => "warn,cyrup_release=debug",
+    }
+}
+
+/// Install the global tracing subscriber for a command invocation.
+///
+/// The console filter is taken from `--log-filter`, then `RUST_LOG`, and
+/// falls back to the verbosity flags. Installing twice is a no-op so library
+/// users that set up their own subscriber keep it.
+pub fn init_logging(args: &Args, verbosity: VerbosityLevel) -> Result<()> {
+    let user_filter = args.log_filter.clone()
+        .or_else(|| std::env::var("RUST_LOG").ok().filter(|filter| !filter.trim().is_empty()));
+
+    let console_filter = parse_filter(user_filter.as_deref().unwrap_or(verbosity_filter(verbosity)))?;
+    let console_layer = ConsoleLayer.with_filter(console_filter);
+
+    let file_layer = match &args.log_file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|e| CliError::InvalidArguments {
+                    reason: format!("Cannot open log file {}: {}", path.display(), e),
+                })?;
+
+            let file_filter = parse_filter(user_filter.as_deref().unwrap_or(DEFAULT_FILE_FILTER))?;
+            let layer = tracing_subscriber::fmt::layer()
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .with_writer(Mutex::new(file))
+                .with_filter(file_filter);
+            Some(layer)
+        }
+        None => None,
+    };
+
+    // Live progress bars only make sense on an interactive terminal
+    set_progress_enabled(
+        verbosity != VerbosityLevel::Quiet && args.ci.is_none() && std::io::stderr().is_terminal()
+    );
+
+    let ci_layer = args.ci
+        .filter(|_| verbosity != VerbosityLevel::Quiet)
+        .map(CiGroupLayer::new);
+
+    let _ = Registry::default()
+        .with(ci_layer)
+        .with(console_layer)
+        .with(file_layer)
+        .try_init();
+
+    Ok(())
+}
+
+/// Parse `RUST_LOG`-style filter directives
+fn parse_filter(directives: &str) -> Result<EnvFilter> {
+    EnvFilter::builder()
+        .parse(directives)
+        .map_err(|e| CliError::InvalidArguments {
+            reason: format!("Invalid log filter '{}': {}", directives, e),
+        }.into())
+}
+
+/// Layer rendering events to stdout/stderr in the CLI's output format
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConsoleLayer;
+
+impl<S: Subscriber> Layer<S> for ConsoleLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        let level = *metadata.level();
+
+        if metadata.target() == OUTPUT_TARGET {
+            suspend(|| match level {
+                Level::ERROR => eprintln!("❌ {}", visitor.message),
+                Level::WARN => println!("⚠️ {}", visitor.message),
+                Level::INFO if visitor.success => println!("✅ {}", visitor.message),
+                Level::INFO => println!("{}", visitor.message),
+                _ => println!("🔍 {}", visitor.message),
+            });
+            return;
+        }
+
+        let mut line = format!("{} {}: {}", level, metadata.target(), visitor.message);
+        for (name, value) in &visitor.fields {
+            let _ = write!(line, " {}={}", name, value);
+        }
+        suspend(|| eprintln!("{}", line));
+    }
+}
+
+/// Collects the message and fields of an event
+#[derive(Default)]
+struct EventVisitor {
+    message: String,
+    success: bool,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for EventVisitor {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == "success" {
+            self.success = value;
+        } else {
+            self.fields.push((field.name(), value.to_string()));
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push((field.name(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push((field.name(), format!("{:?}", value)));
+        }
+    }
+}
@@ -2,13 +2,27 @@
 //!
 //! This module provides a comprehensive CLI for release management operations,
 //! with proper argument parsing, command execution, and user feedback.
+//! Every flag can also be set through a `CYRUP_RELEASE_*` environment variable.
 
 mod args;
+mod ci;
 mod commands;
+mod deprecation;
+mod logging;
+mod progress;
+mod prompt;
 
 pub use args::{
-    Args, Command, BumpType, ResumePhase, RuntimeConfig, VerbosityLevel,
+    Args, Command, BumpType, GraphFormat, ResumePhase, SbomFormat, RuntimeConfig, VerbosityLevel,
 };
+pub use ci::{CiGroupLayer, CiProvider, ENV_PREFIX};
+pub use deprecation::{
+    DeprecatedFlag, DeprecationKind, DeprecationWarning, DEPRECATED_FLAGS,
+    rewrite_deprecated_args, report_deprecations,
+};
+pub use prompt::{AlwaysNo, AlwaysYes, InteractivePrompter, Prompter, select_prompter};
+pub use progress::{ProgressBar, set_progress_enabled, suspend};
+pub use logging::{ConsoleLayer, OUTPUT_TARGET, init_logging, verbosity_filter};
 pub use commands::execute_command;
 
 use crate::error::Result;
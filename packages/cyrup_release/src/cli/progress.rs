@@ -0,0 +1,208 @@
+//! Live progress display for long-running phases.
+//!
+//! A status line at the bottom of the terminal shows a bar for the current
+//! phase, a spinner with the items in flight, and an ETA. It is drawn on
+//! stderr only when stderr is a terminal and output is neither quiet nor
+//! going to CI logs. The console layer clears the line around regular output
+//! so log lines and the bar never interleave.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+/// Whether progress bars are drawn
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Bar currently drawn on the terminal
+static ACTIVE: Mutex<Option<Weak<BarInner>>> = Mutex::new(None);
+
+/// Spinner animation frames
+const SPINNER: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Width of the bar in characters
+const BAR_WIDTH: usize = 24;
+
+/// Enable or disable drawing of progress bars
+pub fn set_progress_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Progress bar for a phase, with spinners for items in flight.
+///
+/// Clones share the same bar, so concurrent tasks can report on it. The bar
+/// is cleared when the last clone is dropped.
+#[derive(Clone)]
+pub struct ProgressBar {
+    inner: Arc<BarInner>,
+}
+
+struct BarInner {
+    state: Mutex<BarState>,
+    ticker: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    visible: bool,
+}
+
+#[derive(Default)]
+struct BarState {
+    label: String,
+    position: usize,
+    length: usize,
+    in_flight: Vec<String>,
+    eta: Option<Duration>,
+    frame: usize,
+    drawn: bool,
+}
+
+impl ProgressBar {
+    /// Create a bar with a label and the number of items it tracks
+    pub fn new(label: &str, length: usize) -> Self {
+        let visible = ENABLED.load(Ordering::Relaxed) && tokio::runtime::Handle::try_current().is_ok();
+        let inner = Arc::new(BarInner {
+            state: Mutex::new(BarState {
+                label: label.to_string(),
+                length,
+                ..BarState::default()
+            }),
+            ticker: Mutex::new(None),
+            visible,
+        });
+
+        if visible {
+            *ACTIVE.lock().unwrap_or_else(|e| e.into_inner()) = Some(Arc::downgrade(&inner));
+
+            let weak = Arc::downgrade(&inner);
+            let ticker = tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_millis(100));
+                loop {
+                    interval.tick().await;
+                    let Some(inner) = weak.upgrade() else {
+                        break;
+                    };
+                    inner.update(|state| state.frame = state.frame.wrapping_add(1));
+                }
+            });
+            *inner.ticker.lock().unwrap_or_else(|e| e.into_inner()) = Some(ticker);
+        }
+
+        Self { inner }
+    }
+
+    /// Change the label, e.g. when moving to the next tier
+    pub fn set_label(&self, label: &str) {
+        self.inner.update(|state| state.label = label.to_string());
+    }
+
+    /// Show an item as in flight
+    pub fn start(&self, item: &str) {
+        self.inner.update(|state| state.in_flight.push(item.to_string()));
+    }
+
+    /// Mark an in-flight item as finished and advance the bar
+    pub fn finish_item(&self, item: &str) {
+        self.inner.update(|state| {
+            state.in_flight.retain(|name| name != item);
+            state.position = (state.position + 1).min(state.length);
+        });
+    }
+
+    /// Set the estimated time remaining
+    pub fn set_eta(&self, eta: Option<Duration>) {
+        self.inner.update(|state| state.eta = eta);
+    }
+}
+
+impl fmt::Debug for ProgressBar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressBar").field("visible", &self.inner.visible).finish_non_exhaustive()
+    }
+}
+
+impl BarInner {
+    /// Apply a change and redraw
+    fn update(&self, change: impl FnOnce(&mut BarState)) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        change(&mut state);
+        if self.visible {
+            state.draw();
+        }
+    }
+}
+
+impl Drop for BarInner {
+    fn drop(&mut self) {
+        if let Some(ticker) = self.ticker.get_mut().unwrap_or_else(|e| e.into_inner()).take() {
+            ticker.abort();
+        }
+        if self.visible {
+            self.state.get_mut().unwrap_or_else(|e| e.into_inner()).clear();
+        }
+    }
+}
+
+impl BarState {
+    /// Redraw the status line in place
+    fn draw(&mut self) {
+        let filled = (self.position * BAR_WIDTH).checked_div(self.length).unwrap_or(BAR_WIDTH);
+        let mut line = format!(
+            "{} [{}{}] {}/{}",
+            self.label,
+            "█".repeat(filled),
+            "░".repeat(BAR_WIDTH - filled),
+            self.position,
+            self.length
+        );
+        if !self.in_flight.is_empty() {
+            line.push_str(&format!(" {} {}", SPINNER[self.frame % SPINNER.len()], self.in_flight.join(", ")));
+        }
+        if let Some(eta) = self.eta {
+            line.push_str(&format!(" · ETA {}", format_eta(eta)));
+        }
+
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K{}", line);
+        let _ = stderr.flush();
+        self.drawn = true;
+    }
+
+    /// Erase the status line
+    fn clear(&mut self) {
+        if self.drawn {
+            let mut stderr = io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+            self.drawn = false;
+        }
+    }
+}
+
+/// Run output code with the active bar cleared, redrawing it afterwards
+pub fn suspend<R>(output: impl FnOnce() -> R) -> R {
+    let active = ACTIVE.lock().unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .and_then(Weak::upgrade);
+
+    let Some(inner) = active else {
+        return output();
+    };
+
+    let mut state = inner.state.lock().unwrap_or_else(|e| e.into_inner());
+    let was_drawn = state.drawn;
+    state.clear();
+    let result = output();
+    if was_drawn {
+        state.draw();
+    }
+    result
+}
+
+/// Format an ETA as `1m05s` or `42s`
+fn format_eta(eta: Duration) -> String {
+    let seconds = eta.as_secs();
+    if seconds >= 60 {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
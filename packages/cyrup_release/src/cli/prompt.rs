@@ -0,0 +1,136 @@
+//! Confirmation prompts for destructive commands.
+//!
+//! Commands ask for confirmation through a [`Prompter`] chosen once from the
+//! command line: `--yes`/`--yes-all` confirm everything, `--assume-no`
+//! declines everything, and otherwise the user is asked on the terminal.
+//! When stdin is not a terminal nobody can answer, so prompts are declined
+//! instead of blocking on input that never arrives.
+
+use crate::cli::logging::OUTPUT_TARGET;
+use crate::error::{CliError, Result};
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::sync::Arc;
+
+/// Answers yes/no confirmation prompts
+pub trait Prompter: fmt::Debug + Send + Sync {
+    /// Ask for confirmation, returning whether to proceed
+    fn confirm(&self, message: &str) -> Result<bool>;
+}
+
+/// Asks the user on the terminal
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InteractivePrompter;
+
+/// Confirms every prompt
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysYes;
+
+/// Declines every prompt
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysNo {
+    /// Whether the prompt is declined because stdin is not a terminal
+    pub no_terminal: bool,
+}
+
+impl Prompter for InteractivePrompter {
+    /// Returns true if the user answers "yes" or "y" (case-insensitive),
+    /// false on EOF, empty input, or any other input
+    fn confirm(&self, message: &str) -> Result<bool> {
+        print!("{} [y/N]: ", message);
+        io::stdout().flush()
+            .map_err(|e| CliError::ExecutionFailed {
+                command: "prompt".to_string(),
+                reason: format!("Failed to flush stdout: {}", e),
+            })?;
+
+        Ok(read_confirmation(&mut io::stdin().lock()))
+    }
+}
+
+/// Read one answer, accepting "yes" or "y" in any case
+fn read_confirmation(input: &mut impl io::BufRead) -> bool {
+    let mut line = String::new();
+    match input.read_line(&mut line) {
+        Ok(0) | Err(_) => false,
+        Ok(_) => {
+            let trimmed = line.trim().to_lowercase();
+            trimmed == "yes" || trimmed == "y"
+        }
+    }
+}
+
+impl Prompter for AlwaysYes {
+    fn confirm(&self, message: &str) -> Result<bool> {
+        tracing::debug!(target: OUTPUT_TARGET, "{} yes (confirmed by --yes)", message);
+        Ok(true)
+    }
+}
+
+impl Prompter for AlwaysNo {
+    fn confirm(&self, message: &str) -> Result<bool> {
+        if self.no_terminal {
+            tracing::warn!(
+                target: OUTPUT_TARGET,
+                "{} no (stdin is not a terminal; pass --yes or --yes-all to confirm)",
+                message
+            );
+        } else {
+            tracing::info!(target: OUTPUT_TARGET, "{} no (declined by --assume-no)", message);
+        }
+        Ok(false)
+    }
+}
+
+/// Select the prompter for an invocation.
+///
+/// Explicit flags win; without them, prompts are only interactive when
+/// stdin is a terminal.
+pub fn select_prompter(assume_yes: bool, assume_no: bool) -> Arc<dyn Prompter> {
+    if assume_yes {
+        Arc::new(AlwaysYes)
+    } else if assume_no {
+        Arc::new(AlwaysNo { no_terminal: false })
+    } else if io::stdin().is_terminal() {
+        Arc::new(InteractivePrompter)
+    } else {
+        Arc::new(AlwaysNo { no_terminal: true })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_confirmation() {
+        for answer in ["y\n", "Y\n", "yes\n", "  YES  \r\n", "y"] {
+            assert!(read_confirmation(&mut answer.as_bytes()), "{:?}", answer);
+        }
+        for answer in ["", "\n", "n\n", "no\n", "yep\n", "maybe\ny\n"] {
+            assert!(!read_confirmation(&mut answer.as_bytes()), "{:?}", answer);
+        }
+    }
+
+    #[test]
+    fn test_fixed_answers() {
+        assert!(AlwaysYes.confirm("Delete the release?").unwrap());
+        assert!(!AlwaysNo { no_terminal: false }.confirm("Delete the release?").unwrap());
+        assert!(!AlwaysNo { no_terminal: true }.confirm("Delete the release?").unwrap());
+    }
+
+    #[test]
+    fn test_select_prompter() {
+        // --yes wins over --assume-no
+        assert_eq!(format!("{:?}", select_prompter(true, true)), "AlwaysYes");
+        assert_eq!(format!("{:?}", select_prompter(true, false)), "AlwaysYes");
+        assert_eq!(format!("{:?}", select_prompter(false, true)), "AlwaysNo { no_terminal: false }");
+
+        let expected = if io::stdin().is_terminal() {
+            "InteractivePrompter"
+        } else {
+            "AlwaysNo { no_terminal: true }"
+        };
+        assert_eq!(format!("{:?}", select_prompter(false, false)), expected);
+    }
+}
@@ -0,0 +1,203 @@
+//! Migration of renamed and moved configuration keys.
+//!
+//! Old keys are still understood when loading `release.toml`: they are moved
+//! to their new location in memory and reported as deprecations. The
+//! `migrate-config` command applies the same moves to the file on disk,
+//! preserving formatting and comments.
+
+use crate::error::{ConfigError, Result};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, Table};
+
+/// A configuration key that has been renamed or moved
+#[derive(Debug, Clone, Copy)]
+pub struct RenamedKey {
+    /// Old dotted key path
+    pub old: &'static str,
+    /// New dotted key path
+    pub new: &'static str,
+    /// Version in which the old key was deprecated
+    pub since: &'static str,
+}
+
+/// Keys accepted under an old name and migrated to their replacement
+///
+/// No `release.toml` key has been renamed yet; add an entry here when one is.
+pub const RENAMED_KEYS: &[RenamedKey] = &[];
+
+/// A key that was moved from its deprecated location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigMigration {
+    /// Deprecated key path that was found
+    pub old_key: String,
+    /// Key path it was moved to
+    pub new_key: String,
+    /// Version in which the old key was deprecated
+    pub since: String,
+}
+
+/// Result of migrating a configuration file
+#[derive(Debug, Clone)]
+pub struct MigrationReport {
+    /// File that was migrated
+    pub path: PathBuf,
+    /// Keys that were moved
+    pub migrations: Vec<ConfigMigration>,
+    /// Whether the file was written
+    pub written: bool,
+}
+
+/// Move deprecated keys in a parsed document to their new locations
+pub fn migrate_document(document: &mut DocumentMut) -> Result<Vec<ConfigMigration>> {
+    migrate_keys(document, RENAMED_KEYS)
+}
+
+/// Move each of `renamed_keys` that is set in the document to its new location
+fn migrate_keys(document: &mut DocumentMut, renamed_keys: &[RenamedKey]) -> Result<Vec<ConfigMigration>> {
+    let mut migrations = Vec::new();
+
+    for renamed in renamed_keys {
+        let Some(item) = take_item(document.as_table_mut(), renamed.old) else {
+            continue;
+        };
+
+        if get_item(document.as_table(), renamed.new).is_some() {
+            return Err(ConfigError::InvalidValue {
+                key: renamed.old.to_string(),
+                reason: format!(
+                    "both '{}' and its replacement '{}' are set; remove the deprecated key",
+                    renamed.old, renamed.new
+                ),
+            }.into());
+        }
+
+        insert_item(document.as_table_mut(), renamed.new, item)?;
+        migrations.push(ConfigMigration {
+            old_key: renamed.old.to_string(),
+            new_key: renamed.new.to_string(),
+            since: renamed.since.to_string(),
+        });
+    }
+
+    Ok(migrations)
+}
+
+/// Rewrite deprecated keys in a configuration file in place
+pub fn migrate_config_file<P: AsRef<Path>>(path: P, dry_run: bool) -> Result<MigrationReport> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Err(ConfigError::NotFound {
+            path: path.to_path_buf(),
+        }.into());
+    }
+
+    let content = std::fs::read_to_string(path)?;
+    let mut document = content.parse::<DocumentMut>()
+        .map_err(|e| ConfigError::ParseFailed {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+
+    let migrations = migrate_document(&mut document)?;
+    let written = !dry_run && !migrations.is_empty();
+
+    if written {
+        std::fs::write(path, document.to_string())?;
+    }
+
+    Ok(MigrationReport {
+        path: path.to_path_buf(),
+        migrations,
+        written,
+    })
+}
+
+/// Look up an item by dotted key path
+fn get_item<'a>(table: &'a Table, key_path: &str) -> Option<&'a Item> {
+    let mut parts = key_path.split('.').peekable();
+    let mut current = table;
+
+    while let Some(part) = parts.next() {
+        let item = current.get(part)?;
+        if parts.peek().is_none() {
+            return Some(item);
+        }
+        current = item.as_table()?;
+    }
+
+    None
+}
+
+/// Remove and return an item by dotted key path
+fn take_item(table: &mut Table, key_path: &str) -> Option<Item> {
+    match key_path.split_once('.') {
+        Some((head, rest)) => take_item(table.get_mut(head)?.as_table_mut()?, rest),
+        None => table.remove(key_path),
+    }
+}
+
+/// Insert an item at a dotted key path, creating intermediate tables
+fn insert_item(table: &mut Table, key_path: &str, item: Item) -> Result<()> {
+    match key_path.split_once('.') {
+        Some((head, rest)) => {
+            let entry = table.entry(head).or_insert_with(|| Item::Table(Table::new()));
+            let child = entry.as_table_mut().ok_or_else(|| ConfigError::InvalidValue {
+                key: head.to_string(),
+                reason: "expected a table".to_string(),
+            })?;
+            insert_item(child, rest, item)
+        }
+        None => {
+            table.insert(key_path, item);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENAMED: &[RenamedKey] = &[RenamedKey {
+        old: "release.old-name",
+        new: "git.new-name",
+        since: "1.0.0",
+    }];
+
+    fn parse(toml: &str) -> DocumentMut {
+        toml.parse().unwrap()
+    }
+
+    #[test]
+    fn test_renamed_key_moves_and_keeps_other_comments() {
+        let mut document = parse("[release]\nold-name = \"x\"\n# kept\nother = 1\n");
+
+        let migrations = migrate_keys(&mut document, RENAMED).unwrap();
+
+        assert_eq!(migrations, vec![ConfigMigration {
+            old_key: "release.old-name".to_string(),
+            new_key: "git.new-name".to_string(),
+            since: "1.0.0".to_string(),
+        }]);
+        assert!(get_item(document.as_table(), "release.old-name").is_none());
+        assert_eq!(get_item(document.as_table(), "git.new-name").and_then(Item::as_str), Some("x"));
+        assert_eq!(get_item(document.as_table(), "release.other").and_then(Item::as_integer), Some(1));
+        assert!(document.to_string().contains("# kept"));
+    }
+
+    #[test]
+    fn test_old_and_new_key_together_is_an_error() {
+        let mut document = parse("[release]\nold-name = \"x\"\n[git]\nnew-name = \"y\"\n");
+        assert!(migrate_keys(&mut document, RENAMED).is_err());
+    }
+
+    #[test]
+    fn test_current_keys_are_left_alone() {
+        let toml = "[git]\ntag-template = \"v{version}\"\nrelease-branches = [\"main\"]\n";
+        let mut document = parse(toml);
+
+        assert!(migrate_keys(&mut document, RENAMED).unwrap().is_empty());
+        assert!(migrate_document(&mut document).unwrap().is_empty());
+        assert_eq!(document.to_string(), toml);
+    }
+}
@@ -0,0 +1,40 @@
+//! Project-level release configuration.
+//!
+//! This module loads the optional `release.toml` file that lives next to the
+//! workspace root `Cargo.toml` and exposes its settings to the rest of the
+//! release pipeline.
+
+mod migrate;
+mod project;
+mod schedule;
+
+pub use migrate::{
+    migrate_config_file, ConfigMigration, MigrationReport, RenamedKey, RENAMED_KEYS,
+};
+pub use project::{
+    ProjectConfig, ReleaseSection, AdvisoriesSection, AdvisoryAction, DocsRsSection, MetricsSection, NotifySection, OwnersSection,
+    PackageContentsSection, PackageOverride, StateBackend, StateSection, ValidationSection, VersioningMode, GitSection, DEFAULT_CONFIG_FILE_NAME,
+    DEFAULT_MAINTENANCE_BRANCH_TEMPLATE,
+};
+pub use schedule::{ActiveFreeze, FreezeSpan, FreezeWindow, ScheduleSection};
+
+use crate::error::Result;
+use std::path::{Path, PathBuf};
+
+/// Path of the configuration file for a workspace (explicit or default location)
+pub fn project_config_path<P: AsRef<Path>>(workspace_root: P, explicit_path: Option<&Path>) -> PathBuf {
+    explicit_path
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| workspace_root.as_ref().join(DEFAULT_CONFIG_FILE_NAME))
+}
+
+/// Load project configuration for a workspace.
+///
+/// An explicitly provided path must exist; otherwise `release.toml` in the
+/// workspace root is used when present, falling back to defaults.
+pub fn load_project_config<P: AsRef<Path>>(
+    workspace_root: P,
+    explicit_path: Option<&Path>,
+) -> Result<ProjectConfig> {
+    ProjectConfig::discover(workspace_root, explicit_path)
+}
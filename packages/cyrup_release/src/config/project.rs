@@ -0,0 +1,672 @@
+//! `release.toml` parsing and validation.
+
+use crate::config::migrate::{migrate_document, ConfigMigration};
+use crate::config::schedule::ScheduleSection;
+use crate::error::{ConfigError, Result};
+use crate::git::{
+    render_template, validate_template_for, CredentialSource, Credentials, GitConfig, TemplateContext, TemplateKind, DEFAULT_PACKAGE_TAG_TEMPLATE,
+    DEFAULT_TAG_TEMPLATE, BRANCH_TEMPLATE_VARIABLES, MESSAGE_TEMPLATE_VARIABLES, PACKAGE_TAG_TEMPLATE_VARIABLES, TAG_TEMPLATE_VARIABLES,
+};
+use crate::metrics::ExporterKind;
+use crate::notify::WebhookConfig;
+use crate::workspace::{
+    DEFAULT_ADVISORY_DB_URL, DEFAULT_DENY_PATTERNS, DEFAULT_LARGE_FILE_SIZE, DEFAULT_MAX_PACKAGE_SIZE,
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Default file name for project configuration in the workspace root
+pub const DEFAULT_CONFIG_FILE_NAME: &str = "release.toml";
+
+/// Default maintenance branch template for hotfix releases
+pub const DEFAULT_MAINTENANCE_BRANCH_TEMPLATE: &str = "release/{major}.{minor}";
+
+/// Project-level release configuration loaded from `release.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Release policy settings
+    pub release: ReleaseSection,
+    /// Git policy settings
+    pub git: GitSection,
+    /// Post-release documentation build verification
+    pub docs_rs: DocsRsSection,
+    /// Release event notifications
+    pub notify: NotifySection,
+    /// Expected crates.io owners of workspace packages
+    pub owners: OwnersSection,
+    /// Size and content checks for packaged crates
+    pub package_contents: PackageContentsSection,
+    /// Release freeze windows
+    pub schedule: ScheduleSection,
+    /// Optional validation checks
+    pub validation: ValidationSection,
+    /// Security advisory checks for dependencies
+    pub advisories: AdvisoriesSection,
+    /// Shared storage for release state
+    pub state: StateSection,
+    /// Release metrics export
+    pub metrics: MetricsSection,
+    /// Per-package publish overrides (`[package.<name>]`)
+    #[serde(rename = "package")]
+    pub packages: BTreeMap<String, PackageOverride>,
+    /// Path the configuration was loaded from (if any)
+    #[serde(skip)]
+    pub source_path: Option<PathBuf>,
+    /// Deprecated keys that were migrated while loading
+    #[serde(skip)]
+    pub migrations: Vec<ConfigMigration>,
+}
+
+/// `[release]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ReleaseSection {
+    /// How package versions relate to each other
+    pub versioning: VersioningMode,
+    /// Whether to refresh and verify an existing Cargo.lock after bumping versions
+    pub update_lockfile: bool,
+}
+
+impl Default for ReleaseSection {
+    fn default() -> Self {
+        Self {
+            versioning: VersioningMode::default(),
+            update_lockfile: true,
+        }
+    }
+}
+
+/// How versions are assigned to workspace members
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VersioningMode {
+    /// All members share the workspace version and a single release tag
+    #[default]
+    Unified,
+    /// Each member is bumped from its own version and tagged separately
+    Independent,
+}
+
+/// `[docs-rs]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct DocsRsSection {
+    /// Whether to wait for docs.rs builds after publishing
+    pub verify: bool,
+    /// How long to wait for builds to finish, in seconds
+    pub window_secs: u64,
+    /// Delay between status checks, in seconds
+    pub poll_interval_secs: u64,
+}
+
+impl Default for DocsRsSection {
+    fn default() -> Self {
+        Self {
+            verify: false,
+            window_secs: 1800,
+            poll_interval_secs: 60,
+        }
+    }
+}
+
+/// `[notify]` section of the project configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct NotifySection {
+    /// Webhooks that receive release events (`[[notify.webhooks]]`)
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+/// `[owners]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct OwnersSection {
+    /// Owners every published package should have (e.g. `github:org:team`)
+    pub expected: Vec<String>,
+    /// Whether validation checks name availability and publish rights on the registry
+    pub check_publish_rights: bool,
+}
+
+impl Default for OwnersSection {
+    fn default() -> Self {
+        Self {
+            expected: Vec::new(),
+            check_publish_rights: true,
+        }
+    }
+}
+
+/// `[validation]` section of the project configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ValidationSection {
+    /// Whether to build each package's documentation, failing on broken intra-doc links
+    pub docs: bool,
+    /// Whether to run each package's doctests
+    pub doctests: bool,
+    /// Nightly features packages may enable (supports `*` and `?` globs)
+    pub allowed_nightly_features: Vec<String>,
+    /// Whether `[patch]` entries pointing at workspace members block the release
+    /// instead of only warning
+    pub deny_internal_patches: bool,
+}
+
+/// `[package.<name>]` publish overrides for one workspace package
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PackageOverride {
+    /// Never publish this package (its version is still bumped)
+    pub skip: bool,
+    /// Registry to publish this package to, instead of `--registry`
+    pub registry: Option<String>,
+    /// Extra arguments passed to `cargo publish` for this package
+    pub extra_flags: Vec<String>,
+}
+
+/// `[advisories]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AdvisoriesSection {
+    /// Whether to check dependencies against the RustSec advisory database
+    pub check: bool,
+    /// Whether vulnerable dependencies block the release or only warn
+    pub action: AdvisoryAction,
+    /// Advisory IDs or aliases to ignore (e.g. `RUSTSEC-2023-0071`)
+    pub ignore: Vec<String>,
+    /// Local copy of the advisory database (defaults to `$CARGO_HOME/advisory-db`)
+    pub db_path: Option<PathBuf>,
+    /// Git URL the advisory database is fetched from
+    pub db_url: String,
+    /// Whether to fetch the latest advisories before checking
+    pub fetch: bool,
+}
+
+impl Default for AdvisoriesSection {
+    fn default() -> Self {
+        Self {
+            check: true,
+            action: AdvisoryAction::default(),
+            ignore: Vec::new(),
+            db_path: None,
+            db_url: DEFAULT_ADVISORY_DB_URL.to_string(),
+            fetch: true,
+        }
+    }
+}
+
+/// What to do when a dependency has a known vulnerability
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AdvisoryAction {
+    /// Block the release
+    #[default]
+    Deny,
+    /// Report a warning and continue
+    Warn,
+}
+
+/// `[state]` section of the project configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct StateSection {
+    /// Where release state is shared so another machine can resume the release
+    pub backend: StateBackend,
+    /// Directory for the `file` backend
+    pub path: Option<PathBuf>,
+    /// Base URL for the `http` backend
+    pub url: Option<String>,
+    /// Bucket for the `s3` backend
+    pub bucket: Option<String>,
+    /// Key prefix inside the bucket (e.g. the repository name)
+    pub prefix: Option<String>,
+    /// Signing region (defaults to `AWS_REGION`, then `us-east-1`)
+    pub region: Option<String>,
+    /// S3-compatible endpoint (e.g. `https://storage.googleapis.com` for GCS)
+    pub endpoint: Option<String>,
+}
+
+/// Storage backend for shared release state
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StateBackend {
+    /// State stays in the local state file only
+    #[default]
+    Local,
+    /// A directory, such as a shared mount or CI cache
+    File,
+    /// `GET`/`PUT`/`DELETE` requests below a base URL
+    Http,
+    /// An S3 or S3-compatible bucket
+    S3,
+}
+
+/// `[metrics]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct MetricsSection {
+    /// Where release metrics are sent
+    pub exporter: ExporterKind,
+    /// Pushgateway URL, or OTLP collector URL (defaults to `OTEL_EXPORTER_OTLP_ENDPOINT`)
+    pub endpoint: Option<String>,
+    /// Pushgateway job name and OTLP `service.name`
+    pub job: String,
+    /// Pushgateway grouping labels and OTLP resource attributes (e.g. the repository)
+    pub labels: BTreeMap<String, String>,
+}
+
+impl Default for MetricsSection {
+    fn default() -> Self {
+        Self {
+            exporter: ExporterKind::default(),
+            endpoint: None,
+            job: "cyrup_release".to_string(),
+            labels: BTreeMap::new(),
+        }
+    }
+}
+
+/// `[package-contents]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PackageContentsSection {
+    /// Whether to inspect package contents during validation
+    pub check: bool,
+    /// Maximum compressed package size in bytes
+    pub max_size: u64,
+    /// Size in bytes above which a single file is flagged
+    pub large_file_size: u64,
+    /// Glob patterns for files that must not be published
+    pub deny: Vec<String>,
+}
+
+impl Default for PackageContentsSection {
+    fn default() -> Self {
+        Self {
+            check: true,
+            max_size: DEFAULT_MAX_PACKAGE_SIZE,
+            large_file_size: DEFAULT_LARGE_FILE_SIZE,
+            deny: DEFAULT_DENY_PATTERNS.iter().map(|pattern| pattern.to_string()).collect(),
+        }
+    }
+}
+
+/// `[git]` section of the project configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct GitSection {
+    /// Branch name patterns releases may be cut from (supports `*` and `?` globs)
+    pub release_branches: Vec<String>,
+    /// Remote used for upstream and remote tag checks
+    pub remote: String,
+    /// Whether to verify the local branch is not behind its upstream
+    pub check_upstream: bool,
+    /// Whether to verify the release tag does not already exist on the remote
+    pub check_remote_tag: bool,
+    /// Tag name template (e.g. `v{version}` or `{package}-v{version}`)
+    pub tag_template: String,
+    /// Per-package tag name template used in independent versioning mode
+    pub package_tag_template: String,
+    /// Release commit message template (defaults to `release: v{version}`)
+    pub commit_message: Option<String>,
+    /// Annotated tag message template (defaults to `Release v{version}`)
+    pub tag_message: Option<String>,
+    /// Whether to sign release tags with the configured signing key
+    pub sign_tags: bool,
+    /// Whether rollback reverts the release commit (a new commit) instead of resetting the branch
+    pub revert_on_rollback: bool,
+    /// Notes ref each release is recorded under (e.g. `refs/notes/releases`), pushed with the release
+    pub notes_ref: Option<String>,
+    /// Whether validation requires HEAD and the latest release tags to be signed by a trusted key
+    pub verify_signatures: bool,
+    /// GnuPG home holding the keyring trusted for signature verification (relative to the workspace root)
+    pub gpg_home: Option<PathBuf>,
+    /// SSH allowed signers file trusted for signature verification (relative to the workspace root)
+    pub allowed_signers: Option<PathBuf>,
+    /// Maintenance branch name template for hotfix releases (e.g. `release/{major}.{minor}`)
+    pub maintenance_branch: String,
+    /// Seconds to wait for the remote when querying its refs
+    pub remote_timeout: u64,
+    /// Credential sources asked in order for pushes, fetches, and remote queries
+    /// (`env`, `ssh-agent`, `keychain`, `helper`, `helper:<name>`)
+    pub credentials: Vec<CredentialSource>,
+}
+
+impl Default for GitSection {
+    fn default() -> Self {
+        Self {
+            release_branches: vec!["main".to_string(), "master".to_string()],
+            remote: "origin".to_string(),
+            check_upstream: true,
+            check_remote_tag: true,
+            tag_template: DEFAULT_TAG_TEMPLATE.to_string(),
+            package_tag_template: DEFAULT_PACKAGE_TAG_TEMPLATE.to_string(),
+            commit_message: None,
+            tag_message: None,
+            sign_tags: false,
+            revert_on_rollback: false,
+            notes_ref: None,
+            verify_signatures: false,
+            gpg_home: None,
+            allowed_signers: None,
+            maintenance_branch: DEFAULT_MAINTENANCE_BRANCH_TEMPLATE.to_string(),
+            remote_timeout: 30,
+            credentials: CredentialSource::defaults(),
+        }
+    }
+}
+
+impl ProjectConfig {
+    /// Load configuration from a specific file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(ConfigError::NotFound {
+                path: path.to_path_buf(),
+            }.into());
+        }
+
+        let content = std::fs::read_to_string(path)?;
+        let parse_failed = |reason: String| ConfigError::ParseFailed {
+            path: path.to_path_buf(),
+            reason,
+        };
+
+        // Move deprecated keys to their current names before deserializing
+        let mut document = content.parse::<toml_edit::DocumentMut>()
+            .map_err(|e| parse_failed(e.to_string()))?;
+        let migrations = migrate_document(&mut document)?;
+
+        let mut config: ProjectConfig = toml::from_str(&document.to_string())
+            .map_err(|e| parse_failed(e.to_string()))?;
+
+        config.source_path = Some(path.to_path_buf());
+        config.migrations = migrations;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Locate and load configuration for a workspace root
+    pub fn discover<P: AsRef<Path>>(workspace_root: P, explicit_path: Option<&Path>) -> Result<Self> {
+        if let Some(path) = explicit_path {
+            return Self::load(path);
+        }
+
+        let default_path = workspace_root.as_ref().join(DEFAULT_CONFIG_FILE_NAME);
+        if default_path.exists() {
+            Self::load(default_path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Check if a package is excluded from publishing by `[package.<name>] skip`
+    pub fn is_publish_skipped(&self, package_name: &str) -> bool {
+        self.packages.get(package_name).is_some_and(|overrides| overrides.skip)
+    }
+
+    /// Registry a package is published to, preferring its `[package.<name>]` override
+    pub fn package_registry<'a>(&'a self, package_name: &str, default: Option<&'a str>) -> Option<&'a str> {
+        self.packages.get(package_name)
+            .and_then(|overrides| overrides.registry.as_deref())
+            .or(default)
+    }
+
+    /// Validate configuration values
+    pub fn validate(&self) -> Result<()> {
+        for pattern in &self.git.release_branches {
+            glob::Pattern::new(pattern).map_err(|e| ConfigError::InvalidValue {
+                key: "git.release-branches".to_string(),
+                reason: format!("Invalid branch pattern '{}': {}", pattern, e),
+            })?;
+        }
+
+        for pattern in &self.package_contents.deny {
+            glob::Pattern::new(pattern).map_err(|e| ConfigError::InvalidValue {
+                key: "package-contents.deny".to_string(),
+                reason: format!("Invalid deny pattern '{}': {}", pattern, e),
+            })?;
+        }
+
+        for pattern in &self.validation.allowed_nightly_features {
+            glob::Pattern::new(pattern).map_err(|e| ConfigError::InvalidValue {
+                key: "validation.allowed-nightly-features".to_string(),
+                reason: format!("Invalid feature pattern '{}': {}", pattern, e),
+            })?;
+        }
+
+        if self.package_contents.max_size == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "package-contents.max-size".to_string(),
+                reason: "must be greater than zero".to_string(),
+            }.into());
+        }
+
+        validate_template_for(&self.git.tag_template, TemplateKind::TagName, TAG_TEMPLATE_VARIABLES)
+            .map_err(|e| ConfigError::InvalidValue {
+                key: "git.tag-template".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        validate_template_for(&self.git.package_tag_template, TemplateKind::TagName, PACKAGE_TAG_TEMPLATE_VARIABLES)
+            .map_err(|e| ConfigError::InvalidValue {
+                key: "git.package-tag-template".to_string(),
+                reason: e.to_string(),
+            })?;
+        if !self.git.package_tag_template.contains("{package}") {
+            return Err(ConfigError::InvalidValue {
+                key: "git.package-tag-template".to_string(),
+                reason: "per-package tag template must include '{package}'".to_string(),
+            }.into());
+        }
+
+        validate_template_for(&self.git.maintenance_branch, TemplateKind::Message, BRANCH_TEMPLATE_VARIABLES)
+            .map_err(|e| ConfigError::InvalidValue {
+                key: "git.maintenance-branch".to_string(),
+                reason: e.to_string(),
+            })?;
+        if !self.git.maintenance_branch.contains("{major}") {
+            return Err(ConfigError::InvalidValue {
+                key: "git.maintenance-branch".to_string(),
+                reason: "maintenance branch template must include '{major}'".to_string(),
+            }.into());
+        }
+
+        if self.git.remote_timeout == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "git.remote-timeout".to_string(),
+                reason: "must be greater than zero".to_string(),
+            }.into());
+        }
+
+        let message_templates = [
+            ("git.commit-message", &self.git.commit_message),
+            ("git.tag-message", &self.git.tag_message),
+        ];
+        for (key, template) in message_templates {
+            if let Some(template) = template {
+                validate_template_for(template, TemplateKind::Message, MESSAGE_TEMPLATE_VARIABLES)
+                    .map_err(|e| ConfigError::InvalidValue {
+                        key: key.to_string(),
+                        reason: e.to_string(),
+                    })?;
+            }
+        }
+
+        if self.docs_rs.poll_interval_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "docs-rs.poll-interval-secs".to_string(),
+                reason: "Poll interval must be at least 1 second".to_string(),
+            }.into());
+        }
+
+        for webhook in &self.notify.webhooks {
+            reqwest::Url::parse(&webhook.url).map_err(|e| ConfigError::InvalidValue {
+                key: "notify.webhooks.url".to_string(),
+                reason: format!("Invalid webhook URL '{}': {}", webhook.url, e),
+            })?;
+        }
+
+        for window in &self.schedule.freeze {
+            window.span()?;
+        }
+
+        let state_urls = [("state.url", &self.state.url), ("state.endpoint", &self.state.endpoint)];
+        for (key, url) in state_urls {
+            if let Some(url) = url {
+                reqwest::Url::parse(url).map_err(|e| ConfigError::InvalidValue {
+                    key: key.to_string(),
+                    reason: format!("Invalid URL '{}': {}", url, e),
+                })?;
+            }
+        }
+
+        if let Some(endpoint) = &self.metrics.endpoint {
+            reqwest::Url::parse(endpoint).map_err(|e| ConfigError::InvalidValue {
+                key: "metrics.endpoint".to_string(),
+                reason: format!("Invalid URL '{}': {}", endpoint, e),
+            })?;
+        }
+        if self.metrics.exporter == ExporterKind::Prometheus && self.metrics.endpoint.is_none() {
+            return Err(ConfigError::InvalidValue {
+                key: "metrics.endpoint".to_string(),
+                reason: "required by the 'prometheus' exporter".to_string(),
+            }.into());
+        }
+        if self.metrics.job.trim().is_empty() {
+            return Err(ConfigError::InvalidValue {
+                key: "metrics.job".to_string(),
+                reason: "Job name cannot be empty".to_string(),
+            }.into());
+        }
+        for name in self.metrics.labels.keys() {
+            let mut chars = name.chars();
+            let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid || name == "job" {
+                return Err(ConfigError::InvalidValue {
+                    key: "metrics.labels".to_string(),
+                    reason: format!("Invalid label name '{}'", name),
+                }.into());
+            }
+        }
+
+        if self.git.remote.trim().is_empty() {
+            return Err(ConfigError::InvalidValue {
+                key: "git.remote".to_string(),
+                reason: "Remote name cannot be empty".to_string(),
+            }.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl GitSection {
+    /// Build the git manager configuration described by this section
+    pub fn git_config(&self) -> GitConfig {
+        GitConfig {
+            default_remote: self.remote.clone(),
+            tag_name_template: self.tag_template.clone(),
+            package_tag_template: self.package_tag_template.clone(),
+            commit_message_template: self.commit_message.clone(),
+            tag_message_template: self.tag_message.clone(),
+            sign_tags: self.sign_tags,
+            revert_on_rollback: self.revert_on_rollback,
+            verify_signatures: self.verify_signatures,
+            notes_ref: self.notes_ref.clone(),
+            credentials: self.credentials(),
+            ..GitConfig::default()
+        }
+    }
+
+    /// Credential chain for the configured sources
+    pub fn credentials(&self) -> Credentials {
+        Credentials::from_sources(&self.credentials)
+    }
+
+    /// How long to wait for the remote when querying its refs
+    pub fn remote_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.remote_timeout)
+    }
+
+    /// Name of the maintenance branch for a release line
+    pub fn maintenance_branch_name(&self, version: &Version) -> Result<String> {
+        render_template(&self.maintenance_branch, &TemplateContext::new(version))
+    }
+
+    /// Check if a branch name is allowed by the release branch patterns.
+    ///
+    /// An empty pattern list allows every branch. Maintenance branches are
+    /// always allowed, so hotfixes can be released from them.
+    pub fn is_release_branch(&self, branch_name: &str) -> bool {
+        if self.release_branches.is_empty() || self.is_maintenance_branch(branch_name) {
+            return true;
+        }
+
+        self.release_branches.iter().any(|pattern| {
+            glob::Pattern::new(pattern)
+                .map(|p| p.matches(branch_name))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Check if a branch name matches the maintenance branch template
+    pub fn is_maintenance_branch(&self, branch_name: &str) -> bool {
+        let pattern = ["{version}", "{major}", "{minor}", "{patch}"]
+            .iter()
+            .fold(glob::Pattern::escape(&self.maintenance_branch), |pattern, variable| {
+                pattern.replace(&glob::Pattern::escape(variable), "*")
+            });
+
+        glob::Pattern::new(&pattern)
+            .map(|p| p.matches(branch_name))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        ProjectConfig::default().validate().unwrap();
+    }
+
+    #[test]
+    fn test_package_placeholder_in_workspace_tag_template_is_rejected() {
+        let mut config = ProjectConfig::default();
+        config.git.tag_template = "{package}-v{version}".to_string();
+
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("git.tag-template"), "{}", error);
+    }
+
+    #[test]
+    fn test_release_branch_patterns() {
+        let mut git = GitSection {
+            release_branches: vec!["main".to_string(), "release-*".to_string(), "v?".to_string()],
+            ..GitSection::default()
+        };
+        assert!(git.is_release_branch("main"));
+        assert!(git.is_release_branch("release-2024"));
+        assert!(git.is_release_branch("v2"));
+        assert!(!git.is_release_branch("v10"));
+        assert!(!git.is_release_branch("feature/main"));
+
+        // Maintenance branches are allowed whatever the patterns say
+        assert!(git.is_maintenance_branch("release/1.2"));
+        assert!(git.is_release_branch("release/1.2"));
+        assert!(!git.is_maintenance_branch("release/next"));
+        assert!(!git.is_release_branch("release/next"));
+
+        // An invalid pattern matches nothing, and no patterns allow everything
+        git.release_branches = vec!["[".to_string()];
+        assert!(!git.is_release_branch("["));
+        git.release_branches.clear();
+        assert!(git.is_release_branch("feature/anything"));
+    }
+}
@@ -0,0 +1,274 @@
+//! Release windows and freeze periods.
+//!
+//! The `[schedule]` section lists freeze windows during which releases are
+//! blocked, either recurring every week (`"Fri 18:00"` to `"Mon 08:00"`) or
+//! between two dates (`"2026-12-20 00:00"` to `"2027-01-04 08:00"`). All
+//! times are UTC.
+
+use crate::error::{ConfigError, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, NaiveTime, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Minutes in a week, used to wrap recurring windows
+const MINUTES_PER_WEEK: i64 = 7 * 24 * 60;
+
+/// `[schedule]` section of the project configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ScheduleSection {
+    /// Check freeze windows on every release, not only with `--schedule`
+    pub enforce: bool,
+    /// Periods during which releases are blocked (`[[schedule.freeze]]`)
+    pub freeze: Vec<FreezeWindow>,
+}
+
+/// A period during which releases are blocked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct FreezeWindow {
+    /// Name shown when the freeze blocks a release
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Start of the window (`"Fri 18:00"` or `"2026-12-20 00:00"`, UTC)
+    pub start: String,
+    /// End of the window, exclusive (same format as `start`)
+    pub end: String,
+}
+
+/// Parsed bounds of a freeze window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FreezeSpan {
+    /// Recurs every week; bounds are minutes since Monday 00:00
+    Weekly {
+        /// Start, in minutes since Monday 00:00
+        start: i64,
+        /// End, in minutes since Monday 00:00 (may be before `start` to wrap the week)
+        end: i64,
+    },
+    /// A single period between two instants
+    Once {
+        /// Start of the period
+        start: DateTime<Utc>,
+        /// End of the period
+        end: DateTime<Utc>,
+    },
+}
+
+/// A freeze window in effect at a given time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveFreeze {
+    /// Name of the window
+    pub name: String,
+    /// When the window ends and releases are allowed again
+    pub until: DateTime<Utc>,
+}
+
+impl ScheduleSection {
+    /// Find the freeze window in effect at `now`, if any.
+    ///
+    /// When windows overlap, the one ending last is reported.
+    pub fn active_freeze(&self, now: DateTime<Utc>) -> Result<Option<ActiveFreeze>> {
+        let mut active: Option<ActiveFreeze> = None;
+        for window in &self.freeze {
+            if let Some(until) = window.span()?.active_until(now)
+                && active.as_ref().is_none_or(|active| until > active.until)
+            {
+                active = Some(ActiveFreeze {
+                    name: window.display_name(),
+                    until,
+                });
+            }
+        }
+
+        Ok(active)
+    }
+}
+
+impl FreezeWindow {
+    /// Name of the window, falling back to its bounds
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("{} – {}", self.start, self.end))
+    }
+
+    /// Parse the window bounds
+    pub fn span(&self) -> Result<FreezeSpan> {
+        match (parse_weekly(&self.start), parse_weekly(&self.end)) {
+            (Some(start), Some(end)) if start == end => Err(invalid_window(self, "start and end are the same time")),
+            (Some(start), Some(end)) => Ok(FreezeSpan::Weekly { start, end }),
+            (None, None) => {
+                let start = parse_date(&self.start).ok_or_else(|| invalid_bound(self, &self.start))?;
+                let end = parse_date(&self.end).ok_or_else(|| invalid_bound(self, &self.end))?;
+                if end <= start {
+                    return Err(invalid_window(self, "end must be after start"));
+                }
+                Ok(FreezeSpan::Once { start, end })
+            }
+            _ => Err(invalid_window(self, "start and end must both be weekdays or both be dates")),
+        }
+    }
+}
+
+impl FreezeSpan {
+    /// End of the window if `now` falls inside it
+    pub fn active_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match *self {
+            FreezeSpan::Weekly { start, end } => {
+                let now_minute = minute_of_week(now);
+                let active = if start < end {
+                    (start..end).contains(&now_minute)
+                } else {
+                    now_minute >= start || now_minute < end
+                };
+                active.then(|| {
+                    let minutes_left = (end - now_minute).rem_euclid(MINUTES_PER_WEEK);
+                    let current_minute = now.with_second(0).and_then(|now| now.with_nanosecond(0)).unwrap_or(now);
+                    current_minute + Duration::minutes(minutes_left)
+                })
+            }
+            FreezeSpan::Once { start, end } => (start..end).contains(&now).then_some(end),
+        }
+    }
+}
+
+/// Parse a weekly bound such as `Fri 18:00` into minutes since Monday 00:00
+fn parse_weekly(value: &str) -> Option<i64> {
+    let (day, time) = value.trim().split_once(char::is_whitespace)?;
+    let day: Weekday = day.parse().ok()?;
+    let time = NaiveTime::parse_from_str(time.trim(), "%H:%M").ok()?;
+    Some(i64::from(day.num_days_from_monday()) * 24 * 60 + i64::from(time.hour() * 60 + time.minute()))
+}
+
+/// Parse a date bound such as `2026-12-20 00:00` or `2026-12-20` (UTC)
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M")
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|date_time| date_time.and_utc())
+}
+
+/// Minutes since Monday 00:00 UTC
+fn minute_of_week(now: DateTime<Utc>) -> i64 {
+    i64::from(now.weekday().num_days_from_monday()) * 24 * 60 + i64::from(now.hour() * 60 + now.minute())
+}
+
+/// Error for a window that cannot be used
+fn invalid_window(window: &FreezeWindow, reason: &str) -> crate::error::ReleaseError {
+    ConfigError::InvalidValue {
+        key: "schedule.freeze".to_string(),
+        reason: format!("Freeze window '{}': {}", window.display_name(), reason),
+    }.into()
+}
+
+/// Error for a bound that is neither a weekday time nor a date
+fn invalid_bound(window: &FreezeWindow, value: &str) -> crate::error::ReleaseError {
+    invalid_window(
+        window,
+        &format!("'{}' is not a weekday time ('Fri 18:00') or date ('2026-12-20 00:00')", value),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn window(start: &str, end: &str) -> FreezeWindow {
+        FreezeWindow {
+            name: None,
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, day, hour, minute, 0).unwrap()
+    }
+
+    fn error(window: &FreezeWindow) -> String {
+        window.span().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_weekend_freeze_bounds() {
+        // 2026-10-16 is a Friday
+        let span = window("Fri 18:00", "Mon 08:00").span().unwrap();
+        assert_eq!(span, FreezeSpan::Weekly { start: 4 * 24 * 60 + 18 * 60, end: 8 * 60 });
+        let monday_morning = at(19, 8, 0);
+
+        assert_eq!(span.active_until(at(16, 17, 59)), None);
+        assert_eq!(span.active_until(at(16, 18, 0)), Some(monday_morning));
+        assert_eq!(span.active_until(at(18, 12, 0)), Some(monday_morning));
+        assert_eq!(span.active_until(at(19, 7, 59)), Some(monday_morning));
+        // The end is exclusive
+        assert_eq!(span.active_until(at(19, 8, 0)), None);
+        assert_eq!(span.active_until(at(21, 12, 0)), None);
+    }
+
+    #[test]
+    fn test_weekly_freeze_within_a_day() {
+        let span = window("Wed 09:00", "Wed 17:30").span().unwrap();
+        let until = at(21, 17, 30);
+        assert_eq!(span.active_until(at(21, 8, 59)), None);
+        // Seconds into the current minute do not shift the end
+        assert_eq!(span.active_until(at(21, 9, 0) + Duration::seconds(42)), Some(until));
+        assert_eq!(span.active_until(at(21, 17, 29)), Some(until));
+        assert_eq!(span.active_until(at(21, 17, 30)), None);
+    }
+
+    #[test]
+    fn test_one_off_freeze_bounds() {
+        let span = window("2026-12-20", "2027-01-04 08:00").span().unwrap();
+        let start = Utc.with_ymd_and_hms(2026, 12, 20, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2027, 1, 4, 8, 0, 0).unwrap();
+        assert_eq!(span, FreezeSpan::Once { start, end });
+
+        assert_eq!(span.active_until(start - Duration::seconds(1)), None);
+        assert_eq!(span.active_until(start), Some(end));
+        assert_eq!(span.active_until(Utc.with_ymd_and_hms(2026, 12, 25, 12, 0, 0).unwrap()), Some(end));
+        assert_eq!(span.active_until(end), None);
+    }
+
+    #[test]
+    fn test_invalid_windows() {
+        assert!(error(&window("Fri 18:00", "fri 18:00")).contains("start and end are the same time"));
+        assert!(error(&window("2026-12-20 00:00", "2026-12-20")).contains("end must be after start"));
+        assert!(error(&window("2027-01-04", "2026-12-20")).contains("end must be after start"));
+        assert!(error(&window("Fri 18:00", "2026-12-20 00:00")).contains("both be weekdays or both be dates"));
+        assert!(error(&window("2026-12-20", "Mon 08:00")).contains("both be weekdays or both be dates"));
+        assert!(error(&window("Someday", "Never")).contains("'Someday' is not a weekday time"));
+        assert!(error(&window("Fri 25:00", "Mon 08:00")).contains("both be weekdays or both be dates"));
+    }
+
+    #[test]
+    fn test_active_freeze_reports_the_latest_end() {
+        let schedule = ScheduleSection {
+            enforce: true,
+            freeze: vec![
+                window("Fri 18:00", "Mon 08:00"),
+                FreezeWindow {
+                    name: Some("Holidays".to_string()),
+                    ..window("2026-10-01", "2026-11-01")
+                },
+            ],
+        };
+        assert_eq!(schedule.active_freeze(at(17, 12, 0)).unwrap(), Some(ActiveFreeze {
+            name: "Holidays".to_string(),
+            until: Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap(),
+        }));
+
+        let schedule = ScheduleSection {
+            freeze: vec![window("Fri 18:00", "Mon 08:00")],
+            ..ScheduleSection::default()
+        };
+        assert_eq!(schedule.active_freeze(at(17, 12, 0)).unwrap(), Some(ActiveFreeze {
+            name: "Fri 18:00 – Mon 08:00".to_string(),
+            until: at(19, 8, 0),
+        }));
+        assert_eq!(schedule.active_freeze(at(20, 12, 0)).unwrap(), None);
+    }
+}
@@ -35,6 +35,18 @@ pub enum ReleaseError {
     #[error("CLI error: {0}")]
     Cli(#[from] CliError),
 
+    /// Configuration file errors
+    #[error("Config error: {0}")]
+    Config(#[from] ConfigError),
+
+    /// Notification delivery errors
+    #[error("Notification error: {0}")]
+    Notify(#[from] NotifyError),
+
+    /// Metrics export errors
+    #[error("Metrics error: {0}")]
+    Metrics(#[from] MetricsError),
+
     /// IO errors
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -74,10 +86,10 @@ pub enum WorkspaceError {
     },
 
     /// Circular dependency detected
-    #[error("Circular dependency detected in packages: {packages:?}")]
+    #[error("Circular dependency detected: {}", packages.join(" → "))]
     CircularDependency {
-        /// List of packages involved in the circular dependency
-        packages: Vec<String>
+        /// Packages along the cycle, starting and ending with the same package
+        packages: Vec<String>,
     },
 
     /// Missing Cargo.toml file
@@ -95,6 +107,27 @@ pub enum WorkspaceError {
         /// Reason for the invalid configuration
         reason: String
     },
+
+    /// Workspace failed to build after the version bump
+    #[error("Workspace failed to build: {reason}")]
+    BuildFailed {
+        /// Compiler output or reason for the failure
+        reason: String
+    },
+
+    /// RustSec advisory database could not be fetched or read
+    #[error("Advisory database unavailable: {reason}")]
+    AdvisoryDatabase {
+        /// Reason the database is unavailable
+        reason: String
+    },
+
+    /// Software bill of materials could not be generated
+    #[error("Failed to generate SBOM: {reason}")]
+    SbomFailed {
+        /// Reason generation failed
+        reason: String
+    },
 }
 
 /// Version management errors
@@ -147,6 +180,51 @@ pub enum VersionError {
         /// The current version
         version: String
     },
+
+    /// Failed to refresh Cargo.lock
+    #[error("Failed to update Cargo.lock: {reason}")]
+    LockfileUpdateFailed {
+        /// Reason for the update failure
+        reason: String
+    },
+
+    /// Cargo.lock does not match the workspace versions
+    #[error("Cargo.lock records '{package}' at {found}, expected {expected}")]
+    LockfileMismatch {
+        /// Package with the mismatched version
+        package: String,
+        /// Version in Cargo.toml
+        expected: String,
+        /// Version recorded in Cargo.lock
+        found: String,
+    },
+
+    /// Failed to read internal path dependencies
+    #[error("Failed to check internal path dependencies: {reason}")]
+    PathDependencyCheckFailed {
+        /// Reason for the failure
+        reason: String
+    },
+
+    /// Members cannot be migrated to workspace inheritance
+    #[error("Cannot migrate to workspace inheritance: {reason}")]
+    InheritanceMigrationFailed {
+        /// Reason the migration cannot proceed
+        reason: String
+    },
+
+    /// Internal path dependency requirement does not match the dependency's version
+    #[error("'{package}' depends on '{dependency}' by path with {requirement}, but '{dependency}' is at {version}")]
+    PathDependencyMismatch {
+        /// Package declaring the dependency
+        package: String,
+        /// Workspace member depended on
+        dependency: String,
+        /// Declared version requirement
+        requirement: String,
+        /// Current version of the dependency
+        version: String,
+    },
 }
 
 /// Git operation errors
@@ -176,6 +254,33 @@ pub enum GitError {
         reason: String
     },
 
+    /// No repository was found where discovery looked
+    #[error("No git repository found ({strategy}): {reason}")]
+    DiscoveryFailed {
+        /// How the repository was looked for (e.g. `GIT_DIR=...`)
+        strategy: String,
+        /// Why it was not found
+        reason: String
+    },
+
+    /// The operation needs a working tree
+    #[error("Cannot {operation} in bare repository {path}: it has no working tree")]
+    BareRepository {
+        /// Operation that was refused
+        operation: String,
+        /// Git directory of the bare repository
+        path: PathBuf
+    },
+
+    /// The branch is checked out in another worktree
+    #[error("Branch {branch} is checked out in worktree {}", worktree.display())]
+    BranchCheckedOut {
+        /// Branch that was left alone
+        branch: String,
+        /// Worktree that has it checked out
+        worktree: PathBuf
+    },
+
     /// Remote operation failed
     #[error("Git remote operation failed: {operation} - {reason}")]
     RemoteOperationFailed {
@@ -185,6 +290,20 @@ pub enum GitError {
         reason: String
     },
 
+    /// Remote is not configured
+    #[error("Git remote '{remote}' does not exist")]
+    RemoteNotFound {
+        /// Remote name
+        remote: String
+    },
+
+    /// Remote is already configured
+    #[error("Git remote '{remote}' already exists")]
+    RemoteExists {
+        /// Remote name
+        remote: String
+    },
+
     /// Tag already exists
     #[error("Git tag '{tag}' already exists. Use --force to overwrite or choose a different version.")]
     TagExists {
@@ -212,6 +331,127 @@ pub enum GitError {
         /// Reason for push failure
         reason: String
     },
+
+    /// Tag or commit message template is invalid
+    #[error("Invalid template '{template}': {reason}")]
+    InvalidTemplate {
+        /// The template that failed to parse or render
+        template: String,
+        /// Reason the template is invalid
+        reason: String
+    },
+
+    /// A revision does not name a commit
+    #[error("Revision '{rev}' does not name a commit")]
+    RevisionNotFound {
+        /// The revision
+        rev: String
+    },
+
+    /// Resetting to the pre-release commit would discard work done after the release
+    #[error("Refusing to reset to {target}: {reason}")]
+    UnsafeReset {
+        /// Commit the reset would move HEAD to
+        target: String,
+        /// What the reset would discard
+        reason: String
+    },
+
+    /// Signing a tag failed
+    #[error("Failed to sign tag '{tag}': {reason}")]
+    SigningFailed {
+        /// The tag being created
+        tag: String,
+        /// Reason reported by git
+        reason: String
+    },
+
+    /// Cloning a repository failed
+    #[error("Git clone of '{url}' failed: {reason}")]
+    CloneFailed {
+        /// URL being cloned
+        url: String,
+        /// Reason reported by git
+        reason: String
+    },
+
+    /// Fetching from a remote failed
+    #[error("Git fetch from '{remote}' failed: {reason}")]
+    FetchFailed {
+        /// Remote that was fetched from
+        remote: String,
+        /// Reason for the failure
+        reason: String
+    },
+
+    /// A fetched ref does not exist on the remote
+    #[error("Ref '{refspec}' does not exist on remote '{remote}'")]
+    RemoteRefNotFound {
+        /// Remote that was fetched from
+        remote: String,
+        /// Refspec whose source was not found
+        refspec: String
+    },
+
+    /// The branch has no upstream tracking branch to pull from
+    #[error("Branch '{branch}' has no upstream tracking branch")]
+    NoUpstream {
+        /// Branch without an upstream
+        branch: String
+    },
+
+    /// Pull would need a merge but only fast-forwards were allowed
+    #[error("Cannot fast-forward '{branch}' to {upstream}: {ahead} local and {behind} upstream commit(s) have diverged")]
+    NonFastForward {
+        /// Local branch
+        branch: String,
+        /// Upstream tracking ref
+        upstream: String,
+        /// Commits only on the local branch
+        ahead: usize,
+        /// Commits only on the upstream
+        behind: usize
+    },
+
+    /// Applying a stash entry left conflicts; the entry was kept
+    #[error("Applying {stash} conflicts in: {}", files.join(", "))]
+    StashConflict {
+        /// Stash entry being applied
+        stash: String,
+        /// Files with conflicts
+        files: Vec<String>
+    },
+
+    /// A repository hook rejected a commit or push
+    #[error("git {operation} was rejected by the {hook} hook: {reason}")]
+    HookRejected {
+        /// `commit` or `push`
+        operation: String,
+        /// Hook that ran (e.g. `pre-commit`)
+        hook: String,
+        /// Output of the hook
+        reason: String
+    },
+
+    /// A cherry-pick, revert, or autosquash rebase left conflicts; it was aborted
+    #[error("git {operation} {commit} conflicts in: {}", files.join(", "))]
+    CommitConflict {
+        /// `cherry-pick`, `revert`, or `rebase`
+        operation: String,
+        /// Commit being applied, or the base of the rebase
+        commit: String,
+        /// Files with conflicts
+        files: Vec<String>
+    },
+
+    /// Merging the upstream left conflicts; the merge was aborted
+    #[error("Merging {upstream} conflicts in: {}", files.join(", "))]
+    MergeConflict {
+        /// Upstream tracking ref being merged
+        upstream: String,
+        /// Files with conflicts
+        files: Vec<String>
+    },
 }
 
 /// Publishing errors
@@ -272,6 +512,35 @@ pub enum PublishError {
         /// Reason for the yank failure
         reason: String,
     },
+
+    /// Crate name of a first release is already claimed on the registry
+    #[error("Crate name '{package}' is unavailable: the registry already has '{existing}'")]
+    NameUnavailable {
+        /// Name of the package
+        package: String,
+        /// Existing crate that claims the name
+        existing: String,
+    },
+
+    /// `cargo owner` failed for a package
+    #[error("Failed to manage owners of '{package}': {reason}")]
+    OwnerCommandFailed {
+        /// Name of the package
+        package: String,
+        /// Reason for the failure
+        reason: String,
+    },
+
+    /// Internal dependency requirement cannot be resolved against the registry
+    #[error("Dependency '{dependency}' of '{package}' cannot be resolved from the registry: {reason}")]
+    UnresolvableDependency {
+        /// Name of the package being published
+        package: String,
+        /// Name of the unresolvable dependency
+        dependency: String,
+        /// Reason the dependency cannot be resolved
+        reason: String,
+    },
 }
 
 /// State management errors
@@ -310,6 +579,22 @@ pub enum StateError {
         /// Reason for load failure
         reason: String
     },
+
+    /// Failed to encrypt or decrypt state
+    #[error("State encryption error: {reason}")]
+    Encryption {
+        /// Reason for encryption failure
+        reason: String
+    },
+
+    /// Shared state store could not be read or written
+    #[error("State store {store} failed: {reason}")]
+    RemoteStore {
+        /// Location of the store
+        store: String,
+        /// Reason for the failure
+        reason: String
+    },
 }
 
 /// CLI-specific errors
@@ -344,6 +629,107 @@ pub enum CliError {
         /// Reason for execution failure
         reason: String
     },
+
+    /// Deprecated flags or configuration keys used with --deny-deprecated
+    #[error("{count} deprecated flag(s) or config key(s) used with --deny-deprecated")]
+    DeprecatedUsage {
+        /// Number of deprecated items used
+        count: usize
+    },
+
+    /// A release freeze window is in effect
+    #[error("Releases are frozen by '{window}' until {until}")]
+    ReleaseFrozen {
+        /// Name of the freeze window
+        window: String,
+        /// When the freeze ends (UTC)
+        until: String
+    },
+}
+
+/// Configuration file errors
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    /// Configuration file not found
+    #[error("Configuration file not found at {path}")]
+    NotFound {
+        /// Path where the configuration file was expected
+        path: PathBuf
+    },
+
+    /// Configuration file could not be parsed
+    #[error("Failed to parse configuration file {path}: {reason}")]
+    ParseFailed {
+        /// Path to the configuration file
+        path: PathBuf,
+        /// Reason for the parse failure
+        reason: String
+    },
+
+    /// Configuration value is invalid
+    #[error("Invalid configuration value for '{key}': {reason}")]
+    InvalidValue {
+        /// Configuration key with the invalid value
+        key: String,
+        /// Reason the value is invalid
+        reason: String
+    },
+}
+
+/// Release notification errors
+#[derive(Error, Debug)]
+pub enum NotifyError {
+    /// Webhook request could not be delivered
+    #[error("Failed to notify {url}: {reason}")]
+    DeliveryFailed {
+        /// Webhook URL
+        url: String,
+        /// Reason for the delivery failure
+        reason: String
+    },
+
+    /// HTTP client could not be created
+    #[error("Failed to create notification client: {reason}")]
+    ClientFailed {
+        /// Reason for the failure
+        reason: String
+    },
+}
+
+/// Metrics export errors
+#[derive(Error, Debug)]
+pub enum MetricsError {
+    /// Metrics could not be delivered
+    #[error("Failed to export metrics to {url}: {reason}")]
+    ExportFailed {
+        /// Export URL
+        url: String,
+        /// Reason for the failure
+        reason: String
+    },
+
+    /// No endpoint is configured or set in the environment
+    #[error("No endpoint configured for the '{exporter}' metrics exporter")]
+    MissingEndpoint {
+        /// Configured exporter
+        exporter: String
+    },
+
+    /// Endpoint is not a usable URL
+    #[error("Invalid metrics endpoint '{url}': {reason}")]
+    InvalidEndpoint {
+        /// Configured endpoint
+        url: String,
+        /// Reason the endpoint is invalid
+        reason: String
+    },
+
+    /// HTTP client could not be created
+    #[error("Failed to create metrics client: {reason}")]
+    ClientFailed {
+        /// Reason for the failure
+        reason: String
+    },
 }
 
 impl ReleaseError {
@@ -355,14 +741,85 @@ impl ReleaseError {
                 "Ensure you have a Cargo.toml file with [workspace] section".to_string(),
             ],
             ReleaseError::Workspace(WorkspaceError::CircularDependency { packages }) => vec![
-                format!("Review dependencies between packages: {}", packages.join(", ")),
+                format!("Review dependencies between packages: {}", packages.join(" → ")),
                 "Remove circular dependencies by restructuring package relationships".to_string(),
+                "Move test-only dependencies to [dev-dependencies], which do not constrain publish order".to_string(),
+            ],
+            ReleaseError::Workspace(WorkspaceError::BuildFailed { .. }) => vec![
+                "Fix the build errors on the release branch and commit the fix".to_string(),
+                "Check that internal dependency requirements accept the bumped versions".to_string(),
+            ],
+            ReleaseError::Workspace(WorkspaceError::AdvisoryDatabase { .. }) => vec![
+                "Check network access to the advisory database repository".to_string(),
+                "Set [advisories] fetch = false to use the local copy".to_string(),
+            ],
+            ReleaseError::Workspace(WorkspaceError::SbomFailed { .. }) => vec![
+                "Check that 'cargo metadata' succeeds in the workspace".to_string(),
+                "Run without --sbom and generate the document separately".to_string(),
             ],
             ReleaseError::Git(GitError::DirtyWorkingDirectory) => vec![
                 "Commit pending changes: git add . && git commit -m 'message'".to_string(),
                 "Stash changes temporarily: git stash".to_string(),
                 "Reset working directory: git reset --hard HEAD".to_string(),
             ],
+            ReleaseError::Git(GitError::UnsafeReset { target, .. }) => vec![
+                format!("Review the commits made since the release: git log --oneline {}..HEAD", target),
+                "Revert the release commit instead of resetting: set [git] revert-on-rollback = true in release.toml".to_string(),
+                format!("Reset manually once later work is saved elsewhere: git reset --hard {}", target),
+            ],
+            ReleaseError::Git(GitError::NonFastForward { upstream, .. }) => vec![
+                format!("Review the diverged commits: git log --oneline --left-right HEAD...{}", upstream),
+                format!("Rebase the local commits onto the upstream: git rebase {}", upstream),
+            ],
+            ReleaseError::Git(GitError::MergeConflict { upstream, .. }) => vec![
+                format!("Merge manually and resolve the conflicts: git merge {}", upstream),
+            ],
+            ReleaseError::Git(GitError::CommitConflict { operation, commit, .. }) if operation == "rebase" => vec![
+                format!("Run it manually and resolve the conflicts: git rebase --interactive --autosquash {}", commit),
+                "Then finish with: git rebase --continue".to_string(),
+            ],
+            ReleaseError::Git(GitError::CommitConflict { operation, commit, .. }) => vec![
+                format!("Run it manually and resolve the conflicts: git {} {}", operation, commit),
+                format!("Then finish with: git {} --continue", operation),
+            ],
+            ReleaseError::Git(GitError::DiscoveryFailed { strategy, .. }) => {
+                let mut suggestions = vec!["Run the command inside a git repository, or initialize one: git init".to_string()];
+                if strategy.starts_with("GIT_DIR=") {
+                    suggestions.push("Unset GIT_DIR, or point it at the repository's .git directory".to_string());
+                }
+                if strategy.contains("GIT_CEILING_DIRECTORIES") {
+                    suggestions.push("Remove the repository's parent directories from GIT_CEILING_DIRECTORIES".to_string());
+                }
+                suggestions
+            }
+            ReleaseError::Git(GitError::BareRepository { path, .. }) => vec![
+                format!("Clone the repository to get a working tree: git clone {} <directory>", path.display()),
+            ],
+            ReleaseError::Git(GitError::BranchCheckedOut { worktree, .. }) => vec![
+                format!("Switch that worktree to another branch: git -C {} switch --detach", worktree.display()),
+                format!("Or remove the worktree if it is no longer needed: git worktree remove {}", worktree.display()),
+            ],
+            ReleaseError::Git(GitError::HookRejected { hook, .. }) => vec![
+                format!("Fix the problem reported by the {} hook and commit the fix", hook),
+                "Skip repository hooks for the release with --no-verify".to_string(),
+            ],
+            ReleaseError::Git(GitError::SigningFailed { .. }) => vec![
+                "Check the signing key: git config user.signingkey".to_string(),
+                "Make sure gpg (or the program in gpg.program) can sign non-interactively".to_string(),
+                "Set [git] sign-tags = false in release.toml to create unsigned annotated tags".to_string(),
+            ],
+            ReleaseError::Git(GitError::StashConflict { stash, .. }) => vec![
+                "Resolve the conflicts in the files listed above, then: git add <files>".to_string(),
+                format!("Drop the entry once its changes are restored: git stash drop {}", stash),
+            ],
+            ReleaseError::Git(GitError::RemoteNotFound { remote }) => vec![
+                "List the configured remotes: git remote -v".to_string(),
+                format!("Add the remote: git remote add {} <url>", remote),
+                "Or release through another remote: set [git] remote in release.toml".to_string(),
+            ],
+            ReleaseError::Git(GitError::NoUpstream { branch }) => vec![
+                format!("Set the upstream: git branch --set-upstream-to=origin/{} {}", branch, branch),
+            ],
             ReleaseError::Git(GitError::AuthenticationFailed { .. }) => vec![
                 "Check SSH key configuration: ssh -T git@github.com".to_string(),
                 "Verify git remote URL: git remote -v".to_string(),
@@ -374,12 +831,70 @@ impl ReleaseError {
             ],
             ReleaseError::Publish(PublishError::RateLimitExceeded { retry_after_seconds }) => vec![
                 format!("Wait {} seconds before retrying", retry_after_seconds),
-                "Use --publish-interval to add delays between packages".to_string(),
+                "Use --package-delay to add delays between packages".to_string(),
+            ],
+            ReleaseError::Cli(CliError::ReleaseFrozen { until, .. }) => vec![
+                format!("Release after the freeze ends at {}", until),
+                "Release anyway with --override-freeze \"<reason>\"; the reason is recorded in the release state".to_string(),
+            ],
+            ReleaseError::Cli(CliError::DeprecatedUsage { .. }) => vec![
+                "Replace deprecated flags with the names shown in the warnings".to_string(),
+                "Rewrite deprecated config keys: cyrup_release migrate-config".to_string(),
+            ],
+            ReleaseError::Version(VersionError::InheritanceMigrationFailed { .. }) => vec![
+                "Set version in [workspace.package] of the root Cargo.toml to the version members should share".to_string(),
+            ],
+            ReleaseError::Version(VersionError::PathDependencyMismatch { dependency, version, .. }) => vec![
+                format!("Add version = \"{}\" next to the path of '{}' in the lines shown above", version, dependency),
+                "Roll back the version changes: cyrup_release rollback".to_string(),
+            ],
+            ReleaseError::Version(VersionError::LockfileMismatch { .. }) => vec![
+                "Refresh the lockfile: cargo update --workspace".to_string(),
+                "Roll back the version changes: cyrup_release rollback".to_string(),
+            ],
+            ReleaseError::Publish(PublishError::UnresolvableDependency { dependency, .. }) => vec![
+                format!("Publish a version of '{}' matching the requirement first", dependency),
+                "Run a full workspace release instead of a single-package release".to_string(),
+            ],
+            ReleaseError::Publish(PublishError::NameUnavailable { package, existing }) => vec![
+                format!("Rename package '{}'; crates.io treats it as the same name as '{}'", package, existing),
+                format!("If you own '{}', publish under that exact name instead", existing),
+            ],
+            ReleaseError::State(StateError::Encryption { .. }) => vec![
+                format!("Set {} to the passphrase used when the release started", crate::state::PASSPHRASE_ENV),
+                "If the passphrase is lost, remove the state files with 'cyrup_release cleanup --all'".to_string(),
+            ],
+            ReleaseError::State(StateError::RemoteStore { .. }) => vec![
+                "Check the [state] settings in release.toml and that the store is reachable".to_string(),
+                format!(
+                    "Check the store credentials ({} for http, AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY for s3)",
+                    crate::state::STATE_TOKEN_ENV
+                ),
             ],
             _ => vec!["Check the error message above for specific details".to_string()],
         }
     }
 
+    /// Broad category of this error, as reported in release metrics
+    pub fn category(&self) -> &'static str {
+        match self {
+            ReleaseError::Git(GitError::AuthenticationFailed { .. })
+            | ReleaseError::Publish(PublishError::AuthenticationError) => "authentication",
+            ReleaseError::Publish(PublishError::RateLimitExceeded { .. }) => "rate_limit",
+            ReleaseError::Publish(PublishError::NetworkError { .. }) => "network",
+            ReleaseError::Workspace(_) => "workspace",
+            ReleaseError::Version(_) => "version",
+            ReleaseError::Git(_) => "git",
+            ReleaseError::Publish(_) => "publish",
+            ReleaseError::State(_) => "state",
+            ReleaseError::Cli(_) => "cli",
+            ReleaseError::Config(_) => "config",
+            ReleaseError::Notify(_) | ReleaseError::Metrics(_) => "reporting",
+            ReleaseError::Io(_) => "io",
+            ReleaseError::Json(_) | ReleaseError::Toml(_) | ReleaseError::TomlEdit(_) => "parse",
+        }
+    }
+
     /// Check if this error is recoverable
     pub fn is_recoverable(&self) -> bool {
         match self {
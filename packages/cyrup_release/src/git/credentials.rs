@@ -0,0 +1,516 @@
+//! Credentials for pushing to and fetching from remotes.
+//!
+//! A `Credentials` chain asks its providers in order for a credential for the
+//! remote URL and hands the first one to git, instead of relying on whatever
+//! credential helpers and SSH setup the machine happens to have:
+//! - `env`: tokens from `CYRUP_RELEASE_GIT_TOKEN`, `GH_TOKEN`/`GITHUB_TOKEN`
+//!   (GitHub), and `GITLAB_TOKEN`/`CI_JOB_TOKEN` (GitLab)
+//! - `ssh-agent`: the agent at `SSH_AUTH_SOCK`, when it holds a key
+//! - `keychain`: the OS keychain through git's bundled helper
+//!   (`osxkeychain`, `manager`, or `libsecret`)
+//! - `helper` / `helper:<name>`: git's configured credential helpers, or a named one
+//!
+//! HTTP credentials reach git through a credential helper that reads them from
+//! the environment, so tokens never appear on a command line. SSH runs in batch
+//! mode, so a missing key fails instead of waiting for a passphrase.
+
+use crate::error::{ConfigError, GitError, ReleaseError, Result};
+use crate::git::remote::{HostingProvider, RemoteUrl};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::process::Command;
+
+/// Environment variable holding a token used for any HTTP remote
+pub const GIT_TOKEN_ENV: &str = "CYRUP_RELEASE_GIT_TOKEN";
+
+/// Environment variable holding the user name sent with `CYRUP_RELEASE_GIT_TOKEN`
+pub const GIT_USERNAME_ENV: &str = "CYRUP_RELEASE_GIT_USERNAME";
+
+/// Variable the injected credential helper reads the password from
+const HELPER_PASSWORD_ENV: &str = "CYRUP_RELEASE_GIT_PASSWORD";
+
+/// Credential helper that answers `get` from the environment of the git process
+const ENV_CREDENTIAL_HELPER: &str = "!f() { test \"$1\" = get || exit 0; \
+    echo \"username=${CYRUP_RELEASE_GIT_USERNAME}\"; echo \"password=${CYRUP_RELEASE_GIT_PASSWORD}\"; }; f";
+
+/// A credential git can authenticate with
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credential {
+    /// User name and password or token, for HTTP remotes
+    UserPassword {
+        /// User name
+        username: String,
+        /// Password or access token
+        password: String,
+    },
+    /// Keys held by an SSH agent, for SSH remotes
+    SshAgent {
+        /// Agent socket
+        socket: PathBuf,
+    },
+}
+
+impl Credential {
+    /// Configure a git command to authenticate with this credential
+    pub fn apply(&self, command: &mut Command) {
+        match self {
+            Credential::UserPassword { username, password } => {
+                // An empty helper clears the configured ones, so ours is the only one asked
+                command.args(["-c", "credential.helper=", "-c"])
+                    .arg(format!("credential.helper={}", ENV_CREDENTIAL_HELPER))
+                    .env(GIT_USERNAME_ENV, username)
+                    .env(HELPER_PASSWORD_ENV, password);
+            }
+            Credential::SshAgent { socket } => {
+                command.env("SSH_AUTH_SOCK", socket);
+                if std::env::var_os("GIT_SSH_COMMAND").is_none() {
+                    command.env("GIT_SSH_COMMAND", "ssh -o BatchMode=yes");
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credential::UserPassword { username, .. } => f.debug_struct("UserPassword")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            Credential::SshAgent { socket } => f.debug_struct("SshAgent")
+                .field("socket", socket)
+                .finish(),
+        }
+    }
+}
+
+/// Source of credentials for remote operations
+#[async_trait::async_trait]
+pub trait CredentialProvider: fmt::Debug + Send + Sync {
+    /// Name shown when the provider's credential is used
+    fn name(&self) -> &str;
+
+    /// Credential for a remote URL, or `None` if this provider has none
+    async fn credential(&self, url: &str, repo_path: &Path) -> Result<Option<Credential>>;
+}
+
+/// Tokens from environment variables, for HTTP remotes
+#[derive(Debug, Clone, Default)]
+pub struct EnvTokenProvider;
+
+#[async_trait::async_trait]
+impl CredentialProvider for EnvTokenProvider {
+    fn name(&self) -> &str {
+        "env"
+    }
+
+    async fn credential(&self, url: &str, _repo_path: &Path) -> Result<Option<Credential>> {
+        if !is_http(url) {
+            return Ok(None);
+        }
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        if let Some(token) = var(GIT_TOKEN_ENV) {
+            return Ok(Some(Credential::UserPassword {
+                username: var(GIT_USERNAME_ENV).unwrap_or_else(|| "x-access-token".to_string()),
+                password: token,
+            }));
+        }
+
+        let provider = RemoteUrl::parse(url).map(|remote| remote.provider);
+        let token = match provider {
+            Some(HostingProvider::GitHub) => var("GH_TOKEN")
+                .or_else(|| var("GITHUB_TOKEN"))
+                .map(|token| ("x-access-token", token)),
+            Some(HostingProvider::GitLab) => var("GITLAB_TOKEN")
+                .map(|token| ("oauth2", token))
+                .or_else(|| var("CI_JOB_TOKEN").map(|token| ("gitlab-ci-token", token))),
+            Some(HostingProvider::Other) | None => None,
+        };
+        Ok(token.map(|(username, password)| Credential::UserPassword {
+            username: username.to_string(),
+            password,
+        }))
+    }
+}
+
+/// The running SSH agent, for SSH remotes
+#[derive(Debug, Clone, Default)]
+pub struct SshAgentProvider;
+
+#[async_trait::async_trait]
+impl CredentialProvider for SshAgentProvider {
+    fn name(&self) -> &str {
+        "ssh-agent"
+    }
+
+    async fn credential(&self, url: &str, _repo_path: &Path) -> Result<Option<Credential>> {
+        if is_http(url) || RemoteUrl::parse(url).is_none() {
+            return Ok(None);
+        }
+        let Some(socket) = std::env::var_os("SSH_AUTH_SOCK").map(PathBuf::from) else {
+            return Ok(None);
+        };
+
+        // `ssh-add -l` exits with 1 when the agent has no keys and 2 when it cannot be reached
+        let has_keys = Command::new("ssh-add")
+            .arg("-l")
+            .env("SSH_AUTH_SOCK", &socket)
+            .stdin(std::process::Stdio::null())
+            .output()
+            .await
+            .is_ok_and(|output| output.status.success());
+        Ok(has_keys.then_some(Credential::SshAgent { socket }))
+    }
+}
+
+/// A git credential helper, for HTTP remotes
+#[derive(Debug, Clone)]
+pub struct CredentialHelperProvider {
+    /// Name shown when the helper's credential is used
+    name: String,
+    /// Helper to ask (e.g. `store` or `/usr/bin/my-helper`); the configured helpers if `None`
+    helper: Option<String>,
+}
+
+impl CredentialHelperProvider {
+    /// Ask the credential helpers configured in git
+    pub fn configured() -> Self {
+        Self {
+            name: "helper".to_string(),
+            helper: None,
+        }
+    }
+
+    /// Ask one helper, ignoring the configured ones
+    pub fn new(helper: impl Into<String>) -> Self {
+        let helper = helper.into();
+        Self {
+            name: format!("helper:{}", helper),
+            helper: Some(helper),
+        }
+    }
+
+    /// Ask the OS keychain through the helper bundled with git for this platform
+    pub fn keychain() -> Self {
+        let helper = if cfg!(target_os = "macos") {
+            "osxkeychain"
+        } else if cfg!(windows) {
+            "manager"
+        } else {
+            "libsecret"
+        };
+        Self {
+            name: "keychain".to_string(),
+            helper: Some(helper.to_string()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CredentialProvider for CredentialHelperProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn credential(&self, url: &str, repo_path: &Path) -> Result<Option<Credential>> {
+        use tokio::io::AsyncWriteExt;
+
+        if !is_http(url) {
+            return Ok(None);
+        }
+
+        let mut command = Command::new("git");
+        if let Some(helper) = &self.helper {
+            command.args(["-c", "credential.helper=", "-c"]).arg(format!("credential.helper={}", helper));
+        }
+        // Never fall back to asking on the terminal or in a dialog
+        let mut child = command.args(["-c", "credential.interactive=false", "credential", "fill"])
+            .current_dir(repo_path)
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .env("GCM_INTERACTIVE", "never")
+            .env_remove("GIT_ASKPASS")
+            .env_remove("SSH_ASKPASS")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| GitError::OperationFailed {
+                operation: "git credential fill".to_string(),
+                reason: format!("Failed to execute: {}", e),
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // A helper that exits early closes stdin; its answer still tells whether it had a credential
+            let _ = stdin.write_all(format!("url={}\n\n", url).as_bytes()).await;
+        }
+        let output = child.wait_with_output().await.map_err(|e| GitError::OperationFailed {
+            operation: "git credential fill".to_string(),
+            reason: e.to_string(),
+        })?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let answer = String::from_utf8_lossy(&output.stdout);
+        let field = |key: &str| answer.lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+            .map(str::to_string);
+        Ok(field("password").map(|password| Credential::UserPassword {
+            username: field("username").unwrap_or_default(),
+            password,
+        }))
+    }
+}
+
+/// A credential provider named in `[git] credentials`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum CredentialSource {
+    /// Tokens from environment variables (`env`)
+    Env,
+    /// The running SSH agent (`ssh-agent`)
+    SshAgent,
+    /// The OS keychain (`keychain`)
+    Keychain,
+    /// Git's configured helpers (`helper`), or one named helper (`helper:<name>`)
+    Helper(Option<String>),
+}
+
+impl CredentialSource {
+    /// Sources used when none are configured
+    pub fn defaults() -> Vec<Self> {
+        vec![CredentialSource::Env, CredentialSource::SshAgent]
+    }
+
+    /// Create the provider for this source
+    pub fn provider(&self) -> Arc<dyn CredentialProvider> {
+        match self {
+            CredentialSource::Env => Arc::new(EnvTokenProvider),
+            CredentialSource::SshAgent => Arc::new(SshAgentProvider),
+            CredentialSource::Keychain => Arc::new(CredentialHelperProvider::keychain()),
+            CredentialSource::Helper(None) => Arc::new(CredentialHelperProvider::configured()),
+            CredentialSource::Helper(Some(helper)) => Arc::new(CredentialHelperProvider::new(helper.clone())),
+        }
+    }
+}
+
+impl FromStr for CredentialSource {
+    type Err = ReleaseError;
+
+    fn from_str(source: &str) -> Result<Self> {
+        match source {
+            "env" => Ok(CredentialSource::Env),
+            "ssh-agent" => Ok(CredentialSource::SshAgent),
+            "keychain" => Ok(CredentialSource::Keychain),
+            "helper" => Ok(CredentialSource::Helper(None)),
+            _ => match source.strip_prefix("helper:").filter(|helper| !helper.trim().is_empty()) {
+                Some(helper) => Ok(CredentialSource::Helper(Some(helper.trim().to_string()))),
+                None => Err(ConfigError::InvalidValue {
+                    key: "git.credentials".to_string(),
+                    reason: format!(
+                        "Unknown credential source '{}' (expected env, ssh-agent, keychain, helper, or helper:<name>)",
+                        source
+                    ),
+                }.into()),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for CredentialSource {
+    type Error = ReleaseError;
+
+    fn try_from(source: String) -> Result<Self> {
+        source.parse()
+    }
+}
+
+impl From<CredentialSource> for String {
+    fn from(source: CredentialSource) -> Self {
+        source.to_string()
+    }
+}
+
+impl fmt::Display for CredentialSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialSource::Env => f.write_str("env"),
+            CredentialSource::SshAgent => f.write_str("ssh-agent"),
+            CredentialSource::Keychain => f.write_str("keychain"),
+            CredentialSource::Helper(None) => f.write_str("helper"),
+            CredentialSource::Helper(Some(helper)) => write!(f, "helper:{}", helper),
+        }
+    }
+}
+
+/// Credential providers asked in order; empty leaves authentication to git's own configuration
+#[derive(Debug, Clone, Default)]
+pub struct Credentials {
+    /// Providers, first match wins
+    providers: Vec<Arc<dyn CredentialProvider>>,
+}
+
+impl Credentials {
+    /// Create a chain of providers
+    pub fn new(providers: Vec<Arc<dyn CredentialProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Create the chain for configured sources
+    pub fn from_sources(sources: &[CredentialSource]) -> Self {
+        Self::new(sources.iter().map(CredentialSource::provider).collect())
+    }
+
+    /// Check if the chain has no providers
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    /// First credential a provider has for the URL.
+    ///
+    /// A failing provider is skipped, so one broken helper does not block the others.
+    pub async fn resolve(&self, url: &str, repo_path: &Path) -> Option<Credential> {
+        for provider in &self.providers {
+            match provider.credential(url, repo_path).await {
+                Ok(Some(credential)) => {
+                    tracing::debug!(provider = provider.name(), url, "Using credentials");
+                    return Some(credential);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(provider = provider.name(), url, "Credential provider failed: {}", e),
+            }
+        }
+        None
+    }
+}
+
+/// Check if a remote URL uses HTTP(S)
+fn is_http(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Answers with a fixed result and counts how often it was asked
+    #[derive(Debug)]
+    struct FixedProvider {
+        name: &'static str,
+        answer: Option<Option<Credential>>,
+        calls: AtomicUsize,
+    }
+
+    impl FixedProvider {
+        fn new(name: &'static str, answer: Option<Option<Credential>>) -> Arc<Self> {
+            Arc::new(Self { name, answer, calls: AtomicUsize::new(0) })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CredentialProvider for FixedProvider {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn credential(&self, _url: &str, _repo_path: &Path) -> Result<Option<Credential>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.answer.clone().ok_or_else(|| GitError::OperationFailed {
+                operation: "credential".to_string(),
+                reason: "broken provider".to_string(),
+            }.into())
+        }
+    }
+
+    fn password(password: &str) -> Credential {
+        Credential::UserPassword {
+            username: "user".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_credential_wins_and_failures_are_skipped() {
+        let broken = FixedProvider::new("broken", None);
+        let empty = FixedProvider::new("empty", Some(None));
+        let first = FixedProvider::new("first", Some(Some(password("first"))));
+        let second = FixedProvider::new("second", Some(Some(password("second"))));
+        let credentials = Credentials::new(vec![broken.clone(), empty.clone(), first.clone(), second.clone()]);
+
+        let credential = credentials.resolve("https://github.com/o/r.git", Path::new(".")).await;
+        assert_eq!(credential, Some(password("first")));
+        assert_eq!(broken.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(empty.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.calls.load(Ordering::SeqCst), 0);
+
+        assert!(Credentials::default().is_empty());
+        assert_eq!(Credentials::default().resolve("https://github.com/o/r.git", Path::new(".")).await, None);
+    }
+
+    #[test]
+    fn test_credential_sources_round_trip() {
+        let sources = [
+            ("env", CredentialSource::Env),
+            ("ssh-agent", CredentialSource::SshAgent),
+            ("keychain", CredentialSource::Keychain),
+            ("helper", CredentialSource::Helper(None)),
+            ("helper:store", CredentialSource::Helper(Some("store".to_string()))),
+        ];
+        for (name, source) in sources {
+            assert_eq!(name.parse::<CredentialSource>().unwrap(), source);
+            assert_eq!(source.to_string(), name);
+            assert_eq!(source.provider().name(), name);
+        }
+        assert_eq!(
+            "helper: /usr/bin/my-helper ".parse::<CredentialSource>().unwrap(),
+            CredentialSource::Helper(Some("/usr/bin/my-helper".to_string()))
+        );
+        for invalid in ["", "token", "helper:", "helper:  "] {
+            assert!(invalid.parse::<CredentialSource>().is_err(), "{:?}", invalid);
+        }
+        assert_eq!(CredentialSource::defaults(), vec![CredentialSource::Env, CredentialSource::SshAgent]);
+    }
+
+    #[tokio::test]
+    async fn test_providers_only_answer_for_their_transport() {
+        let dir = Path::new(".");
+        let ssh = "git@github.com:cyrup-ai/sugars.git";
+        assert_eq!(EnvTokenProvider.credential(ssh, dir).await.unwrap(), None);
+        assert_eq!(CredentialHelperProvider::configured().credential(ssh, dir).await.unwrap(), None);
+        assert_eq!(SshAgentProvider.credential("https://github.com/cyrup-ai/sugars.git", dir).await.unwrap(), None);
+        assert_eq!(SshAgentProvider.credential("/srv/git/repo.git", dir).await.unwrap(), None);
+
+        assert!(is_http("  HTTPS://github.com/o/r"));
+        assert!(is_http("http://example.com/o/r"));
+        assert!(!is_http("ssh://git@github.com/o/r"));
+    }
+
+    #[tokio::test]
+    async fn test_named_helper_answers_credential_fill() {
+        let dir = tempfile::tempdir().unwrap();
+        let helper = CredentialHelperProvider::new("!f() { echo username=bot; echo password=s3cret; }; f");
+        let credential = helper.credential("https://example.com/o/r.git", dir.path()).await.unwrap();
+        assert_eq!(credential, Some(Credential::UserPassword {
+            username: "bot".to_string(),
+            password: "s3cret".to_string(),
+        }));
+
+        // A helper without an answer yields no credential instead of prompting
+        let silent = CredentialHelperProvider::new("!f() { :; }; f");
+        assert_eq!(silent.credential("https://example.com/o/r.git", dir.path()).await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_debug_hides_secrets() {
+        let debug = format!("{:?}", password("hunter2"));
+        assert!(!debug.contains("hunter2"), "{}", debug);
+    }
+}
@@ -0,0 +1,379 @@
+//! Listing the refs a remote advertises, over the git protocol itself.
+//!
+//! Local, ssh and `git://` remotes go through gix's transport layer, which asks
+//! for protocol v2 (`ls-refs`) and falls back to the v0/v1 ref advertisement.
+//! HTTP remotes answer the smart HTTP `info/refs` request, whose pkt-lines are
+//! decoded with gix's packetline reader; dumb HTTP servers return plain lines.
+
+use crate::error::{GitError, ReleaseError, Result};
+use crate::git::credentials::Credential;
+use crate::git::operations::{LsRemoteOptions, RemoteRef};
+use gix_transport::bstr::BString;
+use gix_transport::client::{self, MessageKind, Transport, TransportV2Ext, TransportWithoutIO, WriteMode};
+use gix_transport::packetline::{decode, PacketLineRef};
+use std::time::Duration;
+
+/// Lists the refs `url` advertises, giving up after `timeout`
+///
+/// `remote` is the name the caller used, for error messages.
+pub(crate) async fn list_refs(
+    remote: &str,
+    url: &str,
+    credential: Option<Credential>,
+    options: &LsRemoteOptions,
+) -> Result<Vec<RemoteRef>> {
+    let listing = async {
+        if is_http(url) {
+            list_over_http(url, credential.as_ref(), options).await
+        } else {
+            let url = url.to_string();
+            let prefixes = ref_prefixes(options);
+            let ssh_agent = matches!(credential, Some(Credential::SshAgent { .. }));
+            tokio::task::spawn_blocking(move || list_over_transport(&url, &prefixes, ssh_agent))
+                .await
+                .map_err(|e| failed(remote, e))?
+        }
+    };
+    let refs = match options.timeout {
+        Some(timeout) => tokio::time::timeout(timeout, listing).await.map_err(|_| GitError::RemoteOperationFailed {
+            operation: format!("ls-remote {}", remote),
+            reason: format!("'{}' did not answer within {}s", remote, timeout.as_secs()),
+        })?,
+        None => listing.await,
+    }?;
+    Ok(refs.into_iter().filter(|remote_ref| options.matches(&remote_ref.name)).collect())
+}
+
+/// Ref prefixes a protocol v2 server can filter on before answering
+fn ref_prefixes(options: &LsRemoteOptions) -> Vec<&'static str> {
+    let mut prefixes = Vec::new();
+    if options.heads {
+        prefixes.push("refs/heads/");
+    }
+    if options.tags {
+        prefixes.push("refs/tags/");
+    }
+    prefixes
+}
+
+fn is_http(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+fn failed(url: &str, error: impl std::fmt::Display) -> ReleaseError {
+    GitError::RemoteOperationFailed {
+        operation: format!("ls-remote {}", url),
+        reason: error.to_string(),
+    }.into()
+}
+
+/// Lists refs over a local, ssh or `git://` transport
+fn list_over_transport(url: &str, prefixes: &[&str], ssh_agent: bool) -> Result<Vec<RemoteRef>> {
+    let mut connect_options = client::connect::Options::default();
+    connect_options.ssh.command = std::env::var_os("GIT_SSH_COMMAND").or_else(|| std::env::var_os("GIT_SSH"));
+    if ssh_agent && connect_options.ssh.command.is_none() {
+        // Like the git CLI path, fail on a missing key instead of asking for a passphrase
+        connect_options.ssh.command = Some("ssh -o BatchMode=yes".into());
+        connect_options.ssh.kind = Some(client::ssh::ProgramKind::Ssh);
+    }
+    let mut transport = client::connect(url, connect_options).map_err(|e| failed(url, e))?;
+
+    let mut refs = Vec::new();
+    let advertised = {
+        let response = transport.handshake(gix_transport::Service::UploadPack, &[]).map_err(|e| failed(url, e))?;
+        match response.refs {
+            // Protocol v0/v1 advertises every ref as part of the handshake
+            Some(mut advertisement) => {
+                read_ref_lines(&mut advertisement, &mut refs).map_err(|e| failed(url, e))?;
+                true
+            }
+            None => false,
+        }
+    };
+    if !advertised {
+        let mut arguments: Vec<BString> = vec!["peel".into(), "symrefs".into()];
+        arguments.extend(prefixes.iter().map(|prefix| format!("ref-prefix {}", prefix).into()));
+        let mut listing = transport
+            .invoke("ls-refs", std::iter::empty::<(&str, Option<&str>)>(), Some(arguments.into_iter()), false)
+            .map_err(|e| failed(url, e))?;
+        read_ref_lines(&mut listing, &mut refs).map_err(|e| failed(url, e))?;
+    }
+
+    // Stateful connections (ssh, local) expect a flush before hanging up
+    if transport.connection_persists_across_multiple_requests() {
+        transport
+            .request(WriteMode::Binary, MessageKind::Flush, false)
+            .and_then(|writer| writer.into_read().map_err(Into::into))
+            .map_err(|e| failed(url, e))?;
+    }
+    Ok(refs)
+}
+
+fn read_ref_lines(lines: &mut dyn client::ReadlineBufRead, refs: &mut Vec<RemoteRef>) -> std::io::Result<()> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if lines.readline_str(&mut line)? == 0 {
+            return Ok(());
+        }
+        parse_ref_line(&line, refs);
+    }
+}
+
+/// Lists refs from the `info/refs` advertisement of an HTTP remote
+async fn list_over_http(url: &str, credential: Option<&Credential>, options: &LsRemoteOptions) -> Result<Vec<RemoteRef>> {
+    let mut request = reqwest::Client::new()
+        .get(format!("{}/info/refs?service=git-upload-pack", url.trim_end_matches('/')))
+        .header(reqwest::header::USER_AGENT, concat!("git/2.0 (cyrup_release/", env!("CARGO_PKG_VERSION"), ")"));
+    if let Some(Credential::UserPassword { username, password }) = credential {
+        request = request.basic_auth(username, Some(password));
+    }
+    if let Some(timeout) = options.timeout {
+        request = request.timeout(timeout.max(Duration::from_millis(1)));
+    }
+
+    let response = request.send().await.map_err(|e| failed(url, e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        return Err(GitError::AuthenticationFailed {
+            reason: format!("{} answered {}", url, status),
+        }.into());
+    }
+    if !status.is_success() {
+        return Err(failed(url, format!("server answered {}", status)));
+    }
+    let body = response.bytes().await.map_err(|e| failed(url, e))?;
+    parse_http_advertisement(&body).map_err(|e| failed(url, e))
+}
+
+/// Parses an `info/refs` response: pkt-lines from a smart server, or
+/// `<oid>\t<ref>` lines from a dumb one
+fn parse_http_advertisement(body: &[u8]) -> std::result::Result<Vec<RemoteRef>, String> {
+    let mut refs = Vec::new();
+    // A dumb listing starts with an object id and a tab, where a pkt-line has its length
+    let dumb = body.iter().position(|&byte| byte == b'\t' || byte == b'\n')
+        .is_some_and(|end| body[end] == b'\t' && body[..end].iter().all(u8::is_ascii_hexdigit));
+    if dumb {
+        for line in String::from_utf8_lossy(body).lines() {
+            parse_ref_line(line, &mut refs);
+        }
+        return Ok(refs);
+    }
+
+    let mut rest = body;
+    while !rest.is_empty() {
+        let (line, consumed) = match decode::streaming(rest).map_err(|e| e.to_string())? {
+            decode::Stream::Complete { line, bytes_consumed } => (line, bytes_consumed),
+            decode::Stream::Incomplete { .. } => return Err("advertisement ends in the middle of a line".to_string()),
+        };
+        rest = &rest[consumed..];
+        if let Some(error) = line.check_error() {
+            return Err(String::from_utf8_lossy(error.0).into_owned());
+        }
+        let PacketLineRef::Data(data) = line else {
+            continue;
+        };
+        // The first ref carries the capabilities after a NUL
+        let data = data.split(|&byte| byte == 0).next().unwrap_or_default();
+        let text = String::from_utf8_lossy(data);
+        if text.starts_with("# service=") || text.starts_with("version ") {
+            continue;
+        }
+        parse_ref_line(&text, &mut refs);
+    }
+    Ok(refs)
+}
+
+/// Adds one advertised ref to `refs`
+///
+/// Accepts protocol v0/v1 (`<oid> <ref>`, followed by `<oid> <ref>^{}` for
+/// annotated tags), v2 (`<oid> <ref> [symref-target:<ref>] [peeled:<oid>]`) and
+/// `git ls-remote` (`<oid>\t<ref>`) lines. Lines that name no ref are skipped.
+fn parse_ref_line(line: &str, refs: &mut Vec<RemoteRef>) {
+    let mut fields = line.trim_end().split([' ', '\t']);
+    let (Some(oid), Some(name)) = (fields.next(), fields.next()) else {
+        return;
+    };
+    // v2 reports an unborn HEAD, v1 lists shallow commits, and an empty repository
+    // advertises `capabilities^{}`
+    if oid == "unborn" || oid == "shallow" || !oid.chars().all(|c| c.is_ascii_hexdigit()) {
+        return;
+    }
+    match name.strip_suffix("^{}") {
+        Some(tag) => {
+            if let Some(tag_ref) = refs.iter_mut().rev().find(|remote_ref| remote_ref.name == tag) {
+                tag_ref.peeled = Some(oid.to_string());
+            }
+        }
+        None => refs.push(RemoteRef {
+            name: name.to_string(),
+            oid: oid.to_string(),
+            peeled: fields.find_map(|attribute| attribute.strip_prefix("peeled:")).map(str::to_string),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::test_repo::TestRepo;
+    use crate::git::GitOperations;
+
+    const COMMIT: &str = "1111111111111111111111111111111111111111";
+    const TAG: &str = "2222222222222222222222222222222222222222";
+
+    fn parse(lines: &[&str]) -> Vec<RemoteRef> {
+        let mut refs = Vec::new();
+        for line in lines {
+            parse_ref_line(line, &mut refs);
+        }
+        refs
+    }
+
+    fn remote_ref(name: &str, oid: &str, peeled: Option<&str>) -> RemoteRef {
+        RemoteRef {
+            name: name.to_string(),
+            oid: oid.to_string(),
+            peeled: peeled.map(str::to_string),
+        }
+    }
+
+    fn pkt_line(data: &str) -> String {
+        format!("{:04x}{}", data.len() + 4, data)
+    }
+
+    #[test]
+    fn test_parse_v1_peels_annotated_tags() {
+        let refs = parse(&[
+            &format!("{} HEAD\n", COMMIT),
+            &format!("{} refs/heads/main\n", COMMIT),
+            &format!("{} refs/tags/v1.0.0\n", TAG),
+            &format!("{} refs/tags/v1.0.0^{{}}\n", COMMIT),
+        ]);
+        assert_eq!(refs, vec![
+            remote_ref("HEAD", COMMIT, None),
+            remote_ref("refs/heads/main", COMMIT, None),
+            remote_ref("refs/tags/v1.0.0", TAG, Some(COMMIT)),
+        ]);
+        assert_eq!(refs[2].commit(), COMMIT);
+    }
+
+    #[test]
+    fn test_parse_v2_reads_peeled_and_symref_attributes() {
+        let refs = parse(&[
+            &format!("{} HEAD symref-target:refs/heads/main", COMMIT),
+            &format!("{} refs/tags/v1.0.0 peeled:{}", TAG, COMMIT),
+            "unborn refs/heads/next",
+        ]);
+        assert_eq!(refs, vec![
+            remote_ref("HEAD", COMMIT, None),
+            remote_ref("refs/tags/v1.0.0", TAG, Some(COMMIT)),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_skips_lines_without_refs() {
+        let refs = parse(&[
+            &format!("{} capabilities^{{}}", "0".repeat(40)),
+            &format!("shallow {}", COMMIT),
+            "",
+            "garbage",
+        ]);
+        assert_eq!(refs, Vec::new());
+    }
+
+    #[test]
+    fn test_parse_smart_http_advertisement() {
+        let body = [
+            pkt_line("# service=git-upload-pack\n"),
+            "0000".to_string(),
+            pkt_line(&format!("{} refs/heads/main\0multi_ack side-band-64k\n", COMMIT)),
+            pkt_line(&format!("{} refs/tags/v1.0.0\n", TAG)),
+            pkt_line(&format!("{} refs/tags/v1.0.0^{{}}\n", COMMIT)),
+            "0000".to_string(),
+        ].concat();
+        assert_eq!(parse_http_advertisement(body.as_bytes()), Ok(vec![
+            remote_ref("refs/heads/main", COMMIT, None),
+            remote_ref("refs/tags/v1.0.0", TAG, Some(COMMIT)),
+        ]));
+    }
+
+    #[test]
+    fn test_parse_http_advertisement_reports_errors() {
+        let body = pkt_line("ERR access denied\n");
+        assert!(parse_http_advertisement(body.as_bytes()).unwrap_err().contains("access denied"));
+
+        let truncated = &pkt_line(&format!("{} refs/heads/main\n", COMMIT))[..20];
+        assert!(parse_http_advertisement(truncated.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_parse_dumb_http_listing() {
+        let body = format!("{}\trefs/heads/main\n{}\trefs/tags/v1.0.0\n", COMMIT, TAG);
+        assert_eq!(parse_http_advertisement(body.as_bytes()), Ok(vec![
+            remote_ref("refs/heads/main", COMMIT, None),
+            remote_ref("refs/tags/v1.0.0", TAG, None),
+        ]));
+    }
+
+    #[test]
+    fn test_options_match_like_git_ls_remote() {
+        let options = LsRemoteOptions {
+            patterns: vec!["v1.*".to_string(), "refs/heads/main".to_string()],
+            ..LsRemoteOptions::default()
+        };
+        assert!(options.matches("refs/tags/v1.2.0"));
+        assert!(options.matches("refs/heads/main"));
+        // Patterns match whole trailing path components
+        assert!(!options.matches("refs/tags/xv1.2.0"));
+        assert!(!options.matches("refs/heads/not-main"));
+
+        let tags = LsRemoteOptions {
+            tags: true,
+            ..LsRemoteOptions::default()
+        };
+        assert!(tags.matches("refs/tags/v1.0.0"));
+        assert!(!tags.matches("refs/heads/main"));
+        assert!(!tags.matches("HEAD"));
+        assert!(LsRemoteOptions::default().matches("HEAD"));
+    }
+
+    #[tokio::test]
+    async fn test_lists_refs_of_a_local_bare_remote() {
+        let test = TestRepo::new();
+        test.git(&["tag", "-a", "v1.0.0", "-m", "Release 1.0.0"]);
+        test.git(&["push", "--quiet", "origin", "main", "v1.0.0"]);
+        let commit = test.git(&["rev-parse", "HEAD"]);
+        let tag = test.git(&["rev-parse", "v1.0.0"]);
+        let repo = test.repository();
+
+        let tags = repo.ls_remote(&LsRemoteOptions {
+            tags: true,
+            timeout: Some(Duration::from_secs(30)),
+            ..LsRemoteOptions::default()
+        }).await.unwrap();
+        assert_eq!(tags, vec![remote_ref("refs/tags/v1.0.0", &tag, Some(&commit))]);
+
+        let main = repo.find_remote_ref(None, "refs/heads/main", None).await.unwrap();
+        assert_eq!(main.map(|remote_ref| remote_ref.oid), Some(commit.clone()));
+        let missing = repo.find_remote_ref(Some("origin"), "refs/tags/v9.9.9", None).await.unwrap();
+        assert_eq!(missing, None);
+
+        // A URL works as well as a remote name
+        let url = format!("file://{}", test.remote.display());
+        let heads = repo.ls_remote(&LsRemoteOptions {
+            remote: Some(url),
+            heads: true,
+            ..LsRemoteOptions::default()
+        }).await.unwrap();
+        assert_eq!(heads, vec![remote_ref("refs/heads/main", &commit, None)]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_remote_repository_fails() {
+        let test = TestRepo::new();
+        test.git(&["remote", "set-url", "origin", "/nonexistent/cyrup-release-remote.git"]);
+        let result = test.repository().ls_remote(&LsRemoteOptions::default()).await;
+        assert!(matches!(result, Err(ReleaseError::Git(GitError::RemoteOperationFailed { .. }))), "{:?}", result);
+    }
+}
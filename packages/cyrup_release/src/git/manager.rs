@@ -4,9 +4,13 @@
 //! coordinating commits, tags, pushes, and rollbacks.
 
 use crate::error::{Result, GitError};
-use crate::git::{GitOperations, GitRepository, CommitInfo, TagInfo, PushInfo, ValidationResult, ResetType};
+use crate::git::{Credentials, GitOperations, GitRepository, CommitInfo, TagInfo, PushInfo, ValidationResult, ResetType, TagKind, TagOptions};
+use crate::git::template::{
+    TemplateContext, render_template, DEFAULT_COMMIT_MESSAGE_TEMPLATE, DEFAULT_TAG_MESSAGE_TEMPLATE,
+    DEFAULT_PACKAGE_TAG_TEMPLATE, DEFAULT_TAG_TEMPLATE,
+};
 use semver::Version;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// High-level Git manager for release operations
 #[derive(Debug)]
@@ -19,6 +23,10 @@ pub struct GitManager {
     release_state: ReleaseState,
 }
 
+/// Callback given the release commit and the tags created so far, called after the commit
+/// and after each tag so callers can persist what a rollback has to undo before the next step can fail
+pub type ReleaseRecorder<'a> = dyn FnMut(&CommitInfo, &[TagInfo]) -> Result<()> + Send + 'a;
+
 /// Configuration for Git operations
 #[derive(Debug, Clone)]
 pub struct GitConfig {
@@ -28,12 +36,30 @@ pub struct GitConfig {
     pub annotated_tags: bool,
     /// Whether to push tags automatically
     pub auto_push_tags: bool,
+    /// Tag name template (e.g. `v{version}` or `{package}-v{version}`)
+    pub tag_name_template: String,
+    /// Tag name template for per-package tags in independent versioning mode
+    pub package_tag_template: String,
     /// Custom commit message template
     pub commit_message_template: Option<String>,
     /// Custom tag message template
     pub tag_message_template: Option<String>,
     /// Whether to verify signatures
     pub verify_signatures: bool,
+    /// Whether to sign release tags (`git tag -s`)
+    pub sign_tags: bool,
+    /// Branch to push the release commit to, instead of the checked-out branch
+    pub push_branch: Option<String>,
+    /// Paths (relative to the repository root) left out of the release commit
+    pub excluded_paths: Vec<PathBuf>,
+    /// Whether rollback reverts the release commit instead of resetting past it
+    pub revert_on_rollback: bool,
+    /// Notes ref each release is recorded under, as a note on the release commit
+    pub notes_ref: Option<String>,
+    /// Credentials for pushing the release (git's own configuration if empty)
+    pub credentials: Credentials,
+    /// Whether repository hooks are skipped for the release commit and push
+    pub skip_hooks: bool,
 }
 
 impl Default for GitConfig {
@@ -42,9 +68,18 @@ impl Default for GitConfig {
             default_remote: "origin".to_string(),
             annotated_tags: true,
             auto_push_tags: true,
+            tag_name_template: DEFAULT_TAG_TEMPLATE.to_string(),
+            package_tag_template: DEFAULT_PACKAGE_TAG_TEMPLATE.to_string(),
             commit_message_template: None,
             tag_message_template: None,
             verify_signatures: false,
+            sign_tags: false,
+            push_branch: None,
+            excluded_paths: Vec::new(),
+            revert_on_rollback: false,
+            notes_ref: None,
+            credentials: Credentials::default(),
+            skip_hooks: false,
         }
     }
 }
@@ -54,8 +89,8 @@ impl Default for GitConfig {
 pub struct ReleaseState {
     /// Commit created for this release
     release_commit: Option<CommitInfo>,
-    /// Tag created for this release
-    release_tag: Option<TagInfo>,
+    /// Tags created for this release
+    release_tags: Vec<TagInfo>,
     /// Whether commits have been pushed
     commits_pushed: bool,
     /// Whether tags have been pushed
@@ -68,7 +103,7 @@ impl Default for ReleaseState {
     fn default() -> Self {
         Self {
             release_commit: None,
-            release_tag: None,
+            release_tags: Vec::new(),
             commits_pushed: false,
             tags_pushed: false,
             previous_head: None,
@@ -83,8 +118,8 @@ pub struct ReleaseResult {
     pub version: Version,
     /// Commit information
     pub commit: CommitInfo,
-    /// Tag information
-    pub tag: TagInfo,
+    /// Tags created (one per package in independent versioning mode)
+    pub tags: Vec<TagInfo>,
     /// Push information (if pushed)
     pub push_info: Option<PushInfo>,
     /// Duration of the operation
@@ -120,7 +155,9 @@ impl GitManager {
 
     /// Create a Git manager with custom configuration
     pub fn with_config<P: AsRef<Path>>(repo_path: P, config: GitConfig) -> Result<Self> {
-        let repository = GitRepository::open(repo_path)?;
+        let repository = GitRepository::open(repo_path)?
+            .with_credentials(config.credentials.clone())
+            .skip_hooks(config.skip_hooks);
         let release_state = ReleaseState::default();
 
         Ok(Self {
@@ -132,6 +169,79 @@ impl GitManager {
 
     /// Perform a complete release operation (commit + tag + push)
     pub async fn perform_release(&mut self, version: &Version, push_to_remote: bool) -> Result<ReleaseResult> {
+        self.perform_release_with_context(&TemplateContext::new(version), push_to_remote).await
+    }
+
+    /// Perform a complete release operation using the given template context
+    pub async fn perform_release_with_context(
+        &mut self,
+        context: &TemplateContext,
+        push_to_remote: bool,
+    ) -> Result<ReleaseResult> {
+        self.perform_release_recorded(context, push_to_remote, &mut |_, _| Ok(())).await
+    }
+
+    /// Like [`Self::perform_release_with_context`], calling `record` after the commit and the tag are created
+    #[tracing::instrument(skip_all, fields(version = %context.version, push = push_to_remote))]
+    pub async fn perform_release_recorded(
+        &mut self,
+        context: &TemplateContext,
+        push_to_remote: bool,
+        record: &mut ReleaseRecorder<'_>,
+    ) -> Result<ReleaseResult> {
+        // Render all names up front so template errors leave the repository untouched
+        let commit_message = self.generate_commit_message(context)?;
+        let tag_name = self.tag_name(context)?;
+        let tag_message = self.generate_tag_message(context)?;
+
+        self.commit_and_tag(&context.version, commit_message, vec![(tag_name, tag_message)], push_to_remote, record)
+            .await
+    }
+
+    /// Perform an independent-versioning release: one commit and one tag per released package.
+    ///
+    /// `context` renders the commit message; each package's tag is named with the
+    /// package tag template and that package's new version.
+    pub async fn perform_package_releases(
+        &mut self,
+        context: &TemplateContext,
+        packages: &[(String, Version)],
+        push_to_remote: bool,
+    ) -> Result<ReleaseResult> {
+        self.perform_package_releases_recorded(context, packages, push_to_remote, &mut |_, _| Ok(())).await
+    }
+
+    /// Like [`Self::perform_package_releases`], calling `record` after the commit and after each tag is created
+    #[tracing::instrument(skip_all, fields(packages = packages.len(), push = push_to_remote))]
+    pub async fn perform_package_releases_recorded(
+        &mut self,
+        context: &TemplateContext,
+        packages: &[(String, Version)],
+        push_to_remote: bool,
+        record: &mut ReleaseRecorder<'_>,
+    ) -> Result<ReleaseResult> {
+        let commit_message = self.generate_commit_message(context)?;
+
+        let mut tags = Vec::with_capacity(packages.len());
+        for (package, version) in packages {
+            let package_context = context.for_package(package, version);
+            let tag_name = self.package_tag_name(&package_context)?;
+            let tag_message = self.generate_tag_message(&package_context)?;
+            tags.push((tag_name, tag_message));
+        }
+
+        self.commit_and_tag(&context.version, commit_message, tags, push_to_remote, record).await
+    }
+
+    /// Create the release commit and tags, then push them if requested
+    async fn commit_and_tag(
+        &mut self,
+        version: &Version,
+        commit_message: String,
+        tags: Vec<(String, String)>,
+        push_to_remote: bool,
+        record: &mut ReleaseRecorder<'_>,
+    ) -> Result<ReleaseResult> {
         let start_time = std::time::Instant::now();
 
         // Note: Validation is performed at the start of the release command,
@@ -139,26 +249,61 @@ impl GitManager {
         // the working directory is expected to have modified version files
         // that need to be committed as part of the release.
 
-        // Store current HEAD for potential rollback
-        let current_branch = self.repository.get_current_branch().await?;
-        self.release_state.previous_head = Some(current_branch.commit_hash);
+        // Store current HEAD for potential rollback, unless the caller already recorded it
+        if self.release_state.previous_head.is_none() {
+            self.capture_previous_head().await?;
+        }
 
         // Create release commit
-        let commit_message = self.generate_commit_message(version);
-        let commit = self.repository.create_release_commit(version, Some(commit_message)).await?;
+        let commit = if self.config.excluded_paths.is_empty() {
+            self.repository.create_release_commit(version, Some(commit_message)).await?
+        } else {
+            self.repository.commit_all_excluding(&commit_message, &self.config.excluded_paths).await?
+        };
         self.release_state.release_commit = Some(commit.clone());
+        record(&commit, &[])?;
 
-        // Create version tag
-        let tag_message = self.generate_tag_message(version);
-        let tag = self.repository.create_version_tag(version, Some(tag_message)).await?;
-        self.release_state.release_tag = Some(tag.clone());
+        // Create tags, recording each one as it is created so rollback removes exactly these
+        let kind = if self.config.sign_tags {
+            TagKind::Signed
+        } else if self.config.annotated_tags {
+            TagKind::Annotated
+        } else {
+            TagKind::Lightweight
+        };
+        for (tag_name, tag_message) in tags {
+            let options = TagOptions {
+                kind,
+                message: Some(tag_message),
+                target: None,
+            };
+            let tag = self.repository.create_tag_with_options(&tag_name, &options).await?;
+            self.release_state.release_tags.push(tag);
+            record(&commit, &self.release_state.release_tags)?;
+        }
+
+        // Record the release in the repository itself
+        if let Some(notes_ref) = &self.config.notes_ref {
+            let note = serde_json::json!({
+                "version": version.to_string(),
+                "tags": self.release_state.release_tags.iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>(),
+                "released_at": chrono::Utc::now().to_rfc3339(),
+            });
+            self.repository.write_note(notes_ref, &commit.hash, &format!("{:#}", note)).await?;
+        }
 
         // Push to remote if requested
         let push_info = if push_to_remote {
             match self.push_release().await {
-                Ok(push_info) => {
+                Ok(mut push_info) => {
+                    // Notes refs are shared by all releases, so a concurrent release may have moved it
+                    if let Some(notes_ref) = &self.config.notes_ref
+                        && let Err(e) = self.repository.push_notes(Some(&self.config.default_remote), notes_ref).await
+                    {
+                        push_info.warnings.push(format!("Release note not pushed: {}", e));
+                    }
                     self.release_state.commits_pushed = true;
-                    self.release_state.tags_pushed = true;
+                    self.release_state.tags_pushed = self.config.auto_push_tags;
                     Some(push_info)
                 }
                 Err(e) => {
@@ -177,21 +322,27 @@ impl GitManager {
         Ok(ReleaseResult {
             version: version.clone(),
             commit,
-            tag,
+            tags: self.release_state.release_tags.clone(),
             push_info,
             duration,
         })
     }
 
-    /// Push release commits and tags to remote
+    /// Push the release commit and this release's tags to remote atomically
     async fn push_release(&self) -> Result<PushInfo> {
-        self.repository.push_to_remote(
-            Some(&self.config.default_remote),
-            self.config.auto_push_tags,
-        ).await
+        let tag_names: Vec<String> = if self.config.auto_push_tags {
+            self.release_state.release_tags.iter().map(|tag| tag.name.clone()).collect()
+        } else {
+            Vec::new()
+        };
+
+        self.repository
+            .push_atomic(Some(&self.config.default_remote), self.config.push_branch.as_deref(), &tag_names, None)
+            .await
     }
 
     /// Rollback a release operation
+    #[tracing::instrument(skip_all)]
     pub async fn rollback_release(&mut self) -> Result<RollbackResult> {
         let start_time = std::time::Instant::now();
         let mut rolled_back_operations = Vec::new();
@@ -200,12 +351,14 @@ impl GitManager {
 
         // Rollback in reverse order of operations
 
-        // 1. Delete remote tag if it was pushed
+        // 1. Delete remote tags if they were pushed (this also removes the local tag)
+        let mut deleted_tags = Vec::new();
         if self.release_state.tags_pushed {
-            if let Some(ref tag_info) = self.release_state.release_tag {
+            for tag_info in &self.release_state.release_tags {
                 match self.repository.delete_tag(&tag_info.name, true).await {
                     Ok(()) => {
                         rolled_back_operations.push(format!("Deleted remote tag {}", tag_info.name));
+                        deleted_tags.push(tag_info.name.clone());
                     }
                     Err(e) => {
                         warnings.push(format!("Failed to delete remote tag {}: {}", tag_info.name, e));
@@ -214,8 +367,11 @@ impl GitManager {
             }
         }
 
-        // 2. Delete local tag
-        if let Some(ref tag_info) = self.release_state.release_tag {
+        // 2. Delete local tags not already removed above
+        for tag_info in &self.release_state.release_tags {
+            if deleted_tags.contains(&tag_info.name) {
+                continue;
+            }
             match self.repository.delete_tag(&tag_info.name, false).await {
                 Ok(()) => {
                     rolled_back_operations.push(format!("Deleted local tag {}", tag_info.name));
@@ -227,14 +383,51 @@ impl GitManager {
             }
         }
 
-        // 3. Reset to previous commit if we have it
-        if let Some(ref previous_head) = self.release_state.previous_head {
-            match self.repository.reset_to_commit(previous_head, ResetType::Hard).await {
-                Ok(()) => {
-                    rolled_back_operations.push("Reset to previous commit".to_string());
+        // 3. Remove the note recording the release
+        if let (Some(notes_ref), Some(release_commit)) = (&self.config.notes_ref, &self.release_state.release_commit) {
+            match self.repository.remove_note(notes_ref, &release_commit.hash).await {
+                Ok(true) => rolled_back_operations.push(format!("Removed release note from {}", notes_ref)),
+                Ok(false) => {}
+                Err(e) => warnings.push(format!("Failed to remove release note: {}", e)),
+            }
+        }
+
+        // 4. Undo the release commit: revert it, keeping history (and any later commits) intact,
+        //    or reset to the pre-release HEAD, unless that would discard later commits
+        if self.config.revert_on_rollback
+            && let Some(release_commit) = self.release_state.release_commit.clone()
+        {
+            match self.revert_release_commit(&release_commit).await {
+                Ok(Some(revert)) => {
+                    rolled_back_operations.push(format!(
+                        "Reverted release commit {} with {}",
+                        release_commit.short_hash, revert.short_hash
+                    ));
                 }
+                Ok(None) => {}
                 Err(e) => {
-                    warnings.push(format!("Failed to reset to previous commit: {}", e));
+                    warnings.push(format!("Failed to revert release commit {}: {}", release_commit.short_hash, e));
+                    success = false;
+                }
+            }
+        } else if let Some(previous_head) = self.release_state.previous_head.clone() {
+            match self.reset_needed(&previous_head).await {
+                Ok(true) => match self.repository.reset(&previous_head, ResetType::Hard).await {
+                    Ok(reset) => {
+                        rolled_back_operations.push(format!(
+                            "Reset from {} to previous commit {}",
+                            short_hash(&reset.previous_head),
+                            short_hash(&reset.head)
+                        ));
+                    }
+                    Err(e) => {
+                        warnings.push(format!("Failed to reset to previous commit: {}", e));
+                        success = false;
+                    }
+                },
+                Ok(false) => {}
+                Err(e) => {
+                    warnings.push(e.to_string());
                     success = false;
                 }
             }
@@ -253,22 +446,96 @@ impl GitManager {
         })
     }
 
-    /// Generate commit message for release
-    fn generate_commit_message(&self, version: &Version) -> String {
-        if let Some(ref template) = self.config.commit_message_template {
-            template.replace("{version}", &version.to_string())
-        } else {
-            format!("release: v{}", version)
+    /// Record the current HEAD as the commit to reset to on rollback
+    pub async fn capture_previous_head(&mut self) -> Result<String> {
+        let current_branch = self.repository.get_current_branch().await?;
+        self.release_state.previous_head = Some(current_branch.commit_hash.clone());
+        Ok(current_branch.commit_hash)
+    }
+
+    /// Restore the pre-release HEAD and release commit recorded by an earlier process (for rollback)
+    pub fn restore_release_commit(&mut self, previous_head: Option<String>, release_commit: Option<CommitInfo>) {
+        self.release_state.previous_head = previous_head;
+        self.release_state.release_commit = release_commit;
+    }
+
+    /// Revert the release commit if HEAD contains it; returns the revert commit
+    async fn revert_release_commit(&self, release_commit: &CommitInfo) -> Result<Option<CommitInfo>> {
+        let head = self.repository.get_current_branch().await?.commit_hash;
+        if head != release_commit.hash && !self.repository.is_ancestor(&release_commit.hash, &head).await? {
+            return Ok(None);
         }
+        self.repository.revert(&release_commit.hash, None).await.map(Some)
     }
 
-    /// Generate tag message for release
-    fn generate_tag_message(&self, version: &Version) -> String {
-        if let Some(ref template) = self.config.tag_message_template {
-            template.replace("{version}", &version.to_string())
-        } else {
-            format!("Release v{}", version)
+    /// Check whether HEAD has to move back to `previous_head`, and that only the release commit is lost
+    async fn reset_needed(&self, previous_head: &str) -> Result<bool> {
+        let head = self.repository.get_current_branch().await?.commit_hash;
+        if head == previous_head {
+            return Ok(false);
+        }
+
+        let unsafe_reset = |reason: String| GitError::UnsafeReset {
+            target: short_hash(previous_head).to_string(),
+            reason,
+        };
+
+        let Some(release_commit) = &self.release_state.release_commit else {
+            return Err(unsafe_reset(format!(
+                "HEAD moved to {} but no release commit was recorded",
+                short_hash(&head)
+            )).into());
+        };
+
+        if head != release_commit.hash {
+            let reason = if self.repository.is_ancestor(&release_commit.hash, &head).await? {
+                format!("commits were made on top of release commit {}", release_commit.short_hash)
+            } else {
+                format!("HEAD ({}) is no longer at release commit {}", short_hash(&head), release_commit.short_hash)
+            };
+            return Err(unsafe_reset(reason).into());
+        }
+
+        if !self.repository.is_ancestor(previous_head, &head).await? {
+            return Err(unsafe_reset(format!(
+                "release commit {} does not descend from it",
+                release_commit.short_hash
+            )).into());
         }
+
+        Ok(true)
+    }
+
+    /// Restore knowledge of release tags created by an earlier process (for rollback)
+    pub fn restore_release_tags(&mut self, tags: Vec<TagInfo>, pushed: bool) {
+        self.release_state.release_tags = tags;
+        self.release_state.tags_pushed = pushed;
+    }
+
+    /// Render the tag name for a release
+    pub fn tag_name(&self, context: &TemplateContext) -> Result<String> {
+        render_template(&self.config.tag_name_template, context)
+    }
+
+    /// Render the tag name for one package in an independent-versioning release
+    pub fn package_tag_name(&self, context: &TemplateContext) -> Result<String> {
+        render_template(&self.config.package_tag_template, context)
+    }
+
+    /// Generate commit message for release
+    fn generate_commit_message(&self, context: &TemplateContext) -> Result<String> {
+        let template = self.config.commit_message_template
+            .as_deref()
+            .unwrap_or(DEFAULT_COMMIT_MESSAGE_TEMPLATE);
+        render_template(template, context)
+    }
+
+    /// Generate tag message for release
+    fn generate_tag_message(&self, context: &TemplateContext) -> Result<String> {
+        let template = self.config.tag_message_template
+            .as_deref()
+            .unwrap_or(DEFAULT_TAG_MESSAGE_TEMPLATE);
+        render_template(template, context)
     }
 
     /// Check if working directory is clean
@@ -288,7 +555,7 @@ impl GitManager {
 
     /// Check if a version tag already exists
     pub async fn version_tag_exists(&self, version: &Version) -> Result<bool> {
-        let tag_name = format!("v{}", version);
+        let tag_name = self.tag_name(&TemplateContext::new(version))?;
         self.repository.tag_exists(&tag_name).await
     }
 
@@ -314,7 +581,7 @@ impl GitManager {
 
     /// Check if there's an active release
     pub fn has_active_release(&self) -> bool {
-        self.release_state.release_commit.is_some() || self.release_state.release_tag.is_some()
+        self.release_state.release_commit.is_some() || !self.release_state.release_tags.is_empty()
     }
 
     /// Get current release state
@@ -395,11 +662,16 @@ impl ReleaseResult {
     pub fn format_result(&self) -> String {
         let mut result = format!("🎉 Release v{} completed successfully!\n", self.version);
         result.push_str(&format!("📦 Commit: {} ({})\n", self.commit.short_hash, self.commit.message));
-        result.push_str(&format!("🏷️  Tag: {}\n", self.tag.name));
+        for tag in &self.tags {
+            result.push_str(&format!("🏷️  Tag: {}\n", tag.name));
+        }
         
         if let Some(ref push_info) = self.push_info {
             result.push_str(&format!("📤 Pushed to {}: {} commits, {} tags\n", 
                 push_info.remote_name, push_info.commits_pushed, push_info.tags_pushed));
+            for warning in &push_info.warnings {
+                result.push_str(&format!("⚠️  {}\n", warning));
+            }
         }
         
         result.push_str(&format!("⏱️  Duration: {:.2}s\n", self.duration.as_secs_f64()));
@@ -455,4 +727,8 @@ impl RepositoryStats {
             upstream_status
         )
     }
-}
\ No newline at end of file
+}
+/// Abbreviate a commit hash for messages
+fn short_hash(hash: &str) -> &str {
+    hash.get(..7).unwrap_or(hash)
+}
@@ -1,17 +1,43 @@
 //! Git operations and management for release workflows.
 //!
-//! This module provides comprehensive Git integration using the gix library,
-//! offering atomic operations, rollback capabilities, and release coordination.
+//! This module provides Git integration for releases, offering atomic operations,
+//! rollback capabilities, and release coordination. Repository operations (fetch,
+//! pull, tags, status, diffs, log, stash, ...) run the `git` CLI, so the user's
+//! configuration, hooks and credential helpers apply unchanged; remote ref
+//! listing speaks the git protocol directly through gix's transport layer.
 
 mod operations;
 mod manager;
+mod credentials;
+mod ls_remote;
+mod progress;
+mod remote;
+mod template;
+#[cfg(test)]
+pub(crate) mod test_repo;
 
 pub use operations::{
     GitOperations, GitRepository, CommitInfo, TagInfo, PushInfo, BranchInfo, RemoteInfo,
-    ResetType, ValidationResult,
+    ResetType, ResetInfo, FixupKind, WorktreeInfo, ValidationResult, FetchOptions, FetchInfo, RefUpdate, PullStrategy, PullInfo, PullOutcome,
+    UpstreamRef, Divergence, TagKind, TagOptions, StatusReport, StatusEntry, FileChange,
+    DiffTarget, DiffOptions, FileDiff, DiffHunk, DiffLine, DiffLineKind, LogOptions, CommitLog,
+    StashOptions, StashEntry, CloneOptions, CloneOperation, TrustedKeys, SignatureStatus,
+    SignatureVerification, Note, RELEASE_NOTES_REF, BlameOptions, BlameLine, Blame,
+    ArchiveFormat, ArchiveOptions, ArchiveInfo, LsRemoteOptions, RemoteRef,
 };
+pub use credentials::{
+    Credential, CredentialProvider, CredentialSource, Credentials, EnvTokenProvider, SshAgentProvider,
+    CredentialHelperProvider, GIT_TOKEN_ENV, GIT_USERNAME_ENV,
+};
+pub use progress::{progress_channel, ProgressEvent, ProgressPhase, ProgressSender, ProgressSink};
+pub use remote::{HostingProvider, RemoteUrl};
 pub use manager::{
-    GitManager, GitConfig, ReleaseResult, RollbackResult, BackupPoint, RepositoryStats,
+    GitManager, GitConfig, ReleaseRecorder, ReleaseResult, RollbackResult, BackupPoint, RepositoryStats,
+};
+pub use template::{
+    TemplateContext, TemplateKind, render_template, template_glob, validate_template, validate_template_for,
+    TAG_TEMPLATE_VARIABLES, PACKAGE_TAG_TEMPLATE_VARIABLES, MESSAGE_TEMPLATE_VARIABLES, BRANCH_TEMPLATE_VARIABLES,
+    DEFAULT_TAG_TEMPLATE, DEFAULT_PACKAGE_TAG_TEMPLATE, DEFAULT_COMMIT_MESSAGE_TEMPLATE, DEFAULT_TAG_MESSAGE_TEMPLATE,
 };
 
 use crate::error::Result;
@@ -28,31 +54,31 @@ pub fn create_git_manager_with_config(config: GitConfig) -> Result<GitManager> {
 
 /// Quick validation of Git repository for release
 pub async fn quick_git_validation() -> Result<ValidationResult> {
-    let repo = GitRepository::open(".")?;
+    let repo = GitRepository::discover(".")?;
     repo.validate_release_readiness().await
 }
 
 /// Check if current directory is a clean Git repository
 pub async fn is_git_clean() -> Result<bool> {
-    let repo = GitRepository::open(".")?;
+    let repo = GitRepository::discover(".")?;
     repo.is_working_directory_clean().await
 }
 
 /// Get current Git branch information
 pub async fn current_git_branch() -> Result<BranchInfo> {
-    let repo = GitRepository::open(".")?;
+    let repo = GitRepository::discover(".")?;
     repo.get_current_branch().await
 }
 
-/// Check if a version tag exists
-pub async fn version_tag_exists(version: &semver::Version) -> Result<bool> {
-    let repo = GitRepository::open(".")?;
-    let tag_name = format!("v{}", version);
+/// Check if a version tag exists, naming it with the given tag template
+pub async fn version_tag_exists(version: &semver::Version, tag_template: &str) -> Result<bool> {
+    let repo = GitRepository::discover(".")?;
+    let tag_name = render_template(tag_template, &TemplateContext::new(version))?;
     repo.tag_exists(&tag_name).await
 }
 
 /// Get recent commit history
 pub async fn get_recent_commits(count: usize) -> Result<Vec<CommitInfo>> {
-    let repo = GitRepository::open(".")?;
+    let repo = GitRepository::discover(".")?;
     repo.get_recent_commits(count).await
 }
\ No newline at end of file
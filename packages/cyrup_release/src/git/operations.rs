@@ -1,8 +1,13 @@
 //! Git operations using CLI commands via tokio::process
 
-use crate::error::{Result, GitError};
+use crate::error::{Result, GitError, ReleaseError};
+use crate::git::credentials::Credentials;
+use crate::git::ls_remote;
+use crate::git::progress::{progress_channel, ProgressEvent, ProgressSink};
+use crate::git::remote::RemoteUrl;
 use semver::Version;
 use std::path::{Path, PathBuf};
+use sugars_async_stream::AsyncStream;
 use tokio::process::Command;
 
 /// Trait defining required Git operations for release management
@@ -10,15 +15,51 @@ use tokio::process::Command;
 pub trait GitOperations {
     /// Creates a release commit with all staged changes
     async fn create_release_commit(&self, version: &Version, message: Option<String>) -> Result<CommitInfo>;
+
+    /// Stages every change in the working tree and commits it with the given message
+    async fn commit_all(&self, message: &str) -> Result<CommitInfo>;
+
+    /// Like `commit_all`, leaving the given paths (relative to the repository root) unstaged
+    async fn commit_all_excluding(&self, message: &str, excluded: &[PathBuf]) -> Result<CommitInfo>;
     
     /// Creates an annotated git tag for the specified version
     async fn create_version_tag(&self, version: &Version, message: Option<String>) -> Result<TagInfo>;
+
+    /// Creates an annotated git tag with an explicit name
+    async fn create_tag(&self, tag_name: &str, message: Option<String>) -> Result<TagInfo>;
+
+    /// Creates a lightweight, annotated, or signed tag
+    async fn create_tag_with_options(&self, tag_name: &str, options: &TagOptions) -> Result<TagInfo>;
+
+    /// Lists tags, sorted by name, optionally only those matching a glob (e.g. `mypkg-v*`)
+    async fn list_tags(&self, pattern: Option<&str>) -> Result<Vec<TagInfo>>;
     
     /// Pushes commits and optionally tags to a remote repository
     async fn push_to_remote(&self, remote_name: Option<&str>, push_tags: bool) -> Result<PushInfo>;
+
+    /// Pushes HEAD (to `branch` if given) and the given tags in a single atomic push, reporting git's progress to `progress`
+    async fn push_atomic(&self, remote_name: Option<&str>, branch: Option<&str>, tag_names: &[String], progress: Option<&dyn ProgressSink>) -> Result<PushInfo>;
     
     /// Checks if the working directory has uncommitted changes
     async fn is_working_directory_clean(&self) -> Result<bool>;
+
+    /// Classifies the changes in the working tree and index
+    async fn status(&self) -> Result<StatusReport>;
+
+    /// Stashes uncommitted changes, returning the new entry or `None` if there was nothing to stash
+    async fn stash_save(&self, options: &StashOptions) -> Result<Option<StashEntry>>;
+
+    /// Lists stash entries, newest first
+    async fn stash_list(&self) -> Result<Vec<StashEntry>>;
+
+    /// Applies a stash entry, keeping it in the stash list
+    async fn stash_apply(&self, reference: &str) -> Result<()>;
+
+    /// Applies a stash entry and drops it; a conflicting entry is kept
+    async fn stash_pop(&self, reference: &str) -> Result<()>;
+
+    /// Compares a commit with another commit, the index, or the working tree
+    async fn diff(&self, from: &str, to: &DiffTarget, options: &DiffOptions) -> Result<Vec<FileDiff>>;
     
     /// Gets information about the current branch
     async fn get_current_branch(&self) -> Result<BranchInfo>;
@@ -31,15 +72,126 @@ pub trait GitOperations {
     
     /// Resets the repository to a specific commit
     async fn reset_to_commit(&self, commit_id: &str, reset_type: ResetType) -> Result<()>;
+
+    /// Moves HEAD (and the checked-out branch) to any revision, updating the index and, for hard resets, the working tree
+    async fn reset(&self, rev: &str, reset_type: ResetType) -> Result<ResetInfo>;
     
     /// Retrieves the N most recent commits
     async fn get_recent_commits(&self, count: usize) -> Result<Vec<CommitInfo>>;
+
+    /// Walks the history from a revision, yielding commits as git produces them
+    async fn log(&self, options: &LogOptions) -> Result<CommitLog>;
     
-    /// Gets all configured git remotes
+    /// Gets all configured git remotes, sorted by name
     async fn get_remotes(&self) -> Result<Vec<RemoteInfo>>;
+
+    /// Gets the fetch (or push) URL of a remote
+    async fn remote_url(&self, name: &str, push: bool) -> Result<String>;
+
+    /// Adds a remote
+    async fn add_remote(&self, name: &str, url: &str) -> Result<()>;
+
+    /// Removes a remote and its remote-tracking branches
+    async fn remove_remote(&self, name: &str) -> Result<()>;
+
+    /// Changes the fetch (or push) URL of a remote
+    async fn set_remote_url(&self, name: &str, url: &str, push: bool) -> Result<()>;
     
     /// Validates that the repository is ready for a release
     async fn validate_release_readiness(&self) -> Result<ValidationResult>;
+
+    /// Gets the root directory of the repository's working tree
+    async fn top_level(&self) -> Result<PathBuf>;
+
+    /// Checks out HEAD into a new worktree with a detached HEAD
+    async fn add_worktree(&self, path: &Path) -> Result<()>;
+
+    /// Removes a worktree, discarding any changes in it
+    async fn remove_worktree(&self, path: &Path) -> Result<()>;
+
+    /// Lists the main worktree and every linked worktree
+    async fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>>;
+
+    /// Finds the worktree that has a local branch checked out
+    async fn find_worktree_for_branch(&self, branch_name: &str) -> Result<Option<WorktreeInfo>>;
+
+    /// Checks if a local branch is checked out in a worktree other than this one
+    async fn is_branch_checked_out_elsewhere(&self, branch_name: &str) -> Result<bool>;
+
+    /// Deletes a local branch, refusing if another worktree has it checked out
+    async fn delete_branch(&self, branch_name: &str, force: bool) -> Result<()>;
+
+    /// Points a local branch at a revision, refusing if another worktree has it checked out
+    async fn reset_branch(&self, branch_name: &str, rev: &str) -> Result<()>;
+
+    /// Checks if a branch exists locally or on any remote
+    async fn branch_exists(&self, branch_name: &str) -> Result<bool>;
+
+    /// Checks out a branch, creating it at `start_point` if given
+    async fn checkout_branch(&self, branch_name: &str, start_point: Option<&str>) -> Result<()>;
+
+    /// Checks if `ancestor` is reachable from `descendant`
+    async fn is_ancestor(&self, ancestor: &str, descendant: &str) -> Result<bool>;
+
+    /// Fetches from a remote, reporting git's progress to `progress`
+    async fn fetch(&self, options: &FetchOptions, progress: Option<&dyn ProgressSink>) -> Result<FetchInfo>;
+
+    /// Fetches the upstream of a branch (the current one if `None`) and integrates it with `strategy`
+    async fn pull(&self, branch: Option<&str>, strategy: PullStrategy, progress: Option<&dyn ProgressSink>) -> Result<PullInfo>;
+
+    /// Gets the remote and remote ref a branch tracks, if it has an upstream
+    async fn upstream_of(&self, branch_name: &str) -> Result<Option<UpstreamRef>>;
+
+    /// Counts the commits only reachable from `local` and only reachable from `upstream`
+    async fn divergence(&self, local: &str, upstream: &str) -> Result<Divergence>;
+
+    /// Applies the changes of a single commit on top of HEAD, keeping its message and author
+    async fn cherry_pick(&self, commit: &str) -> Result<CommitInfo>;
+
+    /// Commits the inverse of a single commit on top of HEAD (git's "Revert ..." message if `None`)
+    async fn revert(&self, commit: &str, message: Option<String>) -> Result<CommitInfo>;
+
+    /// Amends HEAD with all changes in the working tree, keeping its message if `message` is `None`
+    async fn commit_amend(&self, message: Option<String>) -> Result<CommitInfo>;
+
+    /// Commits all changes in the working tree as a fixup or squash of an earlier commit
+    async fn commit_fixup(&self, target: &str, kind: FixupKind) -> Result<CommitInfo>;
+
+    /// Folds the fixup and squash commits since `base` into the commits they target
+    async fn autosquash(&self, base: &str) -> Result<CommitInfo>;
+
+    /// Annotates the lines of a file with the commit that last changed them, streamed as git finds them
+    async fn blame(&self, path: &Path, options: &BlameOptions) -> Result<Blame>;
+
+    /// Writes a tar or zip archive of a commit's tree to a file
+    async fn archive(&self, rev: &str, output: &Path, options: &ArchiveOptions) -> Result<ArchiveInfo>;
+
+    /// Lists refs on a remote without fetching them
+    async fn ls_remote(&self, options: &LsRemoteOptions) -> Result<Vec<RemoteRef>>;
+
+    /// Looks up a single ref on a remote (e.g. `refs/tags/v1.0.0`), `None` if the remote does not have it
+    async fn find_remote_ref(&self, remote_name: Option<&str>, ref_name: &str, timeout: Option<std::time::Duration>) -> Result<Option<RemoteRef>>;
+
+    /// Reads the note attached to a commit under a notes ref (e.g. `refs/notes/releases`)
+    async fn read_note(&self, notes_ref: &str, rev: &str) -> Result<Option<String>>;
+
+    /// Attaches a note to a commit, replacing any note it has under the same notes ref
+    async fn write_note(&self, notes_ref: &str, rev: &str, message: &str) -> Result<()>;
+
+    /// Removes the note of a commit, returning whether it had one
+    async fn remove_note(&self, notes_ref: &str, rev: &str) -> Result<bool>;
+
+    /// Lists the notes under a notes ref
+    async fn list_notes(&self, notes_ref: &str) -> Result<Vec<Note>>;
+
+    /// Pushes a notes ref to a remote
+    async fn push_notes(&self, remote_name: Option<&str>, notes_ref: &str) -> Result<()>;
+
+    /// Verifies the GPG or SSH signature of a commit against the trusted keys
+    async fn verify_commit_signature(&self, rev: &str, keys: &TrustedKeys) -> Result<SignatureVerification>;
+
+    /// Verifies the GPG or SSH signature of an annotated tag against the trusted keys
+    async fn verify_tag_signature(&self, tag_name: &str, keys: &TrustedKeys) -> Result<SignatureVerification>;
 }
 
 /// Information about a git commit
@@ -76,6 +228,870 @@ pub struct TagInfo {
     pub is_annotated: bool,
 }
 
+/// Changes in the working tree and index, with paths relative to the repository root
+#[derive(Debug, Clone, Default)]
+pub struct StatusReport {
+    /// Changes staged in the index
+    pub staged: Vec<StatusEntry>,
+    /// Changes to tracked files not staged yet
+    pub unstaged: Vec<StatusEntry>,
+    /// Files git does not track (untracked directories are listed once, with a trailing '/')
+    pub untracked: Vec<PathBuf>,
+    /// Files excluded by `.gitignore` (ignored directories are listed once)
+    pub ignored: Vec<PathBuf>,
+    /// Files with unresolved merge conflicts
+    pub conflicted: Vec<PathBuf>,
+}
+
+/// A changed file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEntry {
+    /// Path of the file
+    pub path: PathBuf,
+    /// How the file changed
+    pub change: FileChange,
+}
+
+/// How a file changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileChange {
+    /// The file was added
+    Added,
+    /// The file's contents changed
+    Modified,
+    /// The file was deleted
+    Deleted,
+    /// The file was renamed
+    Renamed {
+        /// Previous path
+        from: PathBuf,
+    },
+    /// The file was copied
+    Copied {
+        /// Path it was copied from
+        from: PathBuf,
+    },
+    /// The file's type changed (e.g. regular file to symlink)
+    TypeChanged,
+}
+
+impl StatusReport {
+    /// Check if there are no changes at all, ignoring ignored files
+    pub fn is_clean(&self) -> bool {
+        !self.has_tracked_changes() && self.untracked.is_empty()
+    }
+
+    /// Check if tracked files are staged, modified, or conflicted
+    pub fn has_tracked_changes(&self) -> bool {
+        !self.staged.is_empty() || !self.unstaged.is_empty() || !self.conflicted.is_empty()
+    }
+
+    /// Summarize the changes by kind (e.g. "2 unstaged, 1 untracked")
+    pub fn summary(&self) -> String {
+        let counts = [
+            (self.staged.len(), "staged"),
+            (self.unstaged.len(), "unstaged"),
+            (self.conflicted.len(), "conflicted"),
+            (self.untracked.len(), "untracked"),
+        ];
+        let parts: Vec<String> = counts.iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, kind)| format!("{} {}", count, kind))
+            .collect();
+        if parts.is_empty() {
+            "clean".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    /// Parse `git status --porcelain=v2 -z` output
+    fn parse(output: &[u8]) -> Self {
+        let mut report = Self::default();
+        let mut records = output.split(|byte| *byte == 0)
+            .map(|record| String::from_utf8_lossy(record).into_owned());
+
+        while let Some(record) = records.next() {
+            let mut fields = record.splitn(2, ' ');
+            let (kind, rest) = (fields.next().unwrap_or_default(), fields.next().unwrap_or_default());
+            match kind {
+                "?" => report.untracked.push(PathBuf::from(rest)),
+                "!" => report.ignored.push(PathBuf::from(rest)),
+                // u XY sub m1 m2 m3 mW h1 h2 h3 path
+                "u" => if let Some(path) = rest.splitn(10, ' ').nth(9) {
+                    report.conflicted.push(PathBuf::from(path));
+                },
+                // 1 XY sub mH mI mW hH hI path
+                "1" => {
+                    let fields: Vec<&str> = rest.splitn(8, ' ').collect();
+                    if let [xy, .., path] = fields[..] {
+                        report.push_changes(xy, PathBuf::from(path), None);
+                    }
+                }
+                // 2 XY sub mH mI mW hH hI Xscore path, followed by the original path
+                "2" => {
+                    let fields: Vec<&str> = rest.splitn(9, ' ').collect();
+                    let from = records.next().map(PathBuf::from);
+                    if let [xy, .., path] = fields[..] {
+                        report.push_changes(xy, PathBuf::from(path), from);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        report
+    }
+
+    /// Record the index (X) and worktree (Y) changes of an `XY` status code
+    fn push_changes(&mut self, xy: &str, path: PathBuf, from: Option<PathBuf>) {
+        let mut codes = xy.chars();
+        let change = |code: char| match code {
+            'A' => Some(FileChange::Added),
+            'M' => Some(FileChange::Modified),
+            'D' => Some(FileChange::Deleted),
+            'T' => Some(FileChange::TypeChanged),
+            'R' => Some(FileChange::Renamed { from: from.clone().unwrap_or_default() }),
+            'C' => Some(FileChange::Copied { from: from.clone().unwrap_or_default() }),
+            _ => None,
+        };
+        if let Some(change) = codes.next().and_then(change) {
+            self.staged.push(StatusEntry { path: path.clone(), change });
+        }
+        if let Some(change) = codes.next().and_then(change) {
+            self.unstaged.push(StatusEntry { path, change });
+        }
+    }
+}
+
+/// What `stash_save` stashes
+#[derive(Debug, Clone, Default)]
+pub struct StashOptions {
+    /// Stash message (git's "WIP on <branch>" if `None`)
+    pub message: Option<String>,
+    /// Also stash untracked files
+    pub include_untracked: bool,
+    /// Paths (relative to the repository root, globs allowed) left in the working tree
+    pub excluded: Vec<String>,
+}
+
+/// An entry of the stash list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashEntry {
+    /// Reference of the entry (e.g. `stash@{0}`); changes as entries are added and dropped
+    pub reference: String,
+    /// Commit holding the stashed changes; stable for the entry's lifetime
+    pub commit: String,
+    /// Stash message (e.g. "On main: before release")
+    pub message: String,
+}
+
+impl StashEntry {
+    /// Parse `git stash list --format=%gd%x00%H%x00%gs` output
+    fn parse_list(output: &str) -> Vec<Self> {
+        output.lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\0');
+                Some(Self {
+                    reference: fields.next()?.to_string(),
+                    commit: fields.next()?.to_string(),
+                    message: fields.next().unwrap_or_default().to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Which commits `log` walks
+#[derive(Debug, Clone, Default)]
+pub struct LogOptions {
+    /// Revision to start from (defaults to HEAD)
+    pub from: Option<String>,
+    /// Stop at commits reachable from this revision (e.g. the previous release tag)
+    pub until: Option<String>,
+    /// Only commits touching these paths, relative to the repository root (globs like `packages/collections/**`)
+    pub paths: Vec<String>,
+    /// Follow only the first parent of merge commits
+    pub first_parent: bool,
+    /// Maximum number of commits
+    pub max_count: Option<usize>,
+}
+
+/// Commits walked by `log`, newest first, read from git as they are produced
+#[derive(Debug)]
+pub struct CommitLog {
+    /// The running `git log`
+    child: tokio::process::Child,
+    /// Its output
+    reader: tokio::io::BufReader<tokio::process::ChildStdout>,
+    /// Command line, for errors
+    operation: String,
+}
+
+impl CommitLog {
+    /// Get the next commit, or `None` when the history is exhausted
+    pub async fn next(&mut self) -> Option<Result<CommitInfo>> {
+        use tokio::io::AsyncBufReadExt;
+
+        loop {
+            let mut record = Vec::new();
+            match self.reader.read_until(0x1e, &mut record).await {
+                Ok(0) => return self.finish().await.err().map(Err),
+                Ok(_) => {}
+                Err(e) => return Some(Err(GitError::OperationFailed {
+                    operation: self.operation.clone(),
+                    reason: e.to_string(),
+                }.into())),
+            }
+
+            let record = String::from_utf8_lossy(&record);
+            let record = record.trim_start_matches('\n').trim_end_matches('\x1e');
+            if let Some(commit) = parse_commit_record(record) {
+                return Some(Ok(commit));
+            }
+        }
+    }
+
+    /// Read every remaining commit
+    pub async fn collect(mut self) -> Result<Vec<CommitInfo>> {
+        let mut commits = Vec::new();
+        while let Some(commit) = self.next().await {
+            commits.push(commit?);
+        }
+        Ok(commits)
+    }
+
+    /// Wait for git to exit, reporting a failed walk (e.g. an unknown revision)
+    async fn finish(&mut self) -> Result<()> {
+        use tokio::io::AsyncReadExt;
+
+        let mut stderr = String::new();
+        if let Some(pipe) = self.child.stderr.as_mut() {
+            pipe.read_to_string(&mut stderr).await?;
+        }
+        let status = self.child.wait().await?;
+        if !status.success() {
+            return Err(GitError::OperationFailed {
+                operation: self.operation.clone(),
+                reason: stderr.trim().to_string(),
+            }.into());
+        }
+        Ok(())
+    }
+}
+
+/// `git log` format of one commit: NUL-separated fields ending with an RS byte
+const COMMIT_RECORD_FORMAT: &str = "--format=%H%x00%h%x00%P%x00%an%x00%ae%x00%aI%x00%s%x1e";
+
+/// Parse a commit written with `COMMIT_RECORD_FORMAT`
+fn parse_commit_record(record: &str) -> Option<CommitInfo> {
+    let fields: Vec<&str> = record.split('\0').collect();
+    let [hash, short_hash, parents, author_name, author_email, date, subject] = fields[..] else {
+        return None;
+    };
+    Some(CommitInfo {
+        hash: hash.to_string(),
+        short_hash: short_hash.to_string(),
+        message: subject.to_string(),
+        author_name: author_name.to_string(),
+        author_email: author_email.to_string(),
+        timestamp: chrono::DateTime::parse_from_rfc3339(date)
+            .map_or_else(|_| chrono::Utc::now(), |date| date.with_timezone(&chrono::Utc)),
+        parents: parents.split_whitespace().map(str::to_string).collect(),
+    })
+}
+
+/// What a commit is compared with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffTarget {
+    /// Another commit (or any revision, e.g. a tag)
+    Commit(String),
+    /// The staged changes
+    Index,
+    /// Tracked files in the working tree
+    WorkingTree,
+}
+
+/// Options for `diff`
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    /// Only compare these paths (relative to the repository root); everything if empty
+    pub paths: Vec<PathBuf>,
+    /// Lines of context around each hunk (git's default of 3 if `None`)
+    pub context_lines: Option<u32>,
+    /// Parse the hunks of each file, not only the change summary
+    pub include_hunks: bool,
+}
+
+/// Changes to one file, with paths relative to the repository root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// Path of the file (the new path for renames and copies)
+    pub path: PathBuf,
+    /// How the file changed
+    pub change: FileChange,
+    /// Lines added (0 for binary files)
+    pub additions: usize,
+    /// Lines removed (0 for binary files)
+    pub deletions: usize,
+    /// Whether git treats the file as binary
+    pub binary: bool,
+    /// Hunks, if requested with `DiffOptions::include_hunks`
+    pub hunks: Vec<DiffHunk>,
+}
+
+impl FileDiff {
+    /// Parse `git diff --name-status -z` output
+    fn parse_name_status(output: &[u8]) -> Vec<Self> {
+        // "R100\0old\0new\0" for renames and copies, "M\0path\0" otherwise
+        let mut fields = output.split(|byte| *byte == 0)
+            .map(|field| String::from_utf8_lossy(field).into_owned());
+        let mut files = Vec::new();
+        while let Some(status) = fields.next().filter(|status| !status.is_empty()) {
+            let first = PathBuf::from(fields.next().unwrap_or_default());
+            let (path, change) = match status.chars().next() {
+                Some('A') => (first, FileChange::Added),
+                Some('D') => (first, FileChange::Deleted),
+                Some('T') => (first, FileChange::TypeChanged),
+                Some('R') => (PathBuf::from(fields.next().unwrap_or_default()), FileChange::Renamed { from: first }),
+                Some('C') => (PathBuf::from(fields.next().unwrap_or_default()), FileChange::Copied { from: first }),
+                _ => (first, FileChange::Modified),
+            };
+            files.push(Self {
+                path,
+                change,
+                additions: 0,
+                deletions: 0,
+                binary: false,
+                hunks: Vec::new(),
+            });
+        }
+        files
+    }
+
+    /// Fill in line counts from `git diff --numstat -z` output
+    fn add_line_counts(files: &mut [Self], numstat: &[u8]) {
+        // "added\tdeleted\tpath\0", or "added\tdeleted\t\0old\0new\0" for renames; "-" counts for binary files
+        let mut fields = numstat.split(|byte| *byte == 0)
+            .map(|field| String::from_utf8_lossy(field).into_owned());
+        while let Some(record) = fields.next().filter(|record| !record.is_empty()) {
+            let mut columns = record.splitn(3, '\t');
+            let (added, deleted, path) = (columns.next(), columns.next(), columns.next().unwrap_or_default());
+            let path = if path.is_empty() {
+                fields.next();
+                fields.next().unwrap_or_default()
+            } else {
+                path.to_string()
+            };
+            if let Some(file) = files.iter_mut().find(|file| file.path.as_path() == Path::new(&path)) {
+                match (added.and_then(|n| n.parse().ok()), deleted.and_then(|n| n.parse().ok())) {
+                    (Some(added), Some(deleted)) => {
+                        file.additions = added;
+                        file.deletions = deleted;
+                    }
+                    _ => file.binary = true,
+                }
+            }
+        }
+    }
+}
+
+/// A contiguous block of changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// First line of the hunk in the old file
+    pub old_start: usize,
+    /// Number of old lines in the hunk
+    pub old_lines: usize,
+    /// First line of the hunk in the new file
+    pub new_start: usize,
+    /// Number of new lines in the hunk
+    pub new_lines: usize,
+    /// Section heading git shows after the range (e.g. the enclosing function)
+    pub heading: String,
+    /// Lines of the hunk
+    pub lines: Vec<DiffLine>,
+}
+
+/// A line of a hunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// Whether the line was kept, added, or removed
+    pub kind: DiffLineKind,
+    /// Line content without the leading marker
+    pub content: String,
+}
+
+/// Kind of a hunk line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// Unchanged line
+    Context,
+    /// Added line
+    Added,
+    /// Removed line
+    Removed,
+}
+
+impl DiffHunk {
+    /// Parse a `@@ -a,b +c,d @@ heading` header
+    fn parse_header(line: &str) -> Option<Self> {
+        let rest = line.strip_prefix("@@ -")?;
+        let (ranges, heading) = rest.split_once(" @@")?;
+        let (old, new) = ranges.split_once(" +")?;
+        let range = |range: &str| -> Option<(usize, usize)> {
+            match range.split_once(',') {
+                Some((start, lines)) => Some((start.parse().ok()?, lines.parse().ok()?)),
+                None => Some((range.parse().ok()?, 1)),
+            }
+        };
+        let (old_start, old_lines) = range(old)?;
+        let (new_start, new_lines) = range(new)?;
+        Some(Self {
+            old_start,
+            old_lines,
+            new_start,
+            new_lines,
+            heading: heading.trim().to_string(),
+            lines: Vec::new(),
+        })
+    }
+}
+
+/// Parse `git diff -p` output into hunks by the file's new path (old path for deletions)
+fn parse_patch(patch: &str) -> std::collections::HashMap<PathBuf, Vec<DiffHunk>> {
+    let mut hunks_by_path: std::collections::HashMap<PathBuf, Vec<DiffHunk>> = std::collections::HashMap::new();
+    let mut old_path: Option<PathBuf> = None;
+    let mut current_path: Option<PathBuf> = None;
+    let mut in_hunk = false;
+
+    for line in patch.lines() {
+        if line.starts_with("diff --git ") {
+            old_path = None;
+            current_path = None;
+            in_hunk = false;
+        } else if !in_hunk && let Some(path) = line.strip_prefix("--- ") {
+            old_path = patch_path(path, "a/");
+        } else if !in_hunk && let Some(path) = line.strip_prefix("+++ ") {
+            current_path = patch_path(path, "b/").or_else(|| old_path.clone());
+        } else if let Some(hunk) = DiffHunk::parse_header(line)
+            && let Some(path) = &current_path
+        {
+            hunks_by_path.entry(path.clone()).or_default().push(hunk);
+            in_hunk = true;
+        } else if in_hunk
+            && let Some(hunk) = current_path.as_ref()
+                .and_then(|path| hunks_by_path.get_mut(path))
+                .and_then(|hunks| hunks.last_mut())
+        {
+            let (kind, content) = match line.split_at_checked(1) {
+                Some(("+", content)) => (DiffLineKind::Added, content),
+                Some(("-", content)) => (DiffLineKind::Removed, content),
+                Some((" ", content)) => (DiffLineKind::Context, content),
+                // "\ No newline at end of file" and empty context lines
+                _ if line.is_empty() => (DiffLineKind::Context, ""),
+                _ => continue,
+            };
+            hunk.lines.push(DiffLine {
+                kind,
+                content: content.to_string(),
+            });
+        }
+    }
+
+    hunks_by_path
+}
+
+/// Path of a `---`/`+++` line, `None` for /dev/null
+fn patch_path(path: &str, prefix: &str) -> Option<PathBuf> {
+    let path = path.trim_end_matches('\t');
+    // Paths with special characters are C-quoted
+    let unquoted = match path.strip_prefix('"').and_then(|path| path.strip_suffix('"')) {
+        Some(quoted) => {
+            let mut unquoted = String::with_capacity(quoted.len());
+            let mut chars = quoted.chars();
+            while let Some(c) = chars.next() {
+                if c != '\\' {
+                    unquoted.push(c);
+                    continue;
+                }
+                match chars.next() {
+                    Some('t') => unquoted.push('\t'),
+                    Some('n') => unquoted.push('\n'),
+                    Some(escaped) => unquoted.push(escaped),
+                    None => {}
+                }
+            }
+            unquoted
+        }
+        None => path.to_string(),
+    };
+    unquoted.strip_prefix(prefix).map(PathBuf::from)
+}
+
+/// Kind of tag to create
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagKind {
+    /// A plain ref to the commit, without a tag object
+    Lightweight,
+    /// A tag object with a message, tagger, and date
+    #[default]
+    Annotated,
+    /// An annotated tag signed with the configured signing key (`git tag -s`)
+    Signed,
+}
+
+/// How to create a tag
+#[derive(Debug, Clone, Default)]
+pub struct TagOptions {
+    /// Kind of tag
+    pub kind: TagKind,
+    /// Message of annotated and signed tags (defaults to "Release <tag>")
+    pub message: Option<String>,
+    /// Commit to tag (defaults to HEAD)
+    pub target: Option<String>,
+}
+
+/// Format of an archive written by `archive`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Uncompressed tar
+    Tar,
+    /// Gzip-compressed tar
+    #[default]
+    TarGz,
+    /// Zip
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Detect the format from an output file name (`.tar`, `.tar.gz`/`.tgz`, `.zip`)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_ascii_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else {
+            None
+        }
+    }
+
+    /// Name of the format as `git archive --format` takes it
+    fn as_str(self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+}
+
+/// What `archive` includes
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveOptions {
+    /// Archive format (detected from the output file name if `None`)
+    pub format: Option<ArchiveFormat>,
+    /// Directory every entry is placed under (e.g. `sugars-0.5.4`)
+    pub prefix: Option<String>,
+    /// Only paths matching these pathspecs (the whole tree if empty)
+    pub pathspecs: Vec<String>,
+}
+
+/// An archive written by `archive`
+#[derive(Debug, Clone)]
+pub struct ArchiveInfo {
+    /// Commit whose tree was archived
+    pub commit: String,
+    /// Archive file
+    pub path: PathBuf,
+    /// Format it was written in
+    pub format: ArchiveFormat,
+    /// Size of the archive in bytes
+    pub size: u64,
+}
+
+/// Which lines `blame` annotates
+#[derive(Debug, Clone, Default)]
+pub struct BlameOptions {
+    /// Revision to blame (defaults to the working tree, where uncommitted lines have an all-zero commit)
+    pub rev: Option<String>,
+    /// Only these lines (1-based, inclusive)
+    pub lines: Option<std::ops::RangeInclusive<usize>>,
+}
+
+/// A line of a file and the commit that last changed it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameLine {
+    /// Line number in the blamed revision (1-based)
+    pub line_number: usize,
+    /// Commit that last changed the line
+    pub commit: String,
+    /// Author of that commit
+    pub author_name: String,
+    /// Author email, without angle brackets
+    pub author_email: String,
+    /// Author time of that commit
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Subject of that commit
+    pub summary: String,
+    /// Line content, without the newline
+    pub content: String,
+}
+
+/// Blame of a file running in the background.
+///
+/// Lines arrive in the order git attributes them, not in file order.
+pub struct Blame {
+    /// Annotated lines, until git finishes
+    lines: AsyncStream<BlameLine>,
+    /// The running blame
+    task: tokio::task::JoinHandle<Result<()>>,
+}
+
+impl Blame {
+    /// Annotated lines; the stream ends when git exits
+    pub fn lines(&mut self) -> &mut AsyncStream<BlameLine> {
+        &mut self.lines
+    }
+
+    /// Wait for git to finish, reporting whether every line was annotated
+    pub async fn finish(self) -> Result<()> {
+        self.task.await.map_err(|e| GitError::OperationFailed {
+            operation: "git blame".to_string(),
+            reason: e.to_string(),
+        })?
+    }
+}
+
+impl std::fmt::Debug for Blame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Blame").finish_non_exhaustive()
+    }
+}
+
+/// Commit details git prints the first time `blame --incremental` names a commit
+#[derive(Debug, Clone, Default)]
+struct BlameCommit {
+    author_name: String,
+    author_email: String,
+    author_time: i64,
+    summary: String,
+}
+
+/// Read `git blame --incremental` output, sending one `BlameLine` per annotated line
+async fn stream_blame(
+    mut child: tokio::process::Child,
+    operation: String,
+    contents: Vec<String>,
+    sender: tokio::sync::mpsc::UnboundedSender<BlameLine>,
+) -> Result<()> {
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+    let stdout = child.stdout.take().ok_or_else(|| GitError::OperationFailed {
+        operation: operation.clone(),
+        reason: "stdout was not captured".to_string(),
+    })?;
+    let io_error = |e: std::io::Error| GitError::OperationFailed {
+        operation: operation.clone(),
+        reason: e.to_string(),
+    };
+
+    let mut commits: std::collections::HashMap<String, BlameCommit> = std::collections::HashMap::new();
+    // Commit, first line, and line count of the entry being read
+    let mut entry: Option<(String, usize, usize)> = None;
+    let mut lines = tokio::io::BufReader::new(stdout).lines();
+    while let Some(line) = lines.next_line().await.map_err(io_error)? {
+        let Some((commit, first_line, count)) = &entry else {
+            // "<commit> <original line> <final line> <line count>"
+            let mut fields = line.split(' ');
+            let (Some(commit), Some(_), Some(first_line), Some(count)) = (fields.next(), fields.next(), fields.next(), fields.next()) else {
+                continue;
+            };
+            let (Ok(first_line), Ok(count)) = (first_line.parse(), count.parse()) else {
+                continue;
+            };
+            commits.entry(commit.to_string()).or_default();
+            entry = Some((commit.to_string(), first_line, count));
+            continue;
+        };
+
+        let (key, value) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+        let details = commits.entry(commit.clone()).or_default();
+        match key {
+            "author" => details.author_name = value.to_string(),
+            "author-mail" => details.author_email = value.trim_start_matches('<').trim_end_matches('>').to_string(),
+            "author-time" => details.author_time = value.parse().unwrap_or_default(),
+            "summary" => details.summary = value.to_string(),
+            // "filename" ends the entry
+            "filename" => {
+                for line_number in *first_line..first_line + count {
+                    let annotated = BlameLine {
+                        line_number,
+                        commit: commit.clone(),
+                        author_name: details.author_name.clone(),
+                        author_email: details.author_email.clone(),
+                        timestamp: chrono::DateTime::from_timestamp(details.author_time, 0).unwrap_or_default(),
+                        summary: details.summary.clone(),
+                        content: contents.get(line_number - 1).cloned().unwrap_or_default(),
+                    };
+                    if sender.send(annotated).is_err() {
+                        // Nobody is listening any more; the child is killed on drop
+                        return Ok(());
+                    }
+                }
+                entry = None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut stderr = String::new();
+    if let Some(pipe) = child.stderr.as_mut() {
+        pipe.read_to_string(&mut stderr).await.map_err(io_error)?;
+    }
+    let status = child.wait().await.map_err(io_error)?;
+    if !status.success() {
+        return Err(GitError::OperationFailed {
+            operation,
+            reason: stderr.trim().to_string(),
+        }.into());
+    }
+    Ok(())
+}
+
+/// Notes ref cyrup_release records releases under
+pub const RELEASE_NOTES_REF: &str = "refs/notes/releases";
+
+/// A note attached to a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    /// Annotated commit
+    pub commit: String,
+    /// Note text
+    pub message: String,
+}
+
+/// Keys signatures are verified against; unset entries fall back to the git and GnuPG configuration
+#[derive(Debug, Clone, Default)]
+pub struct TrustedKeys {
+    /// GnuPG home directory holding the trusted keyring (`GNUPGHOME`)
+    pub gpg_home: Option<PathBuf>,
+    /// SSH allowed signers file (`gpg.ssh.allowedSignersFile`)
+    pub allowed_signers: Option<PathBuf>,
+}
+
+/// Outcome of verifying a signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// Valid signature by a trusted key
+    Good,
+    /// Valid signature by a key that is not trusted (or not in the allowed signers)
+    UntrustedKey,
+    /// Signed by a key that is not in the keyring
+    UnknownKey,
+    /// The signature does not match the signed object
+    Bad,
+    /// The signature or its key has expired
+    Expired,
+    /// The signing key has been revoked
+    Revoked,
+    /// Not signed (or a lightweight tag)
+    Unsigned,
+}
+
+/// Result of verifying the signature of a commit or tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureVerification {
+    /// Outcome
+    pub status: SignatureStatus,
+    /// Signer (GPG user ID or SSH principal), if known
+    pub signer: Option<String>,
+    /// Key ID or fingerprint, if known
+    pub key: Option<String>,
+}
+
+impl SignatureStatus {
+    /// Describe the outcome for display
+    pub fn describe(&self) -> &'static str {
+        match self {
+            SignatureStatus::Good => "good signature",
+            SignatureStatus::UntrustedKey => "signed by an untrusted key",
+            SignatureStatus::UnknownKey => "signed by an unknown key",
+            SignatureStatus::Bad => "bad signature",
+            SignatureStatus::Expired => "expired signature or key",
+            SignatureStatus::Revoked => "signed by a revoked key",
+            SignatureStatus::Unsigned => "not signed",
+        }
+    }
+}
+
+impl SignatureVerification {
+    /// Check if the signature is valid and made by a trusted key
+    pub fn is_trusted(&self) -> bool {
+        self.status == SignatureStatus::Good
+    }
+
+    /// Parse the output of `git verify-commit --raw` or `git verify-tag --raw`
+    fn parse(stderr: &str, success: bool) -> Self {
+        let mut verification = Self {
+            status: SignatureStatus::Unsigned,
+            signer: None,
+            key: None,
+        };
+
+        for line in stderr.lines().map(str::trim) {
+            if let Some(status) = line.strip_prefix("[GNUPG:] ") {
+                // GnuPG status lines: "<KEYWORD> <key id> <user id>"
+                let mut fields = status.splitn(3, ' ');
+                let keyword = fields.next().unwrap_or_default();
+                let key = fields.next().map(str::to_string);
+                let signer = fields.next().map(str::to_string);
+                let status = match keyword {
+                    "GOODSIG" => SignatureStatus::Good,
+                    "BADSIG" => SignatureStatus::Bad,
+                    "EXPSIG" | "EXPKEYSIG" => SignatureStatus::Expired,
+                    "REVKEYSIG" => SignatureStatus::Revoked,
+                    "ERRSIG" | "NO_PUBKEY" => SignatureStatus::UnknownKey,
+                    "TRUST_UNDEFINED" | "TRUST_NEVER" if verification.status == SignatureStatus::Good => {
+                        verification.status = SignatureStatus::UntrustedKey;
+                        continue;
+                    }
+                    "VALIDSIG" => {
+                        verification.key = key;
+                        continue;
+                    }
+                    _ => continue,
+                };
+                verification.status = status;
+                verification.key = verification.key.take().or(key);
+                verification.signer = signer.filter(|_| status != SignatureStatus::UnknownKey);
+            } else if let Some(rest) = line.strip_prefix("Good \"git\" signature ") {
+                // SSH: `Good "git" signature [for <principal>] with <type> key <fingerprint>`
+                let (principal, key) = rest.rsplit_once(" key ").unzip();
+                verification.key = key.map(str::to_string);
+                verification.signer = principal
+                    .and_then(|principal| principal.strip_prefix("for "))
+                    .and_then(|principal| principal.rsplit_once(" with "))
+                    .map(|(principal, _)| principal.to_string());
+                verification.status = if success && verification.signer.is_some() {
+                    SignatureStatus::Good
+                } else {
+                    SignatureStatus::UntrustedKey
+                };
+            } else if line.contains("allowedSignersFile needs to be configured") {
+                verification.status = SignatureStatus::UnknownKey;
+            } else if line.starts_with("Could not verify signature") || line.contains("Signature verification failed") {
+                verification.status = SignatureStatus::Bad;
+            }
+        }
+
+        verification
+    }
+}
+
 /// Information about a git push operation
 #[derive(Debug, Clone)]
 pub struct PushInfo {
@@ -102,6 +1118,56 @@ pub struct BranchInfo {
     pub commit_hash: String,
 }
 
+/// A worktree of the repository, as listed by `git worktree list`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeInfo {
+    /// Root of the worktree (the git directory for a bare repository)
+    pub path: PathBuf,
+    /// Commit checked out, if any
+    pub head: Option<String>,
+    /// Local branch checked out, without `refs/heads/` (`None` if detached or bare)
+    pub branch: Option<String>,
+    /// Whether this is the bare repository itself
+    pub bare: bool,
+    /// Whether the worktree is locked against pruning
+    pub locked: bool,
+    /// Whether the worktree's directory is gone and `git worktree prune` would remove it
+    pub prunable: bool,
+}
+
+impl WorktreeInfo {
+    /// Parse the output of `git worktree list --porcelain`
+    fn parse_list(output: &str) -> Vec<Self> {
+        let mut worktrees: Vec<Self> = Vec::new();
+        for line in output.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            if key == "worktree" {
+                worktrees.push(Self {
+                    path: PathBuf::from(value),
+                    head: None,
+                    branch: None,
+                    bare: false,
+                    locked: false,
+                    prunable: false,
+                });
+                continue;
+            }
+            let Some(worktree) = worktrees.last_mut() else {
+                continue;
+            };
+            match key {
+                "HEAD" => worktree.head = Some(value.to_string()),
+                "branch" => worktree.branch = Some(value.strip_prefix("refs/heads/").unwrap_or(value).to_string()),
+                "bare" => worktree.bare = true,
+                "locked" => worktree.locked = true,
+                "prunable" => worktree.prunable = true,
+                _ => {}
+            }
+        }
+        worktrees
+    }
+}
+
 /// Information about a git remote
 #[derive(Debug, Clone)]
 pub struct RemoteInfo {
@@ -114,7 +1180,7 @@ pub struct RemoteInfo {
 }
 
 /// Git reset type specifying what to reset
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResetType {
     /// Keep staged changes and working directory changes
     Soft,
@@ -124,6 +1190,25 @@ pub enum ResetType {
     Hard,
 }
 
+/// How `commit_fixup` folds its changes into the target commit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FixupKind {
+    /// Keep the target's message (`fixup!`)
+    #[default]
+    Fixup,
+    /// Let `autosquash` combine the messages (`squash!`)
+    Squash,
+}
+
+/// Where a reset moved HEAD
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResetInfo {
+    /// Commit HEAD pointed to before the reset
+    pub previous_head: String,
+    /// Commit HEAD points to now
+    pub head: String,
+}
+
 /// Result of validating release readiness
 #[derive(Debug, Clone)]
 pub struct ValidationResult {
@@ -133,24 +1218,368 @@ pub struct ValidationResult {
     pub issues: Vec<String>,
 }
 
-/// Git repository handle using CLI commands
+/// What to fetch from a remote
+#[derive(Debug, Clone, Default)]
+pub struct FetchOptions {
+    /// Remote to fetch from (defaults to "origin")
+    pub remote: Option<String>,
+    /// Refspecs to fetch; the remote's configured refspecs if empty
+    pub refspecs: Vec<String>,
+    /// Also fetch all tags
+    pub tags: bool,
+    /// Remove remote-tracking refs that no longer exist on the remote
+    pub prune: bool,
+}
+
+/// Result of a fetch
 #[derive(Debug, Clone)]
-pub struct GitRepository {
-    repo_path: PathBuf,
+pub struct FetchInfo {
+    /// Name of the remote that was fetched from
+    pub remote_name: String,
+    /// Local refs the fetch created, moved, or pruned
+    pub updated_refs: Vec<RefUpdate>,
 }
 
-impl GitRepository {
-    /// Discovers a git repository starting from the given path
-    pub fn discover(path: impl AsRef<Path>) -> Result<Self> {
-        let path = path.as_ref();
-        Ok(Self {
-            repo_path: path.to_path_buf(),
-        })
-    }
+/// A local ref changed by a fetch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefUpdate {
+    /// Full ref name
+    pub name: String,
+    /// Commit before the fetch, `None` if the ref was created
+    pub old_oid: Option<String>,
+    /// Commit after the fetch, `None` if the ref was pruned
+    pub new_oid: Option<String>,
+}
+
+/// How `pull` integrates the upstream into the local branch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PullStrategy {
+    /// Only move the branch forward; fail if it has diverged from the upstream
+    #[default]
+    FastForwardOnly,
+    /// Fast-forward when possible, otherwise create a merge commit
+    Merge,
+}
+
+/// Which refs `ls_remote` lists
+#[derive(Debug, Clone, Default)]
+pub struct LsRemoteOptions {
+    /// Remote name or URL to query (defaults to "origin")
+    pub remote: Option<String>,
+    /// Only refs matching these patterns (matched against the end of the ref name, like `git ls-remote`)
+    pub patterns: Vec<String>,
+    /// Only branches
+    pub heads: bool,
+    /// Only tags
+    pub tags: bool,
+    /// Give up if the remote has not answered in time
+    pub timeout: Option<std::time::Duration>,
+}
+
+/// A ref advertised by a remote
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteRef {
+    /// Full ref name (e.g. `refs/tags/v1.0.0`)
+    pub name: String,
+    /// Object the ref points to (the tag object for annotated tags)
+    pub oid: String,
+    /// Commit an annotated tag points to
+    pub peeled: Option<String>,
+}
+
+impl LsRemoteOptions {
+    /// Whether `ls_remote` lists a ref with this name
+    ///
+    /// Patterns match trailing path components and may use globs, like `git ls-remote`.
+    pub fn matches(&self, name: &str) -> bool {
+        if (self.heads || self.tags)
+            && !(self.heads && name.starts_with("refs/heads/"))
+            && !(self.tags && name.starts_with("refs/tags/"))
+        {
+            return false;
+        }
+        let path = format!("/{}", name);
+        self.patterns.is_empty() || self.patterns.iter().any(|pattern| {
+            match glob::Pattern::new(&format!("*/{}", pattern)) {
+                Ok(glob) => glob.matches(&path),
+                Err(_) => path.ends_with(&format!("/{}", pattern)),
+            }
+        })
+    }
+}
+
+impl RemoteRef {
+    /// Commit the ref resolves to
+    pub fn commit(&self) -> &str {
+        self.peeled.as_deref().unwrap_or(&self.oid)
+    }
+}
+
+/// Result of a pull
+#[derive(Debug, Clone)]
+pub struct PullInfo {
+    /// Local branch that was updated
+    pub branch: String,
+    /// Remote-tracking ref that was integrated
+    pub upstream: String,
+    /// Result of the fetch
+    pub fetch: FetchInfo,
+    /// How the branch was updated
+    pub outcome: PullOutcome,
+}
+
+/// How a pull updated the local branch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PullOutcome {
+    /// The branch already contained the upstream
+    UpToDate,
+    /// The branch was moved forward to the upstream
+    FastForward {
+        /// Commit before the pull
+        from: String,
+        /// Commit after the pull
+        to: String,
+    },
+    /// A merge commit was created
+    Merged {
+        /// The merge commit
+        commit: String,
+    },
+}
+
+/// Remote branch a local branch tracks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpstreamRef {
+    /// Remote name
+    pub remote: String,
+    /// Ref on the remote (e.g. "refs/heads/main")
+    pub merge_ref: String,
+}
+
+/// Commits on either side of a local branch and its upstream
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Divergence {
+    /// Commits only on the local side
+    pub ahead: usize,
+    /// Commits only on the upstream side
+    pub behind: usize,
+}
+
+/// How `GitRepository::clone_from_url` clones
+#[derive(Debug, Clone, Default)]
+pub struct CloneOptions {
+    /// Only fetch this many commits of history (shallow clone)
+    pub depth: Option<u32>,
+    /// Branch to check out instead of the remote's default branch
+    pub branch: Option<String>,
+    /// Only fetch the history of the checked-out branch
+    pub single_branch: bool,
+    /// Create a bare repository without a working tree
+    pub bare: bool,
+}
+
+/// A clone running in the background
+pub struct CloneOperation {
+    /// Progress updates, until the clone finishes
+    progress: AsyncStream<ProgressEvent>,
+    /// The clone
+    task: tokio::task::JoinHandle<Result<GitRepository>>,
+}
+
+impl CloneOperation {
+    /// Progress updates; the stream ends when git exits
+    pub fn progress(&mut self) -> &mut AsyncStream<ProgressEvent> {
+        &mut self.progress
+    }
+
+    /// Wait for the clone, returning the cloned repository
+    pub async fn finish(self) -> Result<GitRepository> {
+        self.task.await.map_err(|e| GitError::OperationFailed {
+            operation: "git clone".to_string(),
+            reason: e.to_string(),
+        })?
+    }
+}
+
+impl std::fmt::Debug for CloneOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CloneOperation").finish_non_exhaustive()
+    }
+}
+
+/// Git repository handle using CLI commands
+#[derive(Debug, Clone)]
+pub struct GitRepository {
+    /// Working tree root (the git directory of a bare repository)
+    repo_path: PathBuf,
+    /// Git directory, when found by `discover`; commands then name it explicitly
+    git_dir: Option<PathBuf>,
+    /// Whether the repository has no working tree
+    bare: bool,
+    /// Credentials for push, fetch, and ls-remote (git's own configuration if empty)
+    credentials: Credentials,
+    /// Whether commits and pushes skip the repository's hooks
+    skip_hooks: bool,
+}
+
+impl GitRepository {
+    /// Discovers the repository containing `path` the way git does.
+    ///
+    /// `GIT_DIR` and `GIT_WORK_TREE` take precedence over searching `path` and
+    /// its parents, and the search stops at `GIT_CEILING_DIRECTORIES`. Bare
+    /// repositories are found too; see `is_bare`.
+    pub fn discover(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let strategy = discovery_strategy(path);
+        let discovery_failed = |reason: String| GitError::DiscoveryFailed {
+            strategy: strategy.clone(),
+            reason,
+        };
+
+        let rev_parse = |args: &[&str]| -> Result<Vec<String>> {
+            let output = std::process::Command::new("git")
+                .args(["rev-parse"])
+                .args(args)
+                .current_dir(path)
+                .output()
+                .map_err(|e| discovery_failed(format!("could not run git in {}: {}", path.display(), e)))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let reason = stderr.lines().next().unwrap_or_default();
+                return Err(discovery_failed(reason.trim_start_matches("fatal: ").to_string()).into());
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).lines().map(str::to_string).collect())
+        };
+
+        let found = rev_parse(&["--absolute-git-dir", "--is-bare-repository", "--is-inside-work-tree"])?;
+        let [git_dir, bare, inside_work_tree] = found.as_slice() else {
+            return Err(discovery_failed(format!("unexpected git rev-parse output: {:?}", found)).into());
+        };
+        let git_dir = PathBuf::from(git_dir);
+        let bare = bare == "true";
+
+        let repo_path = if bare {
+            git_dir.clone()
+        } else if inside_work_tree == "true" {
+            PathBuf::from(rev_parse(&["--show-toplevel"])?.concat())
+        } else {
+            // Inside the git directory of a repository with a working tree
+            match git_dir.parent() {
+                Some(work_tree) if git_dir.ends_with(".git") => work_tree.to_path_buf(),
+                _ => return Err(discovery_failed(format!(
+                    "{} is inside the git directory {}, and its working tree could not be determined",
+                    path.display(),
+                    git_dir.display()
+                )).into()),
+            }
+        };
+
+        Ok(Self {
+            repo_path,
+            git_dir: Some(git_dir),
+            bare,
+            credentials: Credentials::default(),
+            skip_hooks: false,
+        })
+    }
+
+    /// Check if the repository has no working tree
+    pub fn is_bare(&self) -> bool {
+        self.bare
+    }
 
-    /// Opens a git repository at the given path
+    /// Git directory, if the repository was found by `discover`
+    pub fn git_dir(&self) -> Option<&Path> {
+        self.git_dir.as_deref()
+    }
+
+    /// Authenticate push, fetch, and ls-remote with a credential chain
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Skip repository hooks (pre-commit, commit-msg, pre-push, ...) when committing and pushing
+    pub fn skip_hooks(mut self, skip: bool) -> Self {
+        self.skip_hooks = skip;
+        self
+    }
+
+    /// Opens the repository at `path` without checking it; git finds the repository when commands run
     pub fn open(path: impl AsRef<Path>) -> Result<Self> {
-        Self::discover(path)
+        Ok(Self {
+            repo_path: path.as_ref().to_path_buf(),
+            git_dir: None,
+            bare: false,
+            credentials: Credentials::default(),
+            skip_hooks: false,
+        })
+    }
+
+    /// Opens a bare repository, failing if `path` is not one
+    pub fn open_bare(path: impl AsRef<Path>) -> Result<Self> {
+        let repository = Self::discover(&path)?;
+        if !repository.bare {
+            return Err(GitError::DiscoveryFailed {
+                strategy: format!("opening {} as a bare repository", path.as_ref().display()),
+                reason: format!("{} has a working tree at {}", path.as_ref().display(), repository.repo_path.display()),
+            }.into());
+        }
+        Ok(repository)
+    }
+
+    /// Clones `url` into `path` in the background; must be called within a Tokio runtime.
+    ///
+    /// The returned handle streams git's progress and resolves to the cloned repository.
+    pub fn clone_from_url(url: &str, path: impl AsRef<Path>, options: &CloneOptions) -> CloneOperation {
+        let url = url.to_string();
+        let path = path.as_ref().to_path_buf();
+        let options = options.clone();
+        let (sender, progress) = progress_channel();
+
+        let task = tokio::spawn(async move {
+            let depth = options.depth.map(|depth| depth.to_string());
+            let mut args = vec!["clone", "--progress"];
+            if let Some(depth) = &depth {
+                args.extend(["--depth", depth]);
+            }
+            if let Some(branch) = &options.branch {
+                args.extend(["--branch", branch]);
+            }
+            if options.single_branch {
+                args.push("--single-branch");
+            } else if options.depth.is_some() {
+                // git implies --single-branch for shallow clones
+                args.push("--no-single-branch");
+            }
+            if options.bare {
+                args.push("--bare");
+            }
+            let destination = path.to_string_lossy();
+            args.extend(["--", &url, &destination]);
+
+            let output = run_git_streaming(Command::new("git"), &args, Some(&sender)).await?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                // Keep git's error lines, not the progress output before them
+                let errors: Vec<&str> = stderr.lines()
+                    .filter(|line| line.starts_with("fatal: ") || line.starts_with("error: "))
+                    .map(|line| line.split_once(": ").map_or(line, |(_, message)| message))
+                    .collect();
+                let reason = if errors.is_empty() { stderr.trim().to_string() } else { errors.join("; ") };
+                if reason.contains("Authentication failed")
+                    || reason.contains("could not read Username")
+                    || reason.contains("Permission denied")
+                {
+                    return Err(GitError::AuthenticationFailed { reason }.into());
+                }
+                return Err(GitError::CloneFailed { url, reason }.into());
+            }
+
+            GitRepository::open(&path)
+        });
+
+        CloneOperation { progress, task }
     }
 
     /// Returns the path to the repository root
@@ -158,10 +1587,28 @@ impl GitRepository {
         &self.repo_path
     }
 
+    /// A git command in the repository, with hooks disabled if they are skipped
+    fn git_command(&self) -> Command {
+        let mut command = Command::new("git");
+        command.current_dir(&self.repo_path);
+        // Name the discovered directories, so a relative GIT_DIR in the environment cannot point elsewhere
+        if let Some(git_dir) = &self.git_dir {
+            command.arg(format!("--git-dir={}", git_dir.display()));
+            if !self.bare {
+                command.arg(format!("--work-tree={}", self.repo_path.display()));
+            }
+        }
+        if self.skip_hooks {
+            // Hooks are looked up in core.hooksPath, so a path that is not a directory finds none
+            let no_hooks = if cfg!(windows) { "NUL" } else { "/dev/null" };
+            command.arg("-c").arg(format!("core.hooksPath={}", no_hooks));
+        }
+        command
+    }
+
     async fn run_git(&self, args: &[&str]) -> Result<std::process::Output> {
-        Command::new("git")
+        self.git_command()
             .args(args)
-            .current_dir(&self.repo_path)
             .output()
             .await
             .map_err(|e| GitError::OperationFailed {
@@ -170,6 +1617,318 @@ impl GitRepository {
             }.into())
     }
 
+    /// Resolve a revision to the full hash of the commit it names
+    async fn resolve_commit(&self, rev: &str) -> Result<String> {
+        let output = self.run_git(&["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", rev)]).await?;
+        if !output.status.success() {
+            return Err(GitError::RevisionNotFound { rev: rev.to_string() }.into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Worktree other than this one that has a local branch checked out
+    async fn other_worktree_for_branch(&self, branch_name: &str) -> Result<Option<WorktreeInfo>> {
+        let Some(worktree) = self.find_worktree_for_branch(branch_name).await? else {
+            return Ok(None);
+        };
+        let here = if self.bare {
+            self.repo_path.clone()
+        } else {
+            self.top_level().await?
+        };
+        // Compare resolved paths, since either side may go through a symlink
+        let same = |a: &Path, b: &Path| match (a.canonicalize(), b.canonicalize()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a == b,
+        };
+        Ok((!same(&worktree.path, &here)).then_some(worktree))
+    }
+
+    /// Fail if another worktree has the branch checked out, since moving or deleting it there corrupts that worktree
+    async fn require_branch_not_checked_out_elsewhere(&self, branch_name: &str) -> Result<()> {
+        match self.other_worktree_for_branch(branch_name).await? {
+            Some(worktree) => Err(GitError::BranchCheckedOut {
+                branch: branch_name.to_string(),
+                worktree: worktree.path,
+            }.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Fail with a clear error for operations that need a working tree
+    fn require_work_tree(&self, operation: &str) -> Result<()> {
+        if self.bare {
+            return Err(GitError::BareRepository {
+                operation: operation.to_string(),
+                path: self.repo_path.clone(),
+            }.into());
+        }
+        Ok(())
+    }
+
+    /// Run `git verify-commit` or `git verify-tag` with the trusted keys
+    async fn verify_signature(&self, command: &str, object: &str, keys: &TrustedKeys) -> Result<SignatureVerification> {
+        let mut git = self.git_command();
+        if let Some(allowed_signers) = &keys.allowed_signers {
+            git.arg("-c").arg(format!("gpg.ssh.allowedSignersFile={}", allowed_signers.display()));
+        }
+        if let Some(gpg_home) = &keys.gpg_home {
+            git.env("GNUPGHOME", gpg_home);
+        }
+        let output = git.args([command, "--raw", object])
+            .output()
+            .await
+            .map_err(|e| GitError::OperationFailed {
+                operation: format!("git {} {}", command, object),
+                reason: format!("Failed to execute: {}", e),
+            })?;
+
+        Ok(SignatureVerification::parse(&String::from_utf8_lossy(&output.stderr), output.status.success()))
+    }
+
+    /// A git command authenticated for a remote (a remote name, or a URL)
+    async fn remote_command(&self, remote: &str, push: bool) -> Result<Command> {
+        let mut command = self.git_command();
+        command.env("GIT_TERMINAL_PROMPT", "0");
+        if self.credentials.is_empty() {
+            return Ok(command);
+        }
+
+        let url = match self.remote_url(remote, push).await {
+            Ok(url) => url,
+            Err(ReleaseError::Git(GitError::RemoteNotFound { .. })) => remote.to_string(),
+            Err(e) => return Err(e),
+        };
+        if let Some(credential) = self.credentials.resolve(&url, &self.repo_path).await {
+            credential.apply(&mut command);
+        }
+        Ok(command)
+    }
+
+    /// Run a git command that talks to a remote, reporting its progress
+    async fn run_remote_git(&self, remote: &str, push: bool, args: &[&str], progress: Option<&dyn ProgressSink>) -> Result<std::process::Output> {
+        run_git_streaming(self.remote_command(remote, push).await?, args, progress).await
+    }
+
+    /// Run a git command that pushes to a remote, failing on non-zero exit
+    async fn run_push_checked(&self, remote: &str, args: &[&str], progress: Option<&dyn ProgressSink>) -> Result<String> {
+        let output = self.run_remote_git(remote, true, args, progress).await?;
+        if !output.status.success() {
+            if let Some(rejection) = self.hook_rejection("push", &output).await {
+                return Err(rejection.into());
+            }
+            return Err(GitError::OperationFailed {
+                operation: format!("git {}", args.join(" ")),
+                reason: String::from_utf8_lossy(&output.stderr).to_string(),
+            }.into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Run `git diff` with an output format, revisions, and pathspecs, returning stdout
+    async fn run_diff(&self, format: &[&str], revisions: &[&str], paths: &[String]) -> Result<Vec<u8>> {
+        let mut args = vec!["-c", "core.quotePath=false", "diff", "--no-color", "--no-ext-diff", "-M"];
+        args.extend_from_slice(format);
+        args.extend_from_slice(revisions);
+        args.push("--");
+        args.extend(paths.iter().map(String::as_str));
+
+        let output = self.run_git(&args).await?;
+        if !output.status.success() {
+            return Err(GitError::OperationFailed {
+                operation: format!("git {}", args.join(" ")),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+        Ok(output.stdout)
+    }
+
+    /// Run `git stash apply` or `git stash pop`, reporting conflicted files
+    async fn apply_stash(&self, reference: &str, command: &str) -> Result<()> {
+        let output = self.run_git(&["stash", command, reference]).await?;
+        if output.status.success() {
+            return Ok(());
+        }
+
+        let conflicts = self.run_git_checked(&["diff", "--name-only", "--diff-filter=U"]).await?;
+        if conflicts.is_empty() {
+            return Err(GitError::OperationFailed {
+                operation: format!("git stash {} {}", command, reference),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+        Err(GitError::StashConflict {
+            stash: reference.to_string(),
+            files: conflicts.lines().map(str::to_string).collect(),
+        }.into())
+    }
+
+    /// Run `git cherry-pick` or `git revert` on a single commit with a three-way merge.
+    ///
+    /// On conflicts the operation is aborted, leaving HEAD and the working tree as they were.
+    async fn apply_commit(&self, command: &str, commit: &str, message: Option<&str>) -> Result<CommitInfo> {
+        self.require_work_tree(command)?;
+        let parents = self.run_git_checked(&["rev-list", "--parents", "--max-count=1", commit]).await?;
+        let mut args = vec![command, "--no-edit"];
+        if command == "cherry-pick" {
+            // Record where the commit came from
+            args.push("-x");
+        }
+        if parents.split_whitespace().count() > 2 {
+            // Merge commits are applied relative to their first parent
+            args.extend(["--mainline", "1"]);
+        }
+        if message.is_some() {
+            args.push("--no-commit");
+        }
+        args.push(commit);
+
+        let output = self.run_git(&args).await?;
+        if !output.status.success() {
+            let conflicts = self.run_git_checked(&["diff", "--name-only", "--diff-filter=U"]).await?;
+            // Leave no sequencer state behind (e.g. after an empty cherry-pick); fails if none was started
+            let _ = self.run_git(&[command, "--abort"]).await;
+            if conflicts.is_empty() {
+                return Err(GitError::OperationFailed {
+                    operation: format!("git {} {}", command, commit),
+                    reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                }.into());
+            }
+            return Err(GitError::CommitConflict {
+                operation: command.to_string(),
+                commit: commit.to_string(),
+                files: conflicts.lines().map(str::to_string).collect(),
+            }.into());
+        }
+
+        if let Some(message) = message
+            && let Err(e) = self.run_commit(&["-m", message]).await
+        {
+            // Undo the staged changes, keeping unrelated local changes
+            let _ = self.run_git(&["reset", "--merge"]).await;
+            return Err(e);
+        }
+
+        self.head_commit(&format!("git {} {}", command, commit)).await
+    }
+
+    /// The commit HEAD points to, after an operation created it
+    async fn head_commit(&self, operation: &str) -> Result<CommitInfo> {
+        let mut log = self.log(&LogOptions {
+            max_count: Some(1),
+            ..LogOptions::default()
+        }).await?;
+        log.next().await.unwrap_or_else(|| Err(GitError::OperationFailed {
+            operation: operation.to_string(),
+            reason: "HEAD has no commit".to_string(),
+        }.into()))
+    }
+
+    /// Run a `git remote` subcommand, telling a missing or duplicate remote apart from other failures
+    async fn run_remote_command(&self, name: &str, args: &[&str]) -> Result<String> {
+        let output = self.run_git(args).await?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
+        }
+
+        // git exits with 2 for an unknown remote and 3 for one that already exists
+        match output.status.code() {
+            Some(2) => Err(GitError::RemoteNotFound { remote: name.to_string() }.into()),
+            Some(3) => Err(GitError::RemoteExists { remote: name.to_string() }.into()),
+            _ => Err(GitError::OperationFailed {
+                operation: format!("git {}", args.join(" ")),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into()),
+        }
+    }
+
+    /// Snapshot of every ref and the commit it points to
+    async fn ref_snapshot(&self) -> Result<std::collections::BTreeMap<String, String>> {
+        let output = self.run_git_checked(&["for-each-ref", "--format=%(objectname) %(refname)"]).await?;
+        Ok(output.lines()
+            .filter_map(|line| line.split_once(' '))
+            .map(|(oid, name)| (name.to_string(), oid.to_string()))
+            .collect())
+    }
+
+    /// Stage all changes in the whole working tree, except the excluded paths
+    async fn stage_changes(&self, excluded: &[PathBuf]) -> Result<()> {
+        let exclusions: Vec<String> = excluded.iter()
+            .map(|path| format!(":(top,exclude){}", path.display()))
+            .collect();
+        let mut args = vec!["add", "-A", "--", ":(top)"];
+        args.extend(exclusions.iter().map(String::as_str));
+        self.run_git_checked(&args).await?;
+        Ok(())
+    }
+
+    /// Commit the staged changes, running the commit hooks unless they are skipped
+    async fn run_commit(&self, args: &[&str]) -> Result<()> {
+        let mut command = vec!["commit"];
+        command.extend(args);
+        let output = self.run_git(&command).await?;
+        if output.status.success() {
+            return Ok(());
+        }
+        if let Some(rejection) = self.hook_rejection("commit", &output).await {
+            return Err(rejection.into());
+        }
+        // "nothing to commit" is printed on stdout
+        let reason = match String::from_utf8_lossy(&output.stderr).trim() {
+            "" => String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            stderr => stderr.to_string(),
+        };
+        Err(GitError::OperationFailed {
+            operation: "git commit".to_string(),
+            reason,
+        }.into())
+    }
+
+    /// Attribute a failed commit or push to the hooks that ran, if git itself reported nothing
+    async fn hook_rejection(&self, operation: &str, output: &std::process::Output) -> Option<GitError> {
+        if self.skip_hooks {
+            return None;
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let (hooks, git_reported) = match operation {
+            "commit" => (
+                ["pre-commit", "prepare-commit-msg", "commit-msg"].as_slice(),
+                String::from_utf8_lossy(&output.stdout).contains("nothing to commit")
+                    || stderr.lines().any(|line| line.starts_with("fatal: ")),
+            ),
+            // A pre-push rejection fails the push without any ref being rejected
+            _ => (
+                ["pre-push"].as_slice(),
+                stderr.lines().any(|line| line.starts_with(" ! [") || line.starts_with("fatal: ")),
+            ),
+        };
+        if git_reported {
+            return None;
+        }
+
+        let mut installed = Vec::new();
+        for hook in hooks {
+            let Ok(path) = self.run_git_checked(&["rev-parse", "--git-path", &format!("hooks/{}", hook)]).await else {
+                continue;
+            };
+            if is_executable(&self.repo_path.join(path)) {
+                installed.push(*hook);
+            }
+        }
+        if installed.is_empty() {
+            return None;
+        }
+
+        let reason: Vec<&str> = stderr.lines()
+            .filter(|line| !line.starts_with("error: failed to push some refs"))
+            .collect();
+        Some(GitError::HookRejected {
+            operation: operation.to_string(),
+            hook: installed.join(" or "),
+            reason: reason.join("\n").trim().to_string(),
+        })
+    }
+
     async fn run_git_checked(&self, args: &[&str]) -> Result<String> {
         let output = self.run_git(args).await?;
         
@@ -188,64 +1947,132 @@ impl GitRepository {
 impl GitOperations for GitRepository {
     async fn create_release_commit(&self, version: &Version, message: Option<String>) -> Result<CommitInfo> {
         let commit_message = message.unwrap_or_else(|| format!("release: v{}", version));
-        
-        // Stage all changes
-        self.run_git_checked(&["add", "-A"]).await?;
+        self.commit_all(&commit_message).await
+    }
+
+    async fn commit_all(&self, message: &str) -> Result<CommitInfo> {
+        self.commit_all_excluding(message, &[]).await
+    }
+
+    async fn commit_all_excluding(&self, message: &str, excluded: &[PathBuf]) -> Result<CommitInfo> {
+        self.require_work_tree("commit")?;
+        self.stage_changes(excluded).await?;
         
         // Create commit
-        self.run_git_checked(&["commit", "-m", &commit_message]).await?;
-        
-        // Get commit info
-        let output = self.run_git_checked(&["log", "-1", "--format=%H%n%h%n%s%n%an%n%ae"]).await?;
-        let lines: Vec<&str> = output.lines().collect();
-        
-        if lines.len() < 5 {
-            return Err(GitError::OperationFailed {
-                operation: "get commit info".to_string(),
-                reason: "Unexpected git log output format".to_string(),
-            }.into());
-        }
-        
-        Ok(CommitInfo {
-            hash: lines[0].to_string(),
-            short_hash: lines[1].to_string(),
-            message: lines[2].to_string(),
-            author_name: lines[3].to_string(),
-            author_email: lines[4].to_string(),
-            timestamp: chrono::Utc::now(),
-            parents: Vec::new(),
-        })
+        self.run_commit(&["-m", message]).await?;
+        self.head_commit("git commit").await
     }
 
     async fn create_version_tag(&self, version: &Version, message: Option<String>) -> Result<TagInfo> {
         let tag_name = format!("v{}", version);
         let tag_message = message.unwrap_or_else(|| format!("Release v{}", version));
-        
-        // Create annotated tag
-        self.run_git_checked(&["tag", "-a", &tag_name, "-m", &tag_message]).await?;
-        
+        self.create_tag(&tag_name, Some(tag_message)).await
+    }
+
+    async fn create_tag(&self, tag_name: &str, message: Option<String>) -> Result<TagInfo> {
+        self.create_tag_with_options(tag_name, &TagOptions {
+            message,
+            ..TagOptions::default()
+        }).await
+    }
+
+    async fn create_tag_with_options(&self, tag_name: &str, options: &TagOptions) -> Result<TagInfo> {
+        let tag_message = match options.kind {
+            TagKind::Lightweight => None,
+            TagKind::Annotated | TagKind::Signed => Some(
+                options.message.clone().unwrap_or_else(|| format!("Release {}", tag_name)),
+            ),
+        };
+
+        let mut args = vec!["tag"];
+        match options.kind {
+            TagKind::Lightweight => {}
+            TagKind::Annotated => args.push("-a"),
+            TagKind::Signed => args.push("-s"),
+        }
+        if let Some(message) = &tag_message {
+            args.extend(["-m", message]);
+        }
+        args.extend(["--", tag_name]);
+        if let Some(target) = &options.target {
+            args.push(target);
+        }
+
+        let output = self.run_git(&args).await?;
+        if !output.status.success() {
+            let reason = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            if reason.contains("already exists") {
+                return Err(GitError::TagExists { tag: tag_name.to_string() }.into());
+            }
+            if options.kind == TagKind::Signed && (reason.contains("gpg") || reason.contains("sign")) {
+                return Err(GitError::SigningFailed {
+                    tag: tag_name.to_string(),
+                    reason,
+                }.into());
+            }
+            return Err(GitError::OperationFailed {
+                operation: format!("git {}", args.join(" ")),
+                reason,
+            }.into());
+        }
+
         // Get tag info
-        let commit = self.run_git_checked(&["rev-parse", &tag_name]).await?;
-        
+        let commit = self.run_git_checked(&["rev-parse", &format!("{}^{{commit}}", tag_name)]).await?;
+
         Ok(TagInfo {
-            name: tag_name,
-            message: Some(tag_message),
+            name: tag_name.to_string(),
+            message: tag_message,
             target_commit: commit,
             timestamp: chrono::Utc::now(),
-            is_annotated: true,
+            is_annotated: options.kind != TagKind::Lightweight,
         })
     }
 
+    async fn list_tags(&self, pattern: Option<&str>) -> Result<Vec<TagInfo>> {
+        // Fields are NUL-separated and records end with an RS byte, since messages span lines
+        let format = "--format=%(refname:strip=2)%00%(objecttype)%00%(objectname)%00%(*objectname)%00\
+                      %(creatordate:iso-strict)%00%(contents:subject)%00%(contents:body)%1e";
+        let mut args = vec!["tag", "--list", format];
+        if let Some(pattern) = pattern {
+            args.push(pattern);
+        }
+        let output = self.run_git_checked(&args).await?;
+
+        let tags = output.split('\x1e')
+            .filter_map(|record| {
+                let fields: Vec<&str> = record.trim_start_matches('\n').split('\0').collect();
+                let [name, object_type, object, peeled, date, subject, body] = fields[..] else {
+                    return None;
+                };
+                let is_annotated = object_type == "tag";
+                let message = is_annotated.then(|| match body.trim() {
+                    "" => subject.to_string(),
+                    body => format!("{}\n\n{}", subject, body),
+                });
+                Some(TagInfo {
+                    name: name.to_string(),
+                    message,
+                    target_commit: if is_annotated { peeled } else { object }.to_string(),
+                    timestamp: chrono::DateTime::parse_from_rfc3339(date)
+                        .map_or_else(|_| chrono::Utc::now(), |date| date.with_timezone(&chrono::Utc)),
+                    is_annotated,
+                })
+            })
+            .collect();
+
+        Ok(tags)
+    }
+
     async fn push_to_remote(&self, remote_name: Option<&str>, push_tags: bool) -> Result<PushInfo> {
         let remote = remote_name.unwrap_or("origin");
         
         // Push commits
-        self.run_git_checked(&["push", remote, "HEAD"]).await?;
+        self.run_push_checked(remote, &["push", remote, "HEAD"], None).await?;
         
         let mut tags_pushed = 0;
         if push_tags {
             // Push tags
-            self.run_git_checked(&["push", remote, "--tags"]).await?;
+            self.run_push_checked(remote, &["push", remote, "--tags"], None).await?;
             tags_pushed = 1; // Simplified
         }
         
@@ -257,16 +2084,134 @@ impl GitOperations for GitRepository {
         })
     }
 
+    async fn push_atomic(&self, remote_name: Option<&str>, branch: Option<&str>, tag_names: &[String], progress: Option<&dyn ProgressSink>) -> Result<PushInfo> {
+        let remote = remote_name.unwrap_or("origin");
+
+        // Either the branch and every tag are updated on the remote, or none are
+        let head_ref = match branch {
+            Some(branch) => format!("HEAD:refs/heads/{}", branch),
+            None => "HEAD".to_string(),
+        };
+        let tag_refs: Vec<String> = tag_names.iter()
+            .map(|name| format!("refs/tags/{}", name))
+            .collect();
+
+        // Commits the remote does not have yet, counted against its remote-tracking refs before they move
+        let exclude_remote = format!("--remotes={}", remote);
+        let commits_pushed = self.run_git_checked(&["rev-list", "--count", "HEAD", "--not", &exclude_remote]).await?;
+        let commits_pushed = commits_pushed.parse::<usize>().map_err(|_| GitError::OperationFailed {
+            operation: format!("git rev-list --count HEAD --not {}", exclude_remote),
+            reason: "Unexpected rev-list output".to_string(),
+        })?;
+
+        let mut args = vec!["push", "--atomic", "--progress", remote, head_ref.as_str()];
+        args.extend(tag_refs.iter().map(String::as_str));
+        self.run_push_checked(remote, &args, progress).await?;
+
+        Ok(PushInfo {
+            remote_name: remote.to_string(),
+            commits_pushed,
+            tags_pushed: tag_names.len(),
+            warnings: Vec::new(),
+        })
+    }
+
     async fn is_working_directory_clean(&self) -> Result<bool> {
-        let output = self.run_git_checked(&["status", "--porcelain"]).await?;
-        Ok(output.is_empty())
+        Ok(self.status().await?.is_clean())
     }
 
-    async fn get_current_branch(&self) -> Result<BranchInfo> {
-        let name = self.run_git_checked(&["branch", "--show-current"]).await?;
-        let commit_hash = self.run_git_checked(&["rev-parse", "HEAD"]).await?;
-        
-        let upstream = self.run_git(&["rev-parse", "--abbrev-ref", "@{upstream}"])
+    async fn diff(&self, from: &str, to: &DiffTarget, options: &DiffOptions) -> Result<Vec<FileDiff>> {
+        let mut revisions: Vec<&str> = Vec::new();
+        match to {
+            DiffTarget::Commit(to) => revisions.extend([from, to.as_str()]),
+            DiffTarget::Index => revisions.extend(["--cached", from]),
+            DiffTarget::WorkingTree => revisions.push(from),
+        }
+        let paths: Vec<String> = options.paths.iter()
+            .map(|path| format!(":(top){}", path.display()))
+            .collect();
+        let name_status = self.run_diff(&["--name-status", "-z"], &revisions, &paths).await?;
+        let mut files = FileDiff::parse_name_status(&name_status);
+        let numstat = self.run_diff(&["--numstat", "-z"], &revisions, &paths).await?;
+        FileDiff::add_line_counts(&mut files, &numstat);
+
+        if options.include_hunks {
+            let context = format!("-U{}", options.context_lines.unwrap_or(3));
+            let patch = self.run_diff(&["-p", &context], &revisions, &paths).await?;
+            let mut hunks = parse_patch(&String::from_utf8_lossy(&patch));
+            for file in &mut files {
+                let old_path = match &file.change {
+                    FileChange::Deleted => Some(file.path.clone()),
+                    _ => None,
+                };
+                file.hunks = hunks.remove(&file.path)
+                    .or_else(|| old_path.and_then(|path| hunks.remove(&path)))
+                    .unwrap_or_default();
+            }
+        }
+
+        Ok(files)
+    }
+
+    async fn stash_save(&self, options: &StashOptions) -> Result<Option<StashEntry>> {
+        self.require_work_tree("stash")?;
+        let before = self.run_git(&["rev-parse", "--verify", "--quiet", "refs/stash"]).await?;
+
+        let mut args = vec!["stash".to_string(), "push".to_string()];
+        if options.include_untracked {
+            args.push("--include-untracked".to_string());
+        }
+        if let Some(message) = &options.message {
+            args.extend(["--message".to_string(), message.clone()]);
+        }
+        if !options.excluded.is_empty() {
+            args.extend(["--".to_string(), ":(top)".to_string()]);
+            args.extend(options.excluded.iter().map(|path| format!(":(top,glob,exclude){}", path)));
+        }
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.run_git_checked(&args).await?;
+
+        // Nothing is pushed (and refs/stash is unchanged) when there were no changes
+        let after = self.run_git(&["rev-parse", "--verify", "--quiet", "refs/stash"]).await?;
+        if !after.status.success() || after.stdout == before.stdout {
+            return Ok(None);
+        }
+        Ok(self.stash_list().await?.into_iter().next())
+    }
+
+    async fn stash_list(&self) -> Result<Vec<StashEntry>> {
+        let output = self.run_git_checked(&["stash", "list", "--format=%gd%x00%H%x00%gs"]).await?;
+        Ok(StashEntry::parse_list(&output))
+    }
+
+    async fn stash_apply(&self, reference: &str) -> Result<()> {
+        self.require_work_tree("apply a stash")?;
+        self.apply_stash(reference, "apply").await
+    }
+
+    async fn stash_pop(&self, reference: &str) -> Result<()> {
+        self.require_work_tree("pop a stash")?;
+        self.apply_stash(reference, "pop").await
+    }
+
+    async fn status(&self) -> Result<StatusReport> {
+        self.require_work_tree("read the status")?;
+        let args = ["-c", "core.quotePath=false", "status", "--porcelain=v2", "-z", "--ignored"];
+        let output = self.run_git(&args).await?;
+        if !output.status.success() {
+            return Err(GitError::OperationFailed {
+                operation: format!("git {}", args.join(" ")),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+        Ok(StatusReport::parse(&output.stdout))
+    }
+
+    async fn get_current_branch(&self) -> Result<BranchInfo> {
+        let name = self.run_git_checked(&["branch", "--show-current"]).await?;
+        let commit_hash = self.run_git_checked(&["rev-parse", "HEAD"]).await?;
+        
+        let upstream = self.run_git(&["rev-parse", "--abbrev-ref", "@{upstream}"])
             .await
             .ok()
             .and_then(|output| {
@@ -296,75 +2241,121 @@ impl GitOperations for GitRepository {
         
         if delete_remote {
             // Delete remote tag
-            self.run_git_checked(&["push", "origin", &format!(":{}", tag_name)]).await?;
+            self.run_push_checked("origin", &["push", "origin", &format!(":{}", tag_name)], None).await?;
         }
         
         Ok(())
     }
 
     async fn reset_to_commit(&self, commit_id: &str, reset_type: ResetType) -> Result<()> {
+        self.reset(commit_id, reset_type).await.map(drop)
+    }
+
+    async fn reset(&self, rev: &str, reset_type: ResetType) -> Result<ResetInfo> {
+        if reset_type != ResetType::Soft {
+            self.require_work_tree("reset the index")?;
+        }
+        let head = self.resolve_commit(rev).await?;
+        let previous_head = self.run_git_checked(&["rev-parse", "HEAD"]).await?;
+
         let reset_arg = match reset_type {
             ResetType::Soft => "--soft",
             ResetType::Mixed => "--mixed",
             ResetType::Hard => "--hard",
         };
-        
-        self.run_git_checked(&["reset", reset_arg, commit_id]).await?;
-        Ok(())
+        // Reset to the resolved commit so a moving rev (e.g. a branch) cannot change in between
+        self.run_git_checked(&["reset", "--quiet", reset_arg, &head]).await?;
+
+        Ok(ResetInfo { previous_head, head })
     }
 
     async fn get_recent_commits(&self, count: usize) -> Result<Vec<CommitInfo>> {
-        let output = self.run_git_checked(&[
-            "log",
-            &format!("-{}", count),
-            "--format=%H%x00%h%x00%s%x00%an%x00%ae%x00"
-        ]).await?;
-        
-        let mut commits = Vec::new();
-        for entry in output.split('\n').filter(|s| !s.is_empty()) {
-            let parts: Vec<&str> = entry.split('\0').collect();
-            if parts.len() >= 5 {
-                commits.push(CommitInfo {
-                    hash: parts[0].to_string(),
-                    short_hash: parts[1].to_string(),
-                    message: parts[2].to_string(),
-                    author_name: parts[3].to_string(),
-                    author_email: parts[4].to_string(),
-                    timestamp: chrono::Utc::now(),
-                    parents: Vec::new(),
-                });
-            }
+        self.log(&LogOptions {
+            max_count: Some(count),
+            ..LogOptions::default()
+        }).await?.collect().await
+    }
+
+    async fn log(&self, options: &LogOptions) -> Result<CommitLog> {
+        let mut args = vec!["log".to_string(), COMMIT_RECORD_FORMAT.to_string()];
+        if let Some(max_count) = options.max_count {
+            args.push(format!("--max-count={}", max_count));
         }
-        
-        Ok(commits)
+        if options.first_parent {
+            args.push("--first-parent".to_string());
+        }
+        let from = options.from.as_deref().unwrap_or("HEAD");
+        match &options.until {
+            Some(until) => args.push(format!("{}..{}", until, from)),
+            None => args.push(from.to_string()),
+        }
+        args.push("--".to_string());
+        args.extend(options.paths.iter().map(|path| format!(":(top,glob){}", path)));
+
+        let operation = format!("git {}", args.join(" "));
+        let mut child = Command::new("git")
+            .args(&args)
+            .current_dir(&self.repo_path)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| GitError::OperationFailed {
+                operation: operation.clone(),
+                reason: format!("Failed to execute: {}", e),
+            })?;
+        let stdout = child.stdout.take().ok_or_else(|| GitError::OperationFailed {
+            operation: operation.clone(),
+            reason: "stdout was not captured".to_string(),
+        })?;
+
+        Ok(CommitLog {
+            child,
+            reader: tokio::io::BufReader::new(stdout),
+            operation,
+        })
     }
 
     async fn get_remotes(&self) -> Result<Vec<RemoteInfo>> {
-        let output = self.run_git_checked(&["remote", "-v"]).await?;
-        
-        let mut remotes = std::collections::HashMap::new();
-        for line in output.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 3 {
-                let name = parts[0];
-                let url = parts[1];
-                let remote_type = parts[2].trim_matches(|c| c == '(' || c == ')');
-                
-                let entry = remotes.entry(name.to_string()).or_insert_with(|| RemoteInfo {
-                    name: name.to_string(),
-                    fetch_url: String::new(),
-                    push_url: String::new(),
-                });
-                
-                if remote_type == "fetch" {
-                    entry.fetch_url = url.to_string();
-                } else if remote_type == "push" {
-                    entry.push_url = url.to_string();
-                }
-            }
+        let output = self.run_git_checked(&["remote"]).await?;
+
+        let mut remotes = Vec::new();
+        for name in output.lines().map(str::trim).filter(|name| !name.is_empty()) {
+            remotes.push(RemoteInfo {
+                name: name.to_string(),
+                fetch_url: self.remote_url(name, false).await?,
+                push_url: self.remote_url(name, true).await?,
+            });
         }
-        
-        Ok(remotes.into_values().collect())
+        remotes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(remotes)
+    }
+
+    async fn remote_url(&self, name: &str, push: bool) -> Result<String> {
+        let mut args = vec!["remote", "get-url"];
+        if push {
+            args.push("--push");
+        }
+        args.push(name);
+        self.run_remote_command(name, &args).await
+    }
+
+    async fn add_remote(&self, name: &str, url: &str) -> Result<()> {
+        self.run_remote_command(name, &["remote", "add", name, url]).await.map(drop)
+    }
+
+    async fn remove_remote(&self, name: &str) -> Result<()> {
+        self.run_remote_command(name, &["remote", "remove", name]).await.map(drop)
+    }
+
+    async fn set_remote_url(&self, name: &str, url: &str, push: bool) -> Result<()> {
+        let mut args = vec!["remote", "set-url"];
+        if push {
+            args.push("--push");
+        }
+        args.extend([name, url]);
+        self.run_remote_command(name, &args).await.map(drop)
     }
 
     async fn validate_release_readiness(&self) -> Result<ValidationResult> {
@@ -392,6 +2383,485 @@ impl GitOperations for GitRepository {
             issues,
         })
     }
+
+    async fn top_level(&self) -> Result<PathBuf> {
+        let path = self.run_git_checked(&["rev-parse", "--show-toplevel"]).await?;
+        Ok(PathBuf::from(path))
+    }
+
+    async fn add_worktree(&self, path: &Path) -> Result<()> {
+        let path = path.to_string_lossy();
+        self.run_git_checked(&["worktree", "add", "--detach", &path, "HEAD"]).await?;
+        Ok(())
+    }
+
+    async fn remove_worktree(&self, path: &Path) -> Result<()> {
+        let path = path.to_string_lossy();
+        self.run_git_checked(&["worktree", "remove", "--force", &path]).await?;
+        Ok(())
+    }
+
+    async fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
+        let output = self.run_git_checked(&["worktree", "list", "--porcelain"]).await?;
+        Ok(WorktreeInfo::parse_list(&output))
+    }
+
+    async fn find_worktree_for_branch(&self, branch_name: &str) -> Result<Option<WorktreeInfo>> {
+        Ok(self.list_worktrees().await?
+            .into_iter()
+            .find(|worktree| worktree.branch.as_deref() == Some(branch_name)))
+    }
+
+    async fn is_branch_checked_out_elsewhere(&self, branch_name: &str) -> Result<bool> {
+        Ok(self.other_worktree_for_branch(branch_name).await?.is_some())
+    }
+
+    async fn delete_branch(&self, branch_name: &str, force: bool) -> Result<()> {
+        self.require_branch_not_checked_out_elsewhere(branch_name).await?;
+        let flag = if force { "-D" } else { "-d" };
+        self.run_git_checked(&["branch", flag, "--", branch_name]).await?;
+        Ok(())
+    }
+
+    async fn reset_branch(&self, branch_name: &str, rev: &str) -> Result<()> {
+        self.require_branch_not_checked_out_elsewhere(branch_name).await?;
+        let commit = self.resolve_commit(rev).await?;
+        self.run_git_checked(&["branch", "--force", "--", branch_name, &commit]).await?;
+        Ok(())
+    }
+
+    async fn branch_exists(&self, branch_name: &str) -> Result<bool> {
+        let local_ref = format!("refs/heads/{}", branch_name);
+        let remote_refs = format!("refs/remotes/*/{}", branch_name);
+        let refs = self.run_git_checked(&["for-each-ref", "--format=%(refname)", &local_ref, &remote_refs]).await?;
+        Ok(!refs.is_empty())
+    }
+
+    async fn checkout_branch(&self, branch_name: &str, start_point: Option<&str>) -> Result<()> {
+        self.require_branch_not_checked_out_elsewhere(branch_name).await?;
+        match start_point {
+            Some(start_point) => self.run_git_checked(&["checkout", "-b", branch_name, start_point]).await?,
+            None => self.run_git_checked(&["checkout", branch_name]).await?,
+        };
+        Ok(())
+    }
+
+    async fn is_ancestor(&self, ancestor: &str, descendant: &str) -> Result<bool> {
+        let output = self.run_git(&["merge-base", "--is-ancestor", ancestor, descendant]).await?;
+        Ok(output.status.success())
+    }
+
+    async fn fetch(&self, options: &FetchOptions, progress: Option<&dyn ProgressSink>) -> Result<FetchInfo> {
+        let remote = options.remote.as_deref().unwrap_or("origin");
+        let before = self.ref_snapshot().await?;
+
+        let mut args = vec!["fetch", "--progress"];
+        if options.tags {
+            args.push("--tags");
+        }
+        if options.prune {
+            args.push("--prune");
+        }
+        args.push(remote);
+        args.extend(options.refspecs.iter().map(String::as_str));
+
+        let output = self.run_remote_git(remote, false, &args, progress).await?;
+        if !output.status.success() {
+            let reason = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            if reason.contains("couldn't find remote ref") {
+                let refspec = options.refspecs.iter()
+                    .find(|refspec| reason.contains(refspec.trim_start_matches('+').split(':').next().unwrap_or(refspec)))
+                    .cloned()
+                    .unwrap_or_default();
+                return Err(GitError::RemoteRefNotFound {
+                    remote: remote.to_string(),
+                    refspec,
+                }.into());
+            }
+            if reason.contains("Authentication failed")
+                || reason.contains("could not read Username")
+                || reason.contains("Permission denied")
+            {
+                return Err(GitError::AuthenticationFailed { reason }.into());
+            }
+            return Err(GitError::FetchFailed {
+                remote: remote.to_string(),
+                reason,
+            }.into());
+        }
+
+        let after = self.ref_snapshot().await?;
+        let names: std::collections::BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+        let updated_refs = names.into_iter()
+            .filter(|name| before.get(*name) != after.get(*name))
+            .map(|name| RefUpdate {
+                name: name.clone(),
+                old_oid: before.get(name).cloned(),
+                new_oid: after.get(name).cloned(),
+            })
+            .collect();
+
+        Ok(FetchInfo {
+            remote_name: remote.to_string(),
+            updated_refs,
+        })
+    }
+
+    async fn pull(&self, branch: Option<&str>, strategy: PullStrategy, progress: Option<&dyn ProgressSink>) -> Result<PullInfo> {
+        self.require_work_tree("pull")?;
+        let branch = match branch {
+            Some(branch) => branch.to_string(),
+            None => self.get_current_branch().await?.name,
+        };
+        let upstream = self.upstream_of(&branch).await?
+            .ok_or_else(|| GitError::NoUpstream { branch: branch.clone() })?;
+
+        // Update only the tracking ref of this branch, whatever the remote's fetch refspecs are
+        let short_name = upstream.merge_ref.strip_prefix("refs/heads/").unwrap_or(&upstream.merge_ref);
+        let tracking_ref = format!("refs/remotes/{}/{}", upstream.remote, short_name);
+        let fetch = self.fetch(&FetchOptions {
+            remote: Some(upstream.remote.clone()),
+            refspecs: vec![format!("+{}:{}", upstream.merge_ref, tracking_ref)],
+            ..FetchOptions::default()
+        }, progress).await?;
+
+        let local_ref = format!("refs/heads/{}", branch);
+        let divergence = self.divergence(&local_ref, &tracking_ref).await?;
+        let outcome = if divergence.behind == 0 {
+            PullOutcome::UpToDate
+        } else if divergence.ahead == 0 {
+            let from = self.run_git_checked(&["rev-parse", &local_ref]).await?;
+            self.run_git_checked(&["merge", "--ff-only", &tracking_ref]).await?;
+            let to = self.run_git_checked(&["rev-parse", &local_ref]).await?;
+            PullOutcome::FastForward { from, to }
+        } else if strategy == PullStrategy::FastForwardOnly {
+            return Err(GitError::NonFastForward {
+                branch,
+                upstream: tracking_ref,
+                ahead: divergence.ahead,
+                behind: divergence.behind,
+            }.into());
+        } else {
+            let output = self.run_git(&["merge", "--no-edit", &tracking_ref]).await?;
+            if !output.status.success() {
+                let conflicts = self.run_git_checked(&["diff", "--name-only", "--diff-filter=U"]).await?;
+                if conflicts.is_empty() {
+                    return Err(GitError::OperationFailed {
+                        operation: format!("git merge {}", tracking_ref),
+                        reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                    }.into());
+                }
+                self.run_git_checked(&["merge", "--abort"]).await?;
+                return Err(GitError::MergeConflict {
+                    upstream: tracking_ref,
+                    files: conflicts.lines().map(str::to_string).collect(),
+                }.into());
+            }
+            PullOutcome::Merged {
+                commit: self.run_git_checked(&["rev-parse", "HEAD"]).await?,
+            }
+        };
+
+        Ok(PullInfo {
+            branch,
+            upstream: tracking_ref,
+            fetch,
+            outcome,
+        })
+    }
+
+    async fn upstream_of(&self, branch_name: &str) -> Result<Option<UpstreamRef>> {
+        let remote = self.run_git(&["config", "--get", &format!("branch.{}.remote", branch_name)]).await?;
+        let merge_ref = self.run_git(&["config", "--get", &format!("branch.{}.merge", branch_name)]).await?;
+        let remote = String::from_utf8_lossy(&remote.stdout).trim().to_string();
+        let merge_ref = String::from_utf8_lossy(&merge_ref.stdout).trim().to_string();
+
+        // "." as the remote means the branch tracks another local branch
+        if remote.is_empty() || remote == "." || merge_ref.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(UpstreamRef { remote, merge_ref }))
+    }
+
+    async fn divergence(&self, local: &str, upstream: &str) -> Result<Divergence> {
+        let range = format!("{}...{}", local, upstream);
+        let counts = self.run_git_checked(&["rev-list", "--left-right", "--count", &range]).await?;
+        let mut counts = counts.split_whitespace().map(str::parse::<usize>);
+        match (counts.next(), counts.next()) {
+            (Some(Ok(ahead)), Some(Ok(behind))) => Ok(Divergence { ahead, behind }),
+            _ => Err(GitError::OperationFailed {
+                operation: format!("git rev-list --left-right --count {}", range),
+                reason: "Unexpected rev-list output".to_string(),
+            }.into()),
+        }
+    }
+
+    async fn cherry_pick(&self, commit: &str) -> Result<CommitInfo> {
+        self.apply_commit("cherry-pick", commit, None).await
+    }
+
+    async fn revert(&self, commit: &str, message: Option<String>) -> Result<CommitInfo> {
+        self.apply_commit("revert", commit, message.as_deref()).await
+    }
+
+    async fn commit_amend(&self, message: Option<String>) -> Result<CommitInfo> {
+        self.require_work_tree("commit --amend")?;
+        self.stage_changes(&[]).await?;
+        match &message {
+            Some(message) => self.run_commit(&["--amend", "-m", message]).await?,
+            None => self.run_commit(&["--amend", "--no-edit"]).await?,
+        }
+        self.head_commit("git commit --amend").await
+    }
+
+    async fn commit_fixup(&self, target: &str, kind: FixupKind) -> Result<CommitInfo> {
+        self.require_work_tree("commit --fixup")?;
+        let commit = self.resolve_commit(target).await?;
+        self.stage_changes(&[]).await?;
+        let option = match kind {
+            FixupKind::Fixup => format!("--fixup={}", commit),
+            FixupKind::Squash => format!("--squash={}", commit),
+        };
+        self.run_commit(&[&option, "--no-edit"]).await?;
+        self.head_commit("git commit --fixup").await
+    }
+
+    async fn autosquash(&self, base: &str) -> Result<CommitInfo> {
+        self.require_work_tree("rebase --autosquash")?;
+        let base = self.resolve_commit(base).await?;
+        let args = ["rebase", "--interactive", "--autosquash", &base];
+
+        // Accept the todo list and the combined messages as generated, without an editor
+        let output = self.git_command()
+            .args(args)
+            .env("GIT_SEQUENCE_EDITOR", ":")
+            .env("GIT_EDITOR", ":")
+            .output()
+            .await
+            .map_err(|e| GitError::OperationFailed {
+                operation: format!("git {}", args.join(" ")),
+                reason: format!("Failed to execute: {}", e),
+            })?;
+        if !output.status.success() {
+            let conflicts = self.run_git_checked(&["diff", "--name-only", "--diff-filter=U"]).await?;
+            // Put HEAD back where it was; fails if the rebase never started (e.g. a dirty working tree)
+            let _ = self.run_git(&["rebase", "--abort"]).await;
+            if conflicts.is_empty() {
+                return Err(GitError::OperationFailed {
+                    operation: format!("git {}", args.join(" ")),
+                    reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                }.into());
+            }
+            return Err(GitError::CommitConflict {
+                operation: "rebase".to_string(),
+                commit: base,
+                files: conflicts.lines().map(str::to_string).collect(),
+            }.into());
+        }
+        self.head_commit("git rebase --autosquash").await
+    }
+
+    async fn blame(&self, path: &Path, options: &BlameOptions) -> Result<Blame> {
+        // Line contents come from the blamed revision, since incremental output has none
+        let contents = match &options.rev {
+            Some(rev) => {
+                let commit = self.resolve_commit(rev).await?;
+                self.run_git_checked(&["show", &format!("{}:./{}", commit, path.display())]).await?
+            }
+            None => {
+                self.require_work_tree("blame the working tree")?;
+                std::fs::read_to_string(self.repo_path.join(path)).map_err(|e| GitError::OperationFailed {
+                    operation: format!("read {}", path.display()),
+                    reason: e.to_string(),
+                })?
+            }
+        };
+        let contents: Vec<String> = contents.lines().map(str::to_string).collect();
+
+        let mut args = vec!["blame".to_string(), "--incremental".to_string()];
+        if let Some(lines) = &options.lines {
+            args.push(format!("-L{},{}", lines.start(), lines.end()));
+        }
+        args.extend(options.rev.iter().cloned());
+        args.extend(["--".to_string(), path.display().to_string()]);
+
+        let operation = format!("git {}", args.join(" "));
+        let child = self.git_command()
+            .args(&args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| GitError::OperationFailed {
+                operation: operation.clone(),
+                reason: format!("Failed to execute: {}", e),
+            })?;
+
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        Ok(Blame {
+            lines: AsyncStream::new(receiver),
+            task: tokio::spawn(stream_blame(child, operation, contents, sender)),
+        })
+    }
+
+    async fn archive(&self, rev: &str, output: &Path, options: &ArchiveOptions) -> Result<ArchiveInfo> {
+        let commit = self.resolve_commit(rev).await?;
+        let format = options.format
+            .or_else(|| ArchiveFormat::from_path(output))
+            .unwrap_or_default();
+
+        // Relative output paths are relative to the caller, not the repository
+        let path = std::path::absolute(output).map_err(|e| GitError::OperationFailed {
+            operation: format!("archive {}", output.display()),
+            reason: e.to_string(),
+        })?;
+        let mut args = vec![
+            "archive".to_string(),
+            format!("--format={}", format.as_str()),
+            format!("--output={}", path.display()),
+        ];
+        if let Some(prefix) = options.prefix.as_deref().filter(|prefix| !prefix.is_empty()) {
+            args.push(format!("--prefix={}/", prefix.trim_end_matches('/')));
+        }
+        args.push(commit.clone());
+        if !options.pathspecs.is_empty() {
+            args.push("--".to_string());
+            args.extend(options.pathspecs.iter().cloned());
+        }
+
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.run_git_checked(&args).await?;
+
+        let size = std::fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or_default();
+        Ok(ArchiveInfo {
+            commit,
+            path,
+            format,
+            size,
+        })
+    }
+
+    async fn ls_remote(&self, options: &LsRemoteOptions) -> Result<Vec<RemoteRef>> {
+        let remote = options.remote.as_deref().unwrap_or("origin");
+        let mut url = match self.remote_url(remote, false).await {
+            Ok(url) => url,
+            Err(ReleaseError::Git(GitError::RemoteNotFound { .. })) => remote.to_string(),
+            Err(e) => return Err(e),
+        };
+        // Local paths are relative to the repository, not to our working directory
+        let local = self.repo_path.join(&url);
+        if local.exists() {
+            url = local.display().to_string();
+        }
+        let credential = self.credentials.resolve(&url, &self.repo_path).await;
+        ls_remote::list_refs(remote, &url, credential, options).await
+    }
+
+    async fn find_remote_ref(&self, remote_name: Option<&str>, ref_name: &str, timeout: Option<std::time::Duration>) -> Result<Option<RemoteRef>> {
+        let refs = self.ls_remote(&LsRemoteOptions {
+            remote: remote_name.map(str::to_string),
+            patterns: vec![ref_name.to_string()],
+            timeout,
+            ..LsRemoteOptions::default()
+        }).await?;
+        // Patterns match any ref ending in the name, so insist on the exact ref
+        Ok(refs.into_iter().find(|remote_ref| remote_ref.name == ref_name))
+    }
+
+    async fn read_note(&self, notes_ref: &str, rev: &str) -> Result<Option<String>> {
+        let commit = self.resolve_commit(rev).await?;
+        let notes_arg = format!("--ref={}", notes_ref);
+        let output = self.run_git(&["notes", &notes_arg, "show", &commit]).await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("no note found") {
+                return Ok(None);
+            }
+            return Err(GitError::OperationFailed {
+                operation: format!("git notes {} show {}", notes_arg, rev),
+                reason: stderr.trim().to_string(),
+            }.into());
+        }
+        Ok(Some(String::from_utf8_lossy(&output.stdout).trim_end().to_string()))
+    }
+
+    async fn write_note(&self, notes_ref: &str, rev: &str, message: &str) -> Result<()> {
+        let commit = self.resolve_commit(rev).await?;
+        let notes_arg = format!("--ref={}", notes_ref);
+        self.run_git_checked(&["notes", &notes_arg, "add", "--force", "--message", message, &commit]).await?;
+        Ok(())
+    }
+
+    async fn remove_note(&self, notes_ref: &str, rev: &str) -> Result<bool> {
+        if self.read_note(notes_ref, rev).await?.is_none() {
+            return Ok(false);
+        }
+        let commit = self.resolve_commit(rev).await?;
+        let notes_arg = format!("--ref={}", notes_ref);
+        self.run_git_checked(&["notes", &notes_arg, "remove", &commit]).await?;
+        Ok(true)
+    }
+
+    async fn list_notes(&self, notes_ref: &str) -> Result<Vec<Note>> {
+        let notes_arg = format!("--ref={}", notes_ref);
+        let output = self.run_git(&["notes", &notes_arg, "list"]).await?;
+        if !output.status.success() {
+            // A notes ref that was never written has no notes
+            return Ok(Vec::new());
+        }
+
+        let mut notes = Vec::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Some((blob, commit)) = line.split_once(' ') else {
+                continue;
+            };
+            let message = self.run_git_checked(&["cat-file", "blob", blob]).await?;
+            notes.push(Note {
+                commit: commit.to_string(),
+                message,
+            });
+        }
+        Ok(notes)
+    }
+
+    async fn push_notes(&self, remote_name: Option<&str>, notes_ref: &str) -> Result<()> {
+        let remote = remote_name.unwrap_or("origin");
+        let full_ref = if notes_ref.starts_with("refs/") {
+            notes_ref.to_string()
+        } else {
+            format!("refs/notes/{}", notes_ref)
+        };
+        let refspec = format!("{}:{}", full_ref, full_ref);
+        let output = self.run_remote_git(remote, true, &["push", remote, &refspec], None).await?;
+        if !output.status.success() {
+            if let Some(rejection) = self.hook_rejection("push", &output).await {
+                return Err(rejection.into());
+            }
+            return Err(GitError::PushFailed {
+                reason: format!("{}: {}", full_ref, String::from_utf8_lossy(&output.stderr).trim()),
+            }.into());
+        }
+        Ok(())
+    }
+
+    async fn verify_commit_signature(&self, rev: &str, keys: &TrustedKeys) -> Result<SignatureVerification> {
+        let commit = self.resolve_commit(rev).await?;
+        self.verify_signature("verify-commit", &commit, keys).await
+    }
+
+    async fn verify_tag_signature(&self, tag_name: &str, keys: &TrustedKeys) -> Result<SignatureVerification> {
+        if !self.tag_exists(tag_name).await? {
+            return Err(GitError::RevisionNotFound { rev: tag_name.to_string() }.into());
+        }
+        self.verify_signature("verify-tag", &format!("refs/tags/{}", tag_name), keys).await
+    }
+}
+
+impl RemoteInfo {
+    /// Host, owner, and repository parsed from the fetch URL
+    pub fn parsed_url(&self) -> Option<RemoteUrl> {
+        RemoteUrl::parse(&self.fetch_url)
+    }
 }
 
 impl BranchInfo {
@@ -400,3 +2870,439 @@ impl BranchInfo {
         &self.commit_hash
     }
 }
+
+/// Describe how git will look for the repository containing `path`, for discovery errors
+fn discovery_strategy(path: &Path) -> String {
+    let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+    let mut strategy = match var("GIT_DIR") {
+        Some(git_dir) => format!("GIT_DIR={}", git_dir),
+        None => format!("searching {} and its parents", path.display()),
+    };
+    if let Some(work_tree) = var("GIT_WORK_TREE") {
+        strategy.push_str(&format!(", GIT_WORK_TREE={}", work_tree));
+    }
+    if let Some(ceilings) = var("GIT_CEILING_DIRECTORIES") {
+        strategy.push_str(&format!(", stopping at GIT_CEILING_DIRECTORIES={}", ceilings));
+    }
+    strategy
+}
+
+/// Check if a hook file exists and git would run it
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// Check if a stderr line is one of git's messages (an error, warning, or hint) rather than progress
+fn is_message(line: &str) -> bool {
+    ["fatal: ", "error: ", "warning: ", "hint: "].iter().any(|prefix| line.starts_with(prefix))
+}
+
+/// Run a prepared git command with `args`, reporting progress lines to `progress` as they are printed
+async fn run_git_streaming(mut command: Command, args: &[&str], progress: Option<&dyn ProgressSink>) -> Result<std::process::Output> {
+    use tokio::io::AsyncReadExt;
+
+    let operation = format!("git {}", args.join(" "));
+    let mut child = command
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| GitError::OperationFailed {
+            operation: operation.clone(),
+            reason: format!("Failed to execute: {}", e),
+        })?;
+
+    let mut stdout_pipe = child.stdout.take();
+    let mut stderr_pipe = child.stderr.take();
+    let read_stdout = async {
+        let mut stdout = Vec::new();
+        if let Some(pipe) = stdout_pipe.as_mut() {
+            pipe.read_to_end(&mut stdout).await?;
+        }
+        Ok::<_, std::io::Error>(stdout)
+    };
+    let read_stderr = async {
+        let mut stderr = Vec::new();
+        let mut buffer = [0u8; 4096];
+        let mut line_start = 0;
+        while let Some(pipe) = stderr_pipe.as_mut() {
+            let read = pipe.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            stderr.extend_from_slice(&buffer[..read]);
+            // Progress lines are rewritten in place with '\r' until they end with '\n'
+            while let Some(end) = stderr[line_start..].iter().position(|byte| *byte == b'\r' || *byte == b'\n') {
+                let line = String::from_utf8_lossy(&stderr[line_start..line_start + end]);
+                if let Some(progress) = progress
+                    && !line.trim().is_empty()
+                    && !is_message(line.trim())
+                {
+                    progress.on_progress(&ProgressEvent::parse(line.trim()));
+                }
+                line_start += end + 1;
+            }
+        }
+        Ok::<_, std::io::Error>(stderr)
+    };
+
+    let (stdout, stderr) = tokio::try_join!(read_stdout, read_stderr).map_err(|e| GitError::OperationFailed {
+        operation: operation.clone(),
+        reason: e.to_string(),
+    })?;
+    let status = child.wait().await.map_err(|e| GitError::OperationFailed {
+        operation,
+        reason: e.to_string(),
+    })?;
+
+    Ok(std::process::Output { status, stdout, stderr })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::test_repo::{git, TestRepo};
+
+    fn entry(path: &str, change: FileChange) -> StatusEntry {
+        StatusEntry {
+            path: PathBuf::from(path),
+            change,
+        }
+    }
+
+    fn renamed(from: &str) -> FileChange {
+        FileChange::Renamed { from: PathBuf::from(from) }
+    }
+
+    fn line(kind: DiffLineKind, content: &str) -> DiffLine {
+        DiffLine {
+            kind,
+            content: content.to_string(),
+        }
+    }
+
+    fn messages(commits: &[CommitInfo]) -> Vec<&str> {
+        commits.iter().map(|commit| commit.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_porcelain_v2_status() {
+        let output = [
+            "1 M. N... 100644 100644 100644 1111111 2222222 src/lib.rs",
+            "1 .D N... 100644 100644 000000 1111111 1111111 gone.rs",
+            "2 R. N... 100644 100644 100644 1111111 1111111 R100 new name.rs",
+            "old name.rs",
+            "u UU N... 100644 100644 100644 100644 1111111 2222222 3333333 conflict.rs",
+            "? notes.txt",
+            "! target/",
+            "",
+        ].join("\0");
+        let report = StatusReport::parse(output.as_bytes());
+
+        assert_eq!(report.staged, vec![
+            entry("src/lib.rs", FileChange::Modified),
+            entry("new name.rs", renamed("old name.rs")),
+        ]);
+        assert_eq!(report.unstaged, vec![entry("gone.rs", FileChange::Deleted)]);
+        assert_eq!(report.conflicted, vec![PathBuf::from("conflict.rs")]);
+        assert_eq!(report.untracked, vec![PathBuf::from("notes.txt")]);
+        assert_eq!(report.ignored, vec![PathBuf::from("target/")]);
+        assert_eq!(report.summary(), "2 staged, 1 unstaged, 1 conflicted, 1 untracked");
+
+        let ignored_only = StatusReport::parse(b"! target/\0");
+        assert!(ignored_only.is_clean());
+        assert_eq!(ignored_only.summary(), "clean");
+    }
+
+    #[test]
+    fn test_parse_name_status_and_numstat() {
+        let mut files = FileDiff::parse_name_status(b"M\0src/lib.rs\0R087\0old.rs\0new.rs\0A\0logo.png\0D\0gone.rs\0");
+        FileDiff::add_line_counts(&mut files, b"3\t1\tsrc/lib.rs\x002\t2\t\0old.rs\0new.rs\0-\t-\tlogo.png\x000\t4\tgone.rs\0");
+
+        let summary: Vec<(&Path, &FileChange, usize, usize, bool)> = files.iter()
+            .map(|file| (file.path.as_path(), &file.change, file.additions, file.deletions, file.binary))
+            .collect();
+        assert_eq!(summary, vec![
+            (Path::new("src/lib.rs"), &FileChange::Modified, 3, 1, false),
+            (Path::new("new.rs"), &renamed("old.rs"), 2, 2, false),
+            (Path::new("logo.png"), &FileChange::Added, 0, 0, true),
+            (Path::new("gone.rs"), &FileChange::Deleted, 0, 4, false),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_patch_hunks() {
+        let patch = concat!(
+            "diff --git a/src/lib.rs b/src/lib.rs\n",
+            "index 1111111..2222222 100644\n",
+            "--- a/src/lib.rs\n",
+            "+++ b/src/lib.rs\n",
+            "@@ -1,3 +1,4 @@ fn main() {\n",
+            " keep\n",
+            "-old\n",
+            "+new\n",
+            "+added\n",
+            "\\ No newline at end of file\n",
+            "diff --git a/gone.rs b/gone.rs\n",
+            "deleted file mode 100644\n",
+            "--- a/gone.rs\n",
+            "+++ /dev/null\n",
+            "@@ -1 +0,0 @@\n",
+            "-bye\n",
+            "diff --git \"a/tab\\there.rs\" \"b/tab\\there.rs\"\n",
+            "--- \"a/tab\\there.rs\"\n",
+            "+++ \"b/tab\\there.rs\"\n",
+            "@@ -0,0 +1 @@\n",
+            "+x\n",
+        );
+        let hunks = parse_patch(patch);
+
+        let lib = &hunks[Path::new("src/lib.rs")];
+        assert_eq!(lib.len(), 1);
+        assert_eq!((lib[0].old_start, lib[0].old_lines, lib[0].new_start, lib[0].new_lines), (1, 3, 1, 4));
+        assert_eq!(lib[0].heading, "fn main() {");
+        assert_eq!(lib[0].lines, vec![
+            line(DiffLineKind::Context, "keep"),
+            line(DiffLineKind::Removed, "old"),
+            line(DiffLineKind::Added, "new"),
+            line(DiffLineKind::Added, "added"),
+        ]);
+
+        // Deleted files are keyed by their old path
+        let gone = &hunks[Path::new("gone.rs")];
+        assert_eq!((gone[0].old_start, gone[0].old_lines, gone[0].new_start, gone[0].new_lines), (1, 1, 0, 0));
+        assert_eq!(gone[0].lines, vec![line(DiffLineKind::Removed, "bye")]);
+
+        let quoted = &hunks[Path::new("tab\there.rs")];
+        assert_eq!(quoted[0].lines, vec![line(DiffLineKind::Added, "x")]);
+        assert_eq!(DiffHunk::parse_header("@@ -1,2 +1,2"), None);
+    }
+
+    #[test]
+    fn test_parse_commit_record() {
+        let commit = parse_commit_record(
+            "abcdef0123\0abcdef0\0p1 p2\0Release Bot\0release@example.com\x002024-01-02T03:04:05+02:00\0Merge branch 'next'",
+        ).unwrap();
+        assert_eq!(commit.hash, "abcdef0123");
+        assert_eq!(commit.short_hash, "abcdef0");
+        assert_eq!(commit.parents, vec!["p1", "p2"]);
+        assert_eq!((commit.author_name.as_str(), commit.author_email.as_str()), ("Release Bot", "release@example.com"));
+        assert_eq!(commit.timestamp.to_rfc3339(), "2024-01-02T01:04:05+00:00");
+        assert_eq!(commit.message, "Merge branch 'next'");
+
+        assert!(parse_commit_record("abcdef0123\0abcdef0").is_none());
+    }
+
+    #[test]
+    fn test_parse_stash_and_worktree_lists() {
+        assert_eq!(StashEntry::parse_list("stash@{0}\0aaa\0On main: before release\nstash@{1}\0bbb\0WIP on main: 1234567 x\n"), vec![
+            StashEntry {
+                reference: "stash@{0}".to_string(),
+                commit: "aaa".to_string(),
+                message: "On main: before release".to_string(),
+            },
+            StashEntry {
+                reference: "stash@{1}".to_string(),
+                commit: "bbb".to_string(),
+                message: "WIP on main: 1234567 x".to_string(),
+            },
+        ]);
+
+        let worktrees = WorktreeInfo::parse_list(concat!(
+            "worktree /repo\nHEAD aaa\nbranch refs/heads/main\n\n",
+            "worktree /repo-release\nHEAD bbb\ndetached\nlocked in use\nprunable gitdir file points to non-existent location\n",
+        ));
+        assert_eq!(worktrees, vec![
+            WorktreeInfo {
+                path: PathBuf::from("/repo"),
+                head: Some("aaa".to_string()),
+                branch: Some("main".to_string()),
+                bare: false,
+                locked: false,
+                prunable: false,
+            },
+            WorktreeInfo {
+                path: PathBuf::from("/repo-release"),
+                head: Some("bbb".to_string()),
+                branch: None,
+                bare: false,
+                locked: true,
+                prunable: true,
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_commit_tag_and_push_to_bare_remote() {
+        let test = TestRepo::new();
+        let repo = test.repository();
+
+        std::fs::write(test.work.join("CHANGELOG.md"), "## 1.0.0\n").unwrap();
+        let commit = repo.commit_all("Release 1.0.0").await.unwrap();
+        assert_eq!(commit.message, "Release 1.0.0");
+        assert_eq!(commit.parents.len(), 1);
+        assert!(repo.is_working_directory_clean().await.unwrap());
+
+        let tag = repo.create_tag_with_options("v1.0.0", &TagOptions::default()).await.unwrap();
+        assert!(tag.is_annotated);
+        assert_eq!(tag.target_commit, commit.hash);
+        repo.create_tag_with_options("docs-1.0.0", &TagOptions {
+            kind: TagKind::Lightweight,
+            ..TagOptions::default()
+        }).await.unwrap();
+        let tags: Vec<String> = repo.list_tags(Some("v1.*")).await.unwrap().into_iter().map(|tag| tag.name).collect();
+        assert_eq!(tags, vec!["v1.0.0"]);
+
+        // Nothing has been pushed yet, so both commits count
+        let push = repo.push_atomic(None, Some("main"), &["v1.0.0".to_string()], None).await.unwrap();
+        assert_eq!((push.commits_pushed, push.tags_pushed), (2, 1));
+        assert_eq!(git(&test.remote, &["rev-parse", "refs/heads/main"]), commit.hash);
+        assert_eq!(git(&test.remote, &["rev-parse", "v1.0.0^{commit}"]), commit.hash);
+
+        test.commit("CHANGELOG.md", "## 1.0.1\n", "Release 1.0.1");
+        let push = repo.push_atomic(None, Some("main"), &[], None).await.unwrap();
+        assert_eq!((push.commits_pushed, push.tags_pushed), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_pull_from_bare_remote() {
+        let test = TestRepo::new();
+        test.git(&["push", "--quiet", "--set-upstream", "origin", "main"]);
+        let upstream = tempfile::tempdir().unwrap();
+        git(upstream.path(), &["clone", "--quiet", &test.remote.display().to_string(), "clone"]);
+        let clone = upstream.path().join("clone");
+        let push_upstream = |file: &str, message: &str| {
+            std::fs::write(clone.join(file), message).unwrap();
+            git(&clone, &["add", file]);
+            git(&clone, &["commit", "--quiet", "-m", message]);
+            git(&clone, &["push", "--quiet", "origin", "main"]);
+            git(&clone, &["rev-parse", "HEAD"])
+        };
+        let upstream_head = push_upstream("UPSTREAM.md", "Upstream change");
+        let repo = test.repository();
+
+        assert_eq!(repo.upstream_of("main").await.unwrap(), Some(UpstreamRef {
+            remote: "origin".to_string(),
+            merge_ref: "refs/heads/main".to_string(),
+        }));
+        let fetch = repo.fetch(&FetchOptions::default(), None).await.unwrap();
+        assert_eq!(fetch.remote_name, "origin");
+        assert!(fetch.updated_refs.iter().any(|update| {
+            update.name == "refs/remotes/origin/main" && update.new_oid.as_deref() == Some(upstream_head.as_str())
+        }), "{:?}", fetch.updated_refs);
+        assert_eq!(repo.divergence("main", "origin/main").await.unwrap(), Divergence { ahead: 0, behind: 1 });
+
+        let pull = repo.pull(None, PullStrategy::FastForwardOnly, None).await.unwrap();
+        assert_eq!(pull.upstream, "refs/remotes/origin/main");
+        assert!(matches!(&pull.outcome, PullOutcome::FastForward { to, .. } if *to == upstream_head), "{:?}", pull.outcome);
+        let pull = repo.pull(None, PullStrategy::FastForwardOnly, None).await.unwrap();
+        assert_eq!(pull.outcome, PullOutcome::UpToDate);
+
+        // Once both sides have moved, only a merge can integrate the upstream
+        test.commit("LOCAL.md", "local", "Local change");
+        push_upstream("UPSTREAM.md", "Second upstream change");
+        let error = repo.pull(None, PullStrategy::FastForwardOnly, None).await.unwrap_err();
+        assert!(matches!(error, ReleaseError::Git(GitError::NonFastForward { ahead: 1, behind: 1, .. })), "{:?}", error);
+        let pull = repo.pull(None, PullStrategy::Merge, None).await.unwrap();
+        assert!(matches!(pull.outcome, PullOutcome::Merged { .. }), "{:?}", pull.outcome);
+        assert_eq!(repo.divergence("main", "origin/main").await.unwrap(), Divergence { ahead: 2, behind: 0 });
+
+        let error = repo.fetch(&FetchOptions {
+            refspecs: vec!["refs/heads/missing".to_string()],
+            ..FetchOptions::default()
+        }, None).await.unwrap_err();
+        assert!(matches!(error, ReleaseError::Git(GitError::RemoteRefNotFound { .. })), "{:?}", error);
+    }
+
+    #[tokio::test]
+    async fn test_status_diff_stash_and_reset() {
+        let test = TestRepo::new();
+        let first = test.git(&["rev-parse", "HEAD"]);
+        let second = test.commit("lib.rs", "fn a() {}\n", "Add lib");
+        let repo = test.repository();
+
+        std::fs::write(test.work.join("lib.rs"), "fn b() {}\n").unwrap();
+        std::fs::write(test.work.join("scratch.txt"), "notes\n").unwrap();
+        let status = repo.status().await.unwrap();
+        assert_eq!(status.unstaged, vec![entry("lib.rs", FileChange::Modified)]);
+        assert_eq!(status.untracked, vec![PathBuf::from("scratch.txt")]);
+
+        let diff = repo.diff("HEAD", &DiffTarget::WorkingTree, &DiffOptions {
+            include_hunks: true,
+            ..DiffOptions::default()
+        }).await.unwrap();
+        assert_eq!(diff.len(), 1);
+        assert_eq!((diff[0].additions, diff[0].deletions), (1, 1));
+        assert_eq!(diff[0].hunks[0].lines, vec![
+            line(DiffLineKind::Removed, "fn a() {}"),
+            line(DiffLineKind::Added, "fn b() {}"),
+        ]);
+
+        let stash = repo.stash_save(&StashOptions {
+            message: Some("before release".to_string()),
+            include_untracked: true,
+            ..StashOptions::default()
+        }).await.unwrap().expect("changes were stashed");
+        assert_eq!(stash.reference, "stash@{0}");
+        assert!(stash.message.ends_with("before release"), "{}", stash.message);
+        assert!(repo.status().await.unwrap().is_clean());
+        assert_eq!(repo.stash_save(&StashOptions::default()).await.unwrap(), None);
+
+        repo.stash_pop(&stash.reference).await.unwrap();
+        assert_eq!(std::fs::read_to_string(test.work.join("lib.rs")).unwrap(), "fn b() {}\n");
+        assert!(test.work.join("scratch.txt").exists());
+        assert!(repo.stash_list().await.unwrap().is_empty());
+
+        let reset = repo.reset(&first, ResetType::Hard).await.unwrap();
+        assert_eq!(reset, ResetInfo {
+            previous_head: second,
+            head: first,
+        });
+        assert!(!test.work.join("lib.rs").exists());
+        // Untracked files survive a hard reset
+        assert!(test.work.join("scratch.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn test_log_walks_paths_and_ranges() {
+        let test = TestRepo::new();
+        test.commit("a.txt", "a", "Touch a");
+        test.commit("b.txt", "b", "Touch b");
+        let repo = test.repository();
+
+        let touching_a = repo.log(&LogOptions {
+            paths: vec!["a.txt".to_string()],
+            ..LogOptions::default()
+        }).await.unwrap().collect().await.unwrap();
+        assert_eq!(messages(&touching_a), vec!["Touch a"]);
+
+        let recent = repo.get_recent_commits(2).await.unwrap();
+        assert_eq!(messages(&recent), vec!["Touch b", "Touch a"]);
+        assert_eq!(recent[0].parents, vec![recent[1].hash.clone()]);
+
+        let since = repo.log(&LogOptions {
+            until: Some("HEAD~1".to_string()),
+            ..LogOptions::default()
+        }).await.unwrap().collect().await.unwrap();
+        assert_eq!(messages(&since), vec!["Touch b"]);
+
+        let unknown = repo.log(&LogOptions {
+            from: Some("no-such-revision".to_string()),
+            ..LogOptions::default()
+        }).await;
+        let unknown = match unknown {
+            Ok(log) => log.collect().await,
+            Err(e) => Err(e),
+        };
+        assert!(unknown.is_err());
+    }
+}
@@ -0,0 +1,230 @@
+//! Progress of long-running git operations.
+//!
+//! Clone, fetch, and push report git's progress output as `ProgressEvent`s to
+//! a `ProgressSink`: a closure, or the sending half of `progress_channel`,
+//! whose `AsyncStream` a CLI or TUI reads to draw progress bars.
+
+use sugars_async_stream::AsyncStream;
+
+/// Phase of a transfer, as git reports it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressPhase {
+    /// Enumerating or counting the objects to send ("Counting objects")
+    Counting,
+    /// Compressing objects before sending them ("Compressing objects")
+    Compressing,
+    /// Uploading objects to the remote ("Writing objects")
+    Writing,
+    /// Downloading objects from the remote ("Receiving objects")
+    Receiving,
+    /// Indexing received objects ("Resolving deltas")
+    Resolving,
+    /// Writing files into the working tree ("Updating files")
+    Checkout,
+    /// Any other message
+    Other,
+}
+
+/// A progress update of a running git operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressEvent {
+    /// Phase the stage belongs to
+    pub phase: ProgressPhase,
+    /// Stage git reports (e.g. "Receiving objects", "Resolving deltas")
+    pub stage: String,
+    /// Whether the remote reported the stage (`remote: ` lines)
+    pub remote: bool,
+    /// Completion of the stage, if git reports it
+    pub percent: Option<u8>,
+    /// Objects processed so far
+    pub current: Option<u64>,
+    /// Objects to process in the stage
+    pub total: Option<u64>,
+    /// Bytes transferred so far
+    pub bytes: Option<u64>,
+    /// Transfer rate in bytes per second
+    pub bytes_per_second: Option<u64>,
+    /// The line as printed by git
+    pub message: String,
+}
+
+/// Receives progress updates of git operations
+pub trait ProgressSink: Send + Sync {
+    /// Handle one progress update
+    fn on_progress(&self, event: &ProgressEvent);
+}
+
+impl<F: Fn(&ProgressEvent) + Send + Sync> ProgressSink for F {
+    fn on_progress(&self, event: &ProgressEvent) {
+        self(event)
+    }
+}
+
+/// Sending half of `progress_channel`
+#[derive(Debug, Clone)]
+pub struct ProgressSender {
+    /// Channel feeding the progress stream
+    sender: tokio::sync::mpsc::UnboundedSender<ProgressEvent>,
+}
+
+impl ProgressSink for ProgressSender {
+    fn on_progress(&self, event: &ProgressEvent) {
+        // The stream being dropped only means nobody is watching
+        let _ = self.sender.send(event.clone());
+    }
+}
+
+/// A sink whose updates arrive on the returned stream, which ends once every sender is dropped
+pub fn progress_channel() -> (ProgressSender, AsyncStream<ProgressEvent>) {
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    (ProgressSender { sender }, AsyncStream::new(receiver))
+}
+
+impl ProgressPhase {
+    /// Phase of a stage name
+    fn from_stage(stage: &str) -> Self {
+        match stage {
+            "Enumerating objects" | "Counting objects" => ProgressPhase::Counting,
+            "Compressing objects" => ProgressPhase::Compressing,
+            "Writing objects" => ProgressPhase::Writing,
+            "Receiving objects" => ProgressPhase::Receiving,
+            "Resolving deltas" | "Indexing objects" => ProgressPhase::Resolving,
+            "Updating files" | "Checking out files" => ProgressPhase::Checkout,
+            _ => ProgressPhase::Other,
+        }
+    }
+}
+
+impl ProgressEvent {
+    /// Parse a progress line such as "Receiving objects:  45% (450/1000), 1.20 MiB | 2.00 MiB/s"
+    pub(crate) fn parse(line: &str) -> Self {
+        let (remote, body) = match line.strip_prefix("remote: ") {
+            Some(body) => (true, body),
+            None => (false, line),
+        };
+        let Some((stage, detail)) = body.split_once(": ") else {
+            let stage = body.trim_end_matches("...");
+            return Self {
+                phase: ProgressPhase::from_stage(stage),
+                stage: stage.to_string(),
+                remote,
+                percent: None,
+                current: None,
+                total: None,
+                bytes: None,
+                bytes_per_second: None,
+                message: line.to_string(),
+            };
+        };
+
+        let percent = detail.split_once('%').and_then(|(percent, _)| percent.trim().parse().ok());
+        let counts = detail.split_once('(')
+            .and_then(|(_, counts)| counts.split_once(')'))
+            .and_then(|(counts, _)| counts.split_once('/'));
+        // ", 1.20 MiB | 2.00 MiB/s" follows the counts while data is transferred
+        let (bytes, rate) = detail.split_once("), ")
+            .map(|(_, transfer)| transfer.split_once('|').unwrap_or((transfer, "")))
+            .unwrap_or_default();
+        Self {
+            phase: ProgressPhase::from_stage(stage),
+            stage: stage.to_string(),
+            remote,
+            percent,
+            current: counts.and_then(|(current, _)| current.trim().parse().ok()),
+            total: counts.and_then(|(_, total)| total.trim().parse().ok()),
+            bytes: parse_size(bytes),
+            bytes_per_second: parse_size(rate),
+            message: line.to_string(),
+        }
+    }
+}
+
+/// Parse a size or rate git prints with a binary unit ("512 bytes", "1.20 MiB", "2.00 MiB/s")
+fn parse_size(size: &str) -> Option<u64> {
+    let size = size.split(',').next()?.trim().trim_end_matches("/s");
+    let (value, unit) = size.split_once(' ')?;
+    let value: f64 = value.parse().ok()?;
+    let scale: f64 = match unit.trim() {
+        "byte" | "bytes" => 1.0,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some((value * scale) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transfer_line() {
+        let event = ProgressEvent::parse("Receiving objects:  45% (450/1000), 1.50 MiB | 512.00 KiB/s");
+        assert_eq!(event, ProgressEvent {
+            phase: ProgressPhase::Receiving,
+            stage: "Receiving objects".to_string(),
+            remote: false,
+            percent: Some(45),
+            current: Some(450),
+            total: Some(1000),
+            bytes: Some(1_572_864),
+            bytes_per_second: Some(524_288),
+            message: "Receiving objects:  45% (450/1000), 1.50 MiB | 512.00 KiB/s".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_parse_remote_and_finished_lines() {
+        let event = ProgressEvent::parse("remote: Compressing objects: 100% (3/3), done.");
+        assert!(event.remote);
+        assert_eq!(event.phase, ProgressPhase::Compressing);
+        assert_eq!((event.percent, event.current, event.total), (Some(100), Some(3), Some(3)));
+        assert_eq!((event.bytes, event.bytes_per_second), (None, None));
+
+        let event = ProgressEvent::parse("Writing objects: 100% (5/5), 412 bytes | 412.00 KiB/s, done.");
+        assert_eq!(event.phase, ProgressPhase::Writing);
+        assert_eq!(event.bytes, Some(412));
+        assert_eq!(event.bytes_per_second, Some(421_888));
+
+        let event = ProgressEvent::parse("remote: Enumerating objects: 5, done.");
+        assert_eq!(event.phase, ProgressPhase::Counting);
+        assert_eq!((event.percent, event.current, event.total), (None, None, None));
+    }
+
+    #[test]
+    fn test_parse_lines_without_counts() {
+        let event = ProgressEvent::parse("Checking connectivity...");
+        assert_eq!(event.phase, ProgressPhase::Other);
+        assert_eq!(event.stage, "Checking connectivity");
+        assert_eq!(event.message, "Checking connectivity...");
+        assert_eq!(event.percent, None);
+
+        assert_eq!(ProgressEvent::parse("Resolving deltas:  10% (1/10)").phase, ProgressPhase::Resolving);
+        assert_eq!(ProgressEvent::parse("Updating files: 100% (12/12), done.").phase, ProgressPhase::Checkout);
+    }
+
+    #[test]
+    fn test_parse_size_units() {
+        assert_eq!(parse_size("512 bytes"), Some(512));
+        assert_eq!(parse_size("1 byte"), Some(1));
+        assert_eq!(parse_size(" 2.00 KiB/s"), Some(2048));
+        assert_eq!(parse_size("1.00 GiB, done."), Some(1 << 30));
+        assert_eq!(parse_size("3 furlongs"), None);
+        assert_eq!(parse_size(""), None);
+    }
+
+    #[tokio::test]
+    async fn test_progress_channel_ends_with_its_senders() {
+        let (sender, stream) = progress_channel();
+        let sink: &dyn ProgressSink = &sender;
+        sink.on_progress(&ProgressEvent::parse("Counting objects: 1"));
+        sender.clone().on_progress(&ProgressEvent::parse("Writing objects: 100% (1/1)"));
+        drop(sender);
+
+        let events = stream.collect_async().await;
+        let stages: Vec<_> = events.into_iter().map(|event| event.stage).collect();
+        assert_eq!(stages, vec!["Counting objects".to_string(), "Writing objects".to_string()]);
+    }
+}
@@ -0,0 +1,340 @@
+//! Tag name and commit message templating.
+//!
+//! Templates use `{variable}` placeholders that are substituted when a
+//! release commit or tag is created. Literal braces are written as `{{` and `}}`.
+//!
+//! Supported variables:
+//! - `{version}`, `{major}`, `{minor}`, `{patch}`: the release version
+//! - `{package}`: the package being released (per-package tag names only)
+//! - `{packages}`: comma-separated list of released packages (messages only)
+//! - `{date}`: the current UTC date (`YYYY-MM-DD`)
+//!
+//! Each config field accepts only the variables it is rendered with; see the `*_VARIABLES` constants.
+
+use crate::error::{GitError, Result};
+use semver::Version;
+
+/// Default tag name template
+pub const DEFAULT_TAG_TEMPLATE: &str = "v{version}";
+
+/// Default per-package tag name template for independent versioning
+pub const DEFAULT_PACKAGE_TAG_TEMPLATE: &str = "{package}-v{version}";
+
+/// Default release commit message template
+pub const DEFAULT_COMMIT_MESSAGE_TEMPLATE: &str = "release: v{version}";
+
+/// Default annotated tag message template
+pub const DEFAULT_TAG_MESSAGE_TEMPLATE: &str = "Release v{version}";
+
+/// Variables recognized in templates
+const KNOWN_VARIABLES: &[&str] = &["version", "major", "minor", "patch", "package", "packages", "date"];
+
+/// Variables available to the workspace tag template (`git.tag-template`)
+pub const TAG_TEMPLATE_VARIABLES: &[&str] = &["version", "major", "minor", "patch", "date"];
+
+/// Variables available to the per-package tag template (`git.package-tag-template`)
+pub const PACKAGE_TAG_TEMPLATE_VARIABLES: &[&str] = &["version", "major", "minor", "patch", "package", "date"];
+
+/// Variables available to commit and tag messages, which are not always rendered for a single package
+pub const MESSAGE_TEMPLATE_VARIABLES: &[&str] = &["version", "major", "minor", "patch", "packages", "date"];
+
+/// Variables available to the maintenance branch template (`git.maintenance-branch`)
+pub const BRANCH_TEMPLATE_VARIABLES: &[&str] = &["version", "major", "minor", "patch"];
+
+/// Kind of template, which determines validation rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateKind {
+    /// Git tag name (must be a valid ref name and include `{version}`)
+    TagName,
+    /// Commit or tag message
+    Message,
+}
+
+/// Values substituted into release templates
+#[derive(Debug, Clone)]
+pub struct TemplateContext {
+    /// Version being released
+    pub version: Version,
+    /// Package being released, for single-package releases
+    pub package: Option<String>,
+    /// All packages included in the release
+    pub packages: Vec<String>,
+    /// Release date (`YYYY-MM-DD`)
+    pub date: String,
+}
+
+impl TemplateContext {
+    /// Create a context for a workspace-wide release
+    pub fn new(version: &Version) -> Self {
+        Self {
+            version: version.clone(),
+            package: None,
+            packages: Vec::new(),
+            date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        }
+    }
+
+    /// Set the single package being released
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        let package = package.into();
+        if self.packages.is_empty() {
+            self.packages = vec![package.clone()];
+        }
+        self.package = Some(package);
+        self
+    }
+
+    /// Set the list of packages included in the release
+    pub fn with_packages(mut self, mut packages: Vec<String>) -> Self {
+        packages.sort();
+        self.packages = packages;
+        self
+    }
+
+    /// Derive the context for one package's tag in an independent-versioning release
+    pub fn for_package(&self, package: impl Into<String>, version: &Version) -> Self {
+        Self {
+            version: version.clone(),
+            package: Some(package.into()),
+            packages: self.packages.clone(),
+            date: self.date.clone(),
+        }
+    }
+
+    /// Look up the value of a template variable
+    fn value_of(&self, variable: &str) -> Option<String> {
+        match variable {
+            "version" => Some(self.version.to_string()),
+            "major" => Some(self.version.major.to_string()),
+            "minor" => Some(self.version.minor.to_string()),
+            "patch" => Some(self.version.patch.to_string()),
+            "package" => self.package.clone(),
+            "packages" => Some(self.packages.join(", ")),
+            "date" => Some(self.date.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Render a template with the given context
+pub fn render_template(template: &str, context: &TemplateContext) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+
+    for segment in parse_template(template)? {
+        match segment {
+            Segment::Literal(text) => rendered.push_str(&text),
+            Segment::Variable(name) => {
+                let value = context.value_of(&name).ok_or_else(|| GitError::InvalidTemplate {
+                    template: template.to_string(),
+                    reason: format!("variable '{{{}}}' has no value for this release", name),
+                })?;
+                rendered.push_str(&value);
+            }
+        }
+    }
+
+    Ok(rendered)
+}
+
+/// Glob matching every name a tag template can render (each variable becomes `*`)
+pub fn template_glob(template: &str) -> Result<String> {
+    let mut glob = String::with_capacity(template.len());
+    for segment in parse_template(template)? {
+        match segment {
+            Segment::Literal(text) => glob.push_str(&text),
+            Segment::Variable(_) if glob.ends_with('*') => {}
+            Segment::Variable(_) => glob.push('*'),
+        }
+    }
+    Ok(glob)
+}
+
+/// Validate template syntax and variables without rendering
+pub fn validate_template(template: &str, kind: TemplateKind) -> Result<()> {
+    validate_template_for(template, kind, KNOWN_VARIABLES)
+}
+
+/// Validate a template that is rendered with only `variables` available, such as one config field
+pub fn validate_template_for(template: &str, kind: TemplateKind, variables: &[&str]) -> Result<()> {
+    let segments = parse_template(template)?;
+
+    for segment in &segments {
+        if let Segment::Variable(name) = segment
+            && !variables.contains(&name.as_str())
+        {
+            return Err(GitError::InvalidTemplate {
+                template: template.to_string(),
+                reason: format!(
+                    "variable '{{{}}}' is not available here (expected one of: {})",
+                    name,
+                    variables.join(", ")
+                ),
+            }.into());
+        }
+    }
+
+    if template.trim().is_empty() {
+        return Err(GitError::InvalidTemplate {
+            template: template.to_string(),
+            reason: "template cannot be empty".to_string(),
+        }.into());
+    }
+
+    if kind == TemplateKind::TagName {
+        let has_version = segments.iter().any(|segment| {
+            matches!(segment, Segment::Variable(name) if name == "version")
+        });
+        if !has_version {
+            return Err(GitError::InvalidTemplate {
+                template: template.to_string(),
+                reason: "tag template must include '{version}'".to_string(),
+            }.into());
+        }
+
+        // Render with sample values to check the result is a valid ref name
+        let sample = TemplateContext::new(&Version::new(1, 2, 3)).with_package("package");
+        let tag_name = render_template(template, &sample)?;
+        check_ref_name(&tag_name).map_err(|reason| GitError::InvalidTemplate {
+            template: template.to_string(),
+            reason,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Parsed template segment
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Variable(String),
+}
+
+/// Split a template into literal and variable segments
+fn parse_template(template: &str) -> Result<Vec<Segment>> {
+    let invalid = |reason: String| GitError::InvalidTemplate {
+        template: template.to_string(),
+        reason,
+    };
+
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(invalid("unclosed '{'".to_string()).into()),
+                    }
+                }
+
+                let name = name.trim().to_string();
+                if !KNOWN_VARIABLES.contains(&name.as_str()) {
+                    return Err(invalid(format!(
+                        "unknown variable '{{{}}}' (expected one of: {})",
+                        name,
+                        KNOWN_VARIABLES.join(", ")
+                    )).into());
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Variable(name));
+            }
+            '}' => return Err(invalid("unmatched '}'".to_string()).into()),
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+/// Check a tag name against git's ref name rules
+fn check_ref_name(name: &str) -> std::result::Result<(), String> {
+    if name.is_empty() {
+        return Err("tag name is empty".to_string());
+    }
+    if name.starts_with('-') || name.starts_with('.') || name.starts_with('/') {
+        return Err(format!("tag name '{}' cannot start with '-', '.' or '/'", name));
+    }
+    if name.ends_with('.') || name.ends_with('/') || name.ends_with(".lock") {
+        return Err(format!("tag name '{}' cannot end with '.', '/' or '.lock'", name));
+    }
+    if name.contains("..") || name.contains("@{") || name.contains("//") {
+        return Err(format!("tag name '{}' cannot contain '..', '@{{' or '//'", name));
+    }
+    if let Some(c) = name.chars().find(|c| c.is_control() || " ~^:?*[\\".contains(*c)) {
+        return Err(format!("tag name '{}' contains invalid character {:?}", name, c));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejects(template: &str, kind: TemplateKind, variables: &[&str]) -> String {
+        validate_template_for(template, kind, variables).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_tag_template_rejects_package() {
+        let error = rejects("{package}-v{version}", TemplateKind::TagName, TAG_TEMPLATE_VARIABLES);
+        assert!(error.contains("'{package}' is not available"), "{}", error);
+
+        // The same template renders fine for per-package tags
+        validate_template_for("{package}-v{version}", TemplateKind::TagName, PACKAGE_TAG_TEMPLATE_VARIABLES).unwrap();
+    }
+
+    #[test]
+    fn test_field_variables_render_in_their_contexts() {
+        let version = Version::new(1, 2, 3);
+        let workspace = TemplateContext::new(&version).with_packages(vec!["a".to_string(), "b".to_string()]);
+        let all = |variables: &[&str]| variables.iter().map(|name| format!("{{{}}}", name)).collect::<Vec<_>>().join("-");
+
+        // Every variable a field accepts has a value in the context that field is rendered with
+        render_template(&all(TAG_TEMPLATE_VARIABLES), &TemplateContext::new(&version)).unwrap();
+        render_template(&all(BRANCH_TEMPLATE_VARIABLES), &TemplateContext::new(&version)).unwrap();
+        render_template(&all(MESSAGE_TEMPLATE_VARIABLES), &workspace).unwrap();
+        render_template(&all(PACKAGE_TAG_TEMPLATE_VARIABLES), &workspace.for_package("a", &version)).unwrap();
+
+        // Which is not true of `{package}` outside per-package tags
+        assert!(render_template("{package}", &workspace).is_err());
+    }
+
+    #[test]
+    fn test_message_and_branch_templates() {
+        validate_template_for("release: {packages} v{version}", TemplateKind::Message, MESSAGE_TEMPLATE_VARIABLES).unwrap();
+        rejects("Release {package}", TemplateKind::Message, MESSAGE_TEMPLATE_VARIABLES);
+        rejects("release/{date}", TemplateKind::Message, BRANCH_TEMPLATE_VARIABLES);
+    }
+
+    #[test]
+    fn test_validate_template_syntax() {
+        validate_template(DEFAULT_TAG_TEMPLATE, TemplateKind::TagName).unwrap();
+        validate_template(DEFAULT_PACKAGE_TAG_TEMPLATE, TemplateKind::TagName).unwrap();
+        assert!(validate_template("v{unknown}", TemplateKind::TagName).is_err());
+        assert!(validate_template("v{version", TemplateKind::TagName).is_err());
+        assert!(validate_template("release-{major}", TemplateKind::TagName).is_err());
+        assert!(validate_template("v {version}", TemplateKind::TagName).is_err());
+        assert_eq!(render_template("{{v}}{version}", &TemplateContext::new(&Version::new(1, 0, 0))).unwrap(), "{v}1.0.0");
+    }
+}
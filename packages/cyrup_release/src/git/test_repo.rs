@@ -0,0 +1,66 @@
+//! Scratch repositories for git tests.
+
+use crate::git::GitRepository;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Runs git in `dir` with a fixed identity and no signing, returning trimmed stdout
+pub(crate) fn git(dir: &Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Release Bot", "-c", "user.email=release@example.com"])
+        .args(["-c", "init.defaultBranch=main", "-c", "commit.gpgSign=false", "-c", "tag.gpgSign=false"])
+        .args(args)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .output()
+        .expect("git runs");
+    assert!(output.status.success(), "git {:?} failed: {}", args, String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// A repository on `main` with one commit, and an empty bare repository added as `origin`
+pub(crate) struct TestRepo {
+    _dir: TempDir,
+    /// Working tree of the repository
+    pub work: PathBuf,
+    /// The bare repository behind `origin`
+    pub remote: PathBuf,
+}
+
+impl TestRepo {
+    pub(crate) fn new() -> Self {
+        let dir = tempfile::tempdir().expect("temporary directory");
+        let work = dir.path().join("work");
+        let remote = dir.path().join("origin.git");
+        git(dir.path(), &["init", "--quiet", "--bare", "origin.git"]);
+        git(dir.path(), &["init", "--quiet", "work"]);
+        // The identity also has to reach commits made through GitRepository
+        git(&work, &["config", "user.name", "Release Bot"]);
+        git(&work, &["config", "user.email", "release@example.com"]);
+        git(&work, &["config", "commit.gpgSign", "false"]);
+        git(&work, &["config", "tag.gpgSign", "false"]);
+        git(&work, &["remote", "add", "origin", &remote.display().to_string()]);
+        let repo = Self { _dir: dir, work, remote };
+        repo.commit("README.md", "# test\n", "Initial commit");
+        repo
+    }
+
+    /// Opens the working repository
+    pub(crate) fn repository(&self) -> GitRepository {
+        GitRepository::open(&self.work).expect("test repository opens")
+    }
+
+    /// Writes `contents` to `file` and commits it, returning the commit hash
+    pub(crate) fn commit(&self, file: &str, contents: &str, message: &str) -> String {
+        std::fs::write(self.work.join(file), contents).expect("write test file");
+        git(&self.work, &["add", file]);
+        git(&self.work, &["commit", "--quiet", "-m", message]);
+        git(&self.work, &["rev-parse", "HEAD"])
+    }
+
+    /// Runs git in the working repository
+    pub(crate) fn git(&self, args: &[&str]) -> String {
+        git(&self.work, args)
+    }
+}
@@ -35,6 +35,9 @@ pub mod git;
 pub mod publish;
 pub mod state;
 pub mod cli;
+pub mod config;
+pub mod notify;
+pub mod metrics;
 pub mod error;
 
 // Re-export main types for public API
@@ -46,6 +49,7 @@ pub use publish::Publisher;
 pub use workspace::PublishOrder;
 pub use state::{ReleaseState, StateManager};
 pub use cli::{Command, Args};
+pub use config::ProjectConfig;
 
 /// Main release orchestrator that coordinates all operations
 pub struct ReleaseManager {
@@ -0,0 +1,287 @@
+//! Delivery of release metrics to a pushgateway or OTLP collector.
+
+use crate::config::MetricsSection;
+use crate::error::{MetricsError, Result};
+use crate::metrics::ReleaseMetrics;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Environment variable holding a bearer token sent with metric exports
+pub const METRICS_TOKEN_ENV: &str = "CYRUP_RELEASE_METRICS_TOKEN";
+
+/// Where release metrics are sent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExporterKind {
+    /// Metrics are not exported
+    #[default]
+    None,
+    /// Prometheus pushgateway (text exposition format)
+    Prometheus,
+    /// OpenTelemetry collector (OTLP/HTTP with JSON encoding)
+    Otlp,
+}
+
+/// Pushes release metrics to the configured exporter
+#[derive(Debug, Clone)]
+pub struct MetricsExporter {
+    /// HTTP client used for export requests
+    client: reqwest::Client,
+    /// Exporter settings
+    section: MetricsSection,
+    /// Resolved export URL
+    url: reqwest::Url,
+    /// Extra headers (`OTEL_EXPORTER_OTLP_HEADERS` for OTLP)
+    headers: Vec<(String, String)>,
+    /// Bearer token from `CYRUP_RELEASE_METRICS_TOKEN`
+    token: Option<String>,
+}
+
+impl MetricsExporter {
+    /// Create an exporter for the `[metrics]` settings, or `None` if export is disabled
+    pub fn new(section: &MetricsSection) -> Result<Option<Self>> {
+        let (url, headers) = match section.exporter {
+            ExporterKind::None => return Ok(None),
+            ExporterKind::Prometheus => (pushgateway_url(section)?, Vec::new()),
+            ExporterKind::Otlp => (otlp_url(section)?, otlp_headers()),
+        };
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!("cyrup_release/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| MetricsError::ClientFailed {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Some(Self {
+            client,
+            section: section.clone(),
+            url,
+            headers,
+            token: std::env::var(METRICS_TOKEN_ENV).ok().filter(|token| !token.is_empty()),
+        }))
+    }
+
+    /// URL metrics are sent to
+    pub fn url(&self) -> &reqwest::Url {
+        &self.url
+    }
+
+    /// Send the metrics of a release
+    #[tracing::instrument(skip_all, fields(exporter = ?self.section.exporter, outcome = metrics.outcome.as_str()))]
+    pub async fn export(&self, metrics: &ReleaseMetrics) -> std::result::Result<(), MetricsError> {
+        let request = match self.section.exporter {
+            // PUT replaces the whole group, so series of packages from earlier releases are dropped
+            ExporterKind::Prometheus => self.client.put(self.url.clone())
+                .header(reqwest::header::CONTENT_TYPE, "text/plain; version=0.0.4")
+                .body(metrics.to_prometheus()),
+            ExporterKind::Otlp | ExporterKind::None => self.client.post(self.url.clone())
+                .json(&metrics.to_otlp(&self.section.job, &self.section.labels)),
+        };
+
+        let mut request = self.headers.iter()
+            .fold(request, |request, (name, value)| request.header(name, value));
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await.map_err(|e| MetricsError::ExportFailed {
+            url: self.url.to_string(),
+            reason: e.to_string(),
+        })?;
+
+        if !response.status().is_success() {
+            return Err(MetricsError::ExportFailed {
+                url: self.url.to_string(),
+                reason: format!("collector returned {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// `<endpoint>/metrics/job/<job>/<label>/<value>...` for the configured grouping labels
+fn pushgateway_url(section: &MetricsSection) -> Result<reqwest::Url> {
+    let endpoint = section.endpoint.as_deref().ok_or_else(|| MetricsError::MissingEndpoint {
+        exporter: "prometheus".to_string(),
+    })?;
+    let mut url = parse_url(endpoint)?;
+
+    {
+        let mut segments = url.path_segments_mut().map_err(|_| MetricsError::InvalidEndpoint {
+            url: endpoint.to_string(),
+            reason: "URL cannot have a path".to_string(),
+        })?;
+        segments.pop_if_empty().extend(["metrics"]);
+        let grouping = std::iter::once(("job", section.job.as_str()))
+            .chain(section.labels.iter().map(|(name, value)| (name.as_str(), value.as_str())));
+        for (name, value) in grouping {
+            // The pushgateway needs values containing '/' in base64, and empty ones as a lone '='
+            if value.is_empty() {
+                segments.extend([format!("{}@base64", name), "=".to_string()]);
+            } else if value.contains('/') {
+                let encoded = base64::engine::general_purpose::URL_SAFE.encode(value);
+                segments.extend([format!("{}@base64", name), encoded]);
+            } else {
+                segments.extend([name, value]);
+            }
+        }
+    }
+
+    Ok(url)
+}
+
+/// Configured endpoint, else the standard OpenTelemetry environment variables
+fn otlp_url(section: &MetricsSection) -> Result<reqwest::Url> {
+    if let Ok(url) = std::env::var("OTEL_EXPORTER_OTLP_METRICS_ENDPOINT")
+        && section.endpoint.is_none()
+        && !url.is_empty()
+    {
+        return parse_url(&url);
+    }
+
+    let endpoint = section.endpoint.clone()
+        .or_else(|| std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok().filter(|url| !url.is_empty()))
+        .ok_or_else(|| MetricsError::MissingEndpoint {
+            exporter: "otlp".to_string(),
+        })?;
+
+    if endpoint.trim_end_matches('/').ends_with("/v1/metrics") {
+        return parse_url(&endpoint);
+    }
+    parse_url(&format!("{}/v1/metrics", endpoint.trim_end_matches('/')))
+}
+
+/// Headers from `OTEL_EXPORTER_OTLP_HEADERS` (`key=value,key=value`)
+fn otlp_headers() -> Vec<(String, String)> {
+    std::env::var("OTEL_EXPORTER_OTLP_HEADERS")
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .filter(|(name, _)| !name.is_empty())
+        .collect()
+}
+
+/// Parse an endpoint URL
+fn parse_url(url: &str) -> Result<reqwest::Url> {
+    reqwest::Url::parse(url).map_err(|e| MetricsError::InvalidEndpoint {
+        url: url.to_string(),
+        reason: e.to_string(),
+    }.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn section(exporter: ExporterKind, endpoint: &str) -> MetricsSection {
+        MetricsSection {
+            exporter,
+            endpoint: Some(endpoint.to_string()),
+            ..MetricsSection::default()
+        }
+    }
+
+    /// Answer one request with `status` and return the request as received
+    async fn collector(status: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let request = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 8192];
+            loop {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head.lines()
+                        .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length: ").map(str::to_string))
+                        .map_or(0, |length| length.parse().unwrap());
+                    if body.len() >= length {
+                        break;
+                    }
+                }
+            }
+            let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status);
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, request)
+    }
+
+    fn metrics() -> ReleaseMetrics {
+        ReleaseMetrics {
+            release_id: "release-1.0.0".to_string(),
+            version: "1.0.0".to_string(),
+            outcome: crate::metrics::ReleaseOutcome::Succeeded,
+            timestamp: chrono::Utc::now(),
+            metrics: vec![crate::metrics::Metric {
+                name: "cyrup_release_publish_retries",
+                help: "Publish retries needed across all packages",
+                unit: "1",
+                points: vec![crate::metrics::DataPoint { labels: Vec::new(), value: 0.0 }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_disabled_exporter() {
+        assert!(MetricsExporter::new(&MetricsSection::default()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_pushgateway_grouping_url() {
+        let mut grouped = section(ExporterKind::Prometheus, "http://pushgateway:9091/");
+        grouped.labels.insert("repository".to_string(), "acme/sugars".to_string());
+        grouped.labels.insert("branch".to_string(), String::new());
+        grouped.labels.insert("env".to_string(), "ci".to_string());
+        assert_eq!(
+            pushgateway_url(&grouped).unwrap().as_str(),
+            "http://pushgateway:9091/metrics/job/cyrup_release/branch@base64/=/env/ci/repository@base64/YWNtZS9zdWdhcnM="
+        );
+
+        let error = pushgateway_url(&MetricsSection { exporter: ExporterKind::Prometheus, ..MetricsSection::default() }).unwrap_err();
+        assert!(error.to_string().contains("prometheus"), "{}", error);
+        assert!(pushgateway_url(&section(ExporterKind::Prometheus, "mailto:ops@example.com")).is_err());
+        assert!(pushgateway_url(&section(ExporterKind::Prometheus, "not a url")).is_err());
+    }
+
+    #[test]
+    fn test_otlp_url_appends_metrics_path_once() {
+        let url = |endpoint| otlp_url(&section(ExporterKind::Otlp, endpoint)).unwrap().to_string();
+        assert_eq!(url("http://collector:4318"), "http://collector:4318/v1/metrics");
+        assert_eq!(url("http://collector:4318/"), "http://collector:4318/v1/metrics");
+        assert_eq!(url("http://collector:4318/v1/metrics"), "http://collector:4318/v1/metrics");
+    }
+
+    #[tokio::test]
+    async fn test_prometheus_export_replaces_the_group() {
+        let (url, request) = collector("200 OK").await;
+        let exporter = MetricsExporter::new(&section(ExporterKind::Prometheus, &url)).unwrap().unwrap();
+        exporter.export(&metrics()).await.unwrap();
+
+        let request = request.await.unwrap();
+        assert!(request.starts_with("PUT /metrics/job/cyrup_release HTTP/1.1\r\n"), "{}", request);
+        assert!(request.contains("content-type: text/plain; version=0.0.4\r\n"), "{}", request);
+        assert!(request.ends_with("cyrup_release_publish_retries 0\n"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_otlp_export_failure_names_the_status() {
+        let (url, request) = collector("503 Service Unavailable").await;
+        let exporter = MetricsExporter::new(&section(ExporterKind::Otlp, &url)).unwrap().unwrap();
+        assert_eq!(exporter.url().path(), "/v1/metrics");
+
+        let error = exporter.export(&metrics()).await.unwrap_err().to_string();
+        assert!(error.contains("503"), "{}", error);
+        let request = request.await.unwrap();
+        assert!(request.starts_with("POST /v1/metrics HTTP/1.1\r\n"), "{}", request);
+        assert!(request.contains("\"resourceMetrics\""), "{}", request);
+    }
+}
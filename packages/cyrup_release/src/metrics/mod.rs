@@ -0,0 +1,13 @@
+//! Release metrics export.
+//!
+//! When a release finishes, fails, or is rolled back, metrics built from the
+//! persisted `ReleaseState` (phase durations, publish retries, package counts
+//! and the failure category) are pushed to a Prometheus pushgateway or an
+//! OTLP/HTTP collector configured in `release.toml`. Export failures are
+//! returned to the caller to report as warnings; they never fail a release.
+
+mod exporter;
+mod report;
+
+pub use exporter::{ExporterKind, MetricsExporter, METRICS_TOKEN_ENV};
+pub use report::{DataPoint, Metric, ReleaseMetrics, ReleaseOutcome};
@@ -0,0 +1,401 @@
+//! Metrics built from release state.
+
+use crate::error::ReleaseError;
+use crate::state::{ReleasePhase, ReleaseState, ReleaseTimeline};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+/// How a release ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseOutcome {
+    /// Every phase completed
+    Succeeded,
+    /// A phase failed
+    Failed,
+    /// The release was rolled back
+    RolledBack,
+}
+
+/// Metrics describing one release
+#[derive(Debug, Clone)]
+pub struct ReleaseMetrics {
+    /// Release identifier
+    pub release_id: String,
+    /// Version being released
+    pub version: String,
+    /// How the release ended
+    pub outcome: ReleaseOutcome,
+    /// When the metrics were collected
+    pub timestamp: DateTime<Utc>,
+    /// Collected metrics
+    pub metrics: Vec<Metric>,
+}
+
+/// A named gauge with one or more data points
+#[derive(Debug, Clone)]
+pub struct Metric {
+    /// Metric name
+    pub name: &'static str,
+    /// Description of the metric
+    pub help: &'static str,
+    /// Unit (`s` for seconds, `1` for counts)
+    pub unit: &'static str,
+    /// Values, one per label set
+    pub points: Vec<DataPoint>,
+}
+
+/// A single value of a metric
+#[derive(Debug, Clone)]
+pub struct DataPoint {
+    /// Label names and values
+    pub labels: Vec<(&'static str, String)>,
+    /// Value
+    pub value: f64,
+}
+
+impl ReleaseOutcome {
+    /// Outcome name as used in metric labels
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseOutcome::Succeeded => "succeeded",
+            ReleaseOutcome::Failed => "failed",
+            ReleaseOutcome::RolledBack => "rolled-back",
+        }
+    }
+}
+
+impl Metric {
+    /// Create a metric without data points
+    fn new(name: &'static str, unit: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            unit,
+            points: Vec::new(),
+        }
+    }
+
+    /// Add a data point
+    fn point(mut self, labels: Vec<(&'static str, String)>, value: f64) -> Self {
+        self.points.push(DataPoint { labels, value });
+        self
+    }
+}
+
+impl ReleaseMetrics {
+    /// Collect the metrics of a release from its state and, for failed releases, the error
+    pub fn from_state(state: &ReleaseState, outcome: ReleaseOutcome, failure: Option<&ReleaseError>) -> Self {
+        let timestamp = Utc::now();
+        let seconds = |millis: i64| millis as f64 / 1000.0;
+
+        let mut metrics = vec![
+            Metric::new("cyrup_release_outcome", "1", "Outcome of the last release")
+                .point(vec![
+                    ("outcome", outcome.as_str().to_string()),
+                    ("version", state.target_version.to_string()),
+                ], 1.0),
+            Metric::new("cyrup_release_timestamp_seconds", "s", "When the last release finished, as a Unix timestamp")
+                .point(Vec::new(), timestamp.timestamp() as f64),
+            Metric::new("cyrup_release_duration_seconds", "s", "Wall-clock duration of the last release")
+                .point(Vec::new(), seconds((timestamp - state.started_at).num_milliseconds())),
+        ];
+
+        // Phases may be entered more than once (e.g. after a resume)
+        let mut phase_durations: BTreeMap<&'static str, i64> = BTreeMap::new();
+        for span in ReleaseTimeline::from_state(state).phases {
+            *phase_durations.entry(phase_label(span.phase)).or_default() += span.duration_ms;
+        }
+        let mut phases = Metric::new("cyrup_release_phase_duration_seconds", "s", "Time spent in each release phase");
+        for (phase, millis) in phase_durations {
+            phases = phases.point(vec![("phase", phase.to_string())], seconds(millis));
+        }
+        metrics.push(phases);
+
+        let updated = state.version_state.as_ref()
+            .and_then(|version_state| version_state.update_result.as_ref())
+            .map_or(0, |update| update.packages_updated);
+        let (published, failed) = match &state.publish_state {
+            Some(publish_state) => {
+                let mut published: Vec<_> = publish_state.published_packages.values().collect();
+                published.sort_by(|a, b| a.package_name.cmp(&b.package_name));
+                (published, publish_state.failed_packages.len())
+            }
+            None => (Vec::new(), 0),
+        };
+        metrics.push(
+            Metric::new("cyrup_release_packages", "1", "Packages updated, published, and failed by the last release")
+                .point(vec![("status", "updated".to_string())], updated as f64)
+                .point(vec![("status", "published".to_string())], published.len() as f64)
+                .point(vec![("status", "failed".to_string())], failed as f64),
+        );
+
+        let retries: usize = published.iter().map(|package| package.retry_attempts).sum();
+        metrics.push(
+            Metric::new("cyrup_release_publish_retries", "1", "Publish retries needed across all packages")
+                .point(Vec::new(), retries as f64),
+        );
+
+        let mut durations = Metric::new("cyrup_release_package_publish_duration_seconds", "s", "Time spent publishing each package");
+        let mut package_retries = Metric::new("cyrup_release_package_publish_retries", "1", "Publish retries needed for each package");
+        for package in &published {
+            let labels = vec![("package", package.package_name.clone())];
+            durations = durations.point(labels.clone(), seconds(package.duration_ms as i64));
+            package_retries = package_retries.point(labels, package.retry_attempts as f64);
+        }
+        metrics.extend([durations, package_retries].into_iter().filter(|metric| !metric.points.is_empty()));
+
+        if let Some(error) = failure {
+            metrics.push(
+                Metric::new("cyrup_release_failure", "1", "Category of the error that failed the last release")
+                    .point(vec![
+                        ("category", error.category().to_string()),
+                        ("phase", phase_label(state.current_phase).to_string()),
+                    ], 1.0),
+            );
+        }
+
+        let mut errors_by_phase: BTreeMap<&'static str, usize> = BTreeMap::new();
+        for error in &state.errors {
+            *errors_by_phase.entry(phase_label(error.phase)).or_default() += 1;
+        }
+        if !errors_by_phase.is_empty() {
+            let mut errors = Metric::new("cyrup_release_errors", "1", "Errors recorded in each release phase");
+            for (phase, count) in errors_by_phase {
+                errors = errors.point(vec![("phase", phase.to_string())], count as f64);
+            }
+            metrics.push(errors);
+        }
+
+        Self {
+            release_id: state.release_id.clone(),
+            version: state.target_version.to_string(),
+            outcome,
+            timestamp,
+            metrics,
+        }
+    }
+
+    /// Render the metrics in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut output = String::new();
+        for metric in &self.metrics {
+            output.push_str(&format!("# HELP {} {}\n", metric.name, metric.help));
+            output.push_str(&format!("# TYPE {} gauge\n", metric.name));
+            for point in &metric.points {
+                output.push_str(metric.name);
+                if !point.labels.is_empty() {
+                    let labels: Vec<String> = point.labels.iter()
+                        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+                        .collect();
+                    output.push_str(&format!("{{{}}}", labels.join(",")));
+                }
+                output.push_str(&format!(" {}\n", point.value));
+            }
+        }
+        output
+    }
+
+    /// Render the metrics as an OTLP/HTTP JSON export request
+    pub fn to_otlp(&self, service_name: &str, resource_labels: &BTreeMap<String, String>) -> serde_json::Value {
+        let attribute = |key: &str, value: &str| serde_json::json!({
+            "key": key,
+            "value": { "stringValue": value },
+        });
+
+        let mut resource = vec![
+            attribute("service.name", service_name),
+            attribute("release.id", &self.release_id),
+        ];
+        resource.extend(resource_labels.iter().map(|(key, value)| attribute(key, value)));
+
+        let time = self.timestamp.timestamp_nanos_opt().unwrap_or_default().to_string();
+        let metrics: Vec<serde_json::Value> = self.metrics.iter()
+            .map(|metric| {
+                let points: Vec<serde_json::Value> = metric.points.iter()
+                    .map(|point| serde_json::json!({
+                        "timeUnixNano": time,
+                        "asDouble": point.value,
+                        "attributes": point.labels.iter()
+                            .map(|(name, value)| attribute(name, value))
+                            .collect::<Vec<_>>(),
+                    }))
+                    .collect();
+                serde_json::json!({
+                    "name": metric.name,
+                    "description": metric.help,
+                    "unit": metric.unit,
+                    "gauge": { "dataPoints": points },
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "resourceMetrics": [{
+                "resource": { "attributes": resource },
+                "scopeMetrics": [{
+                    "scope": { "name": "cyrup_release", "version": env!("CARGO_PKG_VERSION") },
+                    "metrics": metrics,
+                }],
+            }],
+        })
+    }
+}
+
+/// Phase name as used in metric labels
+fn phase_label(phase: ReleasePhase) -> &'static str {
+    match phase {
+        ReleasePhase::Validation => "validation",
+        ReleasePhase::VersionUpdate => "version_update",
+        ReleasePhase::GitOperations => "git_operations",
+        ReleasePhase::Publishing => "publishing",
+        ReleasePhase::Cleanup => "cleanup",
+        ReleasePhase::Completed => "completed",
+        ReleasePhase::Failed => "failed",
+        ReleasePhase::RollingBack => "rolling_back",
+        ReleasePhase::RolledBack => "rolled_back",
+    }
+}
+
+/// Escape a label value for the text exposition format
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PublishError;
+    use crate::state::{PublishPackageInfo, PublishState, ReleaseCheckpoint, ReleaseConfig, VersionState, VersionUpdateInfo};
+    use crate::version::VersionBump;
+    use chrono::{Duration, TimeZone};
+    use std::collections::HashMap;
+
+    /// A release that failed while publishing, 12 seconds after it started
+    fn failed_release() -> ReleaseState {
+        let mut state = ReleaseState::new(semver::Version::new(1, 2, 0), VersionBump::Minor, ReleaseConfig::default());
+        let started = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let at = |seconds| started + Duration::seconds(seconds);
+        state.started_at = started;
+        state.current_phase = ReleasePhase::Failed;
+        state.checkpoints = [
+            ("validated", ReleasePhase::Validation, 1),
+            ("bumped", ReleasePhase::VersionUpdate, 3),
+            ("published", ReleasePhase::Publishing, 10),
+        ]
+            .into_iter()
+            .map(|(name, phase, seconds)| ReleaseCheckpoint {
+                name: name.to_string(),
+                phase,
+                timestamp: at(seconds),
+                data: None,
+                rollback_capable: true,
+            })
+            .collect();
+        state.version_state = Some(VersionState {
+            previous_version: semver::Version::new(1, 1, 0),
+            new_version: semver::Version::new(1, 2, 0),
+            update_result: Some(VersionUpdateInfo { packages_updated: 3, dependencies_updated: 2, duration_ms: 0 }),
+            modified_files: Vec::new(),
+            backup_files: Vec::new(),
+        });
+        let published = |name: &str, duration_ms, retry_attempts| (name.to_string(), PublishPackageInfo {
+            package_name: name.to_string(),
+            version: semver::Version::new(1, 2, 0),
+            duration_ms,
+            retry_attempts,
+            warnings: Vec::new(),
+            published_at: at(9),
+        });
+        state.publish_state = Some(PublishState {
+            published_packages: HashMap::from([published("beta", 500, 0), published("alpha", 1500, 2)]),
+            failed_packages: HashMap::from([("gamma".to_string(), "rate limited".to_string())]),
+            current_tier: 1,
+            total_tiers: 2,
+            publishing_started_at: Some(at(3)),
+        });
+        for phase in [ReleasePhase::Publishing, ReleasePhase::Validation, ReleasePhase::Publishing] {
+            state.add_error("boom".to_string(), phase, true, None);
+        }
+        state.updated_at = at(12);
+        state
+    }
+
+    #[test]
+    fn test_metrics_of_a_failed_release() {
+        let failure = crate::error::ReleaseError::Publish(PublishError::AuthenticationError);
+        let metrics = ReleaseMetrics::from_state(&failed_release(), ReleaseOutcome::Failed, Some(&failure));
+        let prometheus = metrics.to_prometheus();
+
+        for line in [
+            "# HELP cyrup_release_outcome Outcome of the last release",
+            "# TYPE cyrup_release_outcome gauge",
+            "cyrup_release_outcome{outcome=\"failed\",version=\"1.2.0\"} 1",
+            "cyrup_release_phase_duration_seconds{phase=\"failed\"} 2",
+            "cyrup_release_phase_duration_seconds{phase=\"publishing\"} 7",
+            "cyrup_release_phase_duration_seconds{phase=\"validation\"} 1",
+            "cyrup_release_phase_duration_seconds{phase=\"version_update\"} 2",
+            "cyrup_release_packages{status=\"updated\"} 3",
+            "cyrup_release_packages{status=\"published\"} 2",
+            "cyrup_release_packages{status=\"failed\"} 1",
+            "cyrup_release_publish_retries 2",
+            "cyrup_release_package_publish_duration_seconds{package=\"alpha\"} 1.5",
+            "cyrup_release_package_publish_retries{package=\"beta\"} 0",
+            "cyrup_release_failure{category=\"authentication\",phase=\"failed\"} 1",
+            "cyrup_release_errors{phase=\"publishing\"} 2",
+            "cyrup_release_errors{phase=\"validation\"} 1",
+        ] {
+            assert!(prometheus.lines().any(|output| output == line), "missing {:?} in\n{}", line, prometheus);
+        }
+        // Packages are reported in name order
+        assert!(prometheus.find("package=\"alpha\"").unwrap() < prometheus.find("package=\"beta\"").unwrap());
+    }
+
+    #[test]
+    fn test_metrics_of_a_release_without_publishing() {
+        let mut state = failed_release();
+        state.publish_state = None;
+        state.errors.clear();
+        state.current_phase = ReleasePhase::RolledBack;
+        let metrics = ReleaseMetrics::from_state(&state, ReleaseOutcome::RolledBack, None);
+
+        let names: Vec<_> = metrics.metrics.iter().map(|metric| metric.name).collect();
+        assert_eq!(names, vec![
+            "cyrup_release_outcome",
+            "cyrup_release_timestamp_seconds",
+            "cyrup_release_duration_seconds",
+            "cyrup_release_phase_duration_seconds",
+            "cyrup_release_packages",
+            "cyrup_release_publish_retries",
+        ]);
+        assert!(metrics.to_prometheus().contains("outcome=\"rolled-back\""));
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn test_otlp_request() {
+        let metrics = ReleaseMetrics::from_state(&failed_release(), ReleaseOutcome::Failed, None);
+        let labels = BTreeMap::from([("repository".to_string(), "acme/sugars".to_string())]);
+        let request = metrics.to_otlp("releases", &labels);
+
+        let resource = &request["resourceMetrics"][0];
+        assert_eq!(resource["resource"]["attributes"], serde_json::json!([
+            { "key": "service.name", "value": { "stringValue": "releases" } },
+            { "key": "release.id", "value": { "stringValue": metrics.release_id } },
+            { "key": "repository", "value": { "stringValue": "acme/sugars" } },
+        ]));
+
+        let exported = &resource["scopeMetrics"][0]["metrics"];
+        assert_eq!(exported.as_array().unwrap().len(), metrics.metrics.len());
+        let outcome = &exported[0];
+        assert_eq!(outcome["name"], "cyrup_release_outcome");
+        assert_eq!(outcome["unit"], "1");
+        let point = &outcome["gauge"]["dataPoints"][0];
+        assert_eq!(point["asDouble"], 1.0);
+        assert_eq!(point["timeUnixNano"], metrics.timestamp.timestamp_nanos_opt().unwrap().to_string());
+        assert_eq!(point["attributes"][0], serde_json::json!({ "key": "outcome", "value": { "stringValue": "failed" } }));
+    }
+}
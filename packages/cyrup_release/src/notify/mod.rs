@@ -0,0 +1,14 @@
+//! Release event notifications.
+//!
+//! Release lifecycle events (started, succeeded, failed, rolled back) and
+//! docs.rs build failures are posted to the webhooks configured in
+//! `release.toml`; this is the only place the release sends notifications. Payloads are built
+//! from the persisted `ReleaseState`, either as generic JSON or in Slack's
+//! incoming webhook format. Delivery failures are returned to the caller to
+//! report as warnings; they never fail a release.
+
+mod notifier;
+mod payload;
+
+pub use notifier::{Notifier, WebhookConfig, WebhookFormat};
+pub use payload::{NotifyEvent, ReleasePayload, PublishedPackage, FailedPackage};
@@ -0,0 +1,116 @@
+//! Webhook delivery of release notifications.
+
+use crate::error::{NotifyError, Result};
+use crate::notify::{NotifyEvent, ReleasePayload};
+use crate::state::ReleaseState;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Payload format expected by a webhook
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WebhookFormat {
+    /// Generic JSON payload (`ReleasePayload`)
+    #[default]
+    Json,
+    /// Slack incoming webhook message
+    Slack,
+}
+
+/// A configured notification webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct WebhookConfig {
+    /// Webhook URL
+    pub url: String,
+    /// Payload format
+    #[serde(default)]
+    pub format: WebhookFormat,
+    /// Events to send (all events when empty)
+    #[serde(default)]
+    pub events: Vec<NotifyEvent>,
+}
+
+/// Sends release events to configured webhooks
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    /// HTTP client used for webhook requests
+    client: reqwest::Client,
+    /// Webhooks to notify
+    webhooks: Vec<WebhookConfig>,
+}
+
+impl WebhookConfig {
+    /// Check if this webhook subscribes to an event
+    pub fn wants(&self, event: NotifyEvent) -> bool {
+        self.events.is_empty() || self.events.contains(&event)
+    }
+}
+
+impl Notifier {
+    /// Create a notifier for the given webhooks
+    pub fn new(webhooks: Vec<WebhookConfig>) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!("cyrup_release/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| NotifyError::ClientFailed {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self { client, webhooks })
+    }
+
+    /// Check if any webhooks are configured
+    pub fn is_empty(&self) -> bool {
+        self.webhooks.is_empty()
+    }
+
+    /// Send an event to every subscribed webhook.
+    ///
+    /// Returns the delivery failures; callers should report them as warnings.
+    #[tracing::instrument(skip_all, fields(event = event.as_str()))]
+    pub async fn notify(
+        &self,
+        event: NotifyEvent,
+        state: &ReleaseState,
+        error: Option<&str>,
+    ) -> Vec<NotifyError> {
+        let payload = ReleasePayload::from_state(event, state, error);
+        let mut failures = Vec::new();
+
+        for webhook in self.webhooks.iter().filter(|webhook| webhook.wants(event)) {
+            if let Err(e) = self.deliver(webhook, &payload).await {
+                failures.push(e);
+            }
+        }
+
+        failures
+    }
+
+    /// Post a payload to a single webhook
+    async fn deliver(&self, webhook: &WebhookConfig, payload: &ReleasePayload) -> std::result::Result<(), NotifyError> {
+        let body = match webhook.format {
+            WebhookFormat::Json => serde_json::to_value(payload).map_err(|e| NotifyError::DeliveryFailed {
+                url: webhook.url.clone(),
+                reason: format!("Failed to serialize payload: {}", e),
+            })?,
+            WebhookFormat::Slack => payload.to_slack(),
+        };
+
+        let response = self.client.post(&webhook.url).json(&body).send().await
+            .map_err(|e| NotifyError::DeliveryFailed {
+                url: webhook.url.clone(),
+                reason: e.to_string(),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(NotifyError::DeliveryFailed {
+                url: webhook.url.clone(),
+                reason: format!("webhook returned {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+//! Notification payloads built from release state.
+
+use crate::state::{ReleasePhase, ReleaseState};
+use serde::{Deserialize, Serialize};
+
+/// Release lifecycle event that triggers a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotifyEvent {
+    /// Release started (versions about to be updated)
+    Started,
+    /// Release completed successfully
+    Succeeded,
+    /// Release failed
+    Failed,
+    /// Release was rolled back
+    RolledBack,
+    /// docs.rs failed to build (or did not finish building) published crates
+    DocsFailed,
+}
+
+/// Generic JSON notification payload
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleasePayload {
+    /// Event being reported
+    pub event: NotifyEvent,
+    /// Unique ID of the release operation
+    pub release_id: String,
+    /// Version being released
+    pub version: String,
+    /// Type of version bump
+    pub bump: String,
+    /// Phase the release was in when the event occurred
+    pub phase: ReleasePhase,
+    /// When the release started
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// When the event occurred
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Release commit hash (if created)
+    pub commit: Option<String>,
+    /// Tags created by the release
+    pub tags: Vec<String>,
+    /// Packages published so far
+    pub published: Vec<PublishedPackage>,
+    /// Packages that failed to publish
+    pub failed: Vec<FailedPackage>,
+    /// Error message for failed releases
+    pub error: Option<String>,
+}
+
+/// A package published by the release
+#[derive(Debug, Clone, Serialize)]
+pub struct PublishedPackage {
+    /// Package name
+    pub name: String,
+    /// Published version
+    pub version: String,
+}
+
+/// A package that failed to publish
+#[derive(Debug, Clone, Serialize)]
+pub struct FailedPackage {
+    /// Package name
+    pub name: String,
+    /// Publish error
+    pub error: String,
+}
+
+impl NotifyEvent {
+    /// Event name as used in configuration
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotifyEvent::Started => "started",
+            NotifyEvent::Succeeded => "succeeded",
+            NotifyEvent::Failed => "failed",
+            NotifyEvent::RolledBack => "rolled-back",
+            NotifyEvent::DocsFailed => "docs-failed",
+        }
+    }
+}
+
+impl ReleasePayload {
+    /// Build a payload for an event from the current release state
+    pub fn from_state(event: NotifyEvent, state: &ReleaseState, error: Option<&str>) -> Self {
+        let git_state = state.git_state.as_ref();
+
+        let (mut published, mut failed) = match &state.publish_state {
+            Some(publish_state) => (
+                publish_state.published_packages.values()
+                    .map(|info| PublishedPackage {
+                        name: info.package_name.clone(),
+                        version: info.version.to_string(),
+                    })
+                    .collect::<Vec<_>>(),
+                publish_state.failed_packages.iter()
+                    .map(|(name, error)| FailedPackage {
+                        name: name.clone(),
+                        error: error.clone(),
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            None => (Vec::new(), Vec::new()),
+        };
+        published.sort_by(|a, b| a.name.cmp(&b.name));
+        failed.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            event,
+            release_id: state.release_id.clone(),
+            version: state.target_version.to_string(),
+            bump: state.version_bump.to_string(),
+            phase: state.current_phase,
+            started_at: state.started_at,
+            timestamp: chrono::Utc::now(),
+            commit: git_state
+                .and_then(|git| git.release_commit.as_ref())
+                .map(|commit| commit.hash.clone()),
+            tags: git_state
+                .map(|git| git.release_tags.iter().map(|tag| tag.name.clone()).collect())
+                .unwrap_or_default(),
+            published,
+            failed,
+            error: error.map(str::to_string),
+        }
+    }
+
+    /// One-line human readable summary of the event
+    pub fn summary(&self) -> String {
+        match self.event {
+            NotifyEvent::Started => format!("🚀 Release v{} started ({} bump)", self.version, self.bump),
+            NotifyEvent::Succeeded => format!(
+                "🎉 Release v{} succeeded: {} packages published",
+                self.version,
+                self.published.len()
+            ),
+            NotifyEvent::Failed => format!(
+                "❌ Release v{} failed during {:?}: {}",
+                self.version,
+                self.phase,
+                self.error.as_deref().unwrap_or("unknown error")
+            ),
+            NotifyEvent::RolledBack => format!("⏪ Release v{} rolled back", self.version),
+            NotifyEvent::DocsFailed => format!(
+                "📚 docs.rs builds incomplete for release v{}: {}",
+                self.version,
+                self.error.as_deref().unwrap_or("unknown error")
+            ),
+        }
+    }
+
+    /// Render the payload in Slack's incoming webhook format
+    pub fn to_slack(&self) -> serde_json::Value {
+        let color = match self.event {
+            NotifyEvent::Started => "#439FE0",
+            NotifyEvent::Succeeded => "good",
+            NotifyEvent::Failed => "danger",
+            NotifyEvent::RolledBack | NotifyEvent::DocsFailed => "warning",
+        };
+
+        let mut fields = vec![
+            serde_json::json!({ "title": "Version", "value": self.version, "short": true }),
+            serde_json::json!({ "title": "Phase", "value": format!("{:?}", self.phase), "short": true }),
+        ];
+        if !self.tags.is_empty() {
+            fields.push(serde_json::json!({ "title": "Tags", "value": self.tags.join(", "), "short": false }));
+        }
+        if !self.published.is_empty() {
+            let published = self.published.iter()
+                .map(|package| format!("{} {}", package.name, package.version))
+                .collect::<Vec<_>>()
+                .join(", ");
+            fields.push(serde_json::json!({ "title": "Published", "value": published, "short": false }));
+        }
+        if !self.failed.is_empty() {
+            let failed = self.failed.iter()
+                .map(|package| package.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ");
+            fields.push(serde_json::json!({ "title": "Failed", "value": failed, "short": false }));
+        }
+
+        serde_json::json!({
+            "text": self.summary(),
+            "attachments": [{
+                "color": color,
+                "fields": fields,
+                "footer": format!("cyrup_release • {}", self.release_id),
+                "ts": self.timestamp.timestamp(),
+            }],
+        })
+    }
+}
@@ -63,6 +63,8 @@ pub struct YankResult {
     pub duration: Duration,
     /// Whether the package was successfully yanked
     pub success: bool,
+    /// Whether this was an undo of a previous yank
+    pub undo: bool,
 }
 
 /// Configuration for publishing operations
@@ -78,6 +80,10 @@ pub struct PublishConfig {
     pub additional_args: Vec<String>,
     /// Token for authentication (if not using cargo login)
     pub token: Option<String>,
+    /// Maximum retry attempts, overriding the publisher's setting
+    pub max_retries: Option<usize>,
+    /// Timeout for each cargo invocation, overriding the publisher's setting
+    pub timeout: Option<Duration>,
 }
 
 impl Default for PublishConfig {
@@ -88,6 +94,8 @@ impl Default for PublishConfig {
             allow_dirty: false,
             additional_args: Vec::new(),
             token: None,
+            max_retries: None,
+            timeout: None,
         }
     }
 }
@@ -113,6 +121,11 @@ impl CargoPublisher {
     }
 
     /// Publish a package to crates.io
+    #[tracing::instrument(
+        name = "publish",
+        skip_all,
+        fields(package = %package_info.name, version = %package_info.version)
+    )]
     pub async fn publish_package(
         &self,
         package_info: &PackageInfo,
@@ -137,6 +150,7 @@ impl CargoPublisher {
         // Attempt publish with retry logic
         let _result = self.retry_with_backoff(
             || self.attempt_publish(package_info, config),
+            config.max_retries.unwrap_or(self.max_retries),
             &mut retry_attempts,
             &mut warnings,
         ).await?;
@@ -170,7 +184,7 @@ impl CargoPublisher {
         let mut cmd = self.build_publish_command(package_info, config);
         cmd.arg("--dry-run");
 
-        let output = timeout(self.operation_timeout, cmd.output()).await
+        let output = timeout(self.operation_timeout(config), cmd.output()).await
             .map_err(|_| PublishError::DryRunFailed {
                 package: package_name.clone(),
                 reason: "Dry run timed out".to_string(),
@@ -204,11 +218,34 @@ impl CargoPublisher {
     }
 
     /// Yank a published package version
+    #[tracing::instrument(name = "yank", skip_all, fields(package = %package_name, version = %version))]
     pub async fn yank_package(
         &self,
         package_name: &str,
         version: &Version,
         config: &PublishConfig,
+    ) -> Result<YankResult> {
+        self.run_yank(package_name, version, config, false).await
+    }
+
+    /// Undo a yank, making the version available for new dependents again
+    #[tracing::instrument(name = "unyank", skip_all, fields(package = %package_name, version = %version))]
+    pub async fn unyank_package(
+        &self,
+        package_name: &str,
+        version: &Version,
+        config: &PublishConfig,
+    ) -> Result<YankResult> {
+        self.run_yank(package_name, version, config, true).await
+    }
+
+    /// Run `cargo yank`, with `--undo` when reverting a yank
+    async fn run_yank(
+        &self,
+        package_name: &str,
+        version: &Version,
+        config: &PublishConfig,
+        undo: bool,
     ) -> Result<YankResult> {
         let start_time = std::time::Instant::now();
 
@@ -220,6 +257,10 @@ impl CargoPublisher {
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
 
+        if undo {
+            cmd.arg("--undo");
+        }
+
         // Add registry if specified
         if let Some(ref registry) = config.registry {
             cmd.arg("--registry").arg(registry);
@@ -230,7 +271,7 @@ impl CargoPublisher {
             cmd.arg("--token").arg(token);
         }
 
-        let output = timeout(self.operation_timeout, cmd.output()).await
+        let output = timeout(self.operation_timeout(config), cmd.output()).await
             .map_err(|_| PublishError::YankFailed {
                 package: package_name.to_string(),
                 version: version.to_string(),
@@ -259,6 +300,7 @@ impl CargoPublisher {
             version: version.clone(),
             duration,
             success,
+            undo,
         })
     }
 
@@ -270,7 +312,7 @@ impl CargoPublisher {
     ) -> Result<()> {
         let mut cmd = self.build_publish_command(package_info, config);
 
-        let output = timeout(self.operation_timeout, cmd.output()).await
+        let output = timeout(self.operation_timeout(config), cmd.output()).await
             .map_err(|_| PublishError::PublishFailed {
                 package: package_info.name.clone(),
                 reason: "Publish operation timed out".to_string(),
@@ -346,10 +388,16 @@ impl CargoPublisher {
         cmd
     }
 
+    /// Timeout for a cargo invocation
+    fn operation_timeout(&self, config: &PublishConfig) -> Duration {
+        config.timeout.unwrap_or(self.operation_timeout)
+    }
+
     /// Retry operation with exponential backoff
     async fn retry_with_backoff<F, Fut>(
         &self,
         mut operation: F,
+        max_retries: usize,
         retry_attempts: &mut usize,
         warnings: &mut Vec<String>,
     ) -> Result<()>
@@ -359,26 +407,35 @@ impl CargoPublisher {
     {
         let mut delay = self.base_retry_delay;
 
-        for attempt in 0..=self.max_retries {
+        for attempt in 0..=max_retries {
             *retry_attempts = attempt;
 
             match operation().await {
                 Ok(()) => return Ok(()),
-                Err(e) if attempt == self.max_retries => return Err(e),
+                Err(e) if attempt == max_retries => return Err(e),
                 Err(e) => {
                     // Check if this is a retryable error
                     if !self.is_retryable_error(&e) {
                         return Err(e);
                     }
 
+                    // Wait at least as long as the registry asks
+                    let wait = match &e {
+                        crate::error::ReleaseError::Publish(PublishError::RateLimitExceeded { retry_after_seconds }) => {
+                            delay.max(Duration::from_secs(*retry_after_seconds))
+                        }
+                        _ => delay,
+                    };
+
                     warnings.push(format!(
-                        "Attempt {} failed: {}. Retrying in {:.1}s...",
+                        "Attempt {}/{} failed: {}. Retrying in {:.1}s...",
                         attempt + 1,
+                        max_retries + 1,
                         e,
-                        delay.as_secs_f64()
+                        wait.as_secs_f64()
                     ));
 
-                    sleep(delay).await;
+                    sleep(wait).await;
                     delay = std::cmp::min(delay * 2, Duration::from_secs(300)); // Cap at 5 minutes
                 }
             }
@@ -553,16 +610,19 @@ impl PublishResult {
 impl YankResult {
     /// Format yank result for display
     pub fn format_result(&self) -> String {
+        let action = if self.undo { "unyank" } else { "yank" };
         if self.success {
             format!(
-                "✅ Successfully yanked {}@{} in {:.2}s",
+                "✅ Successfully {}ed {}@{} in {:.2}s",
+                action,
                 self.package_name,
                 self.version,
                 self.duration.as_secs_f64()
             )
         } else {
             format!(
-                "❌ Failed to yank {}@{}",
+                "❌ Failed to {} {}@{}",
+                action,
                 self.package_name,
                 self.version
             )
@@ -0,0 +1,227 @@
+//! Post-release verification of docs.rs documentation builds.
+//!
+//! docs.rs builds documentation for every crate published to crates.io, but
+//! a failed build is only visible on the crate's docs page. This module polls
+//! the docs.rs build status endpoint for freshly published versions so broken
+//! documentation builds are reported as part of the release.
+
+use crate::error::{Result, PublishError};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Base URL of docs.rs
+pub const DOCS_RS_URL: &str = "https://docs.rs";
+
+/// Client for the docs.rs build status API
+#[derive(Debug, Clone)]
+pub struct DocsRsClient {
+    /// HTTP client used for status requests
+    client: reqwest::Client,
+    /// Base URL of docs.rs (without trailing slash)
+    base_url: String,
+}
+
+/// Documentation build status of a crate version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DocsBuildStatus {
+    /// Documentation built successfully
+    Succeeded,
+    /// Documentation build failed
+    Failed,
+    /// Build has not finished (queued, running, or not yet known to docs.rs)
+    Pending,
+}
+
+/// Documentation build outcome for one published crate
+#[derive(Debug, Clone, Serialize)]
+pub struct DocsBuildReport {
+    /// Package name
+    pub package_name: String,
+    /// Published version
+    pub version: Version,
+    /// Last observed build status
+    pub status: DocsBuildStatus,
+    /// Last error encountered while querying docs.rs (if any)
+    pub last_error: Option<String>,
+}
+
+/// Result of verifying documentation builds for a release
+#[derive(Debug, Clone, Serialize)]
+pub struct DocsVerification {
+    /// Per-crate build reports, in the order they were requested
+    pub reports: Vec<DocsBuildReport>,
+    /// Time spent polling
+    #[serde(skip)]
+    pub duration: Duration,
+}
+
+/// Response body of the docs.rs status endpoint
+#[derive(Debug, Deserialize)]
+struct StatusResponse {
+    doc_status: bool,
+}
+
+impl DocsRsClient {
+    /// Create a client for docs.rs
+    pub fn new() -> Result<Self> {
+        Self::with_base_url(DOCS_RS_URL)
+    }
+
+    /// Create a client for a custom docs.rs instance
+    pub fn with_base_url(base_url: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("cyrup_release/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to create HTTP client: {}", e),
+            })?;
+
+        Ok(Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Query the current documentation build status of a crate version
+    pub async fn build_status(&self, package_name: &str, version: &Version) -> Result<DocsBuildStatus> {
+        let url = format!("{}/crate/{}/{}/status.json", self.base_url, package_name, version);
+
+        let response = self.client.get(&url).send().await
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to query docs.rs for '{}' {}: {}", package_name, version, e),
+            })?;
+
+        // docs.rs does not know about a release until its build has been queued and run
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(DocsBuildStatus::Pending);
+        }
+
+        if !response.status().is_success() {
+            return Err(PublishError::NetworkError {
+                reason: format!(
+                    "docs.rs returned {} for '{}' {}",
+                    response.status(),
+                    package_name,
+                    version
+                ),
+            }.into());
+        }
+
+        let status: StatusResponse = response.json().await
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Invalid docs.rs status for '{}' {}: {}", package_name, version, e),
+            })?;
+
+        Ok(if status.doc_status {
+            DocsBuildStatus::Succeeded
+        } else {
+            DocsBuildStatus::Failed
+        })
+    }
+
+    /// Poll until every crate's build has finished or the window elapses.
+    ///
+    /// Query errors are recorded on the report and retried on the next poll,
+    /// so a flaky connection does not abort verification.
+    #[tracing::instrument(skip_all, fields(crates = crates.len()))]
+    pub async fn wait_for_builds(
+        &self,
+        crates: &[(String, Version)],
+        window: Duration,
+        poll_interval: Duration,
+    ) -> DocsVerification {
+        let start_time = Instant::now();
+        let mut reports: Vec<DocsBuildReport> = crates.iter()
+            .map(|(package_name, version)| DocsBuildReport {
+                package_name: package_name.clone(),
+                version: version.clone(),
+                status: DocsBuildStatus::Pending,
+                last_error: None,
+            })
+            .collect();
+
+        loop {
+            for report in reports.iter_mut().filter(|report| report.is_pending()) {
+                match self.build_status(&report.package_name, &report.version).await {
+                    Ok(status) => {
+                        report.status = status;
+                        report.last_error = None;
+                    }
+                    Err(e) => report.last_error = Some(e.to_string()),
+                }
+            }
+
+            let all_finished = reports.iter().all(|report| !report.is_pending());
+            if all_finished || start_time.elapsed() + poll_interval > window {
+                break;
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+
+        DocsVerification {
+            reports,
+            duration: start_time.elapsed(),
+        }
+    }
+}
+
+impl DocsBuildReport {
+    /// Check if the build has not finished yet
+    pub fn is_pending(&self) -> bool {
+        self.status == DocsBuildStatus::Pending
+    }
+
+    /// Link to the crate's docs.rs build log page
+    pub fn builds_url(&self) -> String {
+        format!("{}/crate/{}/{}/builds", DOCS_RS_URL, self.package_name, self.version)
+    }
+
+    /// Format report for display
+    pub fn format_result(&self) -> String {
+        match self.status {
+            DocsBuildStatus::Succeeded => format!("{} {}: documentation built", self.package_name, self.version),
+            DocsBuildStatus::Failed => format!(
+                "{} {}: documentation build failed (see {})",
+                self.package_name,
+                self.version,
+                self.builds_url()
+            ),
+            DocsBuildStatus::Pending => match &self.last_error {
+                Some(error) => format!("{} {}: build status unknown ({})", self.package_name, self.version, error),
+                None => format!("{} {}: build did not finish in time", self.package_name, self.version),
+            },
+        }
+    }
+}
+
+impl DocsVerification {
+    /// Reports for crates whose documentation build failed
+    pub fn failed(&self) -> Vec<&DocsBuildReport> {
+        self.reports.iter().filter(|report| report.status == DocsBuildStatus::Failed).collect()
+    }
+
+    /// Reports for crates whose build did not finish within the window
+    pub fn pending(&self) -> Vec<&DocsBuildReport> {
+        self.reports.iter().filter(|report| report.is_pending()).collect()
+    }
+
+    /// Check if every documentation build succeeded
+    pub fn all_succeeded(&self) -> bool {
+        self.reports.iter().all(|report| report.status == DocsBuildStatus::Succeeded)
+    }
+
+    /// Format verification summary
+    pub fn format_summary(&self) -> String {
+        format!(
+            "{} built, {} failed, {} unfinished in {:.0}s",
+            self.reports.len() - self.failed().len() - self.pending().len(),
+            self.failed().len(),
+            self.pending().len(),
+            self.duration.as_secs_f64()
+        )
+    }
+}
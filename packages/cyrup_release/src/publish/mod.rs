@@ -4,7 +4,10 @@
 //! dependency-ordered publishing, retry logic, rate limiting, and rollback support.
 
 mod cargo_ops;
+mod docs_rs;
+mod owners;
 mod publisher;
+mod registry;
 
 pub use cargo_ops::{
     CargoPublisher, PublishConfig, PublishResult, YankResult,
@@ -12,6 +15,13 @@ pub use cargo_ops::{
 pub use publisher::{
     Publisher, PublisherConfig, PublishingResult, RollbackResult, PublishProgress,
 };
+pub use docs_rs::{
+    DocsRsClient, DocsBuildStatus, DocsBuildReport, DocsVerification, DOCS_RS_URL,
+};
+pub use owners::{is_team_owner, CargoOwners, OwnerChange, OwnershipDiff, CRATES_IO_API};
+pub use registry::{
+    RegistryIndex, CrateAvailability, IndexVersion, PinnedDependency, CRATES_IO_INDEX,
+};
 
 use crate::error::Result;
 use crate::workspace::WorkspaceInfo;
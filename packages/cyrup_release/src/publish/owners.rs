@@ -0,0 +1,312 @@
+//! Crate ownership management across workspace packages.
+//!
+//! Wraps `cargo owner` so owners can be listed, added and removed for every
+//! workspace package at once, and compares current owners against the
+//! expected set so missing owner teams are spotted before a release.
+
+use crate::error::{PublishError, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::time::timeout;
+
+/// Client for `cargo owner` operations
+#[derive(Debug, Clone)]
+pub struct CargoOwners {
+    /// Registry to manage owners on (defaults to crates.io)
+    registry: Option<String>,
+    /// Timeout for each cargo invocation
+    operation_timeout: Duration,
+}
+
+/// Base URL of the crates.io web API
+pub const CRATES_IO_API: &str = "https://crates.io";
+
+/// Change applied to a crate's owner list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnerChange {
+    /// Owner is added
+    Add,
+    /// Owner is removed
+    Remove,
+}
+
+/// Current and planned owners of one crate
+#[derive(Debug, Clone)]
+pub struct OwnershipDiff {
+    /// Package name
+    pub package_name: String,
+    /// Current owner logins
+    pub current: Vec<String>,
+    /// Owners to add (expected or requested, and not yet present)
+    pub to_add: Vec<String>,
+    /// Owners to remove (requested and currently present)
+    pub to_remove: Vec<String>,
+}
+
+/// Response body of the crates.io `/api/v1/me` endpoint
+#[derive(Debug, Deserialize)]
+struct MeResponse {
+    user: MeUser,
+}
+
+/// User part of the `/api/v1/me` response
+#[derive(Debug, Deserialize)]
+struct MeUser {
+    login: String,
+}
+
+impl Default for CargoOwners {
+    fn default() -> Self {
+        Self {
+            registry: None,
+            operation_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl CargoOwners {
+    /// Create an owner client for crates.io
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an owner client for a specific registry
+    pub fn with_registry(registry: Option<String>) -> Self {
+        Self {
+            registry,
+            ..Self::default()
+        }
+    }
+
+    /// List the owner logins of a crate
+    pub async fn list(&self, package_name: &str) -> Result<Vec<String>> {
+        let stdout = self.run(package_name, &["--list"]).await?;
+
+        // Each line is `login (Display Name)` or just `login`
+        Ok(stdout.lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Add or remove an owner of a crate
+    pub async fn apply(&self, package_name: &str, change: OwnerChange, login: &str) -> Result<()> {
+        let flag = match change {
+            OwnerChange::Add => "--add",
+            OwnerChange::Remove => "--remove",
+        };
+        self.run(package_name, &[flag, login]).await?;
+        Ok(())
+    }
+
+    /// Compute the ownership diff for a crate
+    pub async fn diff(
+        &self,
+        package_name: &str,
+        add: &[String],
+        remove: &[String],
+    ) -> Result<OwnershipDiff> {
+        let current = self.list(package_name).await?;
+        Ok(OwnershipDiff::new(package_name, current, add, remove))
+    }
+
+    /// Login of the user the configured registry token belongs to.
+    ///
+    /// Only crates.io exposes the authenticated user, so `None` is returned
+    /// for alternate registries and when no token is configured.
+    pub async fn current_login(&self) -> Result<Option<String>> {
+        if self.registry.as_deref().is_some_and(|name| name != "crates-io") {
+            return Ok(None);
+        }
+        let Some(token) = crates_io_token() else {
+            return Ok(None);
+        };
+
+        let client = reqwest::Client::builder()
+            .timeout(self.operation_timeout)
+            .user_agent(concat!("cyrup_release/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to create HTTP client: {}", e),
+            })?;
+
+        let response = client.get(format!("{}/api/v1/me", CRATES_IO_API))
+            .header(reqwest::header::AUTHORIZATION, token)
+            .send()
+            .await
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to query the crates.io user: {}", e),
+            })?;
+
+        if response.status() == reqwest::StatusCode::FORBIDDEN
+            || response.status() == reqwest::StatusCode::UNAUTHORIZED
+        {
+            return Err(PublishError::AuthenticationError.into());
+        }
+
+        if !response.status().is_success() {
+            return Err(PublishError::NetworkError {
+                reason: format!("crates.io returned {} for the current user", response.status()),
+            }.into());
+        }
+
+        let me: MeResponse = response.json().await
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Invalid crates.io user response: {}", e),
+            })?;
+
+        Ok(Some(me.user.login))
+    }
+
+    /// Run `cargo owner` for a crate and return its stdout
+    async fn run(&self, package_name: &str, args: &[&str]) -> Result<String> {
+        let mut cmd = Command::new("cargo");
+        cmd.arg("owner")
+            .args(args)
+            .arg(package_name)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(ref registry) = self.registry {
+            cmd.arg("--registry").arg(registry);
+        }
+
+        let output = timeout(self.operation_timeout, cmd.output()).await
+            .map_err(|_| PublishError::OwnerCommandFailed {
+                package: package_name.to_string(),
+                reason: "cargo owner timed out".to_string(),
+            })?
+            .map_err(|e| PublishError::OwnerCommandFailed {
+                package: package_name.to_string(),
+                reason: format!("Failed to execute cargo owner: {}", e),
+            })?;
+
+        if !output.status.success() {
+            return Err(PublishError::OwnerCommandFailed {
+                package: package_name.to_string(),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+/// Check if an owner entry is a team (`github:org:team`) rather than a user
+pub fn is_team_owner(login: &str) -> bool {
+    login.contains(':')
+}
+
+/// Find the crates.io token the same way cargo does: the environment first,
+/// then the credentials file in the cargo home directory
+fn crates_io_token() -> Option<String> {
+    if let Ok(token) = std::env::var("CARGO_REGISTRY_TOKEN")
+        && !token.trim().is_empty()
+    {
+        return Some(token);
+    }
+
+    let cargo_home = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cargo")))?;
+
+    ["credentials.toml", "credentials"].iter()
+        .filter_map(|file_name| std::fs::read_to_string(cargo_home.join(file_name)).ok())
+        .filter_map(|contents| contents.parse::<toml::Table>().ok())
+        .find_map(|credentials| {
+            credentials.get("registry")?.get("token")?.as_str().map(str::to_string)
+        })
+}
+
+impl OwnershipDiff {
+    /// Plan the changes that bring a crate's `current` owners in line with `add` and `remove`
+    fn new(package_name: &str, current: Vec<String>, add: &[String], remove: &[String]) -> Self {
+        let to_add = add.iter()
+            .filter(|login| !current.contains(login) && !remove.contains(login))
+            .cloned()
+            .collect();
+        let to_remove = remove.iter()
+            .filter(|login| current.contains(login))
+            .cloned()
+            .collect();
+
+        Self {
+            package_name: package_name.to_string(),
+            current,
+            to_add,
+            to_remove,
+        }
+    }
+
+    /// Check if no owners would change
+    pub fn is_empty(&self) -> bool {
+        self.to_add.is_empty() && self.to_remove.is_empty()
+    }
+
+    /// Planned changes in the order they should be applied
+    pub fn changes(&self) -> impl Iterator<Item = (OwnerChange, &String)> {
+        self.to_add.iter().map(|login| (OwnerChange::Add, login))
+            .chain(self.to_remove.iter().map(|login| (OwnerChange::Remove, login)))
+    }
+
+    /// Format as a diff: unchanged owners indented, `+` for additions, `-` for removals
+    pub fn format_diff(&self) -> String {
+        let mut lines = vec![self.package_name.clone()];
+        for login in &self.current {
+            let marker = if self.to_remove.contains(login) { '-' } else { ' ' };
+            lines.push(format!("  {} {}", marker, login));
+        }
+        for login in &self.to_add {
+            lines.push(format!("  + {}", login));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logins(logins: &[&str]) -> Vec<String> {
+        logins.iter().map(|login| login.to_string()).collect()
+    }
+
+    #[test]
+    fn test_diff_adds_missing_and_removes_present_owners() {
+        let diff = OwnershipDiff::new(
+            "sugars",
+            logins(&["alice", "github:org:team"]),
+            &logins(&["alice", "bob"]),
+            &logins(&["github:org:team", "carol"]),
+        );
+
+        assert_eq!(diff.to_add, logins(&["bob"]));
+        assert_eq!(diff.to_remove, logins(&["github:org:team"]));
+        let changes: Vec<(OwnerChange, &str)> = diff.changes().map(|(change, login)| (change, login.as_str())).collect();
+        assert_eq!(changes, vec![(OwnerChange::Add, "bob"), (OwnerChange::Remove, "github:org:team")]);
+        assert_eq!(diff.format_diff(), "sugars\n    alice\n  - github:org:team\n  + bob");
+    }
+
+    #[test]
+    fn test_login_both_added_and_removed_is_only_removed() {
+        let diff = OwnershipDiff::new("sugars", logins(&["alice"]), &logins(&["alice", "bob"]), &logins(&["bob", "alice"]));
+        assert!(diff.to_add.is_empty());
+        assert_eq!(diff.to_remove, logins(&["alice"]));
+    }
+
+    #[test]
+    fn test_diff_with_expected_owners_present_is_empty() {
+        let diff = OwnershipDiff::new("sugars", logins(&["alice", "bob"]), &logins(&["bob"]), &[]);
+        assert!(diff.is_empty());
+        assert_eq!(diff.changes().count(), 0);
+    }
+
+    #[test]
+    fn test_team_owners() {
+        assert!(is_team_owner("github:org:team"));
+        assert!(!is_team_owner("alice"));
+    }
+}
@@ -3,13 +3,18 @@
 //! This module coordinates the publishing of multiple packages in dependency order
 //! with proper timing, error handling, and rollback capabilities.
 
+use crate::cli::{ProgressBar, OUTPUT_TARGET};
+use crate::config::PackageOverride;
 use crate::error::{Result, PublishError};
 use crate::publish::{CargoPublisher, PublishConfig, PublishResult, YankResult};
+use crate::state::ReleaseHistory;
 use crate::workspace::{WorkspaceInfo, DependencyGraph, PublishTier};
 use semver::Version;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
 use std::time::Duration;
 use tokio::time::sleep;
+use tracing::Instrument;
 
 /// Publishing orchestrator for workspace packages
 #[derive(Debug)]
@@ -24,8 +29,13 @@ pub struct Publisher {
     config: PublisherConfig,
     /// State of current publishing operation
     publish_state: PublishState,
+    /// Publish durations of previous releases, used for the ETA
+    history: ReleaseHistory,
 }
 
+/// Assumed publish duration of a package without any timing information
+const DEFAULT_PACKAGE_ESTIMATE: Duration = Duration::from_secs(30);
+
 /// Configuration for the publishing orchestrator
 #[derive(Debug, Clone)]
 pub struct PublisherConfig {
@@ -43,6 +53,16 @@ pub struct PublisherConfig {
     pub allow_dirty: bool,
     /// Additional cargo arguments
     pub additional_cargo_args: Vec<String>,
+    /// Packages that have never been published (skip already-published checks)
+    pub new_crates: HashSet<String>,
+    /// Release history file recording per-package publish durations
+    pub history_path: Option<PathBuf>,
+    /// Maximum retry attempts for a failed publish
+    pub max_retries: usize,
+    /// Timeout for each cargo invocation
+    pub operation_timeout: Duration,
+    /// Per-package overrides from `[package.<name>]`
+    pub package_overrides: BTreeMap<String, PackageOverride>,
 }
 
 impl Default for PublisherConfig {
@@ -55,6 +75,11 @@ impl Default for PublisherConfig {
             registry: None,
             allow_dirty: false,
             additional_cargo_args: Vec::new(),
+            new_crates: HashSet::new(),
+            history_path: None,
+            max_retries: 3,
+            operation_timeout: Duration::from_secs(300),
+            package_overrides: BTreeMap::new(),
         }
     }
 }
@@ -70,6 +95,8 @@ struct PublishState {
     current_tier: usize,
     /// Total tiers to publish
     total_tiers: usize,
+    /// Tiers being published, for estimating the remaining time
+    tiers: Vec<PublishTier>,
     /// Start time of publishing operation
     start_time: Option<std::time::Instant>,
 }
@@ -81,6 +108,7 @@ impl Default for PublishState {
             failed_packages: HashMap::new(),
             current_tier: 0,
             total_tiers: 0,
+            tiers: Vec::new(),
             start_time: None,
         }
     }
@@ -99,6 +127,8 @@ pub struct PublishingResult {
     pub tiers_processed: usize,
     /// Whether all packages were published successfully
     pub all_successful: bool,
+    /// Packages not published because of `skip = true`
+    pub skipped_packages: Vec<String>,
 }
 
 /// Result of rollback operation
@@ -128,6 +158,7 @@ impl Publisher {
             cargo_publisher,
             config,
             publish_state,
+            history: ReleaseHistory::default(),
         })
     }
 
@@ -143,16 +174,22 @@ impl Publisher {
             cargo_publisher,
             config,
             publish_state,
+            history: ReleaseHistory::default(),
         })
     }
 
     /// Publish all packages in dependency order
     pub async fn publish_all_packages(&mut self) -> Result<PublishingResult> {
         self.publish_state.start_time = Some(std::time::Instant::now());
+        self.load_history();
         
         // Get publishing order
         let publish_order = self.dependency_graph.publish_order()?;
         self.publish_state.total_tiers = publish_order.tier_count();
+        self.publish_state.tiers = publish_order.tiers.clone();
+
+        let package_count = publish_order.ordered_packages().filter(|name| !self.is_skipped(name)).count();
+        let progress = ProgressBar::new("Publishing", package_count);
 
         // NOTE: We DON'T validate all packages upfront because workspace packages
         // depend on each other, and validation will fail for packages that depend
@@ -164,8 +201,10 @@ impl Publisher {
         // Publish packages tier by tier
         for (tier_index, tier) in publish_order.tiers.iter().enumerate() {
             self.publish_state.current_tier = tier_index;
+            progress.set_label(&format!("Publishing tier {}/{}", tier_index + 1, publish_order.tiers.len()));
+            progress.set_eta(Some(self.estimate_remaining()));
             
-            match self.publish_tier(tier).await {
+            match self.publish_tier(tier, &progress).await {
                 Ok(()) => {
                     // Add delay between tiers (except after the last tier)
                     if tier_index < publish_order.tiers.len() - 1 {
@@ -174,7 +213,7 @@ impl Publisher {
                 }
                 Err(e) if self.config.continue_on_failure => {
                     // Log error but continue with next tier
-                    eprintln!("Tier {} failed but continuing: {}", tier_index, e);
+                    tracing::warn!(target: OUTPUT_TARGET, "Tier {} failed but continuing: {}", tier_index, e);
                 }
                 Err(e) => {
                     // Fail fast - stop publishing
@@ -195,21 +234,68 @@ impl Publisher {
             total_duration,
             tiers_processed: self.publish_state.current_tier + 1,
             all_successful,
+            skipped_packages: publish_order.ordered_packages()
+                .filter(|name| self.is_skipped(name))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Publish only the named package, leaving other workspace members unpublished
+    pub async fn publish_package_only(&mut self, package_name: &str) -> Result<PublishingResult> {
+        self.publish_state.start_time = Some(std::time::Instant::now());
+        self.publish_state.total_tiers = 1;
+        self.publish_state.current_tier = 0;
+        self.load_history();
+
+        let skipped = self.is_skipped(package_name);
+        if skipped {
+            tracing::info!(target: OUTPUT_TARGET, "⏭️  Skipping {} (skip = true in [package.{}])", package_name, package_name);
+        } else {
+            let progress = ProgressBar::new("Publishing", 1);
+            progress.set_eta(self.history.expected_publish_duration(package_name));
+
+            let publish_config = self.create_publish_config(package_name);
+            self.publish_single_package(package_name, &publish_config, &progress).await?;
+        }
+
+        let total_duration = self.publish_state.start_time
+            .map(|start| start.elapsed())
+            .unwrap_or_default();
+
+        Ok(PublishingResult {
+            successful_publishes: self.publish_state.completed_publishes.clone(),
+            failed_packages: self.publish_state.failed_packages.clone(),
+            total_duration,
+            tiers_processed: 1,
+            all_successful: self.publish_state.failed_packages.is_empty(),
+            skipped_packages: if skipped { vec![package_name.to_string()] } else { Vec::new() },
         })
     }
 
     /// Publish a single tier of packages
-    async fn publish_tier(&mut self, tier: &PublishTier) -> Result<()> {
-        let publish_config = self.create_publish_config();
-        
+    async fn publish_tier(&mut self, tier: &PublishTier, progress: &ProgressBar) -> Result<()> {
+        let mut packages = Vec::new();
+        for package_name in &tier.packages {
+            if self.is_skipped(package_name) {
+                tracing::info!(target: OUTPUT_TARGET, "⏭️  Skipping {} (skip = true in [package.{}])", package_name, package_name);
+            } else {
+                packages.push(package_name.clone());
+            }
+        }
+
         // Handle single package or parallel publishing
-        if tier.packages.len() == 1 {
-            // Single package - publish directly
-            let package_name = &tier.packages[0];
-            self.publish_single_package(package_name, &publish_config).await?;
-        } else {
-            // Multiple packages - publish with controlled concurrency
-            self.publish_packages_concurrently(&tier.packages, &publish_config).await?;
+        match packages.as_slice() {
+            [] => {}
+            [package_name] => {
+                // Single package - publish directly
+                let publish_config = self.create_publish_config(package_name);
+                self.publish_single_package(package_name, &publish_config, progress).await?;
+            }
+            _ => {
+                // Multiple packages - publish with controlled concurrency
+                self.publish_packages_concurrently(&packages, progress).await?;
+            }
         }
 
         Ok(())
@@ -220,15 +306,21 @@ impl Publisher {
         &mut self,
         package_name: &str,
         publish_config: &PublishConfig,
+        progress: &ProgressBar,
     ) -> Result<()> {
         let package_info = self.workspace.get_package(package_name)?;
         
-        println!("📦 Publishing {} v{}...", package_name, package_info.version);
+        tracing::info!(target: OUTPUT_TARGET, "📦 Publishing {} v{}...", package_name, package_info.version);
+        progress.start(package_name);
         
-        match self.cargo_publisher.publish_package(package_info, publish_config).await {
+        let result = self.cargo_publisher.publish_package(package_info, publish_config).await;
+        progress.finish_item(package_name);
+
+        match result {
             Ok(result) => {
-                println!("✅ {}", result.summary());
-                self.publish_state.completed_publishes.insert(package_name.to_string(), result);
+                tracing::info!(target: OUTPUT_TARGET, success = true, "{}", result.summary());
+                self.record_publish(result);
+                progress.set_eta(Some(self.estimate_remaining()));
                 Ok(())
             }
             Err(e) => {
@@ -246,7 +338,7 @@ impl Publisher {
     async fn publish_packages_concurrently(
         &mut self,
         package_names: &[String],
-        publish_config: &PublishConfig,
+        progress: &ProgressBar,
     ) -> Result<()> {
         use tokio::sync::Semaphore;
         use std::sync::Arc;
@@ -257,18 +349,21 @@ impl Publisher {
         for package_name in package_names {
             let package_info = self.workspace.get_package(package_name)?.clone();
             let publisher = self.cargo_publisher.clone();
-            let config = publish_config.clone();
+            let config = self.create_publish_config(package_name);
             let semaphore = Arc::clone(&semaphore);
             let package_name = package_name.clone();
+            let progress = progress.clone();
 
             let handle = tokio::spawn(async move {
                 let _permit = semaphore.acquire().await.unwrap();
                 
-                println!("📦 Publishing {} v{}...", package_name, package_info.version);
+                tracing::info!(target: OUTPUT_TARGET, "📦 Publishing {} v{}...", package_name, package_info.version);
+                progress.start(&package_name);
                 
                 let result = publisher.publish_package(&package_info, &config).await;
+                progress.finish_item(&package_name);
                 (package_name, result)
-            });
+            }.in_current_span());
 
             handles.push(handle);
         }
@@ -283,8 +378,9 @@ impl Publisher {
 
             match result {
                 Ok(publish_result) => {
-                    println!("✅ {}", publish_result.summary());
-                    self.publish_state.completed_publishes.insert(package_name, publish_result);
+                    tracing::info!(target: OUTPUT_TARGET, success = true, "{}", publish_result.summary());
+                    self.record_publish(publish_result);
+                    progress.set_eta(Some(self.estimate_remaining()));
                 }
                 Err(e) => {
                     let error_msg = format!("Failed to publish {}: {}", package_name, e);
@@ -303,12 +399,66 @@ impl Publisher {
         Ok(())
     }
 
+    /// Load publish durations of previous releases
+    fn load_history(&mut self) {
+        if let Some(path) = &self.config.history_path {
+            self.history = ReleaseHistory::load(path);
+        }
+    }
+
+    /// Store a successful publish and add its duration to the release history
+    fn record_publish(&mut self, result: PublishResult) {
+        if !result.dry_run && let Some(path) = &self.config.history_path {
+            self.history.record_publish(&result.package_name, result.duration);
+            if let Err(e) = self.history.save(path) {
+                tracing::debug!("Failed to save release history: {}", e);
+            }
+        }
+        self.publish_state.completed_publishes.insert(result.package_name.clone(), result);
+    }
+
+    /// Estimate the time left to publish the remaining packages.
+    ///
+    /// Each pending package is expected to take its historical average, or
+    /// the average of this run's publishes when it has no history yet.
+    fn estimate_remaining(&self) -> Duration {
+        let observed: Vec<Duration> = self.publish_state.completed_publishes.values()
+            .map(|result| result.duration)
+            .collect();
+        let fallback = if observed.is_empty() {
+            DEFAULT_PACKAGE_ESTIMATE
+        } else {
+            observed.iter().sum::<Duration>() / observed.len() as u32
+        };
+
+        let mut remaining = Duration::ZERO;
+        let mut pending_tiers = 0u32;
+        for tier in self.publish_state.tiers.iter().skip(self.publish_state.current_tier) {
+            let pending: Vec<Duration> = tier.packages.iter()
+                .filter(|name| {
+                    !self.publish_state.completed_publishes.contains_key(*name)
+                        && !self.publish_state.failed_packages.contains_key(*name)
+                        && !self.is_skipped(name)
+                })
+                .map(|name| self.history.expected_publish_duration(name).unwrap_or(fallback))
+                .collect();
+            if pending.is_empty() {
+                continue;
+            }
+
+            let parallelism = self.config.max_concurrent_per_tier.clamp(1, pending.len()) as u32;
+            remaining += pending.iter().sum::<Duration>() / parallelism;
+            pending_tiers += 1;
+        }
+
+        remaining + self.config.inter_package_delay * pending_tiers.saturating_sub(1)
+    }
+
     /// Rollback published packages by yanking them
     pub async fn rollback_published_packages(&self) -> Result<RollbackResult> {
         let start_time = std::time::Instant::now();
         let mut yanked_packages = HashMap::new();
         let mut yank_failures = HashMap::new();
-        let publish_config = self.create_publish_config();
 
         // Yank packages in reverse dependency order
         let publish_order = self.dependency_graph.publish_order()?;
@@ -317,20 +467,20 @@ impl Publisher {
 
         for package_name in packages_to_yank {
             if let Some(publish_result) = self.publish_state.completed_publishes.get(package_name) {
-                println!("🔄 Yanking {} v{}...", package_name, publish_result.version);
+                tracing::info!(target: OUTPUT_TARGET, "🔄 Yanking {} v{}...", package_name, publish_result.version);
                 
                 match self.cargo_publisher.yank_package(
                     package_name,
                     &publish_result.version,
-                    &publish_config,
+                    &self.create_publish_config(package_name),
                 ).await {
                     Ok(yank_result) => {
-                        println!("✅ {}", yank_result.format_result());
+                        tracing::info!(target: OUTPUT_TARGET, success = true, "{}", yank_result.format_result());
                         yanked_packages.insert(package_name.to_string(), yank_result);
                     }
                     Err(e) => {
                         let error_msg = format!("Failed to yank {}: {}", package_name, e);
-                        println!("❌ {}", error_msg);
+                        tracing::error!(target: OUTPUT_TARGET, "{}", error_msg);
                         yank_failures.insert(package_name.to_string(), error_msg);
                     }
                 }
@@ -348,14 +498,27 @@ impl Publisher {
         })
     }
 
-    /// Create publish configuration from publisher config
-    fn create_publish_config(&self) -> PublishConfig {
+    /// Check if a package is excluded from publishing by its overrides
+    pub fn is_skipped(&self, package_name: &str) -> bool {
+        self.config.package_overrides.get(package_name).is_some_and(|overrides| overrides.skip)
+    }
+
+    /// Create the publish configuration for a package, applying its overrides
+    fn create_publish_config(&self, package_name: &str) -> PublishConfig {
+        let overrides = self.config.package_overrides.get(package_name);
+        let mut additional_args = self.config.additional_cargo_args.clone();
+        additional_args.extend(overrides.into_iter().flat_map(|overrides| overrides.extra_flags.iter().cloned()));
+
         PublishConfig {
-            registry: self.config.registry.clone(),
+            registry: overrides
+                .and_then(|overrides| overrides.registry.clone())
+                .or_else(|| self.config.registry.clone()),
             dry_run_first: self.config.dry_run_first, // Validate each package right before publishing
             allow_dirty: self.config.allow_dirty,
-            additional_args: self.config.additional_cargo_args.clone(),
+            additional_args,
             token: None, // Use cargo login
+            max_retries: Some(self.config.max_retries),
+            timeout: Some(self.config.operation_timeout),
         }
     }
 
@@ -364,6 +527,16 @@ impl Publisher {
         let mut results = HashMap::new();
 
         for (package_name, package_info) in &self.workspace.packages {
+            if self.is_skipped(package_name) {
+                continue;
+            }
+
+            // A crate that has never been published cannot have this version yet
+            if self.config.new_crates.contains(package_name) {
+                results.insert(package_name.clone(), false);
+                continue;
+            }
+
             let version = Version::parse(&package_info.version)
                 .map_err(|e| PublishError::PublishFailed {
                     package: package_name.clone(),
@@ -473,6 +646,13 @@ impl PublishingResult {
             }
         }
 
+        if !self.skipped_packages.is_empty() {
+            report.push_str("\n⏭️  Skipped Packages:\n");
+            for package in &self.skipped_packages {
+                report.push_str(&format!("  ⏭️  {}\n", package));
+            }
+        }
+
         report
     }
 }
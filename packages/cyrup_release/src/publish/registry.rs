@@ -0,0 +1,327 @@
+//! Registry index queries for published crate versions.
+//!
+//! This module reads the sparse registry index to find every published
+//! version of a crate, which `cargo search` cannot provide. It is used to
+//! verify that pinned internal dependency requirements resolve against the
+//! registry before a single package is released on its own.
+
+use crate::error::{Result, PublishError};
+use crate::workspace::{PackageInfo, WorkspaceInfo};
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Sparse index URL for crates.io
+pub const CRATES_IO_INDEX: &str = "https://index.crates.io";
+
+/// Client for querying a sparse registry index
+#[derive(Debug, Clone)]
+pub struct RegistryIndex {
+    /// HTTP client used for index requests
+    client: reqwest::Client,
+    /// Base URL of the sparse index (without trailing slash)
+    index_url: String,
+}
+
+/// A single version entry from the registry index
+#[derive(Debug, Clone)]
+pub struct IndexVersion {
+    /// Published version
+    pub version: Version,
+    /// Whether the version has been yanked
+    pub yanked: bool,
+}
+
+/// An internal dependency requirement checked against the registry
+#[derive(Debug, Clone)]
+pub struct PinnedDependency {
+    /// Dependency package name
+    pub name: String,
+    /// Version requirement declared in Cargo.toml
+    pub requirement: VersionReq,
+    /// Highest non-yanked published version satisfying the requirement
+    pub resolved: Version,
+}
+
+/// Registry status of a crate name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrateAvailability {
+    /// The crate has been published before
+    Published {
+        /// Highest published version (including yanked versions)
+        latest: Version,
+    },
+    /// The name is free to claim with a first release
+    Available,
+    /// The name is unpublished, but an equivalent spelling is already taken
+    Taken {
+        /// Existing crate that claims the name
+        existing: String,
+    },
+}
+
+/// Raw index line as stored in the registry
+#[derive(Debug, Deserialize)]
+struct IndexEntry {
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+}
+
+impl RegistryIndex {
+    /// Create a client for the crates.io sparse index
+    pub fn new() -> Result<Self> {
+        Self::with_index_url(CRATES_IO_INDEX)
+    }
+
+    /// Create a client for a custom sparse index URL
+    pub fn with_index_url(index_url: &str) -> Result<Self> {
+        let index_url = index_url
+            .trim_start_matches("sparse+")
+            .trim_end_matches('/')
+            .to_string();
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("cyrup_release/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to create HTTP client: {}", e),
+            })?;
+
+        Ok(Self { client, index_url })
+    }
+
+    /// Create a client for a named registry, or crates.io when `None`.
+    ///
+    /// Named registries are resolved through the standard
+    /// `CARGO_REGISTRIES_<NAME>_INDEX` environment variable and must use the
+    /// sparse protocol.
+    pub fn for_registry(registry: Option<&str>) -> Result<Self> {
+        let Some(name) = registry else {
+            return Self::new();
+        };
+
+        if name == "crates-io" {
+            return Self::new();
+        }
+
+        let env_key = format!(
+            "CARGO_REGISTRIES_{}_INDEX",
+            name.to_uppercase().replace('-', "_")
+        );
+        let index_url = std::env::var(&env_key).map_err(|_| PublishError::NetworkError {
+            reason: format!("Index URL for registry '{}' not found (set {})", name, env_key),
+        })?;
+
+        if !index_url.starts_with("sparse+") {
+            return Err(PublishError::NetworkError {
+                reason: format!("Registry '{}' does not use the sparse protocol", name),
+            }.into());
+        }
+
+        Self::with_index_url(&index_url)
+    }
+
+    /// Fetch all published versions of a crate
+    pub async fn published_versions(&self, package_name: &str) -> Result<Vec<IndexVersion>> {
+        let url = format!("{}/{}", self.index_url, index_path(package_name));
+
+        let response = self.client.get(&url).send().await
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to query registry index for '{}': {}", package_name, e),
+            })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+
+        if !response.status().is_success() {
+            return Err(PublishError::NetworkError {
+                reason: format!(
+                    "Registry index returned {} for '{}'",
+                    response.status(),
+                    package_name
+                ),
+            }.into());
+        }
+
+        let body = response.text().await
+            .map_err(|e| PublishError::NetworkError {
+                reason: format!("Failed to read registry index for '{}': {}", package_name, e),
+            })?;
+
+        Ok(parse_index_entries(&body))
+    }
+
+    /// Check whether a crate has been published and, if not, whether its name can be claimed.
+    ///
+    /// The registry treats names case-insensitively and `-` and `_` as equal,
+    /// so an unpublished name also checks its alternate spellings.
+    pub async fn availability(&self, package_name: &str) -> Result<CrateAvailability> {
+        let versions = self.published_versions(package_name).await?;
+        if let Some(latest) = versions.into_iter().map(|entry| entry.version).max() {
+            return Ok(CrateAvailability::Published { latest });
+        }
+
+        for spelling in alternate_spellings(package_name) {
+            if !self.published_versions(&spelling).await?.is_empty() {
+                return Ok(CrateAvailability::Taken { existing: spelling });
+            }
+        }
+
+        Ok(CrateAvailability::Available)
+    }
+
+    /// Find the highest non-yanked published version satisfying a requirement
+    pub async fn resolve(&self, package_name: &str, requirement: &VersionReq) -> Result<Option<Version>> {
+        let versions = self.published_versions(package_name).await?;
+
+        Ok(versions
+            .into_iter()
+            .filter(|entry| !entry.yanked && requirement.matches(&entry.version))
+            .map(|entry| entry.version)
+            .max())
+    }
+
+    /// Verify that a package's internal dependency requirements resolve against the registry.
+    ///
+    /// Only dependencies that ship with the published crate are checked
+    /// (normal and build dependencies, plus dev-dependencies that declare a
+    /// version). Each must declare a version requirement that matches a
+    /// published, non-yanked release.
+    pub async fn verify_pinned_dependencies(
+        &self,
+        package: &PackageInfo,
+        workspace: &WorkspaceInfo,
+    ) -> Result<Vec<PinnedDependency>> {
+        let mut pinned = Vec::new();
+
+        let mut dependencies: Vec<_> = package.all_dependencies.iter().collect();
+        dependencies.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (key, spec) in dependencies {
+            let (kind, dep_name) = match key.split_once(':') {
+                Some((kind, name)) => (Some(kind), name),
+                None => (None, key.as_str()),
+            };
+
+            if !workspace.has_package(dep_name) {
+                continue;
+            }
+
+            let requirement_str = match (&spec.version, kind) {
+                (Some(version), _) => version,
+                // Path-only dev-dependencies are stripped by cargo on publish
+                (None, Some("dev")) => continue,
+                (None, _) => {
+                    return Err(PublishError::UnresolvableDependency {
+                        package: package.name.clone(),
+                        dependency: dep_name.to_string(),
+                        reason: "no version requirement declared".to_string(),
+                    }.into());
+                }
+            };
+
+            let requirement = VersionReq::parse(requirement_str)
+                .map_err(|e| PublishError::UnresolvableDependency {
+                    package: package.name.clone(),
+                    dependency: dep_name.to_string(),
+                    reason: format!("invalid version requirement '{}': {}", requirement_str, e),
+                })?;
+
+            let versions = self.published_versions(dep_name).await?;
+            let resolved = versions.iter()
+                .filter(|entry| !entry.yanked && requirement.matches(&entry.version))
+                .map(|entry| entry.version.clone())
+                .max()
+                .ok_or_else(|| PublishError::UnresolvableDependency {
+                    package: package.name.clone(),
+                    dependency: dep_name.to_string(),
+                    reason: if versions.is_empty() {
+                        "it has never been published; release it first (see --first-release)".to_string()
+                    } else {
+                        format!("no published version matches '{}'", requirement)
+                    },
+                })?;
+
+            pinned.push(PinnedDependency {
+                name: dep_name.to_string(),
+                requirement,
+                resolved,
+            });
+        }
+
+        Ok(pinned)
+    }
+}
+
+impl PinnedDependency {
+    /// Format dependency resolution for display
+    pub fn format_result(&self) -> String {
+        format!("{} {} → {}", self.name, self.requirement, self.resolved)
+    }
+}
+
+/// Every other spelling of a crate name that swaps `-` and `_` at any of its separators
+fn alternate_spellings(package_name: &str) -> Vec<String> {
+    let mut spellings = vec![String::new()];
+    for c in package_name.chars() {
+        let choices: &[char] = if c == '-' || c == '_' { &['-', '_'] } else { &[c] };
+        spellings = spellings.iter()
+            .flat_map(|prefix| choices.iter().map(move |choice| format!("{}{}", prefix, choice)))
+            .collect();
+    }
+    spellings.retain(|spelling| spelling != package_name);
+    spellings
+}
+
+/// Compute the sparse index path for a crate name
+fn index_path(package_name: &str) -> String {
+    let name = package_name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+/// Parse newline-delimited JSON index entries, skipping malformed lines
+fn parse_index_entries(body: &str) -> Vec<IndexVersion> {
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str::<IndexEntry>(line).ok())
+        .filter_map(|entry| {
+            Version::parse(&entry.vers).ok().map(|version| IndexVersion {
+                version,
+                yanked: entry.yanked,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alternate_spellings_cover_mixed_separators() {
+        let mut spellings = alternate_spellings("a-b_c");
+        spellings.sort();
+        assert_eq!(spellings, vec!["a-b-c", "a_b-c", "a_b_c"]);
+    }
+
+    #[test]
+    fn test_name_without_separators_has_no_alternate_spellings() {
+        assert!(alternate_spellings("serde").is_empty());
+    }
+
+    #[test]
+    fn test_index_path() {
+        assert_eq!(index_path("a"), "1/a");
+        assert_eq!(index_path("ab"), "2/ab");
+        assert_eq!(index_path("abc"), "3/a/abc");
+        assert_eq!(index_path("Serde_Json"), "se/rd/serde_json");
+    }
+}
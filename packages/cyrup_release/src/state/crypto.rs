@@ -0,0 +1,287 @@
+//! Optional encryption of persisted release state.
+//!
+//! Release state can carry registry tokens in `additional_options` and hook
+//! output. When a passphrase is provided through [`PASSPHRASE_ENV`], state
+//! files and their backups are written as an AES-256-GCM envelope whose key
+//! is derived from the passphrase with PBKDF2-HMAC-SHA256. Plain state files
+//! remain readable, so encryption can be enabled mid-release.
+
+use crate::error::{Result, StateError};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::NonZeroU32;
+use std::sync::OnceLock;
+
+/// Environment variable holding the state passphrase
+pub const PASSPHRASE_ENV: &str = "CYRUP_RELEASE_STATE_PASSPHRASE";
+
+/// Cipher identifier recorded in the envelope
+const CIPHER: &str = "aes-256-gcm";
+
+/// PBKDF2 iterations for newly encrypted files
+const PBKDF2_ITERATIONS: u32 = 100_000;
+
+/// Highest iteration count accepted when decrypting, so a crafted file cannot stall key derivation
+const MAX_PBKDF2_ITERATIONS: u32 = 10 * PBKDF2_ITERATIONS;
+
+/// Salt length in bytes
+const SALT_LEN: usize = 16;
+
+/// Passphrase used to encrypt state files
+#[derive(Clone)]
+pub struct StatePassphrase(String);
+
+/// Encrypts and decrypts state file contents
+pub struct StateCipher {
+    /// Passphrase keys are derived from
+    passphrase: StatePassphrase,
+    /// Salt and key used for writing, derived once per cipher
+    write_key: OnceLock<([u8; SALT_LEN], [u8; 32])>,
+}
+
+/// On-disk format of an encrypted state file
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedEnvelope {
+    /// Cipher used for the ciphertext
+    encrypted: String,
+    /// PBKDF2 iteration count
+    iterations: u32,
+    /// Hex-encoded PBKDF2 salt
+    salt: String,
+    /// Hex-encoded AES-GCM nonce
+    nonce: String,
+    /// Hex-encoded ciphertext with authentication tag
+    ciphertext: String,
+}
+
+impl StatePassphrase {
+    /// Create a passphrase
+    pub fn new(passphrase: impl Into<String>) -> Self {
+        Self(passphrase.into())
+    }
+
+    /// Read the passphrase from [`PASSPHRASE_ENV`], if set
+    pub fn from_env() -> Option<Self> {
+        std::env::var(PASSPHRASE_ENV)
+            .ok()
+            .filter(|passphrase| !passphrase.is_empty())
+            .map(Self)
+    }
+}
+
+impl fmt::Debug for StatePassphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StatePassphrase(<redacted>)")
+    }
+}
+
+impl StateCipher {
+    /// Create a cipher for a passphrase
+    pub fn new(passphrase: StatePassphrase) -> Self {
+        Self {
+            passphrase,
+            write_key: OnceLock::new(),
+        }
+    }
+
+    /// Encrypt serialized state into an envelope
+    pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let rng = SystemRandom::new();
+        let (salt, key) = match self.write_key.get() {
+            Some(write_key) => *write_key,
+            None => {
+                let mut salt = [0u8; SALT_LEN];
+                rng.fill(&mut salt).map_err(|_| encryption_error("Failed to generate salt"))?;
+                *self.write_key.get_or_init(|| (salt, self.derive_key(&salt, PBKDF2_ITERATIONS)))
+            }
+        };
+
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut nonce).map_err(|_| encryption_error("Failed to generate nonce"))?;
+
+        let mut in_out = plaintext.as_bytes().to_vec();
+        sealing_key(&key)?
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut in_out)
+            .map_err(|_| encryption_error("Failed to encrypt state"))?;
+
+        let envelope = EncryptedEnvelope {
+            encrypted: CIPHER.to_string(),
+            iterations: PBKDF2_ITERATIONS,
+            salt: to_hex(&salt),
+            nonce: to_hex(&nonce),
+            ciphertext: to_hex(&in_out),
+        };
+
+        serde_json::to_string_pretty(&envelope)
+            .map_err(|e| encryption_error(&format!("Failed to serialize encrypted state: {}", e)))
+    }
+
+    /// Decrypt an envelope produced by [`StateCipher::encrypt`]
+    pub fn decrypt(&self, contents: &str) -> Result<String> {
+        let envelope: EncryptedEnvelope = serde_json::from_str(contents)
+            .map_err(|e| encryption_error(&format!("Invalid encrypted state: {}", e)))?;
+
+        if envelope.encrypted != CIPHER {
+            return Err(encryption_error(&format!("Unsupported cipher '{}'", envelope.encrypted)));
+        }
+
+        if envelope.iterations == 0 || envelope.iterations > MAX_PBKDF2_ITERATIONS {
+            return Err(encryption_error(&format!(
+                "Unsupported PBKDF2 iteration count {} (expected 1 to {})",
+                envelope.iterations, MAX_PBKDF2_ITERATIONS
+            )));
+        }
+
+        let salt = from_hex(&envelope.salt)?;
+        let nonce: [u8; NONCE_LEN] = from_hex(&envelope.nonce)?
+            .try_into()
+            .map_err(|_| encryption_error("Invalid nonce length"))?;
+        let mut in_out = from_hex(&envelope.ciphertext)?;
+
+        let key = self.derive_key(&salt, envelope.iterations);
+        let plaintext = sealing_key(&key)?
+            .open_in_place(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut in_out)
+            .map_err(|_| encryption_error("Decryption failed; wrong passphrase or tampered state"))?;
+
+        String::from_utf8(plaintext.to_vec())
+            .map_err(|_| encryption_error("Decrypted state is not valid UTF-8"))
+    }
+
+    /// Derive a 256-bit key from the passphrase
+    fn derive_key(&self, salt: &[u8], iterations: u32) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        let iterations = NonZeroU32::new(iterations).unwrap_or(NonZeroU32::MIN);
+        pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations, salt, self.passphrase.0.as_bytes(), &mut key);
+        key
+    }
+}
+
+impl fmt::Debug for StateCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateCipher").field("passphrase", &self.passphrase).finish_non_exhaustive()
+    }
+}
+
+/// Check if file contents are an encrypted envelope
+pub fn is_encrypted(contents: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(contents)
+        .is_ok_and(|value| value.get("encrypted").is_some() && value.get("ciphertext").is_some())
+}
+
+/// AES-256-GCM key for sealing and opening
+fn sealing_key(key: &[u8; 32]) -> Result<LessSafeKey> {
+    UnboundKey::new(&AES_256_GCM, key)
+        .map(LessSafeKey::new)
+        .map_err(|_| encryption_error("Invalid encryption key"))
+}
+
+/// Create an encryption error
+fn encryption_error(reason: &str) -> crate::error::ReleaseError {
+    StateError::Encryption {
+        reason: reason.to_string(),
+    }.into()
+}
+
+/// Hex-encode bytes
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decode a hex string
+fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+        return Err(encryption_error("Invalid hex encoding"));
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| {
+            u8::from_str_radix(&hex[index..index + 2], 16)
+                .map_err(|_| encryption_error("Invalid hex encoding"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cipher(passphrase: &str) -> StateCipher {
+        StateCipher::new(StatePassphrase::new(passphrase))
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let writer = cipher("correct horse");
+        let encrypted = writer.encrypt(r#"{"release_id":"r1"}"#).unwrap();
+
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("release_id"));
+        assert_eq!(writer.decrypt(&encrypted).unwrap(), r#"{"release_id":"r1"}"#);
+
+        // A fresh cipher with the same passphrase reads it too
+        assert_eq!(cipher("correct horse").decrypt(&encrypted).unwrap(), r#"{"release_id":"r1"}"#);
+    }
+
+    #[test]
+    fn test_each_encryption_uses_a_new_nonce() {
+        let cipher = cipher("correct horse");
+        let first: EncryptedEnvelope = serde_json::from_str(&cipher.encrypt("state").unwrap()).unwrap();
+        let second: EncryptedEnvelope = serde_json::from_str(&cipher.encrypt("state").unwrap()).unwrap();
+
+        assert_eq!(first.salt, second.salt);
+        assert_ne!(first.nonce, second.nonce);
+        assert_ne!(first.ciphertext, second.ciphertext);
+    }
+
+    #[test]
+    fn test_wrong_passphrase_is_rejected() {
+        let encrypted = cipher("correct horse").encrypt("state").unwrap();
+        let error = cipher("battery staple").decrypt(&encrypted).unwrap_err();
+        assert!(error.to_string().contains("wrong passphrase"), "{}", error);
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_is_rejected() {
+        let cipher = cipher("correct horse");
+        let mut envelope: EncryptedEnvelope = serde_json::from_str(&cipher.encrypt("state").unwrap()).unwrap();
+        let flipped = if envelope.ciphertext.starts_with('0') { "1" } else { "0" };
+        envelope.ciphertext.replace_range(..1, flipped);
+
+        assert!(cipher.decrypt(&serde_json::to_string(&envelope).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_iterations_are_rejected() {
+        let cipher = cipher("correct horse");
+        let mut envelope: EncryptedEnvelope = serde_json::from_str(&cipher.encrypt("state").unwrap()).unwrap();
+
+        for iterations in [0, MAX_PBKDF2_ITERATIONS + 1, u32::MAX] {
+            envelope.iterations = iterations;
+            let error = cipher.decrypt(&serde_json::to_string(&envelope).unwrap()).unwrap_err();
+            assert!(error.to_string().contains("Unsupported PBKDF2 iteration count"), "{}", error);
+        }
+
+        // Files written with a different count within bounds only fail authentication
+        envelope.iterations = PBKDF2_ITERATIONS + 1;
+        let error = cipher.decrypt(&serde_json::to_string(&envelope).unwrap()).unwrap_err();
+        assert!(error.to_string().contains("wrong passphrase"), "{}", error);
+    }
+
+    #[test]
+    fn test_plain_state_is_not_encrypted() {
+        assert!(!is_encrypted(r#"{"release_id":"r1"}"#));
+        assert!(!is_encrypted("not json"));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        assert_eq!(to_hex(&[0x00, 0xab, 0xff]), "00abff");
+        assert_eq!(from_hex("00abff").unwrap(), vec![0x00, 0xab, 0xff]);
+        assert!(from_hex("abc").is_err());
+        assert!(from_hex("zz").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Per-package publish timings kept across releases.
+//!
+//! Unlike release state, the history file survives cleanup. It records how
+//! long each package took to publish in recent releases so later releases can
+//! estimate how long the publish phase will take.
+
+use crate::error::{Result, StateError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// File name of the release history, next to the state file
+pub const HISTORY_FILE_NAME: &str = ".cyrup_release_history.json";
+
+/// Number of recent publish durations kept per package
+const MAX_SAMPLES: usize = 10;
+
+/// Publish durations of recent releases
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReleaseHistory {
+    /// Recent publish durations in milliseconds per package, oldest first
+    pub publish_durations_ms: BTreeMap<String, Vec<u64>>,
+}
+
+impl ReleaseHistory {
+    /// Location of the history file for a state file
+    pub fn path_for_state_file(state_file_path: &Path) -> PathBuf {
+        state_file_path.with_file_name(HISTORY_FILE_NAME)
+    }
+
+    /// Load history, starting empty if the file is missing or unreadable
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write history to disk
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(self)
+            .map_err(|e| StateError::SaveFailed {
+                reason: format!("Failed to serialize release history: {}", e),
+            })?;
+
+        fs::write(path, serialized)
+            .map_err(|e| StateError::SaveFailed {
+                reason: format!("Failed to write release history {}: {}", path.display(), e),
+            })?;
+
+        Ok(())
+    }
+
+    /// Record how long a package took to publish
+    pub fn record_publish(&mut self, package_name: &str, duration: Duration) {
+        let samples = self.publish_durations_ms.entry(package_name.to_string()).or_default();
+        samples.push(duration.as_millis() as u64);
+        if samples.len() > MAX_SAMPLES {
+            samples.drain(..samples.len() - MAX_SAMPLES);
+        }
+    }
+
+    /// Average publish duration of a package over recent releases
+    pub fn expected_publish_duration(&self, package_name: &str) -> Option<Duration> {
+        let samples = self.publish_durations_ms.get(package_name).filter(|samples| !samples.is_empty())?;
+        let average = samples.iter().sum::<u64>() / samples.len() as u64;
+        Some(Duration::from_millis(average))
+    }
+}
@@ -1,14 +1,20 @@
 //! State persistence and management for release operations.
 //!
 //! This module provides robust state persistence with file locking,
-//! corruption recovery, and atomic operations.
-
-use crate::error::{Result, StateError};
-use crate::state::ReleaseState;
+//! corruption recovery, and atomic operations. Every save rotates a set of
+//! backups whose SHA-256 checksums are recorded in a manifest next to the
+//! state file, so a corrupted state or backup is detected on load. With a
+//! shared [`StateStore`] configured, saves are mirrored to it and a missing
+//! local state file is fetched from it.
+
+use crate::error::{ReleaseError, Result, StateError};
+use crate::state::{is_encrypted, ReleaseState, StateCipher, StatePassphrase, StateStore, PASSPHRASE_ENV};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::fs;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 /// State manager for persistent release state
@@ -16,14 +22,18 @@ use std::time::{Duration, SystemTime};
 pub struct StateManager {
     /// Path to state file
     state_file_path: PathBuf,
-    /// Path to backup state file
+    /// Path to newest backup state file
     backup_file_path: PathBuf,
+    /// Path to checksum manifest
+    manifest_file_path: PathBuf,
     /// Path to lock file
     lock_file_path: PathBuf,
     /// Current lock handle
     lock_handle: Option<FileLock>,
     /// Configuration for state management
     config: StateConfig,
+    /// Cipher for encrypted state files
+    cipher: Option<StateCipher>,
 }
 
 /// Configuration for state management
@@ -41,6 +51,12 @@ pub struct StateConfig {
     pub validate_on_load: bool,
     /// Whether to create backup files
     pub create_backups: bool,
+    /// Number of rotating backups to keep
+    pub backup_count: usize,
+    /// Passphrase to encrypt state files with (defaults to the environment)
+    pub passphrase: Option<StatePassphrase>,
+    /// Shared store the state file is mirrored to
+    pub store: Option<Arc<dyn StateStore>>,
 }
 
 impl Default for StateConfig {
@@ -52,10 +68,33 @@ impl Default for StateConfig {
             lock_timeout_ms: 5000, // 5 seconds
             validate_on_load: true,
             create_backups: true,
+            backup_count: 3,
+            passphrase: StatePassphrase::from_env(),
+            store: None,
         }
     }
 }
 
+/// Checksums of the state file and its backups
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StateManifest {
+    /// SHA-256 of the state file contents
+    state_sha256: Option<String>,
+    /// Backups, newest first
+    backups: Vec<BackupEntry>,
+}
+
+/// Manifest entry for one backup file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupEntry {
+    /// Backup file name
+    file: String,
+    /// SHA-256 of the backup contents
+    sha256: String,
+    /// When the backup was written
+    created_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// File lock implementation
 #[derive(Debug)]
 struct FileLock {
@@ -72,7 +111,7 @@ impl Drop for FileLock {
         // Clean up lock file when FileLock is dropped
         // Log lock information for debugging
         let duration = self.acquired_at.elapsed().unwrap_or_default();
-        log::debug!(
+        tracing::debug!(
             "Releasing file lock (PID: {}, held for: {:?})",
             self.pid,
             duration
@@ -92,6 +131,8 @@ pub struct LoadStateResult {
     pub state: ReleaseState,
     /// Whether state was recovered from backup
     pub recovered_from_backup: bool,
+    /// Backup the state was recovered from
+    pub backup_used: Option<PathBuf>,
     /// Any warnings during loading
     pub warnings: Vec<String>,
 }
@@ -109,33 +150,46 @@ pub struct SaveStateResult {
     pub backup_created: bool,
 }
 
+/// State file removed by a retention cleanup
+#[derive(Debug, Clone)]
+pub struct RemovedStateFile {
+    /// Path of the removed file
+    pub path: PathBuf,
+    /// Age of the file when it was removed
+    pub age: Duration,
+}
+
+/// Result of a retention-based cleanup
+#[derive(Debug, Clone, Default)]
+pub struct RetentionCleanupResult {
+    /// Files older than the retention period that were removed
+    pub removed: Vec<RemovedStateFile>,
+    /// Files newer than the retention period (or in use) that were kept
+    pub kept: Vec<PathBuf>,
+    /// Why every file was kept regardless of age, when the release is still in use
+    pub in_use: Option<String>,
+}
+
 impl StateManager {
     /// Create a new state manager
     pub fn new<P: AsRef<Path>>(state_file_path: P) -> Result<Self> {
-        let state_file_path = state_file_path.as_ref().to_path_buf();
-        let backup_file_path = state_file_path.with_extension("backup.json");
-        let lock_file_path = state_file_path.with_extension("lock");
-
-        Ok(Self {
-            state_file_path,
-            backup_file_path,
-            lock_file_path,
-            lock_handle: None,
-            config: StateConfig::default(),
-        })
+        Self::with_config(state_file_path, StateConfig::default())
     }
 
     /// Create a state manager with custom configuration
     pub fn with_config<P: AsRef<Path>>(state_file_path: P, config: StateConfig) -> Result<Self> {
         let state_file_path = state_file_path.as_ref().to_path_buf();
         let backup_file_path = state_file_path.with_extension("backup.json");
+        let manifest_file_path = state_file_path.with_extension("manifest.json");
         let lock_file_path = state_file_path.with_extension("lock");
 
         Ok(Self {
             state_file_path,
             backup_file_path,
+            manifest_file_path,
             lock_file_path,
             lock_handle: None,
+            cipher: config.passphrase.clone().map(StateCipher::new),
             config,
         })
     }
@@ -158,8 +212,18 @@ impl StateManager {
                 reason: format!("Failed to serialize state: {}", e),
             })?;
 
-        // Create backup if needed
-        let backup_created = self.maybe_create_backup(&serialized)?;
+        let serialized = match &self.cipher {
+            Some(cipher) => cipher.encrypt(&serialized)?,
+            None => serialized,
+        };
+
+        // Rotate backups and record checksums before replacing the state, so
+        // an interrupted write is caught by the checksum on the next load
+        let checksum = sha256_hex(serialized.as_bytes());
+        let mut manifest = self.load_manifest();
+        let backup_created = self.maybe_create_backup(&serialized, &checksum, &mut manifest)?;
+        manifest.state_sha256 = Some(checksum);
+        self.write_manifest(&manifest)?;
 
         // Write to temporary file first (atomic operation)
         let temp_file_path = self.state_file_path.with_extension("tmp");
@@ -187,6 +251,11 @@ impl StateManager {
                 reason: format!("Failed to rename temp file: {}", e),
             })?;
 
+        // Mirror to the shared store so another machine can pick the release up
+        if let Some(store) = &self.config.store {
+            store.write(&file_name_of(&self.state_file_path), serialized.as_bytes())?;
+        }
+
         // Get file size
         let file_size_bytes = fs::metadata(&self.state_file_path)
             .map(|m| m.len())
@@ -208,26 +277,43 @@ impl StateManager {
         self.acquire_lock()?;
 
         let mut warnings = Vec::new();
-        let mut recovered_from_backup = false;
+        let mut backup_used = None;
+        if let Some(store) = self.fetch_from_store()? {
+            warnings.push(format!("Fetched state from {}", store));
+        }
+        let manifest = self.load_manifest();
 
         // Try to load from main state file first
-        let state = match self.load_from_file(&self.state_file_path) {
+        let state = match self.load_verified(&self.state_file_path, manifest.state_sha256.as_deref()) {
             Ok(state) => state,
             Err(e) => {
                 warnings.push(format!("Failed to load main state file: {}", e));
-                
-                // Try to load from backup
-                match self.load_from_file(&self.backup_file_path) {
-                    Ok(state) => {
-                        warnings.push("Recovered state from backup file".to_string());
-                        recovered_from_backup = true;
-                        state
+
+                // Fall back to the newest backup that passes its checksum
+                let mut recovered = None;
+                for backup_path in self.backup_paths() {
+                    match self.load_verified(&backup_path, manifest.backup_checksum(&backup_path)) {
+                        Ok(state) => {
+                            warnings.push(format!("Recovered state from backup {}", backup_path.display()));
+                            recovered = Some(state);
+                            backup_used = Some(backup_path);
+                            break;
+                        }
+                        Err(backup_err) => {
+                            warnings.push(format!("Skipping backup {}: {}", backup_path.display(), backup_err));
+                        }
                     }
-                    Err(backup_err) => {
+                }
+
+                match recovered {
+                    Some(state) => state,
+                    // A missing or wrong passphrase affects every backup alike
+                    None if matches!(e, ReleaseError::State(StateError::Encryption { .. })) => return Err(e),
+                    None => {
                         return Err(StateError::LoadFailed {
                             reason: format!(
-                                "Failed to load from both main ({}) and backup ({}) files",
-                                e, backup_err
+                                "Failed to load main state file ({}) and no valid backup was found",
+                                e
                             ),
                         }.into());
                     }
@@ -242,14 +328,28 @@ impl StateManager {
 
         Ok(LoadStateResult {
             state,
-            recovered_from_backup,
+            recovered_from_backup: backup_used.is_some(),
+            backup_used,
             warnings,
         })
     }
 
-    /// Check if state file exists
+    /// Check if state exists locally or in the shared store
     pub fn state_exists(&self) -> bool {
-        self.state_file_path.exists()
+        if self.state_file_path.exists() {
+            return true;
+        }
+
+        let Some(store) = &self.config.store else {
+            return false;
+        };
+        match store.read(&file_name_of(&self.state_file_path)) {
+            Ok(contents) => contents.is_some(),
+            Err(e) => {
+                tracing::warn!("Could not check {} for release state: {}", store.describe(), e);
+                false
+            }
+        }
     }
 
     /// Check if backup state file exists
@@ -268,10 +368,12 @@ impl StateManager {
             }
         }
 
-        // Remove backup file
-        if self.backup_file_path.exists() {
-            if let Err(e) = fs::remove_file(&self.backup_file_path) {
-                errors.push(format!("Failed to remove backup file: {}", e));
+        // Remove backup files and their manifest
+        let mut backup_files = self.backup_paths();
+        backup_files.push(self.manifest_file_path.clone());
+        for path in backup_files.iter().filter(|path| path.exists()) {
+            if let Err(e) = fs::remove_file(path) {
+                errors.push(format!("Failed to remove {}: {}", path.display(), e));
             }
         }
 
@@ -282,6 +384,13 @@ impl StateManager {
             }
         }
 
+        // Remove the shared copy
+        if let Some(store) = &self.config.store
+            && let Err(e) = store.delete(&file_name_of(&self.state_file_path))
+        {
+            errors.push(e.to_string());
+        }
+
         if !errors.is_empty() {
             return Err(StateError::SaveFailed {
                 reason: format!("Cleanup errors: {}", errors.join("; ")),
@@ -291,32 +400,132 @@ impl StateManager {
         Ok(())
     }
 
+    /// Delete state files and backups older than `max_age`.
+    ///
+    /// Every file next to the state file that shares its name (state, backup,
+    /// lock and temporary files) is considered. State and backup files are
+    /// aged by the `updated_at` timestamp they record, falling back to the
+    /// file modification time when they cannot be parsed. Nothing is removed
+    /// while the state is locked or its release has not finished (completed
+    /// or rolled back), since it may still be resumed or rolled back.
+    pub fn cleanup_older_than(&self, max_age: Duration) -> Result<RetentionCleanupResult> {
+        let directory = match self.state_file_path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let file_name = self.state_file_path.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let prefix = self.state_file_path.file_stem()
+            .map(|stem| format!("{}.", stem.to_string_lossy()))
+            .unwrap_or_default();
+
+        let entries = fs::read_dir(directory)
+            .map_err(|e| StateError::LoadFailed {
+                reason: format!("Failed to read state directory {}: {}", directory.display(), e),
+            })?;
+
+        let mut candidates: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_file()))
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                name == file_name || name.starts_with(&prefix)
+            })
+            .map(|entry| entry.path())
+            .collect();
+        candidates.sort();
+
+        let mut result = RetentionCleanupResult::default();
+        if let Some(reason) = self.in_use_reason() {
+            result.kept = candidates;
+            result.in_use = Some(reason);
+            return Ok(result);
+        }
+
+        let mut errors = Vec::new();
+        for path in candidates {
+            let age = self.file_age(&path);
+            if age <= max_age {
+                result.kept.push(path);
+                continue;
+            }
+
+            match fs::remove_file(&path) {
+                Ok(()) => result.removed.push(RemovedStateFile { path, age }),
+                Err(e) => errors.push(format!("Failed to remove {}: {}", path.display(), e)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(StateError::SaveFailed {
+                reason: format!("Cleanup errors: {}", errors.join("; ")),
+            }.into());
+        }
+
+        Ok(result)
+    }
+
+    /// Why the state must be kept: it is locked, or its release can still be resumed or rolled back
+    fn in_use_reason(&self) -> Option<String> {
+        if self.lock_handle.is_some() || self.is_locked_by_other_process() {
+            return Some(format!("{} is locked by a running release", self.lock_file_path.display()));
+        }
+
+        match self.load_from_file(&self.state_file_path) {
+            Ok(state) if !state.current_phase.is_terminal() => Some(format!(
+                "release {} has not finished (phase: {})",
+                state.release_id, state.current_phase
+            )),
+            _ => None,
+        }
+    }
+
     /// Create manual backup of current state
     pub fn create_backup(&self) -> Result<()> {
         if !self.state_file_path.exists() {
             return Err(StateError::NotFound.into());
         }
 
-        fs::copy(&self.state_file_path, &self.backup_file_path)
+        let contents = fs::read_to_string(&self.state_file_path)
             .map_err(|e| StateError::SaveFailed {
-                reason: format!("Failed to create backup: {}", e),
+                reason: format!("Failed to read state for backup: {}", e),
             })?;
 
-        Ok(())
+        let mut manifest = self.load_manifest();
+        self.rotate_backups(&contents, &sha256_hex(contents.as_bytes()), &mut manifest)?;
+        self.write_manifest(&manifest)
     }
 
-    /// Restore from backup
+    /// Restore the state file from the newest backup that passes its checksum
     pub fn restore_from_backup(&self) -> Result<()> {
-        if !self.backup_file_path.exists() {
-            return Err(StateError::NotFound.into());
+        let mut manifest = self.load_manifest();
+        let mut last_error = None;
+
+        for backup_path in self.backup_paths() {
+            match self.load_verified(&backup_path, manifest.backup_checksum(&backup_path)) {
+                Ok(_) => {
+                    fs::copy(&backup_path, &self.state_file_path)
+                        .map_err(|e| StateError::LoadFailed {
+                            reason: format!("Failed to restore from backup: {}", e),
+                        })?;
+
+                    manifest.state_sha256 = manifest.backup_checksum(&backup_path).map(str::to_string);
+                    return self.write_manifest(&manifest);
+                }
+                Err(e) => last_error = Some(e),
+            }
         }
 
-        fs::copy(&self.backup_file_path, &self.state_file_path)
-            .map_err(|e| StateError::LoadFailed {
-                reason: format!("Failed to restore from backup: {}", e),
-            })?;
+        Err(last_error.unwrap_or_else(|| StateError::NotFound.into()))
+    }
 
-        Ok(())
+    /// Existing backup files, newest first
+    pub fn backup_paths(&self) -> Vec<PathBuf> {
+        (0..self.config.backup_count.max(1))
+            .map(|index| self.backup_path(index))
+            .filter(|path| path.exists())
+            .collect()
     }
 
     /// Get state file information
@@ -409,6 +618,13 @@ impl StateManager {
                 reason: format!("Failed to read file {}: {}", file_path.display(), e),
             })?;
 
+        if is_encrypted(&contents) {
+            let cipher = self.cipher.as_ref().ok_or_else(|| StateError::Encryption {
+                reason: format!("{} is encrypted; set {}", file_path.display(), PASSPHRASE_ENV),
+            })?;
+            contents = cipher.decrypt(&contents)?;
+        }
+
         let state: ReleaseState = serde_json::from_str(&contents)
             .map_err(|e| StateError::Corrupted {
                 reason: format!("Failed to deserialize state: {}", e),
@@ -417,18 +633,151 @@ impl StateManager {
         Ok(state)
     }
 
+    /// Copy the state from the shared store when there is no local state file.
+    ///
+    /// Returns the store it was fetched from, if any.
+    fn fetch_from_store(&self) -> Result<Option<String>> {
+        let Some(store) = &self.config.store else {
+            return Ok(None);
+        };
+        if self.state_file_path.exists() {
+            return Ok(None);
+        }
+        let Some(contents) = store.read(&file_name_of(&self.state_file_path))? else {
+            return Ok(None);
+        };
+
+        let temp_file_path = self.state_file_path.with_extension("tmp");
+        fs::write(&temp_file_path, &contents)
+            .and_then(|()| fs::rename(&temp_file_path, &self.state_file_path))
+            .map_err(|e| StateError::LoadFailed {
+                reason: format!("Failed to write fetched state: {}", e),
+            })?;
+
+        // The fetched copy replaces whatever the manifest recorded for the state file
+        let mut manifest = self.load_manifest();
+        manifest.state_sha256 = Some(sha256_hex(&contents));
+        self.write_manifest(&manifest)?;
+
+        Ok(Some(store.describe()))
+    }
+
+    /// Age of a state file from its recorded timestamp or modification time
+    fn file_age(&self, path: &Path) -> Duration {
+        let is_json = path.extension().is_some_and(|extension| extension == "json");
+        if is_json && let Ok(state) = self.load_from_file(path) {
+            return (chrono::Utc::now() - state.updated_at).to_std().unwrap_or_default();
+        }
+
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .unwrap_or_default()
+    }
+
     /// Create backup if configured
-    fn maybe_create_backup(&self, serialized_state: &str) -> Result<bool> {
-        if !self.config.create_backups {
+    fn maybe_create_backup(
+        &self,
+        serialized_state: &str,
+        checksum: &str,
+        manifest: &mut StateManifest,
+    ) -> Result<bool> {
+        if !self.config.create_backups || self.config.backup_count == 0 {
             return Ok(false);
         }
 
-        fs::write(&self.backup_file_path, serialized_state)
+        self.rotate_backups(serialized_state, checksum, manifest)?;
+        Ok(true)
+    }
+
+    /// Shift existing backups one slot older and write a new newest backup
+    fn rotate_backups(&self, contents: &str, checksum: &str, manifest: &mut StateManifest) -> Result<()> {
+        let backup_count = self.config.backup_count.max(1);
+
+        for index in (0..backup_count - 1).rev() {
+            let from = self.backup_path(index);
+            if from.exists() {
+                fs::rename(&from, self.backup_path(index + 1))
+                    .map_err(|e| StateError::SaveFailed {
+                        reason: format!("Failed to rotate backup {}: {}", from.display(), e),
+                    })?;
+            }
+        }
+
+        fs::write(&self.backup_file_path, contents)
             .map_err(|e| StateError::SaveFailed {
                 reason: format!("Failed to create backup: {}", e),
             })?;
 
-        Ok(true)
+        // Entries follow their files to the next slot
+        manifest.backups.truncate(backup_count - 1);
+        for (index, entry) in manifest.backups.iter_mut().enumerate() {
+            entry.file = file_name_of(&self.backup_path(index + 1));
+        }
+        manifest.backups.insert(0, BackupEntry {
+            file: file_name_of(&self.backup_file_path),
+            sha256: checksum.to_string(),
+            created_at: chrono::Utc::now(),
+        });
+
+        Ok(())
+    }
+
+    /// Path of the backup in a rotation slot (0 is the newest)
+    fn backup_path(&self, index: usize) -> PathBuf {
+        if index == 0 {
+            self.backup_file_path.clone()
+        } else {
+            self.state_file_path.with_extension(format!("backup.{}.json", index))
+        }
+    }
+
+    /// Load a state file, checking its contents against a recorded checksum
+    fn load_verified(&self, file_path: &Path, expected_sha256: Option<&str>) -> Result<ReleaseState> {
+        if let Some(expected) = expected_sha256 {
+            let contents = fs::read(file_path)
+                .map_err(|e| StateError::LoadFailed {
+                    reason: format!("Failed to read file {}: {}", file_path.display(), e),
+                })?;
+
+            if sha256_hex(&contents) != expected {
+                return Err(StateError::Corrupted {
+                    reason: format!("Checksum mismatch for {}", file_path.display()),
+                }.into());
+            }
+        }
+
+        self.load_from_file(file_path)
+    }
+
+    /// Read the checksum manifest, starting over if it is missing or unreadable
+    fn load_manifest(&self) -> StateManifest {
+        let Ok(contents) = fs::read_to_string(&self.manifest_file_path) else {
+            return StateManifest::default();
+        };
+
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring unreadable state manifest {}: {}", self.manifest_file_path.display(), e);
+            StateManifest::default()
+        })
+    }
+
+    /// Atomically write the checksum manifest
+    fn write_manifest(&self, manifest: &StateManifest) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(manifest)
+            .map_err(|e| StateError::SaveFailed {
+                reason: format!("Failed to serialize state manifest: {}", e),
+            })?;
+
+        let temp_file_path = self.manifest_file_path.with_extension("tmp");
+        fs::write(&temp_file_path, serialized)
+            .and_then(|()| fs::rename(&temp_file_path, &self.manifest_file_path))
+            .map_err(|e| StateError::SaveFailed {
+                reason: format!("Failed to write state manifest: {}", e),
+            })?;
+
+        Ok(())
     }
 
     /// Acquire file lock
@@ -481,6 +830,7 @@ impl StateManager {
 
     /// Update configuration
     pub fn set_config(&mut self, config: StateConfig) {
+        self.cipher = config.passphrase.clone().map(StateCipher::new);
         self.config = config;
     }
 
@@ -499,6 +849,32 @@ impl Drop for StateManager {
     }
 }
 
+impl StateManifest {
+    /// Recorded checksum of a backup file
+    fn backup_checksum(&self, backup_path: &Path) -> Option<&str> {
+        let file = file_name_of(backup_path);
+        self.backups.iter()
+            .find(|entry| entry.file == file)
+            .map(|entry| entry.sha256.as_str())
+    }
+}
+
+/// Hex-encoded SHA-256 digest
+pub(super) fn sha256_hex(data: &[u8]) -> String {
+    ring::digest::digest(&ring::digest::SHA256, data)
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// File name of a path as a string
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
 /// Information about state files
 #[derive(Debug, Clone)]
 pub struct StateFileInfo {
@@ -602,4 +978,145 @@ impl LoadStateResult {
 
         result
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{ReleaseConfig, ReleasePhase};
+    use crate::version::VersionBump;
+
+    const WEEK: Duration = Duration::from_secs(7 * 86400);
+
+    fn config() -> StateConfig {
+        StateConfig {
+            passphrase: None,
+            ..StateConfig::default()
+        }
+    }
+
+    /// Saves a state in `phase` last updated `age` ago, releasing the lock afterwards
+    fn save_old_state(path: &Path, phase: ReleasePhase, age: Duration) {
+        let mut state = ReleaseState::new(semver::Version::new(1, 0, 0), VersionBump::Patch, ReleaseConfig::default());
+        state.current_phase = phase;
+        state.updated_at = chrono::Utc::now() - chrono::Duration::from_std(age).unwrap();
+        StateManager::with_config(path, config()).unwrap().save_state(&state).unwrap();
+    }
+
+    #[test]
+    fn test_state_round_trips_plain_and_encrypted() {
+        for passphrase in [None, Some(StatePassphrase::new("correct horse"))] {
+            let directory = tempfile::tempdir().unwrap();
+            let path = directory.path().join("release.json");
+            let config = StateConfig {
+                passphrase: passphrase.clone(),
+                ..config()
+            };
+
+            let mut state = ReleaseState::new(semver::Version::new(2, 1, 0), VersionBump::Minor, ReleaseConfig::default());
+            state.set_previous_head("abc123");
+            state.set_phase(ReleasePhase::GitOperations);
+            state.add_checkpoint("git_operations_complete".to_string(), ReleasePhase::GitOperations, None, true);
+            StateManager::with_config(&path, config.clone()).unwrap().save_state(&state).unwrap();
+
+            let contents = fs::read_to_string(&path).unwrap();
+            assert_eq!(is_encrypted(&contents), passphrase.is_some());
+
+            let loaded = StateManager::with_config(&path, config).unwrap().load_state().unwrap();
+            assert!(!loaded.recovered_from_backup);
+            assert_eq!(loaded.state.release_id, state.release_id);
+            assert_eq!(loaded.state.target_version, state.target_version);
+            assert_eq!(loaded.state.current_phase, ReleasePhase::GitOperations);
+            assert_eq!(loaded.state.checkpoints.len(), 1);
+            assert_eq!(loaded.state.git_state.and_then(|git| git.previous_head).as_deref(), Some("abc123"));
+        }
+    }
+
+    #[test]
+    fn test_corrupted_state_is_recovered_from_backup() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("release.json");
+        let state = ReleaseState::new(semver::Version::new(1, 0, 0), VersionBump::Patch, ReleaseConfig::default());
+        {
+            let mut manager = StateManager::with_config(&path, config()).unwrap();
+            manager.save_state(&state).unwrap();
+            manager.save_state(&state).unwrap();
+        }
+
+        fs::write(&path, "{ truncated").unwrap();
+
+        let loaded = StateManager::with_config(&path, config()).unwrap().load_state().unwrap();
+        assert!(loaded.recovered_from_backup);
+        assert_eq!(loaded.state.release_id, state.release_id);
+    }
+
+    #[test]
+    fn test_cleanup_removes_state_backups_and_manifest() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("release.json");
+        save_old_state(&path, ReleasePhase::Completed, Duration::ZERO);
+
+        let manager = StateManager::with_config(&path, config()).unwrap();
+        manager.create_backup().unwrap();
+        assert!(!manager.backup_paths().is_empty());
+
+        manager.cleanup_state().unwrap();
+        let remaining: Vec<_> = fs::read_dir(directory.path()).unwrap().map(|entry| entry.unwrap().path()).collect();
+        assert!(remaining.is_empty(), "{:?}", remaining);
+    }
+
+    #[test]
+    fn test_finished_release_older_than_retention_is_removed() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("release.json");
+        save_old_state(&path, ReleasePhase::Completed, 2 * WEEK);
+
+        let result = StateManager::with_config(&path, config()).unwrap().cleanup_older_than(WEEK).unwrap();
+
+        assert!(result.in_use.is_none());
+        assert!(result.removed.iter().any(|removed| removed.path == path));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_recent_finished_release_is_kept() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("release.json");
+        save_old_state(&path, ReleasePhase::RolledBack, Duration::from_secs(60));
+
+        let result = StateManager::with_config(&path, config()).unwrap().cleanup_older_than(WEEK).unwrap();
+
+        assert!(result.removed.is_empty());
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_unfinished_release_is_kept_however_old() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("release.json");
+        save_old_state(&path, ReleasePhase::Failed, 2 * WEEK);
+
+        let result = StateManager::with_config(&path, config()).unwrap().cleanup_older_than(WEEK).unwrap();
+
+        assert!(result.in_use.is_some_and(|reason| reason.contains("has not finished")));
+        assert!(result.removed.is_empty());
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_locked_state_is_kept() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("release.json");
+        save_old_state(&path, ReleasePhase::Completed, 2 * WEEK);
+
+        // Saving again takes the lock and holds it until the manager is dropped
+        let mut manager = StateManager::with_config(&path, config()).unwrap();
+        let state = manager.load_state().unwrap().state;
+        manager.save_state(&state).unwrap();
+
+        let result = manager.cleanup_older_than(WEEK).unwrap();
+
+        assert!(result.in_use.is_some_and(|reason| reason.contains("locked")));
+        assert!(result.removed.is_empty());
+        assert!(path.exists());
+    }
+}
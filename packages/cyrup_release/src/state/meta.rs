@@ -0,0 +1,241 @@
+//! Shared state of a release spanning several workspaces.
+//!
+//! Each workspace keeps its own release state while it is being released;
+//! this file, next to the meta manifest, records which workspaces are done
+//! and the versions they published, so an interrupted meta release continues
+//! with the first workspace that has not been released.
+
+use crate::error::{Result, StateError};
+use crate::version::VersionBump;
+use chrono::{DateTime, Utc};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the meta release state, next to the meta manifest
+pub const META_STATE_FILE_NAME: &str = ".cyrup_release_meta_state.json";
+
+/// Progress of a release across the workspaces of a meta-repo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaReleaseState {
+    /// Unique ID of the meta release
+    pub meta_release_id: String,
+    /// Bump applied to every workspace
+    pub version_bump: VersionBump,
+    /// When the meta release started
+    pub started_at: DateTime<Utc>,
+    /// When the state was last updated
+    pub updated_at: DateTime<Utc>,
+    /// Workspaces in release order
+    pub workspaces: Vec<MetaWorkspaceState>,
+}
+
+/// Progress of one workspace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaWorkspaceState {
+    /// Workspace name
+    pub name: String,
+    /// Workspace root
+    pub root: PathBuf,
+    /// Release status
+    pub status: MetaWorkspaceStatus,
+    /// Versions published by the workspace's release
+    pub released_versions: BTreeMap<String, Version>,
+    /// Error of the last failed attempt
+    pub error: Option<String>,
+}
+
+/// Release status of a workspace in a meta release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetaWorkspaceStatus {
+    /// Not released yet
+    Pending,
+    /// Released and published
+    Released,
+    /// The last release attempt failed
+    Failed,
+}
+
+impl MetaReleaseState {
+    /// Start a meta release over workspaces given in release order
+    pub fn new(version_bump: VersionBump, workspaces: Vec<(String, PathBuf)>) -> Self {
+        let now = Utc::now();
+        Self {
+            meta_release_id: format!("meta-{}", now.timestamp()),
+            version_bump,
+            started_at: now,
+            updated_at: now,
+            workspaces: workspaces.into_iter()
+                .map(|(name, root)| MetaWorkspaceState {
+                    name,
+                    root,
+                    status: MetaWorkspaceStatus::Pending,
+                    released_versions: BTreeMap::new(),
+                    error: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Location of the meta state for a meta manifest
+    pub fn path_for_manifest(manifest_path: &Path) -> PathBuf {
+        manifest_path.with_file_name(META_STATE_FILE_NAME)
+    }
+
+    /// Load the meta state, if a meta release is in progress
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(path)
+            .map_err(|e| StateError::LoadFailed {
+                reason: format!("Failed to read meta release state {}: {}", path.display(), e),
+            })?;
+        let state = serde_json::from_str(&contents)
+            .map_err(|e| StateError::Corrupted {
+                reason: format!("Invalid meta release state {}: {}", path.display(), e),
+            })?;
+
+        Ok(Some(state))
+    }
+
+    /// Write the meta state, replacing the previous file atomically
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let serialized = serde_json::to_string_pretty(self)
+            .map_err(|e| StateError::SaveFailed {
+                reason: format!("Failed to serialize meta release state: {}", e),
+            })?;
+
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serialized)
+            .and_then(|()| fs::rename(&temp_path, path))
+            .map_err(|e| StateError::SaveFailed {
+                reason: format!("Failed to write meta release state {}: {}", path.display(), e),
+            })?;
+
+        Ok(())
+    }
+
+    /// Mark a workspace as released with the versions it published
+    pub fn mark_released(&mut self, name: &str, versions: BTreeMap<String, Version>) {
+        if let Some(workspace) = self.workspaces.iter_mut().find(|workspace| workspace.name == name) {
+            workspace.status = MetaWorkspaceStatus::Released;
+            workspace.released_versions = versions;
+            workspace.error = None;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Mark a workspace's release as failed
+    pub fn mark_failed(&mut self, name: &str, error: String) {
+        if let Some(workspace) = self.workspaces.iter_mut().find(|workspace| workspace.name == name) {
+            workspace.status = MetaWorkspaceStatus::Failed;
+            workspace.error = Some(error);
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Check if a workspace has been released
+    pub fn is_released(&self, name: &str) -> bool {
+        self.workspaces.iter()
+            .any(|workspace| workspace.name == name && workspace.status == MetaWorkspaceStatus::Released)
+    }
+
+    /// Versions published so far, by package
+    pub fn released_versions(&self) -> BTreeMap<String, Version> {
+        self.workspaces.iter()
+            .flat_map(|workspace| workspace.released_versions.clone())
+            .collect()
+    }
+
+    /// Check if every workspace has been released
+    pub fn is_complete(&self) -> bool {
+        self.workspaces.iter().all(|workspace| workspace.status == MetaWorkspaceStatus::Released)
+    }
+
+    /// Format the progress for display
+    pub fn format_status(&self) -> String {
+        let mut output = String::new();
+        for workspace in &self.workspaces {
+            let (icon, detail) = match workspace.status {
+                MetaWorkspaceStatus::Pending => ("⏳", String::new()),
+                MetaWorkspaceStatus::Released => {
+                    let versions: Vec<String> = workspace.released_versions.iter()
+                        .map(|(package, version)| format!("{} {}", package, version))
+                        .collect();
+                    ("✅", format!(": {}", versions.join(", ")))
+                }
+                MetaWorkspaceStatus::Failed => (
+                    "❌",
+                    format!(": {}", workspace.error.as_deref().unwrap_or("unknown error")),
+                ),
+            };
+            output.push_str(&format!("  {} {}{}\n", icon, workspace.name, detail));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> MetaReleaseState {
+        MetaReleaseState::new(VersionBump::Minor, vec![
+            ("core".to_string(), PathBuf::from("/repos/core")),
+            ("apps".to_string(), PathBuf::from("/repos/apps")),
+        ])
+    }
+
+    #[test]
+    fn test_progress_across_workspaces() {
+        let mut state = state();
+        assert!(!state.is_released("core") && !state.is_complete());
+        assert_eq!(state.format_status(), "  ⏳ core\n  ⏳ apps\n");
+
+        state.mark_failed("core", "publish timed out".to_string());
+        assert_eq!(state.format_status(), "  ❌ core: publish timed out\n  ⏳ apps\n");
+
+        state.mark_released("core", BTreeMap::from([
+            ("core-io".to_string(), Version::new(0, 2, 0)),
+            ("core-types".to_string(), Version::new(0, 2, 0)),
+        ]));
+        assert!(state.is_released("core") && !state.is_complete());
+        assert_eq!(state.workspaces[0].error, None);
+        assert_eq!(state.format_status(), "  ✅ core: core-io 0.2.0, core-types 0.2.0\n  ⏳ apps\n");
+
+        // Unknown workspaces are ignored
+        state.mark_released("docs", BTreeMap::new());
+        state.mark_released("apps", BTreeMap::from([("app-cli".to_string(), Version::new(1, 1, 0))]));
+        assert!(state.is_complete());
+        assert_eq!(state.released_versions().keys().collect::<Vec<_>>(), vec!["app-cli", "core-io", "core-types"]);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = MetaReleaseState::path_for_manifest(&dir.path().join("release-meta.toml"));
+        assert_eq!(path, dir.path().join(META_STATE_FILE_NAME));
+        assert!(MetaReleaseState::load(&path).unwrap().is_none());
+
+        let mut state = state();
+        state.mark_released("core", BTreeMap::from([("core-io".to_string(), Version::new(0, 2, 0))]));
+        state.save(&path).unwrap();
+        assert!(!path.with_extension("json.tmp").exists());
+
+        let loaded = MetaReleaseState::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.meta_release_id, state.meta_release_id);
+        assert_eq!(loaded.started_at, state.started_at);
+        assert_eq!(loaded.format_status(), state.format_status());
+        assert_eq!(loaded.workspaces[1].root, PathBuf::from("/repos/apps"));
+        assert!(std::fs::read_to_string(&path).unwrap().contains("\"status\": \"released\""));
+
+        std::fs::write(&path, "{").unwrap();
+        let error = MetaReleaseState::load(&path).unwrap_err().to_string();
+        assert!(error.contains("Invalid meta release state"), "{}", error);
+    }
+}
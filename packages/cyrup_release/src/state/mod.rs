@@ -1,18 +1,32 @@
 //! State management for release operations.
 //!
 //! This module provides comprehensive state tracking and persistence for release operations,
-//! enabling resume capabilities and ensuring atomic operations.
+//! enabling resume capabilities and ensuring atomic operations. State files can
+//! optionally be encrypted with a passphrase taken from the environment.
 
 mod release_state;
 mod manager;
+mod crypto;
+mod history;
+mod meta;
+mod timeline;
+mod store;
 
 pub use release_state::{
     ReleaseState, ReleasePhase, ReleaseCheckpoint, VersionState, GitState, PublishState,
     ReleaseError, ReleaseConfig, VersionUpdateInfo, GitCommitInfo, GitTagInfo, GitPushInfo,
     PublishPackageInfo, FileBackup, STATE_FORMAT_VERSION,
 };
+pub use crypto::{StateCipher, StatePassphrase, PASSPHRASE_ENV, is_encrypted};
+pub use history::{ReleaseHistory, HISTORY_FILE_NAME};
+pub use meta::{MetaReleaseState, MetaWorkspaceState, MetaWorkspaceStatus, META_STATE_FILE_NAME};
+pub use store::{
+    open_state_store, FileStateStore, HttpStateStore, S3StateStore, StateStore, STATE_TOKEN_ENV,
+};
+pub use timeline::{ReleaseTimeline, TimelineEvent, TimelineEventKind, PhaseSpan, FailedPublish};
 pub use manager::{
     StateManager, StateConfig, LoadStateResult, SaveStateResult, StateFileInfo, FileInfo,
+    RemovedStateFile, RetentionCleanupResult,
 };
 
 use crate::error::Result;
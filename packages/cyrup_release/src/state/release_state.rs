@@ -70,6 +70,13 @@ pub enum ReleasePhase {
     RolledBack,
 }
 
+impl ReleasePhase {
+    /// Whether the release is over and can no longer be resumed or rolled back
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ReleasePhase::Completed | ReleasePhase::RolledBack)
+    }
+}
+
 /// Checkpoint in the release process
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReleaseCheckpoint {
@@ -101,20 +108,41 @@ pub struct VersionState {
 }
 
 /// Git operation state
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GitState {
     /// Previous HEAD commit before release
     pub previous_head: Option<String>,
     /// Commit created for this release
     pub release_commit: Option<GitCommitInfo>,
-    /// Tag created for this release
-    pub release_tag: Option<GitTagInfo>,
+    /// Tags created for this release (one per package in independent versioning mode)
+    #[serde(default, alias = "release_tag", deserialize_with = "deserialize_release_tags")]
+    pub release_tags: Vec<GitTagInfo>,
     /// Push information
     pub push_info: Option<GitPushInfo>,
     /// Whether git operations have been pushed to remote
     pub pushed_to_remote: bool,
 }
 
+/// Reads `release_tags`, migrating the single optional `release_tag` that state files
+/// written before independent versioning contain
+fn deserialize_release_tags<'de, D>(deserializer: D) -> std::result::Result<Vec<GitTagInfo>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tags {
+        Many(Vec<GitTagInfo>),
+        One(GitTagInfo),
+    }
+
+    Ok(match Option::<Tags>::deserialize(deserializer)? {
+        Some(Tags::Many(tags)) => tags,
+        Some(Tags::One(tag)) => vec![tag],
+        None => Vec::new(),
+    })
+}
+
 /// Publishing state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PublishState {
@@ -205,6 +233,18 @@ pub struct GitTagInfo {
     pub is_annotated: bool,
 }
 
+impl From<&TagInfo> for GitTagInfo {
+    fn from(tag: &TagInfo) -> Self {
+        Self {
+            name: tag.name.clone(),
+            message: tag.message.clone(),
+            target_commit: tag.target_commit.clone(),
+            timestamp: tag.timestamp,
+            is_annotated: tag.is_annotated,
+        }
+    }
+}
+
 /// Git push information for serialization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitPushInfo {
@@ -343,44 +383,35 @@ impl ReleaseState {
         self.updated_at = chrono::Utc::now();
     }
 
-    /// Update git state
-    pub fn set_git_state(&mut self, commit: Option<&CommitInfo>, tag: Option<&TagInfo>) {
-        if self.git_state.is_none() {
-            self.git_state = Some(GitState {
-                previous_head: None,
-                release_commit: None,
-                release_tag: None,
-                push_info: None,
-                pushed_to_remote: false,
+    /// Update git state; tags that are already recorded are not added again
+    pub fn set_git_state(&mut self, commit: Option<&CommitInfo>, tags: &[TagInfo]) {
+        let git_state = self.git_state.get_or_insert_with(GitState::default);
+        if let Some(commit) = commit {
+            git_state.release_commit = Some(GitCommitInfo {
+                hash: commit.hash.clone(),
+                short_hash: commit.short_hash.clone(),
+                message: commit.message.clone(),
+                author_name: commit.author_name.clone(),
+                author_email: commit.author_email.clone(),
+                timestamp: commit.timestamp,
             });
         }
 
-        if let Some(git_state) = &mut self.git_state {
-            if let Some(commit) = commit {
-                git_state.release_commit = Some(GitCommitInfo {
-                    hash: commit.hash.clone(),
-                    short_hash: commit.short_hash.clone(),
-                    message: commit.message.clone(),
-                    author_name: commit.author_name.clone(),
-                    author_email: commit.author_email.clone(),
-                    timestamp: commit.timestamp,
-                });
-            }
-
-            if let Some(tag) = tag {
-                git_state.release_tag = Some(GitTagInfo {
-                    name: tag.name.clone(),
-                    message: tag.message.clone(),
-                    target_commit: tag.target_commit.clone(),
-                    timestamp: tag.timestamp,
-                    is_annotated: tag.is_annotated,
-                });
+        for tag in tags {
+            if !git_state.release_tags.iter().any(|recorded| recorded.name == tag.name) {
+                git_state.release_tags.push(GitTagInfo::from(tag));
             }
         }
 
         self.updated_at = chrono::Utc::now();
     }
 
+    /// Record the HEAD commit the release was started from (for rollback)
+    pub fn set_previous_head(&mut self, commit_hash: &str) {
+        self.git_state.get_or_insert_with(GitState::default).previous_head = Some(commit_hash.to_string());
+        self.updated_at = chrono::Utc::now();
+    }
+
     /// Update git push state
     pub fn set_git_push_state(&mut self, push_info: &PushInfo) {
         if let Some(git_state) = &mut self.git_state {
@@ -602,4 +633,85 @@ impl std::fmt::Display for ReleasePhase {
             ReleasePhase::RolledBack => write!(f, "Rolled Back"),
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str) -> TagInfo {
+        TagInfo {
+            name: name.to_string(),
+            message: None,
+            target_commit: "abc123".to_string(),
+            timestamp: chrono::Utc::now(),
+            is_annotated: false,
+        }
+    }
+
+    fn tag_json(name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "message": null,
+            "target_commit": "abc123",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "is_annotated": true,
+        })
+    }
+
+    fn tag_names(state: &GitState) -> Vec<&str> {
+        state.release_tags.iter().map(|tag| tag.name.as_str()).collect()
+    }
+
+    fn git_state(release_tags: (&str, serde_json::Value)) -> GitState {
+        let mut json = serde_json::json!({
+            "previous_head": "abc123",
+            "release_commit": null,
+            "push_info": null,
+            "pushed_to_remote": false,
+        });
+        json[release_tags.0] = release_tags.1;
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_old_single_release_tag_is_migrated() {
+        let state = git_state(("release_tag", tag_json("v1.0.0")));
+        assert_eq!(tag_names(&state), vec!["v1.0.0"]);
+    }
+
+    #[test]
+    fn test_old_missing_release_tag_is_migrated() {
+        assert!(git_state(("release_tag", serde_json::Value::Null)).release_tags.is_empty());
+
+        let json = serde_json::json!({ "previous_head": null, "release_commit": null, "push_info": null, "pushed_to_remote": false });
+        let state: GitState = serde_json::from_value(json).unwrap();
+        assert!(state.release_tags.is_empty());
+    }
+
+    #[test]
+    fn test_release_tags_round_trip() {
+        let state = git_state(("release_tags", serde_json::json!([tag_json("a-v1.0.0"), tag_json("b-v2.0.0")])));
+        assert_eq!(tag_names(&state), vec!["a-v1.0.0", "b-v2.0.0"]);
+
+        let json = serde_json::to_value(&state).unwrap();
+        let round_tripped: GitState = serde_json::from_value(json).unwrap();
+        assert_eq!(tag_names(&round_tripped), vec!["a-v1.0.0", "b-v2.0.0"]);
+    }
+
+    #[test]
+    fn test_set_git_state_records_each_tag_once() {
+        let mut state = ReleaseState::new(
+            semver::Version::new(1, 0, 0),
+            VersionBump::Minor,
+            ReleaseConfig::default(),
+        );
+        let tags = [tag("a-v1.0.0"), tag("b-v1.0.0")];
+
+        // Checkpoints after each tag, then the final result with every tag
+        state.set_git_state(None, &tags[..1]);
+        state.set_git_state(None, &tags);
+        state.set_git_state(None, &tags);
+
+        assert_eq!(tag_names(state.git_state.as_ref().unwrap()), vec!["a-v1.0.0", "b-v1.0.0"]);
+    }
+}
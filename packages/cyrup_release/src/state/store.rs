@@ -0,0 +1,621 @@
+//! Shared storage for release state.
+//!
+//! The state manager always works on a local state file. When a `[state]`
+//! store is configured, every save is mirrored to it, and a machine without
+//! a local state file fetches it from the store before loading. A release
+//! interrupted on one ephemeral CI runner can then be resumed or rolled back
+//! from another. The store is not locked: two runners must not drive the same
+//! release at once.
+//!
+//! Backends:
+//! - `file`: a directory, typically a shared mount or CI cache
+//! - `http`: `GET`/`PUT`/`DELETE` below a base URL, with an optional bearer
+//!   token from `CYRUP_RELEASE_STATE_TOKEN`
+//! - `s3`: an S3 bucket, signed with `AWS_ACCESS_KEY_ID`,
+//!   `AWS_SECRET_ACCESS_KEY`, and `AWS_SESSION_TOKEN`. Google Cloud Storage
+//!   works through its S3-compatible endpoint with HMAC keys.
+
+use crate::config::{StateBackend, StateSection};
+use crate::error::{Result, StateError};
+use crate::state::manager::sha256_hex;
+use ring::hmac;
+use std::fmt::Debug;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Environment variable holding the bearer token for the `http` backend
+pub const STATE_TOKEN_ENV: &str = "CYRUP_RELEASE_STATE_TOKEN";
+
+/// Timeout for one request to a remote store
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Storage for state documents, addressed by file name
+pub trait StateStore: Debug + Send + Sync {
+    /// Human-readable location of the store
+    fn describe(&self) -> String;
+
+    /// Read a document, or `None` if it does not exist
+    fn read(&self, name: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Create or replace a document
+    fn write(&self, name: &str, contents: &[u8]) -> Result<()>;
+
+    /// Delete a document; deleting a missing document succeeds
+    fn delete(&self, name: &str) -> Result<()>;
+}
+
+/// Build the store selected by a `[state]` section (`None` for local-only state)
+pub fn open_state_store(section: &StateSection) -> Result<Option<Arc<dyn StateStore>>> {
+    let store: Arc<dyn StateStore> = match section.backend {
+        StateBackend::Local => return Ok(None),
+        StateBackend::File => {
+            let directory = section.path.clone().ok_or_else(|| missing_setting("file", "path"))?;
+            Arc::new(FileStateStore::new(directory))
+        }
+        StateBackend::Http => {
+            let url = section.url.as_deref().ok_or_else(|| missing_setting("http", "url"))?;
+            Arc::new(HttpStateStore::new(url, std::env::var(STATE_TOKEN_ENV).ok())?)
+        }
+        StateBackend::S3 => {
+            let bucket = section.bucket.clone().ok_or_else(|| missing_setting("s3", "bucket"))?;
+            Arc::new(S3StateStore::from_env(
+                bucket,
+                section.prefix.clone().unwrap_or_default(),
+                section.region.clone(),
+                section.endpoint.clone(),
+            )?)
+        }
+    };
+
+    Ok(Some(store))
+}
+
+/// State documents in a directory
+#[derive(Debug, Clone)]
+pub struct FileStateStore {
+    /// Directory holding the documents
+    directory: PathBuf,
+}
+
+impl FileStateStore {
+    /// Create a store in a directory (created on first write)
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+impl StateStore for FileStateStore {
+    fn describe(&self) -> String {
+        self.directory.display().to_string()
+    }
+
+    fn read(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        match std::fs::read(self.directory.join(name)) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(store_error(self, format!("Failed to read {}: {}", name, e))),
+        }
+    }
+
+    fn write(&self, name: &str, contents: &[u8]) -> Result<()> {
+        let path = self.directory.join(name);
+        let temp_path = self.directory.join(format!("{}.tmp", name));
+        std::fs::create_dir_all(&self.directory)
+            .and_then(|()| std::fs::write(&temp_path, contents))
+            .and_then(|()| std::fs::rename(&temp_path, &path))
+            .map_err(|e| store_error(self, format!("Failed to write {}: {}", name, e)))
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        match std::fs::remove_file(self.directory.join(name)) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(store_error(self, format!("Failed to delete {}: {}", name, e))),
+        }
+    }
+}
+
+/// State documents below a base URL
+#[derive(Debug, Clone)]
+pub struct HttpStateStore {
+    /// URL documents are stored under
+    base_url: reqwest::Url,
+    /// Bearer token sent with every request
+    token: Option<String>,
+    /// HTTP client
+    client: reqwest::Client,
+}
+
+impl HttpStateStore {
+    /// Create a store for a base URL
+    pub fn new(base_url: &str, token: Option<String>) -> Result<Self> {
+        // A trailing slash keeps the last path segment when joining document names
+        let base_url = if base_url.ends_with('/') { base_url.to_string() } else { format!("{}/", base_url) };
+        let base_url = reqwest::Url::parse(&base_url).map_err(|e| StateError::RemoteStore {
+            store: base_url.clone(),
+            reason: format!("Invalid URL: {}", e),
+        })?;
+
+        Ok(Self {
+            base_url,
+            token,
+            client: http_client()?,
+        })
+    }
+
+    /// Send a request for a document and return the response
+    fn send(&self, method: reqwest::Method, name: &str, body: Option<Vec<u8>>) -> Result<reqwest::Response> {
+        let url = self.base_url.join(name)
+            .map_err(|e| store_error(self, format!("Invalid document name '{}': {}", name, e)))?;
+
+        let mut request = self.client.request(method, url);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+
+        block_on(request.send())?.map_err(|e| store_error(self, e.to_string()))
+    }
+}
+
+impl StateStore for HttpStateStore {
+    fn describe(&self) -> String {
+        self.base_url.to_string()
+    }
+
+    fn read(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        let response = self.send(reqwest::Method::GET, name, None)?;
+        read_response(self, name, response)
+    }
+
+    fn write(&self, name: &str, contents: &[u8]) -> Result<()> {
+        let response = self.send(reqwest::Method::PUT, name, Some(contents.to_vec()))?;
+        check_response(self, name, response, false)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        let response = self.send(reqwest::Method::DELETE, name, None)?;
+        check_response(self, name, response, true)
+    }
+}
+
+/// State documents in an S3 (or S3-compatible) bucket, signed with AWS Signature Version 4
+#[derive(Debug, Clone)]
+pub struct S3StateStore {
+    /// Bucket name
+    bucket: String,
+    /// Key prefix documents are stored under
+    prefix: String,
+    /// Signing region
+    region: String,
+    /// Custom endpoint (path-style addressing); AWS virtual-hosted addressing otherwise
+    endpoint: Option<String>,
+    /// Access key ID
+    access_key_id: String,
+    /// Secret access key
+    secret_access_key: String,
+    /// Session token for temporary credentials
+    session_token: Option<String>,
+    /// HTTP client
+    client: reqwest::Client,
+}
+
+impl S3StateStore {
+    /// Create a store with credentials from the standard AWS environment variables
+    pub fn from_env(
+        bucket: String,
+        prefix: String,
+        region: Option<String>,
+        endpoint: Option<String>,
+    ) -> Result<Self> {
+        let credential = |name: &str| std::env::var(name).map_err(|_| StateError::RemoteStore {
+            store: format!("s3://{}", bucket),
+            reason: format!("{} is not set", name),
+        });
+
+        Ok(Self {
+            access_key_id: credential("AWS_ACCESS_KEY_ID")?,
+            secret_access_key: credential("AWS_SECRET_ACCESS_KEY")?,
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+            region: region
+                .or_else(|| std::env::var("AWS_REGION").ok())
+                .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+                .unwrap_or_else(|| "us-east-1".to_string()),
+            prefix: prefix.trim_matches('/').to_string(),
+            endpoint: endpoint.map(|endpoint| endpoint.trim_end_matches('/').to_string()),
+            bucket,
+            client: http_client()?,
+        })
+    }
+
+    /// Object key of a document
+    fn key(&self, name: &str) -> String {
+        if self.prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", self.prefix, name)
+        }
+    }
+
+    /// Host and canonical (encoded) path of a document
+    fn location(&self, name: &str) -> Result<(String, String, String)> {
+        let key = uri_encode(&self.key(name));
+        match &self.endpoint {
+            Some(endpoint) => {
+                let url = reqwest::Url::parse(endpoint)
+                    .map_err(|e| store_error(self, format!("Invalid endpoint '{}': {}", endpoint, e)))?;
+                let host = match (url.host_str(), url.port()) {
+                    (Some(host), Some(port)) => format!("{}:{}", host, port),
+                    (Some(host), None) => host.to_string(),
+                    (None, _) => return Err(store_error(self, format!("Endpoint '{}' has no host", endpoint))),
+                };
+                let path = format!("/{}/{}", uri_encode(&self.bucket), key);
+                Ok((format!("{}://{}{}", url.scheme(), host, path), host, path))
+            }
+            None => {
+                let host = format!("{}.s3.{}.amazonaws.com", self.bucket, self.region);
+                let path = format!("/{}", key);
+                Ok((format!("https://{}{}", host, path), host, path))
+            }
+        }
+    }
+
+    /// Send a signed request for a document and return the response
+    fn send(&self, method: reqwest::Method, name: &str, body: Vec<u8>) -> Result<reqwest::Response> {
+        let (url, host, path) = self.location(name)?;
+        let now = chrono::Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = sha256_hex(&body);
+
+        let mut headers = vec![
+            ("host", host),
+            ("x-amz-content-sha256", payload_hash.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(token) = &self.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+
+        let signed_headers = headers.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(";");
+        let canonical_headers: String = headers.iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let canonical_request = format!(
+            "{}\n{}\n\n{}\n{}\n{}",
+            method.as_str(), path, canonical_headers, signed_headers, payload_hash
+        );
+
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date, scope, sha256_hex(canonical_request.as_bytes())
+        );
+
+        let signing_key = [date.as_str(), self.region.as_str(), "s3", "aws4_request"].iter()
+            .fold(format!("AWS4{}", self.secret_access_key).into_bytes(), |key, part| hmac_sha256(&key, part.as_bytes()));
+        let signature = hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+        let mut request = self.client.request(method, &url)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                    self.access_key_id, scope, signed_headers, signature
+                ),
+            );
+        for (name, value) in headers.into_iter().filter(|(name, _)| *name != "host") {
+            request = request.header(name, value);
+        }
+        if !body.is_empty() {
+            request = request.body(body);
+        }
+
+        block_on(request.send())?.map_err(|e| store_error(self, e.to_string()))
+    }
+}
+
+impl StateStore for S3StateStore {
+    fn describe(&self) -> String {
+        match &self.endpoint {
+            Some(endpoint) => format!("{}/{}/{}", endpoint, self.bucket, self.prefix),
+            None => format!("s3://{}/{}", self.bucket, self.prefix),
+        }
+    }
+
+    fn read(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        let response = self.send(reqwest::Method::GET, name, Vec::new())?;
+        read_response(self, name, response)
+    }
+
+    fn write(&self, name: &str, contents: &[u8]) -> Result<()> {
+        let response = self.send(reqwest::Method::PUT, name, contents.to_vec())?;
+        check_response(self, name, response, false)
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        let response = self.send(reqwest::Method::DELETE, name, Vec::new())?;
+        check_response(self, name, response, true)
+    }
+}
+
+/// Client shared by the remote backends
+fn http_client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(concat!("cyrup_release/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| StateError::RemoteStore {
+            store: "http".to_string(),
+            reason: format!("Failed to create HTTP client: {}", e),
+        }.into())
+}
+
+/// Body of a successful read, or `None` for a missing document
+fn read_response(store: &dyn StateStore, name: &str, response: reqwest::Response) -> Result<Option<Vec<u8>>> {
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(store_error(store, format!("Reading {} returned {}", name, response.status())));
+    }
+
+    let body = block_on(response.bytes())?
+        .map_err(|e| store_error(store, format!("Failed to read {}: {}", name, e)))?;
+    Ok(Some(body.to_vec()))
+}
+
+/// Fail unless a write or delete succeeded
+fn check_response(store: &dyn StateStore, name: &str, response: reqwest::Response, allow_missing: bool) -> Result<()> {
+    let status = response.status();
+    if status.is_success() || (allow_missing && status == reqwest::StatusCode::NOT_FOUND) {
+        Ok(())
+    } else {
+        Err(store_error(store, format!("Request for {} returned {}", name, status)))
+    }
+}
+
+/// Run a future to completion from synchronous state manager code.
+///
+/// Inside a multi-threaded runtime the current worker is handed over to
+/// blocking; outside any runtime a temporary one is started.
+fn block_on<F: Future>(future: F) -> Result<F::Output> {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            Ok(tokio::task::block_in_place(|| handle.block_on(future)))
+        }
+        Ok(_) => Err(StateError::RemoteStore {
+            store: "remote".to_string(),
+            reason: "Remote state stores require a multi-threaded runtime".to_string(),
+        }.into()),
+        Err(_) => {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| StateError::RemoteStore {
+                    store: "remote".to_string(),
+                    reason: format!("Failed to start runtime: {}", e),
+                })?;
+            Ok(runtime.block_on(future))
+        }
+    }
+}
+
+/// HMAC-SHA256 of `data`
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, key), data).as_ref().to_vec()
+}
+
+/// Lowercase hex encoding
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Percent-encode everything but unreserved characters and `/`, as SigV4 requires
+fn uri_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Error for a failed store operation
+fn store_error(store: &dyn StateStore, reason: String) -> crate::error::ReleaseError {
+    StateError::RemoteStore {
+        store: store.describe(),
+        reason,
+    }.into()
+}
+
+/// Error for a backend missing a required `[state]` setting
+fn missing_setting(backend: &str, key: &str) -> crate::error::ReleaseError {
+    crate::error::ConfigError::InvalidValue {
+        key: format!("state.{}", key),
+        reason: format!("required by the '{}' backend", backend),
+    }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Documents held by `serve`, with the last `Authorization` header seen
+    #[derive(Debug, Default)]
+    struct Served {
+        documents: HashMap<String, Vec<u8>>,
+        authorization: Option<String>,
+    }
+
+    /// Minimal HTTP document server answering one request per connection
+    async fn serve() -> (String, Arc<Mutex<Served>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/state", listener.local_addr().unwrap());
+        let served = Arc::new(Mutex::new(Served::default()));
+        let state = served.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buffer = [0; 4096];
+                let header_end = loop {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    if let Some(end) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+                        break end + 4;
+                    }
+                };
+                let head = String::from_utf8_lossy(&request[..header_end]).to_string();
+                let header = |name: &str| head.lines()
+                    .find_map(|line| line.split_once(": ").filter(|(key, _)| key.eq_ignore_ascii_case(name)))
+                    .map(|(_, value)| value.to_string());
+                let length: usize = header("content-length").map_or(0, |length| length.parse().unwrap());
+                while request.len() < header_end + length {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let body = request[header_end..].to_vec();
+
+                let mut parts = head.split_whitespace();
+                let (method, path) = (parts.next().unwrap().to_string(), parts.next().unwrap().to_string());
+                let (status, response) = {
+                    let mut served = state.lock().unwrap();
+                    served.authorization = header("authorization");
+                    match method.as_str() {
+                        "GET" => served.documents.get(&path).map_or(("404 Not Found", Vec::new()), |body| ("200 OK", body.clone())),
+                        "PUT" => {
+                            served.documents.insert(path, body);
+                            ("201 Created", Vec::new())
+                        }
+                        _ => match served.documents.remove(&path) {
+                            Some(_) => ("204 No Content", Vec::new()),
+                            None => ("404 Not Found", Vec::new()),
+                        },
+                    }
+                };
+                let head = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, response.len());
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(&response).await.unwrap();
+            }
+        });
+        (url, served)
+    }
+
+    fn s3_store(endpoint: Option<&str>, prefix: &str) -> S3StateStore {
+        S3StateStore {
+            bucket: "releases".to_string(),
+            prefix: prefix.to_string(),
+            region: "eu-west-1".to_string(),
+            endpoint: endpoint.map(str::to_string),
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "secret".to_string(),
+            session_token: None,
+            client: http_client().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_file_store_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileStateStore::new(dir.path().join("shared"));
+        assert_eq!(store.read("state.json").unwrap(), None);
+        // Deleting a missing document succeeds, even before the directory exists
+        store.delete("state.json").unwrap();
+
+        store.write("state.json", b"{\"v\":1}").unwrap();
+        store.write("state.json", b"{\"v\":2}").unwrap();
+        assert_eq!(store.read("state.json").unwrap(), Some(b"{\"v\":2}".to_vec()));
+        assert!(!dir.path().join("shared/state.json.tmp").exists());
+
+        store.delete("state.json").unwrap();
+        assert_eq!(store.read("state.json").unwrap(), None);
+    }
+
+    #[test]
+    fn test_open_state_store_requires_backend_settings() {
+        assert!(open_state_store(&StateSection::default()).unwrap().is_none());
+
+        let missing = |backend| open_state_store(&StateSection { backend, ..StateSection::default() }).unwrap_err().to_string();
+        assert!(missing(StateBackend::File).contains("state.path"));
+        assert!(missing(StateBackend::Http).contains("state.url"));
+        assert!(missing(StateBackend::S3).contains("state.bucket"));
+
+        let store = open_state_store(&StateSection {
+            backend: StateBackend::File,
+            path: Some(PathBuf::from("/mnt/cache/release")),
+            ..StateSection::default()
+        }).unwrap().unwrap();
+        assert_eq!(store.describe(), "/mnt/cache/release");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_http_store_round_trip() {
+        let (url, served) = serve().await;
+        let store = HttpStateStore::new(&url, Some("s3cret".to_string())).unwrap();
+        assert_eq!(store.describe(), format!("{}/", url));
+
+        assert_eq!(store.read("state.json").unwrap(), None);
+        store.write("state.json", b"{\"v\":1}").unwrap();
+        assert_eq!(served.lock().unwrap().documents.get("/state/state.json"), Some(&b"{\"v\":1}".to_vec()));
+        assert_eq!(served.lock().unwrap().authorization.as_deref(), Some("Bearer s3cret"));
+        assert_eq!(store.read("state.json").unwrap(), Some(b"{\"v\":1}".to_vec()));
+
+        store.delete("state.json").unwrap();
+        // A 404 on delete means the document is already gone
+        store.delete("state.json").unwrap();
+        assert_eq!(store.read("state.json").unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_remote_store_needs_multi_threaded_runtime() {
+        let store = HttpStateStore::new("http://127.0.0.1:9/state", None).unwrap();
+        let error = store.read("state.json").unwrap_err().to_string();
+        assert!(error.contains("multi-threaded runtime"), "{}", error);
+    }
+
+    #[test]
+    fn test_invalid_http_url() {
+        let error = HttpStateStore::new("not a url", None).unwrap_err().to_string();
+        assert!(error.contains("Invalid URL"), "{}", error);
+    }
+
+    #[test]
+    fn test_s3_locations() {
+        let store = s3_store(None, "acme/sugars");
+        assert_eq!(store.key("state.json"), "acme/sugars/state.json");
+        assert_eq!(store.describe(), "s3://releases/acme/sugars");
+        assert_eq!(store.location("state file.json").unwrap(), (
+            "https://releases.s3.eu-west-1.amazonaws.com/acme/sugars/state%20file.json".to_string(),
+            "releases.s3.eu-west-1.amazonaws.com".to_string(),
+            "/acme/sugars/state%20file.json".to_string(),
+        ));
+
+        let store = s3_store(Some("http://localhost:9000"), "");
+        assert_eq!(store.key("state.json"), "state.json");
+        assert_eq!(store.location("state.json").unwrap(), (
+            "http://localhost:9000/releases/state.json".to_string(),
+            "localhost:9000".to_string(),
+            "/releases/state.json".to_string(),
+        ));
+        assert!(s3_store(Some("not a url"), "").location("state.json").is_err());
+    }
+
+    #[test]
+    fn test_signing_helpers() {
+        // RFC 4231, test case 2
+        assert_eq!(
+            hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(uri_encode("a-b_c.d~e/f g+h"), "a-b_c.d~e/f%20g%2Bh");
+    }
+}
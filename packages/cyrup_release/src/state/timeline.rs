@@ -0,0 +1,432 @@
+//! Timeline view of a release state.
+//!
+//! Checkpoints are recorded when a phase finishes, so each phase is taken to
+//! run from the previous checkpoint to its own. Errors and per-package
+//! publish times are interleaved with the checkpoints in time order.
+
+use crate::state::{ReleasePhase, ReleaseState};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Chronological history of one release
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseTimeline {
+    /// Release identifier
+    pub release_id: String,
+    /// Version being released
+    pub target_version: semver::Version,
+    /// When the release started
+    pub started_at: DateTime<Utc>,
+    /// When the state was last updated
+    pub updated_at: DateTime<Utc>,
+    /// Phase the release is in
+    pub current_phase: ReleasePhase,
+    /// Events in time order
+    pub events: Vec<TimelineEvent>,
+    /// Time spent in each phase, in order
+    pub phases: Vec<PhaseSpan>,
+    /// Packages that failed to publish, with their errors
+    pub failed_packages: Vec<FailedPublish>,
+}
+
+/// Something that happened during a release
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEvent {
+    /// When it happened
+    pub timestamp: DateTime<Utc>,
+    /// Milliseconds since the release started
+    pub offset_ms: i64,
+    /// What happened
+    #[serde(flatten)]
+    pub kind: TimelineEventKind,
+}
+
+/// Kind of timeline event
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimelineEventKind {
+    /// The release started
+    Started,
+    /// A checkpoint was reached
+    Checkpoint {
+        /// Checkpoint name
+        name: String,
+        /// Phase the checkpoint completes
+        phase: ReleasePhase,
+    },
+    /// A package was published
+    Published {
+        /// Package name
+        package: String,
+        /// Published version
+        version: semver::Version,
+        /// Time spent publishing, in milliseconds
+        duration_ms: u64,
+        /// Retries needed before the publish succeeded
+        retry_attempts: usize,
+    },
+    /// An error was recorded
+    Error {
+        /// Error message
+        message: String,
+        /// Phase the error occurred in
+        phase: ReleasePhase,
+        /// Whether the release can continue past it
+        recoverable: bool,
+    },
+}
+
+/// Time spent in one phase
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseSpan {
+    /// The phase
+    pub phase: ReleasePhase,
+    /// When the phase started
+    pub started_at: DateTime<Utc>,
+    /// When the phase finished, or the last state update if still running
+    pub ended_at: DateTime<Utc>,
+    /// Duration in milliseconds
+    pub duration_ms: i64,
+    /// Whether the phase has not finished
+    pub in_progress: bool,
+}
+
+/// A package that failed to publish
+#[derive(Debug, Clone, Serialize)]
+pub struct FailedPublish {
+    /// Package name
+    pub package: String,
+    /// Publish error
+    pub error: String,
+}
+
+impl ReleaseTimeline {
+    /// Build the timeline of a release state
+    pub fn from_state(state: &ReleaseState) -> Self {
+        let event = |timestamp: DateTime<Utc>, kind| TimelineEvent {
+            timestamp,
+            offset_ms: (timestamp - state.started_at).num_milliseconds(),
+            kind,
+        };
+
+        let mut events = vec![event(state.started_at, TimelineEventKind::Started)];
+        events.extend(state.checkpoints.iter().map(|checkpoint| event(
+            checkpoint.timestamp,
+            TimelineEventKind::Checkpoint {
+                name: checkpoint.name.clone(),
+                phase: checkpoint.phase,
+            },
+        )));
+        events.extend(state.errors.iter().map(|error| event(
+            error.timestamp,
+            TimelineEventKind::Error {
+                message: error.message.clone(),
+                phase: error.phase,
+                recoverable: error.recoverable,
+            },
+        )));
+
+        let mut failed_packages = Vec::new();
+        if let Some(publish_state) = &state.publish_state {
+            events.extend(publish_state.published_packages.values().map(|package| event(
+                package.published_at,
+                TimelineEventKind::Published {
+                    package: package.package_name.clone(),
+                    version: package.version.clone(),
+                    duration_ms: package.duration_ms,
+                    retry_attempts: package.retry_attempts,
+                },
+            )));
+            failed_packages = publish_state.failed_packages.iter()
+                .map(|(package, error)| FailedPublish {
+                    package: package.clone(),
+                    error: error.clone(),
+                })
+                .collect();
+            failed_packages.sort_by(|a, b| a.package.cmp(&b.package));
+        }
+
+        // Stable sort keeps checkpoints ahead of errors and publishes recorded at the same instant
+        events.sort_by_key(|event| event.timestamp);
+
+        Self {
+            release_id: state.release_id.clone(),
+            target_version: state.target_version.clone(),
+            started_at: state.started_at,
+            updated_at: state.updated_at,
+            current_phase: state.current_phase,
+            phases: phase_spans(state),
+            events,
+            failed_packages,
+        }
+    }
+
+    /// Format the timeline for display
+    pub fn format_timeline(&self) -> String {
+        let mut output = format!(
+            "🕒 Release v{} timeline ({})\n",
+            self.target_version, self.release_id
+        );
+
+        for event in &self.events {
+            let offset = format!("+{}", format_millis(event.offset_ms));
+            let description = match &event.kind {
+                TimelineEventKind::Started => "▶ Release started".to_string(),
+                TimelineEventKind::Checkpoint { name, phase } => format!("✓ {} ({})", name, phase),
+                TimelineEventKind::Published { package, version, duration_ms, retry_attempts } => {
+                    let mut line = format!(
+                        "📦 Published {} {} in {}",
+                        package,
+                        version,
+                        format_millis(*duration_ms as i64)
+                    );
+                    match retry_attempts {
+                        0 => {}
+                        1 => line.push_str(" after 1 retry"),
+                        retries => line.push_str(&format!(" after {} retries", retries)),
+                    }
+                    line
+                }
+                TimelineEventKind::Error { message, phase, recoverable } => {
+                    let severity = if *recoverable { "recoverable" } else { "critical" };
+                    format!("❌ {} ({}, {})", message, phase, severity)
+                }
+            };
+            output.push_str(&format!(
+                "  {:>9}  {}  {}\n",
+                offset,
+                event.timestamp.format("%H:%M:%S"),
+                description
+            ));
+        }
+
+        if !self.phases.is_empty() {
+            output.push_str("\nPhase durations:\n");
+            for span in &self.phases {
+                let status = if span.in_progress { " (in progress)" } else { "" };
+                output.push_str(&format!(
+                    "  {:<16} {:>9}{}\n",
+                    span.phase.to_string(),
+                    format_millis(span.duration_ms),
+                    status
+                ));
+            }
+        }
+
+        if !self.failed_packages.is_empty() {
+            output.push_str("\nFailed packages:\n");
+            for failed in &self.failed_packages {
+                output.push_str(&format!("  ❌ {}: {}\n", failed.package, failed.error));
+            }
+        }
+
+        output
+    }
+}
+
+/// Split the release into phases ending at each checkpoint
+fn phase_spans(state: &ReleaseState) -> Vec<PhaseSpan> {
+    let mut checkpoints: Vec<_> = state.checkpoints.iter().collect();
+    checkpoints.sort_by_key(|checkpoint| checkpoint.timestamp);
+
+    let mut spans: Vec<PhaseSpan> = Vec::new();
+    let mut previous_end = state.started_at;
+    for checkpoint in checkpoints {
+        match spans.last_mut() {
+            Some(span) if span.phase == checkpoint.phase => span.ended_at = checkpoint.timestamp,
+            _ => spans.push(PhaseSpan {
+                phase: checkpoint.phase,
+                started_at: previous_end,
+                ended_at: checkpoint.timestamp,
+                duration_ms: 0,
+                in_progress: false,
+            }),
+        }
+        previous_end = checkpoint.timestamp;
+    }
+
+    // The current phase has no checkpoint until it finishes
+    let finished = matches!(state.current_phase, ReleasePhase::Completed | ReleasePhase::RolledBack);
+    if !finished && spans.last().is_none_or(|span| span.phase != state.current_phase) {
+        spans.push(PhaseSpan {
+            phase: state.current_phase,
+            started_at: previous_end,
+            ended_at: state.updated_at.max(previous_end),
+            duration_ms: 0,
+            in_progress: true,
+        });
+    }
+
+    for span in &mut spans {
+        span.duration_ms = (span.ended_at - span.started_at).num_milliseconds();
+    }
+    spans
+}
+
+/// Format milliseconds as `850ms`, `2.3s`, or `1m 05s`
+fn format_millis(millis: i64) -> String {
+    match millis {
+        millis if millis < 1000 => format!("{}ms", millis),
+        millis if millis < 60_000 => format!("{:.1}s", millis as f64 / 1000.0),
+        millis => format!("{}m {:02}s", millis / 60_000, (millis % 60_000) / 1000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PublishPackageInfo, PublishState, ReleaseCheckpoint, ReleaseConfig};
+    use crate::version::VersionBump;
+    use chrono::{Duration, TimeZone};
+    use std::collections::HashMap;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap()
+    }
+
+    fn at(millis: i64) -> DateTime<Utc> {
+        start() + Duration::milliseconds(millis)
+    }
+
+    fn checkpoint(name: &str, phase: ReleasePhase, millis: i64) -> ReleaseCheckpoint {
+        ReleaseCheckpoint {
+            name: name.to_string(),
+            phase,
+            timestamp: at(millis),
+            data: None,
+            rollback_capable: false,
+        }
+    }
+
+    /// A release publishing its second package, 95 seconds in
+    fn publishing_release() -> ReleaseState {
+        let mut state = ReleaseState::new(semver::Version::new(2, 0, 0), VersionBump::Major, ReleaseConfig::default());
+        state.release_id = "release-2.0.0".to_string();
+        state.started_at = start();
+        state.current_phase = ReleasePhase::Publishing;
+        // Recorded out of order, and validation twice (e.g. after a resume)
+        state.checkpoints = vec![
+            checkpoint("versions_updated", ReleasePhase::VersionUpdate, 2_300),
+            checkpoint("validated", ReleasePhase::Validation, 400),
+            checkpoint("revalidated", ReleasePhase::Validation, 850),
+            checkpoint("tagged", ReleasePhase::GitOperations, 5_000),
+            checkpoint("alpha_published", ReleasePhase::Publishing, 65_000),
+        ];
+        state.add_error("crates.io returned 429".to_string(), ReleasePhase::Publishing, true, None);
+        state.errors[0].timestamp = at(65_000);
+        state.publish_state = Some(PublishState {
+            published_packages: HashMap::from([("alpha".to_string(), PublishPackageInfo {
+                package_name: "alpha".to_string(),
+                version: semver::Version::new(2, 0, 0),
+                duration_ms: 60_000,
+                retry_attempts: 2,
+                warnings: Vec::new(),
+                published_at: at(65_000),
+            })]),
+            failed_packages: HashMap::from([
+                ("gamma".to_string(), "timed out".to_string()),
+                ("beta".to_string(), "rate limited".to_string()),
+            ]),
+            current_tier: 1,
+            total_tiers: 2,
+            publishing_started_at: Some(at(5_000)),
+        });
+        state.updated_at = at(95_000);
+        state
+    }
+
+    #[test]
+    fn test_events_are_in_time_order() {
+        let timeline = ReleaseTimeline::from_state(&publishing_release());
+        let events: Vec<(i64, String)> = timeline.events.iter()
+            .map(|event| (event.offset_ms, match &event.kind {
+                TimelineEventKind::Started => "started".to_string(),
+                TimelineEventKind::Checkpoint { name, .. } => name.clone(),
+                TimelineEventKind::Published { package, .. } => format!("published {}", package),
+                TimelineEventKind::Error { message, .. } => message.clone(),
+            }))
+            .collect();
+        assert_eq!(events, vec![
+            (0, "started".to_string()),
+            (400, "validated".to_string()),
+            (850, "revalidated".to_string()),
+            (2_300, "versions_updated".to_string()),
+            (5_000, "tagged".to_string()),
+            // Checkpoints come before errors and publishes at the same instant
+            (65_000, "alpha_published".to_string()),
+            (65_000, "crates.io returned 429".to_string()),
+            (65_000, "published alpha".to_string()),
+        ]);
+        let failed: Vec<_> = timeline.failed_packages.iter().map(|failed| failed.package.as_str()).collect();
+        assert_eq!(failed, vec!["beta", "gamma"]);
+    }
+
+    #[test]
+    fn test_phase_spans() {
+        let spans: Vec<_> = ReleaseTimeline::from_state(&publishing_release()).phases.iter()
+            .map(|span| (span.phase, span.duration_ms, span.in_progress))
+            .collect();
+        assert_eq!(spans, vec![
+            (ReleasePhase::Validation, 850, false),
+            (ReleasePhase::VersionUpdate, 1_450, false),
+            (ReleasePhase::GitOperations, 2_700, false),
+            (ReleasePhase::Publishing, 60_000, false),
+        ]);
+
+        // A phase without a checkpoint yet runs until the last update
+        let mut state = publishing_release();
+        state.checkpoints.retain(|checkpoint| checkpoint.phase != ReleasePhase::Publishing);
+        let last = ReleaseTimeline::from_state(&state).phases.pop().unwrap();
+        assert_eq!((last.phase, last.started_at, last.duration_ms, last.in_progress), (ReleasePhase::Publishing, at(5_000), 90_000, true));
+
+        // Finished releases have no open phase
+        state.current_phase = ReleasePhase::Completed;
+        assert_eq!(ReleaseTimeline::from_state(&state).phases.last().unwrap().phase, ReleasePhase::GitOperations);
+    }
+
+    #[test]
+    fn test_format_timeline() {
+        let mut state = publishing_release();
+        state.current_phase = ReleasePhase::Failed;
+        let output = ReleaseTimeline::from_state(&state).format_timeline();
+        assert_eq!(output, "\
+🕒 Release v2.0.0 timeline (release-2.0.0)
+       +0ms  12:00:00  ▶ Release started
+     +400ms  12:00:00  ✓ validated (Validation)
+     +850ms  12:00:00  ✓ revalidated (Validation)
+      +2.3s  12:00:02  ✓ versions_updated (Version Update)
+      +5.0s  12:00:05  ✓ tagged (Git Operations)
+    +1m 05s  12:01:05  ✓ alpha_published (Publishing)
+    +1m 05s  12:01:05  ❌ crates.io returned 429 (Publishing, recoverable)
+    +1m 05s  12:01:05  📦 Published alpha 2.0.0 in 1m 00s after 2 retries
+
+Phase durations:
+  Validation           850ms
+  Version Update        1.4s
+  Git Operations        2.7s
+  Publishing          1m 00s
+  Failed               30.0s (in progress)
+
+Failed packages:
+  ❌ beta: rate limited
+  ❌ gamma: timed out
+");
+    }
+
+    #[test]
+    fn test_json_events_are_tagged() {
+        let json = serde_json::to_value(ReleaseTimeline::from_state(&publishing_release())).unwrap();
+        assert_eq!(json["events"][0], serde_json::json!({ "timestamp": "2026-10-16T12:00:00Z", "offset_ms": 0, "type": "started" }));
+        assert_eq!(json["events"][7]["type"], "published");
+        assert_eq!(json["events"][7]["retry_attempts"], 2);
+    }
+
+    #[test]
+    fn test_format_millis() {
+        assert_eq!(format_millis(999), "999ms");
+        assert_eq!(format_millis(1_000), "1.0s");
+        assert_eq!(format_millis(59_949), "59.9s");
+        assert_eq!(format_millis(60_000), "1m 00s");
+        assert_eq!(format_millis(3_725_000), "62m 05s");
+    }
+}
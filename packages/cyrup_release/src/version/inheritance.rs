@@ -0,0 +1,417 @@
+//! Migration of member manifests to workspace inheritance.
+//!
+//! Members whose version matches `[workspace.package]` switch to
+//! `version.workspace = true`, and shared metadata keys are inherited where
+//! the member's value equals the workspace one. A key missing from
+//! `[workspace.package]` is moved there when every member sets it to the
+//! same value. Edits go through `TomlEditor`, so comments and formatting
+//! are kept.
+
+use crate::error::{Result, VersionError};
+use crate::version::{TomlBackup, TomlEditor};
+use crate::workspace::WorkspaceInfo;
+use semver::Version;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::path::{Path, PathBuf};
+
+/// `[package]` keys that are usually the same across a workspace
+pub const SHARED_METADATA_KEYS: &[&str] = &["edition", "rust-version", "authors", "license", "repository", "homepage"];
+
+/// Planned rewrite of a workspace to inherited versions and metadata
+#[derive(Debug, Clone)]
+pub struct InheritanceMigration {
+    /// Version members inherit
+    pub workspace_version: Version,
+    /// Manifests that change, root first
+    pub changes: Vec<ManifestChange>,
+    /// Packages left with an explicit version, and why
+    pub skipped: Vec<SkippedPackage>,
+}
+
+/// Rewrite of one manifest
+#[derive(Debug, Clone)]
+pub struct ManifestChange {
+    /// Manifest path
+    pub path: PathBuf,
+    /// Keys now inherited (members) or moved into `[workspace.package]` (root)
+    pub keys: Vec<String>,
+    /// Content before the migration
+    pub original: String,
+    /// Content after the migration
+    pub migrated: String,
+}
+
+/// A package whose version cannot be inherited
+#[derive(Debug, Clone)]
+pub struct SkippedPackage {
+    /// Package name
+    pub package: String,
+    /// Why its version stays explicit
+    pub reason: String,
+}
+
+/// An open manifest and the keys changed in it
+struct PendingEdit {
+    editor: TomlEditor,
+    keys: Vec<String>,
+}
+
+impl InheritanceMigration {
+    /// Work out the changes without touching any file
+    pub fn plan(workspace: &WorkspaceInfo) -> Result<Self> {
+        let mut package_names = workspace.package_names();
+        package_names.sort();
+
+        let mut members = Vec::new();
+        for package_name in &package_names {
+            let package_info = workspace.get_package(package_name)?;
+            let fields = toml::Value::try_from(&package_info.config)
+                .map_err(|e| migration_error(format!("Failed to read '{}' metadata: {}", package_name, e)))?;
+            members.push((package_info, fields));
+        }
+
+        let workspace_fields = match &workspace.workspace_config.package {
+            Some(package) => toml::Value::try_from(package)
+                .map_err(|e| migration_error(format!("Failed to read [workspace.package]: {}", e)))?,
+            None => toml::Value::Table(toml::map::Map::new()),
+        };
+
+        let root_path = workspace.root.join("Cargo.toml");
+        let mut edits: BTreeMap<PathBuf, PendingEdit> = BTreeMap::new();
+        edits.insert(root_path.clone(), PendingEdit {
+            editor: TomlEditor::open(&root_path)?,
+            keys: Vec::new(),
+        });
+
+        // Every explicit member value of a key, if they all agree
+        let shared_value = |key: &str| -> Option<toml::Value> {
+            let mut values = members.iter().map(|(_, fields)| fields.get(key));
+            let first = values.next()??;
+            (!is_inherited(first) && values.all(|value| value == Some(first))).then(|| first.clone())
+        };
+
+        // Version members inherit, adding it to the workspace when they already agree on one
+        let workspace_version = match workspace_fields.get("version").and_then(toml::Value::as_str) {
+            Some(version) => version.to_string(),
+            None => {
+                let version = shared_value("version")
+                    .and_then(|version| version.as_str().map(str::to_string))
+                    .ok_or_else(|| migration_error(
+                        "[workspace.package] has no version and members do not share one".to_string(),
+                    ))?;
+                hoist(&mut edits, &root_path, "version", &toml::Value::String(version.clone()))?;
+                version
+            }
+        };
+        let workspace_version = Version::parse(&workspace_version)
+            .map_err(|e| VersionError::ParseFailed {
+                version: workspace_version.clone(),
+                source: e,
+            })?;
+
+        let mut targets = BTreeMap::new();
+        for key in SHARED_METADATA_KEYS {
+            match workspace_fields.get(*key) {
+                Some(value) => {
+                    targets.insert(*key, value.clone());
+                }
+                None => {
+                    if let Some(value) = shared_value(key) {
+                        hoist(&mut edits, &root_path, key, &value)?;
+                        targets.insert(*key, value);
+                    }
+                }
+            }
+        }
+
+        let mut skipped = Vec::new();
+        for (package_info, fields) in &members {
+            let edit = match edits.entry(package_info.cargo_toml_path.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let editor = TomlEditor::open(entry.key())?;
+                    entry.insert(PendingEdit {
+                        editor,
+                        keys: Vec::new(),
+                    })
+                }
+            };
+
+            match fields.get("version") {
+                Some(version) if is_inherited(version) => {}
+                _ if package_info.version == workspace_version.to_string() => {
+                    edit.editor.set_inherited_package_field("version")?;
+                    edit.keys.push("version".to_string());
+                }
+                _ => skipped.push(SkippedPackage {
+                    package: package_info.name.clone(),
+                    reason: format!(
+                        "version {} differs from workspace version {}",
+                        package_info.version, workspace_version
+                    ),
+                }),
+            }
+
+            for (key, target) in &targets {
+                if fields.get(*key) == Some(target) {
+                    edit.editor.set_inherited_package_field(key)?;
+                    edit.keys.push(key.to_string());
+                }
+            }
+        }
+
+        // Root first, then members in path order
+        let root = edits.remove(&root_path);
+        let changes = root.into_iter()
+            .map(|edit| (root_path.clone(), edit))
+            .chain(edits)
+            .filter(|(_, edit)| edit.editor.is_modified())
+            .map(|(path, edit)| ManifestChange {
+                path,
+                keys: edit.keys,
+                original: edit.editor.create_backup().content,
+                migrated: edit.editor.preview(),
+            })
+            .collect();
+
+        Ok(Self {
+            workspace_version,
+            changes,
+            skipped,
+        })
+    }
+
+    /// Check if nothing would change
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Write every migrated manifest, restoring the originals if any write fails
+    pub fn apply(&self) -> Result<()> {
+        let mut written: Vec<TomlBackup> = Vec::new();
+        for change in &self.changes {
+            if let Err(e) = std::fs::write(&change.path, &change.migrated) {
+                for backup in &written {
+                    TomlEditor::restore_from_backup(backup)?;
+                }
+                return Err(VersionError::TomlUpdateFailed {
+                    path: change.path.clone(),
+                    reason: format!("Failed to write file: {}", e),
+                }.into());
+            }
+            written.push(TomlBackup {
+                file_path: change.path.clone(),
+                content: change.original.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Format the changes as a line diff per manifest
+    pub fn format_diff(&self) -> String {
+        let mut output = String::new();
+        for change in &self.changes {
+            output.push_str(&format!("--- {}\n+++ {}\n", change.path.display(), change.path.display()));
+            output.push_str(&line_diff(&change.original, &change.migrated));
+        }
+        output
+    }
+}
+
+impl ManifestChange {
+    /// Format the change for display
+    pub fn format_change(&self) -> String {
+        format!("{}: {}", self.path.display(), self.keys.join(", "))
+    }
+}
+
+/// Add a key to `[workspace.package]` in the root manifest
+fn hoist(edits: &mut BTreeMap<PathBuf, PendingEdit>, root_path: &Path, key: &str, value: &toml::Value) -> Result<()> {
+    let Some(root) = edits.get_mut(root_path) else {
+        return Ok(());
+    };
+    let value = value.to_string().parse::<toml_edit::Value>()
+        .map_err(|e| migration_error(format!("Failed to convert '{}' for [workspace.package]: {}", key, e)))?;
+    root.editor.set_workspace_package_field(key, value)?;
+    root.keys.push(key.to_string());
+    Ok(())
+}
+
+/// Whether a `[package]` value is `{ workspace = true }`
+fn is_inherited(value: &toml::Value) -> bool {
+    value.get("workspace").and_then(toml::Value::as_bool) == Some(true)
+}
+
+/// Changed lines with one line of context; removed and context lines carry their original line number
+fn line_diff(original: &str, migrated: &str) -> String {
+    let old: Vec<&str> = original.lines().collect();
+    let new: Vec<&str> = migrated.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    // (line in original, marker, text)
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((i + 1, ' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            lines.push((i + 1, '-', old[i]));
+            i += 1;
+        } else {
+            lines.push((i + 1, '+', new[j]));
+            j += 1;
+        }
+    }
+
+    let changed = |index: usize| lines.get(index).is_some_and(|(_, marker, _)| *marker != ' ');
+    let mut output = String::new();
+    let mut last_shown = None;
+    for (index, (line, marker, text)) in lines.iter().enumerate() {
+        let near_change = changed(index) || changed(index + 1) || (index > 0 && changed(index - 1));
+        if !near_change {
+            continue;
+        }
+        if last_shown.is_some_and(|last: usize| last + 1 != index) {
+            output.push_str("  ...\n");
+        }
+        let number = if *marker == '+' { String::new() } else { line.to_string() };
+        output.push_str(&format!("{:>5} {} {}\n", number, marker, text));
+        last_shown = Some(index);
+    }
+    output
+}
+
+/// Create a migration error
+fn migration_error(reason: String) -> crate::error::ReleaseError {
+    VersionError::InheritanceMigrationFailed { reason }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes a workspace of `(name, [package] body)` members under a root `[workspace.package]`
+    fn workspace(workspace_package: &str, members: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let names: Vec<String> = members.iter().map(|(name, _)| format!("\"{}\"", name)).collect();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            format!("[workspace]\nmembers = [{}]\n\n[workspace.package]\n{}", names.join(", "), workspace_package),
+        ).unwrap();
+        for (name, package) in members {
+            let member = dir.path().join(name);
+            std::fs::create_dir_all(member.join("src")).unwrap();
+            std::fs::write(member.join("src/lib.rs"), "").unwrap();
+            std::fs::write(member.join("Cargo.toml"), format!("[package]\nname = \"{}\"\n{}", name, package)).unwrap();
+        }
+        dir
+    }
+
+    fn plan(dir: &tempfile::TempDir) -> Result<InheritanceMigration> {
+        InheritanceMigration::plan(&WorkspaceInfo::analyze(dir.path())?)
+    }
+
+    #[test]
+    fn test_plan_inherits_matching_values_and_hoists_shared_ones() {
+        let dir = workspace("version = \"1.0.0\"\nedition = \"2021\"\n", &[
+            ("alpha", "version = \"1.0.0\" # keep me\nedition = \"2021\"\nlicense = \"MIT\"\n"),
+            ("beta", "version = \"1.0.0\"\nedition = \"2018\"\nlicense = \"MIT\"\n"),
+            ("gamma", "version = \"0.9.0\"\nedition = \"2021\"\nlicense = \"MIT\"\n"),
+        ]);
+        let migration = plan(&dir).unwrap();
+
+        assert_eq!(migration.workspace_version, Version::new(1, 0, 0));
+        let changes: Vec<(String, Vec<String>)> = migration.changes.iter()
+            .map(|change| (
+                change.path.strip_prefix(dir.path()).unwrap().display().to_string(),
+                change.keys.clone(),
+            ))
+            .collect();
+        assert_eq!(changes, vec![
+            ("Cargo.toml".to_string(), vec!["license".to_string()]),
+            ("alpha/Cargo.toml".to_string(), vec!["version".to_string(), "edition".to_string(), "license".to_string()]),
+            ("beta/Cargo.toml".to_string(), vec!["version".to_string(), "license".to_string()]),
+            ("gamma/Cargo.toml".to_string(), vec!["edition".to_string(), "license".to_string()]),
+        ]);
+        assert_eq!(migration.skipped.len(), 1);
+        assert_eq!(migration.skipped[0].package, "gamma");
+        assert_eq!(migration.skipped[0].reason, "version 0.9.0 differs from workspace version 1.0.0");
+        assert!(migration.changes[0].migrated.contains("license = \"MIT\""), "{}", migration.changes[0].migrated);
+        assert!(migration.changes[2].migrated.contains("edition = \"2018\""), "{}", migration.changes[2].migrated);
+
+        // Nothing is written until the migration is applied
+        let alpha = std::fs::read_to_string(dir.path().join("alpha/Cargo.toml")).unwrap();
+        assert!(alpha.contains("version = \"1.0.0\" # keep me"));
+    }
+
+    #[test]
+    fn test_apply_writes_manifests_and_a_second_plan_is_empty() {
+        let dir = workspace("version = \"1.0.0\"\n", &[
+            ("alpha", "version = \"1.0.0\"\nlicense = \"MIT\"\n"),
+            ("beta", "version = \"1.0.0\"\nlicense = \"MIT\"\n"),
+        ]);
+        let migration = plan(&dir).unwrap();
+        assert!(!migration.is_empty());
+        migration.apply().unwrap();
+
+        let alpha = std::fs::read_to_string(dir.path().join("alpha/Cargo.toml")).unwrap();
+        for change in &migration.changes {
+            assert_eq!(std::fs::read_to_string(&change.path).unwrap(), change.migrated);
+        }
+        assert!(!alpha.contains("\"1.0.0\"") && !alpha.contains("\"MIT\""), "{}", alpha);
+        let workspace = WorkspaceInfo::analyze(dir.path()).unwrap();
+        assert_eq!(workspace.get_package("alpha").unwrap().version, "1.0.0");
+
+        assert!(InheritanceMigration::plan(&workspace).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_version_is_hoisted_only_when_members_agree() {
+        let agreeing = workspace("edition = \"2021\"\n", &[
+            ("alpha", "version = \"0.3.0\"\n"),
+            ("beta", "version = \"0.3.0\"\n"),
+        ]);
+        let migration = plan(&agreeing).unwrap();
+        assert_eq!(migration.workspace_version, Version::new(0, 3, 0));
+        assert_eq!(migration.changes[0].keys, vec!["version"]);
+
+        let disagreeing = workspace("edition = \"2021\"\n", &[
+            ("alpha", "version = \"0.3.0\"\n"),
+            ("beta", "version = \"0.4.0\"\n"),
+        ]);
+        let error = plan(&disagreeing).unwrap_err();
+        assert!(error.to_string().contains("members do not share one"), "{}", error);
+    }
+
+    #[test]
+    fn test_line_diff() {
+        let original = "[package]\nname = \"a\"\nversion = \"1.0.0\"\nedition = \"2021\"\none\ntwo\nthree\nlicense = \"MIT\"\n";
+        let migrated = "[package]\nname = \"a\"\nversion.workspace = true\nedition = \"2021\"\none\ntwo\nthree\nlicense.workspace = true\n";
+        assert_eq!(line_diff(original, migrated), concat!(
+            "    2   name = \"a\"\n",
+            "    3 - version = \"1.0.0\"\n",
+            "      + version.workspace = true\n",
+            "    4   edition = \"2021\"\n",
+            "  ...\n",
+            "    7   three\n",
+            "    8 - license = \"MIT\"\n",
+            "      + license.workspace = true\n",
+        ));
+        assert_eq!(line_diff(original, original), "");
+    }
+}
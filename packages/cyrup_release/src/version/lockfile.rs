@@ -0,0 +1,255 @@
+//! Cargo.lock refresh and verification after version bumps.
+//!
+//! Bumping member versions leaves the lockfile recording the old versions.
+//! The lockfile is refreshed with `cargo update --workspace`, which only
+//! rewrites workspace members, and then checked against the manifests so the
+//! release commit never ships a stale lockfile.
+
+use crate::error::{Result, VersionError};
+use crate::workspace::WorkspaceInfo;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Workspace lockfile handle
+#[derive(Debug, Clone)]
+pub struct Lockfile {
+    /// Workspace root containing Cargo.lock
+    workspace_root: PathBuf,
+}
+
+/// Result of checking Cargo.lock against workspace versions
+#[derive(Debug, Clone)]
+pub struct LockfileReport {
+    /// Number of workspace packages checked
+    pub packages_checked: usize,
+    /// Packages whose locked version differs from their manifest
+    pub mismatches: Vec<LockfileMismatch>,
+}
+
+/// A workspace package whose locked version is stale
+#[derive(Debug, Clone)]
+pub struct LockfileMismatch {
+    /// Package name
+    pub package: String,
+    /// Version in Cargo.toml
+    pub expected: String,
+    /// Version in Cargo.lock (`None` if the package is missing)
+    pub found: Option<String>,
+}
+
+/// Subset of the Cargo.lock format needed for verification
+#[derive(Debug, Deserialize)]
+struct LockfileContents {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+/// A `[[package]]` entry in Cargo.lock
+#[derive(Debug, Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    source: Option<String>,
+}
+
+impl Lockfile {
+    /// Create a handle for the lockfile in a workspace root
+    pub fn new<P: AsRef<Path>>(workspace_root: P) -> Self {
+        Self {
+            workspace_root: workspace_root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Path to Cargo.lock
+    pub fn path(&self) -> PathBuf {
+        self.workspace_root.join("Cargo.lock")
+    }
+
+    /// Check if the workspace has a lockfile
+    pub fn exists(&self) -> bool {
+        self.path().exists()
+    }
+
+    /// Rewrite workspace member entries in Cargo.lock without touching other dependencies
+    #[tracing::instrument(skip_all, fields(path = %self.path().display()))]
+    pub async fn refresh(&self) -> Result<()> {
+        let output = Command::new("cargo")
+            .args(["update", "--workspace"])
+            .current_dir(&self.workspace_root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| VersionError::LockfileUpdateFailed {
+                reason: format!("Failed to run cargo update: {}", e),
+            })?;
+
+        if !output.status.success() {
+            return Err(VersionError::LockfileUpdateFailed {
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Compare locked versions of workspace members with their manifests
+    pub fn verify(&self, workspace: &WorkspaceInfo) -> Result<LockfileReport> {
+        let content = std::fs::read_to_string(self.path())?;
+        let contents: LockfileContents = toml::from_str(&content)?;
+
+        let mut package_names = workspace.package_names();
+        package_names.sort();
+
+        let mut mismatches = Vec::new();
+        for package_name in &package_names {
+            let package_info = workspace.get_package(package_name)?;
+
+            // Workspace members are the path entries, which have no source
+            let locked = contents.package.iter()
+                .find(|locked| locked.source.is_none() && &locked.name == package_name);
+
+            if locked.map(|locked| &locked.version) != Some(&package_info.version) {
+                mismatches.push(LockfileMismatch {
+                    package: package_name.clone(),
+                    expected: package_info.version.clone(),
+                    found: locked.map(|locked| locked.version.clone()),
+                });
+            }
+        }
+
+        Ok(LockfileReport {
+            packages_checked: package_names.len(),
+            mismatches,
+        })
+    }
+}
+
+impl LockfileReport {
+    /// Check if every workspace member is locked at its manifest version
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Convert the first mismatch into an error
+    pub fn into_result(self) -> Result<Self> {
+        match self.mismatches.first() {
+            Some(mismatch) => Err(VersionError::LockfileMismatch {
+                package: mismatch.package.clone(),
+                expected: mismatch.expected.clone(),
+                found: mismatch.found.clone().unwrap_or_else(|| "nothing (missing)".to_string()),
+            }.into()),
+            None => Ok(self),
+        }
+    }
+
+    /// Format report for display
+    pub fn format_report(&self) -> String {
+        if self.is_consistent() {
+            return format!("Cargo.lock consistent for {} packages", self.packages_checked);
+        }
+
+        let mut report = format!("Cargo.lock out of date for {} packages:\n", self.mismatches.len());
+        for mismatch in &self.mismatches {
+            report.push_str(&format!(
+                "  - {}: expected {}, locked {}\n",
+                mismatch.package,
+                mismatch.expected,
+                mismatch.found.as_deref().unwrap_or("(missing)")
+            ));
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCKFILE: &str = r#"version = 4
+
+[[package]]
+name = "alpha"
+version = "0.2.0"
+
+[[package]]
+name = "beta"
+version = "0.1.0"
+dependencies = ["alpha"]
+
+[[package]]
+name = "gamma"
+version = "0.2.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "0000000000000000000000000000000000000000000000000000000000000000"
+"#;
+
+    /// Workspace whose members are all at version 0.2.0
+    fn workspace() -> (tempfile::TempDir, WorkspaceInfo) {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[workspace]\nmembers = [\"alpha\", \"beta\", \"gamma\"]\nresolver = \"2\"\n").unwrap();
+        for name in ["alpha", "beta", "gamma"] {
+            let member = dir.path().join(name);
+            std::fs::create_dir_all(member.join("src")).unwrap();
+            std::fs::write(member.join("src/lib.rs"), "").unwrap();
+            std::fs::write(member.join("Cargo.toml"), format!("[package]\nname = \"{}\"\nversion = \"0.2.0\"\nedition = \"2021\"\n", name)).unwrap();
+        }
+        let workspace = WorkspaceInfo::analyze(dir.path()).unwrap();
+        (dir, workspace)
+    }
+
+    #[test]
+    fn test_verify_reports_stale_and_missing_members() {
+        let (dir, workspace) = workspace();
+        let lockfile = Lockfile::new(dir.path());
+        assert!(!lockfile.exists());
+        std::fs::write(lockfile.path(), LOCKFILE).unwrap();
+        assert!(lockfile.exists());
+
+        let report = lockfile.verify(&workspace).unwrap();
+        assert_eq!(report.packages_checked, 3);
+        let mismatches: Vec<_> = report.mismatches.iter()
+            .map(|mismatch| (mismatch.package.as_str(), mismatch.expected.as_str(), mismatch.found.as_deref()))
+            .collect();
+        // A registry package of the same name does not stand in for the member
+        assert_eq!(mismatches, vec![("beta", "0.2.0", Some("0.1.0")), ("gamma", "0.2.0", None)]);
+        assert_eq!(
+            report.format_report(),
+            "Cargo.lock out of date for 2 packages:\n  - beta: expected 0.2.0, locked 0.1.0\n  - gamma: expected 0.2.0, locked (missing)\n"
+        );
+
+        let error = report.into_result().unwrap_err().to_string();
+        assert!(error.contains("beta") && error.contains("0.1.0"), "{}", error);
+    }
+
+    #[test]
+    fn test_verify_fails_on_unreadable_lockfile() {
+        let (dir, workspace) = workspace();
+        let lockfile = Lockfile::new(dir.path());
+        assert!(lockfile.verify(&workspace).is_err());
+        std::fs::write(lockfile.path(), "[[package]\n").unwrap();
+        assert!(lockfile.verify(&workspace).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_locks_members_at_manifest_versions() {
+        let (dir, workspace) = workspace();
+        let lockfile = Lockfile::new(dir.path());
+        std::fs::write(lockfile.path(), LOCKFILE.split("\n[[package]]\nname = \"gamma\"").next().unwrap()).unwrap();
+        assert!(!lockfile.verify(&workspace).unwrap().is_consistent());
+
+        lockfile.refresh().await.unwrap();
+        let report = lockfile.verify(&workspace).unwrap().into_result().unwrap();
+        assert_eq!(report.format_report(), "Cargo.lock consistent for 3 packages");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reports_cargo_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[workspace\n").unwrap();
+        let error = Lockfile::new(dir.path()).refresh().await.unwrap_err().to_string();
+        assert!(error.contains("Cargo.toml"), "{}", error);
+    }
+}
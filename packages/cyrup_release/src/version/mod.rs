@@ -4,10 +4,16 @@
 //! semantic version bumping, workspace synchronization, and TOML editing.
 
 mod bumper;
+mod inheritance;
+mod lockfile;
+mod path_deps;
 mod toml_editor;
 mod updater;
 
 pub use bumper::{VersionBump, VersionBumper, BumpPreview};
+pub use inheritance::{InheritanceMigration, ManifestChange, SkippedPackage, SHARED_METADATA_KEYS};
+pub use lockfile::{Lockfile, LockfileReport, LockfileMismatch};
+pub use path_deps::{PathDependencies, PathDependencyReport, PathDependencyMismatch};
 pub use toml_editor::{TomlEditor, TomlBackup, DependencySection, DependencyInfo};
 pub use updater::{
     VersionUpdater, UpdateResult, UpdateConfig, ConsistencyReport, UpdatePreview,
@@ -39,6 +45,7 @@ impl VersionManager {
     }
 
     /// Perform a complete version release cycle
+    #[tracing::instrument(skip(self))]
     pub fn release_version(&mut self, bump: VersionBump) -> Result<ReleaseVersionResult> {
         // Get current workspace version
         let current_version_str = self.workspace.workspace_version()?;
@@ -82,6 +89,73 @@ impl VersionManager {
         })
     }
 
+    /// Bump and update a single package, keeping all other members unchanged
+    #[tracing::instrument(skip(self))]
+    pub fn release_package_version(
+        &mut self,
+        package_name: &str,
+        bump: VersionBump,
+    ) -> Result<PackageReleaseResult> {
+        let current_version = self.package_version(package_name)?;
+
+        // Calculate new version
+        let bumper = VersionBumper::from_version(current_version.clone());
+        let new_version = bumper.bump(bump.clone())?;
+
+        // Perform the update
+        let update_result = self.updater.update_single_package_version(package_name, &new_version)?;
+
+        // Clear backups on success
+        self.updater.clear_backups();
+
+        Ok(PackageReleaseResult {
+            package_name: package_name.to_string(),
+            bump_type: bump,
+            previous_version: current_version,
+            new_version,
+            update_result,
+        })
+    }
+
+    /// Compute each package's next version when bumped from its own current version
+    pub fn plan_independent_bump(&self, bump: VersionBump) -> Result<Vec<PackageVersionChange>> {
+        let mut package_names = self.workspace.package_names();
+        package_names.sort();
+
+        package_names.into_iter()
+            .map(|package_name| {
+                let previous_version = self.package_version(&package_name)?;
+                let new_version = VersionBumper::from_version(previous_version.clone()).bump(bump.clone())?;
+                Ok(PackageVersionChange {
+                    package_name,
+                    previous_version,
+                    new_version,
+                })
+            })
+            .collect()
+    }
+
+    /// Bump every package independently from its own version (independent versioning mode)
+    #[tracing::instrument(skip(self))]
+    pub fn release_independent_versions(&mut self, bump: VersionBump) -> Result<IndependentReleaseResult> {
+        let packages = self.plan_independent_bump(bump.clone())?;
+        let new_versions = packages.iter()
+            .map(|change| (change.package_name.clone(), change.new_version.clone()))
+            .collect();
+
+        // Perform the update
+        let update_result = self.updater.update_independent_versions(&new_versions)?;
+
+        // Clear backups on success
+        self.updater.clear_backups();
+
+        Ok(IndependentReleaseResult {
+            bump_type: bump,
+            packages,
+            update_result,
+        })
+    }
+
     /// Rollback version changes
     pub fn rollback(&self) -> Result<()> {
         self.updater.rollback_all_changes()
@@ -123,6 +197,16 @@ impl VersionManager {
             }.into())
     }
 
+    /// Get current version of a single package
+    pub fn package_version(&self, package_name: &str) -> Result<Version> {
+        let package_info = self.workspace.get_package(package_name)?;
+        Version::parse(&package_info.version)
+            .map_err(|e| VersionError::ParseFailed {
+                version: package_info.version.clone(),
+                source: e,
+            }.into())
+    }
+
     /// Check if workspace uses version inheritance
     pub fn uses_workspace_inheritance(&self) -> Result<WorkspaceInheritanceInfo> {
         let mut packages_using_inheritance = Vec::new();
@@ -147,28 +231,26 @@ impl VersionManager {
 
     /// Synchronize all package versions to workspace version
     pub fn synchronize_versions(&mut self) -> Result<UpdateResult> {
-        let current_version = self.current_version()?;
         let update_config = UpdateConfig {
             create_backups: true,
             update_internal_dependencies: true,
             preserve_workspace_inheritance: false, // Force synchronization
         };
 
-        let result = self.updater.update_workspace_version(&current_version, update_config)?;
+        let result = self.updater.synchronize_workspace_version(update_config)?;
         self.updater.clear_backups();
         Ok(result)
     }
 
     /// Update internal dependencies to use explicit versions
     pub fn add_explicit_dependency_versions(&mut self) -> Result<UpdateResult> {
-        let current_version = self.current_version()?;
         let update_config = UpdateConfig {
             create_backups: true,
             update_internal_dependencies: true,
             preserve_workspace_inheritance: true,
         };
 
-        let result = self.updater.update_workspace_version(&current_version, update_config)?;
+        let result = self.updater.synchronize_workspace_version(update_config)?;
         self.updater.clear_backups();
         Ok(result)
     }
@@ -201,6 +283,43 @@ pub struct ReleaseVersionResult {
     pub consistency_report: ConsistencyReport,
 }
 
+/// Result of a single-package version release
+#[derive(Debug, Clone)]
+pub struct PackageReleaseResult {
+    /// Package that was released
+    pub package_name: String,
+    /// Type of version bump performed
+    pub bump_type: VersionBump,
+    /// Previous package version
+    pub previous_version: Version,
+    /// New package version
+    pub new_version: Version,
+    /// Update operation result
+    pub update_result: UpdateResult,
+}
+
+/// Version change of one package in an independent-versioning release
+#[derive(Debug, Clone)]
+pub struct PackageVersionChange {
+    /// Package name
+    pub package_name: String,
+    /// Previous package version
+    pub previous_version: Version,
+    /// New package version
+    pub new_version: Version,
+}
+
+/// Result of an independent-versioning release
+#[derive(Debug, Clone)]
+pub struct IndependentReleaseResult {
+    /// Type of version bump performed
+    pub bump_type: VersionBump,
+    /// Per-package version changes, sorted by package name
+    pub packages: Vec<PackageVersionChange>,
+    /// Update operation result
+    pub update_result: UpdateResult,
+}
+
 /// Result of version bump preview
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BumpPreviewResult {
@@ -281,6 +400,38 @@ impl BumpPreviewResult {
     }
 }
 
+impl PackageReleaseResult {
+    /// Get summary of changes made
+    pub fn summary(&self) -> String {
+        format!(
+            "{} {} → {}: internal dependencies left pinned, {} files modified",
+            self.package_name,
+            self.previous_version,
+            self.new_version,
+            self.update_result.modified_files.len()
+        )
+    }
+}
+
+impl IndependentReleaseResult {
+    /// Get summary of changes made
+    pub fn summary(&self) -> String {
+        format!(
+            "{} packages versioned independently, {} dependencies updated, {} files modified",
+            self.packages.len(),
+            self.update_result.dependencies_updated,
+            self.update_result.modified_files.len()
+        )
+    }
+}
+
+impl PackageVersionChange {
+    /// Format the change for display
+    pub fn format_change(&self) -> String {
+        format!("{} {} → {}", self.package_name, self.previous_version, self.new_version)
+    }
+}
+
 impl WorkspaceInheritanceInfo {
     /// Get percentage of packages using inheritance
     pub fn inheritance_percentage(&self) -> f64 {
@@ -0,0 +1,479 @@
+//! Verification of internal path dependencies after version bumps.
+//!
+//! `cargo publish` strips `path` from dependencies and keeps only the version
+//! requirement, so a path dependency without a version, or with one the
+//! bumped member no longer satisfies, is only rejected once the registry sees
+//! it. The bumped manifests are read back through `cargo metadata`, which
+//! resolves workspace inheritance the same way `cargo package` does, and each
+//! offending declaration is reported with the manifest line to change.
+
+use crate::error::{Result, VersionError};
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Path dependency requirements of a workspace
+#[derive(Debug, Clone)]
+pub struct PathDependencies {
+    /// Workspace root to run `cargo metadata` in
+    workspace_root: PathBuf,
+}
+
+/// Result of checking path dependency requirements
+#[derive(Debug, Clone)]
+pub struct PathDependencyReport {
+    /// Number of internal path dependencies checked
+    pub dependencies_checked: usize,
+    /// Declarations the registry would reject
+    pub mismatches: Vec<PathDependencyMismatch>,
+}
+
+/// An internal path dependency whose requirement does not match its target
+#[derive(Debug, Clone)]
+pub struct PathDependencyMismatch {
+    /// Package declaring the dependency
+    pub package: String,
+    /// Workspace member depended on
+    pub dependency: String,
+    /// Declared version requirement (`None` if only a path is given)
+    pub requirement: Option<String>,
+    /// Current version of the dependency
+    pub version: Version,
+    /// Manifest containing the declaration
+    pub manifest: PathBuf,
+    /// 1-based line of the declaration, if it could be located
+    pub line: Option<usize>,
+    /// Declaration as written
+    pub current: Option<String>,
+    /// Declaration with a matching requirement
+    pub expected: String,
+}
+
+/// Subset of `cargo metadata` output needed for verification
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    packages: Vec<MetadataPackage>,
+    workspace_members: Vec<String>,
+    workspace_root: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataPackage {
+    id: String,
+    name: String,
+    version: Version,
+    manifest_path: PathBuf,
+    dependencies: Vec<MetadataDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataDependency {
+    name: String,
+    req: String,
+    kind: Option<String>,
+    rename: Option<String>,
+    path: Option<PathBuf>,
+}
+
+impl PathDependencies {
+    /// Create a checker for a workspace root
+    pub fn new<P: AsRef<Path>>(workspace_root: P) -> Self {
+        Self {
+            workspace_root: workspace_root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Check the internal path dependencies of the packages about to be published.
+    ///
+    /// Dev-dependencies are only checked when they carry a version, since
+    /// `cargo publish` drops path-only dev-dependencies.
+    #[tracing::instrument(skip_all, fields(root = %self.workspace_root.display()))]
+    pub async fn verify(&self, packages: &[String]) -> Result<PathDependencyReport> {
+        let output = Command::new("cargo")
+            .args(["metadata", "--format-version", "1", "--no-deps"])
+            .current_dir(&self.workspace_root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| VersionError::PathDependencyCheckFailed {
+                reason: format!("Failed to run cargo metadata: {}", e),
+            })?;
+
+        if !output.status.success() {
+            return Err(VersionError::PathDependencyCheckFailed {
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+
+        let metadata: Metadata = serde_json::from_slice(&output.stdout)
+            .map_err(|e| VersionError::PathDependencyCheckFailed {
+                reason: format!("Invalid cargo metadata output: {}", e),
+            })?;
+
+        let members: Vec<&MetadataPackage> = metadata.packages.iter()
+            .filter(|package| metadata.workspace_members.contains(&package.id))
+            .collect();
+        let member_dirs: HashMap<&Path, &MetadataPackage> = members.iter()
+            .filter_map(|package| package.manifest_path.parent().map(|dir| (dir, *package)))
+            .collect();
+
+        let mut dependencies_checked = 0;
+        let mut mismatches = Vec::new();
+        for package in members.iter().filter(|package| packages.contains(&package.name)) {
+            for dependency in &package.dependencies {
+                let Some(target) = dependency.path.as_deref().and_then(|path| member_dirs.get(path)) else {
+                    continue;
+                };
+                let requirement = (dependency.req != "*").then(|| dependency.req.clone());
+                if dependency.kind.as_deref() == Some("dev") && requirement.is_none() {
+                    continue;
+                }
+                dependencies_checked += 1;
+
+                let satisfied = requirement.as_deref()
+                    .and_then(|requirement| VersionReq::parse(requirement).ok())
+                    .is_some_and(|requirement| requirement.matches(&target.version));
+                if !satisfied {
+                    mismatches.push(locate_declaration(
+                        package,
+                        dependency,
+                        target,
+                        requirement,
+                        &metadata.workspace_root,
+                    ));
+                }
+            }
+        }
+
+        mismatches.sort_by(|a, b| a.package.cmp(&b.package).then_with(|| a.dependency.cmp(&b.dependency)));
+        Ok(PathDependencyReport {
+            dependencies_checked,
+            mismatches,
+        })
+    }
+}
+
+impl PathDependencyReport {
+    /// Check if every internal path dependency matches its target's version
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Convert the first mismatch into an error
+    pub fn into_result(self) -> Result<Self> {
+        match self.mismatches.first() {
+            Some(mismatch) => Err(VersionError::PathDependencyMismatch {
+                package: mismatch.package.clone(),
+                dependency: mismatch.dependency.clone(),
+                requirement: mismatch.requirement.clone().unwrap_or_else(|| "no version requirement".to_string()),
+                version: mismatch.version.to_string(),
+            }.into()),
+            None => Ok(self),
+        }
+    }
+
+    /// Format report for display, with a diff of each offending declaration
+    pub fn format_report(&self) -> String {
+        if self.is_consistent() {
+            return format!("Internal path dependencies consistent ({} checked)", self.dependencies_checked);
+        }
+
+        let mut report = format!(
+            "{} internal path dependencies would be rejected by the registry:\n",
+            self.mismatches.len()
+        );
+        for mismatch in &self.mismatches {
+            let requirement = match &mismatch.requirement {
+                Some(requirement) => format!("requires {}", requirement),
+                None => "no version requirement".to_string(),
+            };
+            report.push_str(&format!(
+                "  {} → {} {} ({})\n",
+                mismatch.package, mismatch.dependency, mismatch.version, requirement
+            ));
+            match mismatch.line {
+                Some(line) => report.push_str(&format!("    {}:{}\n", mismatch.manifest.display(), line)),
+                None => report.push_str(&format!("    {}\n", mismatch.manifest.display())),
+            }
+            if let Some(current) = &mismatch.current {
+                report.push_str(&format!("    - {}\n", current));
+            }
+            report.push_str(&format!("    + {}\n", mismatch.expected));
+        }
+        report
+    }
+}
+
+/// Find the manifest line declaring a dependency and the fix for it
+fn locate_declaration(
+    package: &MetadataPackage,
+    dependency: &MetadataDependency,
+    target: &MetadataPackage,
+    requirement: Option<String>,
+    workspace_root: &Path,
+) -> PathDependencyMismatch {
+    let key = dependency.rename.as_deref().unwrap_or(&dependency.name);
+    let version = target.version.to_string();
+    let section = match dependency.kind.as_deref() {
+        Some("dev") => "dev-dependencies",
+        Some("build") => "build-dependencies",
+        _ => "dependencies",
+    };
+
+    let mut manifest = package.manifest_path.clone();
+    let mut declaration = find_declaration(&manifest, key, |header| is_dependency_section(header, section));
+
+    // Inherited dependencies take their requirement from the workspace manifest
+    if declaration.as_ref().is_some_and(|(_, line)| is_inherited(line, key)) {
+        manifest = workspace_root.join("Cargo.toml");
+        declaration = find_declaration(&manifest, key, |header| header == "workspace.dependencies");
+    }
+
+    let (line, current, expected) = match declaration {
+        // A `[dependencies.key]` table without a version: insert one below the header
+        Some((index, header)) if header.trim_start().starts_with('[') => {
+            (Some(index + 2), None, format!("version = \"{}\"", version))
+        }
+        Some((index, current)) => {
+            let expected = with_version(&current, key, &version);
+            (Some(index + 1), Some(current), expected)
+        }
+        None => (None, None, format!("{} = {{ path = \"...\", version = \"{}\" }}", key, version)),
+    };
+
+    PathDependencyMismatch {
+        package: package.name.clone(),
+        dependency: target.name.clone(),
+        requirement,
+        version: target.version.clone(),
+        manifest,
+        line,
+        current,
+        expected,
+    }
+}
+
+/// Locate `key` in the sections accepted by `in_section`.
+///
+/// Returns the 0-based line index and the line itself. For a
+/// `[dependencies.key]` table, the `version` line is returned if there is
+/// one, otherwise the table header.
+fn find_declaration(
+    manifest: &Path,
+    key: &str,
+    in_section: impl Fn(&str) -> bool,
+) -> Option<(usize, String)> {
+    let content = std::fs::read_to_string(manifest).ok()?;
+    let mut section = String::new();
+    let mut table: Option<(usize, String)> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed.strip_prefix('[').and_then(|rest| rest.split(']').next()) {
+            if table.is_some() {
+                break;
+            }
+            section = header.trim().to_string();
+            if let Some((parent, name)) = section.rsplit_once('.')
+                && name.trim() == key
+                && in_section(parent.trim())
+            {
+                table = Some((index, line.to_string()));
+            }
+            continue;
+        }
+
+        let Some((name, _)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        if table.is_some() {
+            if name == "version" {
+                return Some((index, line.to_string()));
+            }
+        } else if in_section(&section)
+            && (name == key || name.strip_prefix(key).is_some_and(|rest| rest.starts_with('.')))
+        {
+            return Some((index, line.to_string()));
+        }
+    }
+
+    table
+}
+
+/// Whether a section header holds dependencies of the given kind
+fn is_dependency_section(header: &str, section: &str) -> bool {
+    header == section
+        || header.strip_prefix("target.")
+            .and_then(|rest| rest.strip_suffix(section))
+            .is_some_and(|rest| rest.ends_with('.'))
+}
+
+/// Whether a declaration inherits from `[workspace.dependencies]`
+fn is_inherited(line: &str, key: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.contains("workspace=true") || compact.starts_with(&format!("{}.workspace=", key))
+}
+
+/// Rewrite a declaration so its requirement matches `version`
+fn with_version(line: &str, key: &str, version: &str) -> String {
+    // `version = "..."` inline or on its own line in a dependency table
+    if let Some(start) = line.find("version")
+        && let Some(open) = line[start..].find('"').map(|offset| start + offset)
+        && line[start + "version".len()..open].trim() == "="
+        && let Some(close) = line[open + 1..].find('"').map(|offset| open + 1 + offset)
+    {
+        return format!("{}\"{}\"{}", &line[..open], version, &line[close + 1..]);
+    }
+
+    // Inline table without a version
+    if let Some(close) = line.rfind('}') {
+        let body = line[..close].trim_end();
+        let separator = if body.ends_with('{') { " " } else { ", " };
+        return format!("{}{}version = \"{}\" {}", body, separator, version, &line[close..]);
+    }
+
+    // Dotted key (`key.path = "..."`): add a sibling version key
+    let indent: String = line.chars().take_while(|c| c.is_whitespace()).collect();
+    format!("{}{}.version = \"{}\"", indent, key, version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes a workspace whose `core` member is at 0.2.0 and returns its root
+    fn workspace(members: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let names: Vec<String> = std::iter::once("core").chain(members.iter().map(|(name, _)| *name))
+            .map(|name| format!("\"{}\"", name))
+            .collect();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            format!(
+                "[workspace]\nmembers = [{}]\nresolver = \"2\"\n\n[workspace.dependencies]\ncore = {{ path = \"core\", version = \"0.1.0\" }}\n",
+                names.join(", ")
+            ),
+        ).unwrap();
+        let manifests = std::iter::once(("core", "")).chain(members.iter().copied());
+        for (name, dependencies) in manifests {
+            let package = dir.path().join(name);
+            std::fs::create_dir_all(package.join("src")).unwrap();
+            std::fs::write(package.join("src/lib.rs"), "").unwrap();
+            let version = if name == "core" { "0.2.0" } else { "0.1.0" };
+            std::fs::write(
+                package.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"{}\"\nedition = \"2021\"\n\n{}", name, version, dependencies),
+            ).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_with_version() {
+        assert_eq!(
+            with_version(r#"core = { path = "../core", version = "0.1" }"#, "core", "0.2.0"),
+            r#"core = { path = "../core", version = "0.2.0" }"#
+        );
+        assert_eq!(with_version(r#"version = "0.1""#, "core", "0.2.0"), r#"version = "0.2.0""#);
+        assert_eq!(
+            with_version(r#"core = { path = "../core" }"#, "core", "0.2.0"),
+            r#"core = { path = "../core", version = "0.2.0" }"#
+        );
+        assert_eq!(with_version("core = {}", "core", "0.2.0"), r#"core = { version = "0.2.0" }"#);
+        assert_eq!(with_version(r#"  core.path = "../core""#, "core", "0.2.0"), r#"  core.version = "0.2.0""#);
+    }
+
+    #[test]
+    fn test_declaration_helpers() {
+        assert!(is_inherited("core = { workspace = true }", "core"));
+        assert!(is_inherited("core.workspace = true", "core"));
+        assert!(!is_inherited(r#"core = { path = "../core" }"#, "core"));
+
+        assert!(is_dependency_section("dependencies", "dependencies"));
+        assert!(is_dependency_section("target.'cfg(unix)'.dependencies", "dependencies"));
+        assert!(!is_dependency_section("target.'cfg(unix)'.dev-dependencies", "dependencies"));
+        assert!(!is_dependency_section("workspace.dependencies", "dependencies"));
+    }
+
+    #[test]
+    fn test_find_declaration() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        std::fs::write(&manifest, concat!(
+            "[package]\nname = \"app\"\n\n",
+            "[dev-dependencies]\ncore = { path = \"../core\" }\n\n",
+            "[dependencies.core]\npath = \"../core\"\nversion = \"0.1\"\n\n",
+            "[build-dependencies.core]\npath = \"../core\"\n\n",
+            "[target.'cfg(unix)'.dependencies]\nshim.path = \"../shim\"\n",
+        )).unwrap();
+        let in_dependencies = |header: &str| is_dependency_section(header, "dependencies");
+
+        assert_eq!(find_declaration(&manifest, "core", in_dependencies), Some((8, "version = \"0.1\"".to_string())));
+        assert_eq!(
+            find_declaration(&manifest, "core", |header| header == "dev-dependencies"),
+            Some((4, "core = { path = \"../core\" }".to_string()))
+        );
+        assert_eq!(
+            find_declaration(&manifest, "core", |header| header == "build-dependencies"),
+            Some((10, "[build-dependencies.core]".to_string()))
+        );
+        assert_eq!(find_declaration(&manifest, "shim", in_dependencies), Some((14, "shim.path = \"../shim\"".to_string())));
+        assert_eq!(find_declaration(&manifest, "missing", in_dependencies), None);
+    }
+
+    #[tokio::test]
+    async fn test_verify_reports_each_rejected_declaration() {
+        let dir = workspace(&[
+            ("app", "[dependencies]\ncore = { path = \"../core\", version = \"0.1\" }\n"),
+            ("inherits", "[dependencies]\ncore = { workspace = true }\n"),
+            ("table", "[dependencies.core]\npath = \"../core\"\n"),
+            ("matching", "[dependencies]\ncore = { path = \"../core\", version = \"0.2\" }\n"),
+            ("dev-only", "[dev-dependencies]\ncore = { path = \"../core\" }\n"),
+        ]);
+        let packages: Vec<String> = ["app", "inherits", "table", "matching", "dev-only"].iter().map(|name| name.to_string()).collect();
+
+        let report = PathDependencies::new(dir.path()).verify(&packages).await.unwrap();
+
+        // The path-only dev-dependency is dropped by cargo publish and not checked
+        assert_eq!(report.dependencies_checked, 4);
+        let found: Vec<(&str, Option<&str>, Option<usize>, &str)> = report.mismatches.iter()
+            .map(|mismatch| (
+                mismatch.package.as_str(),
+                mismatch.requirement.as_deref(),
+                mismatch.line,
+                mismatch.expected.as_str(),
+            ))
+            .collect();
+        assert_eq!(found, vec![
+            ("app", Some("^0.1"), Some(7), r#"core = { path = "../core", version = "0.2.0" }"#),
+            ("inherits", Some("^0.1.0"), Some(6), r#"core = { path = "core", version = "0.2.0" }"#),
+            ("table", None, Some(7), r#"version = "0.2.0""#),
+        ]);
+        let inherited = &report.mismatches[1];
+        assert!(inherited.manifest.ends_with("Cargo.toml") && !inherited.manifest.ends_with("inherits/Cargo.toml"));
+
+        let formatted = report.format_report();
+        assert!(formatted.starts_with("3 internal path dependencies would be rejected by the registry:\n"), "{}", formatted);
+        assert!(formatted.contains("  table → core 0.2.0 (no version requirement)\n"), "{}", formatted);
+        assert!(formatted.contains("    - core = { path = \"../core\", version = \"0.1\" }\n"), "{}", formatted);
+        assert!(report.into_result().unwrap_err().to_string().contains("app"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_consistent_workspace() {
+        let dir = workspace(&[("app", "[dependencies]\ncore = { path = \"../core\", version = \"0.2.0\" }\n")]);
+        let report = PathDependencies::new(dir.path()).verify(&["app".to_string()]).await.unwrap();
+        assert!(report.is_consistent());
+        assert_eq!(report.format_report(), "Internal path dependencies consistent (1 checked)");
+        assert!(report.into_result().is_ok());
+
+        // Packages not being published are not checked
+        let report = PathDependencies::new(dir.path()).verify(&[]).await.unwrap();
+        assert_eq!(report.dependencies_checked, 0);
+    }
+}
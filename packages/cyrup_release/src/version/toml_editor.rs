@@ -7,7 +7,7 @@ use crate::error::{Result, VersionError};
 use semver::Version;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use toml_edit::{DocumentMut, Item, Value, InlineTable};
+use toml_edit::{DocumentMut, Item, Value, InlineTable, Table};
 
 /// Format-preserving TOML editor for Cargo.toml files
 #[derive(Debug)]
@@ -108,6 +108,19 @@ impl TomlEditor {
         Ok(())
     }
 
+    /// Set an explicit package version, replacing workspace inheritance if present
+    pub fn set_explicit_package_version(&mut self, new_version: &Version) -> Result<()> {
+        let package_table = self.document.get_mut("package")
+            .and_then(|item| item.as_table_mut())
+            .ok_or_else(|| VersionError::TomlUpdateFailed {
+                path: self.file_path.clone(),
+                reason: "No [package] section found".to_string(),
+            })?;
+
+        package_table.insert("version", toml_edit::value(new_version.to_string()));
+        Ok(())
+    }
+
     /// Update workspace version in [workspace.package] section
     pub fn update_workspace_version(&mut self, new_version: &Version) -> Result<()> {
         let version_str = new_version.to_string();
@@ -149,44 +162,84 @@ impl TomlEditor {
         Ok(())
     }
 
-    /// Update internal dependency version
+    /// Update internal dependency version.
+    ///
+    /// Covers `[dependencies]`, `[dev-dependencies]`, `[build-dependencies]`,
+    /// their `[target.'cfg(...)'.*]` variants, and `[workspace.dependencies]`.
+    /// Entries inheriting from the workspace (`workspace = true`) are left as is.
     pub fn update_dependency_version(&mut self, dependency_name: &str, new_version: &Version) -> Result<()> {
+        if !self.update_dependency_version_if_present(dependency_name, new_version)? {
+            return Err(VersionError::DependencyMismatch {
+                dependency: dependency_name.to_string(),
+                expected: new_version.to_string(),
+                found: "not found".to_string(),
+            }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Update a dependency's version requirement wherever it is declared.
+    ///
+    /// Same as `update_dependency_version`, but returns `false` instead of an
+    /// error when the document does not declare the dependency.
+    pub fn update_dependency_version_if_present(&mut self, dependency_name: &str, new_version: &Version) -> Result<bool> {
         let version_str = new_version.to_string();
         let mut updated = false;
 
-        // Update in [dependencies] section
-        if let Some(deps_table) = self.document.get_mut("dependencies").and_then(|item| item.as_table_mut()) {
+        for deps_table in Self::dependency_tables_mut(&mut self.document) {
             if let Some(dep_item) = deps_table.get_mut(dependency_name) {
-                Self::update_dependency_item(dep_item, &version_str, &self.file_path)?;
+                if !Self::is_inherited_dependency(dep_item) {
+                    Self::update_dependency_item(dep_item, &version_str, &self.file_path)?;
+                }
                 updated = true;
             }
         }
 
-        // Update in [dev-dependencies] section
-        if let Some(dev_deps_table) = self.document.get_mut("dev-dependencies").and_then(|item| item.as_table_mut()) {
-            if let Some(dep_item) = dev_deps_table.get_mut(dependency_name) {
-                Self::update_dependency_item(dep_item, &version_str, &self.file_path)?;
-                updated = true;
-            }
-        }
+        Ok(updated)
+    }
 
-        // Update in [build-dependencies] section
-        if let Some(build_deps_table) = self.document.get_mut("build-dependencies").and_then(|item| item.as_table_mut()) {
-            if let Some(dep_item) = build_deps_table.get_mut(dependency_name) {
-                Self::update_dependency_item(dep_item, &version_str, &self.file_path)?;
-                updated = true;
+    /// Every dependency table in the document, target-specific and workspace ones included
+    fn dependency_tables_mut(document: &mut DocumentMut) -> Vec<&mut Table> {
+        const SECTIONS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+        let mut tables = Vec::new();
+        for (key, item) in document.as_table_mut().iter_mut() {
+            match key.get() {
+                section if SECTIONS.contains(&section) => tables.extend(item.as_table_mut()),
+                "target" => {
+                    let Some(targets) = item.as_table_mut() else {
+                        continue;
+                    };
+                    for (_, target) in targets.iter_mut() {
+                        let Some(target) = target.as_table_mut() else {
+                            continue;
+                        };
+                        for (section, deps) in target.iter_mut() {
+                            if SECTIONS.contains(&section.get()) {
+                                tables.extend(deps.as_table_mut());
+                            }
+                        }
+                    }
+                }
+                "workspace" => tables.extend(
+                    item.as_table_mut()
+                        .and_then(|workspace| workspace.get_mut("dependencies"))
+                        .and_then(Item::as_table_mut),
+                ),
+                _ => {}
             }
         }
+        tables
+    }
 
-        if !updated {
-            return Err(VersionError::DependencyMismatch {
-                dependency: dependency_name.to_string(),
-                expected: version_str,
-                found: "not found".to_string(),
-            }.into());
+    /// Whether a dependency takes its requirement from `[workspace.dependencies]`
+    fn is_inherited_dependency(dep_item: &Item) -> bool {
+        match dep_item {
+            Item::Value(Value::InlineTable(table)) => table.contains_key("workspace"),
+            Item::Table(table) => table.contains_key("workspace"),
+            _ => false,
         }
-
-        Ok(())
     }
 
     /// Update a single dependency item (handles different formats)
@@ -233,6 +286,90 @@ impl TomlEditor {
         Ok(())
     }
 
+    /// Add a version requirement to a dependency declared only by path or git.
+    ///
+    /// Returns `false` if the dependency is not in the section or already has a version.
+    pub fn add_missing_dependency_version(
+        &mut self,
+        dependency_name: &str,
+        section: DependencySection,
+        version: &Version,
+    ) -> Result<bool> {
+        let Some(dep_item) = self.document.get_mut(section.section_name())
+            .and_then(|item| item.as_table_mut())
+            .and_then(|table| table.get_mut(dependency_name))
+        else {
+            return Ok(false);
+        };
+
+        let has_version = match &*dep_item {
+            Item::Value(Value::InlineTable(table)) => table.contains_key("version"),
+            Item::Table(table) => table.contains_key("version"),
+            _ => true,
+        };
+        if has_version {
+            return Ok(false);
+        }
+
+        Self::update_dependency_item(dep_item, &version.to_string(), &self.file_path)?;
+        if let Item::Value(Value::InlineTable(table)) = dep_item {
+            table.fmt();
+        }
+        Ok(true)
+    }
+
+    /// Inherit a missing `[package]` field from `[workspace.package]` (`field.workspace = true`).
+    ///
+    /// Returns `false` if the package already sets the field.
+    pub fn inherit_package_field(&mut self, field: &str) -> Result<bool> {
+        if self.package_table_mut()?.contains_key(field) {
+            return Ok(false);
+        }
+
+        self.set_inherited_package_field(field)?;
+        Ok(true)
+    }
+
+    /// Replace a `[package]` field with `field.workspace = true`, keeping its position
+    pub fn set_inherited_package_field(&mut self, field: &str) -> Result<()> {
+        let mut inherited = Table::new();
+        inherited.set_dotted(true);
+        inherited.insert("workspace", toml_edit::value(true));
+        self.package_table_mut()?.insert(field, Item::Table(inherited));
+        Ok(())
+    }
+
+    /// Set a `[workspace.package]` field, creating the section if needed
+    pub fn set_workspace_package_field(&mut self, field: &str, value: Value) -> Result<()> {
+        let workspace_table = self.document.get_mut("workspace")
+            .and_then(|item| item.as_table_mut())
+            .ok_or_else(|| VersionError::TomlUpdateFailed {
+                path: self.file_path.clone(),
+                reason: "No [workspace] section found".to_string(),
+            })?;
+
+        let package_table = workspace_table.entry("package")
+            .or_insert_with(|| Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| VersionError::TomlUpdateFailed {
+                path: self.file_path.clone(),
+                reason: "[workspace.package] is not a table".to_string(),
+            })?;
+
+        package_table.insert(field, Item::Value(value));
+        Ok(())
+    }
+
+    /// Mutable `[package]` table
+    fn package_table_mut(&mut self) -> Result<&mut Table> {
+        self.document.get_mut("package")
+            .and_then(|item| item.as_table_mut())
+            .ok_or_else(|| VersionError::TomlUpdateFailed {
+                path: self.file_path.clone(),
+                reason: "No [package] section found".to_string(),
+            }.into())
+    }
+
     /// Add a new dependency
     pub fn add_dependency(
         &mut self,
@@ -285,6 +422,25 @@ impl TomlEditor {
         }
     }
 
+    /// Remove an entry from `[patch.<source>]`, dropping tables left empty
+    pub fn remove_patch(&mut self, source: &str, dependency_name: &str) -> Result<bool> {
+        let Some(patch_table) = self.document.get_mut("patch").and_then(|item| item.as_table_mut()) else {
+            return Ok(false);
+        };
+        let Some(source_table) = patch_table.get_mut(source).and_then(|item| item.as_table_mut()) else {
+            return Ok(false);
+        };
+
+        let removed = source_table.remove(dependency_name).is_some();
+        if source_table.is_empty() {
+            patch_table.remove(source);
+        }
+        if patch_table.is_empty() {
+            self.document.remove("patch");
+        }
+        Ok(removed)
+    }
+
     /// Get current version from the TOML
     pub fn get_current_version(&self) -> Result<Version> {
         // Try package version first
@@ -342,6 +498,10 @@ impl TomlEditor {
                 if let Some(Value::InlineTable(table)) = version_item.as_value() {
                     return table.contains_key("workspace");
                 }
+                // Dotted key form: version.workspace = true
+                if let Some(table) = version_item.as_table() {
+                    return table.contains_key("workspace");
+                }
             }
         }
         false
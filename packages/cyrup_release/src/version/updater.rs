@@ -70,11 +70,7 @@ impl VersionUpdater {
         new_version: &Version,
         config: UpdateConfig,
     ) -> Result<UpdateResult> {
-        let current_version = self.workspace.workspace_version()
-            .and_then(|v| Version::parse(&v).map_err(|e| VersionError::ParseFailed {
-                version: v,
-                source: e,
-            }.into()))?;
+        let current_version = self.current_workspace_version()?;
 
         // Validate version progression
         if new_version <= &current_version {
@@ -87,12 +83,37 @@ impl VersionUpdater {
             }.into());
         }
 
+        self.apply_workspace_version(current_version, new_version, config)
+    }
+
+    /// Rewrite every package to the current workspace version without bumping it
+    pub fn synchronize_workspace_version(&mut self, config: UpdateConfig) -> Result<UpdateResult> {
+        let current_version = self.current_workspace_version()?;
+        self.apply_workspace_version(current_version.clone(), &current_version, config)
+    }
+
+    /// Parsed `[workspace.package]` version
+    fn current_workspace_version(&self) -> Result<Version> {
+        self.workspace.workspace_version()
+            .and_then(|v| Version::parse(&v).map_err(|e| VersionError::ParseFailed {
+                version: v,
+                source: e,
+            }.into()))
+    }
+
+    /// Write `new_version` to the workspace root and every package, rolling back on failure
+    fn apply_workspace_version(
+        &mut self,
+        current_version: Version,
+        new_version: &Version,
+        config: UpdateConfig,
+    ) -> Result<UpdateResult> {
         let mut modified_files = Vec::new();
         let mut packages_updated = 0;
         let mut dependencies_updated = 0;
 
         // Update workspace version in root Cargo.toml
-        if let Err(e) = self.update_root_workspace_version(new_version, &config, &mut modified_files) {
+        if let Err(e) = self.update_root_workspace_version(new_version, &config, &mut modified_files, &mut dependencies_updated) {
             self.rollback_all_changes()?;
             return Err(e);
         }
@@ -131,12 +152,218 @@ impl VersionUpdater {
         })
     }
 
+    /// Update a single package to a new version, leaving the rest of the workspace untouched.
+    ///
+    /// Workspace version inheritance is replaced with an explicit version and
+    /// internal dependency requirements are kept pointing at the existing
+    /// member versions.
+    pub fn update_single_package_version(
+        &mut self,
+        package_name: &str,
+        new_version: &Version,
+    ) -> Result<UpdateResult> {
+        let package_info = self.workspace.get_package(package_name)?.clone();
+        let current_version = Version::parse(&package_info.version)
+            .map_err(|e| VersionError::ParseFailed {
+                version: package_info.version.clone(),
+                source: e,
+            })?;
+
+        // Validate version progression
+        if new_version <= &current_version {
+            return Err(VersionError::InvalidVersion {
+                version: new_version.to_string(),
+                reason: format!(
+                    "New version '{}' must be greater than current version '{}' of '{}'",
+                    new_version, current_version, package_name
+                ),
+            }.into());
+        }
+
+        let mut editor = TomlEditor::open(&package_info.cargo_toml_path)?;
+        self.backups.push(editor.create_backup());
+
+        if let Err(e) = editor.set_explicit_package_version(new_version).and_then(|_| editor.save()) {
+            self.rollback_all_changes()?;
+            return Err(e);
+        }
+
+        Ok(UpdateResult {
+            previous_version: current_version,
+            new_version: new_version.clone(),
+            packages_updated: 1,
+            dependencies_updated: 0,
+            modified_files: vec![package_info.cargo_toml_path],
+        })
+    }
+
+    /// Update packages to independent versions in one atomic operation.
+    ///
+    /// Each listed package gets an explicit version, and every workspace member's
+    /// requirements on those packages are updated to the new versions. The
+    /// workspace version itself is left untouched. The reported previous and new
+    /// versions are the highest among the updated packages.
+    pub fn update_independent_versions(
+        &mut self,
+        new_versions: &HashMap<String, Version>,
+    ) -> Result<UpdateResult> {
+        let mut previous_versions = Vec::with_capacity(new_versions.len());
+        for (package_name, new_version) in new_versions {
+            let package_info = self.workspace.get_package(package_name)?;
+            let current_version = Version::parse(&package_info.version)
+                .map_err(|e| VersionError::ParseFailed {
+                    version: package_info.version.clone(),
+                    source: e,
+                })?;
+
+            // Validate version progression
+            if new_version <= &current_version {
+                return Err(VersionError::InvalidVersion {
+                    version: new_version.to_string(),
+                    reason: format!(
+                        "New version '{}' must be greater than current version '{}' of '{}'",
+                        new_version, current_version, package_name
+                    ),
+                }.into());
+            }
+            previous_versions.push(current_version);
+        }
+
+        let mut modified_files = Vec::new();
+        let mut packages_updated = 0;
+        let mut dependencies_updated = 0;
+
+        if let Err(e) = self.update_root_workspace_dependencies(
+            new_versions,
+            &mut modified_files,
+            &mut dependencies_updated,
+        ) {
+            self.rollback_all_changes()?;
+            return Err(e);
+        }
+
+        let packages_to_update: Vec<crate::workspace::PackageInfo> =
+            self.workspace.packages.values().cloned().collect();
+
+        for package_info in packages_to_update {
+            if let Err(e) = self.update_independent_package(
+                &package_info,
+                new_versions,
+                &mut modified_files,
+                &mut packages_updated,
+                &mut dependencies_updated,
+            ) {
+                self.rollback_all_changes()?;
+                return Err(e);
+            }
+        }
+
+        let highest = |versions: Vec<Version>| versions.into_iter().max().ok_or_else(|| VersionError::InvalidVersion {
+            version: String::new(),
+            reason: "No packages selected for independent version update".to_string(),
+        });
+
+        Ok(UpdateResult {
+            previous_version: highest(previous_versions)?,
+            new_version: highest(new_versions.values().cloned().collect())?,
+            packages_updated,
+            dependencies_updated,
+            modified_files,
+        })
+    }
+
+    /// Point `[workspace.dependencies]` entries for internal crates at their new versions
+    fn update_root_workspace_dependencies(
+        &mut self,
+        new_versions: &HashMap<String, Version>,
+        modified_files: &mut Vec<PathBuf>,
+        dependencies_updated: &mut usize,
+    ) -> Result<()> {
+        let updates: Vec<(String, &Version)> = self.internal_workspace_dependencies()
+            .into_iter()
+            .filter_map(|(dep_key, package_name)| new_versions.get(&package_name).map(|version| (dep_key, version)))
+            .collect();
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let workspace_cargo_toml = self.workspace.root.join("Cargo.toml");
+        let mut editor = TomlEditor::open(&workspace_cargo_toml)?;
+        self.backups.push(editor.create_backup());
+
+        for (dep_key, dep_version) in updates {
+            editor.update_dependency_version(&dep_key, dep_version)?;
+            *dependencies_updated += 1;
+        }
+
+        editor.save()?;
+        modified_files.push(workspace_cargo_toml);
+        Ok(())
+    }
+
+    /// `[workspace.dependencies]` keys that point at workspace members by path, with the member name
+    fn internal_workspace_dependencies(&self) -> Vec<(String, String)> {
+        let Some(dependencies) = &self.workspace.workspace_config.dependencies else {
+            return Vec::new();
+        };
+
+        let mut internal: Vec<(String, String)> = dependencies.iter()
+            .filter(|(_, spec)| spec.get("path").is_some())
+            .map(|(dep_key, spec)| {
+                let package_name = spec.get("package")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or(dep_key);
+                (dep_key.clone(), package_name.to_string())
+            })
+            .filter(|(_, package_name)| self.workspace.packages.contains_key(package_name))
+            .collect();
+        internal.sort();
+        internal
+    }
+
+    /// Apply independent version updates to a single package manifest
+    fn update_independent_package(
+        &mut self,
+        package_info: &crate::workspace::PackageInfo,
+        new_versions: &HashMap<String, Version>,
+        modified_files: &mut Vec<PathBuf>,
+        packages_updated: &mut usize,
+        dependencies_updated: &mut usize,
+    ) -> Result<()> {
+        let mut editor = TomlEditor::open(&package_info.cargo_toml_path)?;
+        self.backups.push(editor.create_backup());
+
+        let mut package_modified = false;
+
+        if let Some(new_version) = new_versions.get(&package_info.name) {
+            editor.set_explicit_package_version(new_version)?;
+            package_modified = true;
+            *packages_updated += 1;
+        }
+
+        for dep_name in &package_info.workspace_dependencies {
+            if let Some(dep_version) = new_versions.get(dep_name) {
+                editor.update_dependency_version(dep_name, dep_version)?;
+                package_modified = true;
+                *dependencies_updated += 1;
+            }
+        }
+
+        if package_modified {
+            editor.save()?;
+            modified_files.push(package_info.cargo_toml_path.clone());
+        }
+
+        Ok(())
+    }
+
     /// Update root workspace version
     fn update_root_workspace_version(
         &mut self,
         new_version: &Version,
         config: &UpdateConfig,
         modified_files: &mut Vec<PathBuf>,
+        dependencies_updated: &mut usize,
     ) -> Result<()> {
         let workspace_cargo_toml = self.workspace.root.join("Cargo.toml");
         let mut editor = TomlEditor::open(&workspace_cargo_toml)?;
@@ -148,6 +375,15 @@ impl VersionUpdater {
 
         // Update workspace version
         editor.update_workspace_version(new_version)?;
+
+        // Update internal crates declared in [workspace.dependencies]
+        if config.update_internal_dependencies {
+            for (dep_key, _) in self.internal_workspace_dependencies() {
+                editor.update_dependency_version(&dep_key, new_version)?;
+                *dependencies_updated += 1;
+            }
+        }
+
         editor.save()?;
 
         modified_files.push(workspace_cargo_toml);
@@ -278,8 +514,10 @@ impl VersionUpdater {
                 }
             }
 
-            // Check internal dependency versions
-            for dep_name in &package_info.workspace_dependencies {
+            // Check internal dependency versions (path dependencies outside the workspace are released separately)
+            for dep_name in package_info.workspace_dependencies.iter()
+                .filter(|dep_name| self.workspace.packages.contains_key(*dep_name))
+            {
                 dependencies_checked += 1;
 
                 if let Some(dep_spec) = package_info.all_dependencies.get(dep_name) {
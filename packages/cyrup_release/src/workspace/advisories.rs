@@ -0,0 +1,502 @@
+//! RustSec advisory checks for the dependencies of released packages.
+//!
+//! The advisory database is the git repository cargo-audit uses, kept in
+//! `$CARGO_HOME/advisory-db` and updated with the git CLI. Each advisory is a
+//! Markdown file whose TOML front block names the affected crate and the
+//! version ranges that are patched or unaffected; any other version of that
+//! crate from crates.io is vulnerable.
+
+use crate::error::{Result, WorkspaceError};
+use crate::workspace::Sbom;
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Git URL of the RustSec advisory database
+pub const DEFAULT_ADVISORY_DB_URL: &str = "https://github.com/rustsec/advisory-db.git";
+
+/// Local copy of the RustSec advisory database
+#[derive(Debug, Clone)]
+pub struct AdvisoryDatabase {
+    /// Directory the database was loaded from
+    path: PathBuf,
+    /// Advisories by crate name, withdrawn advisories excluded
+    advisories: HashMap<String, Vec<Advisory>>,
+}
+
+/// A security advisory for one crate
+#[derive(Debug, Clone)]
+pub struct Advisory {
+    /// Advisory ID (e.g. `RUSTSEC-2023-0071`)
+    pub id: String,
+    /// Affected crate
+    pub package: String,
+    /// One-line summary
+    pub title: String,
+    /// Link to details, if the advisory has one
+    pub url: Option<String>,
+    /// Other IDs for the same issue (CVE, GHSA)
+    pub aliases: Vec<String>,
+    /// Kind of informational advisory (`unmaintained`, `unsound`, ...); `None` for vulnerabilities
+    pub informational: Option<String>,
+    /// Version ranges containing the fix
+    pub patched: Vec<VersionReq>,
+    /// Version ranges never affected
+    pub unaffected: Vec<VersionReq>,
+}
+
+/// A dependency version covered by an advisory
+#[derive(Debug, Clone)]
+pub struct AdvisoryMatch {
+    /// The advisory
+    pub advisory: Advisory,
+    /// Resolved version of the affected crate
+    pub version: Version,
+    /// Crate names from a released package down to the affected crate
+    pub path: Vec<String>,
+}
+
+/// TOML front block of an advisory file
+#[derive(Debug, Deserialize)]
+struct AdvisoryFile {
+    advisory: AdvisoryMetadata,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryMetadata {
+    id: String,
+    package: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    informational: Option<String>,
+    #[serde(default)]
+    withdrawn: Option<toml::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+impl AdvisoryDatabase {
+    /// Default location, shared with cargo-audit
+    pub fn default_path() -> PathBuf {
+        let cargo_home = std::env::var_os("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .or_else(|| std::env::var_os("USERPROFILE"))
+                    .map(|home| PathBuf::from(home).join(".cargo"))
+            })
+            .unwrap_or_else(|| PathBuf::from(".cargo"));
+        cargo_home.join("advisory-db")
+    }
+
+    /// Clone the database, or update an existing copy to the latest advisories
+    pub async fn fetch(path: &Path, url: &str) -> Result<()> {
+        if path.join(".git").exists() {
+            run_git(Some(path), &["fetch", "--quiet", "--depth", "1", url, "HEAD"]).await?;
+            run_git(Some(path), &["reset", "--quiet", "--hard", "FETCH_HEAD"]).await?;
+        } else {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| database_error(format!(
+                    "Failed to create {}: {}",
+                    parent.display(),
+                    e
+                )))?;
+            }
+            let path = path.to_string_lossy();
+            run_git(None, &["clone", "--quiet", "--depth", "1", url, path.as_ref()]).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Load every advisory in a local copy of the database
+    pub fn open(path: &Path) -> Result<Self> {
+        let crates_dir = path.join("crates");
+        let crate_dirs = std::fs::read_dir(&crates_dir)
+            .map_err(|e| database_error(format!("Failed to read {}: {}", crates_dir.display(), e)))?;
+
+        let mut advisories: HashMap<String, Vec<Advisory>> = HashMap::new();
+        for crate_dir in crate_dirs.flatten() {
+            let Ok(files) = std::fs::read_dir(crate_dir.path()) else {
+                continue;
+            };
+            for file in files.flatten() {
+                let file_path = file.path();
+                if file_path.extension().is_none_or(|extension| extension != "md") {
+                    continue;
+                }
+                let contents = std::fs::read_to_string(&file_path)
+                    .map_err(|e| database_error(format!("Failed to read {}: {}", file_path.display(), e)))?;
+                if let Some(advisory) = Advisory::parse(&contents)
+                    .map_err(|reason| database_error(format!("{}: {}", file_path.display(), reason)))?
+                {
+                    advisories.entry(advisory.package.clone()).or_default().push(advisory);
+                }
+            }
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            advisories,
+        })
+    }
+
+    /// Directory the database was loaded from
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of advisories loaded
+    pub fn len(&self) -> usize {
+        self.advisories.values().map(Vec::len).sum()
+    }
+
+    /// Check if the database has no advisories
+    pub fn is_empty(&self) -> bool {
+        self.advisories.is_empty()
+    }
+
+    /// Find advisories covering crates.io packages in a dependency tree
+    pub fn check(&self, sbom: &Sbom) -> Vec<AdvisoryMatch> {
+        let mut matches = Vec::new();
+        for component in sbom.components.values().filter(|component| component.is_from_crates_io()) {
+            let Some(advisories) = self.advisories.get(&component.name) else {
+                continue;
+            };
+            let Ok(version) = Version::parse(&component.version) else {
+                continue;
+            };
+            for advisory in advisories.iter().filter(|advisory| advisory.affects(&version)) {
+                matches.push(AdvisoryMatch {
+                    advisory: advisory.clone(),
+                    version: version.clone(),
+                    path: dependency_path(sbom, &component.id),
+                });
+            }
+        }
+
+        matches.sort_by(|a, b| a.advisory.id.cmp(&b.advisory.id).then_with(|| a.version.cmp(&b.version)));
+        matches
+    }
+}
+
+impl Advisory {
+    /// Parse an advisory file, returning `None` for withdrawn advisories
+    fn parse(contents: &str) -> std::result::Result<Option<Self>, String> {
+        let body = contents.trim_start()
+            .strip_prefix("```toml")
+            .ok_or_else(|| "missing TOML front block".to_string())?;
+        let (front, markdown) = body.split_once("\n```")
+            .ok_or_else(|| "unterminated TOML front block".to_string())?;
+
+        let file: AdvisoryFile = toml::from_str(front).map_err(|e| e.to_string())?;
+        if file.advisory.withdrawn.is_some() {
+            return Ok(None);
+        }
+
+        let parse_ranges = |ranges: &[String]| {
+            ranges.iter()
+                .map(|range| VersionReq::parse(range).map_err(|e| format!("invalid version range '{}': {}", range, e)))
+                .collect::<std::result::Result<Vec<_>, _>>()
+        };
+
+        let title = markdown.lines()
+            .find_map(|line| line.trim().strip_prefix("# "))
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+
+        Ok(Some(Self {
+            id: file.advisory.id,
+            package: file.advisory.package,
+            title,
+            url: file.advisory.url,
+            aliases: file.advisory.aliases,
+            informational: file.advisory.informational,
+            patched: parse_ranges(&file.versions.patched)?,
+            unaffected: parse_ranges(&file.versions.unaffected)?,
+        }))
+    }
+
+    /// Check if a version is neither patched nor unaffected
+    pub fn affects(&self, version: &Version) -> bool {
+        !self.patched.iter().chain(&self.unaffected).any(|range| range.matches(version))
+    }
+
+    /// Check if the advisory is a vulnerability rather than an informational notice
+    pub fn is_vulnerability(&self) -> bool {
+        self.informational.is_none()
+    }
+
+    /// Check if the advisory or one of its aliases is in an ignore list
+    pub fn is_ignored(&self, ignore: &[String]) -> bool {
+        ignore.iter().any(|id| *id == self.id || self.aliases.contains(id))
+    }
+}
+
+impl AdvisoryMatch {
+    /// Format the match for validation output
+    pub fn format_result(&self) -> String {
+        let kind = self.advisory.informational.as_deref().unwrap_or("vulnerability");
+        let mut result = format!(
+            "{} ({}): {} {} - {}",
+            self.advisory.id, kind, self.advisory.package, self.version, self.advisory.title
+        );
+        if self.path.len() > 1 {
+            result.push_str(&format!(" [{}]", self.path.join(" → ")));
+        }
+        if self.advisory.patched.is_empty() {
+            result.push_str("; no patched version available");
+        } else {
+            let patched: Vec<String> = self.advisory.patched.iter().map(ToString::to_string).collect();
+            result.push_str(&format!("; patched in {}", patched.join(" or ")));
+        }
+        result
+    }
+}
+
+/// Shortest chain of crate names from a released package to a component
+fn dependency_path(sbom: &Sbom, target: &str) -> Vec<String> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue: VecDeque<&str> = sbom.released.iter().map(String::as_str).collect();
+    let mut seen: std::collections::HashSet<&str> = queue.iter().copied().collect();
+
+    while let Some(id) = queue.pop_front() {
+        if id == target {
+            let mut chain = vec![id];
+            let mut current = id;
+            while let Some(parent) = previous.get(current) {
+                chain.push(parent);
+                current = parent;
+            }
+            return chain.iter()
+                .rev()
+                .filter_map(|id| sbom.components.get(*id).map(|component| component.name.clone()))
+                .collect();
+        }
+        for dependency in sbom.components.get(id).into_iter().flat_map(|component| &component.dependencies) {
+            if seen.insert(dependency.as_str()) {
+                previous.insert(dependency.as_str(), id);
+                queue.push_back(dependency.as_str());
+            }
+        }
+    }
+
+    Vec::new()
+}
+
+/// Run git, optionally inside the database directory
+async fn run_git(directory: Option<&Path>, args: &[&str]) -> Result<()> {
+    let mut command = Command::new("git");
+    if let Some(directory) = directory {
+        command.current_dir(directory);
+    }
+    let output = command
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| database_error(format!("Failed to execute git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(database_error(format!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Create an advisory database error
+fn database_error(reason: String) -> crate::error::ReleaseError {
+    WorkspaceError::AdvisoryDatabase { reason }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workspace::SbomComponent;
+    use std::collections::BTreeMap;
+
+    const ADVISORY: &str = r#"```toml
+[advisory]
+id = "RUSTSEC-2024-0001"
+package = "leaky"
+date = "2024-01-01"
+url = "https://example.com/RUSTSEC-2024-0001"
+aliases = ["CVE-2024-1234", "GHSA-xxxx-yyyy-zzzz"]
+
+[versions]
+patched = [">= 1.2.3", "^0.9.7"]
+unaffected = ["< 0.5"]
+```
+
+# Leaky buffers leak
+
+Details follow.
+"#;
+
+    const WITHDRAWN: &str = "```toml\n[advisory]\nid = \"RUSTSEC-2024-0002\"\npackage = \"leaky\"\nwithdrawn = \"2024-02-01\"\n```\n\n# Never mind\n";
+
+    const UNMAINTAINED: &str = "```toml\n[advisory]\nid = \"RUSTSEC-2024-0003\"\npackage = \"abandoned\"\ninformational = \"unmaintained\"\n\n[versions]\npatched = []\n```\n\n# abandoned is unmaintained\n";
+
+    fn advisory() -> Advisory {
+        Advisory::parse(ADVISORY).unwrap().unwrap()
+    }
+
+    fn component(name: &str, version: &str, registry: bool, dependencies: &[&str]) -> (String, SbomComponent) {
+        let id = format!("{}@{}", name, version);
+        (id.clone(), SbomComponent {
+            id,
+            name: name.to_string(),
+            version: version.to_string(),
+            license: None,
+            source: registry.then(|| "registry+https://github.com/rust-lang/crates.io-index".to_string()),
+            dependencies: dependencies.iter().map(|dependency| dependency.to_string()).collect(),
+        })
+    }
+
+    /// `app` depends on `middle`, which depends on `leaky` 1.0.0 and `abandoned`
+    fn sbom() -> Sbom {
+        let components: BTreeMap<String, SbomComponent> = [
+            component("app", "0.1.0", false, &["middle@2.0.0"]),
+            component("middle", "2.0.0", true, &["leaky@1.0.0", "abandoned@0.1.0"]),
+            component("leaky", "1.0.0", true, &[]),
+            component("abandoned", "0.1.0", true, &[]),
+        ].into_iter().collect();
+        Sbom {
+            released: vec!["app@0.1.0".to_string()],
+            components,
+            commit: None,
+            tags: Vec::new(),
+            created: chrono::Utc::now(),
+        }
+    }
+
+    fn write_database(root: &Path) {
+        for (name, file, contents) in [
+            ("leaky", "RUSTSEC-2024-0001.md", ADVISORY),
+            ("leaky", "RUSTSEC-2024-0002.md", WITHDRAWN),
+            ("abandoned", "RUSTSEC-2024-0003.md", UNMAINTAINED),
+            ("leaky", "README.txt", "not an advisory"),
+        ] {
+            let dir = root.join("crates").join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join(file), contents).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_parse_advisory() {
+        let advisory = advisory();
+        assert_eq!(advisory.id, "RUSTSEC-2024-0001");
+        assert_eq!(advisory.package, "leaky");
+        assert_eq!(advisory.title, "Leaky buffers leak");
+        assert_eq!(advisory.url.as_deref(), Some("https://example.com/RUSTSEC-2024-0001"));
+        assert_eq!(advisory.patched.len(), 2);
+        assert!(advisory.is_vulnerability());
+
+        assert!(Advisory::parse(WITHDRAWN).unwrap().is_none());
+        assert!(!Advisory::parse(UNMAINTAINED).unwrap().unwrap().is_vulnerability());
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_advisories() {
+        assert_eq!(Advisory::parse("# No front block").unwrap_err(), "missing TOML front block");
+        assert_eq!(Advisory::parse("```toml\n[advisory]\n").unwrap_err(), "unterminated TOML front block");
+        let bad_range = "```toml\n[advisory]\nid = \"X\"\npackage = \"p\"\n[versions]\npatched = [\"not a range\"]\n```\n";
+        assert!(Advisory::parse(bad_range).unwrap_err().starts_with("invalid version range 'not a range'"));
+    }
+
+    #[test]
+    fn test_affected_versions() {
+        let advisory = advisory();
+        let affects = |version: &str| advisory.affects(&Version::parse(version).unwrap());
+        assert!(affects("1.2.2"));
+        assert!(affects("0.9.6"));
+        assert!(affects("0.5.0"));
+        assert!(!affects("1.2.3"));
+        assert!(!affects("2.0.0"));
+        assert!(!affects("0.9.8"));
+        assert!(!affects("0.4.9"));
+    }
+
+    #[test]
+    fn test_ignore_by_id_or_alias() {
+        let advisory = advisory();
+        assert!(advisory.is_ignored(&["RUSTSEC-2024-0001".to_string()]));
+        assert!(advisory.is_ignored(&["CVE-2024-1234".to_string()]));
+        assert!(!advisory.is_ignored(&["RUSTSEC-2024-9999".to_string()]));
+        assert!(!advisory.is_ignored(&[]));
+    }
+
+    #[test]
+    fn test_check_dependency_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        write_database(dir.path());
+        let database = AdvisoryDatabase::open(dir.path()).unwrap();
+        assert_eq!(database.len(), 2);
+        assert_eq!(database.path(), dir.path());
+
+        let matches = database.check(&sbom());
+        let results: Vec<String> = matches.iter().map(AdvisoryMatch::format_result).collect();
+        assert_eq!(results, vec![
+            "RUSTSEC-2024-0001 (vulnerability): leaky 1.0.0 - Leaky buffers leak [app → middle → leaky]; \
+             patched in >=1.2.3 or ^0.9.7",
+            "RUSTSEC-2024-0003 (unmaintained): abandoned 0.1.0 - abandoned is unmaintained [app → middle → abandoned]; \
+             no patched version available",
+        ]);
+    }
+
+    #[test]
+    fn test_path_packages_are_not_checked() {
+        let dir = tempfile::tempdir().unwrap();
+        write_database(dir.path());
+        let database = AdvisoryDatabase::open(dir.path()).unwrap();
+
+        let mut sbom = sbom();
+        sbom.components.get_mut("leaky@1.0.0").unwrap().source = None;
+        let ids: Vec<String> = database.check(&sbom).into_iter().map(|found| found.advisory.id).collect();
+        assert_eq!(ids, vec!["RUSTSEC-2024-0003"]);
+        assert!(AdvisoryDatabase::open(&dir.path().join("missing")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_clones_and_updates() {
+        use crate::git::test_repo::git;
+
+        let upstream = tempfile::tempdir().unwrap();
+        git(upstream.path(), &["init", "--quiet"]);
+        write_database(upstream.path());
+        git(upstream.path(), &["add", "."]);
+        git(upstream.path(), &["commit", "--quiet", "-m", "Advisories"]);
+        let url = upstream.path().display().to_string();
+
+        let cache = tempfile::tempdir().unwrap();
+        let path = cache.path().join("nested").join("advisory-db");
+        AdvisoryDatabase::fetch(&path, &url).await.unwrap();
+        assert_eq!(AdvisoryDatabase::open(&path).unwrap().len(), 2);
+
+        std::fs::remove_file(upstream.path().join("crates/abandoned/RUSTSEC-2024-0003.md")).unwrap();
+        git(upstream.path(), &["commit", "--quiet", "-am", "Drop advisory"]);
+        AdvisoryDatabase::fetch(&path, &url).await.unwrap();
+        assert_eq!(AdvisoryDatabase::open(&path).unwrap().len(), 1);
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::error::{Result, WorkspaceError};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use crate::git::{FileChange, FileDiff};
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
 /// Complete workspace information
@@ -72,7 +73,7 @@ pub struct PackageConfig {
     /// Package edition
     pub edition: Option<toml::Value>,
     /// Package description
-    pub description: Option<String>,
+    pub description: Option<toml::Value>,
     /// Package license
     pub license: Option<toml::Value>,
     /// Package authors
@@ -298,6 +299,20 @@ impl WorkspaceInfo {
             }
         }
 
+        // Parse target-specific dependencies ([target.'cfg(...)'.dependencies] and friends)
+        if let Some(targets) = parsed.get("target").and_then(|t| t.as_table()) {
+            for target in targets.values() {
+                for (section, prefix) in [("dependencies", ""), ("dev-dependencies", "dev:"), ("build-dependencies", "build:")] {
+                    if let Some(deps) = target.get(section).and_then(|d| d.as_table()) {
+                        for (name, spec) in deps {
+                            let spec = Self::parse_dependency_spec(spec)?;
+                            dependencies.entry(format!("{}{}", prefix, name)).or_insert(spec);
+                        }
+                    }
+                }
+            }
+        }
+
         Ok(dependencies)
     }
 
@@ -391,4 +406,40 @@ impl WorkspaceInfo {
     pub fn has_package(&self, name: &str) -> bool {
         self.packages.contains_key(name)
     }
-}
\ No newline at end of file
+
+    /// Packages containing any of the changed files of a diff.
+    ///
+    /// `repo_root` is the repository the diff paths are relative to. A file
+    /// belongs to the package with the deepest directory containing it;
+    /// renamed files count for both their old and new package.
+    pub fn packages_changed_in(&self, repo_root: &Path, diffs: &[FileDiff]) -> BTreeSet<String> {
+        let owner = |path: &Path| {
+            let path = repo_root.join(path);
+            self.packages.values()
+                .filter(|package| path.starts_with(&package.absolute_path))
+                .max_by_key(|package| package.absolute_path.components().count())
+                .map(|package| package.name.clone())
+        };
+
+        diffs.iter()
+            .flat_map(|diff| {
+                let previous = match &diff.change {
+                    FileChange::Renamed { from } => owner(from),
+                    _ => None,
+                };
+                owner(&diff.path).into_iter().chain(previous)
+            })
+            .collect()
+    }
+}
+
+impl PackageInfo {
+    /// Whether the package can be published to a registry (`publish = false` and `publish = []` opt out)
+    pub fn is_publishable(&self) -> bool {
+        match self.config.other.get("publish") {
+            Some(toml::Value::Boolean(publish)) => *publish,
+            Some(toml::Value::Array(registries)) => !registries.is_empty(),
+            _ => true,
+        }
+    }
+}
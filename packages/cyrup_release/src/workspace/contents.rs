@@ -0,0 +1,338 @@
+//! Inspection of what each package will upload to the registry.
+//!
+//! Uses `cargo package --list` to find the files a package ships and
+//! `cargo package --workspace` to measure the compressed `.crate` size, so
+//! oversized packages and accidentally included secrets are caught before
+//! anything is published.
+
+use crate::error::{ConfigError, PublishError, Result};
+use crate::workspace::{PackageInfo, WorkspaceInfo};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Size limit crates.io applies to uploaded crates
+pub const DEFAULT_MAX_PACKAGE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Files larger than this are reported as possibly included by accident
+pub const DEFAULT_LARGE_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Glob patterns for files that should never be published
+pub const DEFAULT_DENY_PATTERNS: &[&str] = &[
+    ".env",
+    ".env.*",
+    "*.pem",
+    "*.key",
+    "*.p12",
+    "*.pfx",
+    "id_rsa*",
+    "id_ed25519*",
+    "*.sqlite",
+    "*.db",
+    "credentials*",
+    ".cyrup_release_state*",
+    ".cyrup_release_history*",
+    ".cyrup_release_sbom*",
+];
+
+/// Lists and measures package contents
+#[derive(Debug, Clone)]
+pub struct PackageContentsInspector {
+    /// Glob patterns for denied files
+    deny: Vec<glob::Pattern>,
+    /// Size above which a single file is flagged
+    large_file_size: u64,
+}
+
+/// Contents of one package as it would be uploaded
+#[derive(Debug, Clone)]
+pub struct PackageContents {
+    /// Package name
+    pub package_name: String,
+    /// Files included in the package, relative to the package root
+    pub files: Vec<PathBuf>,
+    /// Total size of the included files on disk
+    pub uncompressed_size: u64,
+    /// Size of the generated `.crate` file (if packaging succeeded)
+    pub compressed_size: Option<u64>,
+    /// Included files matching a deny pattern, with the pattern they matched
+    pub denied_files: Vec<(PathBuf, String)>,
+    /// Included files above the large file threshold, with their size
+    pub large_files: Vec<(PathBuf, u64)>,
+}
+
+impl PackageContentsInspector {
+    /// Create an inspector with deny patterns and a large file threshold
+    pub fn new(deny: &[String], large_file_size: u64) -> Result<Self> {
+        let deny = deny.iter()
+            .map(|pattern| glob::Pattern::new(pattern).map_err(|e| ConfigError::InvalidValue {
+                key: "package-contents.deny".to_string(),
+                reason: format!("Invalid deny pattern '{}': {}", pattern, e),
+            }.into()))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            deny,
+            large_file_size,
+        })
+    }
+
+    /// Inspect the files a package would upload
+    pub async fn inspect(&self, package_info: &PackageInfo) -> Result<PackageContents> {
+        let files = list_package_files(package_info).await?;
+        let package_dir = package_info.cargo_toml_path.parent()
+            .unwrap_or(&package_info.absolute_path);
+
+        let mut uncompressed_size = 0;
+        let mut denied_files = Vec::new();
+        let mut large_files = Vec::new();
+
+        for file in &files {
+            if let Some(pattern) = self.denied_by(file) {
+                denied_files.push((file.clone(), pattern.to_string()));
+            }
+
+            // Generated files such as Cargo.toml.orig have no on-disk counterpart
+            let Ok(metadata) = std::fs::metadata(package_dir.join(file)) else {
+                continue;
+            };
+            uncompressed_size += metadata.len();
+            if metadata.len() > self.large_file_size {
+                large_files.push((file.clone(), metadata.len()));
+            }
+        }
+
+        Ok(PackageContents {
+            package_name: package_info.name.clone(),
+            files,
+            uncompressed_size,
+            compressed_size: None,
+            denied_files,
+            large_files,
+        })
+    }
+
+    /// Package the whole workspace and return each package's `.crate` size.
+    ///
+    /// Packaging all members together lets cargo resolve internal dependencies
+    /// whose new versions are not on the registry yet.
+    pub async fn compressed_sizes(&self, workspace: &WorkspaceInfo) -> Result<HashMap<String, u64>> {
+        let output = Command::new("cargo")
+            .args(["package", "--workspace", "--no-verify", "--allow-dirty", "--quiet"])
+            .current_dir(&workspace.root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| PublishError::DryRunFailed {
+                package: "workspace".to_string(),
+                reason: format!("Failed to execute cargo package: {}", e),
+            })?;
+
+        if !output.status.success() {
+            return Err(PublishError::DryRunFailed {
+                package: "workspace".to_string(),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }.into());
+        }
+
+        let package_dir = target_directory(&workspace.root).await?.join("package");
+        let mut sizes = HashMap::new();
+        for (package_name, package_info) in &workspace.packages {
+            let crate_file = package_dir.join(format!("{}-{}.crate", package_name, package_info.version));
+            if let Ok(metadata) = std::fs::metadata(&crate_file) {
+                sizes.insert(package_name.clone(), metadata.len());
+            }
+        }
+
+        Ok(sizes)
+    }
+
+    /// Deny pattern matching a file, checked against both its path and file name
+    fn denied_by(&self, file: &Path) -> Option<&str> {
+        let file_name = file.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        self.deny.iter()
+            .find(|pattern| pattern.matches_path(file) || pattern.matches(file_name))
+            .map(|pattern| pattern.as_str())
+    }
+}
+
+impl PackageContents {
+    /// Size used for the size ceiling: the `.crate` size when known
+    pub fn effective_size(&self) -> u64 {
+        self.compressed_size.unwrap_or(self.uncompressed_size)
+    }
+
+    /// Format a one-line summary of the package contents
+    pub fn format_summary(&self) -> String {
+        match self.compressed_size {
+            Some(compressed) => format!(
+                "{}: {} files, {} ({} compressed)",
+                self.package_name,
+                self.files.len(),
+                format_size(self.uncompressed_size),
+                format_size(compressed)
+            ),
+            None => format!(
+                "{}: {} files, {}",
+                self.package_name,
+                self.files.len(),
+                format_size(self.uncompressed_size)
+            ),
+        }
+    }
+}
+
+/// Format a byte count for display
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Run `cargo package --list` for a package
+async fn list_package_files(package_info: &PackageInfo) -> Result<Vec<PathBuf>> {
+    let output = Command::new("cargo")
+        .arg("package")
+        .arg("--list")
+        .arg("--allow-dirty")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(&package_info.cargo_toml_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| PublishError::DryRunFailed {
+            package: package_info.name.clone(),
+            reason: format!("Failed to execute cargo package --list: {}", e),
+        })?;
+
+    if !output.status.success() {
+        return Err(PublishError::DryRunFailed {
+            package: package_info.name.clone(),
+            reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }.into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Resolve the cargo target directory of a workspace
+async fn target_directory(workspace_root: &Path) -> Result<PathBuf> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(workspace_root)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| PublishError::DryRunFailed {
+            package: "workspace".to_string(),
+            reason: format!("Failed to execute cargo metadata: {}", e),
+        })?;
+
+    let target_dir = serde_json::from_slice::<serde_json::Value>(&output.stdout)
+        .ok()
+        .and_then(|metadata| metadata["target_directory"].as_str().map(PathBuf::from));
+
+    Ok(target_dir.unwrap_or_else(|| workspace_root.join("target")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inspector() -> PackageContentsInspector {
+        let deny: Vec<String> = DEFAULT_DENY_PATTERNS.iter().map(|pattern| pattern.to_string()).collect();
+        PackageContentsInspector::new(&deny, 1024).unwrap()
+    }
+
+    fn contents(compressed_size: Option<u64>) -> PackageContents {
+        PackageContents {
+            package_name: "demo".to_string(),
+            files: vec![PathBuf::from("Cargo.toml"), PathBuf::from("src/lib.rs")],
+            uncompressed_size: 3 * 1024 * 1024 / 2,
+            compressed_size,
+            denied_files: Vec::new(),
+            large_files: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_default_deny_patterns() {
+        let inspector = inspector();
+        for (file, pattern) in [
+            (".env", ".env"),
+            ("config/.env.production", ".env.*"),
+            ("certs/server.pem", "*.pem"),
+            ("keys/id_ed25519.pub", "id_ed25519*"),
+            ("credentials.json", "credentials*"),
+            (".cyrup_release_state.json", ".cyrup_release_state*"),
+        ] {
+            assert_eq!(inspector.denied_by(Path::new(file)), Some(pattern), "{}", file);
+        }
+        for file in ["src/lib.rs", "README.md", "src/env.rs", "tests/keys.rs"] {
+            assert_eq!(inspector.denied_by(Path::new(file)), None, "{}", file);
+        }
+    }
+
+    #[test]
+    fn test_invalid_deny_pattern_is_a_config_error() {
+        let error = PackageContentsInspector::new(&["[".to_string()], 1024).unwrap_err();
+        assert!(error.to_string().contains("Invalid deny pattern '['"), "{}", error);
+    }
+
+    #[test]
+    fn test_sizes_and_summary() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(DEFAULT_MAX_PACKAGE_SIZE), "10.0 MiB");
+        assert_eq!(format_size(5 * 1024 * 1024 * 1024 * 1024), "5120.0 GiB");
+
+        assert_eq!(contents(None).effective_size(), 3 * 1024 * 1024 / 2);
+        assert_eq!(contents(None).format_summary(), "demo: 2 files, 1.5 MiB");
+        assert_eq!(contents(Some(2048)).effective_size(), 2048);
+        assert_eq!(contents(Some(2048)).format_summary(), "demo: 2 files, 1.5 MiB (2.0 KiB compressed)");
+    }
+
+    #[tokio::test]
+    async fn test_inspect_flags_denied_and_large_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[workspace]\nmembers = [\"demo\"]\n").unwrap();
+        let root = dir.path().join("demo");
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\nlicense = \"MIT\"\ndescription = \"demo\"\n",
+        ).unwrap();
+        std::fs::write(root.join("src/lib.rs"), "").unwrap();
+        std::fs::write(root.join("server.pem"), "-----BEGIN-----\n").unwrap();
+        std::fs::write(root.join("data.bin"), vec![0u8; 4096]).unwrap();
+
+        let package = WorkspaceInfo::analyze(dir.path()).unwrap().packages.remove("demo").unwrap();
+        let contents = inspector().inspect(&package).await.unwrap();
+
+        assert!(contents.files.contains(&PathBuf::from("src/lib.rs")), "{:?}", contents.files);
+        assert_eq!(contents.denied_files, vec![(PathBuf::from("server.pem"), "*.pem".to_string())]);
+        assert_eq!(contents.large_files, vec![(PathBuf::from("data.bin"), 4096)]);
+        assert!(contents.uncompressed_size >= 4096 + 16);
+        assert_eq!(contents.compressed_size, None);
+    }
+}
@@ -5,22 +5,55 @@
 
 use crate::error::{Result, WorkspaceError};
 use crate::workspace::WorkspaceInfo;
-use petgraph::algo::{toposort, DfsSpace};
-use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::algo::toposort;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeFiltered, EdgeRef};
 use petgraph::Graph;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt::Write as _;
 
 /// Dependency graph representing package relationships
 #[derive(Debug, Clone)]
 pub struct DependencyGraph {
     /// Directed graph where edges point from dependency to dependent
-    graph: Graph<String, (), petgraph::Directed>,
+    graph: Graph<String, DependencyKind, petgraph::Directed>,
     /// Mapping from package names to graph node indices
     node_map: HashMap<String, NodeIndex>,
     /// Reverse mapping from node indices to package names
     index_map: HashMap<NodeIndex, String>,
 }
 
+/// Kind of dependency an edge represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DependencyKind {
+    /// Regular `[dependencies]` entry
+    Normal,
+    /// `[build-dependencies]` entry
+    Build,
+    /// `[dev-dependencies]` entry (shown in exports, ignored for publish ordering)
+    Dev,
+}
+
+impl DependencyKind {
+    /// Whether the dependency must be published before its dependent
+    pub fn constrains_order(self) -> bool {
+        self != DependencyKind::Dev
+    }
+}
+
+/// A dependency edge between two workspace packages
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyEdge {
+    /// Package that declares the dependency
+    pub dependent: String,
+    /// Package being depended on
+    pub dependency: String,
+    /// Kind of dependency
+    pub kind: DependencyKind,
+}
+
 /// Publishing order with packages grouped into tiers
 #[derive(Debug, Clone)]
 pub struct PublishOrder {
@@ -74,7 +107,30 @@ impl DependencyGraph {
                 };
 
                 // Edge from dependency to dependent (dependency must be published first)
-                graph.add_edge(*dependency_index, *dependent_index, ());
+                graph.add_edge(*dependency_index, *dependent_index, DependencyKind::Normal);
+            }
+        }
+
+        // Build and dev dependencies are recorded with a prefix by the analyzer
+        for (package_name, package_info) in &workspace.packages {
+            let Some(dependent_index) = node_map.get(package_name) else {
+                continue;
+            };
+
+            for (key, spec) in &package_info.all_dependencies {
+                let (kind, dependency_name) = match key.split_once(':') {
+                    Some(("build", name)) => (DependencyKind::Build, name),
+                    Some(("dev", name)) => (DependencyKind::Dev, name),
+                    _ => continue,
+                };
+
+                if spec.path.is_none() || dependency_name == package_name {
+                    continue;
+                }
+
+                if let Some(dependency_index) = node_map.get(dependency_name) {
+                    graph.add_edge(*dependency_index, *dependent_index, kind);
+                }
             }
         }
 
@@ -90,8 +146,9 @@ impl DependencyGraph {
         // Detect circular dependencies first
         self.validate_no_cycles()?;
 
-        // Perform topological sort
-        let sorted_indices = toposort(&self.graph, None)
+        // Perform topological sort; dev-dependencies may form cycles cargo tolerates
+        let ordering_graph = EdgeFiltered::from_fn(&self.graph, |edge| edge.weight().constrains_order());
+        let sorted_indices = toposort(&ordering_graph, None)
             .map_err(|cycle| {
                 let cycle_packages: Vec<String> = self.index_map.get(&cycle.node_id())
                     .map(|name| vec![name.clone()])
@@ -173,9 +230,11 @@ impl DependencyGraph {
         };
 
         // Check if any dependencies are still in the remaining packages
-        let neighbors = self.graph.neighbors_directed(package_index, petgraph::Direction::Incoming);
-        
-        for dependency_index in neighbors {
+        let dependencies = self.graph.edges_directed(package_index, petgraph::Direction::Incoming)
+            .filter(|edge| edge.weight().constrains_order())
+            .map(|edge| edge.source());
+
+        for dependency_index in dependencies {
             if let Some(dependency_name) = self.index_map.get(&dependency_index) {
                 if remaining_packages.iter().any(|&name| name == dependency_name) {
                     return false; // Dependency still needs to be published
@@ -188,73 +247,196 @@ impl DependencyGraph {
 
     /// Validate that the dependency graph has no circular dependencies
     fn validate_no_cycles(&self) -> Result<()> {
-        // Convert to undirected graph for cycle detection
-        let _undirected: UnGraph<String, ()> = self.graph.clone().into_edge_type();
-        
-        // Use DFS to detect cycles more efficiently
-        let mut dfs_space = DfsSpace::new(&self.graph);
-        
-        // Check each strongly connected component
-        for node_index in self.graph.node_indices() {
-            if let Some(cycle_path) = self.find_cycle_from_node(node_index, &mut dfs_space) {
-                let cycle_packages: Vec<String> = cycle_path
-                    .into_iter()
-                    .filter_map(|idx| self.index_map.get(&idx).cloned())
+        match self.find_cycle() {
+            Some(cycle) => {
+                let mut packages: Vec<String> = cycle.iter()
+                    .map(|edge| edge.dependent.clone())
                     .collect();
+                if let Some(first) = packages.first().cloned() {
+                    packages.push(first);
+                }
 
-                return Err(WorkspaceError::CircularDependency {
-                    packages: cycle_packages,
-                }.into());
+                Err(WorkspaceError::CircularDependency { packages }.into())
             }
+            None => Ok(()),
         }
-
-        Ok(())
     }
 
-    /// Find cycle starting from a specific node using DFS
-    fn find_cycle_from_node(
-        &self,
-        start_node: NodeIndex,
-        _dfs_space: &mut DfsSpace<NodeIndex, fixedbitset::FixedBitSet>,
-    ) -> Option<Vec<NodeIndex>> {
+    /// Find a cycle of publish-ordering edges, returned along the "depends on" direction
+    pub fn find_cycle(&self) -> Option<Vec<DependencyEdge>> {
         let mut visited = std::collections::HashSet::new();
-        let mut recursion_stack = std::collections::HashSet::new();
+        let mut on_stack = std::collections::HashSet::new();
         let mut path = Vec::new();
 
-        self.dfs_cycle_detection(start_node, &mut visited, &mut recursion_stack, &mut path)
+        let mut start_nodes: Vec<NodeIndex> = self.graph.node_indices().collect();
+        start_nodes.sort_by(|a, b| self.graph[*a].cmp(&self.graph[*b]));
+
+        for start_node in start_nodes {
+            if visited.contains(&start_node) {
+                continue;
+            }
+            if let Some(cycle) = self.dfs_cycle_detection(start_node, &mut visited, &mut on_stack, &mut path) {
+                return Some(cycle);
+            }
+        }
+
+        None
     }
 
-    /// DFS-based cycle detection with path tracking
+    /// DFS-based cycle detection following "depends on" edges with path tracking
     fn dfs_cycle_detection(
         &self,
         node: NodeIndex,
         visited: &mut std::collections::HashSet<NodeIndex>,
-        recursion_stack: &mut std::collections::HashSet<NodeIndex>,
-        path: &mut Vec<NodeIndex>,
-    ) -> Option<Vec<NodeIndex>> {
+        on_stack: &mut std::collections::HashSet<NodeIndex>,
+        path: &mut Vec<DependencyEdge>,
+    ) -> Option<Vec<DependencyEdge>> {
         visited.insert(node);
-        recursion_stack.insert(node);
-        path.push(node);
-
-        // Visit all neighbors
-        let neighbors: Vec<_> = self.graph.neighbors_directed(node, petgraph::Direction::Outgoing).collect();
-        for neighbor in neighbors {
-            if !visited.contains(&neighbor) {
-                if let Some(cycle) = self.dfs_cycle_detection(neighbor, visited, recursion_stack, path) {
-                    return Some(cycle);
-                }
-            } else if recursion_stack.contains(&neighbor) {
-                // Found a cycle - extract the cycle path
-                let cycle_start = path.iter().position(|&n| n == neighbor)?;
+        on_stack.insert(node);
+
+        // Incoming edges point from this package's dependencies
+        let edges: Vec<_> = self.graph.edges_directed(node, petgraph::Direction::Incoming)
+            .filter(|edge| edge.weight().constrains_order())
+            .map(|edge| (edge.source(), *edge.weight()))
+            .collect();
+
+        for (dependency, kind) in edges {
+            path.push(DependencyEdge {
+                dependent: self.graph[node].clone(),
+                dependency: self.graph[dependency].clone(),
+                kind,
+            });
+
+            if on_stack.contains(&dependency) {
+                // Found a cycle - keep the edges from where it closes
+                let dependency_name = &self.graph[dependency];
+                let cycle_start = path.iter().position(|edge| &edge.dependent == dependency_name)?;
                 return Some(path[cycle_start..].to_vec());
             }
+
+            if !visited.contains(&dependency)
+                && let Some(cycle) = self.dfs_cycle_detection(dependency, visited, on_stack, path)
+            {
+                return Some(cycle);
+            }
+
+            path.pop();
         }
 
-        recursion_stack.remove(&node);
-        path.pop();
+        on_stack.remove(&node);
         None
     }
 
+    /// All dependency edges, sorted by dependent then dependency
+    pub fn edges(&self) -> Vec<DependencyEdge> {
+        let mut edges: Vec<DependencyEdge> = self.graph.edge_references()
+            .map(|edge| DependencyEdge {
+                dependent: self.graph[edge.target()].clone(),
+                dependency: self.graph[edge.source()].clone(),
+                kind: *edge.weight(),
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.dependent, &a.dependency).cmp(&(&b.dependent, &b.dependency)));
+        edges
+    }
+
+    /// Package names in the graph, sorted
+    pub fn packages(&self) -> Vec<String> {
+        let mut packages: Vec<String> = self.node_map.keys().cloned().collect();
+        packages.sort();
+        packages
+    }
+
+    /// Render the graph in Graphviz DOT format, clustering packages by publish tier
+    pub fn to_dot(&self, order: Option<&PublishOrder>) -> String {
+        let mut out = String::from("digraph workspace {\n    rankdir=BT;\n    node [shape=box];\n");
+
+        match order {
+            Some(order) => {
+                for tier in &order.tiers {
+                    let _ = writeln!(out, "    subgraph cluster_tier_{} {{", tier.tier_number);
+                    let _ = writeln!(out, "        label=\"tier {}\";", tier.tier_number);
+                    for package in &tier.packages {
+                        let _ = writeln!(out, "        \"{}\";", package);
+                    }
+                    out.push_str("    }\n");
+                }
+            }
+            None => {
+                for package in self.packages() {
+                    let _ = writeln!(out, "    \"{}\";", package);
+                }
+            }
+        }
+
+        for edge in self.edges() {
+            let style = match edge.kind {
+                DependencyKind::Normal => "",
+                DependencyKind::Build => " [style=bold, label=\"build\"]",
+                DependencyKind::Dev => " [style=dashed, label=\"dev\"]",
+            };
+            let _ = writeln!(out, "    \"{}\" -> \"{}\"{};", edge.dependent, edge.dependency, style);
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render the graph as a Mermaid flowchart, grouping packages by publish tier
+    pub fn to_mermaid(&self, order: Option<&PublishOrder>) -> String {
+        let packages = self.packages();
+        let node_id = |name: &str| {
+            packages.iter().position(|package| package == name)
+                .map(|index| format!("n{}", index))
+                .unwrap_or_default()
+        };
+
+        let mut out = String::from("graph BT\n");
+        match order {
+            Some(order) => {
+                for tier in &order.tiers {
+                    let _ = writeln!(out, "    subgraph tier_{}[\"Tier {}\"]", tier.tier_number, tier.tier_number);
+                    for package in &tier.packages {
+                        let _ = writeln!(out, "        {}[\"{}\"]", node_id(package), package);
+                    }
+                    out.push_str("    end\n");
+                }
+            }
+            None => {
+                for package in &packages {
+                    let _ = writeln!(out, "    {}[\"{}\"]", node_id(package), package);
+                }
+            }
+        }
+
+        for edge in self.edges() {
+            let arrow = match edge.kind {
+                DependencyKind::Normal => "-->",
+                DependencyKind::Build => "==>|build|",
+                DependencyKind::Dev => "-.->|dev|",
+            };
+            let _ = writeln!(out, "    {} {} {}", node_id(&edge.dependent), arrow, node_id(&edge.dependency));
+        }
+
+        out
+    }
+
+    /// Render the graph as JSON with packages, edges, and publish tiers
+    pub fn to_json(&self, order: Option<&PublishOrder>) -> Result<String> {
+        let tiers: Option<Vec<&Vec<String>>> = order.map(|order| {
+            order.tiers.iter().map(|tier| &tier.packages).collect()
+        });
+
+        let json = serde_json::json!({
+            "packages": self.packages(),
+            "edges": self.edges(),
+            "tiers": tiers,
+            "cycle": self.find_cycle(),
+        });
+
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+
     /// Get all packages that depend on the given package
     pub fn dependents(&self, package_name: &str) -> Vec<String> {
         let package_index = match self.node_map.get(package_name) {
@@ -263,8 +445,9 @@ impl DependencyGraph {
         };
 
         self.graph
-            .neighbors_directed(package_index, petgraph::Direction::Outgoing)
-            .filter_map(|idx| self.index_map.get(&idx).cloned())
+            .edges_directed(package_index, petgraph::Direction::Outgoing)
+            .filter(|edge| edge.weight().constrains_order())
+            .filter_map(|edge| self.index_map.get(&edge.target()).cloned())
             .collect()
     }
 
@@ -276,8 +459,9 @@ impl DependencyGraph {
         };
 
         self.graph
-            .neighbors_directed(package_index, petgraph::Direction::Incoming)
-            .filter_map(|idx| self.index_map.get(&idx).cloned())
+            .edges_directed(package_index, petgraph::Direction::Incoming)
+            .filter(|edge| edge.weight().constrains_order())
+            .filter_map(|edge| self.index_map.get(&edge.source()).cloned())
             .collect()
     }
 
@@ -294,7 +478,8 @@ impl DependencyGraph {
         };
 
         // Use petgraph's has_path_connecting for efficient path detection
-        petgraph::algo::has_path_connecting(&self.graph, start_index, target_index, None)
+        let ordering_graph = EdgeFiltered::from_fn(&self.graph, |edge| edge.weight().constrains_order());
+        petgraph::algo::has_path_connecting(&ordering_graph, start_index, target_index, None)
     }
 
     /// Get the dependency depth of a package (longest path from any root)
@@ -320,8 +505,9 @@ impl DependencyGraph {
         visited.insert(node);
 
         let max_dependency_depth = self.graph
-            .neighbors_directed(node, petgraph::Direction::Incoming)
-            .map(|dep_idx| self.calculate_depth_recursive(dep_idx, visited))
+            .edges_directed(node, petgraph::Direction::Incoming)
+            .filter(|edge| edge.weight().constrains_order())
+            .map(|edge| self.calculate_depth_recursive(edge.source(), visited))
             .max()
             .unwrap_or(0);
 
@@ -380,4 +566,99 @@ impl PublishTier {
     pub fn is_parallel_publishable(&self) -> bool {
         self.packages.len() > 1
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Writes a workspace of `(name, dependency sections)` packages and analyzes it
+    fn workspace(packages: &[(&str, &str)]) -> (TempDir, WorkspaceInfo) {
+        let dir = tempfile::tempdir().unwrap();
+        let members: Vec<String> = packages.iter().map(|(name, _)| format!("\"{}\"", name)).collect();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            format!("[workspace]\nmembers = [{}]\n", members.join(", ")),
+        ).unwrap();
+        for (name, dependencies) in packages {
+            let package = dir.path().join(name);
+            std::fs::create_dir_all(package.join("src")).unwrap();
+            std::fs::write(package.join("src/lib.rs"), "").unwrap();
+            std::fs::write(
+                package.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n\n{}", name, dependencies),
+            ).unwrap();
+        }
+        let workspace = WorkspaceInfo::analyze(dir.path()).unwrap();
+        (dir, workspace)
+    }
+
+    fn edge(dependent: &str, dependency: &str, kind: DependencyKind) -> DependencyEdge {
+        DependencyEdge {
+            dependent: dependent.to_string(),
+            dependency: dependency.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_cycle_is_reported_with_its_path() {
+        let (_dir, workspace) = workspace(&[
+            ("alpha", "[dependencies]\nbeta = { path = \"../beta\" }\n"),
+            ("beta", "[build-dependencies]\nalpha = { path = \"../alpha\" }\n"),
+            ("gamma", "[dependencies]\nalpha = { path = \"../alpha\" }\n"),
+        ]);
+        let graph = DependencyGraph::build(&workspace).unwrap();
+
+        assert_eq!(graph.find_cycle(), Some(vec![
+            edge("alpha", "beta", DependencyKind::Normal),
+            edge("beta", "alpha", DependencyKind::Build),
+        ]));
+        match graph.publish_order() {
+            Err(crate::error::ReleaseError::Workspace(WorkspaceError::CircularDependency { packages })) => {
+                assert_eq!(packages, vec!["alpha", "beta", "alpha"]);
+            }
+            other => panic!("expected a circular dependency error, got {:?}", other.map(|order| order.tiers)),
+        }
+    }
+
+    #[test]
+    fn test_acyclic_graph_is_published_in_tiers() {
+        let (_dir, workspace) = workspace(&[
+            ("alpha", "[dependencies]\nbeta = { path = \"../beta\" }\ngamma = { path = \"../gamma\" }\n"),
+            ("beta", "[dependencies]\ngamma = { path = \"../gamma\" }\n"),
+            ("gamma", ""),
+            ("delta", ""),
+        ]);
+        let graph = DependencyGraph::build(&workspace).unwrap();
+
+        assert_eq!(graph.find_cycle(), None);
+        let order = graph.publish_order().unwrap();
+        let mut tiers: Vec<Vec<String>> = order.tiers.iter().map(|tier| tier.packages.clone()).collect();
+        tiers.iter_mut().for_each(|tier| tier.sort());
+        assert_eq!(tiers, vec![vec!["delta", "gamma"], vec!["beta"], vec!["alpha"]]);
+        assert_eq!(order.total_packages, 4);
+        assert_eq!(graph.dependency_depth("alpha"), 3);
+        assert_eq!(graph.dependency_depth("delta"), 1);
+    }
+
+    #[test]
+    fn test_dev_dependency_back_edge_is_not_a_cycle() {
+        let (_dir, workspace) = workspace(&[
+            ("alpha", "[dependencies]\nbeta = { path = \"../beta\" }\n"),
+            ("beta", "[dev-dependencies]\nalpha = { path = \"../alpha\" }\n"),
+        ]);
+        let graph = DependencyGraph::build(&workspace).unwrap();
+
+        assert_eq!(graph.edges(), vec![
+            edge("alpha", "beta", DependencyKind::Normal),
+            edge("beta", "alpha", DependencyKind::Dev),
+        ]);
+        assert_eq!(graph.find_cycle(), None);
+        let order = graph.publish_order().unwrap();
+        let tiers: Vec<Vec<String>> = order.tiers.iter().map(|tier| tier.packages.clone()).collect();
+        assert_eq!(tiers, vec![vec!["beta"], vec!["alpha"]]);
+        assert_eq!(graph.dependencies("beta"), Vec::<String>::new());
+        assert_eq!(graph.dependents("alpha"), Vec::<String>::new());
+    }
+}
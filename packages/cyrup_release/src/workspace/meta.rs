@@ -0,0 +1,392 @@
+//! Workspaces released together from a meta-repo manifest.
+//!
+//! A `release-meta.toml` lists sibling workspace roots. A workspace whose
+//! packages depend on another workspace's packages is released after it, so
+//! every upstream crate is published before the crates that consume it and
+//! consumers can be moved to the versions just released. Dev-dependencies do
+//! not constrain the order, matching publish order inside a workspace.
+
+use crate::error::{ConfigError, Result, WorkspaceError};
+use crate::version::TomlEditor;
+use crate::workspace::WorkspaceInfo;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Default file name of the meta manifest
+pub const DEFAULT_META_MANIFEST: &str = "release-meta.toml";
+
+/// Workspaces listed in a meta manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct MetaManifest {
+    /// Workspaces to release (`[[workspace]]`)
+    #[serde(rename = "workspace", default)]
+    pub workspaces: Vec<MetaManifestEntry>,
+    /// Path the manifest was loaded from
+    #[serde(skip)]
+    pub source_path: PathBuf,
+}
+
+/// One `[[workspace]]` entry of the meta manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct MetaManifestEntry {
+    /// Workspace root, relative to the manifest
+    pub path: PathBuf,
+    /// Display name (defaults to the directory name)
+    pub name: Option<String>,
+}
+
+/// A workspace of a meta-repo
+#[derive(Debug, Clone)]
+pub struct MetaWorkspace {
+    /// Workspace name
+    pub name: String,
+    /// Analyzed workspace
+    pub info: WorkspaceInfo,
+    /// Workspaces this one must be released after
+    pub depends_on: Vec<String>,
+}
+
+/// Workspaces of a meta-repo in release order
+#[derive(Debug, Clone)]
+pub struct MetaRepo {
+    /// Workspaces, upstream first
+    pub workspaces: Vec<MetaWorkspace>,
+}
+
+impl MetaManifest {
+    /// Load a meta manifest
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(ConfigError::NotFound {
+                path: path.to_path_buf(),
+            }.into());
+        }
+
+        let contents = std::fs::read_to_string(path)?;
+        let mut manifest: Self = toml::from_str(&contents)
+            .map_err(|e| ConfigError::ParseFailed {
+                path: path.to_path_buf(),
+                reason: e.to_string(),
+            })?;
+        manifest.source_path = path.to_path_buf();
+
+        if manifest.workspaces.is_empty() {
+            return Err(ConfigError::InvalidValue {
+                key: "workspace".to_string(),
+                reason: format!("{} lists no workspaces", path.display()),
+            }.into());
+        }
+
+        Ok(manifest)
+    }
+
+    /// Directory workspace paths are relative to
+    pub fn base_dir(&self) -> &Path {
+        self.source_path.parent().unwrap_or_else(|| Path::new("."))
+    }
+}
+
+impl MetaRepo {
+    /// Analyze every workspace of a manifest and order them by cross-workspace dependencies
+    pub fn analyze(manifest: &MetaManifest) -> Result<Self> {
+        let mut workspaces: Vec<MetaWorkspace> = Vec::new();
+        let mut owners: BTreeMap<String, String> = BTreeMap::new();
+
+        for entry in &manifest.workspaces {
+            let info = WorkspaceInfo::analyze(manifest.base_dir().join(&entry.path))?;
+            let name = entry.name.clone().unwrap_or_else(|| {
+                info.root.file_name()
+                    .map_or_else(|| info.root.display().to_string(), |name| name.to_string_lossy().to_string())
+            });
+
+            if let Some(existing) = workspaces.iter().find(|workspace| workspace.name == name || workspace.info.root == info.root) {
+                return Err(meta_error(format!(
+                    "'{}' ({}) is listed twice or shares its name with {}",
+                    name,
+                    info.root.display(),
+                    existing.info.root.display()
+                )));
+            }
+            for package_name in info.packages.keys() {
+                if let Some(owner) = owners.insert(package_name.clone(), name.clone()) {
+                    return Err(meta_error(format!(
+                        "Package '{}' is a member of both '{}' and '{}'",
+                        package_name, owner, name
+                    )));
+                }
+            }
+
+            workspaces.push(MetaWorkspace {
+                name,
+                info,
+                depends_on: Vec::new(),
+            });
+        }
+
+        for workspace in &mut workspaces {
+            let upstream: BTreeSet<String> = workspace.info.packages.values()
+                .flat_map(|package| package.all_dependencies.keys())
+                .filter(|key| !key.starts_with("dev:"))
+                .map(|key| key.strip_prefix("build:").unwrap_or(key))
+                .filter_map(|dependency| owners.get(dependency))
+                .filter(|owner| **owner != workspace.name)
+                .cloned()
+                .collect();
+            workspace.depends_on = upstream.into_iter().collect();
+        }
+
+        Ok(Self {
+            workspaces: release_order(workspaces)?,
+        })
+    }
+
+    /// Workspace that has a package as a member
+    pub fn owner_of(&self, package_name: &str) -> Option<&MetaWorkspace> {
+        self.workspaces.iter().find(|workspace| workspace.info.packages.contains_key(package_name))
+    }
+
+    /// Format the release order for display
+    pub fn format_plan(&self) -> String {
+        let mut output = String::new();
+        for (index, workspace) in self.workspaces.iter().enumerate() {
+            output.push_str(&format!(
+                "  {}. {} ({}, {} packages)",
+                index + 1,
+                workspace.name,
+                workspace.info.root.display(),
+                workspace.info.packages.len()
+            ));
+            if !workspace.depends_on.is_empty() {
+                output.push_str(&format!(" after {}", workspace.depends_on.join(", ")));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Raise requirements on packages released from other workspaces to their new versions.
+///
+/// Member manifests and `[workspace.dependencies]` of the root manifest are
+/// updated; returns the manifests that changed.
+pub fn update_upstream_requirements(
+    workspace: &WorkspaceInfo,
+    released: &BTreeMap<String, Version>,
+) -> Result<Vec<PathBuf>> {
+    let root_manifest = workspace.root.join("Cargo.toml");
+    let mut members: Vec<PathBuf> = workspace.packages.values()
+        .map(|package| package.cargo_toml_path.clone())
+        .filter(|path| *path != root_manifest)
+        .collect();
+    members.sort();
+    let manifests = std::iter::once(root_manifest).chain(members);
+
+    let mut modified = Vec::new();
+    for manifest in manifests {
+        let mut editor = TomlEditor::open(&manifest)?;
+        for (package_name, version) in released {
+            editor.update_dependency_version_if_present(package_name, version)?;
+        }
+        if editor.is_modified() {
+            editor.save()?;
+            modified.push(manifest);
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Order workspaces so each comes after the workspaces it depends on, keeping manifest order otherwise
+fn release_order(mut pending: Vec<MetaWorkspace>) -> Result<Vec<MetaWorkspace>> {
+    let mut ordered: Vec<MetaWorkspace> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|workspace| {
+            workspace.depends_on.iter().all(|upstream| ordered.iter().any(|done| done.name == *upstream))
+        });
+        match ready {
+            Some(index) => ordered.push(pending.remove(index)),
+            None => {
+                // Every pending workspace waits on another pending one; follow them until one repeats
+                let mut cycle = vec![pending[0].name.clone()];
+                while let Some(next) = pending.iter()
+                    .find(|workspace| workspace.name == cycle[cycle.len() - 1])
+                    .and_then(|workspace| workspace.depends_on.iter()
+                        .find(|upstream| pending.iter().any(|other| other.name == **upstream)))
+                {
+                    let repeated = cycle.iter().position(|name| name == next);
+                    cycle.push(next.clone());
+                    if let Some(start) = repeated {
+                        cycle.drain(..start);
+                        break;
+                    }
+                }
+                return Err(WorkspaceError::CircularDependency { packages: cycle }.into());
+            }
+        }
+    }
+    Ok(ordered)
+}
+
+/// Create a meta-repo structure error
+fn meta_error(reason: String) -> crate::error::ReleaseError {
+    WorkspaceError::InvalidStructure { reason }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write a workspace at `base/dir` whose members declare the given dependency sections
+    fn write_workspace(base: &Path, dir: &str, members: &[(&str, &str)]) {
+        let root = base.join(dir);
+        let names: Vec<String> = members.iter().map(|(name, _)| format!("\"{}\"", name)).collect();
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("Cargo.toml"), format!("[workspace]\nmembers = [{}]\n", names.join(", "))).unwrap();
+        for (name, dependencies) in members {
+            let member = root.join(name);
+            std::fs::create_dir_all(member.join("src")).unwrap();
+            std::fs::write(member.join("src/lib.rs"), "").unwrap();
+            std::fs::write(
+                member.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n\n{}", name, dependencies),
+            ).unwrap();
+        }
+    }
+
+    /// Write and load a meta manifest listing `entries`
+    fn manifest(base: &Path, entries: &str) -> MetaManifest {
+        let path = base.join(DEFAULT_META_MANIFEST);
+        std::fs::write(&path, entries).unwrap();
+        MetaManifest::load(&path).unwrap()
+    }
+
+    /// Three workspaces where apps uses tools and tools uses core
+    fn meta_repo() -> (tempfile::TempDir, MetaManifest) {
+        let dir = tempfile::tempdir().unwrap();
+        write_workspace(dir.path(), "core", &[
+            ("core-types", ""),
+            // Dev-dependencies on downstream workspaces do not constrain the order
+            ("core-io", "[dev-dependencies]\napp-cli = { path = \"../../apps/app-cli\" }\n"),
+        ]);
+        write_workspace(dir.path(), "tools", &[
+            ("tool-kit", "[build-dependencies]\ncore-io = { path = \"../../core/core-io\", version = \"0.1.0\" }\n"),
+        ]);
+        write_workspace(dir.path(), "apps", &[
+            ("app-cli", "[dependencies]\ntool-kit = { path = \"../../tools/tool-kit\", version = \"0.1.0\" }\ncore-types = \"0.1\"\n"),
+        ]);
+        let manifest = manifest(dir.path(), r#"
+[[workspace]]
+path = "apps"
+
+[[workspace]]
+path = "tools"
+name = "toolbox"
+
+[[workspace]]
+path = "core"
+"#);
+        (dir, manifest)
+    }
+
+    fn names(repo: &MetaRepo) -> Vec<&str> {
+        repo.workspaces.iter().map(|workspace| workspace.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_load_requires_workspaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_META_MANIFEST);
+        assert!(MetaManifest::load(&path).unwrap_err().to_string().contains(DEFAULT_META_MANIFEST));
+
+        std::fs::write(&path, "").unwrap();
+        assert!(MetaManifest::load(&path).unwrap_err().to_string().contains("lists no workspaces"));
+
+        std::fs::write(&path, "[[workspace]]\npath = \"a\"\nbranch = \"main\"\n").unwrap();
+        assert!(MetaManifest::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_workspaces_are_released_upstream_first() {
+        let (dir, manifest) = meta_repo();
+        assert_eq!(manifest.base_dir(), dir.path());
+        assert_eq!(manifest.workspaces[1].name.as_deref(), Some("toolbox"));
+
+        let repo = MetaRepo::analyze(&manifest).unwrap();
+        assert_eq!(names(&repo), vec!["core", "toolbox", "apps"]);
+        assert_eq!(repo.workspaces[0].depends_on, Vec::<String>::new());
+        assert_eq!(repo.workspaces[1].depends_on, vec!["core".to_string()]);
+        assert_eq!(repo.workspaces[2].depends_on, vec!["core".to_string(), "toolbox".to_string()]);
+        assert_eq!(repo.owner_of("tool-kit").map(|workspace| workspace.name.as_str()), Some("toolbox"));
+        assert!(repo.owner_of("serde").is_none());
+
+        let plan = repo.format_plan();
+        let lines: Vec<&str> = plan.lines().collect();
+        assert!(lines[0].starts_with("  1. core (") && lines[0].ends_with(", 2 packages)"), "{}", plan);
+        assert!(lines[2].starts_with("  3. apps (") && lines[2].ends_with(", 1 packages) after core, toolbox"), "{}", plan);
+    }
+
+    #[test]
+    fn test_cross_workspace_cycle_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        write_workspace(dir.path(), "left", &[("left-a", "[dependencies]\nright-a = \"0.1\"\n")]);
+        write_workspace(dir.path(), "right", &[("right-a", "[dependencies]\nleft-a = \"0.1\"\n")]);
+        write_workspace(dir.path(), "leaf", &[("leaf-a", "")]);
+        let manifest = manifest(dir.path(), "[[workspace]]\npath = \"leaf\"\n[[workspace]]\npath = \"left\"\n[[workspace]]\npath = \"right\"\n");
+
+        match MetaRepo::analyze(&manifest) {
+            Err(crate::error::ReleaseError::Workspace(WorkspaceError::CircularDependency { packages })) => {
+                assert_eq!(packages, vec!["left".to_string(), "right".to_string(), "left".to_string()]);
+            }
+            other => panic!("expected a cycle, got {:?}", other.map(|repo| names(&repo).join(", "))),
+        }
+    }
+
+    #[test]
+    fn test_shared_members_and_duplicate_names_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        write_workspace(dir.path(), "one", &[("shared", "")]);
+        write_workspace(dir.path(), "two", &[("shared", "")]);
+
+        let error = MetaRepo::analyze(&manifest(dir.path(), "[[workspace]]\npath = \"one\"\n[[workspace]]\npath = \"two\"\n"))
+            .unwrap_err().to_string();
+        assert!(error.contains("Package 'shared' is a member of both 'one' and 'two'"), "{}", error);
+
+        let error = MetaRepo::analyze(&manifest(dir.path(), "[[workspace]]\npath = \"one\"\n[[workspace]]\npath = \"two\"\nname = \"one\"\n"))
+            .unwrap_err().to_string();
+        assert!(error.contains("listed twice"), "{}", error);
+    }
+
+    #[test]
+    fn test_update_upstream_requirements() {
+        let (dir, manifest) = meta_repo();
+        let root = dir.path().join("apps");
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"app-cli\"]\n\n[workspace.dependencies]\ncore-types = \"0.1\"\n",
+        ).unwrap();
+        let repo = MetaRepo::analyze(&manifest).unwrap();
+        let apps = &repo.workspaces[2].info;
+
+        let released = BTreeMap::from([
+            ("core-types".to_string(), Version::new(0, 2, 0)),
+            ("tool-kit".to_string(), Version::new(0, 1, 1)),
+            ("core-io".to_string(), Version::new(0, 2, 0)),
+        ]);
+        let modified = update_upstream_requirements(apps, &released).unwrap();
+        assert_eq!(modified, vec![root.join("Cargo.toml"), root.join("app-cli/Cargo.toml")]);
+
+        let member = std::fs::read_to_string(root.join("app-cli/Cargo.toml")).unwrap();
+        assert!(member.contains(r#"tool-kit = { path = "../../tools/tool-kit", version = "0.1.1" }"#), "{}", member);
+        assert!(member.contains(r#"core-types = "0.2.0""#), "{}", member);
+        let workspace = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains(r#"core-types = "0.2.0""#), "{}", workspace);
+
+        // Nothing left to raise
+        assert!(update_upstream_requirements(apps, &released).unwrap().is_empty());
+    }
+}
@@ -3,10 +3,27 @@
 //! This module provides functionality to analyze Cargo workspaces, build dependency graphs,
 //! and validate workspace structure for release operations.
 
+mod advisories;
 mod analyzer;
+mod contents;
 mod dependency;
+mod meta;
+mod nightly;
+mod patches;
+mod sbom;
 mod validator;
 
+pub use advisories::{Advisory, AdvisoryDatabase, AdvisoryMatch, DEFAULT_ADVISORY_DB_URL};
 pub use analyzer::{WorkspaceInfo, PackageInfo, WorkspaceConfig, PackageConfig, DependencySpec};
-pub use dependency::{DependencyGraph, PublishOrder, PublishTier};
-pub use validator::{WorkspaceValidator, ValidationResult, ValidationCheck};
\ No newline at end of file
+pub use contents::{
+    PackageContents, PackageContentsInspector, format_size,
+    DEFAULT_DENY_PATTERNS, DEFAULT_LARGE_FILE_SIZE, DEFAULT_MAX_PACKAGE_SIZE,
+};
+pub use dependency::{DependencyEdge, DependencyGraph, DependencyKind, PublishOrder, PublishTier};
+pub use meta::{
+    update_upstream_requirements, MetaManifest, MetaManifestEntry, MetaRepo, MetaWorkspace, DEFAULT_META_MANIFEST,
+};
+pub use nightly::{nightly_features, NightlyFeature};
+pub use patches::{internal_patches, strip_internal_patches, InternalPatch};
+pub use sbom::{Sbom, SbomComponent};
+pub use validator::{WorkspaceValidator, ValidatorConfig, ValidationResult, ValidationCheck};
\ No newline at end of file
@@ -0,0 +1,197 @@
+//! Detection of nightly-only language features in package sources.
+//!
+//! A crate root with `#![feature(...)]` only compiles on a nightly
+//! toolchain, so stable users of the published crate cannot build it.
+//! Features enabled through `#![cfg_attr(..., feature(...))]` are not
+//! reported, since they stay off unless the consumer opts in.
+
+use crate::error::{Result, WorkspaceError};
+use crate::workspace::PackageInfo;
+use std::path::PathBuf;
+
+/// A nightly feature enabled in a crate root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NightlyFeature {
+    /// Feature name (e.g. `negative_impls`)
+    pub feature: String,
+    /// Crate root enabling it, relative to the package root
+    pub file: PathBuf,
+}
+
+/// Find the nightly features each crate root of a package enables
+pub fn nightly_features(package: &PackageInfo) -> Result<Vec<NightlyFeature>> {
+    let mut features = Vec::new();
+    for root in crate_roots(package)? {
+        let Ok(source) = std::fs::read_to_string(package.absolute_path.join(&root)) else {
+            continue;
+        };
+        for feature in feature_attributes(&source) {
+            features.push(NightlyFeature {
+                feature,
+                file: root.clone(),
+            });
+        }
+    }
+
+    Ok(features)
+}
+
+/// Library, main, and binary crate roots of a package, relative to its root
+fn crate_roots(package: &PackageInfo) -> Result<Vec<PathBuf>> {
+    let manifest = std::fs::read_to_string(&package.cargo_toml_path)
+        .map_err(|e| WorkspaceError::InvalidPackage {
+            package: package.name.clone(),
+            reason: format!("Failed to read {}: {}", package.cargo_toml_path.display(), e),
+        })?;
+    let manifest: toml::Value = toml::from_str(&manifest)
+        .map_err(|e| WorkspaceError::InvalidPackage {
+            package: package.name.clone(),
+            reason: format!("Failed to parse {}: {}", package.cargo_toml_path.display(), e),
+        })?;
+
+    let declared_path = |target: &toml::Value| target.get("path").and_then(toml::Value::as_str).map(PathBuf::from);
+
+    let mut roots = vec![
+        manifest.get("lib").and_then(declared_path).unwrap_or_else(|| PathBuf::from("src/lib.rs")),
+        PathBuf::from("src/main.rs"),
+    ];
+    for bin in manifest.get("bin").and_then(toml::Value::as_array).into_iter().flatten() {
+        roots.extend(declared_path(bin));
+    }
+    if let Ok(entries) = std::fs::read_dir(package.absolute_path.join("src/bin")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let root = if path.is_dir() { path.join("main.rs") } else { path };
+            if root.extension().is_some_and(|extension| extension == "rs")
+                && let Ok(relative) = root.strip_prefix(&package.absolute_path)
+            {
+                roots.push(relative.to_path_buf());
+            }
+        }
+    }
+
+    roots.sort();
+    roots.dedup();
+    roots.retain(|root| package.absolute_path.join(root).is_file());
+    Ok(roots)
+}
+
+/// Feature names from unconditional `#![feature(...)]` attributes
+fn feature_attributes(source: &str) -> Vec<String> {
+    let code = strip_comments(source);
+    let mut features = Vec::new();
+    let mut rest = code.as_str();
+
+    while let Some(start) = rest.find("#!") {
+        rest = &rest[start + 2..];
+        let Some(attribute) = rest.trim_start().strip_prefix('[') else {
+            continue;
+        };
+        let Some(arguments) = attribute.trim_start()
+            .strip_prefix("feature")
+            .map(str::trim_start)
+            .and_then(|after| after.strip_prefix('('))
+        else {
+            continue;
+        };
+        let Some(end) = arguments.find(')') else {
+            break;
+        };
+        features.extend(
+            arguments[..end].split(',')
+                .map(str::trim)
+                .filter(|feature| !feature.is_empty())
+                .map(str::to_string),
+        );
+        rest = &arguments[end..];
+    }
+
+    features
+}
+
+/// Remove line and block comments so commented-out attributes are ignored
+fn strip_comments(source: &str) -> String {
+    let mut code = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        code.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+                code.push(' ');
+            }
+            _ => code.push(c),
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workspace::WorkspaceInfo;
+
+    #[test]
+    fn test_feature_attributes() {
+        let source = "//! Crate docs\n#![feature(negative_impls, never_type)]\n#![ feature ( trait_alias ) ]\n\
+                      #![deny(missing_docs)]\n#![cfg_attr(docsrs, feature(doc_cfg))]\nfn main() {}\n";
+        assert_eq!(feature_attributes(source), vec!["negative_impls", "never_type", "trait_alias"]);
+        assert_eq!(feature_attributes("#![feature(\n    let_chains,\n)]\n"), vec!["let_chains"]);
+        assert!(feature_attributes("#![forbid(unsafe_code)]\n").is_empty());
+        assert!(feature_attributes("#![feature(unterminated").is_empty());
+    }
+
+    #[test]
+    fn test_commented_out_features_are_ignored() {
+        let source = "// #![feature(line_comment)]\n/* #![feature(block_comment)] */\n\
+                      /*\n * #![feature(multi_line)]\n */\n#![feature(real)]\n";
+        assert_eq!(feature_attributes(source), vec!["real"]);
+        assert_eq!(strip_comments("a // b\nc /* d */ e"), "a \nc   e");
+    }
+
+    #[test]
+    fn test_nightly_features_of_every_crate_root() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[workspace]\nmembers = [\"demo\"]\n").unwrap();
+        let root = dir.path().join("demo");
+        for (file, contents) in [
+            ("Cargo.toml", "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n\n[lib]\npath = \"lib/root.rs\"\n\n[[bin]]\nname = \"extra\"\npath = \"tools/extra.rs\"\n"),
+            ("lib/root.rs", "#![feature(lib_feature)]\n"),
+            ("src/lib.rs", "#![feature(not_the_lib_root)]\n"),
+            ("src/main.rs", "fn main() {}\n"),
+            ("src/bin/single.rs", "#![feature(single_bin)]\nfn main() {}\n"),
+            ("src/bin/multi/main.rs", "#![feature(multi_bin)]\nfn main() {}\n"),
+            ("src/bin/notes.txt", "#![feature(not_rust)]\n"),
+            ("tools/extra.rs", "#![feature(declared_bin)]\nfn main() {}\n"),
+        ] {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        let package = WorkspaceInfo::analyze(dir.path()).unwrap().packages.remove("demo").unwrap();
+
+        let features: Vec<(String, String)> = nightly_features(&package).unwrap().into_iter()
+            .map(|found| (found.feature, found.file.display().to_string()))
+            .collect();
+        assert_eq!(features, vec![
+            ("lib_feature".to_string(), "lib/root.rs".to_string()),
+            ("multi_bin".to_string(), "src/bin/multi/main.rs".to_string()),
+            ("single_bin".to_string(), "src/bin/single.rs".to_string()),
+            ("declared_bin".to_string(), "tools/extra.rs".to_string()),
+        ]);
+    }
+}
@@ -0,0 +1,179 @@
+//! Detection of `[patch]` entries that point at workspace members.
+//!
+//! A `[patch.crates-io]` entry redirecting a crate to a member's path only
+//! applies to local builds: `cargo publish` ignores patches, so consumers of
+//! the published packages resolve the registry version instead. Such entries
+//! are reported before a release and can be removed from the release commit.
+
+use crate::error::{Result, WorkspaceError};
+use crate::version::TomlEditor;
+use crate::workspace::WorkspaceInfo;
+use std::path::PathBuf;
+
+/// A `[patch]` entry that redirects a dependency to a workspace member
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternalPatch {
+    /// Patched source (e.g. `crates-io` or a git URL)
+    pub source: String,
+    /// Key of the entry in the patch table
+    pub dependency: String,
+    /// Workspace member the entry points at
+    pub package: String,
+    /// Path as written in the manifest
+    pub path: PathBuf,
+}
+
+impl InternalPatch {
+    /// Format the entry for display
+    pub fn format_patch(&self) -> String {
+        format!(
+            "[patch.{}] {} → {} ({})",
+            self.source,
+            self.dependency,
+            self.package,
+            self.path.display()
+        )
+    }
+}
+
+/// Find the `[patch]` entries in the root manifest that point at workspace members
+pub fn internal_patches(workspace: &WorkspaceInfo) -> Result<Vec<InternalPatch>> {
+    let manifest_path = workspace.root.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&manifest_path)
+        .map_err(|e| WorkspaceError::InvalidStructure {
+            reason: format!("Failed to read {}: {}", manifest_path.display(), e),
+        })?;
+    let manifest: toml::Value = toml::from_str(&manifest)
+        .map_err(|e| WorkspaceError::InvalidStructure {
+            reason: format!("Failed to parse {}: {}", manifest_path.display(), e),
+        })?;
+
+    let Some(sources) = manifest.get("patch").and_then(toml::Value::as_table) else {
+        return Ok(Vec::new());
+    };
+
+    let member_dirs: Vec<(PathBuf, &str)> = workspace.packages.values()
+        .map(|package| {
+            let dir = package.absolute_path.canonicalize().unwrap_or_else(|_| package.absolute_path.clone());
+            (dir, package.name.as_str())
+        })
+        .collect();
+
+    let mut patches = Vec::new();
+    for (source, entries) in sources {
+        let Some(entries) = entries.as_table() else {
+            continue;
+        };
+        for (dependency, spec) in entries {
+            let Some(path) = spec.get("path").and_then(toml::Value::as_str) else {
+                continue;
+            };
+            let target = workspace.root.join(path);
+            let target = target.canonicalize().unwrap_or(target);
+            if let Some((_, package)) = member_dirs.iter().find(|(dir, _)| *dir == target) {
+                patches.push(InternalPatch {
+                    source: source.clone(),
+                    dependency: dependency.clone(),
+                    package: package.to_string(),
+                    path: PathBuf::from(path),
+                });
+            }
+        }
+    }
+
+    patches.sort_by(|a, b| a.source.cmp(&b.source).then_with(|| a.dependency.cmp(&b.dependency)));
+    Ok(patches)
+}
+
+/// Remove the `[patch]` entries that point at workspace members from the root manifest.
+///
+/// Returns the removed entries; the manifest is only written if there were any.
+pub fn strip_internal_patches(workspace: &WorkspaceInfo) -> Result<Vec<InternalPatch>> {
+    let patches = internal_patches(workspace)?;
+    if patches.is_empty() {
+        return Ok(patches);
+    }
+
+    let mut editor = TomlEditor::open(workspace.root.join("Cargo.toml"))?;
+    for patch in &patches {
+        editor.remove_patch(&patch.source, &patch.dependency)?;
+    }
+    editor.save()?;
+
+    Ok(patches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+members = ["alpha", "beta"]
+
+# Local overrides
+[patch.crates-io]
+alpha = { path = "alpha" }
+serde = { path = "../vendor/serde" }
+remote = { git = "https://github.com/acme/remote" }
+
+[patch."https://github.com/acme/beta"]
+beta-renamed = { path = "./beta", package = "beta" }
+"#;
+
+    fn workspace(manifest: &str) -> (tempfile::TempDir, WorkspaceInfo) {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), manifest).unwrap();
+        for name in ["alpha", "beta"] {
+            let member = dir.path().join(name);
+            std::fs::create_dir_all(member.join("src")).unwrap();
+            std::fs::write(member.join("src/lib.rs"), "").unwrap();
+            std::fs::write(member.join("Cargo.toml"), format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", name)).unwrap();
+        }
+        let workspace = WorkspaceInfo::analyze(dir.path()).unwrap();
+        (dir, workspace)
+    }
+
+    fn patch(source: &str, dependency: &str, package: &str, path: &str) -> InternalPatch {
+        InternalPatch {
+            source: source.to_string(),
+            dependency: dependency.to_string(),
+            package: package.to_string(),
+            path: PathBuf::from(path),
+        }
+    }
+
+    #[test]
+    fn test_only_patches_of_members_are_internal() {
+        let (_dir, workspace) = workspace(MANIFEST);
+        let patches = internal_patches(&workspace).unwrap();
+        assert_eq!(patches, vec![
+            patch("crates-io", "alpha", "alpha", "alpha"),
+            patch("https://github.com/acme/beta", "beta-renamed", "beta", "./beta"),
+        ]);
+        assert_eq!(patches[0].format_patch(), "[patch.crates-io] alpha → alpha (alpha)");
+    }
+
+    #[test]
+    fn test_strip_keeps_external_patches_and_comments() {
+        let (dir, workspace) = workspace(MANIFEST);
+        let removed = strip_internal_patches(&workspace).unwrap();
+        assert_eq!(removed.len(), 2);
+
+        let manifest = std::fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("# Local overrides"), "{}", manifest);
+        assert!(manifest.contains(r#"serde = { path = "../vendor/serde" }"#), "{}", manifest);
+        assert!(manifest.contains("remote = "), "{}", manifest);
+        assert!(!manifest.contains("alpha = {") && !manifest.contains("beta-renamed"), "{}", manifest);
+
+        let workspace = WorkspaceInfo::analyze(dir.path()).unwrap();
+        assert!(internal_patches(&workspace).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_manifest_without_patches_is_not_written() {
+        let manifest = "[workspace]\nmembers = [\"alpha\", \"beta\"]\n";
+        let (dir, workspace) = workspace(manifest);
+        assert!(strip_internal_patches(&workspace).unwrap().is_empty());
+        assert_eq!(std::fs::read_to_string(dir.path().join("Cargo.toml")).unwrap(), manifest);
+    }
+}
@@ -0,0 +1,517 @@
+//! Software bill of materials for released packages.
+//!
+//! Describes the released packages, the commit they were released from, and
+//! their resolved dependency tree as a CycloneDX 1.5 or SPDX 2.3 JSON
+//! document. The tree comes from `cargo metadata`, so it matches
+//! `Cargo.lock`; dev-dependencies are left out because they never ship to
+//! users of the released crates.
+
+use crate::error::{Result, WorkspaceError};
+use chrono::{DateTime, SecondsFormat, Utc};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Tool name recorded as the document creator
+const TOOL_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Tool version recorded as the document creator
+const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Bill of materials for one release
+#[derive(Debug, Clone)]
+pub struct Sbom {
+    /// Package ids of the released packages, in release order
+    pub released: Vec<String>,
+    /// Released packages and everything they depend on, by cargo package id
+    pub components: BTreeMap<String, SbomComponent>,
+    /// Commit the release was made from
+    pub commit: Option<String>,
+    /// Tags created for the release
+    pub tags: Vec<String>,
+    /// When the document was generated
+    pub created: DateTime<Utc>,
+}
+
+/// A package in the bill of materials
+#[derive(Debug, Clone)]
+pub struct SbomComponent {
+    /// Cargo package id
+    pub id: String,
+    /// Package name
+    pub name: String,
+    /// Resolved version
+    pub version: String,
+    /// SPDX license expression declared in the manifest
+    pub license: Option<String>,
+    /// Source the package is resolved from (`None` for path packages)
+    pub source: Option<String>,
+    /// Package ids of its normal and build dependencies
+    pub dependencies: Vec<String>,
+}
+
+impl Sbom {
+    /// Resolve the dependency tree of the released packages with `cargo metadata`
+    pub async fn generate(workspace_root: &Path, packages: &[String]) -> Result<Self> {
+        let output = Command::new("cargo")
+            .args(["metadata", "--format-version", "1"])
+            .current_dir(workspace_root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| sbom_error(format!("Failed to execute cargo metadata: {}", e)))?;
+
+        if !output.status.success() {
+            return Err(sbom_error(format!(
+                "cargo metadata failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let metadata: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| sbom_error(format!("Invalid cargo metadata output: {}", e)))?;
+
+        Self::from_metadata(&metadata, packages)
+    }
+
+    /// Build the bill of materials from `cargo metadata` output
+    pub fn from_metadata(metadata: &Value, packages: &[String]) -> Result<Self> {
+        let members: BTreeSet<&str> = metadata["workspace_members"].as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+
+        let mut all_packages = BTreeMap::new();
+        for package in metadata["packages"].as_array().into_iter().flatten() {
+            let (Some(id), Some(name), Some(version)) =
+                (package["id"].as_str(), package["name"].as_str(), package["version"].as_str())
+            else {
+                continue;
+            };
+            all_packages.insert(id.to_string(), SbomComponent {
+                id: id.to_string(),
+                name: name.to_string(),
+                version: version.to_string(),
+                license: package["license"].as_str().map(str::to_string),
+                source: package["source"].as_str().map(str::to_string),
+                dependencies: Vec::new(),
+            });
+        }
+
+        let nodes = metadata["resolve"]["nodes"].as_array()
+            .ok_or_else(|| sbom_error("cargo metadata did not include a resolved dependency graph".to_string()))?;
+        let mut edges: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for node in nodes {
+            let Some(id) = node["id"].as_str() else {
+                continue;
+            };
+            let dependencies = node["deps"].as_array()
+                .into_iter()
+                .flatten()
+                .filter(|dependency| ships_with_package(dependency))
+                .filter_map(|dependency| dependency["pkg"].as_str().map(str::to_string))
+                .collect();
+            edges.insert(id, dependencies);
+        }
+
+        let released = packages.iter()
+            .map(|name| {
+                members.iter()
+                    .find(|id| all_packages.get(**id).is_some_and(|package| &package.name == name))
+                    .map(|id| id.to_string())
+                    .ok_or_else(|| sbom_error(format!("Package '{}' is not a workspace member", name)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Keep only what the released packages reach
+        let mut components = BTreeMap::new();
+        let mut pending = released.clone();
+        while let Some(id) = pending.pop() {
+            if components.contains_key(&id) {
+                continue;
+            }
+            let Some(mut component) = all_packages.get(&id).cloned() else {
+                continue;
+            };
+            component.dependencies = edges.get(id.as_str()).cloned().unwrap_or_default();
+            pending.extend(component.dependencies.iter().cloned());
+            components.insert(id, component);
+        }
+
+        Ok(Self {
+            released,
+            components,
+            commit: None,
+            tags: Vec::new(),
+            created: Utc::now(),
+        })
+    }
+
+    /// Record the commit and tags of the release
+    pub fn set_git(&mut self, commit: &str, tags: &[String]) {
+        self.commit = Some(commit.to_string());
+        self.tags = tags.to_vec();
+    }
+
+    /// Released packages
+    pub fn released_components(&self) -> impl Iterator<Item = &SbomComponent> {
+        self.released.iter().filter_map(|id| self.components.get(id))
+    }
+
+    /// Render as a CycloneDX 1.5 JSON document
+    pub fn to_cyclonedx(&self) -> Result<String> {
+        let mut properties = Vec::new();
+        if let Some(commit) = &self.commit {
+            properties.push(json!({ "name": "cyrup_release:git:commit", "value": commit }));
+        }
+        for tag in &self.tags {
+            properties.push(json!({ "name": "cyrup_release:git:tag", "value": tag }));
+        }
+
+        let mut metadata = json!({
+            "timestamp": self.timestamp(),
+            "tools": {
+                "components": [{ "type": "application", "name": TOOL_NAME, "version": TOOL_VERSION }],
+            },
+            "properties": properties,
+        });
+        if let [released] = self.released.as_slice()
+            && let Some(component) = self.components.get(released)
+        {
+            metadata["component"] = cyclonedx_component(component, "application");
+        }
+
+        let components: Vec<Value> = self.components.values()
+            .map(|component| {
+                let kind = if self.released.contains(&component.id) { "application" } else { "library" };
+                cyclonedx_component(component, kind)
+            })
+            .collect();
+
+        let dependencies: Vec<Value> = self.components.values()
+            .map(|component| json!({ "ref": component.id, "dependsOn": component.dependencies }))
+            .collect();
+
+        let document = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "serialNumber": format!("urn:uuid:{}", random_uuid()?),
+            "version": 1,
+            "metadata": metadata,
+            "components": components,
+            "dependencies": dependencies,
+        });
+
+        render(&document)
+    }
+
+    /// Render as an SPDX 2.3 JSON document
+    pub fn to_spdx(&self) -> Result<String> {
+        let spdx_ids: BTreeMap<&str, String> = self.components.keys()
+            .enumerate()
+            .map(|(index, id)| {
+                let component = &self.components[id];
+                (id.as_str(), format!("SPDXRef-Package-{}-{}", index, spdx_id_safe(&component.name)))
+            })
+            .collect();
+
+        let packages: Vec<Value> = self.components.values()
+            .map(|component| {
+                let mut package = json!({
+                    "SPDXID": spdx_ids[component.id.as_str()],
+                    "name": component.name,
+                    "versionInfo": component.version,
+                    "downloadLocation": download_location(component),
+                    "filesAnalyzed": false,
+                    "licenseConcluded": "NOASSERTION",
+                    "licenseDeclared": component.license.as_deref().unwrap_or("NOASSERTION"),
+                    "copyrightText": "NOASSERTION",
+                    "externalRefs": [{
+                        "referenceCategory": "PACKAGE-MANAGER",
+                        "referenceType": "purl",
+                        "referenceLocator": purl(component),
+                    }],
+                });
+                if self.released.contains(&component.id)
+                    && let Some(commit) = &self.commit
+                {
+                    package["sourceInfo"] = json!(format!("Released from git commit {}", commit));
+                }
+                package
+            })
+            .collect();
+
+        let mut relationships: Vec<Value> = self.released.iter()
+            .map(|id| json!({
+                "spdxElementId": "SPDXRef-DOCUMENT",
+                "relationshipType": "DESCRIBES",
+                "relatedSpdxElement": spdx_ids[id.as_str()],
+            }))
+            .collect();
+        for component in self.components.values() {
+            for dependency in &component.dependencies {
+                if let Some(dependency_id) = spdx_ids.get(dependency.as_str()) {
+                    relationships.push(json!({
+                        "spdxElementId": spdx_ids[component.id.as_str()],
+                        "relationshipType": "DEPENDS_ON",
+                        "relatedSpdxElement": dependency_id,
+                    }));
+                }
+            }
+        }
+
+        let name = self.released_components()
+            .map(|component| format!("{}-{}", component.name, component.version))
+            .collect::<Vec<_>>()
+            .join("+");
+        let mut comment = String::new();
+        if let Some(commit) = &self.commit {
+            comment.push_str(&format!("Git commit: {}", commit));
+        }
+        if !self.tags.is_empty() {
+            if !comment.is_empty() {
+                comment.push_str("; ");
+            }
+            comment.push_str(&format!("Tags: {}", self.tags.join(", ")));
+        }
+
+        let mut document = json!({
+            "spdxVersion": "SPDX-2.3",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": name,
+            "documentNamespace": format!("urn:uuid:{}", random_uuid()?),
+            "creationInfo": {
+                "created": self.timestamp(),
+                "creators": [format!("Tool: {}-{}", TOOL_NAME, TOOL_VERSION)],
+            },
+            "packages": packages,
+            "relationships": relationships,
+        });
+        if !comment.is_empty() {
+            document["comment"] = json!(comment);
+        }
+
+        render(&document)
+    }
+
+    /// Creation time in RFC 3339 with second precision
+    fn timestamp(&self) -> String {
+        self.created.to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
+}
+
+impl SbomComponent {
+    /// Check if the package is resolved from crates.io
+    pub fn is_from_crates_io(&self) -> bool {
+        self.source.as_deref()
+            .is_some_and(|source| source.contains("crates.io-index") || source.contains("index.crates.io"))
+    }
+}
+
+/// Whether a resolved dependency is used outside of tests and benches
+fn ships_with_package(dependency: &Value) -> bool {
+    match dependency["dep_kinds"].as_array() {
+        Some(kinds) if !kinds.is_empty() => kinds.iter().any(|kind| kind["kind"].as_str() != Some("dev")),
+        _ => true,
+    }
+}
+
+/// CycloneDX component for a package
+fn cyclonedx_component(component: &SbomComponent, kind: &str) -> Value {
+    let mut value = json!({
+        "type": kind,
+        "bom-ref": component.id,
+        "name": component.name,
+        "version": component.version,
+        "purl": purl(component),
+    });
+    if let Some(license) = &component.license {
+        value["licenses"] = json!([{ "expression": license }]);
+    }
+    if let Some(source) = &component.source {
+        value["properties"] = json!([{ "name": "cyrup_release:cargo:source", "value": source }]);
+    }
+    value
+}
+
+/// Package URL of a crate
+fn purl(component: &SbomComponent) -> String {
+    format!("pkg:cargo/{}@{}", component.name, component.version)
+}
+
+/// Where a dependency can be downloaded from, as SPDX expects it
+fn download_location(component: &SbomComponent) -> String {
+    match component.source.as_deref() {
+        Some(_) if component.is_from_crates_io() => format!(
+            "https://crates.io/api/v1/crates/{}/{}/download",
+            component.name, component.version
+        ),
+        Some(source) if source.starts_with("git+") => source.to_string(),
+        _ => "NOASSERTION".to_string(),
+    }
+}
+
+/// Restrict a name to the characters SPDX allows in identifiers
+fn spdx_id_safe(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '-' })
+        .collect()
+}
+
+/// Random version 4 UUID for document identifiers
+fn random_uuid() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    SystemRandom::new().fill(&mut bytes)
+        .map_err(|_| sbom_error("Failed to generate document identifier".to_string()))?;
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    Ok(format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..]))
+}
+
+/// Pretty-print a document
+fn render(document: &Value) -> Result<String> {
+    serde_json::to_string_pretty(document)
+        .map_err(|e| sbom_error(format!("Failed to serialize SBOM: {}", e)))
+}
+
+/// Create an SBOM generation error
+fn sbom_error(reason: String) -> crate::error::ReleaseError {
+    WorkspaceError::SbomFailed { reason }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APP: &str = "path+file:///ws/app#0.2.0";
+    const CORE: &str = "path+file:///ws/core#0.2.0";
+    const SERDE: &str = "registry+https://github.com/rust-lang/crates.io-index#serde@1.0.200";
+    const FORK: &str = "git+https://github.com/acme/fork?rev=abc#fork@0.1.0";
+    const TEST_UTIL: &str = "registry+https://github.com/rust-lang/crates.io-index#test-util@1.0.0";
+    const UNUSED: &str = "registry+https://github.com/rust-lang/crates.io-index#unused@1.0.0";
+
+    fn package(id: &str, name: &str, version: &str, source: Option<&str>) -> Value {
+        json!({ "id": id, "name": name, "version": version, "license": "MIT OR Apache-2.0", "source": source })
+    }
+
+    fn dependency(pkg: &str, kinds: &[Option<&str>]) -> Value {
+        let kinds: Vec<Value> = kinds.iter().map(|kind| json!({ "kind": kind })).collect();
+        json!({ "pkg": pkg, "dep_kinds": kinds })
+    }
+
+    /// `cargo metadata` output for a workspace of `app` depending on `core`
+    fn metadata() -> Value {
+        json!({
+            "workspace_members": [APP, CORE],
+            "packages": [
+                package(APP, "app", "0.2.0", None),
+                package(CORE, "core", "0.2.0", None),
+                package(SERDE, "serde", "1.0.200", Some("registry+https://github.com/rust-lang/crates.io-index")),
+                package(FORK, "fork", "0.1.0", Some("git+https://github.com/acme/fork?rev=abc#abc")),
+                package(TEST_UTIL, "test-util", "1.0.0", Some("registry+https://github.com/rust-lang/crates.io-index")),
+                package(UNUSED, "unused", "1.0.0", Some("registry+https://github.com/rust-lang/crates.io-index")),
+            ],
+            "resolve": { "nodes": [
+                { "id": APP, "deps": [dependency(CORE, &[None]), dependency(TEST_UTIL, &[Some("dev")])] },
+                { "id": CORE, "deps": [dependency(SERDE, &[None, Some("dev")]), dependency(FORK, &[Some("build")])] },
+                { "id": SERDE, "deps": [] },
+                { "id": FORK, "deps": [] },
+                { "id": TEST_UTIL, "deps": [] },
+                { "id": UNUSED, "deps": [] },
+            ] },
+        })
+    }
+
+    fn sbom() -> Sbom {
+        let mut sbom = Sbom::from_metadata(&metadata(), &["app".to_string()]).unwrap();
+        sbom.set_git("0123abc", &["app-v0.2.0".to_string()]);
+        sbom
+    }
+
+    #[test]
+    fn test_components_reachable_from_released_packages() {
+        let sbom = sbom();
+        assert_eq!(sbom.released, vec![APP]);
+        // Dev-only and unreachable packages are left out
+        let ids: Vec<&str> = sbom.components.keys().map(String::as_str).collect();
+        assert_eq!(ids, vec![FORK, APP, CORE, SERDE]);
+        assert_eq!(sbom.components[APP].dependencies, vec![CORE]);
+        assert_eq!(sbom.components[CORE].dependencies, vec![SERDE, FORK]);
+        assert!(sbom.components[SERDE].is_from_crates_io());
+        assert!(!sbom.components[FORK].is_from_crates_io());
+        assert_eq!(sbom.released_components().map(|component| component.name.as_str()).collect::<Vec<_>>(), vec!["app"]);
+    }
+
+    #[test]
+    fn test_unknown_package_and_missing_resolve_are_errors() {
+        assert!(Sbom::from_metadata(&metadata(), &["serde".to_string()]).is_err());
+        let mut unresolved = metadata();
+        unresolved["resolve"] = Value::Null;
+        assert!(Sbom::from_metadata(&unresolved, &["app".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_cyclonedx_document() {
+        let document: Value = serde_json::from_str(&sbom().to_cyclonedx().unwrap()).unwrap();
+        assert_eq!(document["bomFormat"], "CycloneDX");
+        assert_eq!(document["specVersion"], "1.5");
+        assert!(document["serialNumber"].as_str().unwrap().starts_with("urn:uuid:"));
+        assert_eq!(document["metadata"]["component"]["name"], "app");
+        assert_eq!(document["metadata"]["properties"], json!([
+            { "name": "cyrup_release:git:commit", "value": "0123abc" },
+            { "name": "cyrup_release:git:tag", "value": "app-v0.2.0" },
+        ]));
+
+        let components = document["components"].as_array().unwrap();
+        assert_eq!(components.len(), 4);
+        let serde = components.iter().find(|component| component["name"] == "serde").unwrap();
+        assert_eq!(serde["type"], "library");
+        assert_eq!(serde["purl"], "pkg:cargo/serde@1.0.200");
+        assert_eq!(serde["licenses"], json!([{ "expression": "MIT OR Apache-2.0" }]));
+        assert!(document["dependencies"].as_array().unwrap()
+            .contains(&json!({ "ref": CORE, "dependsOn": [SERDE, FORK] })));
+    }
+
+    #[test]
+    fn test_spdx_document() {
+        let document: Value = serde_json::from_str(&sbom().to_spdx().unwrap()).unwrap();
+        assert_eq!(document["spdxVersion"], "SPDX-2.3");
+        assert_eq!(document["name"], "app-0.2.0");
+        assert_eq!(document["comment"], "Git commit: 0123abc; Tags: app-v0.2.0");
+
+        let packages = document["packages"].as_array().unwrap();
+        let by_name = |name: &str| packages.iter().find(|package| package["name"] == name).unwrap();
+        assert_eq!(by_name("serde")["downloadLocation"], "https://crates.io/api/v1/crates/serde/1.0.200/download");
+        assert_eq!(by_name("fork")["downloadLocation"], "git+https://github.com/acme/fork?rev=abc#abc");
+        assert_eq!(by_name("core")["downloadLocation"], "NOASSERTION");
+        assert_eq!(by_name("app")["sourceInfo"], "Released from git commit 0123abc");
+        assert_eq!(by_name("app")["SPDXID"], "SPDXRef-Package-1-app");
+
+        let relationships = document["relationships"].as_array().unwrap();
+        assert_eq!(relationships[0], json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": "SPDXRef-Package-1-app",
+        }));
+        let depends_on = relationships.iter().filter(|relationship| relationship["relationshipType"] == "DEPENDS_ON").count();
+        assert_eq!(depends_on, 3);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        let uuid = random_uuid().unwrap();
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+        assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"), "{}", uuid);
+        assert_ne!(uuid, random_uuid().unwrap());
+        assert_eq!(spdx_id_safe("my_crate+extra"), "my-crate-extra");
+    }
+}
@@ -3,8 +3,14 @@
 //! This module performs comprehensive validation to ensure the workspace is ready
 //! for release operations, preventing failures during the release process.
 
-use crate::error::{Result, GitError, PublishError};
-use crate::workspace::WorkspaceInfo;
+use crate::cli::ProgressBar;
+use crate::config::{AdvisoriesSection, AdvisoryAction, GitSection, OwnersSection, PackageContentsSection, ValidationSection};
+use crate::error::{Result, GitError, PublishError, ReleaseError};
+use crate::git::{template_glob, FetchOptions, GitOperations, GitRepository, RemoteUrl, TrustedKeys};
+use crate::publish::{is_team_owner, CargoOwners, CrateAvailability, RegistryIndex};
+use crate::workspace::{
+    format_size, internal_patches, nightly_features, AdvisoryDatabase, PackageContentsInspector, PackageInfo, Sbom, WorkspaceInfo,
+};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::process::Stdio;
@@ -15,6 +21,33 @@ use tokio::process::Command as AsyncCommand;
 pub struct WorkspaceValidator {
     workspace: WorkspaceInfo,
     repo_path: PathBuf,
+    config: ValidatorConfig,
+}
+
+/// Configuration for workspace validation
+#[derive(Debug, Clone, Default)]
+pub struct ValidatorConfig {
+    /// Git policy used for branch, upstream, and remote tag checks
+    pub git: GitSection,
+    /// Tags the release will create (checked against the remote)
+    pub release_tags: Vec<String>,
+    /// Package size and content limits
+    pub package_contents: PackageContentsSection,
+    /// Ownership policy, including whether publish rights are checked
+    pub owners: OwnersSection,
+    /// Registry packages will be published to (defaults to crates.io)
+    pub registry: Option<String>,
+    /// Optional documentation and doctest checks
+    pub validation: ValidationSection,
+    /// Security advisory policy for dependencies
+    pub advisories: AdvisoriesSection,
+    /// Branch being released when validating a detached worktree
+    /// (defaults to the checked-out branch)
+    pub branch: Option<String>,
+    /// Whether internal `[patch]` entries are stripped from the release commit
+    pub strip_patches: bool,
+    /// Whether untracked files are allowed in the working directory
+    pub allow_untracked: bool,
 }
 
 /// Validation result with detailed pass/fail information
@@ -48,11 +81,17 @@ pub struct ValidationCheck {
 impl WorkspaceValidator {
     /// Create a new workspace validator
     pub fn new(workspace: WorkspaceInfo) -> Result<Self> {
+        Self::with_config(workspace, ValidatorConfig::default())
+    }
+
+    /// Create a workspace validator with custom configuration
+    pub fn with_config(workspace: WorkspaceInfo, config: ValidatorConfig) -> Result<Self> {
         let repo_path = workspace.root.clone();
 
         Ok(Self {
             workspace,
             repo_path,
+            config,
         })
     }
 
@@ -62,23 +101,79 @@ impl WorkspaceValidator {
         let mut critical_errors = Vec::new();
         let mut warnings = Vec::new();
 
+        let doc_steps = usize::from(self.config.validation.docs) + usize::from(self.config.validation.doctests);
+        let step_count = 8
+            + usize::from(self.config.package_contents.check)
+            + usize::from(self.config.owners.check_publish_rights)
+            + usize::from(self.config.advisories.check)
+            + doc_steps * self.workspace.packages.len();
+        let progress = ProgressBar::new("Validating", step_count);
+
         // Git repository validation
-        self.validate_git_state(&mut checks, &mut critical_errors).await?;
+        progress.start("git state");
+        self.validate_git_state(&mut checks, &mut critical_errors, &mut warnings).await?;
+        progress.finish_item("git state");
 
         // Version consistency validation
+        progress.start("versions");
         self.validate_version_consistency(&mut checks, &mut critical_errors, &mut warnings).await?;
+        progress.finish_item("versions");
 
         // Build validation
+        progress.start("builds");
         self.validate_builds(&mut checks, &mut critical_errors, &mut warnings).await?;
+        progress.finish_item("builds");
+
+        // Documentation and doctest validation
+        if doc_steps > 0 {
+            self.validate_docs(&progress, &mut checks, &mut critical_errors, &mut warnings).await?;
+        }
+
+        // Package size and content validation
+        if self.config.package_contents.check {
+            progress.start("package contents");
+            self.validate_package_contents(&mut checks, &mut critical_errors, &mut warnings).await?;
+            progress.finish_item("package contents");
+        }
 
         // Credentials validation
+        progress.start("credentials");
         self.validate_credentials(&mut checks, &mut warnings).await?;
+        progress.finish_item("credentials");
 
         // Dependency validation
+        progress.start("dependencies");
         self.validate_dependencies(&mut checks, &mut critical_errors, &mut warnings).await?;
+        progress.finish_item("dependencies");
+
+        // Nightly feature policy validation
+        progress.start("nightly features");
+        self.validate_nightly_features(&mut checks, &mut critical_errors, &mut warnings)?;
+        progress.finish_item("nightly features");
+
+        // Internal patch validation
+        progress.start("patches");
+        self.validate_internal_patches(&mut checks, &mut critical_errors, &mut warnings)?;
+        progress.finish_item("patches");
+
+        // Security advisory validation
+        if self.config.advisories.check {
+            progress.start("advisories");
+            self.validate_advisories(&mut checks, &mut critical_errors, &mut warnings).await;
+            progress.finish_item("advisories");
+        }
 
         // Crates.io validation
+        progress.start("crates.io metadata");
         self.validate_crates_io_readiness(&mut checks, &mut warnings).await?;
+        progress.finish_item("crates.io metadata");
+
+        // Name availability and publish rights validation
+        if self.config.owners.check_publish_rights {
+            progress.start("publish rights");
+            self.validate_publish_rights(&mut checks, &mut critical_errors, &mut warnings).await?;
+            progress.finish_item("publish rights");
+        }
 
         let success = critical_errors.is_empty();
 
@@ -95,61 +190,57 @@ impl WorkspaceValidator {
         &self,
         checks: &mut Vec<ValidationCheck>,
         critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
     ) -> Result<()> {
         let start_time = std::time::Instant::now();
 
         // Check working directory status
-        let status_result = self.check_working_directory_clean().await;
+        let status_result = GitRepository::open(&self.repo_path)?.status().await;
         let duration = start_time.elapsed().as_millis() as u64;
 
-        match status_result {
-            Ok(true) => {
-                checks.push(ValidationCheck {
-                    name: "Git Working Directory".to_string(),
-                    passed: true,
-                    message: "Working directory is clean".to_string(),
-                    critical: true,
-                    duration_ms: duration,
-                });
-            }
-            Ok(false) => {
-                let error_msg = "Working directory has uncommitted changes";
-                checks.push(ValidationCheck {
-                    name: "Git Working Directory".to_string(),
-                    passed: false,
-                    message: error_msg.to_string(),
-                    critical: true,
-                    duration_ms: duration,
-                });
-                critical_errors.push(error_msg.to_string());
-            }
-            Err(e) => {
-                let error_msg = format!("Failed to check git status: {}", e);
-                checks.push(ValidationCheck {
-                    name: "Git Working Directory".to_string(),
-                    passed: false,
-                    message: error_msg.clone(),
-                    critical: true,
-                    duration_ms: duration,
-                });
-                critical_errors.push(error_msg);
+        let (passed, message) = match status_result {
+            Ok(status) if status.is_clean() => (true, "Working directory is clean".to_string()),
+            Ok(status) if !status.has_tracked_changes() && self.config.allow_untracked => {
+                warnings.push(format!(
+                    "{} untracked path(s) will be left out of the release commit",
+                    status.untracked.len()
+                ));
+                (true, format!("No uncommitted changes ({} untracked allowed)", status.untracked.len()))
             }
+            Ok(status) if !status.has_tracked_changes() => (false, format!(
+                "Working directory has {} untracked path(s); commit or remove them, or pass --allow-untracked",
+                status.untracked.len()
+            )),
+            Ok(status) => (false, format!("Working directory has uncommitted changes ({})", status.summary())),
+            Err(e) => (false, format!("Failed to check git status: {}", e)),
+        };
+
+        if !passed {
+            critical_errors.push(message.clone());
         }
+        checks.push(ValidationCheck {
+            name: "Git Working Directory".to_string(),
+            passed,
+            message,
+            critical: true,
+            duration_ms: duration,
+        });
 
         // Check if we're on a valid branch
         let branch_check_start = std::time::Instant::now();
         let branch_result = self.check_valid_branch().await;
         let branch_duration = branch_check_start.elapsed().as_millis() as u64;
 
-        match branch_result {
+        let current_branch = match branch_result {
             Ok(branch_name) => {
                 checks.push(ValidationCheck {
                     name: "Git Branch".to_string(),
                     passed: true,
-                    message: format!("On branch: {}", branch_name),
+                    message: format!("On branch: {}", branch_name.as_deref().unwrap_or("detached HEAD")),
                     critical: false,
                     duration_ms: branch_duration,
                 });
+                branch_name
             }
             Err(e) => {
                 let error_msg = format!("Invalid git branch state: {}", e);
@@ -161,62 +252,362 @@ impl WorkspaceValidator {
                     duration_ms: branch_duration,
                 });
                 critical_errors.push(error_msg);
+                return Ok(());
+            }
+        };
+
+        // Check the branch against the allowed release branch patterns
+        self.validate_release_branch(current_branch.as_deref(), checks, critical_errors);
+
+        // Check that the branch is not behind its upstream
+        if self.config.git.check_upstream
+            && let Some(branch_name) = current_branch.as_deref()
+        {
+            self.validate_upstream_sync(branch_name, checks, critical_errors, warnings).await;
+        }
+
+        // Check that HEAD and the latest release tags are signed by trusted keys
+        if self.config.git.verify_signatures {
+            self.validate_signatures(checks, critical_errors).await;
+        }
+
+        // Check that the configured remote exists
+        let has_remote = self.validate_remote(checks, warnings).await;
+
+        // Check that none of the release tags already exist remotely
+        if self.config.git.check_remote_tag && has_remote {
+            for tag_name in &self.config.release_tags {
+                self.validate_remote_tag(tag_name, checks, critical_errors, warnings).await;
             }
         }
 
         Ok(())
     }
 
-    /// Check if working directory is clean
-    async fn check_working_directory_clean(&self) -> Result<bool> {
-        // Use is_dirty() - the correct gix 0.73.0 API for status checking
-        // Use git CLI to check status
-        let output = AsyncCommand::new("git")
-            .args(&["status", "--porcelain"])
-            .current_dir(&self.repo_path)
-            .output()
-            .await
-            .map_err(|e| GitError::OperationFailed {
-                operation: "git status".to_string(),
-                reason: e.to_string(),
-            })?;
-        
-        if !output.status.success() {
-            return Err(GitError::OperationFailed {
-                operation: "git status".to_string(),
-                reason: String::from_utf8_lossy(&output.stderr).to_string(),
-            }.into());
+    /// Validate that the current branch matches an allowed release branch pattern
+    fn validate_release_branch(
+        &self,
+        current_branch: Option<&str>,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+    ) {
+        let start_time = std::time::Instant::now();
+        let patterns = self.config.git.release_branches.join(", ");
+
+        let (passed, message) = match current_branch {
+            Some(branch_name) if self.config.git.is_release_branch(branch_name) => {
+                if patterns.is_empty() {
+                    (true, format!("Branch '{}' allowed (no release branch restrictions)", branch_name))
+                } else {
+                    (true, format!("Branch '{}' matches release branch patterns [{}]", branch_name, patterns))
+                }
+            }
+            Some(branch_name) => (
+                false,
+                format!("Branch '{}' is not an allowed release branch (allowed: [{}])", branch_name, patterns),
+            ),
+            None => (false, "Cannot release from a detached HEAD".to_string()),
+        };
+
+        if !passed {
+            critical_errors.push(message.clone());
         }
-        
-        // Return true if clean (empty output)
-        Ok(output.stdout.is_empty())
+
+        checks.push(ValidationCheck {
+            name: "Release Branch".to_string(),
+            passed,
+            message,
+            critical: true,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        });
+    }
+
+    /// Validate that the local branch is not behind its upstream
+    async fn validate_upstream_sync(
+        &self,
+        branch_name: &str,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) {
+        let start_time = std::time::Instant::now();
+        let status = self.check_upstream_status(branch_name).await;
+        let duration = start_time.elapsed().as_millis() as u64;
+
+        let (passed, critical, message) = match status {
+            Ok(UpstreamStatus::UpToDate { upstream }) => {
+                (true, true, format!("Branch '{}' is up to date with {}", branch_name, upstream))
+            }
+            Ok(UpstreamStatus::Behind { upstream, commits_behind, commits_ahead: 0 }) => {
+                (false, true, format!(
+                    "Branch '{}' is {} commit(s) behind {}; pull before releasing",
+                    branch_name, commits_behind, upstream
+                ))
+            }
+            Ok(UpstreamStatus::Behind { upstream, commits_behind, commits_ahead }) => {
+                (false, true, format!(
+                    "Branch '{}' has diverged from {} ({} local, {} upstream commit(s)); merge or rebase before releasing",
+                    branch_name, upstream, commits_ahead, commits_behind
+                ))
+            }
+            Ok(UpstreamStatus::NoUpstream) => {
+                (false, false, format!("Branch '{}' has no upstream tracking branch", branch_name))
+            }
+            Ok(UpstreamStatus::MissingOnRemote { upstream }) => {
+                (false, false, format!("Upstream {} does not exist on the remote", upstream))
+            }
+            Err(e) => (false, false, format!("Could not verify upstream state: {}", e)),
+        };
+
+        if !passed {
+            if critical {
+                critical_errors.push(message.clone());
+            } else {
+                warnings.push(message.clone());
+            }
+        }
+
+        checks.push(ValidationCheck {
+            name: "Upstream Sync".to_string(),
+            passed,
+            message,
+            critical,
+            duration_ms: duration,
+        });
     }
 
-    /// Check if we're on a valid branch
-    async fn check_valid_branch(&self) -> Result<String> {
-        let output = AsyncCommand::new("git")
-            .args(&["branch", "--show-current"])
+    /// Validate that HEAD and the latest tag of each release tag template carry trusted signatures
+    async fn validate_signatures(&self, checks: &mut Vec<ValidationCheck>, critical_errors: &mut Vec<String>) {
+        let repository = match GitRepository::open(&self.repo_path) {
+            Ok(repository) => repository,
+            Err(e) => {
+                critical_errors.push(format!("Could not verify signatures: {}", e));
+                return;
+            }
+        };
+        let keys = TrustedKeys {
+            gpg_home: self.config.git.gpg_home.as_ref().map(|path| self.repo_path.join(path)),
+            allowed_signers: self.config.git.allowed_signers.as_ref().map(|path| self.repo_path.join(path)),
+        };
+
+        let mut subjects = vec![("Commit Signature", "HEAD".to_string(), false)];
+        let mut globs = Vec::new();
+        for template in [&self.config.git.tag_template, &self.config.git.package_tag_template] {
+            if let Ok(glob) = template_glob(template)
+                && !globs.contains(&glob)
+            {
+                // Most recent release tag reachable from HEAD, if any
+                if let Ok(tag) = self.git_output(&["describe", "--tags", "--abbrev=0", "--match", &glob, "HEAD"]).await
+                    && !tag.is_empty()
+                {
+                    subjects.push(("Tag Signature", tag, true));
+                }
+                globs.push(glob);
+            }
+        }
+
+        for (name, subject, is_tag) in subjects {
+            let start_time = std::time::Instant::now();
+            let verification = if is_tag {
+                repository.verify_tag_signature(&subject, &keys).await
+            } else {
+                repository.verify_commit_signature(&subject, &keys).await
+            };
+            let (passed, message) = match verification {
+                Ok(verification) if verification.is_trusted() => (true, format!(
+                    "{} has a good signature by {}",
+                    subject,
+                    verification.signer.or(verification.key).unwrap_or_else(|| "a trusted key".to_string())
+                )),
+                Ok(verification) => (false, format!(
+                    "{} is {}{}",
+                    subject,
+                    verification.status.describe(),
+                    verification.key.map(|key| format!(" ({})", key)).unwrap_or_default()
+                )),
+                Err(e) => (false, format!("Could not verify the signature of {}: {}", subject, e)),
+            };
+
+            if !passed {
+                critical_errors.push(message.clone());
+            }
+            checks.push(ValidationCheck {
+                name: name.to_string(),
+                passed,
+                message,
+                critical: true,
+                duration_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+    }
+
+    /// Validate that the configured remote exists, returning whether it does
+    async fn validate_remote(&self, checks: &mut Vec<ValidationCheck>, warnings: &mut Vec<String>) -> bool {
+        let start_time = std::time::Instant::now();
+        let remote = &self.config.git.remote;
+        let url = match GitRepository::open(&self.repo_path) {
+            Ok(repository) => repository.remote_url(remote, false).await,
+            Err(e) => Err(e),
+        };
+        let duration = start_time.elapsed().as_millis() as u64;
+
+        let (passed, message) = match &url {
+            Ok(url) => (true, match RemoteUrl::parse(url) {
+                Some(parsed) => format!("Remote '{}' is {} ({})", remote, parsed, parsed.provider),
+                None => format!("Remote '{}' is {}", remote, url),
+            }),
+            Err(ReleaseError::Git(GitError::RemoteNotFound { .. })) => (false, format!(
+                "Remote '{}' is not configured; the release can only be made with --no-push",
+                remote
+            )),
+            Err(e) => (false, format!("Could not read remote '{}': {}", remote, e)),
+        };
+
+        if !passed {
+            warnings.push(message.clone());
+        }
+        checks.push(ValidationCheck {
+            name: "Git Remote".to_string(),
+            passed,
+            message,
+            critical: false,
+            duration_ms: duration,
+        });
+        passed
+    }
+
+    /// Validate that the release tag does not already exist on the remote
+    async fn validate_remote_tag(
+        &self,
+        tag_name: &str,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) {
+        let start_time = std::time::Instant::now();
+        let remote = &self.config.git.remote;
+        let tag_ref = format!("refs/tags/{}", tag_name);
+        let result = match GitRepository::open(&self.repo_path) {
+            Ok(repository) => repository.with_credentials(self.config.git.credentials()).find_remote_ref(Some(remote), &tag_ref, Some(self.config.git.remote_timeout())).await,
+            Err(e) => Err(e),
+        };
+        let duration = start_time.elapsed().as_millis() as u64;
+
+        match result {
+            Ok(None) => {
+                checks.push(ValidationCheck {
+                    name: "Remote Tag".to_string(),
+                    passed: true,
+                    message: format!("Tag '{}' does not exist on {}", tag_name, remote),
+                    critical: true,
+                    duration_ms: duration,
+                });
+            }
+            Ok(Some(remote_tag)) => {
+                let commit = remote_tag.commit();
+                let error_msg = format!(
+                    "Tag '{}' already exists on remote '{}' at {}",
+                    tag_name,
+                    remote,
+                    &commit[..commit.len().min(8)]
+                );
+                checks.push(ValidationCheck {
+                    name: "Remote Tag".to_string(),
+                    passed: false,
+                    message: error_msg.clone(),
+                    critical: true,
+                    duration_ms: duration,
+                });
+                critical_errors.push(error_msg);
+            }
+            Err(e) => {
+                let warning_msg = format!("Could not query remote tags on '{}': {}", remote, e);
+                checks.push(ValidationCheck {
+                    name: "Remote Tag".to_string(),
+                    passed: false,
+                    message: warning_msg.clone(),
+                    critical: false,
+                    duration_ms: duration,
+                });
+                warnings.push(warning_msg);
+            }
+        }
+    }
+
+    /// Check if we're on a valid branch, returning `None` for a detached HEAD
+    async fn check_valid_branch(&self) -> Result<Option<String>> {
+        if let Some(branch_name) = &self.config.branch {
+            return Ok(Some(branch_name.clone()));
+        }
+
+        let branch_name = self.git_output(&["branch", "--show-current"]).await?;
+        if branch_name.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(branch_name))
+        }
+    }
+
+    /// Fetch the upstream of the local branch and determine how the two relate
+    async fn check_upstream_status(&self, branch_name: &str) -> Result<UpstreamStatus> {
+        let repository = GitRepository::open(&self.repo_path)?.with_credentials(self.config.git.credentials());
+        let Some(upstream_ref) = repository.upstream_of(branch_name).await? else {
+            return Ok(UpstreamStatus::NoUpstream);
+        };
+
+        let short_name = upstream_ref.merge_ref.strip_prefix("refs/heads/").unwrap_or(&upstream_ref.merge_ref);
+        let upstream = format!("{}/{}", upstream_ref.remote, short_name);
+        let tracking_ref = format!("refs/remotes/{}", upstream);
+        let fetched = repository.fetch(&FetchOptions {
+            remote: Some(upstream_ref.remote.clone()),
+            refspecs: vec![format!("+{}:{}", upstream_ref.merge_ref, tracking_ref)],
+            ..FetchOptions::default()
+        }, None).await;
+        match fetched {
+            Ok(_) => {}
+            Err(ReleaseError::Git(GitError::RemoteRefNotFound { .. })) => {
+                return Ok(UpstreamStatus::MissingOnRemote { upstream });
+            }
+            Err(e) => return Err(e),
+        }
+
+        let divergence = repository.divergence("HEAD", &tracking_ref).await?;
+        if divergence.behind == 0 {
+            return Ok(UpstreamStatus::UpToDate { upstream });
+        }
+        Ok(UpstreamStatus::Behind {
+            upstream,
+            commits_behind: divergence.behind,
+            commits_ahead: divergence.ahead,
+        })
+    }
+
+    /// Run a git command in the repository
+    async fn run_git(&self, args: &[&str]) -> Result<std::process::Output> {
+        AsyncCommand::new("git")
+            .args(args)
             .current_dir(&self.repo_path)
+            .env("GIT_TERMINAL_PROMPT", "0")
             .output()
             .await
             .map_err(|e| GitError::OperationFailed {
-                operation: "git branch".to_string(),
+                operation: format!("git {}", args.join(" ")),
                 reason: e.to_string(),
-            })?;
-        
+            }.into())
+    }
+
+    /// Run a git command and return its trimmed stdout, failing on non-zero exit
+    async fn git_output(&self, args: &[&str]) -> Result<String> {
+        let output = self.run_git(args).await?;
+
         if !output.status.success() {
             return Err(GitError::OperationFailed {
-                operation: "git branch".to_string(),
-                reason: String::from_utf8_lossy(&output.stderr).to_string(),
+                operation: format!("git {}", args.join(" ")),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
             }.into());
         }
-        
-        let branch_name = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if branch_name.is_empty() {
-            Ok("detached HEAD".to_string())
-        } else {
-            Ok(branch_name)
-        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
     /// Validate version consistency across packages
@@ -372,6 +763,405 @@ impl WorkspaceValidator {
         Ok(())
     }
 
+    /// Build documentation and run doctests for each package, recording one check per package
+    async fn validate_docs(
+        &self,
+        progress: &ProgressBar,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<()> {
+        let mut packages: Vec<&PackageInfo> = self.workspace.packages.values().collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Broken intra-doc links only warn by default, but they break the published docs
+        let rustdocflags = match std::env::var("RUSTDOCFLAGS") {
+            Ok(flags) if !flags.trim().is_empty() => format!("{} -D rustdoc::broken_intra_doc_links", flags),
+            _ => "-D rustdoc::broken_intra_doc_links".to_string(),
+        };
+
+        for package_info in packages {
+            if self.config.validation.docs {
+                let item = format!("docs {}", package_info.name);
+                progress.start(&item);
+                let start_time = std::time::Instant::now();
+                let output = self.run_package_cargo(package_info, &["doc", "--no-deps"], &rustdocflags).await?;
+                let duration = start_time.elapsed().as_millis() as u64;
+
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let (passed, message) = if output.status.success() {
+                    if stderr.contains("warning:") {
+                        warnings.push(format!("{}: documentation builds with warnings", package_info.name));
+                        (true, "Documentation builds with warnings".to_string())
+                    } else {
+                        (true, "Documentation builds".to_string())
+                    }
+                } else {
+                    let summary = summarize_cargo_output(&stderr, |line| line.starts_with("error"));
+                    critical_errors.push(format!("{}: cargo doc failed: {}", package_info.name, summary));
+                    (false, format!("cargo doc failed: {}", summary))
+                };
+
+                checks.push(ValidationCheck {
+                    name: format!("Docs ({})", package_info.name),
+                    passed,
+                    message,
+                    critical: true,
+                    duration_ms: duration,
+                });
+                progress.finish_item(&item);
+            }
+
+            if self.config.validation.doctests {
+                let item = format!("doctests {}", package_info.name);
+                progress.start(&item);
+                let start_time = std::time::Instant::now();
+                let output = self.run_package_cargo(package_info, &["test", "--doc"], &rustdocflags).await?;
+                let duration = start_time.elapsed().as_millis() as u64;
+
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let (passed, message) = if output.status.success() {
+                    (true, "Doctests pass".to_string())
+                } else if stderr.contains("no library targets found") {
+                    (true, "No library target, so no doctests".to_string())
+                } else {
+                    // Failing doctests are listed on stdout; build errors end up on stderr
+                    let failing: Vec<&str> = stdout.lines()
+                        .filter_map(|line| line.strip_prefix("---- ")?.strip_suffix(" stdout ----"))
+                        .collect();
+                    let summary = if failing.is_empty() {
+                        summarize_cargo_output(&stderr, |line| line.starts_with("error"))
+                    } else {
+                        failing.join(", ")
+                    };
+                    critical_errors.push(format!("{}: doctests failed: {}", package_info.name, summary));
+                    (false, format!("Doctests failed: {}", summary))
+                };
+
+                checks.push(ValidationCheck {
+                    name: format!("Doctests ({})", package_info.name),
+                    passed,
+                    message,
+                    critical: true,
+                    duration_ms: duration,
+                });
+                progress.finish_item(&item);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run a cargo subcommand against a single package
+    async fn run_package_cargo(
+        &self,
+        package_info: &PackageInfo,
+        args: &[&str],
+        rustdocflags: &str,
+    ) -> Result<std::process::Output> {
+        AsyncCommand::new("cargo")
+            .args(args)
+            .arg("--manifest-path")
+            .arg(&package_info.cargo_toml_path)
+            .env("RUSTDOCFLAGS", rustdocflags)
+            .current_dir(&self.workspace.root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .map_err(|e| PublishError::PublishFailed {
+                package: package_info.name.clone(),
+                reason: format!("Failed to execute cargo {}: {}", args.join(" "), e),
+            }.into())
+    }
+
+    /// Validate package sizes and flag files that should not be published
+    async fn validate_package_contents(
+        &self,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<()> {
+        let start_time = std::time::Instant::now();
+        let limits = &self.config.package_contents;
+        let inspector = PackageContentsInspector::new(&limits.deny, limits.large_file_size)?;
+
+        let compressed_sizes = match inspector.compressed_sizes(&self.workspace).await {
+            Ok(sizes) => sizes,
+            Err(e) => {
+                warnings.push(format!("Compressed package sizes unavailable, using file sizes: {}", e));
+                Default::default()
+            }
+        };
+
+        let mut package_names: Vec<&String> = self.workspace.packages.keys().collect();
+        package_names.sort();
+
+        let mut oversized = Vec::new();
+        let mut flagged_files = 0;
+        let mut summaries = Vec::new();
+
+        for package_name in package_names {
+            let package_info = &self.workspace.packages[package_name];
+            let mut contents = match inspector.inspect(package_info).await {
+                Ok(contents) => contents,
+                Err(e) => {
+                    warnings.push(format!("{}: could not list package contents: {}", package_name, e));
+                    continue;
+                }
+            };
+            contents.compressed_size = compressed_sizes.get(package_name).copied();
+            summaries.push(contents.format_summary());
+
+            if contents.effective_size() > limits.max_size {
+                oversized.push(format!(
+                    "{}: package size {} exceeds limit of {}",
+                    package_name,
+                    format_size(contents.effective_size()),
+                    format_size(limits.max_size)
+                ));
+            }
+
+            for (file, pattern) in &contents.denied_files {
+                warnings.push(format!(
+                    "{}: '{}' matches deny pattern '{}' and would be published",
+                    package_name,
+                    file.display(),
+                    pattern
+                ));
+            }
+
+            for (file, size) in &contents.large_files {
+                warnings.push(format!(
+                    "{}: large file '{}' ({}) would be published",
+                    package_name,
+                    file.display(),
+                    format_size(*size)
+                ));
+            }
+
+            flagged_files += contents.denied_files.len() + contents.large_files.len();
+        }
+
+        let duration = start_time.elapsed().as_millis() as u64;
+
+        let mut message = if oversized.is_empty() {
+            format!("All packages within {}", format_size(limits.max_size))
+        } else {
+            format!("{} packages exceed {}", oversized.len(), format_size(limits.max_size))
+        };
+        if flagged_files > 0 {
+            message.push_str(&format!(" ({} files flagged)", flagged_files));
+        }
+        if !summaries.is_empty() {
+            message.push_str(&format!("\n    {}", summaries.join("\n    ")));
+        }
+
+        checks.push(ValidationCheck {
+            name: "Package Contents".to_string(),
+            passed: oversized.is_empty(),
+            message,
+            critical: true,
+            duration_ms: duration,
+        });
+
+        critical_errors.extend(oversized);
+
+        Ok(())
+    }
+
+    /// Check that packages only enable allowed nightly features
+    fn validate_nightly_features(
+        &self,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<()> {
+        let start_time = std::time::Instant::now();
+        let allowed: Vec<glob::Pattern> = self.config.validation.allowed_nightly_features.iter()
+            .filter_map(|pattern| glob::Pattern::new(pattern).ok())
+            .collect();
+
+        let mut package_names: Vec<&String> = self.workspace.packages.keys().collect();
+        package_names.sort();
+
+        let mut nightly_packages = Vec::new();
+        let mut disallowed = Vec::new();
+        for package_name in package_names {
+            let features = nightly_features(&self.workspace.packages[package_name])?;
+            if features.is_empty() {
+                continue;
+            }
+
+            let mut names: Vec<&str> = features.iter().map(|feature| feature.feature.as_str()).collect();
+            names.sort();
+            names.dedup();
+            nightly_packages.push(format!("{} ({})", package_name, names.join(", ")));
+            warnings.push(format!(
+                "{} requires a nightly toolchain ({}); stable users cannot build it",
+                package_name,
+                names.join(", ")
+            ));
+
+            for feature in &features {
+                if !allowed.iter().any(|pattern| pattern.matches(&feature.feature)) {
+                    disallowed.push(format!(
+                        "{}: nightly feature '{}' in {} is not in validation.allowed-nightly-features",
+                        package_name,
+                        feature.feature,
+                        feature.file.display()
+                    ));
+                }
+            }
+        }
+
+        let message = if nightly_packages.is_empty() {
+            "All packages build on stable".to_string()
+        } else {
+            format!("Nightly-only packages: {}", nightly_packages.join("; "))
+        };
+
+        checks.push(ValidationCheck {
+            name: "Nightly Features".to_string(),
+            passed: disallowed.is_empty(),
+            message,
+            critical: true,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        });
+
+        critical_errors.extend(disallowed);
+
+        Ok(())
+    }
+
+    /// Check for `[patch]` entries that make local builds differ from the published packages
+    fn validate_internal_patches(
+        &self,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<()> {
+        let start_time = std::time::Instant::now();
+        let patches = internal_patches(&self.workspace)?;
+
+        let blocking = !patches.is_empty() && self.config.validation.deny_internal_patches && !self.config.strip_patches;
+        for patch in &patches {
+            let problem = format!(
+                "{} patches an internal crate; published packages will not see it",
+                patch.format_patch()
+            );
+            if blocking {
+                critical_errors.push(format!(
+                    "{} (validation.deny-internal-patches is set; remove it or release with --strip-patches)",
+                    problem
+                ));
+            } else {
+                warnings.push(problem);
+            }
+        }
+
+        let message = if patches.is_empty() {
+            "No [patch] entries point at workspace members".to_string()
+        } else if self.config.strip_patches {
+            format!("{} internal [patch] entries will be stripped from the release commit", patches.len())
+        } else {
+            format!("{} [patch] entries point at workspace members", patches.len())
+        };
+
+        checks.push(ValidationCheck {
+            name: "Internal Patches".to_string(),
+            passed: !blocking,
+            message,
+            critical: self.config.validation.deny_internal_patches,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        });
+
+        Ok(())
+    }
+
+    /// Check the dependencies of workspace packages against the RustSec advisory database
+    async fn validate_advisories(
+        &self,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) {
+        let start_time = std::time::Instant::now();
+        let settings = &self.config.advisories;
+        let deny = settings.action == AdvisoryAction::Deny;
+        let db_path = settings.db_path.clone().unwrap_or_else(AdvisoryDatabase::default_path);
+
+        if settings.fetch
+            && let Err(e) = AdvisoryDatabase::fetch(&db_path, &settings.db_url).await
+        {
+            warnings.push(format!("Could not update the advisory database, using the local copy: {}", e));
+        }
+
+        let mut package_names = self.workspace.package_names();
+        package_names.sort();
+        let scan = match AdvisoryDatabase::open(&db_path) {
+            Ok(database) => Sbom::generate(&self.workspace.root, &package_names).await
+                .map(|sbom| (database, sbom)),
+            Err(e) => Err(e),
+        };
+        let (database, sbom) = match scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                warnings.push(format!("Security advisory check skipped: {}", e));
+                checks.push(ValidationCheck {
+                    name: "Security Advisories".to_string(),
+                    passed: false,
+                    message: "Advisory database or dependency tree unavailable".to_string(),
+                    critical: false,
+                    duration_ms: start_time.elapsed().as_millis() as u64,
+                });
+                return;
+            }
+        };
+
+        let (ignored, matches): (Vec<_>, Vec<_>) = database.check(&sbom)
+            .into_iter()
+            .partition(|found| found.advisory.is_ignored(&settings.ignore));
+        let (vulnerabilities, notices): (Vec<_>, Vec<_>) = matches.into_iter()
+            .partition(|found| found.advisory.is_vulnerability());
+
+        for notice in &notices {
+            warnings.push(notice.format_result());
+        }
+        for vulnerability in &vulnerabilities {
+            if deny {
+                critical_errors.push(vulnerability.format_result());
+            } else {
+                warnings.push(vulnerability.format_result());
+            }
+        }
+
+        let mut message = if vulnerabilities.is_empty() {
+            format!(
+                "No known vulnerabilities in {} dependencies ({} advisories)",
+                sbom.components.len(),
+                database.len()
+            )
+        } else {
+            format!("{} known vulnerabilities in dependencies", vulnerabilities.len())
+        };
+        if !notices.is_empty() {
+            message.push_str(&format!(", {} informational", notices.len()));
+        }
+        if !ignored.is_empty() {
+            message.push_str(&format!(", {} ignored", ignored.len()));
+        }
+
+        checks.push(ValidationCheck {
+            name: "Security Advisories".to_string(),
+            passed: vulnerabilities.is_empty(),
+            message,
+            critical: deny,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        });
+    }
+
     /// Test building a single package
     async fn test_package_build(&self, package_info: &crate::workspace::PackageInfo) -> Result<BuildResult> {
         let mut cmd = AsyncCommand::new("cargo");
@@ -484,6 +1274,33 @@ impl WorkspaceValidator {
         let mut missing_dependencies = Vec::new();
         let mut version_conflicts = Vec::new();
 
+        // Dev-dependencies don't constrain publish order, but versioned ones must
+        // still accept the workspace package's current version
+        for (package_name, package_info) in &self.workspace.packages {
+            for (key, dep_spec) in &package_info.all_dependencies {
+                let Some(dep_name) = key.strip_prefix("dev:") else {
+                    continue;
+                };
+                let (Some(requirement), Some(_)) = (&dep_spec.version, &dep_spec.path) else {
+                    continue;
+                };
+                let Some(dep_info) = self.workspace.packages.get(dep_name) else {
+                    continue;
+                };
+
+                let matches = semver::VersionReq::parse(requirement)
+                    .ok()
+                    .zip(semver::Version::parse(&dep_info.version).ok())
+                    .is_some_and(|(req, version)| req.matches(&version));
+                if !matches {
+                    version_conflicts.push(format!(
+                        "Package '{}' dev-dependency on '{}' requires '{}' but the workspace has {}",
+                        package_name, dep_name, requirement, dep_info.version
+                    ));
+                }
+            }
+        }
+
         // Check that all workspace dependencies exist and are properly versioned
         for (package_name, package_info) in &self.workspace.packages {
             for dep_name in &package_info.workspace_dependencies {
@@ -598,6 +1415,123 @@ impl WorkspaceValidator {
 
         Ok(())
     }
+
+    /// Validate that every package name can be published by us.
+    ///
+    /// Unpublished names must not collide with an existing crate, and crates
+    /// that already exist must list the token's user among their owners, so
+    /// a release does not fail halfway through the publish tiers.
+    async fn validate_publish_rights(
+        &self,
+        checks: &mut Vec<ValidationCheck>,
+        critical_errors: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<()> {
+        let start_time = std::time::Instant::now();
+
+        let registry_index = RegistryIndex::for_registry(self.config.registry.as_deref())?;
+        let owners = CargoOwners::with_registry(self.config.registry.clone());
+
+        let login = match owners.current_login().await {
+            Ok(login) => login,
+            Err(e) => {
+                warnings.push(format!("Could not determine the registry user: {}", e));
+                None
+            }
+        };
+
+        // Crates that are never published need no publish rights
+        let mut package_names: Vec<&String> = self.workspace.packages.iter()
+            .filter(|(_, package_info)| package_info.is_publishable())
+            .map(|(name, _)| name)
+            .collect();
+        package_names.sort();
+
+        let mut errors = Vec::new();
+        let mut unverified = Vec::new();
+        for package_name in package_names {
+            let latest = match registry_index.availability(package_name).await {
+                Ok(CrateAvailability::Available) => continue,
+                Ok(CrateAvailability::Taken { existing }) => {
+                    errors.push(format!(
+                        "Package '{}' cannot be published: the registry treats it as the existing crate '{}'",
+                        package_name, existing
+                    ));
+                    continue;
+                }
+                Ok(CrateAvailability::Published { latest }) => latest,
+                Err(e) => {
+                    warnings.push(format!("Could not query the registry for '{}': {}", package_name, e));
+                    continue;
+                }
+            };
+
+            let Some(login) = &login else {
+                unverified.push(package_name.clone());
+                continue;
+            };
+
+            let current_owners = match owners.list(package_name).await {
+                Ok(current_owners) => current_owners,
+                Err(e) => {
+                    warnings.push(format!("Could not list owners of '{}': {}", package_name, e));
+                    continue;
+                }
+            };
+
+            if current_owners.iter().any(|owner| owner == login) {
+                continue;
+            }
+
+            if current_owners.iter().any(|owner| is_team_owner(owner)) {
+                warnings.push(format!(
+                    "'{}' is not a direct owner of '{}'; publishing relies on team membership",
+                    login, package_name
+                ));
+            } else {
+                errors.push(format!(
+                    "'{}' has no publish rights for '{}' (latest {}, owned by {})",
+                    login, package_name, latest, current_owners.join(", ")
+                ));
+            }
+        }
+
+        let duration = start_time.elapsed().as_millis() as u64;
+
+        if !errors.is_empty() {
+            checks.push(ValidationCheck {
+                name: "Publish Rights".to_string(),
+                passed: false,
+                message: format!("{} packages cannot be published", errors.len()),
+                critical: true,
+                duration_ms: duration,
+            });
+            critical_errors.extend(errors);
+        } else if !unverified.is_empty() {
+            let warning_msg = format!(
+                "Publish rights not verified for {} (no registry user available)",
+                unverified.join(", ")
+            );
+            checks.push(ValidationCheck {
+                name: "Publish Rights".to_string(),
+                passed: true,
+                message: warning_msg.clone(),
+                critical: false,
+                duration_ms: duration,
+            });
+            warnings.push(warning_msg);
+        } else {
+            checks.push(ValidationCheck {
+                name: "Publish Rights".to_string(),
+                passed: true,
+                message: "All package names are available or owned by the registry user".to_string(),
+                critical: false,
+                duration_ms: duration,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// Result of a package build test
@@ -607,6 +1541,19 @@ enum BuildResult {
     Warning(String),
 }
 
+/// Relationship between the local branch and its upstream
+#[derive(Debug)]
+enum UpstreamStatus {
+    /// No upstream tracking branch is configured
+    NoUpstream,
+    /// The upstream branch does not exist on the remote
+    MissingOnRemote { upstream: String },
+    /// The remote head is contained in the local branch
+    UpToDate { upstream: String },
+    /// The remote has commits the local branch does not
+    Behind { upstream: String, commits_behind: usize, commits_ahead: usize },
+}
+
 impl ValidationResult {
     /// Get all failed checks
     pub fn failed_checks(&self) -> Vec<&ValidationCheck> {
@@ -676,4 +1623,118 @@ impl ValidationCheck {
             status, self.name, self.duration_ms, criticality, self.message
         )
     }
-}
\ No newline at end of file
+}
+
+/// Condense cargo output to the matching lines (at most five), or its last line
+fn summarize_cargo_output(output: &str, is_relevant: impl Fn(&str) -> bool) -> String {
+    let relevant: Vec<&str> = output.lines()
+        .map(str::trim)
+        .filter(|line| is_relevant(line))
+        .take(5)
+        .collect();
+
+    if relevant.is_empty() {
+        output.lines().map(str::trim).rfind(|line| !line.is_empty()).unwrap_or_default().to_string()
+    } else {
+        relevant.join("; ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::test_repo::{git, TestRepo};
+    use crate::workspace::WorkspaceConfig;
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    fn validator(root: &Path) -> WorkspaceValidator {
+        let workspace = WorkspaceInfo {
+            root: root.to_path_buf(),
+            workspace_config: WorkspaceConfig {
+                members: Vec::new(),
+                package: None,
+                dependencies: None,
+            },
+            packages: HashMap::new(),
+            internal_dependencies: HashMap::new(),
+        };
+        WorkspaceValidator::new(workspace).unwrap()
+    }
+
+    fn release_branch_check(validator: &WorkspaceValidator, branch: Option<&str>) -> (ValidationCheck, Vec<String>) {
+        let mut checks = Vec::new();
+        let mut critical_errors = Vec::new();
+        validator.validate_release_branch(branch, &mut checks, &mut critical_errors);
+        (checks.remove(0), critical_errors)
+    }
+
+    #[test]
+    fn test_release_branch_check() {
+        let validator = validator(Path::new("."));
+
+        let (check, critical_errors) = release_branch_check(&validator, Some("main"));
+        assert!(check.passed && check.critical);
+        assert!(critical_errors.is_empty());
+
+        let (check, critical_errors) = release_branch_check(&validator, Some("feature/login"));
+        assert!(!check.passed && check.critical);
+        assert_eq!(critical_errors, vec![
+            "Branch 'feature/login' is not an allowed release branch (allowed: [main, master])".to_string(),
+        ]);
+
+        let (check, critical_errors) = release_branch_check(&validator, None);
+        assert!(!check.passed && check.critical);
+        assert_eq!(critical_errors, vec!["Cannot release from a detached HEAD".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_detached_head_is_a_critical_error() {
+        let test = TestRepo::new();
+        test.git(&["checkout", "--quiet", "--detach"]);
+        let validator = validator(&test.work);
+
+        let (mut checks, mut critical_errors, mut warnings) = (Vec::new(), Vec::new(), Vec::new());
+        validator.validate_git_state(&mut checks, &mut critical_errors, &mut warnings).await.unwrap();
+        assert!(critical_errors.contains(&"Cannot release from a detached HEAD".to_string()), "{:?}", critical_errors);
+        let branch = checks.iter().find(|check| check.name == "Git Branch").unwrap();
+        assert_eq!(branch.message, "On branch: detached HEAD");
+        // Without a branch there is no upstream to compare with
+        assert!(!checks.iter().any(|check| check.name == "Upstream Sync"));
+    }
+
+    #[tokio::test]
+    async fn test_upstream_status_against_a_bare_remote() {
+        let test = TestRepo::new();
+        let validator = validator(&test.work);
+        let status = validator.check_upstream_status("main").await.unwrap();
+        assert!(matches!(status, UpstreamStatus::NoUpstream), "{:?}", status);
+
+        test.git(&["push", "--quiet", "--set-upstream", "origin", "main"]);
+        let status = validator.check_upstream_status("main").await.unwrap();
+        assert!(matches!(&status, UpstreamStatus::UpToDate { upstream } if upstream == "origin/main"), "{:?}", status);
+
+        // A local commit the remote does not have yet is still up to date
+        test.commit("LOCAL.md", "local", "Local change");
+        let status = validator.check_upstream_status("main").await.unwrap();
+        assert!(matches!(status, UpstreamStatus::UpToDate { .. }), "{:?}", status);
+
+        let upstream = tempfile::tempdir().unwrap();
+        git(upstream.path(), &["clone", "--quiet", &test.remote.display().to_string(), "clone"]);
+        let clone = upstream.path().join("clone");
+        std::fs::write(clone.join("UPSTREAM.md"), "upstream").unwrap();
+        git(&clone, &["add", "UPSTREAM.md"]);
+        git(&clone, &["commit", "--quiet", "-m", "Upstream change"]);
+        git(&clone, &["push", "--quiet", "origin", "main"]);
+        let status = validator.check_upstream_status("main").await.unwrap();
+        assert!(matches!(status, UpstreamStatus::Behind { commits_behind: 1, commits_ahead: 1, .. }), "{:?}", status);
+
+        test.git(&["reset", "--quiet", "--hard", "HEAD~1"]);
+        let status = validator.check_upstream_status("main").await.unwrap();
+        assert!(matches!(status, UpstreamStatus::Behind { commits_behind: 1, commits_ahead: 0, .. }), "{:?}", status);
+
+        git(&test.remote, &["branch", "--quiet", "-m", "main", "trunk"]);
+        let status = validator.check_upstream_status("main").await.unwrap();
+        assert!(matches!(status, UpstreamStatus::MissingOnRemote { .. }), "{:?}", status);
+    }
+}
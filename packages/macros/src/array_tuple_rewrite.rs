@@ -0,0 +1,96 @@
+//! AST rewriting behind `#[array_tuple_syntax]`
+
+use syn::visit_mut::{self, VisitMut};
+use syn::{Attribute, Expr, ExprCall, ExprMethodCall, parse_quote};
+
+/// Name of the attribute that opts an argument out of rewriting
+const OPT_OUT: &str = "array_tuple_syntax";
+
+/// Rewrites array-of-tuple call arguments into `hash_map_fn!`-style closures
+#[derive(Default)]
+pub struct ArrayTupleRewriter {
+    errors: Vec<syn::Error>,
+}
+
+impl ArrayTupleRewriter {
+    /// Returns the errors collected while rewriting, combined into one
+    pub fn into_error(self) -> Option<syn::Error> {
+        self.errors.into_iter().reduce(|mut combined, error| {
+            combined.combine(error);
+            combined
+        })
+    }
+
+    fn rewrite_args<'a>(&mut self, args: impl Iterator<Item = &'a mut Expr>) {
+        for arg in args {
+            match take_opt_out(arg) {
+                Ok(true) => {}
+                Ok(false) => rewrite_array_tuple(arg),
+                Err(error) => self.errors.push(error),
+            }
+        }
+    }
+}
+
+impl VisitMut for ArrayTupleRewriter {
+    fn visit_expr_method_call_mut(&mut self, call: &mut ExprMethodCall) {
+        visit_mut::visit_expr_method_call_mut(self, call);
+        self.rewrite_args(call.args.iter_mut());
+    }
+
+    fn visit_expr_call_mut(&mut self, call: &mut ExprCall) {
+        visit_mut::visit_expr_call_mut(self, call);
+        self.rewrite_args(call.args.iter_mut());
+    }
+}
+
+/// Strips a `#[array_tuple_syntax(skip)]` attribute, returning whether one was present
+fn take_opt_out(arg: &mut Expr) -> syn::Result<bool> {
+    let Some(attrs) = expr_attrs_mut(arg) else {
+        return Ok(false);
+    };
+    let Some(idx) = attrs.iter().position(|attr| attr.path().is_ident(OPT_OUT)) else {
+        return Ok(false);
+    };
+    let attr = attrs.remove(idx);
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("skip") {
+            Ok(())
+        } else {
+            Err(meta.error("expected `skip`"))
+        }
+    })?;
+    Ok(true)
+}
+
+/// Returns the outer attributes of the expressions that can hold an array tuple literal
+fn expr_attrs_mut(expr: &mut Expr) -> Option<&mut Vec<Attribute>> {
+    match expr {
+        Expr::Array(array) => Some(&mut array.attrs),
+        Expr::Path(path) => Some(&mut path.attrs),
+        Expr::Paren(paren) => Some(&mut paren.attrs),
+        Expr::Reference(reference) => Some(&mut reference.attrs),
+        _ => None,
+    }
+}
+
+/// Replaces a non-empty `[(key, value), ...]` literal with a closure building a HashMap
+fn rewrite_array_tuple(arg: &mut Expr) {
+    let Expr::Array(array) = arg else {
+        return;
+    };
+    let is_array_tuple = !array.elems.is_empty()
+        && array
+            .elems
+            .iter()
+            .all(|elem| matches!(elem, Expr::Tuple(tuple) if tuple.elems.len() == 2));
+    if !is_array_tuple || !array.attrs.is_empty() {
+        return;
+    }
+    let pairs = &array.elems;
+    *arg = parse_quote! {
+        || {
+            <::hashbrown::HashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([#pairs])
+        }
+    };
+}
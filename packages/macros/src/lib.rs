@@ -2,31 +2,81 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, Expr, Token, parse_macro_input};
 
-/// Attribute macro that automatically transforms JSON syntax in function bodies
+mod array_tuple_rewrite;
+mod map_literal;
+mod message_chunk;
+mod validate;
+
+use array_tuple_rewrite::ArrayTupleRewriter;
+use map_literal::MapEntries;
+use syn::visit_mut::VisitMut;
+
+/// Attribute macro that rewrites array tuple arguments into HashMap closures
 ///
-/// This macro finds patterns like `.method([("key", "value")])` and transforms them
-/// to work with array tuple syntax automatically.
+/// Inside the annotated item, every non-empty `[(key, value), ...]` literal passed to a
+/// method or function call becomes `|| HashMap::from_iter([(key, value), ...])`, the
+/// same closure `hash_map_fn!` builds, so builder methods taking
+/// `FnOnce() -> hashbrown::HashMap<K, V>` accept the literal directly. Mark an argument
+/// with `#[array_tuple_syntax(skip)]` to pass the array through unchanged.
 ///
 /// Usage:
-/// ```ignore
-/// #[array_tuple_syntax]  
-/// fn main() {
-///     let builder = FluentAi::agent_role("example")
-///         .additional_params([("beta", "true")])  // <- array tuple syntax
-///         .metadata([("key", "val")]);            // <- array tuple syntax
+/// ```rust
+/// use sugars_macros::array_tuple_syntax;
+///
+/// struct Builder(Vec<(&'static str, &'static str)>);
+///
+/// impl Builder {
+///     fn metadata<F>(mut self, f: F) -> Self
+///     where
+///         F: FnOnce() -> hashbrown::HashMap<&'static str, &'static str>,
+///     {
+///         self.0.extend(f());
+///         self
+///     }
+///
+///     fn pairs(mut self, pairs: [(&'static str, &'static str); 1]) -> Self {
+///         self.0.extend(pairs);
+///         self
+///     }
+/// }
+///
+/// #[array_tuple_syntax]
+/// fn build() -> Builder {
+///     Builder(Vec::new())
+///         .metadata([("beta", "true")]) // <- rewritten into a closure
+///         .pairs(#[array_tuple_syntax(skip)] [("key", "val")])
 /// }
+///
+/// assert_eq!(build().0.len(), 2);
 /// ```
 #[proc_macro_attribute]
-pub fn array_tuple_syntax(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    // For now, just pass through the input unchanged
-    // The transformation happens in the builder methods themselves
-    item
+pub fn array_tuple_syntax(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new_spanned(attr, "`#[array_tuple_syntax]` takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let mut item = parse_macro_input!(item as syn::Item);
+    let mut rewriter = ArrayTupleRewriter::default();
+    rewriter.visit_item_mut(&mut item);
+    let errors = rewriter.into_error().map(|error| error.to_compile_error());
+
+    quote! {
+        #errors
+        #item
+    }
+    .into()
 }
 
 /// Creates a closure that returns a hashbrown HashMap from key-value pairs
 ///
-/// This replaces the macro_rules! version to work in proc-macro crates
+/// Entries are `key => value` pairs or `(key, value)` tuples separated by commas, with an
+/// optional trailing comma. Keys and values can be any expression, including ones that
+/// contain commas themselves.
 ///
 /// Usage:
 /// ```rust
@@ -34,38 +84,180 @@ pub fn array_tuple_syntax(_attr: TokenStream, item: TokenStream) -> TokenStream
 ///
 /// let map_fn = hash_map_fn!{"key" => "value", "foo" => "bar"};
 /// let map = map_fn();
+///
+/// let tuples = hash_map_fn![("point", (1, 2)), ("list", (3, 4)),]();
+/// assert_eq!(tuples["point"], (1, 2));
 /// ```
 #[proc_macro]
 pub fn hash_map_fn(input: TokenStream) -> TokenStream {
-    // Convert the input to a string and manually parse key => value pairs
-    let input_str = input.to_string();
-
-    // Transform "key" => "value" pairs to ("key", "value") tuples
-    let parts: Vec<&str> = input_str.split(',').collect();
-    let mut tuple_pairs = Vec::new();
-
-    for part in parts {
-        let trimmed = part.trim();
-        if let Some(arrow_pos) = trimmed.find(" => ") {
-            let key = trimmed[..arrow_pos].trim();
-            let value = trimmed[arrow_pos + 4..].trim();
-            tuple_pairs.push(format!("({}, {})", key, value));
-        } else if let Some(arrow_pos) = trimmed.find("=>") {
-            let key = trimmed[..arrow_pos].trim();
-            let value = trimmed[arrow_pos + 2..].trim();
-            tuple_pairs.push(format!("({}, {})", key, value));
+    let entries = parse_macro_input!(input as MapEntries);
+    let pairs = entries.to_array();
+
+    quote! {
+        || {
+            <::hashbrown::HashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter(#pairs)
+        }
+    }
+    .into()
+}
+
+/// Creates a closure that returns a `std::collections::BTreeMap` from key-value pairs
+///
+/// Accepts the same `key => value` and `(key, value)` entries as `hash_map_fn!`.
+///
+/// Usage:
+/// ```rust
+/// use sugars_macros::btree_map_fn;
+///
+/// let map_fn = btree_map_fn!{"b" => 2, "a" => 1};
+/// assert_eq!(map_fn().into_iter().collect::<Vec<_>>(), [("a", 1), ("b", 2)]);
+/// ```
+#[proc_macro]
+pub fn btree_map_fn(input: TokenStream) -> TokenStream {
+    let entries = parse_macro_input!(input as MapEntries);
+    let pairs = entries.to_array();
+
+    quote! {
+        || {
+            <::std::collections::BTreeMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter(#pairs)
+        }
+    }
+    .into()
+}
+
+/// Creates a closure that returns an `indexmap::IndexMap` from key-value pairs
+///
+/// Entries keep the order they are written in. The calling crate must depend on `indexmap`.
+///
+/// Usage:
+/// ```ignore
+/// use sugars_macros::index_map_fn;
+///
+/// let map_fn = index_map_fn!{"core" => 0, "macros" => 1};
+/// assert_eq!(map_fn().get_index(0), Some((&"core", &0)));
+/// ```
+#[proc_macro]
+pub fn index_map_fn(input: TokenStream) -> TokenStream {
+    let entries = parse_macro_input!(input as MapEntries);
+    let pairs = entries.to_array();
+
+    quote! {
+        || {
+            <::indexmap::IndexMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter(#pairs)
         }
     }
+    .into()
+}
 
-    let tuple_str = tuple_pairs.join(", ");
-    let parsed_tokens: proc_macro2::TokenStream = tuple_str.parse().unwrap_or_default();
+/// Creates a closure that returns a hashbrown `HashSet` from a list of values
+///
+/// Usage:
+/// ```rust
+/// use sugars_macros::hash_set_fn;
+///
+/// let set_fn = hash_set_fn!["rayon", "serde"];
+/// assert!(set_fn().contains("serde"));
+/// ```
+#[proc_macro]
+pub fn hash_set_fn(input: TokenStream) -> TokenStream {
+    let values = parse_macro_input!(input with Punctuated::<Expr, Token![,]>::parse_terminated);
+    let values = values.iter();
 
     quote! {
         || {
-            <::hashbrown::HashMap::<_, _> as ::core::iter::FromIterator<_>>::from_iter([
-                #parsed_tokens
-            ])
+            <::hashbrown::HashSet::<_> as ::core::iter::FromIterator<_>>::from_iter([#(#values),*])
         }
     }
     .into()
 }
+
+/// Derives `MessageChunk`, generating `bad_chunk` and `error`
+///
+/// On a struct, the error is stored in an `Option<String>` field marked `#[chunk(error)]`
+/// or named `error`, and `bad_chunk` fills every other field with `Default::default()`.
+/// On an enum, `bad_chunk` builds the variant marked `#[chunk(error)]`, which must hold
+/// exactly one `String`. The trait is looked up in `sugars_builders`; point elsewhere with
+/// `#[chunk(crate = path)]`.
+///
+/// Usage:
+/// ```ignore
+/// use sugars_builders::MessageChunk;
+///
+/// #[derive(Default, MessageChunk)]
+/// struct Token {
+///     text: String,
+///     #[chunk(error)]
+///     failure: Option<String>,
+/// }
+///
+/// #[derive(MessageChunk)]
+/// enum Event {
+///     Text(String),
+///     #[chunk(error)]
+///     Failed { reason: String },
+/// }
+/// ```
+#[proc_macro_derive(MessageChunk, attributes(chunk))]
+pub fn derive_message_chunk(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    message_chunk::derive_message_chunk(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Rejects types with `Result` fields at compile time
+///
+/// `AsyncTask` and `AsyncStream` only carry unwrapped values, so chunk types should never
+/// hold a `Result`. This derive generates no code; it only reports each `Result` it finds,
+/// including ones nested in generics such as `Vec<Result<T, E>>`.
+///
+/// Usage:
+/// ```ignore
+/// use sugars_builders::NoResult;
+///
+/// #[derive(NoResult)]
+/// struct Chunk {
+///     text: String,
+///     error: Option<String>,
+/// }
+/// ```
+#[proc_macro_derive(NoResult)]
+pub fn derive_no_result(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    message_chunk::derive_no_result(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Validate` from `#[validate(...)]` field attributes
+///
+/// Each field can list any of `non_empty`, `range(min = .., max = ..)` (inclusive, either
+/// bound optional), `regex = "pattern"` (needs the `regex` feature of `sugars_builders`),
+/// `custom = path::to_fn` for a `fn(&T) -> Result<(), String>`, and `nested` to validate a
+/// field that itself implements `Validate`. Every failure is reported with the field's path.
+///
+/// Usage:
+/// ```ignore
+/// use sugars_builders::validate::Validate;
+///
+/// #[derive(Validate)]
+/// struct Retry {
+///     #[validate(range(min = 1, max = 5))]
+///     max: u32,
+/// }
+///
+/// #[derive(Validate)]
+/// struct Config {
+///     #[validate(non_empty)]
+///     name: String,
+///     #[validate(nested)]
+///     retry: Retry,
+/// }
+/// ```
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    validate::derive_validate(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,58 @@
+//! Parsing for map literal macro input
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Token};
+
+/// A single `key => value` or `(key, value)` entry
+pub struct MapEntry {
+    pub key: Expr,
+    pub value: Expr,
+}
+
+impl Parse for MapEntry {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let key: Expr = input.parse()?;
+        if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            let value = input.parse()?;
+            return Ok(MapEntry { key, value });
+        }
+        match key {
+            Expr::Tuple(tuple) if tuple.elems.len() == 2 => {
+                let mut elems = tuple.elems.into_iter();
+                match (elems.next(), elems.next()) {
+                    (Some(key), Some(value)) => Ok(MapEntry { key, value }),
+                    _ => unreachable!("tuple has exactly two elements"),
+                }
+            }
+            _ if !input.is_empty() && !input.peek(Token![,]) => Err(input.error("expected `=>`")),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "expected `key => value` or a `(key, value)` tuple",
+            )),
+        }
+    }
+}
+
+/// Comma-separated map entries with an optional trailing comma
+pub struct MapEntries(pub Punctuated<MapEntry, Token![,]>);
+
+impl Parse for MapEntries {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Punctuated::parse_terminated(input).map(MapEntries)
+    }
+}
+
+impl MapEntries {
+    /// Renders the entries as a `[(key, value), ...]` array expression
+    pub fn to_array(&self) -> TokenStream {
+        let pairs = self
+            .0
+            .iter()
+            .map(|MapEntry { key, value }| quote! { (#key, #value) });
+        quote! { [#(#pairs),*] }
+    }
+}
@@ -0,0 +1,233 @@
+//! `#[derive(MessageChunk)]` and `#[derive(NoResult)]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Fields, Member, Path, Type};
+
+/// Returns whether `attrs` contain `#[chunk(error)]`
+fn is_error_marker(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut marked = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("chunk")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                marked = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `error`"))
+            }
+        })?;
+    }
+    Ok(marked)
+}
+
+/// Reads `#[chunk(crate = path)]` from the container, defaulting to `::sugars_builders`
+fn trait_path(attrs: &[Attribute]) -> syn::Result<Path> {
+    let mut krate: Path = syn::parse_quote!(::sugars_builders);
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("chunk")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate = path`"))
+            }
+        })?;
+    }
+    Ok(syn::parse_quote!(#krate::MessageChunk))
+}
+
+/// Expands `#[derive(MessageChunk)]`
+///
+/// Structs store the error in an `Option<String>` field marked `#[chunk(error)]` (or named
+/// `error`) and default every other field. Enums build the single-field variant marked
+/// `#[chunk(error)]`.
+pub fn derive_message_chunk(input: DeriveInput) -> syn::Result<TokenStream> {
+    let message_chunk = trait_path(&input.attrs)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (bad_chunk, error) = match &input.data {
+        Data::Struct(data) => struct_bodies(name, &data.fields)?,
+        Data::Enum(data) => {
+            let mut marked = Vec::new();
+            for variant in &data.variants {
+                if is_error_marker(&variant.attrs)? {
+                    marked.push(variant);
+                }
+            }
+            let variant = match marked.as_slice() {
+                [variant] => *variant,
+                [] => {
+                    return Err(syn::Error::new_spanned(
+                        name,
+                        "mark the error variant with `#[chunk(error)]`",
+                    ));
+                }
+                [_, extra, ..] => {
+                    return Err(syn::Error::new_spanned(
+                        &extra.ident,
+                        "only one variant can be marked `#[chunk(error)]`",
+                    ));
+                }
+            };
+            let ident = &variant.ident;
+            let member = match &variant.fields {
+                Fields::Named(fields) if fields.named.len() == 1 => {
+                    Member::Named(fields.named[0].ident.clone().expect("named field"))
+                }
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Member::from(0),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "the `#[chunk(error)]` variant must hold exactly one `String` field",
+                    ));
+                }
+            };
+            (
+                quote! { Self::#ident { #member: error } },
+                quote! {
+                    match self {
+                        Self::#ident { #member: error } => {
+                            ::core::option::Option::Some(::core::ops::Deref::deref(error))
+                        }
+                        _ => ::core::option::Option::None,
+                    }
+                },
+            )
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`MessageChunk` cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #message_chunk for #name #ty_generics #where_clause {
+            fn bad_chunk(error: ::std::string::String) -> Self {
+                #bad_chunk
+            }
+
+            fn error(&self) -> ::core::option::Option<&str> {
+                #error
+            }
+        }
+    })
+}
+
+/// Builds the `bad_chunk` and `error` bodies for a struct
+fn struct_bodies(name: &syn::Ident, fields: &Fields) -> syn::Result<(TokenStream, TokenStream)> {
+    let members: Vec<(Member, &syn::Field)> = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::from(idx),
+            };
+            (member, field)
+        })
+        .collect();
+
+    let mut marked = Vec::new();
+    for (member, field) in &members {
+        if is_error_marker(&field.attrs)? {
+            marked.push(member);
+        }
+    }
+    let error_member = match marked.as_slice() {
+        [member] => (*member).clone(),
+        [] => members
+            .iter()
+            .map(|(member, _)| member)
+            .find(|member| matches!(member, Member::Named(ident) if ident == "error"))
+            .cloned()
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    name,
+                    "add an `Option<String>` field named `error` or marked `#[chunk(error)]`",
+                )
+            })?,
+        [_, extra, ..] => {
+            return Err(syn::Error::new(
+                extra.span(),
+                "only one field can be marked `#[chunk(error)]`",
+            ));
+        }
+    };
+
+    let inits = members.iter().map(|(member, _)| {
+        if *member == error_member {
+            quote! { #member: ::core::option::Option::Some(error) }
+        } else {
+            quote! { #member: ::core::default::Default::default() }
+        }
+    });
+    Ok((
+        quote! { Self { #(#inits),* } },
+        quote! { ::core::option::Option::as_deref(&self.#error_member) },
+    ))
+}
+
+/// Returns the first `Result` type found anywhere inside `ty`
+fn find_result(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(path) => {
+            let segments = &path.path.segments;
+            if segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result")
+            {
+                return Some(ty);
+            }
+            segments
+                .iter()
+                .find_map(|segment| match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => {
+                        args.args.iter().find_map(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => find_result(ty),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                })
+        }
+        Type::Array(array) => find_result(&array.elem),
+        Type::Slice(slice) => find_result(&slice.elem),
+        Type::Group(group) => find_result(&group.elem),
+        Type::Paren(paren) => find_result(&paren.elem),
+        Type::Reference(reference) => find_result(&reference.elem),
+        Type::Ptr(ptr) => find_result(&ptr.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().find_map(find_result),
+        _ => None,
+    }
+}
+
+/// Expands `#[derive(NoResult)]`, which only checks that no field holds a `Result`
+pub fn derive_no_result(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields: Vec<&syn::Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| v.fields.iter()).collect(),
+        Data::Union(data) => data.fields.named.iter().collect(),
+    };
+    let errors = fields
+        .into_iter()
+        .filter_map(|field| find_result(&field.ty))
+        .map(|ty| {
+            syn::Error::new_spanned(
+                ty,
+                "`Result` fields break the \"always unwrapped\" pattern; \
+                 store the value and report errors with `MessageChunk::bad_chunk`",
+            )
+        })
+        .reduce(|mut combined, error| {
+            combined.combine(error);
+            combined
+        });
+    match errors {
+        Some(error) => Err(error),
+        None => Ok(TokenStream::new()),
+    }
+}
@@ -0,0 +1,134 @@
+//! `#[derive(Validate)]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Expr, Fields, LitStr, Path};
+
+/// Reads `#[validate(crate = path)]` from the container, defaulting to `::sugars_builders`
+fn crate_path(attrs: &[Attribute]) -> syn::Result<Path> {
+    let mut krate: Path = syn::parse_quote!(::sugars_builders);
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate = path`"))
+            }
+        })?;
+    }
+    Ok(krate)
+}
+
+/// Parses the `#[validate(...)]` attributes of one field into validator calls
+fn field_checks(krate: &Path, field: &syn::Field) -> syn::Result<Vec<TokenStream>> {
+    let ident = field.ident.as_ref().expect("named field");
+    let name = ident.to_string();
+    let mut rules = Vec::new();
+    let mut nested = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("non_empty") {
+                rules.push(quote! { #krate::validate::non_empty() });
+            } else if meta.path.is_ident("nested") {
+                nested = true;
+            } else if meta.path.is_ident("range") {
+                let mut min: Option<Expr> = None;
+                let mut max: Option<Expr> = None;
+                meta.parse_nested_meta(|bound| {
+                    if bound.path.is_ident("min") {
+                        min = Some(bound.value()?.parse()?);
+                    } else if bound.path.is_ident("max") {
+                        max = Some(bound.value()?.parse()?);
+                    } else {
+                        return Err(bound.error("expected `min` or `max`"));
+                    }
+                    Ok(())
+                })?;
+                let bounds = match (min, max) {
+                    (Some(min), Some(max)) => quote! { (#min)..=(#max) },
+                    (Some(min), None) => quote! { (#min).. },
+                    (None, Some(max)) => quote! { ..=(#max) },
+                    (None, None) => return Err(meta.error("`range` needs `min`, `max` or both")),
+                };
+                rules.push(quote! { #krate::validate::range(#bounds) });
+            } else if meta.path.is_ident("regex") {
+                let source: LitStr = meta.value()?.parse()?;
+                rules.push(quote! {
+                    {
+                        static PATTERN: ::std::sync::OnceLock<
+                            ::core::option::Option<#krate::validate::__regex::Regex>,
+                        > = ::std::sync::OnceLock::new();
+                        #krate::validate::cached_pattern(&PATTERN, #source)
+                    }
+                });
+            } else if meta.path.is_ident("custom") {
+                let check: Path = meta.value()?.parse()?;
+                rules.push(quote! { #check });
+            } else {
+                return Err(meta.error(
+                    "expected `non_empty`, `range(..)`, `regex = \"..\"`, `custom = path` or `nested`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    let mut checks = Vec::new();
+    if !rules.is_empty() {
+        checks.push(quote! {
+            validator.field(#name, &self.#ident) #(.rule(#rules))*;
+        });
+    }
+    if nested {
+        checks.push(quote! { validator.nested(#name, &self.#ident); });
+    }
+    Ok(checks)
+}
+
+/// Expands `#[derive(Validate)]` for structs with named fields
+pub fn derive_validate(input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate_path(&input.attrs)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &data.fields,
+                    "`Validate` can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`Validate` can only be derived for structs with named fields",
+            ));
+        }
+    };
+
+    let mut checks = Vec::new();
+    for field in fields {
+        checks.extend(field_checks(&krate, field)?);
+    }
+
+    Ok(quote! {
+        impl #impl_generics #krate::validate::Validate for #name #ty_generics #where_clause {
+            fn validate(
+                &self,
+            ) -> ::core::result::Result<(), #krate::validate::ValidationErrors> {
+                let mut validator = #krate::validate::Validator::new();
+                #(#checks)*
+                validator.finish()
+            }
+        }
+    })
+}
@@ -0,0 +1,60 @@
+use pretty_assertions::assert_eq;
+use sugars_builders::{MessageChunk, NoResult};
+
+#[derive(Debug, Default, PartialEq, MessageChunk, NoResult)]
+struct Token {
+    text: String,
+    index: usize,
+    #[chunk(error)]
+    failure: Option<String>,
+}
+
+#[derive(Debug, PartialEq, MessageChunk)]
+struct Line(String, Option<String>, #[chunk(error)] Option<String>);
+
+#[derive(Default, MessageChunk)]
+struct Plain {
+    error: Option<String>,
+}
+
+#[derive(Debug, PartialEq, MessageChunk, NoResult)]
+enum Event {
+    Text(String),
+    #[chunk(error)]
+    Failed {
+        reason: String,
+    },
+}
+
+#[test]
+fn test_struct_marked_field() {
+    let chunk = Token::bad_chunk("timeout".to_string());
+    assert_eq!(
+        chunk,
+        Token {
+            text: String::new(),
+            index: 0,
+            failure: Some("timeout".to_string()),
+        }
+    );
+    assert_eq!(chunk.error(), Some("timeout"));
+    assert!(!Token::default().is_error());
+}
+
+#[test]
+fn test_tuple_struct_and_default_field_name() {
+    let line = Line::bad_chunk("eof".to_string());
+    assert_eq!(line, Line(String::new(), None, Some("eof".to_string())));
+    assert_eq!(Plain::bad_chunk("x".to_string()).error(), Some("x"));
+}
+
+#[test]
+fn test_enum_error_variant() {
+    assert_eq!(
+        Event::bad_chunk("reset".to_string()),
+        Event::Failed {
+            reason: "reset".to_string()
+        }
+    );
+    assert_eq!(Event::Text("hi".to_string()).error(), None);
+}
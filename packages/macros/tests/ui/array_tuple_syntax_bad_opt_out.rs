@@ -0,0 +1,8 @@
+use sugars_macros::array_tuple_syntax;
+
+fn takes(_pairs: [(&str, &str); 1]) {}
+
+#[array_tuple_syntax]
+fn main() {
+    takes(#[array_tuple_syntax(keep)] [("key", "value")]);
+}
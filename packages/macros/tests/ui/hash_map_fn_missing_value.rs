@@ -0,0 +1,5 @@
+use sugars_macros::hash_map_fn;
+
+fn main() {
+    let _map = hash_map_fn! { "key" => };
+}
@@ -0,0 +1,8 @@
+use sugars_builders::MessageChunk;
+
+#[derive(Default, MessageChunk)]
+struct Token {
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use sugars_builders::NoResult;
+
+#[derive(NoResult)]
+struct Chunk {
+    text: String,
+    parsed: Result<u32, String>,
+    history: Vec<std::io::Result<()>>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sugars_builders::validate::Validate;
+
+#[derive(Validate)]
+struct Config {
+    #[validate(positive)]
+    retries: u32,
+}
+
+fn main() {}
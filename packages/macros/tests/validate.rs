@@ -0,0 +1,71 @@
+use pretty_assertions::assert_eq;
+use sugars_builders::validate::{Validate, ValidationErrors};
+
+fn even(value: &u32) -> Result<(), String> {
+    if value.is_multiple_of(2) {
+        Ok(())
+    } else {
+        Err("must be even".to_string())
+    }
+}
+
+#[derive(Validate)]
+struct Retry {
+    #[validate(range(min = 1, max = 5), custom = even)]
+    max: u32,
+    #[validate(range(max = 60))]
+    backoff_secs: u64,
+}
+
+#[derive(Validate)]
+struct Config {
+    #[validate(non_empty, regex = "^[a-z-]+$")]
+    name: String,
+    #[validate(nested)]
+    retry: Retry,
+}
+
+fn paths(errors: &ValidationErrors) -> Vec<&str> {
+    errors.iter().map(|error| error.path.as_str()).collect()
+}
+
+#[test]
+fn test_valid_config_passes() {
+    let config = Config {
+        name: "agent-one".to_string(),
+        retry: Retry {
+            max: 2,
+            backoff_secs: 30,
+        },
+    };
+    assert!(config.validated().is_ok());
+}
+
+#[test]
+fn test_every_failure_is_reported_with_its_path() {
+    let config = Config {
+        name: " ".to_string(),
+        retry: Retry {
+            max: 7,
+            backoff_secs: 90,
+        },
+    };
+    let errors = config.validate().unwrap_err();
+    assert_eq!(
+        paths(&errors),
+        [
+            "name",
+            "name",
+            "retry.max",
+            "retry.max",
+            "retry.backoff_secs"
+        ]
+    );
+    assert_eq!(
+        errors
+            .field("retry.max")
+            .map(|error| error.message.as_str())
+            .collect::<Vec<_>>(),
+        ["must be between 1 and 5", "must be even"]
+    );
+}